@@ -90,6 +90,7 @@ mod imp {
             obj.set_accels_for_action("win.mode-chess-standard", &["<primary><alt>1"]);
             obj.set_accels_for_action("win.mode-chess-960", &["<primary><alt>2"]);
             obj.set_accels_for_action("win.mode-chess-atomic", &["<primary><alt>3"]);
+            obj.set_accels_for_action("win.mode-chess-960-double", &["<primary><alt>4"]);
             obj.set_accels_for_action("win.chess-flip-board", &["<primary><alt>f"]);
             obj.set_accels_for_action("win.chess-auto-flip-board-each-move", &["<primary><alt>a"]);
             obj.set_accels_for_action("win.chess-show-board-coordinates", &["<primary><alt>c"]);
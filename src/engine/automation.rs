@@ -61,13 +61,128 @@ pub const FREECELL_AUTOMATION_PROFILE: AutomationProfile = AutomationProfile {
     robot_step_interval_ms: 50,
 };
 
+pub const YUKON_AUTOMATION_PROFILE: AutomationProfile = AutomationProfile {
+    hint_guided_analysis_budget: 120_000,
+    hint_exhaustive_analysis_budget: 220_000,
+    auto_play_lookahead_depth: 3,
+    auto_play_beam_width: 10,
+    auto_play_node_budget: 3_200,
+    auto_play_win_score: 1_200_000,
+    dialog_seed_guided_budget: 180_000,
+    dialog_seed_exhaustive_budget: 300_000,
+    dialog_find_winnable_state_budget: 15_000,
+    rapid_wand_interval_ms: 750,
+    rapid_wand_total_steps: 5,
+    robot_step_interval_ms: 250,
+};
+
+pub const PYRAMID_AUTOMATION_PROFILE: AutomationProfile = AutomationProfile {
+    hint_guided_analysis_budget: 60_000,
+    hint_exhaustive_analysis_budget: 200_000,
+    auto_play_lookahead_depth: 3,
+    auto_play_beam_width: 10,
+    auto_play_node_budget: 3_200,
+    auto_play_win_score: 1_200_000,
+    dialog_seed_guided_budget: 180_000,
+    dialog_seed_exhaustive_budget: 300_000,
+    dialog_find_winnable_state_budget: 15_000,
+    rapid_wand_interval_ms: 600,
+    rapid_wand_total_steps: 5,
+    robot_step_interval_ms: 250,
+};
+
+pub const WASTE_BUILD_AUTOMATION_PROFILE: AutomationProfile = AutomationProfile {
+    hint_guided_analysis_budget: 60_000,
+    hint_exhaustive_analysis_budget: 200_000,
+    auto_play_lookahead_depth: 3,
+    auto_play_beam_width: 10,
+    auto_play_node_budget: 3_200,
+    auto_play_win_score: 1_200_000,
+    dialog_seed_guided_budget: 180_000,
+    dialog_seed_exhaustive_budget: 300_000,
+    dialog_find_winnable_state_budget: 60_000,
+    rapid_wand_interval_ms: 500,
+    rapid_wand_total_steps: 5,
+    robot_step_interval_ms: 200,
+};
+
+pub const FORTY_THIEVES_AUTOMATION_PROFILE: AutomationProfile = AutomationProfile {
+    hint_guided_analysis_budget: 40_000,
+    hint_exhaustive_analysis_budget: 120_000,
+    auto_play_lookahead_depth: 3,
+    auto_play_beam_width: 10,
+    auto_play_node_budget: 3_200,
+    auto_play_win_score: 1_200_000,
+    dialog_seed_guided_budget: 180_000,
+    dialog_seed_exhaustive_budget: 300_000,
+    dialog_find_winnable_state_budget: 15_000,
+    rapid_wand_interval_ms: 500,
+    rapid_wand_total_steps: 5,
+    robot_step_interval_ms: 200,
+};
+
+pub const SCORPION_AUTOMATION_PROFILE: AutomationProfile = AutomationProfile {
+    hint_guided_analysis_budget: 40_000,
+    hint_exhaustive_analysis_budget: 120_000,
+    auto_play_lookahead_depth: 3,
+    auto_play_beam_width: 10,
+    auto_play_node_budget: 3_200,
+    auto_play_win_score: 1_200_000,
+    dialog_seed_guided_budget: 180_000,
+    dialog_seed_exhaustive_budget: 300_000,
+    dialog_find_winnable_state_budget: 15_000,
+    rapid_wand_interval_ms: 500,
+    rapid_wand_total_steps: 5,
+    robot_step_interval_ms: 200,
+};
+
+pub const CANFIELD_AUTOMATION_PROFILE: AutomationProfile = AutomationProfile {
+    hint_guided_analysis_budget: 40_000,
+    hint_exhaustive_analysis_budget: 120_000,
+    auto_play_lookahead_depth: 3,
+    auto_play_beam_width: 10,
+    auto_play_node_budget: 3_200,
+    auto_play_win_score: 1_200_000,
+    dialog_seed_guided_budget: 180_000,
+    dialog_seed_exhaustive_budget: 300_000,
+    dialog_find_winnable_state_budget: 15_000,
+    rapid_wand_interval_ms: 500,
+    rapid_wand_total_steps: 5,
+    robot_step_interval_ms: 200,
+};
+
+pub const OPEN_TABLEAU_AUTOMATION_PROFILE: AutomationProfile = AutomationProfile {
+    hint_guided_analysis_budget: 40_000,
+    hint_exhaustive_analysis_budget: 120_000,
+    auto_play_lookahead_depth: 3,
+    auto_play_beam_width: 10,
+    auto_play_node_budget: 3_200,
+    auto_play_win_score: 1_200_000,
+    dialog_seed_guided_budget: 200_000,
+    dialog_seed_exhaustive_budget: 400_000,
+    dialog_find_winnable_state_budget: 40_000,
+    rapid_wand_interval_ms: 500,
+    rapid_wand_total_steps: 5,
+    robot_step_interval_ms: 200,
+};
+
 impl AutomationProfile {
     pub fn for_mode(mode: GameMode) -> Self {
         // Future variants can customize solver/autoplay tuning independently.
         match mode {
             GameMode::Klondike => KLONDIKE_AUTOMATION_PROFILE,
             GameMode::Spider => SPIDER_AUTOMATION_PROFILE,
-            GameMode::Freecell => FREECELL_AUTOMATION_PROFILE,
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => FREECELL_AUTOMATION_PROFILE,
+            GameMode::Yukon => YUKON_AUTOMATION_PROFILE,
+            GameMode::Pyramid => PYRAMID_AUTOMATION_PROFILE,
+            GameMode::TriPeaks | GameMode::Golf => WASTE_BUILD_AUTOMATION_PROFILE,
+            GameMode::FortyThieves => FORTY_THIEVES_AUTOMATION_PROFILE,
+            GameMode::Scorpion => SCORPION_AUTOMATION_PROFILE,
+            GameMode::Canfield => CANFIELD_AUTOMATION_PROFILE,
+            GameMode::BakersDozen | GameMode::BeleagueredCastle => OPEN_TABLEAU_AUTOMATION_PROFILE,
         }
     }
 }
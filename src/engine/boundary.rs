@@ -69,6 +69,12 @@ pub fn execute_command(
         } => {
             changed_or_unchanged(engine.move_foundation_top_to_tableau(state, foundation_idx, dst))
         }
+        EngineCommand::RemovePyramidCards { first, second } => {
+            changed_or_unchanged(engine.remove_pyramid_cards(state, first, second))
+        }
+        EngineCommand::PlayToWaste { slot } => {
+            changed_or_unchanged(engine.play_to_waste(state, slot))
+        }
     }
 }
 
@@ -175,6 +181,10 @@ pub fn waste_top(state: &VariantStateStore, mode: GameMode) -> Option<Card> {
     with_engine(mode, |engine| engine.waste_top(state))
 }
 
+pub fn freecell_card(state: &VariantStateStore, mode: GameMode, cell: usize) -> Option<Card> {
+    with_engine(mode, |engine| engine.freecell_card(state, cell))
+}
+
 pub fn tableau_top(state: &VariantStateStore, mode: GameMode, col: usize) -> Option<Card> {
     with_engine(mode, |engine| engine.tableau_top(state, col))
 }
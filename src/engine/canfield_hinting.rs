@@ -0,0 +1,148 @@
+use crate::engine::hinting::HintNode;
+use crate::engine::variant_engine::CANFIELD_RESERVE_CELL;
+use crate::game::{CanfieldGame, CanfieldMove, CanfieldSolveResult, Card};
+
+/// The reserve shows in the first free-cell slot beside the waste.
+pub const CANFIELD_RESERVE_HINT_NODE: HintNode = HintNode::Freecell(CANFIELD_RESERVE_CELL);
+
+#[derive(Debug, Clone)]
+pub struct CanfieldHintSuggestion {
+    pub message: String,
+    pub source: Option<HintNode>,
+    pub target: Option<HintNode>,
+    pub canfield_move: Option<CanfieldMove>,
+}
+
+/// Source and target highlights for a move. Foundation nodes are indexed by
+/// suit, matching `Suit::foundation_index`.
+pub fn hint_nodes_for_move(
+    game: &CanfieldGame,
+    canfield_move: CanfieldMove,
+) -> (HintNode, HintNode) {
+    let foundation_for = |card: Option<Card>| {
+        card.map_or(HintNode::Foundation(0), |card| {
+            HintNode::Foundation(card.suit.foundation_index())
+        })
+    };
+    let column = |col: usize| HintNode::Tableau { col, index: None };
+    match canfield_move {
+        CanfieldMove::Draw => (HintNode::Stock, HintNode::Waste),
+        CanfieldMove::WasteToFoundation => (HintNode::Waste, foundation_for(game.waste_top())),
+        CanfieldMove::WasteToTableau { dst } => (HintNode::Waste, column(dst)),
+        CanfieldMove::ReserveToFoundation => (
+            CANFIELD_RESERVE_HINT_NODE,
+            foundation_for(game.reserve_top()),
+        ),
+        CanfieldMove::ReserveToTableau { dst } => (CANFIELD_RESERVE_HINT_NODE, column(dst)),
+        CanfieldMove::TableauTopToFoundation { src } => (
+            HintNode::Tableau {
+                col: src,
+                index: game.tableau()[src].len().checked_sub(1),
+            },
+            foundation_for(game.tableau_top(src)),
+        ),
+        CanfieldMove::TableauRunToTableau { src, start, dst } => (
+            HintNode::Tableau {
+                col: src,
+                index: Some(start),
+            },
+            column(dst),
+        ),
+    }
+}
+
+pub fn describe_move(game: &CanfieldGame, canfield_move: CanfieldMove) -> String {
+    let label = |card: Option<Card>| {
+        card.map(|card| card.label())
+            .unwrap_or_else(|| "?".to_string())
+    };
+    match canfield_move {
+        CanfieldMove::Draw if game.stock_len() > 0 => {
+            format!("Draw from the stock ({} left).", game.stock_len())
+        }
+        CanfieldMove::Draw => "Recycle the waste.".to_string(),
+        CanfieldMove::WasteToFoundation => format!(
+            "Move {} from the waste to a foundation.",
+            label(game.waste_top())
+        ),
+        CanfieldMove::WasteToTableau { dst } => format!(
+            "Move {} from the waste to T{}.",
+            label(game.waste_top()),
+            dst + 1
+        ),
+        CanfieldMove::ReserveToFoundation => format!(
+            "Move {} from the reserve to a foundation.",
+            label(game.reserve_top())
+        ),
+        CanfieldMove::ReserveToTableau { dst } => format!(
+            "Move {} from the reserve to T{}.",
+            label(game.reserve_top()),
+            dst + 1
+        ),
+        CanfieldMove::TableauTopToFoundation { src } => format!(
+            "Move {} from T{} to a foundation.",
+            label(game.tableau_top(src)),
+            src + 1
+        ),
+        CanfieldMove::TableauRunToTableau { src, start, dst } => {
+            let amount = game.tableau()[src].len().saturating_sub(start);
+            let card = label(game.tableau_card(src, start));
+            if amount > 1 {
+                format!(
+                    "Move {amount} cards from {card} on T{} to T{}.",
+                    src + 1,
+                    dst + 1
+                )
+            } else {
+                format!("Move {card} from T{} to T{}.", src + 1, dst + 1)
+            }
+        }
+    }
+}
+
+/// Suggests the first move of a solver line, falling back to the solver's
+/// move ordering when the search finds no win within `max_states`.
+pub fn suggest_move(game: &CanfieldGame, max_states: usize) -> CanfieldHintSuggestion {
+    if game.is_won() {
+        return CanfieldHintSuggestion {
+            message: "Hint: game already won.".to_string(),
+            source: None,
+            target: None,
+            canfield_move: None,
+        };
+    }
+    let result = game.solve(max_states);
+    suggestion_from_solve(game, &result)
+}
+
+pub fn suggestion_from_solve(
+    game: &CanfieldGame,
+    result: &CanfieldSolveResult,
+) -> CanfieldHintSuggestion {
+    let (canfield_move, prefix) = match result.line.first() {
+        Some(first) => (Some(*first), "Hint: winning line found. "),
+        None if result.hit_state_limit => (
+            game.solver_moves().first().copied(),
+            "Hint: no win found yet. ",
+        ),
+        None => (
+            game.solver_moves().first().copied(),
+            "Hint: this deal can no longer be won. ",
+        ),
+    };
+    let Some(canfield_move) = canfield_move else {
+        return CanfieldHintSuggestion {
+            message: "Hint: no legal moves. Start a new deal or undo.".to_string(),
+            source: None,
+            target: None,
+            canfield_move: None,
+        };
+    };
+    let (source, target) = hint_nodes_for_move(game, canfield_move);
+    CanfieldHintSuggestion {
+        message: format!("{prefix}{}", describe_move(game, canfield_move)),
+        source: Some(source),
+        target: Some(target),
+        canfield_move: Some(canfield_move),
+    }
+}
@@ -92,6 +92,20 @@ fn count_safe_major_escapes(position: &ChessPosition, from: Square, piece: Chess
     }
 }
 
+/// Non-king pieces of `color` that the opponent attacks and nothing defends.
+pub fn hanging_pieces(position: &ChessPosition, color: ChessColor) -> Vec<Square> {
+    (0_u8..64)
+        .filter(|sq| {
+            position.piece_at(*sq).is_some_and(|piece| {
+                piece.color == color
+                    && piece.kind != ChessPieceKind::King
+                    && square_attacked_by(position, *sq, color.opposite())
+                    && !square_attacked_by(position, *sq, color)
+            })
+        })
+        .collect()
+}
+
 pub fn white_minus_black(position: &ChessPosition) -> i32 {
     let mut white_penalty = 0_i32;
     let mut black_penalty = 0_i32;
@@ -4,6 +4,7 @@ pub mod search;
 pub mod worker;
 
 pub use api::{AiConfig, SearchLimits, SearchResult};
+pub use search::tt::SharedTranspositionTable;
 pub use worker::AsyncSearch;
 
 use crate::game::ChessPosition;
@@ -20,6 +21,18 @@ pub fn spawn_search(
     position: ChessPosition,
     limits: SearchLimits,
     config: AiConfig,
+    table: SharedTranspositionTable,
 ) -> AsyncSearch {
-    worker::spawn_search(position, limits, config)
+    worker::spawn_search(position, limits, config, table)
+}
+
+/// Searches the position the opponent is expected to reach while they think.
+/// Call [`AsyncSearch::ponder_hit`] if they play the expected move.
+pub fn spawn_ponder_search(
+    position: ChessPosition,
+    limits: SearchLimits,
+    config: AiConfig,
+    table: SharedTranspositionTable,
+) -> AsyncSearch {
+    worker::spawn_ponder_search(position, limits, config, table)
 }
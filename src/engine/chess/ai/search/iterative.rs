@@ -1,3 +1,4 @@
+use super::tt::TranspositionTable;
 use super::{alphabeta, move_order, no_legal_move_score, SearchContext};
 use crate::engine::chess::ai::api::{AiConfig, SearchLimits, SearchResult, SearchTermination};
 use crate::engine::chess::ai::eval;
@@ -10,10 +11,25 @@ pub fn search(
     config: AiConfig,
     canceled: Option<&AtomicBool>,
 ) -> SearchResult {
-    let mut ctx = SearchContext::new(limits, config, canceled);
+    search_with_table(position, limits, config, canceled, None, None).0
+}
+
+/// Runs a search that reuses `table` when given and hands it back afterwards.
+///
+/// While `pondering` is set the time budget is suspended; clearing it turns the
+/// search into a regular one whose budget counts from that moment.
+pub fn search_with_table(
+    position: &ChessPosition,
+    limits: SearchLimits,
+    config: AiConfig,
+    canceled: Option<&AtomicBool>,
+    pondering: Option<&AtomicBool>,
+    table: Option<TranspositionTable>,
+) -> (SearchResult, Option<TranspositionTable>) {
+    let mut ctx = SearchContext::new(limits, config, canceled, pondering, table);
     let legal = legal_moves(position);
     if legal.is_empty() {
-        return SearchResult {
+        let result = SearchResult {
             best_move: None,
             best_score_cp: no_legal_move_score(position, 0),
             depth_reached: 0,
@@ -21,6 +37,7 @@ pub fn search(
             pv: Vec::new(),
             termination: SearchTermination::Completed,
         };
+        return (result, ctx.into_table());
     }
 
     let mut best_move: Option<ChessMove> = legal.first().copied();
@@ -47,21 +64,33 @@ pub fn search(
     }
 
     let termination = ctx.stop_reason.unwrap_or(SearchTermination::Completed);
-    SearchResult {
+    let pv = match (best_move, ctx.tt()) {
+        (Some(mv), Some(tt)) => {
+            tt.principal_variation(position, mv, usize::from(depth_reached.max(1)))
+        }
+        _ => best_move.into_iter().collect(),
+    };
+    let result = SearchResult {
         best_move,
         best_score_cp: best_score,
         depth_reached,
         nodes: ctx.nodes,
-        pv: best_move.into_iter().collect(),
+        pv,
         termination,
-    }
+    };
+    (result, ctx.into_table())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::search;
+    use super::super::SearchContext;
+    use super::{search, search_with_table};
     use crate::engine::chess::ai::api::{AiConfig, SearchLimits, SearchTermination};
-    use crate::game::standard_position;
+    use crate::engine::chess::ai::SharedTranspositionTable;
+    use crate::game::{apply_move, standard_position};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+    use std::time::Duration;
 
     #[test]
     fn incomplete_iteration_does_not_replace_last_complete_depth() {
@@ -79,4 +108,121 @@ mod tests {
             "fallback move should still exist"
         );
     }
+
+    #[test]
+    fn principal_variation_is_a_legal_line_from_the_root() {
+        let position = standard_position();
+        let result = search(
+            &position,
+            SearchLimits::new(3, 0, 0),
+            AiConfig::default(),
+            None,
+        );
+        assert_eq!(result.pv.first().copied(), result.best_move);
+        assert!(result.pv.len() >= 2, "expected reply should be in the pv");
+        let mut cursor = position.clone();
+        for mv in &result.pv {
+            assert!(apply_move(&mut cursor, *mv));
+        }
+    }
+
+    #[test]
+    fn returned_table_keeps_entries_for_the_next_search() {
+        let position = standard_position();
+        let limits = SearchLimits::new(2, 0, 0);
+        let (_, table) =
+            search_with_table(&position, limits, AiConfig::default(), None, None, None);
+        let table = table.expect("tt enabled by default");
+        assert!(!table.is_empty());
+        let warm_entries = table.len();
+        let (_, table) = search_with_table(
+            &position,
+            limits,
+            AiConfig::default(),
+            None,
+            None,
+            Some(table),
+        );
+        assert!(table.expect("tt enabled by default").len() >= warm_entries);
+    }
+
+    #[test]
+    fn cleared_shared_table_drops_searches_started_before() {
+        let position = standard_position();
+        let limits = SearchLimits::new(2, 0, 0);
+        let shared = SharedTranspositionTable::default();
+        let (_, table) = search_with_table(
+            &position,
+            limits,
+            AiConfig::default(),
+            None,
+            None,
+            Some(shared.checkout(AiConfig::default().transposition_capacity)),
+        );
+        shared.checkin(table.expect("tt enabled by default"));
+        assert!(!shared
+            .checkout(AiConfig::default().transposition_capacity)
+            .is_empty());
+
+        let stale = shared.checkout(AiConfig::default().transposition_capacity);
+        shared.clear();
+        let (_, table) = search_with_table(
+            &position,
+            limits,
+            AiConfig::default(),
+            None,
+            None,
+            Some(stale),
+        );
+        shared.checkin(table.expect("tt enabled by default"));
+        assert!(shared
+            .checkout(AiConfig::default().transposition_capacity)
+            .is_empty());
+    }
+
+    #[test]
+    fn pondering_suspends_the_time_budget() {
+        let position = standard_position();
+        let pondering = AtomicBool::new(true);
+        let (result, _) = search_with_table(
+            &position,
+            SearchLimits::new(2, 1, 0),
+            AiConfig::default(),
+            None,
+            Some(&pondering),
+            None,
+        );
+        assert_eq!(result.termination, SearchTermination::Completed);
+        assert_eq!(result.depth_reached, 2);
+
+        pondering.store(false, Ordering::Relaxed);
+        let (result, _) = search_with_table(
+            &position,
+            SearchLimits::new(8, 1, 0),
+            AiConfig::default(),
+            None,
+            Some(&pondering),
+            None,
+        );
+        assert_eq!(result.termination, SearchTermination::TimeBudget);
+    }
+
+    #[test]
+    fn ponder_hit_starts_the_time_budget() {
+        let pondering = AtomicBool::new(true);
+        let mut ctx = SearchContext::new(
+            SearchLimits::new(8, 40, 0),
+            AiConfig::default(),
+            None,
+            Some(&pondering),
+            None,
+        );
+        thread::sleep(Duration::from_millis(60));
+        assert!(!ctx.should_abort(), "pondering ignores the clock");
+        pondering.store(false, Ordering::Relaxed);
+        assert!(!ctx.should_abort(), "a long ponder leaves the full budget");
+        thread::sleep(Duration::from_millis(60));
+        assert!(ctx.should_abort());
+        assert_eq!(ctx.stop_reason, Some(SearchTermination::TimeBudget));
+    }
 }
@@ -17,6 +17,8 @@ pub(crate) struct SearchContext<'a> {
     pub(crate) limits: SearchLimits,
     pub(crate) config: AiConfig,
     pub(crate) canceled: Option<&'a AtomicBool>,
+    pub(crate) pondering: Option<&'a AtomicBool>,
+    was_pondering: bool,
     pub(crate) nodes: u64,
     pub(crate) stop_reason: Option<SearchTermination>,
     tt: Option<tt::TranspositionTable>,
@@ -27,12 +29,14 @@ impl<'a> SearchContext<'a> {
         limits: SearchLimits,
         config: AiConfig,
         canceled: Option<&'a AtomicBool>,
+        pondering: Option<&'a AtomicBool>,
+        table: Option<tt::TranspositionTable>,
     ) -> Self {
         let limits = sanitize_limits(limits);
         let tt = if config.enable_transposition_table {
-            Some(tt::TranspositionTable::new(
-                config.transposition_capacity.max(1_024),
-            ))
+            Some(table.unwrap_or_else(|| {
+                tt::TranspositionTable::new(config.transposition_capacity.max(1_024))
+            }))
         } else {
             None
         };
@@ -41,6 +45,8 @@ impl<'a> SearchContext<'a> {
             limits,
             config,
             canceled,
+            pondering,
+            was_pondering: pondering.is_some_and(|pondering| pondering.load(Ordering::Relaxed)),
             nodes: 0,
             stop_reason: None,
             tt,
@@ -62,7 +68,15 @@ impl<'a> SearchContext<'a> {
                 return true;
             }
         }
+        // While pondering the clock is not ours; the budget starts counting
+        // when the expected move is confirmed.
+        let pondering = self.is_pondering();
+        if self.was_pondering && !pondering {
+            self.started = Instant::now();
+        }
+        self.was_pondering = pondering;
         if self.limits.time_budget_ms > 0
+            && !pondering
             && self.started.elapsed().as_millis() >= u128::from(self.limits.time_budget_ms)
         {
             self.stop_reason = Some(SearchTermination::TimeBudget);
@@ -75,9 +89,22 @@ impl<'a> SearchContext<'a> {
         false
     }
 
+    pub(crate) fn is_pondering(&self) -> bool {
+        self.pondering
+            .is_some_and(|pondering| pondering.load(Ordering::Relaxed))
+    }
+
     pub(crate) fn tt_mut(&mut self) -> Option<&mut tt::TranspositionTable> {
         self.tt.as_mut()
     }
+
+    pub(crate) fn tt(&self) -> Option<&tt::TranspositionTable> {
+        self.tt.as_ref()
+    }
+
+    pub(crate) fn into_table(self) -> Option<tt::TranspositionTable> {
+        self.tt
+    }
 }
 
 pub(crate) fn no_legal_move_score(position: &ChessPosition, ply: u8) -> i32 {
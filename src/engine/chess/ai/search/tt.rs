@@ -1,5 +1,6 @@
-use crate::game::{encode_fen, ChessMove, ChessPosition};
+use crate::game::{apply_move, encode_fen, legal_moves, ChessMove, ChessPosition};
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Bound {
@@ -19,6 +20,8 @@ pub struct Entry {
 pub struct TranspositionTable {
     entries: HashMap<String, Entry>,
     capacity: usize,
+    /// The shared table's generation when this table was checked out.
+    generation: u64,
 }
 
 impl TranspositionTable {
@@ -26,6 +29,7 @@ impl TranspositionTable {
         Self {
             entries: HashMap::new(),
             capacity: capacity.max(1024),
+            generation: 0,
         }
     }
 
@@ -39,4 +43,90 @@ impl TranspositionTable {
         }
         let _ = self.entries.insert(encode_fen(position), entry);
     }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Follows stored best moves from `root_move` to rebuild the principal variation.
+    pub fn principal_variation(
+        &self,
+        position: &ChessPosition,
+        root_move: ChessMove,
+        max_len: usize,
+    ) -> Vec<ChessMove> {
+        let mut pv = vec![root_move];
+        let mut cursor = position.clone();
+        if !apply_move(&mut cursor, root_move) {
+            return pv;
+        }
+        while pv.len() < max_len {
+            let Some(mv) = self.probe(&cursor).and_then(|entry| entry.best_move) else {
+                break;
+            };
+            if !legal_moves(&cursor).contains(&mv) || !apply_move(&mut cursor, mv) {
+                break;
+            }
+            pv.push(mv);
+        }
+        pv
+    }
+}
+
+/// Transposition table kept alive between searches so consecutive moves reuse
+/// earlier work. A search checks the table out and returns it when finished.
+#[derive(Clone, Default)]
+pub struct SharedTranspositionTable {
+    slot: Arc<Mutex<SharedSlot>>,
+}
+
+#[derive(Default)]
+struct SharedSlot {
+    generation: u64,
+    table: Option<TranspositionTable>,
+}
+
+impl std::fmt::Debug for SharedTranspositionTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedTranspositionTable")
+            .finish_non_exhaustive()
+    }
+}
+
+impl SharedTranspositionTable {
+    pub fn checkout(&self, capacity: usize) -> TranspositionTable {
+        let Ok(mut slot) = self.slot.lock() else {
+            return TranspositionTable::new(capacity);
+        };
+        let mut table = slot
+            .table
+            .take()
+            .filter(|table| table.capacity == capacity.max(1024))
+            .unwrap_or_else(|| TranspositionTable::new(capacity));
+        table.generation = slot.generation;
+        table
+    }
+
+    /// Returns a table after a search. Tables checked out before the last
+    /// [`SharedTranspositionTable::clear`] are dropped.
+    pub fn checkin(&self, table: TranspositionTable) {
+        if let Ok(mut slot) = self.slot.lock() {
+            if table.generation == slot.generation {
+                slot.table = Some(table);
+            }
+        }
+    }
+
+    /// Forgets everything searched so far, for a new game, variant or
+    /// loaded position.
+    pub fn clear(&self) {
+        if let Ok(mut slot) = self.slot.lock() {
+            slot.generation = slot.generation.wrapping_add(1);
+            slot.table = None;
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use super::api::{AiConfig, SearchLimits, SearchResult};
 use super::search;
+use super::search::tt::SharedTranspositionTable;
 use crate::game::ChessPosition;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{mpsc, Arc};
@@ -8,6 +9,7 @@ use std::thread::JoinHandle;
 #[derive(Debug)]
 pub struct AsyncSearch {
     cancel: Arc<AtomicBool>,
+    pondering: Arc<AtomicBool>,
     rx: mpsc::Receiver<SearchResult>,
     join: Option<JoinHandle<()>>,
 }
@@ -17,6 +19,16 @@ impl AsyncSearch {
         self.cancel.store(true, Ordering::Relaxed);
     }
 
+    pub fn is_pondering(&self) -> bool {
+        self.pondering.load(Ordering::Relaxed)
+    }
+
+    /// The expected move was played: keep the work done so far and let the
+    /// time budget apply again.
+    pub fn ponder_hit(&self) {
+        self.pondering.store(false, Ordering::Relaxed);
+    }
+
     pub fn try_recv(&self) -> Option<SearchResult> {
         self.rx.try_recv().ok()
     }
@@ -34,17 +46,52 @@ pub fn spawn_search(
     position: ChessPosition,
     limits: SearchLimits,
     config: AiConfig,
+    table: SharedTranspositionTable,
+) -> AsyncSearch {
+    spawn(position, limits, config, table, false)
+}
+
+pub fn spawn_ponder_search(
+    position: ChessPosition,
+    limits: SearchLimits,
+    config: AiConfig,
+    table: SharedTranspositionTable,
+) -> AsyncSearch {
+    spawn(position, limits, config, table, true)
+}
+
+fn spawn(
+    position: ChessPosition,
+    limits: SearchLimits,
+    config: AiConfig,
+    table: SharedTranspositionTable,
+    pondering: bool,
 ) -> AsyncSearch {
     let cancel = Arc::new(AtomicBool::new(false));
     let cancel_for_thread = Arc::clone(&cancel);
+    let pondering = Arc::new(AtomicBool::new(pondering));
+    let pondering_for_thread = Arc::clone(&pondering);
     let (tx, rx) = mpsc::channel::<SearchResult>();
     let join = std::thread::spawn(move || {
-        let result =
-            search::iterative::search(&position, limits, config, Some(cancel_for_thread.as_ref()));
+        let checked_out = config
+            .enable_transposition_table
+            .then(|| table.checkout(config.transposition_capacity));
+        let (result, returned) = search::iterative::search_with_table(
+            &position,
+            limits,
+            config,
+            Some(cancel_for_thread.as_ref()),
+            Some(pondering_for_thread.as_ref()),
+            checked_out,
+        );
+        if let Some(returned) = returned {
+            table.checkin(returned);
+        }
         let _ = tx.send(result);
     });
     AsyncSearch {
         cancel,
+        pondering,
         rx,
         join: Some(join),
     }
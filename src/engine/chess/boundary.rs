@@ -1,7 +1,7 @@
 use super::commands::{ChessCommand, ChessCommandResult, ChessStatus};
 use crate::game::{
-    apply_move, atomic_position, chess960_position, legal_moves, standard_position, ChessColor,
-    ChessPosition,
+    apply_move, atomic_position, chess960_position, chess960_position_from_scharnagl,
+    double_chess960_position, legal_moves, standard_position, ChessColor, ChessPosition,
 };
 
 pub fn execute(position: &mut ChessPosition, command: ChessCommand) -> ChessCommandResult {
@@ -10,12 +10,20 @@ pub fn execute(position: &mut ChessPosition, command: ChessCommand) -> ChessComm
             *position = match variant {
                 crate::game::ChessVariant::Standard => standard_position(),
                 crate::game::ChessVariant::Chess960 => chess960_position(seed),
+                crate::game::ChessVariant::DoubleChess960 => double_chess960_position(seed),
                 crate::game::ChessVariant::Atomic => atomic_position(),
             };
             // New games always start with White to move.
             position.set_side_to_move(ChessColor::White);
             ChessCommandResult::changed(ChessStatus::Ready)
         }
+        ChessCommand::NewChess960 { scharnagl } => {
+            let Some(next) = chess960_position_from_scharnagl(scharnagl) else {
+                return ChessCommandResult::unchanged(ChessStatus::Unsupported);
+            };
+            *position = next;
+            ChessCommandResult::changed(ChessStatus::Ready)
+        }
         ChessCommand::TryMove(chess_move) => {
             if legal_moves(position).contains(&chess_move) && apply_move(position, chess_move) {
                 ChessCommandResult::changed(ChessStatus::Ready)
@@ -3,6 +3,7 @@ use crate::game::{ChessMove, ChessVariant};
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChessCommand {
     NewGame { seed: u64, variant: ChessVariant },
+    NewChess960 { scharnagl: u16 },
     TryMove(ChessMove),
 }
 
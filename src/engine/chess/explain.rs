@@ -0,0 +1,267 @@
+use super::ai::eval::{hanging, king_safety, material};
+use crate::game::{
+    apply_move, is_in_check, legal_moves, move_to_san, square_name, terminal_state, ChessColor,
+    ChessMove, ChessPieceKind, ChessPosition, ChessTerminalState, Square,
+};
+
+/// Plies of the principal variation used to judge the material outcome.
+const EXPLAIN_LINE_PLIES: usize = 4;
+/// Material swings smaller than this (centipawns) are positional noise.
+const MATERIAL_SWING_THRESHOLD_CP: i32 = 80;
+
+const PIECE_KINDS: [ChessPieceKind; 5] = [
+    ChessPieceKind::Queen,
+    ChessPieceKind::Rook,
+    ChessPieceKind::Bishop,
+    ChessPieceKind::Knight,
+    ChessPieceKind::Pawn,
+];
+
+/// Explains a suggested move in one sentence, e.g.
+/// "Rxe7 wins a pawn and threatens mate on g7.". `line` is the engine's
+/// principal variation; it is only trusted when it starts with `chess_move`.
+pub fn explain_move(
+    position: &ChessPosition,
+    chess_move: ChessMove,
+    line: &[ChessMove],
+) -> Option<String> {
+    let san = move_to_san(position, chess_move)?;
+    let mover = position.side_to_move();
+    let mut after = position.clone();
+    if !apply_move(&mut after, chess_move) {
+        return None;
+    }
+    let line = if line.first() == Some(&chess_move) {
+        line
+    } else {
+        std::slice::from_ref(&chess_move)
+    };
+    let (line_positions, mate_in) = play_line(position, line, mover);
+
+    let mut clauses = Vec::new();
+    match mate_in {
+        Some(1) => clauses.push("delivers checkmate".to_string()),
+        Some(moves) => clauses.push(format!("forces mate in {moves}")),
+        None => {}
+    }
+
+    if mate_in.is_none() {
+        let settled_index = line_positions
+            .len()
+            .min(EXPLAIN_LINE_PLIES)
+            .saturating_sub(1);
+        if let Some(settled) = line_positions.get(settled_index) {
+            if let Some(clause) = material_clause(position, settled, mover) {
+                clauses.push(clause);
+            }
+        }
+    }
+
+    let king_safety_before = for_side(king_safety::white_minus_black(position), mover);
+    let king_safety_after = for_side(king_safety::white_minus_black(&after), mover);
+    if king_safety_after > king_safety_before {
+        if is_in_check(position, mover) {
+            clauses.push("escapes check".to_string());
+        }
+        if mate_in.is_none() && is_in_check(&after, mover.opposite()) {
+            clauses.push("gives check".to_string());
+        }
+    }
+
+    let hanging_before = major_or_minor(position, hanging::hanging_pieces(position, mover));
+    let hanging_after = major_or_minor(&after, hanging::hanging_pieces(&after, mover));
+    for sq in &hanging_before {
+        let now_at = if *sq == chess_move.from {
+            chess_move.to
+        } else {
+            *sq
+        };
+        if after.piece_at(now_at).is_some() && !hanging_after.contains(&now_at) {
+            clauses.push(format!(
+                "saves the hanging {}",
+                piece_on_square(position, *sq)
+            ));
+            break;
+        }
+    }
+
+    let enemy_before = major_or_minor(
+        position,
+        hanging::hanging_pieces(position, mover.opposite()),
+    );
+    let enemy_after = major_or_minor(&after, hanging::hanging_pieces(&after, mover.opposite()));
+    if let Some(sq) = enemy_after.iter().find(|sq| !enemy_before.contains(sq)) {
+        clauses.push(format!(
+            "attacks the undefended {}",
+            piece_on_square(&after, *sq)
+        ));
+    }
+
+    if mate_in.is_none() {
+        if let Some(mate_square) = mate_threat_square(&after, mover) {
+            clauses.push(format!("threatens mate on {}", square_name(mate_square)));
+        }
+    }
+
+    let newly_hanging = hanging_after
+        .iter()
+        .filter(|sq| **sq != chess_move.to && !hanging_before.contains(sq))
+        .copied()
+        .collect::<Vec<_>>();
+    if let Some(sq) = newly_hanging.first() {
+        clauses.push(format!(
+            "leaves the {} undefended",
+            piece_on_square(&after, *sq)
+        ));
+    }
+
+    if clauses.is_empty() {
+        return Some(format!("{san} improves the position."));
+    }
+    Some(format!("{san} {}.", join_clauses(&clauses)))
+}
+
+/// Plays `line` from `position`, stopping at the first illegal move or game end.
+/// Returns the positions after each ply and, if the mover mates along the way,
+/// the mate distance in the mover's moves.
+fn play_line(
+    position: &ChessPosition,
+    line: &[ChessMove],
+    mover: ChessColor,
+) -> (Vec<ChessPosition>, Option<usize>) {
+    let mut positions = Vec::with_capacity(line.len());
+    let mut current = position.clone();
+    for (ply, chess_move) in line.iter().enumerate() {
+        if !legal_moves(&current).contains(chess_move) || !apply_move(&mut current, *chess_move) {
+            break;
+        }
+        positions.push(current.clone());
+        match terminal_state(&current) {
+            Some(ChessTerminalState::Checkmate { winner }) if winner == mover => {
+                return (positions, Some(ply / 2 + 1));
+            }
+            Some(_) => break,
+            None => {}
+        }
+    }
+    (positions, None)
+}
+
+fn material_clause(
+    before: &ChessPosition,
+    settled: &ChessPosition,
+    mover: ChessColor,
+) -> Option<String> {
+    let swing = for_side(
+        material::white_minus_black(settled) - material::white_minus_black(before),
+        mover,
+    );
+    if swing.abs() < MATERIAL_SWING_THRESHOLD_CP {
+        return None;
+    }
+    // Net pieces the opponent lost minus pieces the mover lost, per kind.
+    let mut net = [0_i32; PIECE_KINDS.len()];
+    for (idx, kind) in PIECE_KINDS.iter().enumerate() {
+        let opponent_lost = piece_count(before, mover.opposite(), *kind)
+            - piece_count(settled, mover.opposite(), *kind);
+        let mover_lost = piece_count(before, mover, *kind) - piece_count(settled, mover, *kind);
+        net[idx] = opponent_lost - mover_lost;
+    }
+    if swing < 0 {
+        net.iter_mut().for_each(|count| *count = -*count);
+    }
+    let gain = material_gain_label(&net);
+    Some(if swing > 0 {
+        format!("wins {gain}")
+    } else {
+        format!("sacrifices {gain}")
+    })
+}
+
+fn material_gain_label(net: &[i32; PIECE_KINDS.len()]) -> String {
+    let [queens, rooks, bishops, knights, pawns] = *net;
+    let minors = bishops + knights;
+    if queens > 0 {
+        "the queen".to_string()
+    } else if rooks > 0 && minors < 0 {
+        "the exchange".to_string()
+    } else if rooks > 0 {
+        "a rook".to_string()
+    } else if minors > 0 {
+        "a piece".to_string()
+    } else if pawns > 1 {
+        format!("{pawns} pawns")
+    } else if pawns == 1 {
+        "a pawn".to_string()
+    } else {
+        "material".to_string()
+    }
+}
+
+/// Square the mover could mate on if the opponent passed.
+fn mate_threat_square(after: &ChessPosition, mover: ChessColor) -> Option<Square> {
+    if is_in_check(after, mover.opposite()) {
+        return None;
+    }
+    let mut passed = after.clone();
+    passed.set_side_to_move(mover);
+    passed.set_en_passant(None);
+    legal_moves(&passed).into_iter().find_map(|threat| {
+        let mut next = passed.clone();
+        (apply_move(&mut next, threat)
+            && matches!(
+                terminal_state(&next),
+                Some(ChessTerminalState::Checkmate { winner }) if winner == mover
+            ))
+        .then_some(threat.to)
+    })
+}
+
+fn major_or_minor(position: &ChessPosition, squares: Vec<Square>) -> Vec<Square> {
+    squares
+        .into_iter()
+        .filter(|sq| {
+            position
+                .piece_at(*sq)
+                .is_some_and(|piece| piece.kind != ChessPieceKind::Pawn)
+        })
+        .collect()
+}
+
+fn piece_count(position: &ChessPosition, color: ChessColor, kind: ChessPieceKind) -> i32 {
+    (0_u8..64)
+        .filter(|sq| {
+            position
+                .piece_at(*sq)
+                .is_some_and(|piece| piece.color == color && piece.kind == kind)
+        })
+        .count() as i32
+}
+
+fn piece_on_square(position: &ChessPosition, sq: Square) -> String {
+    let name = match position.piece_at(sq).map(|piece| piece.kind) {
+        Some(ChessPieceKind::Queen) => "queen",
+        Some(ChessPieceKind::Rook) => "rook",
+        Some(ChessPieceKind::Bishop) => "bishop",
+        Some(ChessPieceKind::Knight) => "knight",
+        Some(ChessPieceKind::Pawn) => "pawn",
+        Some(ChessPieceKind::King) => "king",
+        None => "piece",
+    };
+    format!("{name} on {}", square_name(sq))
+}
+
+fn for_side(white_minus_black: i32, color: ChessColor) -> i32 {
+    match color {
+        ChessColor::White => white_minus_black,
+        ChessColor::Black => -white_minus_black,
+    }
+}
+
+fn join_clauses(clauses: &[String]) -> String {
+    match clauses {
+        [] => String::new(),
+        [only] => only.clone(),
+        [head @ .., last] => format!("{} and {last}", head.join(", ")),
+    }
+}
@@ -1,6 +1,24 @@
 use super::ai::{self, AiConfig, SearchLimits};
+use super::explain::explain_move;
 use crate::game::{ChessMove, ChessPosition};
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplainedHint {
+    pub chess_move: ChessMove,
+    pub explanation: String,
+}
+
 pub fn best_move_hint(position: &ChessPosition) -> Option<ChessMove> {
     ai::search_best_move(position, SearchLimits::hint(), AiConfig::default()).best_move
 }
+
+/// Like [`best_move_hint`], with a one-sentence reason built from the search line.
+pub fn explained_move_hint(position: &ChessPosition) -> Option<ExplainedHint> {
+    let result = ai::search_best_move(position, SearchLimits::hint(), AiConfig::default());
+    let chess_move = result.best_move?;
+    let explanation = explain_move(position, chess_move, &result.pv)?;
+    Some(ExplainedHint {
+        chess_move,
+        explanation,
+    })
+}
@@ -3,6 +3,7 @@
 pub mod ai;
 pub mod boundary;
 pub mod commands;
+pub mod explain;
 pub mod hint;
 pub mod robot;
 pub mod session;
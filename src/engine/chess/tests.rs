@@ -1,6 +1,7 @@
 use super::boundary::execute;
 use super::commands::{ChessCommand, ChessStatus};
-use super::hint::best_move_hint;
+use super::explain::explain_move;
+use super::hint::{best_move_hint, explained_move_hint};
 use super::robot::pick_robot_move;
 use crate::game::{parse_square, ChessColor, ChessMove, ChessPosition, ChessVariant};
 
@@ -40,6 +41,39 @@ fn new_game_chess960_initializes_ready_position_with_white_to_move() {
     assert_eq!(position.piece_count(crate::game::ChessColor::Black), 16);
 }
 
+#[test]
+fn new_chess960_by_scharnagl_number_sets_requested_back_rank() {
+    let mut position = ChessPosition::empty(ChessVariant::Standard);
+    let result = execute(&mut position, ChessCommand::NewChess960 { scharnagl: 518 });
+    assert!(result.changed);
+    assert_eq!(position.variant(), ChessVariant::Chess960);
+    assert_eq!(
+        *position.back_rank(ChessColor::White),
+        crate::game::STANDARD_BACK_RANK
+    );
+
+    let result = execute(&mut position, ChessCommand::NewChess960 { scharnagl: 960 });
+    assert!(!result.changed);
+    assert_eq!(result.status, ChessStatus::Unsupported);
+}
+
+#[test]
+fn new_game_double_chess960_keeps_seeded_back_ranks() {
+    let mut position = ChessPosition::empty(ChessVariant::Standard);
+    let result = execute(
+        &mut position,
+        ChessCommand::NewGame {
+            seed: 11,
+            variant: ChessVariant::DoubleChess960,
+        },
+    );
+    assert!(result.changed);
+    assert_eq!(result.status, ChessStatus::Ready);
+    assert_eq!(position.variant(), ChessVariant::DoubleChess960);
+    assert_eq!(position.side_to_move(), ChessColor::White);
+    assert_eq!(position, crate::game::double_chess960_position(11));
+}
+
 #[test]
 fn new_game_atomic_initializes_ready_position_with_white_to_move() {
     let mut position = ChessPosition::empty(ChessVariant::Atomic);
@@ -115,6 +149,42 @@ fn hint_move_is_legal_when_available() {
     );
 }
 
+#[test]
+fn explanation_names_material_gain_and_mate_threat() {
+    let position = crate::game::decode_fen(
+        "6k1/4pp1p/6pQ/8/8/8/1B6/4R1K1 w - - 0 1",
+        ChessVariant::Standard,
+    )
+    .expect("valid FEN");
+    let rook_takes = ChessMove::new(sq("e1"), sq("e7"));
+    assert_eq!(
+        explain_move(&position, rook_takes, &[rook_takes]).as_deref(),
+        Some("Rxe7 wins a pawn and threatens mate on g7.")
+    );
+}
+
+#[test]
+fn explanation_reports_saved_hanging_piece() {
+    let position =
+        crate::game::decode_fen("4k3/8/8/8/3N4/8/8/K5b1 w - - 0 1", ChessVariant::Standard)
+            .expect("valid FEN");
+    let retreat = ChessMove::new(sq("d4"), sq("b5"));
+    assert_eq!(
+        explain_move(&position, retreat, &[]).as_deref(),
+        Some("Nb5 saves the hanging knight on d4.")
+    );
+}
+
+#[test]
+fn explained_hint_calls_out_mate_in_one() {
+    let position =
+        crate::game::decode_fen("6k1/5ppp/8/8/8/8/8/4R1K1 w - - 0 1", ChessVariant::Standard)
+            .expect("valid FEN");
+    let hint = explained_move_hint(&position).expect("hint available");
+    assert_eq!(hint.chess_move, ChessMove::new(sq("e1"), sq("e8")));
+    assert_eq!(hint.explanation, "Re8# delivers checkmate.");
+}
+
 #[test]
 fn robot_move_is_legal_when_available() {
     let mut position = ChessPosition::empty(ChessVariant::Standard);
@@ -1,4 +1,4 @@
-use crate::game::{DrawMode, DrawResult};
+use crate::game::{DrawMode, DrawResult, PyramidCardRef};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EngineCommand {
@@ -33,6 +33,13 @@ pub enum EngineCommand {
         foundation_idx: usize,
         dst: usize,
     },
+    RemovePyramidCards {
+        first: PyramidCardRef,
+        second: Option<PyramidCardRef>,
+    },
+    PlayToWaste {
+        slot: usize,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
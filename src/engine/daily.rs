@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use crate::engine::stats::StatsKey;
+use crate::game::GameMode;
+
+/// How many date-derived seeds are tried, in order, when looking for a
+/// winnable daily deal.
+pub const DAILY_CANDIDATES: u32 = 8;
+
+const FNV1A64_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV1A64_PRIME: u64 = 0x00000100000001b3;
+
+/// A calendar day in the local time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ChallengeDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl ChallengeDate {
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// `YYYY-MM-DD`.
+    pub fn key(self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        let mut parts = raw.trim().splitn(3, '-');
+        let year = parts.next()?.parse::<i32>().ok()?;
+        let month = parts.next()?.parse::<u8>().ok()?;
+        let day = parts.next()?.parse::<u8>().ok()?;
+        Self::new(year, month, day)
+    }
+}
+
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// The seed tried on attempt `attempt` for this date and rule profile. Only
+/// the date, the variant id and its [`rules_id`](crate::engine::stats::rules_id)
+/// go into the hash, so every install derives the same deals without a
+/// server.
+pub fn candidate_seed(date: ChallengeDate, mode: GameMode, rules_id: &str, attempt: u32) -> u64 {
+    let input = format!("{}|{}|{}|{}", date.key(), mode.id(), rules_id, attempt);
+    let mut hash = FNV1A64_OFFSET_BASIS;
+    for byte in input.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV1A64_PRIME);
+    }
+    // splitmix64 finalizer: spreads nearby dates across the whole seed range.
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d049bb133111eb);
+    hash ^ (hash >> 31)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DailyOutcome {
+    Attempted,
+    Won,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyEntry {
+    pub seed: u64,
+    pub outcome: DailyOutcome,
+}
+
+/// One attempt per day and rule profile, plus whether it was won.
+#[derive(Debug, Clone, Default)]
+pub struct DailyChallengeStore {
+    entries: HashMap<(ChallengeDate, StatsKey), DailyEntry>,
+}
+
+impl DailyChallengeStore {
+    pub fn load_from_string(contents: &str) -> Self {
+        let mut store = Self::default();
+        for line in contents.lines() {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [date, mode, rules, seed, outcome] = fields[..] else {
+                continue;
+            };
+            let (Some(date), Some(mode), Ok(seed)) = (
+                ChallengeDate::parse(date),
+                GameMode::from_id(mode),
+                seed.parse::<u64>(),
+            ) else {
+                continue;
+            };
+            let outcome = match outcome {
+                "won" => DailyOutcome::Won,
+                "attempted" => DailyOutcome::Attempted,
+                _ => continue,
+            };
+            store.entries.insert(
+                (date, StatsKey::from_stored(mode, rules)),
+                DailyEntry { seed, outcome },
+            );
+        }
+        store
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut rows: Vec<_> = self.entries.iter().collect();
+        rows.sort_by(|((a_date, a_key), _), ((b_date, b_key), _)| {
+            (a_date, a_key.mode.id(), a_key.rules.as_str()).cmp(&(
+                b_date,
+                b_key.mode.id(),
+                b_key.rules.as_str(),
+            ))
+        });
+        let mut serialized = String::new();
+        for ((date, key), entry) in rows {
+            serialized.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\n",
+                date.key(),
+                key.mode.id(),
+                key.rules,
+                entry.seed,
+                match entry.outcome {
+                    DailyOutcome::Attempted => "attempted",
+                    DailyOutcome::Won => "won",
+                },
+            ));
+        }
+        serialized
+    }
+
+    pub fn entry(&self, date: ChallengeDate, key: &StatsKey) -> Option<DailyEntry> {
+        self.entries.get(&(date, key.clone())).copied()
+    }
+
+    /// Records the day's attempt. Returns `false` when the day was already
+    /// played under this profile.
+    pub fn record_attempt(&mut self, date: ChallengeDate, key: StatsKey, seed: u64) -> bool {
+        if self.entries.contains_key(&(date, key.clone())) {
+            return false;
+        }
+        self.entries.insert(
+            (date, key),
+            DailyEntry {
+                seed,
+                outcome: DailyOutcome::Attempted,
+            },
+        );
+        true
+    }
+
+    /// Marks the day won. Returns `false` when there was no attempt for it or
+    /// it was already won.
+    pub fn record_win(&mut self, date: ChallengeDate, key: &StatsKey) -> bool {
+        match self.entries.get_mut(&(date, key.clone())) {
+            Some(entry) if entry.outcome == DailyOutcome::Attempted => {
+                entry.outcome = DailyOutcome::Won;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Days of the month won under this profile, ascending.
+    pub fn won_days_in_month(&self, year: i32, month: u8, key: &StatsKey) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .entries
+            .iter()
+            .filter(|((date, entry_key), entry)| {
+                date.year == year
+                    && date.month == month
+                    && entry_key == key
+                    && entry.outcome == DailyOutcome::Won
+            })
+            .map(|((date, _), _)| date.day)
+            .collect();
+        days.sort_unstable();
+        days
+    }
+}
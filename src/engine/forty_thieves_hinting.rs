@@ -0,0 +1,146 @@
+use crate::engine::hinting::HintNode;
+use crate::engine::moves::{map_solver_move_to_hint_move, HintMove};
+use crate::game::{FortyThievesGame, FortyThievesSolveResult, SolverMove};
+
+#[derive(Debug, Clone)]
+pub struct FortyThievesHintSuggestion {
+    pub message: String,
+    pub source: Option<HintNode>,
+    pub target: Option<HintNode>,
+    pub hint_move: Option<HintMove>,
+}
+
+/// Source and target highlights for a move. Foundation slots map one-to-one
+/// onto the eight foundation piles.
+pub fn hint_nodes_for_move(
+    game: &FortyThievesGame,
+    solver_move: SolverMove,
+) -> (HintNode, HintNode) {
+    let foundation_for = |card| {
+        game.foundation_target(card)
+            .map_or(HintNode::Foundation(0), HintNode::Foundation)
+    };
+    match solver_move {
+        SolverMove::Draw => (HintNode::Stock, HintNode::Waste),
+        SolverMove::WasteToFoundation => (
+            HintNode::Waste,
+            game.waste_top()
+                .map_or(HintNode::Foundation(0), foundation_for),
+        ),
+        SolverMove::WasteToTableau { dst } => (
+            HintNode::Waste,
+            HintNode::Tableau {
+                col: dst,
+                index: None,
+            },
+        ),
+        SolverMove::TableauTopToFoundation { src } => (
+            HintNode::Tableau {
+                col: src,
+                index: game.tableau()[src].len().checked_sub(1),
+            },
+            game.tableau_top(src)
+                .map_or(HintNode::Foundation(0), foundation_for),
+        ),
+        SolverMove::TableauRunToTableau { src, start, dst } => (
+            HintNode::Tableau {
+                col: src,
+                index: Some(start),
+            },
+            HintNode::Tableau {
+                col: dst,
+                index: None,
+            },
+        ),
+    }
+}
+
+pub fn describe_move(game: &FortyThievesGame, solver_move: SolverMove) -> String {
+    let label = |card: Option<crate::game::Card>| {
+        card.map(|card| card.label())
+            .unwrap_or_else(|| "?".to_string())
+    };
+    match solver_move {
+        SolverMove::Draw => format!("Draw from the stock ({} left).", game.stock_len()),
+        SolverMove::WasteToFoundation => {
+            format!(
+                "Move {} from the waste to a foundation.",
+                label(game.waste_top())
+            )
+        }
+        SolverMove::WasteToTableau { dst } => {
+            format!(
+                "Move {} from the waste to T{}.",
+                label(game.waste_top()),
+                dst + 1
+            )
+        }
+        SolverMove::TableauTopToFoundation { src } => {
+            format!(
+                "Move {} from T{} to a foundation.",
+                label(game.tableau_top(src)),
+                src + 1
+            )
+        }
+        SolverMove::TableauRunToTableau { src, start, dst } => {
+            let amount = game.tableau()[src].len().saturating_sub(start);
+            let card = label(game.tableau_card(src, start));
+            if amount > 1 {
+                format!(
+                    "Move {amount} cards from {card} on T{} to T{}.",
+                    src + 1,
+                    dst + 1
+                )
+            } else {
+                format!("Move {card} from T{} to T{}.", src + 1, dst + 1)
+            }
+        }
+    }
+}
+
+/// Suggests the first move of a solver line, falling back to the solver's
+/// move ordering when the search finds no win within `max_states`.
+pub fn suggest_move(game: &FortyThievesGame, max_states: usize) -> FortyThievesHintSuggestion {
+    if game.is_won() {
+        return FortyThievesHintSuggestion {
+            message: "Hint: game already won.".to_string(),
+            source: None,
+            target: None,
+            hint_move: None,
+        };
+    }
+    let result = game.solve(max_states);
+    suggestion_from_solve(game, &result)
+}
+
+pub fn suggestion_from_solve(
+    game: &FortyThievesGame,
+    result: &FortyThievesSolveResult,
+) -> FortyThievesHintSuggestion {
+    let (solver_move, prefix) = match result.line.first() {
+        Some(first) => (Some(*first), "Hint: winning line found. "),
+        None if result.hit_state_limit => (
+            game.solver_moves().first().copied(),
+            "Hint: no win found yet. ",
+        ),
+        None => (
+            game.solver_moves().first().copied(),
+            "Hint: this deal can no longer be won. ",
+        ),
+    };
+    let Some(solver_move) = solver_move else {
+        return FortyThievesHintSuggestion {
+            message: "Hint: no legal moves. Start a new deal or undo.".to_string(),
+            source: None,
+            target: None,
+            hint_move: None,
+        };
+    };
+    let (source, target) = hint_nodes_for_move(game, solver_move);
+    FortyThievesHintSuggestion {
+        message: format!("{prefix}{}", describe_move(game, solver_move)),
+        source: Some(source),
+        target: Some(target),
+        hint_move: Some(map_solver_move_to_hint_move(solver_move)),
+    }
+}
@@ -1,4 +1,7 @@
-use crate::game::{Card, FreecellCardCountMode, FreecellGame, Suit, FREECELL_MAX_CELL_COUNT};
+use crate::game::{
+    Card, FreecellCardCountMode, FreecellGame, FreecellLayout, FreecellRules, Suit,
+    FREECELL_CELL_SLOTS, FREECELL_MAX_COLUMNS,
+};
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::hash::{Hash, Hasher};
@@ -89,10 +92,13 @@ struct PlannerUndo {
 
 #[derive(Clone)]
 struct PlannerState {
+    layout: FreecellLayout,
+    rules: FreecellRules,
+    col_count: u8,
     foundations: [u8; 4],
     freecell_count: u8,
     freecells: [Option<Card>; FREECELL_SLOT_MAX],
-    cols: [Vec<Card>; 8],
+    cols: [Vec<Card>; FREECELL_MAX_COLUMNS],
     zhash: u64,
 }
 
@@ -104,8 +110,8 @@ struct PlannerZobrist {
 
 impl PlannerZobrist {
     fn new() -> Self {
-        let mut tab = vec![0_u64; 8 * 52 * 52];
-        for col in 0..8 {
+        let mut tab = vec![0_u64; FREECELL_MAX_COLUMNS * 52 * 52];
+        for col in 0..FREECELL_MAX_COLUMNS {
             for depth in 0..52 {
                 for card in 0..52 {
                     let idx = tab_index(col, depth, card);
@@ -169,8 +175,8 @@ struct Node {
     path: Vec<FreecellPlannerAction>,
 }
 
-const FREECELL_SLOT_MAX: usize = FREECELL_MAX_CELL_COUNT as usize;
-const PLANNER_KEY_BYTES: usize = 4 + FREECELL_SLOT_MAX + 8 + 52;
+const FREECELL_SLOT_MAX: usize = FREECELL_CELL_SLOTS;
+const PLANNER_KEY_BYTES: usize = 4 + FREECELL_SLOT_MAX + FREECELL_MAX_COLUMNS + 52;
 const PLANNER_KEY_EMPTY: u8 = 0xFF;
 
 #[derive(Clone, Copy)]
@@ -533,8 +539,8 @@ pub fn plan_line_ida_with_astar_fallback(
 
 pub fn zobrist_hash(game: &FreecellGame) -> u64 {
     let mut hash = 0_u64;
-    let tableau_base = 0usize; // 8 * 52 slots
-    let freecell_base = tableau_base + (8 * 52);
+    let tableau_base = 0usize; // FREECELL_MAX_COLUMNS * 52 slots
+    let freecell_base = tableau_base + (FREECELL_MAX_COLUMNS * 52);
     let foundation_base = freecell_base + FREECELL_SLOT_MAX;
 
     // Keep tableau column identity stable in the hash (no column sorting).
@@ -583,7 +589,7 @@ fn pack_state_key(game: &FreecellGame) -> [u8; PLANNER_KEY_BYTES] {
     let mut bytes = [PLANNER_KEY_EMPTY; PLANNER_KEY_BYTES];
     let freecell_offset = 4usize;
     let tableau_len_offset = freecell_offset + FREECELL_SLOT_MAX;
-    let tableau_cards_offset = tableau_len_offset + 8;
+    let tableau_cards_offset = tableau_len_offset + FREECELL_MAX_COLUMNS;
 
     for suit in 0..4 {
         bytes[suit] = game
@@ -618,13 +624,13 @@ fn pack_state_key(game: &FreecellGame) -> [u8; PLANNER_KEY_BYTES] {
         .collect();
     canonical_cols.sort_unstable();
 
-    for col in 0..8 {
+    for col in 0..FREECELL_MAX_COLUMNS {
         let len = canonical_cols.get(col).map(Vec::len).unwrap_or(0).min(52);
         bytes[tableau_len_offset + col] = len as u8;
     }
 
     let mut out = tableau_cards_offset;
-    for cards in canonical_cols.iter().take(8) {
+    for cards in &canonical_cols {
         for &card in cards {
             if out >= PLANNER_KEY_BYTES {
                 break;
@@ -644,7 +650,7 @@ fn pack_planner_state_key(st: &PlannerState) -> [u8; PLANNER_KEY_BYTES] {
     let mut bytes = [PLANNER_KEY_EMPTY; PLANNER_KEY_BYTES];
     let freecell_offset = 4usize;
     let tableau_len_offset = freecell_offset + FREECELL_SLOT_MAX;
-    let tableau_cards_offset = tableau_len_offset + 8;
+    let tableau_cards_offset = tableau_len_offset + FREECELL_MAX_COLUMNS;
 
     bytes[..4].copy_from_slice(&st.foundations);
 
@@ -663,20 +669,19 @@ fn pack_planner_state_key(st: &PlannerState) -> [u8; PLANNER_KEY_BYTES] {
         bytes[freecell_offset + idx] = card;
     }
 
-    let mut canonical_cols: Vec<Vec<u8>> = st
-        .cols
+    let mut canonical_cols: Vec<Vec<u8>> = st.cols[..usize::from(st.col_count)]
         .iter()
         .map(|col| col.iter().copied().map(card_to_u8).collect::<Vec<u8>>())
         .collect();
     canonical_cols.sort_unstable();
 
-    for col in 0..8 {
+    for col in 0..FREECELL_MAX_COLUMNS {
         let len = canonical_cols.get(col).map(Vec::len).unwrap_or(0).min(52);
         bytes[tableau_len_offset + col] = len as u8;
     }
 
     let mut out = tableau_cards_offset;
-    for cards in canonical_cols.iter().take(8) {
+    for cards in &canonical_cols {
         for &card in cards {
             if out >= PLANNER_KEY_BYTES {
                 break;
@@ -732,10 +737,6 @@ fn card_from_suit_rank(suit_idx: usize, rank: u8) -> Card {
     }
 }
 
-fn can_stack_on_tableau(moving: Card, dest_top: Card) -> bool {
-    moving.rank + 1 == dest_top.rank && moving.color_red() != dest_top.color_red()
-}
-
 fn can_move_to_foundation(card: Card, foundations: &[u8; 4]) -> bool {
     foundations[card.suit.foundation_index()] + 1 == card.rank
 }
@@ -749,11 +750,18 @@ fn planner_state_from_game(game: &FreecellGame) -> PlannerState {
         }
         freecells[idx] = slot;
     }
+    let mut cols: [Vec<Card>; FREECELL_MAX_COLUMNS] = std::array::from_fn(|_| Vec::new());
+    for (slot, pile) in cols.iter_mut().zip(game.tableau()) {
+        slot.clone_from(pile);
+    }
     let mut st = PlannerState {
+        layout: game.layout(),
+        rules: game.rules(),
+        col_count: game.tableau().len() as u8,
         foundations: [0; 4],
         freecell_count: game.freecell_count() as u8,
         freecells,
-        cols: game.tableau().clone(),
+        cols,
         zhash: 0,
     };
     for suit_idx in 0..4 {
@@ -793,7 +801,9 @@ fn planner_state_to_game(
         }
         pile
     });
-    FreecellGame::from_parts_unchecked_with_cell_count(
+    FreecellGame::from_parts_unchecked(
+        st.layout,
+        st.rules,
         card_count_mode,
         foundations,
         st.freecell_count,
@@ -840,10 +850,12 @@ fn check_dest_legal(
 ) -> Result<(), PlannerIllegalMove> {
     match to {
         PlannerLoc::Col(ci) => {
-            if let Some(dest_top) = st.cols[ci as usize].last().copied() {
-                if !can_stack_on_tableau(card, dest_top) {
-                    return Err(PlannerIllegalMove::BadTableauStack);
-                }
+            let fits = match st.cols[ci as usize].last().copied() {
+                Some(dest_top) => st.rules.builds_on(card, dest_top),
+                None => st.rules.fills_empty_column(card),
+            };
+            if !fits {
+                return Err(PlannerIllegalMove::BadTableauStack);
             }
             Ok(())
         }
@@ -1041,9 +1053,7 @@ fn generate_moves(
         .take(st.freecell_count as usize)
         .position(|slot| slot.is_none())
         .map(|idx| idx as u8);
-    let first_empty_col = (0..8)
-        .find(|&col| st.cols[col].is_empty())
-        .map(|col| col as u8);
+    let first_empty_col = (0..st.col_count).find(|&col| st.cols[col as usize].is_empty());
 
     let mut push_move = |mv: PlannerMove| {
         if mv.count != 1 {
@@ -1063,7 +1073,7 @@ fn generate_moves(
         out.push(mv);
     };
 
-    for src in 0..8_u8 {
+    for src in 0..st.col_count {
         if let Some(card) = st.cols[src as usize].last().copied() {
             if can_move_to_foundation(card, &st.foundations) {
                 let suit = card.suit.foundation_index() as u8;
@@ -1093,16 +1103,16 @@ fn generate_moves(
         let Some(card) = st.freecells[free as usize] else {
             continue;
         };
-        for dst in 0..8_u8 {
+        for dst in 0..st.col_count {
             if let Some(dest_top) = st.cols[dst as usize].last().copied() {
-                if can_stack_on_tableau(card, dest_top) {
+                if st.rules.builds_on(card, dest_top) {
                     push_move(PlannerMove {
                         from: PlannerLoc::Free(free),
                         to: PlannerLoc::Col(dst),
                         count: 1,
                     });
                 }
-            } else if Some(dst) == first_empty_col {
+            } else if Some(dst) == first_empty_col && st.rules.fills_empty_column(card) {
                 push_move(PlannerMove {
                     from: PlannerLoc::Free(free),
                     to: PlannerLoc::Col(dst),
@@ -1112,23 +1122,23 @@ fn generate_moves(
         }
     }
 
-    for src in 0..8_u8 {
+    for src in 0..st.col_count {
         let Some(card) = st.cols[src as usize].last().copied() else {
             continue;
         };
-        for dst in 0..8_u8 {
+        for dst in 0..st.col_count {
             if src == dst {
                 continue;
             }
             if let Some(dest_top) = st.cols[dst as usize].last().copied() {
-                if can_stack_on_tableau(card, dest_top) {
+                if st.rules.builds_on(card, dest_top) {
                     push_move(PlannerMove {
                         from: PlannerLoc::Col(src),
                         to: PlannerLoc::Col(dst),
                         count: 1,
                     });
                 }
-            } else if Some(dst) == first_empty_col {
+            } else if Some(dst) == first_empty_col && st.rules.fills_empty_column(card) {
                 push_move(PlannerMove {
                     from: PlannerLoc::Col(src),
                     to: PlannerLoc::Col(dst),
@@ -1139,7 +1149,7 @@ fn generate_moves(
     }
 
     if let Some(free_dst) = first_empty_fc {
-        for src in 0..8_u8 {
+        for src in 0..st.col_count {
             if !st.cols[src as usize].is_empty() {
                 push_move(PlannerMove {
                     from: PlannerLoc::Col(src),
@@ -1158,16 +1168,16 @@ fn generate_moves(
                 continue;
             }
             let card = card_from_suit_rank(suit_idx, top_rank);
-            for dst in 0..8_u8 {
+            for dst in 0..st.col_count {
                 if let Some(dest_top) = st.cols[dst as usize].last().copied() {
-                    if can_stack_on_tableau(card, dest_top) {
+                    if st.rules.builds_on(card, dest_top) {
                         push_move(PlannerMove {
                             from: PlannerLoc::Found(su),
                             to: PlannerLoc::Col(dst),
                             count: 1,
                         });
                     }
-                } else if Some(dst) == first_empty_col {
+                } else if Some(dst) == first_empty_col && st.rules.fills_empty_column(card) {
                     push_move(PlannerMove {
                         from: PlannerLoc::Found(su),
                         to: PlannerLoc::Col(dst),
@@ -1343,12 +1353,15 @@ fn count_empty_freecells_state(st: &PlannerState) -> u32 {
 }
 
 fn count_empty_cols_state(st: &PlannerState) -> u32 {
-    st.cols.iter().filter(|col| col.is_empty()).count() as u32
+    st.cols[..usize::from(st.col_count)]
+        .iter()
+        .filter(|col| col.is_empty())
+        .count() as u32
 }
 
 fn count_immediate_found_moves_state(st: &PlannerState) -> u32 {
     let mut n = 0u32;
-    for col in 0..8 {
+    for col in 0..usize::from(st.col_count) {
         if let Some(top) = st.cols[col].last().copied() {
             if can_move_to_foundation(top, &st.foundations) {
                 n = n.saturating_add(1);
@@ -1788,6 +1801,248 @@ pub fn plan_line_ida(
     }
 }
 
+/// Search state for perfect-information layouts that reuse the planner's
+/// IDA* / A* loop without FreeCell's packed move representation.
+pub trait PlannerSearchState: Clone {
+    type Action: Copy;
+
+    fn is_goal(&self) -> bool;
+    /// Admissible estimate of the moves still needed; never overestimates.
+    fn lower_bound(&self) -> u32;
+    /// Transposition key; positions that play identically must collide.
+    fn search_key(&self) -> u64;
+    fn actions(&self) -> Vec<Self::Action>;
+    fn apply_action(&mut self, action: Self::Action) -> bool;
+    /// Tie-break for equally bounded children; higher explores first.
+    fn greedy_score(&self) -> i32;
+}
+
+#[derive(Debug, Clone)]
+pub struct PlannerSearchResult<A> {
+    pub actions: VecDeque<A>,
+    pub explored_states: usize,
+    pub stalled: bool,
+    /// Every reachable position was visited without reaching the goal.
+    pub exhausted: bool,
+}
+
+impl<A> PlannerSearchResult<A> {
+    fn unsolved(explored_states: usize, exhausted: bool) -> Self {
+        Self {
+            actions: VecDeque::new(),
+            explored_states,
+            stalled: true,
+            exhausted,
+        }
+    }
+}
+
+struct SearchStateIda<'a, S: PlannerSearchState> {
+    node_budget: usize,
+    cancel: Option<&'a AtomicBool>,
+    explored_states: usize,
+    aborted: bool,
+    path: Vec<S::Action>,
+    on_path: HashSet<u64>,
+    tt: HashMap<u64, u32>,
+    next_bound: u32,
+}
+
+impl<S: PlannerSearchState> SearchStateIda<'_, S> {
+    fn visit(&mut self, state: &S, g: u32, bound: u32) -> bool {
+        let f = g.saturating_add(state.lower_bound());
+        if f > bound {
+            self.next_bound = self.next_bound.min(f);
+            return false;
+        }
+        if state.is_goal() {
+            return true;
+        }
+        if self.explored_states >= self.node_budget
+            || self
+                .cancel
+                .is_some_and(|cancel| cancel.load(AtomicOrdering::Relaxed))
+        {
+            self.aborted = true;
+            return false;
+        }
+        let key = state.search_key();
+        if self.on_path.contains(&key) || self.tt.get(&key).is_some_and(|&seen| seen <= g) {
+            return false;
+        }
+        self.tt.insert(key, g);
+        self.explored_states += 1;
+
+        let mut children: Vec<(u32, i32, S::Action, S)> = state
+            .actions()
+            .into_iter()
+            .filter_map(|action| {
+                let mut next = state.clone();
+                next.apply_action(action).then(|| {
+                    let f = g + 1 + next.lower_bound();
+                    (f, next.greedy_score(), action, next)
+                })
+            })
+            .collect();
+        children.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(&a.1)));
+
+        self.on_path.insert(key);
+        for (_, _, action, next) in children {
+            self.path.push(action);
+            if self.visit(&next, g + 1, bound) {
+                return true;
+            }
+            self.path.pop();
+            if self.aborted {
+                break;
+            }
+        }
+        self.on_path.remove(&key);
+        false
+    }
+}
+
+/// Iterative deepening on `lower_bound`. A finished iteration that never
+/// crossed the bound has seen the whole reachable space.
+pub fn plan_search_state_ida<S: PlannerSearchState>(
+    start: &S,
+    node_budget: usize,
+    cancel: Option<&AtomicBool>,
+) -> PlannerSearchResult<S::Action> {
+    let mut search = SearchStateIda::<S> {
+        node_budget: node_budget.max(1),
+        cancel,
+        explored_states: 0,
+        aborted: false,
+        path: Vec::new(),
+        on_path: HashSet::new(),
+        tt: HashMap::new(),
+        next_bound: u32::MAX,
+    };
+    let mut bound = start.lower_bound();
+    loop {
+        search.tt.clear();
+        search.on_path.clear();
+        search.next_bound = u32::MAX;
+        if search.visit(start, 0, bound) {
+            return PlannerSearchResult {
+                actions: search.path.into_iter().collect(),
+                explored_states: search.explored_states,
+                stalled: false,
+                exhausted: false,
+            };
+        }
+        if search.aborted || search.next_bound == u32::MAX {
+            let exhausted = !search.aborted;
+            return PlannerSearchResult::unsolved(search.explored_states, exhausted);
+        }
+        bound = search.next_bound;
+    }
+}
+
+struct SearchStateNode {
+    priority: u32,
+    greedy: i32,
+    index: usize,
+}
+
+impl Eq for SearchStateNode {}
+impl PartialEq for SearchStateNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.greedy == other.greedy && self.index == other.index
+    }
+}
+impl Ord for SearchStateNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max-heap: lowest priority, then best greedy, first.
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.greedy.cmp(&other.greedy))
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+impl PartialOrd for SearchStateNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Weighted best-first search (`g + 2h`). Lines are not guaranteed shortest,
+/// but an emptied frontier still proves the goal unreachable.
+pub fn plan_search_state_astar<S: PlannerSearchState>(
+    start: &S,
+    node_budget: usize,
+    cancel: Option<&AtomicBool>,
+) -> PlannerSearchResult<S::Action> {
+    let node_budget = node_budget.max(1);
+    // (state, parent index, action from parent, depth)
+    let mut nodes: Vec<(S, usize, Option<S::Action>, u32)> = vec![(start.clone(), 0, None, 0)];
+    let mut seen: HashSet<u64> = HashSet::from([start.search_key()]);
+    let mut frontier = BinaryHeap::from([SearchStateNode {
+        priority: start.lower_bound().saturating_mul(2),
+        greedy: start.greedy_score(),
+        index: 0,
+    }]);
+    let mut explored_states = 0_usize;
+
+    while let Some(node) = frontier.pop() {
+        if nodes[node.index].0.is_goal() {
+            let mut actions = VecDeque::new();
+            let mut index = node.index;
+            while let (_, parent, Some(action), _) = &nodes[index] {
+                actions.push_front(*action);
+                index = *parent;
+            }
+            return PlannerSearchResult {
+                actions,
+                explored_states,
+                stalled: false,
+                exhausted: false,
+            };
+        }
+        if explored_states >= node_budget
+            || cancel.is_some_and(|cancel| cancel.load(AtomicOrdering::Relaxed))
+        {
+            return PlannerSearchResult::unsolved(explored_states, false);
+        }
+        explored_states += 1;
+
+        let g = nodes[node.index].3 + 1;
+        for action in nodes[node.index].0.actions() {
+            let mut next = nodes[node.index].0.clone();
+            if !next.apply_action(action) || !seen.insert(next.search_key()) {
+                continue;
+            }
+            frontier.push(SearchStateNode {
+                priority: g.saturating_add(next.lower_bound().saturating_mul(2)),
+                greedy: next.greedy_score(),
+                index: nodes.len(),
+            });
+            nodes.push((next, node.index, Some(action), g));
+        }
+    }
+    PlannerSearchResult::unsolved(explored_states, true)
+}
+
+/// Generic counterpart of [`plan_line_ida_with_astar_fallback`]: IDA* gets
+/// half the budget, then A* takes over if it ran dry without an answer.
+pub fn plan_search_state_ida_with_astar_fallback<S: PlannerSearchState>(
+    start: &S,
+    node_budget: usize,
+    cancel: Option<&AtomicBool>,
+) -> PlannerSearchResult<S::Action> {
+    let ida_budget = (node_budget / 2).max(1);
+    let ida = plan_search_state_ida(start, ida_budget, cancel);
+    if !ida.stalled || ida.exhausted {
+        return ida;
+    }
+    let astar_budget = node_budget.saturating_sub(ida.explored_states).max(1);
+    let mut astar = plan_search_state_astar(start, astar_budget, cancel);
+    astar.explored_states = astar.explored_states.saturating_add(ida.explored_states);
+    astar
+}
+
 fn generate_relocation_candidates(game: &FreecellGame) -> Vec<Candidate> {
     let mut out = Vec::new();
     let mut st = planner_state_from_game(game);
@@ -1853,7 +2108,7 @@ fn generate_relocation_candidates(game: &FreecellGame) -> Vec<Candidate> {
 
         // Relocation attempt 2: move top blocker card to another tableau.
         let top = len - 1;
-        for dst in 0..8 {
+        for dst in 0..game.tableau().len() {
             if is_empty_tableau_col(game, dst) && Some(dst) != first_empty_col {
                 continue;
             }
@@ -1988,7 +2243,7 @@ fn first_safe_foundation_action(game: &FreecellGame) -> Option<FreecellPlannerAc
             return Some(FreecellPlannerAction::FreecellToFoundation { cell });
         }
     }
-    for src in 0..8 {
+    for src in 0..game.tableau().len() {
         if game.can_move_tableau_top_to_foundation(src)
             && safe_foundation_card(game, game.tableau_top(src))
         {
@@ -2071,29 +2326,19 @@ fn apply_action(game: &mut FreecellGame, action: FreecellPlannerAction) -> bool
 }
 
 fn safe_foundation_card(game: &FreecellGame, card: Option<Card>) -> bool {
-    let Some(card) = card else {
-        return false;
-    };
-    if card.rank <= 1 {
-        return true;
-    }
-    let needed = usize::from(card.rank.saturating_sub(1));
-    if card.color_red() {
-        game.foundations()[0].len() >= needed && game.foundations()[3].len() >= needed
-    } else {
-        game.foundations()[1].len() >= needed && game.foundations()[2].len() >= needed
-    }
+    card.is_some_and(|card| game.is_safe_foundation_play(card))
 }
 
 fn legal_move_count(game: &FreecellGame) -> usize {
     let mut count = 0usize;
+    let columns = game.tableau().len();
     let first_empty_fc = first_empty_freecell(game);
     let first_empty_col = first_empty_tableau_col(game);
     for cell in 0..game.freecell_count() {
         if game.can_move_freecell_to_foundation(cell) {
             count += 1;
         }
-        for dst in 0..8 {
+        for dst in 0..columns {
             if is_empty_tableau_col(game, dst) && Some(dst) != first_empty_col {
                 continue;
             }
@@ -2102,7 +2347,7 @@ fn legal_move_count(game: &FreecellGame) -> usize {
             }
         }
     }
-    for src in 0..8 {
+    for src in 0..columns {
         if game.can_move_tableau_top_to_foundation(src) {
             count += 1;
         }
@@ -2116,7 +2361,7 @@ fn legal_move_count(game: &FreecellGame) -> usize {
         }
         let len = game.tableau().get(src).map(Vec::len).unwrap_or(0);
         for start in 0..len {
-            for dst in 0..8 {
+            for dst in 0..columns {
                 if is_empty_tableau_col(game, dst) && Some(dst) != first_empty_col {
                     continue;
                 }
@@ -2162,6 +2407,7 @@ fn deadlock_penalty(game: &FreecellGame) -> i64 {
 }
 
 fn tableau_order_score(game: &FreecellGame) -> i64 {
+    let rules = game.rules();
     let mut total = 0_i64;
     for col in game.tableau() {
         if col.is_empty() {
@@ -2171,9 +2417,7 @@ fn tableau_order_score(game: &FreecellGame) -> i64 {
         for idx in (1..col.len()).rev() {
             let below = col[idx];
             let above = col[idx - 1];
-            let ok_rank = above.rank == below.rank.saturating_add(1);
-            let ok_color = above.color_red() != below.color_red();
-            if ok_rank && ok_color {
+            if rules.builds_on(below, above) {
                 run_len += 1;
             } else {
                 break;
@@ -2244,6 +2488,145 @@ fn zobrist_value(card_idx: usize, pos_idx: usize) -> u64 {
     splitmix64(mixed)
 }
 
+/// Exhaustive depth-first search over single-card moves, with safe foundation
+/// plays forced. Nothing legal is pruned and supermoves only chain single-card
+/// moves, so `exhausted` is a proof that the deal cannot be won.
+pub fn prove_line(
+    start: &FreecellGame,
+    max_states: usize,
+    cancel: Option<&AtomicBool>,
+) -> PlannerSearchResult<FreecellPlannerAction> {
+    struct ProofFrame {
+        entry: Vec<PlannerUndo>,
+        line_len: usize,
+        moves: Vec<PlannerMove>,
+        next: usize,
+    }
+
+    let mut st = planner_state_from_game(start);
+    let target_cards = u32::from(start.card_count_mode().card_count());
+    let mut line = Vec::new();
+    let entry = force_safe_foundation_moves(&mut st, &mut line);
+    if planner_state_is_goal(&st, target_cards) {
+        return PlannerSearchResult {
+            actions: line.into(),
+            explored_states: 1,
+            stalled: false,
+            exhausted: false,
+        };
+    }
+    let mut visited: HashSet<PlannerKey> = HashSet::new();
+    visited.insert(make_canonical_planner_key(&st));
+    let mut stack = vec![ProofFrame {
+        entry,
+        line_len: 0,
+        moves: generate_moves(&st, None, false),
+        next: 0,
+    }];
+
+    while let Some(frame) = stack.last_mut() {
+        if cancel.is_some_and(|flag| flag.load(AtomicOrdering::Relaxed)) {
+            return PlannerSearchResult::unsolved(visited.len(), false);
+        }
+        let Some(mv) = frame.moves.get(frame.next).copied() else {
+            if let Some(done) = stack.pop() {
+                for undo in done.entry.into_iter().rev() {
+                    undo_move_in_place(&mut st, undo);
+                }
+                line.truncate(done.line_len);
+            }
+            continue;
+        };
+        frame.next += 1;
+
+        let line_len = line.len();
+        let Some(action) = planner_action_from_move(&st, mv) else {
+            continue;
+        };
+        let Ok(undo) = apply_move_in_place(&mut st, mv, false) else {
+            continue;
+        };
+        line.push(action);
+        let mut entry = vec![undo];
+        entry.extend(force_safe_foundation_moves(&mut st, &mut line));
+        if planner_state_is_goal(&st, target_cards) {
+            return PlannerSearchResult {
+                actions: line.into(),
+                explored_states: visited.len(),
+                stalled: false,
+                exhausted: false,
+            };
+        }
+        if !visited.insert(make_canonical_planner_key(&st)) {
+            for undo in entry.into_iter().rev() {
+                undo_move_in_place(&mut st, undo);
+            }
+            line.truncate(line_len);
+            continue;
+        }
+        if visited.len() >= max_states {
+            return PlannerSearchResult::unsolved(visited.len(), false);
+        }
+        stack.push(ProofFrame {
+            entry,
+            line_len,
+            moves: generate_moves(&st, Some(mv), false),
+            next: 0,
+        });
+    }
+
+    PlannerSearchResult::unsolved(visited.len(), true)
+}
+
+/// Unlike `make_planner_key_from_state`, hashes the packed bytes so positions
+/// that differ only in column order land in the same bucket.
+fn make_canonical_planner_key(st: &PlannerState) -> PlannerKey {
+    let bytes = pack_planner_state_key(st);
+    PlannerKey {
+        z: packed_key_hash64(&bytes),
+        bytes,
+    }
+}
+
+/// Plays every safe foundation card until none is left; a safe card is never
+/// needed in the tableau again, so doing this cannot lose a winnable position.
+fn force_safe_foundation_moves(
+    st: &mut PlannerState,
+    line: &mut Vec<FreecellPlannerAction>,
+) -> Vec<PlannerUndo> {
+    let mut undos = Vec::new();
+    loop {
+        let foundation_len = st.foundations.map(usize::from);
+        let from_cells = (0..st.freecell_count).map(PlannerLoc::Free);
+        let from_cols = (0..st.col_count).map(PlannerLoc::Col);
+        let next = from_cells.chain(from_cols).find_map(|from| {
+            let card = match from {
+                PlannerLoc::Free(cell) => st.freecells[usize::from(cell)],
+                PlannerLoc::Col(col) => st.cols[usize::from(col)].last().copied(),
+                PlannerLoc::Found(_) => None,
+            }?;
+            (can_move_to_foundation(card, &st.foundations)
+                && st.rules.is_safe_foundation_play(card, foundation_len))
+            .then_some(PlannerMove {
+                from,
+                to: PlannerLoc::Found(card.suit.foundation_index() as u8),
+                count: 1,
+            })
+        });
+        let Some(mv) = next else {
+            return undos;
+        };
+        let Some(action) = planner_action_from_move(st, mv) else {
+            return undos;
+        };
+        let Ok(undo) = apply_move_in_place(st, mv, false) else {
+            return undos;
+        };
+        line.push(action);
+        undos.push(undo);
+    }
+}
+
 fn splitmix64(mut x: u64) -> u64 {
     x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
     let mut z = x;
@@ -2303,6 +2686,42 @@ mod tests {
         assert_eq!(st.freecells, old_free);
     }
 
+    #[test]
+    fn suit_build_layout_generates_only_in_suit_and_king_column_moves() {
+        let mut tableau = vec![Vec::new(); 10];
+        tableau[0] = vec![c(Suit::Hearts, 9)];
+        tableau[1] = vec![c(Suit::Spades, 10)];
+        tableau[2] = vec![c(Suit::Hearts, 10)];
+        tableau[3] = vec![c(Suit::Diamonds, 2), c(Suit::Clubs, 13)];
+        let game = FreecellGame::debug_new_with_layout(
+            FreecellLayout::SeahavenTowers,
+            std::array::from_fn(|_| Vec::new()),
+            &[None; 4],
+            tableau,
+        );
+        let st = planner_state_from_game(&game);
+        let moves = generate_moves(&st, None, false);
+
+        assert!(moves.contains(&PlannerMove {
+            from: PlannerLoc::Col(0),
+            to: PlannerLoc::Col(2),
+            count: 1,
+        }));
+        assert!(!moves.contains(&PlannerMove {
+            from: PlannerLoc::Col(0),
+            to: PlannerLoc::Col(1),
+            count: 1,
+        }));
+        let into_empty: Vec<_> = moves
+            .iter()
+            .filter(|mv| matches!(mv.to, PlannerLoc::Col(col) if col >= 4))
+            .collect();
+        assert!(!into_empty.is_empty());
+        assert!(into_empty
+            .iter()
+            .all(|mv| mv.from == PlannerLoc::Col(3) && mv.count == 1));
+    }
+
     #[test]
     fn immediate_inverse_pruning_ignores_foundation_when_source_forbidden() {
         let prev = PlannerMove {
@@ -2,7 +2,11 @@
 
 use std::ops::{Deref, DerefMut};
 
-use crate::game::{FreecellGame, GameMode, KlondikeGame, SpiderGame};
+use crate::game::{
+    CanfieldGame, FortyThievesGame, FreecellGame, FreecellLayout, GameMode, KlondikeGame,
+    OpenTableauGame, OpenTableauLayout, PyramidGame, ScorpionGame, SpiderGame, WasteBuildGame,
+    WasteBuildLayout, YukonGame,
+};
 
 /// Runtime container for the active solitaire variant.
 /// Klondike is fully implemented; other variants are explicit placeholders.
@@ -11,6 +15,18 @@ pub enum VariantRuntime {
     Klondike(KlondikeGame),
     Spider(SpiderGame),
     Freecell(FreecellGame),
+    Yukon(YukonGame),
+    Pyramid(PyramidGame),
+    TriPeaks(WasteBuildGame),
+    Golf(WasteBuildGame),
+    FortyThieves(FortyThievesGame),
+    Scorpion(ScorpionGame),
+    Canfield(CanfieldGame),
+    BakersDozen(OpenTableauGame),
+    BeleagueredCastle(OpenTableauGame),
+    EightOff(FreecellGame),
+    SeahavenTowers(FreecellGame),
+    BakersGame(FreecellGame),
 }
 
 impl VariantRuntime {
@@ -19,6 +35,37 @@ impl VariantRuntime {
             GameMode::Klondike => Self::Klondike(KlondikeGame::new_with_seed(seed)),
             GameMode::Spider => Self::Spider(SpiderGame::new_with_seed(seed)),
             GameMode::Freecell => Self::Freecell(FreecellGame::new_with_seed(seed)),
+            GameMode::Yukon => Self::Yukon(YukonGame::new_with_seed(seed)),
+            GameMode::Pyramid => Self::Pyramid(PyramidGame::new_with_seed(seed)),
+            GameMode::TriPeaks => Self::TriPeaks(WasteBuildGame::new_with_seed(
+                WasteBuildLayout::TriPeaks,
+                seed,
+            )),
+            GameMode::Golf => {
+                Self::Golf(WasteBuildGame::new_with_seed(WasteBuildLayout::Golf, seed))
+            }
+            GameMode::FortyThieves => Self::FortyThieves(FortyThievesGame::new_with_seed(seed)),
+            GameMode::Scorpion => Self::Scorpion(ScorpionGame::new_with_seed(seed)),
+            GameMode::Canfield => Self::Canfield(CanfieldGame::new_with_seed(seed)),
+            GameMode::BakersDozen => Self::BakersDozen(OpenTableauGame::new_with_seed(
+                OpenTableauLayout::BakersDozen,
+                seed,
+            )),
+            GameMode::BeleagueredCastle => Self::BeleagueredCastle(OpenTableauGame::new_with_seed(
+                OpenTableauLayout::BeleagueredCastle,
+                seed,
+            )),
+            GameMode::EightOff => Self::EightOff(FreecellGame::new_with_seed_for_layout(
+                FreecellLayout::EightOff,
+                seed,
+            )),
+            GameMode::SeahavenTowers => Self::SeahavenTowers(
+                FreecellGame::new_with_seed_for_layout(FreecellLayout::SeahavenTowers, seed),
+            ),
+            GameMode::BakersGame => Self::BakersGame(FreecellGame::new_with_seed_for_layout(
+                FreecellLayout::BakersGame,
+                seed,
+            )),
         }
     }
 
@@ -27,6 +74,18 @@ impl VariantRuntime {
             Self::Klondike(_) => GameMode::Klondike,
             Self::Spider(_) => GameMode::Spider,
             Self::Freecell(_) => GameMode::Freecell,
+            Self::Yukon(_) => GameMode::Yukon,
+            Self::Pyramid(_) => GameMode::Pyramid,
+            Self::TriPeaks(_) => GameMode::TriPeaks,
+            Self::Golf(_) => GameMode::Golf,
+            Self::FortyThieves(_) => GameMode::FortyThieves,
+            Self::Scorpion(_) => GameMode::Scorpion,
+            Self::Canfield(_) => GameMode::Canfield,
+            Self::BakersDozen(_) => GameMode::BakersDozen,
+            Self::BeleagueredCastle(_) => GameMode::BeleagueredCastle,
+            Self::EightOff(_) => GameMode::EightOff,
+            Self::SeahavenTowers(_) => GameMode::SeahavenTowers,
+            Self::BakersGame(_) => GameMode::BakersGame,
         }
     }
 
@@ -37,21 +96,60 @@ impl VariantRuntime {
     pub fn as_klondike(&self) -> Option<&KlondikeGame> {
         match self {
             Self::Klondike(game) => Some(game),
-            Self::Spider(_) | Self::Freecell(_) => None,
+            Self::Spider(_)
+            | Self::Freecell(_)
+            | Self::Yukon(_)
+            | Self::Pyramid(_)
+            | Self::TriPeaks(_)
+            | Self::Golf(_)
+            | Self::FortyThieves(_)
+            | Self::Scorpion(_)
+            | Self::Canfield(_)
+            | Self::BakersDozen(_)
+            | Self::BeleagueredCastle(_)
+            | Self::EightOff(_)
+            | Self::SeahavenTowers(_)
+            | Self::BakersGame(_) => None,
         }
     }
 
     pub fn as_klondike_mut(&mut self) -> Option<&mut KlondikeGame> {
         match self {
             Self::Klondike(game) => Some(game),
-            Self::Spider(_) | Self::Freecell(_) => None,
+            Self::Spider(_)
+            | Self::Freecell(_)
+            | Self::Yukon(_)
+            | Self::Pyramid(_)
+            | Self::TriPeaks(_)
+            | Self::Golf(_)
+            | Self::FortyThieves(_)
+            | Self::Scorpion(_)
+            | Self::Canfield(_)
+            | Self::BakersDozen(_)
+            | Self::BeleagueredCastle(_)
+            | Self::EightOff(_)
+            | Self::SeahavenTowers(_)
+            | Self::BakersGame(_) => None,
         }
     }
 
     pub fn into_klondike(self) -> Option<KlondikeGame> {
         match self {
             Self::Klondike(game) => Some(game),
-            Self::Spider(_) | Self::Freecell(_) => None,
+            Self::Spider(_)
+            | Self::Freecell(_)
+            | Self::Yukon(_)
+            | Self::Pyramid(_)
+            | Self::TriPeaks(_)
+            | Self::Golf(_)
+            | Self::FortyThieves(_)
+            | Self::Scorpion(_)
+            | Self::Canfield(_)
+            | Self::BakersDozen(_)
+            | Self::BeleagueredCastle(_)
+            | Self::EightOff(_)
+            | Self::SeahavenTowers(_)
+            | Self::BakersGame(_) => None,
         }
     }
 }
@@ -93,7 +93,7 @@ pub fn enumerate_hint_candidates(game: &KlondikeGame) -> Vec<HintSuggestion> {
         }
     }
 
-    if game.stock_len() > 0 || game.waste_top().is_some() {
+    if game.can_draw_or_recycle() {
         candidates.push(HintSuggestion {
             message: "Hint: Draw from stock.".to_string(),
             source: Some(HintNode::Stock),
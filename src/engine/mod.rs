@@ -2,8 +2,11 @@ pub mod automation;
 pub mod autoplay;
 pub mod autoplay_search;
 pub mod boundary;
+pub mod canfield_hinting;
 pub mod chess;
 pub mod commands;
+pub mod daily;
+pub mod forty_thieves_hinting;
 pub mod foundation_safety;
 pub mod freecell_planner;
 pub mod game_mode;
@@ -11,17 +14,25 @@ pub mod hinting;
 pub mod keyboard_nav;
 pub mod loss_analysis;
 pub mod moves;
+pub mod open_tableau_hinting;
+pub mod open_tableau_planner;
+pub mod pyramid_hinting;
 pub mod render_plan;
+pub mod replay;
 pub mod robot;
+pub mod scoring;
+pub mod scorpion_hinting;
 pub mod seed_history;
 pub mod seed_ops;
 pub mod session;
 pub mod smart_move;
+pub mod stats;
 pub mod status_text;
 pub mod variant;
 pub mod variant_engine;
 pub mod variant_state;
 pub mod view_model;
+pub mod waste_build_hinting;
 
 #[cfg(test)]
 mod tests;
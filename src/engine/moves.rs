@@ -87,7 +87,7 @@ pub fn enumerate_hint_moves(game: &KlondikeGame) -> Vec<HintMove> {
         }
     }
 
-    if game.stock_len() > 0 || game.waste_top().is_some() {
+    if game.can_draw_or_recycle() {
         moves.push(HintMove::Draw);
     }
 
@@ -0,0 +1,113 @@
+use crate::engine::hinting::HintNode;
+use crate::engine::moves::{map_solver_move_to_hint_move, HintMove};
+use crate::engine::open_tableau_planner::{self, OpenTableauSolveResult};
+use crate::game::{OpenTableauGame, SolverMove};
+
+#[derive(Debug, Clone)]
+pub struct OpenTableauHintSuggestion {
+    pub message: String,
+    pub source: Option<HintNode>,
+    pub target: Option<HintNode>,
+    pub hint_move: Option<HintMove>,
+}
+
+/// Source and target highlights for a move. Foundation slots follow
+/// `Suit::foundation_index`.
+pub fn hint_nodes_for_move(
+    game: &OpenTableauGame,
+    solver_move: SolverMove,
+) -> (HintNode, HintNode) {
+    match solver_move {
+        SolverMove::TableauTopToFoundation { src } => (
+            HintNode::Tableau {
+                col: src,
+                index: game.tableau()[src].len().checked_sub(1),
+            },
+            game.tableau_top(src)
+                .and_then(|card| game.foundation_target(card))
+                .map_or(HintNode::Foundation(0), HintNode::Foundation),
+        ),
+        SolverMove::TableauRunToTableau { src, start, dst } => (
+            HintNode::Tableau {
+                col: src,
+                index: Some(start),
+            },
+            HintNode::Tableau {
+                col: dst,
+                index: None,
+            },
+        ),
+        SolverMove::Draw | SolverMove::WasteToFoundation | SolverMove::WasteToTableau { .. } => {
+            (HintNode::Stock, HintNode::Waste)
+        }
+    }
+}
+
+pub fn describe_move(game: &OpenTableauGame, solver_move: SolverMove) -> String {
+    let label = |card: Option<crate::game::Card>| {
+        card.map(|card| card.label())
+            .unwrap_or_else(|| "?".to_string())
+    };
+    match solver_move {
+        SolverMove::TableauTopToFoundation { src } => {
+            format!(
+                "Move {} from T{} to a foundation.",
+                label(game.tableau_top(src)),
+                src + 1
+            )
+        }
+        SolverMove::TableauRunToTableau { src, dst, .. } => {
+            format!(
+                "Move {} from T{} to T{}.",
+                label(game.tableau_top(src)),
+                src + 1,
+                dst + 1
+            )
+        }
+        SolverMove::Draw | SolverMove::WasteToFoundation | SolverMove::WasteToTableau { .. } => {
+            "No stock or waste in this layout.".to_string()
+        }
+    }
+}
+
+/// Suggests the first move of a solver line, falling back to the solver's
+/// move ordering when the search finds no win within `max_states`.
+pub fn suggest_move(game: &OpenTableauGame, max_states: usize) -> OpenTableauHintSuggestion {
+    if game.is_won() {
+        return OpenTableauHintSuggestion {
+            message: "Hint: game already won.".to_string(),
+            source: None,
+            target: None,
+            hint_move: None,
+        };
+    }
+    let result = open_tableau_planner::solve(game, max_states);
+    suggestion_from_solve(game, &result)
+}
+
+pub fn suggestion_from_solve(
+    game: &OpenTableauGame,
+    result: &OpenTableauSolveResult,
+) -> OpenTableauHintSuggestion {
+    let fallback = || open_tableau_planner::solver_moves(game).first().copied();
+    let (solver_move, prefix) = match result.line.first() {
+        Some(first) => (Some(*first), "Hint: winning line found. "),
+        None if result.hit_state_limit => (fallback(), "Hint: no win found yet. "),
+        None => (fallback(), "Hint: this deal can no longer be won. "),
+    };
+    let Some(solver_move) = solver_move else {
+        return OpenTableauHintSuggestion {
+            message: "Hint: no legal moves. Start a new deal or undo.".to_string(),
+            source: None,
+            target: None,
+            hint_move: None,
+        };
+    };
+    let (source, target) = hint_nodes_for_move(game, solver_move);
+    OpenTableauHintSuggestion {
+        message: format!("{prefix}{}", describe_move(game, solver_move)),
+        source: Some(source),
+        target: Some(target),
+        hint_move: Some(map_solver_move_to_hint_move(solver_move)),
+    }
+}
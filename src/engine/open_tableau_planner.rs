@@ -0,0 +1,151 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::AtomicBool;
+
+use crate::engine::freecell_planner::{
+    plan_search_state_ida_with_astar_fallback, PlannerSearchState,
+};
+use crate::game::{OpenTableauGame, SolverMove};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenTableauSolveResult {
+    pub winnable: bool,
+    pub explored_states: usize,
+    pub hit_state_limit: bool,
+    /// Winning line from the analysed position; empty unless `winnable`.
+    pub line: Vec<SolverMove>,
+}
+
+impl PlannerSearchState for OpenTableauGame {
+    type Action = SolverMove;
+
+    fn is_goal(&self) -> bool {
+        self.is_won()
+    }
+
+    /// Each move sends at most one card home.
+    fn lower_bound(&self) -> u32 {
+        52 - self.foundation_cards() as u32
+    }
+
+    fn search_key(&self) -> u64 {
+        // Columns are interchangeable, so hash them in a canonical order.
+        let mut columns: Vec<u64> = self
+            .tableau()
+            .iter()
+            .map(|column| {
+                let mut hasher = DefaultHasher::new();
+                column.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+        columns.sort_unstable();
+        let mut hasher = DefaultHasher::new();
+        columns.hash(&mut hasher);
+        for pile in self.foundations() {
+            pile.len().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Legal moves minus the ones that cannot change the position's shape.
+    /// A safe foundation play is forced: nothing could ever stack on it.
+    fn actions(&self) -> Vec<SolverMove> {
+        let lowest_foundation = self.foundations().iter().map(Vec::len).min().unwrap_or(0);
+        let mut first_empty_target = None;
+        let mut actions = Vec::new();
+        for candidate in self.legal_moves() {
+            match candidate {
+                SolverMove::TableauTopToFoundation { src } => {
+                    let safe = self
+                        .tableau_top(src)
+                        .is_some_and(|card| usize::from(card.rank) <= lowest_foundation + 1);
+                    if safe {
+                        return vec![candidate];
+                    }
+                }
+                SolverMove::TableauRunToTableau { src, dst, .. }
+                    if self.tableau()[dst].is_empty() =>
+                {
+                    if self.tableau()[src].len() == 1 {
+                        continue;
+                    }
+                    // Every empty column is the same square; try only one.
+                    if *first_empty_target.get_or_insert(dst) != dst {
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            actions.push(candidate);
+        }
+        actions
+    }
+
+    fn apply_action(&mut self, action: SolverMove) -> bool {
+        self.apply_move(action)
+    }
+
+    fn greedy_score(&self) -> i32 {
+        let mut breaks = 0;
+        let mut empty = 0;
+        for pile in self.tableau() {
+            if pile.is_empty() {
+                empty += 1;
+            }
+            breaks += pile
+                .windows(2)
+                .filter(|pair| pair[0].rank != pair[1].rank + 1)
+                .count() as i32;
+        }
+        1000 * self.foundation_cards() as i32 - 10 * breaks + 20 * empty
+    }
+}
+
+pub fn solve(game: &OpenTableauGame, max_states: usize) -> OpenTableauSolveResult {
+    let cancel = AtomicBool::new(false);
+    solve_cancelable(game, max_states, &cancel).unwrap_or(OpenTableauSolveResult {
+        winnable: false,
+        explored_states: 0,
+        hit_state_limit: true,
+        line: Vec::new(),
+    })
+}
+
+/// Exact search: every card is visible, so a found line always wins and an
+/// exhausted search proves the position lost.
+pub fn solve_cancelable(
+    game: &OpenTableauGame,
+    max_states: usize,
+    cancel: &AtomicBool,
+) -> Option<OpenTableauSolveResult> {
+    let result = plan_search_state_ida_with_astar_fallback(game, max_states, Some(cancel));
+    if result.stalled && !result.exhausted && cancel.load(std::sync::atomic::Ordering::Relaxed) {
+        return None;
+    }
+    let winnable = !result.stalled;
+    Some(OpenTableauSolveResult {
+        winnable,
+        explored_states: result.explored_states,
+        hit_state_limit: !winnable && !result.exhausted,
+        line: result.actions.into_iter().collect(),
+    })
+}
+
+/// Candidate moves in hint-fallback order: best resulting position first.
+pub fn solver_moves(game: &OpenTableauGame) -> Vec<SolverMove> {
+    let mut ordered: Vec<(i32, SolverMove)> = game
+        .actions()
+        .into_iter()
+        .filter_map(|candidate| {
+            let mut next = game.clone();
+            next.apply_move(candidate)
+                .then(|| (next.greedy_score(), candidate))
+        })
+        .collect();
+    ordered.sort_by_key(|(score, _)| -score);
+    ordered
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
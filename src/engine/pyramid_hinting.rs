@@ -0,0 +1,109 @@
+use crate::engine::hinting::HintNode;
+use crate::game::{PyramidCardRef, PyramidGame, PyramidMove, PyramidSolveResult};
+
+#[derive(Debug, Clone)]
+pub struct PyramidHintSuggestion {
+    pub message: String,
+    pub source: Option<HintNode>,
+    pub target: Option<HintNode>,
+    pub pyramid_move: Option<PyramidMove>,
+}
+
+/// Pyramid slots render into tableau column 0, indexed by slot.
+pub fn hint_node_for_card_ref(card_ref: PyramidCardRef) -> HintNode {
+    match card_ref {
+        PyramidCardRef::Pyramid(slot) => HintNode::Tableau {
+            col: 0,
+            index: Some(slot),
+        },
+        PyramidCardRef::Waste => HintNode::Waste,
+    }
+}
+
+/// Source and target highlights for a move. The removed pile shares the first
+/// foundation slot.
+pub fn hint_nodes_for_move(pyramid_move: PyramidMove) -> (HintNode, HintNode) {
+    match pyramid_move {
+        PyramidMove::RemoveKing(card_ref) => {
+            (hint_node_for_card_ref(card_ref), HintNode::Foundation(0))
+        }
+        PyramidMove::RemovePair(first, second) => (
+            hint_node_for_card_ref(first),
+            hint_node_for_card_ref(second),
+        ),
+        PyramidMove::Draw => (HintNode::Stock, HintNode::Waste),
+    }
+}
+
+pub fn describe_move(game: &PyramidGame, pyramid_move: PyramidMove) -> String {
+    let card_name = |card_ref: PyramidCardRef| {
+        let label = game
+            .card(card_ref)
+            .map(|card| card.label())
+            .unwrap_or_else(|| "?".to_string());
+        match card_ref {
+            PyramidCardRef::Pyramid(slot) => {
+                format!("{label} (row {})", PyramidGame::slot_row_pos(slot).0 + 1)
+            }
+            PyramidCardRef::Waste => format!("{label} (waste)"),
+        }
+    };
+    match pyramid_move {
+        PyramidMove::RemoveKing(card_ref) => format!("Remove {}.", card_name(card_ref)),
+        PyramidMove::RemovePair(first, second) => {
+            format!("Pair {} with {}.", card_name(first), card_name(second))
+        }
+        PyramidMove::Draw if game.stock_len() > 0 => "Draw from the stock.".to_string(),
+        PyramidMove::Draw => format!(
+            "Recycle the waste ({} left).",
+            game.recycles_remaining().saturating_sub(1)
+        ),
+    }
+}
+
+/// Suggests the first move of a solver line, falling back to the solver's
+/// move ordering when the search finds no win within `max_states`.
+pub fn suggest_move(game: &PyramidGame, max_states: usize) -> PyramidHintSuggestion {
+    if game.is_won() {
+        return PyramidHintSuggestion {
+            message: "Hint: game already won.".to_string(),
+            source: None,
+            target: None,
+            pyramid_move: None,
+        };
+    }
+    let result = game.solve(max_states);
+    suggestion_from_solve(game, &result)
+}
+
+pub fn suggestion_from_solve(
+    game: &PyramidGame,
+    result: &PyramidSolveResult,
+) -> PyramidHintSuggestion {
+    let (pyramid_move, prefix) = match result.line.first() {
+        Some(first) => (Some(*first), "Hint: winning line found. "),
+        None if result.hit_state_limit => (
+            game.solver_moves().first().copied(),
+            "Hint: no win found yet. ",
+        ),
+        None => (
+            game.solver_moves().first().copied(),
+            "Hint: this deal can no longer be won. ",
+        ),
+    };
+    let Some(pyramid_move) = pyramid_move else {
+        return PyramidHintSuggestion {
+            message: "Hint: no legal moves. Start a new deal or undo.".to_string(),
+            source: None,
+            target: None,
+            pyramid_move: None,
+        };
+    };
+    let (source, target) = hint_nodes_for_move(pyramid_move);
+    PyramidHintSuggestion {
+        message: format!("{prefix}{}", describe_move(game, pyramid_move)),
+        source: Some(source),
+        target: Some(target),
+        pyramid_move: Some(pyramid_move),
+    }
+}
@@ -0,0 +1,369 @@
+//! Move logs for playing a finished game back.
+//!
+//! A log is the opening position plus every move that changed the board, in
+//! a plain-text form that can be saved and shared:
+//!
+//! ```text
+//! # Cardthropic replay
+//! Game: klondike
+//! Seed: v1:12345
+//! Rules: Deal 3, Unlimited
+//! Draw: 3
+//! Start: k:draw=3;passes=unlimited;...
+//! Moves: 4
+//! D3 W-T4 T2.5-T6 T1-F
+//! ```
+//!
+//! Piles are numbered from 1: `Tn` is a tableau column (`Tn.k` the run from
+//! its k-th card up), `Cn` a free cell, `Fn` a foundation, `W` the waste and
+//! `Dn` a stock deal of n cards. `R` removes Pyramid cards (`P3` a pyramid
+//! slot, `W` the waste, joined by `+`), and `X:` records the board a cyclone
+//! shuffle produced.
+
+use crate::engine::boundary;
+use crate::engine::commands::EngineCommand;
+use crate::engine::seed_ops::{parse_seed_input, seed_label};
+use crate::engine::variant_state::VariantStateStore;
+use crate::game::{DrawMode, GameMode, PyramidCardRef};
+
+const REPLAY_HEADER: &str = "# Cardthropic replay";
+const MOVES_PER_LINE: usize = 12;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayStep {
+    Command(EngineCommand),
+    /// Cyclone shuffles are random, so the board they dealt is kept instead.
+    Reshuffle(String),
+}
+
+/// One game from its opening position. The seed and rules say what was
+/// dealt; the opening position travels with them so edited and PySol boards
+/// play back too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayLog {
+    pub mode: GameMode,
+    pub seed: u64,
+    /// The rule profile as statistics label it, e.g. `Deal 3, Unlimited passes`.
+    pub rules: String,
+    pub draw_mode: DrawMode,
+    start: String,
+    steps: Vec<ReplayStep>,
+}
+
+impl ReplayLog {
+    /// Starts a log at the mode's current board.
+    pub fn new(
+        state: &VariantStateStore,
+        mode: GameMode,
+        seed: u64,
+        rules: impl Into<String>,
+        draw_mode: DrawMode,
+    ) -> Self {
+        Self {
+            mode,
+            seed,
+            rules: rules.into(),
+            draw_mode,
+            start: state.encode_runtime_for_session(mode),
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn steps(&self) -> &[ReplayStep] {
+        &self.steps
+    }
+
+    /// Drops moves past `len`, as undo followed by a new move does.
+    pub fn truncate(&mut self, len: usize) {
+        self.steps.truncate(len);
+    }
+
+    /// Appends a command that just changed `state`.
+    pub fn record(&mut self, command: EngineCommand, state: &VariantStateStore) {
+        self.steps.push(match command {
+            EngineCommand::CycloneShuffleTableau => {
+                ReplayStep::Reshuffle(state.encode_runtime_for_session(self.mode))
+            }
+            command => ReplayStep::Command(command),
+        });
+    }
+
+    /// The opening position.
+    pub fn start_state(&self) -> Result<VariantStateStore, String> {
+        let runtime = VariantStateStore::decode_runtime_for_session(self.mode, &self.start)
+            .filter(|runtime| runtime.mode() == self.mode)
+            .ok_or_else(|| format!("the start position is not a {} board", self.mode.label()))?;
+        let mut state = VariantStateStore::new(self.seed);
+        state.set_runtime(runtime);
+        let _ = boundary::set_draw_mode(&mut state, self.mode, self.draw_mode);
+        Ok(state)
+    }
+
+    /// Plays step `index` on `state`. Returns `false` when it does not apply.
+    pub fn apply_step(&self, state: &mut VariantStateStore, index: usize) -> bool {
+        match self.steps.get(index) {
+            Some(ReplayStep::Command(command)) => {
+                boundary::execute_command(state, self.mode, *command).changed
+            }
+            Some(ReplayStep::Reshuffle(board)) => {
+                match VariantStateStore::decode_runtime_for_session(self.mode, board) {
+                    Some(runtime) if runtime.mode() == self.mode => {
+                        state.set_runtime(runtime);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            None => false,
+        }
+    }
+
+    /// The board after the first `steps` moves.
+    pub fn state_after(&self, steps: usize) -> Result<VariantStateStore, String> {
+        let mut state = self.start_state()?;
+        for index in 0..steps.min(self.steps.len()) {
+            if !self.apply_step(&mut state, index) {
+                return Err(format!(
+                    "move {} ({}) is not legal there",
+                    index + 1,
+                    step_token(&self.steps[index])
+                ));
+            }
+        }
+        Ok(state)
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.state_after(self.steps.len())
+            .is_ok_and(|state| boundary::is_won(&state, self.mode))
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "{REPLAY_HEADER}\nGame: {}\nSeed: {}\nRules: {}\nDraw: {}\nStart: {}\nMoves: {}\n",
+            self.mode.id(),
+            seed_label(self.seed),
+            self.rules,
+            self.draw_mode.count(),
+            self.start,
+            self.steps.len()
+        );
+        for line in self.steps.chunks(MOVES_PER_LINE) {
+            let tokens: Vec<String> = line.iter().map(step_token).collect();
+            text.push_str(&tokens.join(" "));
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Reads a log written by [`ReplayLog::to_text`] and checks that every
+    /// move plays from the start position.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut mode = None;
+        let mut seed = None;
+        let mut rules = String::new();
+        let mut draw_mode = DrawMode::One;
+        let mut start = None;
+        let mut expected_moves = None;
+        let mut steps = Vec::new();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if expected_moves.is_none() {
+                let Some((key, value)) = line.split_once(':') else {
+                    return Err(format!("expected a header line, found `{line}`"));
+                };
+                let value = value.trim();
+                match key.trim().to_ascii_lowercase().as_str() {
+                    "game" => {
+                        mode = Some(
+                            GameMode::from_id(value)
+                                .ok_or_else(|| format!("unknown game `{value}`"))?,
+                        );
+                    }
+                    "seed" => {
+                        seed = parse_seed_input(value)?;
+                    }
+                    "rules" => rules = value.to_string(),
+                    "draw" => {
+                        draw_mode = value
+                            .parse::<u8>()
+                            .ok()
+                            .and_then(DrawMode::from_count)
+                            .ok_or_else(|| format!("draw count `{value}` is not 1 to 5"))?;
+                    }
+                    "start" => start = Some(value.to_string()),
+                    "moves" => {
+                        expected_moves = Some(
+                            value
+                                .parse::<usize>()
+                                .map_err(|_| format!("move count `{value}` is not a number"))?,
+                        );
+                    }
+                    _ => return Err(format!("unknown header `{}`", key.trim())),
+                }
+                continue;
+            }
+            for token in line.split_whitespace() {
+                steps.push(
+                    parse_step_token(token).ok_or_else(|| format!("`{token}` is not a move"))?,
+                );
+            }
+        }
+
+        let mode = mode.ok_or_else(|| "the replay has no Game: line".to_string())?;
+        let seed = seed.ok_or_else(|| "the replay has no Seed: line".to_string())?;
+        let start = start.ok_or_else(|| "the replay has no Start: line".to_string())?;
+        let expected_moves =
+            expected_moves.ok_or_else(|| "the replay has no Moves: line".to_string())?;
+        if steps.len() != expected_moves {
+            return Err(format!(
+                "the replay lists {} moves but says it has {expected_moves}",
+                steps.len()
+            ));
+        }
+        let log = Self {
+            mode,
+            seed,
+            rules,
+            draw_mode,
+            start,
+            steps,
+        };
+        log.state_after(log.len())?;
+        Ok(log)
+    }
+}
+
+fn pyramid_ref_token(card: PyramidCardRef) -> String {
+    match card {
+        PyramidCardRef::Pyramid(slot) => format!("P{}", slot + 1),
+        PyramidCardRef::Waste => "W".to_string(),
+    }
+}
+
+fn step_token(step: &ReplayStep) -> String {
+    let command = match step {
+        ReplayStep::Command(command) => *command,
+        ReplayStep::Reshuffle(board) => return format!("X:{board}"),
+    };
+    match command {
+        EngineCommand::DrawOrRecycle { draw_mode } => format!("D{}", draw_mode.count()),
+        // Recorded logs keep the shuffled board; a bare X cannot be replayed.
+        EngineCommand::CycloneShuffleTableau => "X".to_string(),
+        EngineCommand::MoveWasteToFoundation => "W-F".to_string(),
+        EngineCommand::MoveWasteToTableau { dst } => format!("W-T{}", dst + 1),
+        EngineCommand::MoveTableauRunToTableau { src, start, dst } => {
+            format!("T{}.{}-T{}", src + 1, start + 1, dst + 1)
+        }
+        EngineCommand::MoveTableauTopToFoundation { src } => format!("T{}-F", src + 1),
+        EngineCommand::MoveTableauTopToFreecell { src, cell } => {
+            format!("T{}-C{}", src + 1, cell + 1)
+        }
+        EngineCommand::MoveFreecellToFoundation { cell } => format!("C{}-F", cell + 1),
+        EngineCommand::MoveFreecellToTableau { cell, dst } => {
+            format!("C{}-T{}", cell + 1, dst + 1)
+        }
+        EngineCommand::MoveFoundationTopToTableau {
+            foundation_idx,
+            dst,
+        } => format!("F{}-T{}", foundation_idx + 1, dst + 1),
+        EngineCommand::RemovePyramidCards { first, second } => match second {
+            Some(second) => format!(
+                "R{}+{}",
+                pyramid_ref_token(first),
+                pyramid_ref_token(second)
+            ),
+            None => format!("R{}", pyramid_ref_token(first)),
+        },
+        EngineCommand::PlayToWaste { slot } => format!("T{}-W", slot + 1),
+    }
+}
+
+/// `n` counted from 1, as an index.
+fn parse_pile_number(raw: &str) -> Option<usize> {
+    raw.parse::<usize>().ok()?.checked_sub(1)
+}
+
+fn parse_pyramid_ref(raw: &str) -> Option<PyramidCardRef> {
+    if raw == "W" {
+        return Some(PyramidCardRef::Waste);
+    }
+    raw.strip_prefix('P')
+        .and_then(parse_pile_number)
+        .map(PyramidCardRef::Pyramid)
+}
+
+fn parse_step_token(token: &str) -> Option<ReplayStep> {
+    if let Some(board) = token.strip_prefix("X:") {
+        return (!board.is_empty()).then(|| ReplayStep::Reshuffle(board.to_string()));
+    }
+    let command = if let Some(count) = token.strip_prefix('D') {
+        EngineCommand::DrawOrRecycle {
+            draw_mode: DrawMode::from_count(count.parse().ok()?)?,
+        }
+    } else if let Some(cards) = token.strip_prefix('R') {
+        match cards.split_once('+') {
+            Some((first, second)) => EngineCommand::RemovePyramidCards {
+                first: parse_pyramid_ref(first)?,
+                second: Some(parse_pyramid_ref(second)?),
+            },
+            None => EngineCommand::RemovePyramidCards {
+                first: parse_pyramid_ref(cards)?,
+                second: None,
+            },
+        }
+    } else {
+        let (from, to) = token.split_once('-')?;
+        let mut from_chars = from.chars();
+        let pile = from_chars.next()?;
+        let number = from_chars.as_str();
+        match (pile, to) {
+            ('W', "F") if number.is_empty() => EngineCommand::MoveWasteToFoundation,
+            ('W', _) if number.is_empty() => EngineCommand::MoveWasteToTableau {
+                dst: parse_pile_number(to.strip_prefix('T')?)?,
+            },
+            ('T', "F") => EngineCommand::MoveTableauTopToFoundation {
+                src: parse_pile_number(number)?,
+            },
+            ('T', "W") => EngineCommand::PlayToWaste {
+                slot: parse_pile_number(number)?,
+            },
+            ('T', _) if to.starts_with('C') => EngineCommand::MoveTableauTopToFreecell {
+                src: parse_pile_number(number)?,
+                cell: parse_pile_number(to.strip_prefix('C')?)?,
+            },
+            ('T', _) => {
+                let (src, start) = number.split_once('.')?;
+                EngineCommand::MoveTableauRunToTableau {
+                    src: parse_pile_number(src)?,
+                    start: parse_pile_number(start)?,
+                    dst: parse_pile_number(to.strip_prefix('T')?)?,
+                }
+            }
+            ('C', "F") => EngineCommand::MoveFreecellToFoundation {
+                cell: parse_pile_number(number)?,
+            },
+            ('C', _) => EngineCommand::MoveFreecellToTableau {
+                cell: parse_pile_number(number)?,
+                dst: parse_pile_number(to.strip_prefix('T')?)?,
+            },
+            ('F', _) => EngineCommand::MoveFoundationTopToTableau {
+                foundation_idx: parse_pile_number(number)?,
+                dst: parse_pile_number(to.strip_prefix('T')?)?,
+            },
+            _ => return None,
+        }
+    };
+    Some(ReplayStep::Command(command))
+}
@@ -0,0 +1,273 @@
+use crate::engine::game_mode::VariantRuntime;
+use crate::game::{DrawMode, GameMode};
+
+/// How a deal is scored. Cumulative Vegas carries the bank from one deal into
+/// the next instead of starting every game from the buy-in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScoringSystem {
+    Standard,
+    Vegas,
+    CumulativeVegas,
+}
+
+impl ScoringSystem {
+    pub const ALL: [ScoringSystem; 3] = [Self::Standard, Self::Vegas, Self::CumulativeVegas];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::Vegas => "Vegas",
+            Self::CumulativeVegas => "Cumulative Vegas",
+        }
+    }
+
+    pub fn as_setting(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Vegas => "vegas",
+            Self::CumulativeVegas => "cumulative-vegas",
+        }
+    }
+
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "vegas" => Self::Vegas,
+            "cumulative-vegas" => Self::CumulativeVegas,
+            _ => Self::Standard,
+        }
+    }
+
+    pub fn is_vegas(self) -> bool {
+        matches!(self, Self::Vegas | Self::CumulativeVegas)
+    }
+}
+
+/// Points awarded for each kind of progress in one variant under one system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreTable {
+    pub opening: i32,
+    pub per_move: i32,
+    pub to_foundation: i32,
+    pub from_foundation: i32,
+    pub waste_to_tableau: i32,
+    pub reveal: i32,
+    pub completed_run: i32,
+    pub recycle: i32,
+    /// Standard Klondike charges for time and pays a bonus for a fast win.
+    pub timed: bool,
+}
+
+impl ScoreTable {
+    const EMPTY: Self = Self {
+        opening: 0,
+        per_move: 0,
+        to_foundation: 0,
+        from_foundation: 0,
+        waste_to_tableau: 0,
+        reveal: 0,
+        completed_run: 0,
+        recycle: 0,
+        timed: false,
+    };
+}
+
+/// Card counts the score tables react to, read off a variant's runtime.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScoreFacts {
+    pub foundation_cards: usize,
+    pub face_down_cards: usize,
+    pub waste_cards: usize,
+    pub stock_cards: usize,
+    pub completed_runs: usize,
+}
+
+impl ScoreFacts {
+    pub fn from_runtime(runtime: &VariantRuntime) -> Option<Self> {
+        match runtime {
+            VariantRuntime::Klondike(game) => Some(Self {
+                foundation_cards: game.foundations().iter().map(Vec::len).sum(),
+                face_down_cards: game
+                    .tableau()
+                    .iter()
+                    .flatten()
+                    .filter(|card| !card.face_up)
+                    .count(),
+                waste_cards: game.waste_len(),
+                stock_cards: game.stock_len(),
+                completed_runs: 0,
+            }),
+            VariantRuntime::Spider(game) => Some(Self {
+                foundation_cards: game.completed_runs() * 13,
+                face_down_cards: game
+                    .tableau()
+                    .iter()
+                    .flatten()
+                    .filter(|card| !card.face_up)
+                    .count(),
+                waste_cards: 0,
+                stock_cards: game.stock_len(),
+                completed_runs: game.completed_runs(),
+            }),
+            VariantRuntime::Freecell(game)
+            | VariantRuntime::EightOff(game)
+            | VariantRuntime::SeahavenTowers(game)
+            | VariantRuntime::BakersGame(game) => Some(Self {
+                foundation_cards: game.foundations().iter().map(Vec::len).sum(),
+                ..Self::default()
+            }),
+            VariantRuntime::Yukon(_)
+            | VariantRuntime::Pyramid(_)
+            | VariantRuntime::TriPeaks(_)
+            | VariantRuntime::Golf(_)
+            | VariantRuntime::FortyThieves(_)
+            | VariantRuntime::Scorpion(_)
+            | VariantRuntime::Canfield(_)
+            | VariantRuntime::BakersDozen(_)
+            | VariantRuntime::BeleagueredCastle(_) => None,
+        }
+    }
+}
+
+/// Whether `mode` keeps a score at all.
+pub fn mode_is_scored(mode: GameMode) -> bool {
+    matches!(mode, GameMode::Klondike | GameMode::Spider) || mode.is_freecell_family()
+}
+
+/// Score table for the deal in `runtime`, or `None` for variants without one.
+pub fn table_for(
+    system: ScoringSystem,
+    runtime: &VariantRuntime,
+    draw_mode: DrawMode,
+) -> Option<ScoreTable> {
+    let deck_cards = match runtime {
+        VariantRuntime::Spider(game) => game.rule_set().decks() as i32 * 52,
+        VariantRuntime::Freecell(game)
+        | VariantRuntime::EightOff(game)
+        | VariantRuntime::SeahavenTowers(game)
+        | VariantRuntime::BakersGame(game) => i32::from(game.card_count_mode().card_count()),
+        _ => 52,
+    };
+    let mode = runtime.mode();
+    if system.is_vegas() {
+        return match mode {
+            GameMode::Klondike | GameMode::Spider => Some(ScoreTable {
+                opening: -deck_cards,
+                to_foundation: 5,
+                from_foundation: -5,
+                completed_run: if mode == GameMode::Spider { 65 } else { 0 },
+                ..ScoreTable::EMPTY
+            }),
+            _ if mode.is_freecell_family() => Some(ScoreTable {
+                opening: -deck_cards,
+                to_foundation: 5,
+                from_foundation: -5,
+                ..ScoreTable::EMPTY
+            }),
+            _ => None,
+        };
+    }
+    match mode {
+        GameMode::Klondike => Some(ScoreTable {
+            to_foundation: 10,
+            from_foundation: -15,
+            waste_to_tableau: 5,
+            reveal: 5,
+            recycle: if draw_mode == DrawMode::One {
+                -100
+            } else {
+                -20
+            },
+            timed: true,
+            ..ScoreTable::EMPTY
+        }),
+        GameMode::Spider => Some(ScoreTable {
+            opening: 500,
+            per_move: -1,
+            completed_run: 100,
+            ..ScoreTable::EMPTY
+        }),
+        _ if mode.is_freecell_family() => Some(ScoreTable {
+            to_foundation: 10,
+            from_foundation: -15,
+            ..ScoreTable::EMPTY
+        }),
+        _ => None,
+    }
+}
+
+/// Score a fresh deal starts from. Cumulative Vegas pays the buy-in out of
+/// the previous bank; a variant without a table keeps the bank untouched.
+pub fn opening_score(system: ScoringSystem, table: Option<ScoreTable>, carried: i32) -> i32 {
+    let opening = table.map(|table| table.opening).unwrap_or(0);
+    match system {
+        ScoringSystem::CumulativeVegas if table.is_some() => carried.saturating_add(opening),
+        ScoringSystem::CumulativeVegas => carried,
+        ScoringSystem::Standard | ScoringSystem::Vegas => opening,
+    }
+}
+
+/// Points earned by the move that turned `before` into `after`.
+pub fn move_delta(table: &ScoreTable, before: &ScoreFacts, after: &ScoreFacts) -> i32 {
+    let gained = |from: usize, to: usize| to as i32 - from as i32;
+    let mut delta = table.per_move;
+
+    let runs = gained(before.completed_runs, after.completed_runs);
+    delta += runs * table.completed_run;
+
+    // Spider runs reach the foundation whole and are paid as runs above.
+    let foundation = gained(before.foundation_cards, after.foundation_cards) - runs * 13;
+    if foundation > 0 {
+        delta += foundation * table.to_foundation;
+    } else {
+        delta -= foundation * table.from_foundation;
+    }
+
+    let revealed = gained(after.face_down_cards, before.face_down_cards);
+    if revealed > 0 {
+        delta += revealed * table.reveal;
+    }
+
+    if after.stock_cards > before.stock_cards && after.waste_cards < before.waste_cards {
+        delta += table.recycle;
+    } else {
+        let left_waste = gained(after.waste_cards, before.waste_cards);
+        if left_waste > 0 && foundation <= 0 {
+            delta += left_waste * table.waste_to_tableau;
+        }
+    }
+    delta
+}
+
+/// Stored score after a move worth `delta`. Standard scores never drop below
+/// zero, so points lost at zero are not owed back out of later gains.
+pub fn add_move_score(system: ScoringSystem, score: i32, delta: i32) -> i32 {
+    let score = score.saturating_add(delta);
+    if system == ScoringSystem::Standard {
+        score.max(0)
+    } else {
+        score
+    }
+}
+
+/// Score shown in the HUD: the stored `score`, less two points per ten
+/// seconds on timed tables, plus the speed bonus once the deal is won. The
+/// time charge takes a Standard score no lower than zero either.
+pub fn displayed_score(
+    system: ScoringSystem,
+    table: &ScoreTable,
+    score: i32,
+    elapsed_seconds: u32,
+    won: bool,
+) -> i32 {
+    if !table.timed {
+        return score;
+    }
+    let mut shown = score - 2 * (elapsed_seconds / 10) as i32;
+    if system == ScoringSystem::Standard {
+        shown = shown.max(0);
+    }
+    if won && elapsed_seconds >= 30 {
+        shown += (700_000 / elapsed_seconds) as i32;
+    }
+    shown
+}
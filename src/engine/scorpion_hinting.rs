@@ -0,0 +1,112 @@
+use crate::engine::hinting::HintNode;
+use crate::engine::moves::{map_solver_move_to_hint_move, HintMove};
+use crate::game::{ScorpionGame, ScorpionSolveResult, SolverMove};
+
+#[derive(Debug, Clone)]
+pub struct ScorpionHintSuggestion {
+    pub message: String,
+    pub source: Option<HintNode>,
+    pub target: Option<HintNode>,
+    pub hint_move: Option<HintMove>,
+}
+
+/// Source and target highlights for a move. The reserve deal points at the
+/// first column it lands on.
+pub fn hint_nodes_for_move(solver_move: SolverMove) -> (HintNode, HintNode) {
+    match solver_move {
+        SolverMove::TableauRunToTableau { src, start, dst } => (
+            HintNode::Tableau {
+                col: src,
+                index: Some(start),
+            },
+            HintNode::Tableau {
+                col: dst,
+                index: None,
+            },
+        ),
+        SolverMove::Draw
+        | SolverMove::WasteToFoundation
+        | SolverMove::WasteToTableau { .. }
+        | SolverMove::TableauTopToFoundation { .. } => (
+            HintNode::Stock,
+            HintNode::Tableau {
+                col: 0,
+                index: None,
+            },
+        ),
+    }
+}
+
+pub fn describe_move(game: &ScorpionGame, solver_move: SolverMove) -> String {
+    match solver_move {
+        SolverMove::TableauRunToTableau { src, start, dst } => {
+            let amount = game.tableau()[src].len().saturating_sub(start);
+            let card = game
+                .tableau_card(src, start)
+                .map(|card| card.label())
+                .unwrap_or_else(|| "?".to_string());
+            if amount > 1 {
+                format!(
+                    "Move {amount} cards from {card} on T{} to T{}.",
+                    src + 1,
+                    dst + 1
+                )
+            } else {
+                format!("Move {card} from T{} to T{}.", src + 1, dst + 1)
+            }
+        }
+        SolverMove::Draw
+        | SolverMove::WasteToFoundation
+        | SolverMove::WasteToTableau { .. }
+        | SolverMove::TableauTopToFoundation { .. } => {
+            "Deal the reserve onto the first three columns.".to_string()
+        }
+    }
+}
+
+/// Suggests the first move of a solver line, falling back to the solver's
+/// move ordering when the search finds no win within `max_states`.
+pub fn suggest_move(game: &ScorpionGame, max_states: usize) -> ScorpionHintSuggestion {
+    if game.is_won() {
+        return ScorpionHintSuggestion {
+            message: "Hint: game already won.".to_string(),
+            source: None,
+            target: None,
+            hint_move: None,
+        };
+    }
+    let result = game.solve(max_states);
+    suggestion_from_solve(game, &result)
+}
+
+pub fn suggestion_from_solve(
+    game: &ScorpionGame,
+    result: &ScorpionSolveResult,
+) -> ScorpionHintSuggestion {
+    let (solver_move, prefix) = match result.line.first() {
+        Some(first) => (Some(*first), "Hint: winning line found. "),
+        None if result.hit_state_limit => (
+            game.solver_moves().first().copied(),
+            "Hint: no win found yet. ",
+        ),
+        None => (
+            game.solver_moves().first().copied(),
+            "Hint: this deal can no longer be won. ",
+        ),
+    };
+    let Some(solver_move) = solver_move else {
+        return ScorpionHintSuggestion {
+            message: "Hint: no legal moves. Start a new deal or undo.".to_string(),
+            source: None,
+            target: None,
+            hint_move: None,
+        };
+    };
+    let (source, target) = hint_nodes_for_move(solver_move);
+    ScorpionHintSuggestion {
+        message: format!("{prefix}{}", describe_move(game, solver_move)),
+        source: Some(source),
+        target: Some(target),
+        hint_move: Some(map_solver_move_to_hint_move(solver_move)),
+    }
+}
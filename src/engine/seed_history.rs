@@ -1,6 +1,8 @@
 use std::cmp::Reverse;
 use std::collections::HashMap;
 
+use crate::game::DEAL_ALGORITHM;
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct SeedHistoryStats {
     pub plays: u32,
@@ -29,6 +31,12 @@ impl SeedHistoryStore {
             let Some(wins_raw) = parts.next() else {
                 continue;
             };
+            // Rows from before seeds were tagged are bare numbers.
+            let seed_raw = match seed_raw.split_once(':') {
+                Some((tag, rest)) if tag == format!("v{DEAL_ALGORITHM}") => rest,
+                Some(_) => continue,
+                None => seed_raw,
+            };
             let Ok(seed) = seed_raw.parse::<u64>() else {
                 continue;
             };
@@ -69,7 +77,7 @@ impl SeedHistoryStore {
         for (seed, stats) in rows {
             let wins = stats.wins.min(stats.plays);
             serialized.push_str(&format!(
-                "{seed} {} {wins} {}\n",
+                "v{DEAL_ALGORITHM}:{seed} {} {wins} {}\n",
                 stats.plays, stats.last_play_order
             ));
         }
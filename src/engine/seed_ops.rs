@@ -1,4 +1,11 @@
+use crate::game::{
+    is_supported_deal_algorithm, microsoft_deal_number, microsoft_deal_seed, DEAL_ALGORITHM,
+    MICROSOFT_DEAL_MAX,
+};
+
 pub const WORD_SEED_MAX_LEN: usize = 32;
+/// Prefix for Microsoft FreeCell game numbers, as in `ms:11982`.
+pub const MICROSOFT_DEAL_PREFIX: &str = "ms:";
 
 const FNV1A64_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
 const FNV1A64_PRIME: u64 = 0x00000100000001b3;
@@ -18,6 +25,34 @@ pub fn parse_seed_input(input: &str) -> Result<Option<u64>, String> {
         return Ok(None);
     }
 
+    if let Some((algorithm, rest)) = split_deal_algorithm(trimmed) {
+        if !is_supported_deal_algorithm(algorithm) {
+            return Err(format!(
+                "Seed uses deal algorithm {algorithm}; this build only deals with algorithm {DEAL_ALGORITHM}."
+            ));
+        }
+        if rest.trim().is_empty() {
+            return Err("Seed is missing after the deal algorithm.".to_string());
+        }
+        return parse_seed_input(rest);
+    }
+
+    if let Some(number) = trimmed
+        .to_ascii_lowercase()
+        .strip_prefix(MICROSOFT_DEAL_PREFIX)
+    {
+        return number
+            .trim()
+            .replace('_', "")
+            .parse::<u64>()
+            .ok()
+            .and_then(microsoft_deal_seed)
+            .map(Some)
+            .ok_or_else(|| {
+                format!("Microsoft deal numbers run from ms:1 to ms:{MICROSOFT_DEAL_MAX}.")
+            });
+    }
+
     let normalized = trimmed.replace('_', "");
     if normalized.chars().all(|ch| ch.is_ascii_digit()) {
         return normalized.parse::<u64>().map(Some).map_err(|_| {
@@ -35,12 +70,39 @@ pub fn parse_seed_input(input: &str) -> Result<Option<u64>, String> {
     }
 
     Err(format!(
-        "Seed must be either a number (u64, underscores allowed) or a word (A-Z letters only, underscores allowed, max {WORD_SEED_MAX_LEN})."
+        "Seed must be a number (u64, underscores allowed), a word (A-Z letters only, underscores allowed, max {WORD_SEED_MAX_LEN}) or a Microsoft deal number (ms:1 to ms:{MICROSOFT_DEAL_MAX})."
     ))
 }
 
+/// Splits `v<N>:rest`, which pins the deal algorithm a seed was shared under,
+/// into `(N, rest)`. Bare seeds deal with the current algorithm.
+fn split_deal_algorithm(input: &str) -> Option<(u8, &str)> {
+    let (tag, rest) = input.split_once(':')?;
+    let digits = tag.strip_prefix(['v', 'V'])?;
+    if digits.is_empty() || !digits.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+    Some((digits.parse::<u8>().unwrap_or(u8::MAX), rest))
+}
+
+/// Never lands on a Microsoft deal number, so random FreeCell deals stay
+/// shuffled.
 pub fn random_seed() -> u64 {
-    rand::random()
+    loop {
+        let seed = rand::random();
+        if microsoft_deal_number(seed).is_none() {
+            return seed;
+        }
+    }
+}
+
+/// How a seed is shown, copied and stored: tagged with the deal algorithm, then
+/// `ms:N` for Microsoft deals, else the number.
+pub fn seed_label(seed: u64) -> String {
+    match microsoft_deal_number(seed) {
+        Some(game_number) => format!("v{DEAL_ALGORITHM}:{MICROSOFT_DEAL_PREFIX}{game_number}"),
+        None => format!("v{DEAL_ALGORITHM}:{seed}"),
+    }
 }
 
 pub fn seed_from_text_or_random(input: &str) -> Result<u64, String> {
@@ -70,11 +132,11 @@ pub fn msg_seed_search_still_running() -> String {
 }
 
 pub fn msg_started_seed(seed: u64) -> String {
-    format!("Started a new game. Seed {seed}.")
+    format!("Started a new game. Seed {}.", seed_label(seed))
 }
 
 pub fn msg_repeated_seed(seed: u64) -> String {
-    format!("Dealt again. Seed {seed}.")
+    format!("Dealt again. Seed {}.", seed_label(seed))
 }
 
 pub fn msg_winnability_check_canceled(deal_count: u8) -> String {
@@ -92,18 +154,21 @@ pub fn msg_winnability_check_stopped_unexpectedly(deal_count: u8) -> String {
 }
 
 pub fn msg_seed_winnable(seed: u64, deal_count: u8, moves: u32, iterations: usize) -> String {
+    let seed = seed_label(seed);
     format!(
         "Seed {seed} is winnable for Deal {deal_count} from a fresh deal (solver line: {moves} moves, {iterations} iterations). Use Robot as first action to see win."
     )
 }
 
 pub fn msg_seed_unwinnable_limited(seed: u64, deal_count: u8, iterations: usize) -> String {
+    let seed = seed_label(seed);
     format!(
         "Seed {seed} not proven winnable for Deal {deal_count} from a fresh deal ({iterations} iterations, limits hit)."
     )
 }
 
 pub fn msg_seed_unwinnable(seed: u64, deal_count: u8, iterations: usize) -> String {
+    let seed = seed_label(seed);
     format!(
         "Seed {seed}: solver found no winning line for Deal {deal_count} from a fresh deal ({iterations} iterations)."
     )
@@ -115,18 +180,21 @@ pub fn msg_searching_winnable_seed(
     attempts: u32,
     max_states: usize,
 ) -> String {
+    let start_seed = seed_label(start_seed);
     format!(
         "Searching Deal {deal_count} winnable seed from {start_seed} (attempts: {attempts}, state budget: {max_states})..."
     )
 }
 
 pub fn msg_started_winnable_seed(seed: u64, deal_count: u8, tested: u32) -> String {
+    let seed = seed_label(seed);
     format!(
         "Started Deal {deal_count} winnable game. Seed {seed} (checked {tested} seed(s)). Use Robot as first action to see win."
     )
 }
 
 pub fn msg_no_winnable_seed(start_seed: u64, deal_count: u8, attempts: u32) -> String {
+    let start_seed = seed_label(start_seed);
     format!(
         "No Deal {deal_count} winnable seed found in {attempts} attempt(s) from seed {start_seed}."
     )
@@ -2,7 +2,10 @@ use std::collections::HashMap;
 
 use crate::engine::game_mode::VariantRuntime;
 use crate::engine::variant_state::VariantStateStore;
-use crate::game::{DrawMode, FreecellCardCountMode, GameMode, KlondikeGame};
+use crate::game::{
+    is_supported_deal_algorithm, DrawMode, FreecellCardCountMode, FreecellLayout, GameMode,
+    KlondikeGame, DEAL_ALGORITHM,
+};
 
 #[derive(Debug, Clone)]
 pub struct PersistedSession {
@@ -26,14 +29,18 @@ pub fn encode_persisted_session(
     klondike_draw_mode: DrawMode,
 ) -> String {
     format!(
-        "v=2\nseed={}\nmode={}\nmoves={}\nelapsed={}\ntimer={}\ndraw={}\nfreecell-card-count={}\nruntime={}",
+        "v=2\nseed={}\ndeal={}\nmode={}\nmoves={}\nelapsed={}\ntimer={}\ndraw={}\nfreecell-card-count={}\nruntime={}",
         seed,
+        DEAL_ALGORITHM,
         mode.id(),
         move_count,
         elapsed_seconds,
         if timer_started { 1 } else { 0 },
         klondike_draw_mode.count(),
-        state.freecell().card_count_mode().card_count(),
+        state
+            .freecell_for(FreecellLayout::FreeCell)
+            .card_count_mode()
+            .card_count(),
         state.encode_runtime_for_session(mode),
     )
 }
@@ -41,7 +48,20 @@ pub fn encode_persisted_session(
 fn derive_freecell_card_count_mode(runtime: &VariantRuntime) -> FreecellCardCountMode {
     match runtime {
         VariantRuntime::Freecell(game) => game.card_count_mode(),
-        VariantRuntime::Klondike(_) | VariantRuntime::Spider(_) => FreecellCardCountMode::FiftyTwo,
+        VariantRuntime::Klondike(_)
+        | VariantRuntime::Spider(_)
+        | VariantRuntime::Yukon(_)
+        | VariantRuntime::Pyramid(_)
+        | VariantRuntime::TriPeaks(_)
+        | VariantRuntime::Golf(_)
+        | VariantRuntime::FortyThieves(_)
+        | VariantRuntime::Scorpion(_)
+        | VariantRuntime::Canfield(_)
+        | VariantRuntime::BakersDozen(_)
+        | VariantRuntime::BeleagueredCastle(_)
+        | VariantRuntime::EightOff(_)
+        | VariantRuntime::SeahavenTowers(_)
+        | VariantRuntime::BakersGame(_) => FreecellCardCountMode::FiftyTwo,
     }
 }
 
@@ -56,6 +76,14 @@ pub fn decode_persisted_session(raw: &str) -> Option<PersistedSession> {
 
     let version = fields.get("v").copied()?;
     let seed = fields.get("seed")?.parse::<u64>().ok()?;
+    // Sessions saved before the field existed were all dealt by algorithm 1.
+    let deal_algorithm = match fields.get("deal") {
+        Some(raw) => raw.parse::<u8>().ok()?,
+        None => 1,
+    };
+    if !is_supported_deal_algorithm(deal_algorithm) {
+        return None;
+    }
     let mode = GameMode::from_id(fields.get("mode")?)?;
     let move_count = fields.get("moves")?.parse::<u32>().ok()?;
     let elapsed_seconds = fields.get("elapsed")?.parse::<u32>().ok()?;
@@ -1,7 +1,7 @@
 use crate::engine::boundary;
 use crate::engine::moves::HintMove;
 use crate::engine::variant_state::VariantStateStore;
-use crate::game::GameMode;
+use crate::game::{GameMode, BAKERS_DOZEN_COLUMNS};
 
 pub fn direct_tableau_to_foundation_move(
     state: &VariantStateStore,
@@ -35,8 +35,12 @@ pub fn fallback_tableau_run_move(
     start: usize,
 ) -> Option<HintMove> {
     let tableau_columns = match mode {
-        GameMode::Spider => 10,
-        GameMode::Freecell => 8,
+        GameMode::BakersDozen => BAKERS_DOZEN_COLUMNS,
+        GameMode::Spider | GameMode::FortyThieves | GameMode::SeahavenTowers => 10,
+        GameMode::Freecell
+        | GameMode::EightOff
+        | GameMode::BakersGame
+        | GameMode::BeleagueredCastle => 8,
         _ => 7,
     };
     (0..tableau_columns)
@@ -53,8 +57,12 @@ pub fn fallback_waste_to_tableau_move(
     mode: GameMode,
 ) -> Option<HintMove> {
     let tableau_columns = match mode {
-        GameMode::Spider => 10,
-        GameMode::Freecell => 8,
+        GameMode::BakersDozen => BAKERS_DOZEN_COLUMNS,
+        GameMode::Spider | GameMode::FortyThieves | GameMode::SeahavenTowers => 10,
+        GameMode::Freecell
+        | GameMode::EightOff
+        | GameMode::BakersGame
+        | GameMode::BeleagueredCastle => 8,
         _ => 7,
     };
     (0..tableau_columns)
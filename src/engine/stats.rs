@@ -0,0 +1,390 @@
+use std::collections::HashMap;
+
+use crate::engine::variant_state::VariantStateStore;
+use crate::game::{
+    DrawMode, FreecellLayout, GameMode, KlondikePassLimit, SpiderRuleSet, SpiderSuitMode,
+    WasteBuildLayout, YukonRuleSet,
+};
+
+const KLONDIKE_PASS_LIMITS: [KlondikePassLimit; 3] = [
+    KlondikePassLimit::Unlimited,
+    KlondikePassLimit::Three,
+    KlondikePassLimit::Single,
+];
+const SPIDER_RULE_SETS: [SpiderRuleSet; 4] = [
+    SpiderRuleSet::Spider,
+    SpiderRuleSet::Spiderette,
+    SpiderRuleSet::WillOTheWisp,
+    SpiderRuleSet::Tarantula,
+];
+const YUKON_RULE_SETS: [YukonRuleSet; 2] = [YukonRuleSet::Yukon, YukonRuleSet::Russian];
+
+/// Identifies one statistics table: a variant plus the rule choices that make
+/// its deals comparable (draw count, suits, card and cell counts, ...).
+/// `rules` holds the [`rules_id`], never a display label.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatsKey {
+    pub mode: GameMode,
+    pub rules: String,
+}
+
+impl StatsKey {
+    pub fn new(mode: GameMode, rules: impl Into<String>) -> Self {
+        Self {
+            mode,
+            rules: rules.into(),
+        }
+    }
+
+    pub fn for_game(game: &VariantStateStore, mode: GameMode, klondike_draw: DrawMode) -> Self {
+        Self::new(mode, rules_id(game, mode, klondike_draw))
+    }
+
+    /// A key read back from settings. Rows saved before keys used rule ids
+    /// hold the display label, which maps onto the same id.
+    pub fn from_stored(mode: GameMode, rules: &str) -> Self {
+        let rules = legacy_rules_id(mode, rules).unwrap_or_else(|| rules.to_string());
+        Self::new(mode, rules)
+    }
+
+    /// `Deal 3, Unlimited`, `Spiderette, 2 Suits`, ... for tables and status
+    /// lines. An id this build does not know is shown as is.
+    pub fn rules_label(&self) -> String {
+        let field = |name: &str| {
+            self.rules
+                .split(';')
+                .find_map(|part| part.strip_prefix(name)?.strip_prefix('='))
+        };
+        let label = match self.mode {
+            GameMode::Klondike => field("draw")
+                .zip(field("passes").and_then(KlondikePassLimit::from_session_tag))
+                .map(|(draw, passes)| {
+                    format!(
+                        "Deal {draw}, {}{}",
+                        passes.label(),
+                        if field("thoughtful") == Some("1") {
+                            ", Thoughtful"
+                        } else {
+                            ""
+                        }
+                    )
+                }),
+            GameMode::Spider => field("rules")
+                .and_then(SpiderRuleSet::from_session_tag)
+                .zip(field("mode").and_then(SpiderSuitMode::from_session_tag))
+                .map(|(rule_set, suit_mode)| {
+                    let suits = suit_mode.suit_count();
+                    format!(
+                        "{}, {} Suit{}",
+                        rule_set.label(),
+                        suits,
+                        if suits == 1 { "" } else { "s" }
+                    )
+                }),
+            GameMode::Yukon => field("rules")
+                .and_then(YukonRuleSet::from_session_tag)
+                .map(|rule_set| rule_set.label().to_string()),
+            _ if self.rules == "standard" => Some("Standard".to_string()),
+            _ if FreecellLayout::for_mode(self.mode).is_some() => field("cards")
+                .zip(field("fc"))
+                .map(|(cards, cells)| format!("{cards} Cards, {cells} Cells")),
+            _ => match field("wrap") {
+                Some("1") => Some("Wrap King\u{2013}Ace".to_string()),
+                Some("0") => Some("Standard".to_string()),
+                _ => None,
+            },
+        };
+        label.unwrap_or_else(|| self.rules.clone())
+    }
+}
+
+/// Stable id of the rule choices a `mode` deal is played under, built from
+/// the same tags the session codec persists so relabelling a menu entry
+/// never changes it. Streak scoring only changes the score, so it is left
+/// out.
+pub fn rules_id(game: &VariantStateStore, mode: GameMode, klondike_draw: DrawMode) -> String {
+    match mode {
+        GameMode::Klondike => format!(
+            "draw={};passes={};thoughtful={}",
+            klondike_draw.count(),
+            game.klondike().pass_limit().session_tag(),
+            u8::from(game.klondike().is_thoughtful())
+        ),
+        GameMode::Spider => format!(
+            "rules={};mode={}",
+            game.spider().rule_set().session_tag(),
+            game.spider().suit_mode().session_tag()
+        ),
+        GameMode::Yukon => format!("rules={}", game.yukon().rule_set().session_tag()),
+        _ => {
+            if let Some(layout) = FreecellLayout::for_mode(mode) {
+                let freecell = game.freecell_for(layout);
+                format!(
+                    "cards={};fc={}",
+                    freecell.card_count_mode().card_count(),
+                    freecell.freecell_count()
+                )
+            } else if let Some(layout) = WasteBuildLayout::for_mode(mode) {
+                format!(
+                    "wrap={}",
+                    u8::from(game.waste_build(layout).rules().wrap_ranks)
+                )
+            } else {
+                "standard".to_string()
+            }
+        }
+    }
+}
+
+/// Maps a display label stored by an older build onto its rules id, or
+/// `None` when `label` is already an id or unrecognised.
+fn legacy_rules_id(mode: GameMode, label: &str) -> Option<String> {
+    let parts: Vec<&str> = label.split(", ").collect();
+    match mode {
+        GameMode::Klondike => {
+            let draw = parts.first()?.strip_prefix("Deal ")?.parse::<u8>().ok()?;
+            let passes = KLONDIKE_PASS_LIMITS
+                .into_iter()
+                .find(|passes| parts.get(1) == Some(&passes.label()))?;
+            let thoughtful = match parts.get(2..)? {
+                [] => false,
+                ["Thoughtful"] => true,
+                _ => return None,
+            };
+            Some(format!(
+                "draw={draw};passes={};thoughtful={}",
+                passes.session_tag(),
+                u8::from(thoughtful)
+            ))
+        }
+        GameMode::Spider => {
+            let [rule_set, suits] = parts[..] else {
+                return None;
+            };
+            let rule_set = SPIDER_RULE_SETS
+                .into_iter()
+                .find(|candidate| candidate.label() == rule_set)?;
+            let suits = suits.split(' ').next()?.parse::<u8>().ok()?;
+            let suit_mode = SpiderSuitMode::from_suit_count(suits)?;
+            Some(format!(
+                "rules={};mode={}",
+                rule_set.session_tag(),
+                suit_mode.session_tag()
+            ))
+        }
+        GameMode::Yukon => YUKON_RULE_SETS
+            .into_iter()
+            .find(|rule_set| rule_set.label() == label)
+            .map(|rule_set| format!("rules={}", rule_set.session_tag())),
+        // Older builds kept no wrap setting apart; those games were dealt
+        // with it off.
+        _ if label == "Standard" => Some(
+            if WasteBuildLayout::for_mode(mode).is_some() {
+                "wrap=0"
+            } else {
+                "standard"
+            }
+            .to_string(),
+        ),
+        _ if FreecellLayout::for_mode(mode).is_some() => {
+            let [cards, cells] = parts[..] else {
+                return None;
+            };
+            let cards = cards.strip_suffix(" Cards")?.parse::<u8>().ok()?;
+            let cells = cells.strip_suffix(" Cells")?.parse::<u8>().ok()?;
+            Some(format!("cards={cards};fc={cells}"))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VariantStats {
+    pub played: u32,
+    pub won: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub fastest_win_seconds: Option<u32>,
+    pub fewest_moves: Option<u32>,
+    pub win_moves_total: u64,
+    pub win_seconds_total: u64,
+    /// A game was started and has not been won yet; starting another one
+    /// counts it as lost and breaks the streak.
+    pub in_progress: bool,
+}
+
+impl VariantStats {
+    pub fn win_rate_percent(&self) -> f64 {
+        if self.played == 0 {
+            0.0
+        } else {
+            f64::from(self.won) * 100.0 / f64::from(self.played)
+        }
+    }
+
+    /// Average actions per minute across won games.
+    pub fn average_apm(&self) -> Option<f64> {
+        (self.win_seconds_total > 0)
+            .then(|| self.win_moves_total as f64 * 60.0 / self.win_seconds_total as f64)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct VariantStatsStore {
+    entries: HashMap<StatsKey, VariantStats>,
+}
+
+impl VariantStatsStore {
+    pub fn load_from_string(contents: &str) -> Self {
+        let mut store = Self::default();
+        for line in contents.lines() {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [mode, rules, played, won, current, best, fastest, fewest, moves, seconds, pending] =
+                fields[..]
+            else {
+                continue;
+            };
+            let Some(mode) = GameMode::from_id(mode) else {
+                continue;
+            };
+            let (Ok(played), Ok(won), Ok(current_streak), Ok(best_streak)) = (
+                played.parse::<u32>(),
+                won.parse::<u32>(),
+                current.parse::<u32>(),
+                best.parse::<u32>(),
+            ) else {
+                continue;
+            };
+            let (Ok(win_moves_total), Ok(win_seconds_total)) =
+                (moves.parse::<u64>(), seconds.parse::<u64>())
+            else {
+                continue;
+            };
+            store.entries.insert(
+                StatsKey::from_stored(mode, rules),
+                VariantStats {
+                    played,
+                    won: won.min(played),
+                    current_streak,
+                    best_streak: best_streak.max(current_streak),
+                    fastest_win_seconds: fastest.parse::<u32>().ok(),
+                    fewest_moves: fewest.parse::<u32>().ok(),
+                    win_moves_total,
+                    win_seconds_total,
+                    in_progress: pending == "1",
+                },
+            );
+        }
+        store
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut serialized = String::new();
+        for (key, stats) in self.rows() {
+            let optional = |value: Option<u32>| {
+                value
+                    .map(|value| value.to_string())
+                    .unwrap_or_else(|| "-".to_string())
+            };
+            serialized.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                key.mode.id(),
+                key.rules,
+                stats.played,
+                stats.won,
+                stats.current_streak,
+                stats.best_streak,
+                optional(stats.fastest_win_seconds),
+                optional(stats.fewest_moves),
+                stats.win_moves_total,
+                stats.win_seconds_total,
+                if stats.in_progress { 1 } else { 0 },
+            ));
+        }
+        serialized
+    }
+
+    /// Counts a new game. An earlier game under the same key that was never
+    /// won is taken as lost.
+    pub fn note_game_started(&mut self, key: StatsKey) {
+        let stats = self.entries.entry(key).or_default();
+        if stats.in_progress {
+            stats.current_streak = 0;
+        }
+        stats.played = stats.played.saturating_add(1);
+        stats.in_progress = true;
+    }
+
+    pub fn note_win(&mut self, key: StatsKey, elapsed_seconds: u32, moves: u32) {
+        let stats = self.entries.entry(key).or_default();
+        if stats.played == 0 {
+            stats.played = 1;
+        }
+        stats.won = stats.won.saturating_add(1).min(stats.played);
+        stats.current_streak = stats.current_streak.saturating_add(1);
+        stats.best_streak = stats.best_streak.max(stats.current_streak);
+        stats.fastest_win_seconds = Some(
+            stats
+                .fastest_win_seconds
+                .map_or(elapsed_seconds, |best| best.min(elapsed_seconds)),
+        );
+        stats.fewest_moves = Some(stats.fewest_moves.map_or(moves, |best| best.min(moves)));
+        stats.win_moves_total = stats.win_moves_total.saturating_add(u64::from(moves));
+        stats.win_seconds_total = stats
+            .win_seconds_total
+            .saturating_add(u64::from(elapsed_seconds));
+        stats.in_progress = false;
+    }
+
+    pub fn is_in_progress(&self, key: &StatsKey) -> bool {
+        self.entries
+            .get(key)
+            .map(|stats| stats.in_progress)
+            .unwrap_or(false)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries ordered by variant label, then rules.
+    pub fn rows(&self) -> Vec<(&StatsKey, &VariantStats)> {
+        let mut rows: Vec<_> = self.entries.iter().collect();
+        rows.sort_by(|(a, _), (b, _)| {
+            (a.mode.label(), a.rules_label()).cmp(&(b.mode.label(), b.rules_label()))
+        });
+        rows
+    }
+
+    pub fn export_csv(&self) -> String {
+        let mut lines = vec![
+            "variant,rules,played,won,win_percent,current_streak,best_streak,fastest_win_seconds,fewest_moves,average_apm"
+                .to_string(),
+        ];
+        for (key, stats) in self.rows() {
+            lines.push(format!(
+                "{},\"{}\",{},{},{:.1},{},{},{},{},{}",
+                key.mode.label(),
+                key.rules_label().replace('"', "\"\""),
+                stats.played,
+                stats.won,
+                stats.win_rate_percent(),
+                stats.current_streak,
+                stats.best_streak,
+                stats
+                    .fastest_win_seconds
+                    .map(|value| value.to_string())
+                    .unwrap_or_default(),
+                stats
+                    .fewest_moves
+                    .map(|value| value.to_string())
+                    .unwrap_or_default(),
+                stats
+                    .average_apm()
+                    .map(|apm| format!("{apm:.1}"))
+                    .unwrap_or_default(),
+            ));
+        }
+        lines.join("\n")
+    }
+}
@@ -1,20 +1,34 @@
+use crate::engine::daily::{
+    candidate_seed, ChallengeDate, DailyChallengeStore, DailyOutcome, DAILY_CANDIDATES,
+};
+use crate::engine::game_mode::VariantRuntime;
 use crate::engine::moves::HintMove;
+use crate::engine::replay::ReplayLog;
 use crate::engine::robot::RobotPlayback;
+use crate::engine::scoring::{self, ScoreFacts, ScoringSystem};
 use crate::engine::session::{decode_persisted_session, encode_persisted_session};
 use crate::engine::smart_move;
+use crate::engine::stats::{self, StatsKey, VariantStatsStore};
 use crate::engine::variant::{
     all_variant_specs, all_variants, spec_for_id, spec_for_mode, variant_for_mode,
 };
 use crate::engine::variant_engine::{all_engines, engine_for_mode};
 use crate::engine::variant_state::VariantStateStore;
 use crate::engine::{
-    automation::AutomationProfile, automation::FREECELL_AUTOMATION_PROFILE,
-    automation::KLONDIKE_AUTOMATION_PROFILE, automation::SPIDER_AUTOMATION_PROFILE,
+    automation::AutomationProfile, automation::CANFIELD_AUTOMATION_PROFILE,
+    automation::FORTY_THIEVES_AUTOMATION_PROFILE, automation::FREECELL_AUTOMATION_PROFILE,
+    automation::KLONDIKE_AUTOMATION_PROFILE, automation::OPEN_TABLEAU_AUTOMATION_PROFILE,
+    automation::PYRAMID_AUTOMATION_PROFILE, automation::SCORPION_AUTOMATION_PROFILE,
+    automation::SPIDER_AUTOMATION_PROFILE, automation::WASTE_BUILD_AUTOMATION_PROFILE,
+    automation::YUKON_AUTOMATION_PROFILE,
 };
-use crate::engine::{boundary, commands::EngineCommand};
+use crate::engine::{boundary, commands::EngineCommand, open_tableau_planner};
 use crate::game::{
-    Card, DrawMode, FreecellCardCountMode, FreecellGame, GameMode, KlondikeGame, SpiderGame,
-    SpiderSuitMode, Suit,
+    CanfieldGame, Card, DrawMode, DrawResult, FortyThievesGame, FreecellCardCountMode,
+    FreecellGame, FreecellLayout, GameMode, KlondikeGame, KlondikePassLimit, OpenTableauGame,
+    OpenTableauLayout, PyramidCardRef, PyramidGame, PyramidMove, ScorpionGame, SpiderGame,
+    SpiderRuleSet, SpiderSuitMode, Suit, TextBoard, WasteBuildGame, WasteBuildLayout,
+    WasteBuildMove, WasteBuildRules, YukonGame, YukonRuleSet,
 };
 
 #[test]
@@ -52,7 +66,23 @@ fn robot_playback_scripted_line_lifecycle() {
 
 #[test]
 fn automation_profile_is_defined_per_mode() {
-    for mode in [GameMode::Klondike, GameMode::Spider, GameMode::Freecell] {
+    for mode in [
+        GameMode::Klondike,
+        GameMode::Spider,
+        GameMode::Freecell,
+        GameMode::Yukon,
+        GameMode::Pyramid,
+        GameMode::TriPeaks,
+        GameMode::Golf,
+        GameMode::FortyThieves,
+        GameMode::Scorpion,
+        GameMode::Canfield,
+        GameMode::BakersDozen,
+        GameMode::BeleagueredCastle,
+        GameMode::EightOff,
+        GameMode::SeahavenTowers,
+        GameMode::BakersGame,
+    ] {
         let profile = AutomationProfile::for_mode(mode);
         assert!(profile.auto_play_node_budget > 0);
         assert!(profile.auto_play_beam_width > 0);
@@ -125,13 +155,41 @@ fn variant_engine_registry_matches_modes() {
     }
     assert_eq!(registered_modes.len(), all_variant_specs().len());
 
-    for mode in [GameMode::Klondike, GameMode::Spider, GameMode::Freecell] {
+    for mode in [
+        GameMode::Klondike,
+        GameMode::Spider,
+        GameMode::Freecell,
+        GameMode::Yukon,
+        GameMode::Pyramid,
+        GameMode::TriPeaks,
+        GameMode::Golf,
+        GameMode::FortyThieves,
+        GameMode::Scorpion,
+        GameMode::Canfield,
+        GameMode::BakersDozen,
+        GameMode::BeleagueredCastle,
+        GameMode::EightOff,
+        GameMode::SeahavenTowers,
+        GameMode::BakersGame,
+    ] {
         let engine = engine_for_mode(mode);
         assert_eq!(engine.mode(), mode);
     }
     assert!(engine_for_mode(GameMode::Klondike).engine_ready());
     assert!(engine_for_mode(GameMode::Spider).engine_ready());
     assert!(engine_for_mode(GameMode::Freecell).engine_ready());
+    assert!(engine_for_mode(GameMode::Yukon).engine_ready());
+    assert!(engine_for_mode(GameMode::Pyramid).engine_ready());
+    assert!(engine_for_mode(GameMode::TriPeaks).engine_ready());
+    assert!(engine_for_mode(GameMode::Golf).engine_ready());
+    assert!(engine_for_mode(GameMode::FortyThieves).engine_ready());
+    assert!(engine_for_mode(GameMode::Scorpion).engine_ready());
+    assert!(engine_for_mode(GameMode::Canfield).engine_ready());
+    assert!(engine_for_mode(GameMode::BakersDozen).engine_ready());
+    assert!(engine_for_mode(GameMode::BeleagueredCastle).engine_ready());
+    assert!(engine_for_mode(GameMode::EightOff).engine_ready());
+    assert!(engine_for_mode(GameMode::SeahavenTowers).engine_ready());
+    assert!(engine_for_mode(GameMode::BakersGame).engine_ready());
     assert_eq!(
         engine_for_mode(GameMode::Klondike).automation_profile(),
         KLONDIKE_AUTOMATION_PROFILE
@@ -140,10 +198,49 @@ fn variant_engine_registry_matches_modes() {
         engine_for_mode(GameMode::Spider).automation_profile(),
         SPIDER_AUTOMATION_PROFILE
     );
+    for mode in [
+        GameMode::Freecell,
+        GameMode::EightOff,
+        GameMode::SeahavenTowers,
+        GameMode::BakersGame,
+    ] {
+        assert_eq!(
+            engine_for_mode(mode).automation_profile(),
+            FREECELL_AUTOMATION_PROFILE
+        );
+    }
+    assert_eq!(
+        engine_for_mode(GameMode::Yukon).automation_profile(),
+        YUKON_AUTOMATION_PROFILE
+    );
     assert_eq!(
-        engine_for_mode(GameMode::Freecell).automation_profile(),
-        FREECELL_AUTOMATION_PROFILE
+        engine_for_mode(GameMode::Pyramid).automation_profile(),
+        PYRAMID_AUTOMATION_PROFILE
     );
+    for mode in [GameMode::TriPeaks, GameMode::Golf] {
+        assert_eq!(
+            engine_for_mode(mode).automation_profile(),
+            WASTE_BUILD_AUTOMATION_PROFILE
+        );
+    }
+    assert_eq!(
+        engine_for_mode(GameMode::FortyThieves).automation_profile(),
+        FORTY_THIEVES_AUTOMATION_PROFILE
+    );
+    assert_eq!(
+        engine_for_mode(GameMode::Scorpion).automation_profile(),
+        SCORPION_AUTOMATION_PROFILE
+    );
+    assert_eq!(
+        engine_for_mode(GameMode::Canfield).automation_profile(),
+        CANFIELD_AUTOMATION_PROFILE
+    );
+    for mode in [GameMode::BakersDozen, GameMode::BeleagueredCastle] {
+        assert_eq!(
+            engine_for_mode(mode).automation_profile(),
+            OPEN_TABLEAU_AUTOMATION_PROFILE
+        );
+    }
     let klondike_caps = engine_for_mode(GameMode::Klondike).capabilities();
     assert!(klondike_caps.draw);
     assert!(klondike_caps.undo_redo);
@@ -161,16 +258,97 @@ fn variant_engine_registry_matches_modes() {
     assert!(spider_caps.seeded_deals);
     assert!(spider_caps.cyclone_shuffle);
 
-    let freecell_caps = engine_for_mode(GameMode::Freecell).capabilities();
-    assert!(!freecell_caps.draw);
-    assert!(freecell_caps.undo_redo);
-    assert!(freecell_caps.seeded_deals);
-    assert!(freecell_caps.winnability);
-    assert!(freecell_caps.smart_move);
-    assert!(freecell_caps.autoplay);
-    assert!(freecell_caps.rapid_wand);
-    assert!(freecell_caps.robot_mode);
-    assert!(freecell_caps.cyclone_shuffle);
+    for mode in [
+        GameMode::Freecell,
+        GameMode::EightOff,
+        GameMode::SeahavenTowers,
+        GameMode::BakersGame,
+    ] {
+        let freecell_caps = engine_for_mode(mode).capabilities();
+        assert!(!freecell_caps.draw);
+        assert!(freecell_caps.undo_redo);
+        assert!(freecell_caps.seeded_deals);
+        assert!(freecell_caps.winnability);
+        assert!(freecell_caps.smart_move);
+        assert!(freecell_caps.autoplay);
+        assert!(freecell_caps.rapid_wand);
+        assert!(freecell_caps.robot_mode);
+        assert!(freecell_caps.cyclone_shuffle);
+    }
+
+    let yukon_caps = engine_for_mode(GameMode::Yukon).capabilities();
+    assert!(!yukon_caps.draw);
+    assert!(yukon_caps.undo_redo);
+    assert!(yukon_caps.seeded_deals);
+    assert!(yukon_caps.smart_move);
+    assert!(yukon_caps.cyclone_shuffle);
+    assert!(!yukon_caps.winnability);
+    assert!(!yukon_caps.robot_mode);
+
+    let pyramid_caps = engine_for_mode(GameMode::Pyramid).capabilities();
+    assert!(pyramid_caps.draw);
+    assert!(pyramid_caps.undo_redo);
+    assert!(pyramid_caps.seeded_deals);
+    assert!(pyramid_caps.autoplay);
+    assert!(pyramid_caps.robot_mode);
+    assert!(!pyramid_caps.smart_move);
+    assert!(!pyramid_caps.cyclone_shuffle);
+
+    for mode in [GameMode::TriPeaks, GameMode::Golf] {
+        let caps = engine_for_mode(mode).capabilities();
+        assert!(caps.draw);
+        assert!(caps.undo_redo);
+        assert!(caps.seeded_deals);
+        assert!(caps.winnability);
+        assert!(caps.autoplay);
+        assert!(caps.robot_mode);
+        assert!(!caps.smart_move);
+        assert!(!caps.cyclone_shuffle);
+    }
+
+    let forty_thieves_caps = engine_for_mode(GameMode::FortyThieves).capabilities();
+    assert!(forty_thieves_caps.draw);
+    assert!(forty_thieves_caps.undo_redo);
+    assert!(forty_thieves_caps.seeded_deals);
+    assert!(forty_thieves_caps.smart_move);
+    assert!(forty_thieves_caps.autoplay);
+    assert!(forty_thieves_caps.robot_mode);
+    assert!(!forty_thieves_caps.winnability);
+    assert!(!forty_thieves_caps.cyclone_shuffle);
+    assert!(!forty_thieves_caps.draw_mode_selection);
+
+    let scorpion_caps = engine_for_mode(GameMode::Scorpion).capabilities();
+    assert!(scorpion_caps.draw);
+    assert!(scorpion_caps.undo_redo);
+    assert!(scorpion_caps.seeded_deals);
+    assert!(scorpion_caps.smart_move);
+    assert!(scorpion_caps.robot_mode);
+    assert!(!scorpion_caps.winnability);
+    assert!(!scorpion_caps.cyclone_shuffle);
+    assert!(!scorpion_caps.draw_mode_selection);
+
+    let canfield_caps = engine_for_mode(GameMode::Canfield).capabilities();
+    assert!(canfield_caps.draw);
+    assert!(canfield_caps.undo_redo);
+    assert!(canfield_caps.seeded_deals);
+    assert!(canfield_caps.smart_move);
+    assert!(canfield_caps.autoplay);
+    assert!(canfield_caps.robot_mode);
+    assert!(!canfield_caps.winnability);
+    assert!(!canfield_caps.cyclone_shuffle);
+    assert!(!canfield_caps.draw_mode_selection);
+
+    for mode in [GameMode::BakersDozen, GameMode::BeleagueredCastle] {
+        let caps = engine_for_mode(mode).capabilities();
+        assert!(!caps.draw);
+        assert!(caps.undo_redo);
+        assert!(caps.seeded_deals);
+        assert!(caps.smart_move);
+        assert!(caps.robot_mode);
+        assert!(caps.winnability);
+        assert!(!caps.cyclone_shuffle);
+        assert!(!caps.draw_mode_selection);
+    }
 }
 
 #[test]
@@ -209,6 +387,35 @@ fn boundary_initialize_seeded_with_draw_mode_sets_both() {
     assert_eq!(state.klondike().draw_mode(), DrawMode::Five);
 }
 
+#[test]
+fn boundary_initialize_seeded_keeps_the_klondike_pass_limit() {
+    let mut state = VariantStateStore::new(1);
+    state
+        .klondike_mut()
+        .set_pass_limit(KlondikePassLimit::Single);
+    assert!(boundary::initialize_seeded_with_draw_mode(
+        &mut state,
+        GameMode::Klondike,
+        99,
+        DrawMode::Three,
+    ));
+    assert_eq!(state.klondike().pass_limit(), KlondikePassLimit::Single);
+    assert_eq!(state.klondike().recycles_used(), 0);
+}
+
+#[test]
+fn boundary_initialize_seeded_keeps_klondike_thoughtful() {
+    let mut state = VariantStateStore::new(1);
+    state.klondike_mut().set_thoughtful(true);
+    assert!(boundary::initialize_seeded_with_draw_mode(
+        &mut state,
+        GameMode::Klondike,
+        99,
+        DrawMode::One,
+    ));
+    assert!(state.klondike().is_thoughtful());
+}
+
 #[test]
 fn boundary_is_won_is_mode_aware() {
     let state = VariantStateStore::new(3);
@@ -556,6 +763,27 @@ fn spider_boundary_006_cyclone_shuffle_routes_to_spider_state_only() {
     assert_ne!(state.spider(), &spider_before);
 }
 
+#[test]
+fn spider_boundary_007_initialize_seeded_keeps_the_rule_set() {
+    let mut state = VariantStateStore::new(5);
+    state.set_spider(SpiderGame::new_with_seed_and_rules(
+        5,
+        SpiderSuitMode::Two,
+        SpiderRuleSet::Tarantula,
+    ));
+
+    assert!(boundary::initialize_seeded_with_draw_mode(
+        &mut state,
+        GameMode::Spider,
+        808,
+        DrawMode::One,
+    ));
+    assert_eq!(
+        state.spider(),
+        &SpiderGame::new_with_seed_and_rules(808, SpiderSuitMode::Two, SpiderRuleSet::Tarantula)
+    );
+}
+
 #[test]
 fn smart_move_fallback_helpers_return_legal_moves() {
     let mut state = VariantStateStore::new(17);
@@ -657,6 +885,22 @@ fn persisted_session_v2_round_trip_for_klondike_runtime() {
     assert_eq!(decoded.klondike_draw_mode, DrawMode::Three);
 }
 
+#[test]
+fn persisted_session_records_and_checks_the_deal_algorithm() {
+    let state = VariantStateStore::new(42);
+    let encoded =
+        encode_persisted_session(&state, 42, GameMode::Klondike, 0, 0, false, DrawMode::One);
+    assert!(encoded.contains(&format!("\ndeal={}\n", crate::game::DEAL_ALGORITHM)));
+
+    let legacy = encoded.replace(&format!("\ndeal={}", crate::game::DEAL_ALGORITHM), "");
+    assert!(decode_persisted_session(&legacy).is_some());
+    let future = encoded.replace(
+        &format!("\ndeal={}", crate::game::DEAL_ALGORITHM),
+        "\ndeal=200",
+    );
+    assert!(decode_persisted_session(&future).is_none());
+}
+
 #[test]
 fn persisted_session_v2_round_trip_for_spider_runtime() {
     let mut state = VariantStateStore::new(42);
@@ -682,6 +926,593 @@ fn persisted_session_v2_round_trip_for_spider_runtime() {
     }
 }
 
+#[test]
+fn persisted_session_v2_round_trip_for_yukon_runtime_keeps_rule_set() {
+    let mut state = VariantStateStore::new(42);
+    let mode = GameMode::Yukon;
+    let seed = 3_131_u64;
+    let yukon = YukonGame::new_with_seed_and_rules(seed, YukonRuleSet::Russian);
+    state.set_yukon(yukon.clone());
+
+    let encoded = encode_persisted_session(&state, seed, mode, 3, 20, false, DrawMode::One);
+    let decoded = decode_persisted_session(&encoded).expect("decode persisted yukon session");
+    assert_eq!(decoded.seed, seed);
+    assert_eq!(decoded.mode, GameMode::Yukon);
+    assert_eq!(decoded.move_count, 3);
+    match decoded.runtime {
+        crate::engine::game_mode::VariantRuntime::Yukon(decoded_yukon) => {
+            assert_eq!(decoded_yukon, yukon);
+            assert_eq!(decoded_yukon.rule_set(), YukonRuleSet::Russian);
+        }
+        _ => panic!("expected yukon runtime"),
+    }
+}
+
+#[test]
+fn boundary_seeded_yukon_deal_keeps_selected_rule_set() {
+    let mut state = VariantStateStore::new(1);
+    state.set_yukon(YukonGame::new_with_seed_and_rules(1, YukonRuleSet::Russian));
+    assert!(boundary::initialize_seeded(
+        &mut state,
+        GameMode::Yukon,
+        2,
+        DrawMode::One
+    ));
+    assert_eq!(
+        state.yukon(),
+        &YukonGame::new_with_seed_and_rules(2, YukonRuleSet::Russian)
+    );
+}
+
+#[test]
+fn persisted_session_v2_round_trip_for_pyramid_runtime() {
+    let mut state = VariantStateStore::new(42);
+    let seed = 7_777_u64;
+    let mut pyramid = PyramidGame::new_with_seed(seed);
+    assert!(pyramid.apply_move(PyramidMove::Draw));
+    state.set_pyramid(pyramid.clone());
+
+    let encoded =
+        encode_persisted_session(&state, seed, GameMode::Pyramid, 1, 5, true, DrawMode::One);
+    let decoded = decode_persisted_session(&encoded).expect("decode persisted pyramid session");
+    assert_eq!(decoded.seed, seed);
+    assert_eq!(decoded.mode, GameMode::Pyramid);
+    match decoded.runtime {
+        crate::engine::game_mode::VariantRuntime::Pyramid(decoded_pyramid) => {
+            assert_eq!(decoded_pyramid, pyramid);
+        }
+        _ => panic!("expected pyramid runtime"),
+    }
+}
+
+#[test]
+fn boundary_remove_pyramid_cards_only_accepts_legal_removals() {
+    let mut state = VariantStateStore::new(1);
+    let (seed, removal) = (0..200_u64)
+        .find_map(|seed| {
+            PyramidGame::new_with_seed(seed)
+                .legal_moves()
+                .into_iter()
+                .find(|candidate| *candidate != PyramidMove::Draw)
+                .map(|removal| (seed, removal))
+        })
+        .expect("some early deal offers a removal");
+    assert!(boundary::initialize_seeded(
+        &mut state,
+        GameMode::Pyramid,
+        seed,
+        DrawMode::One
+    ));
+    let (first, second) = match removal {
+        PyramidMove::RemoveKing(card_ref) => (card_ref, None),
+        PyramidMove::RemovePair(a, b) => (a, Some(b)),
+        PyramidMove::Draw => unreachable!(),
+    };
+
+    let covered = crate::game::PyramidCardRef::Pyramid(0);
+    assert!(
+        !boundary::execute_command(
+            &mut state,
+            GameMode::Pyramid,
+            EngineCommand::RemovePyramidCards {
+                first: covered,
+                second: None,
+            },
+        )
+        .changed
+    );
+
+    let mut expected = PyramidGame::new_with_seed(seed);
+    assert!(expected.apply_move(removal));
+    assert!(
+        boundary::execute_command(
+            &mut state,
+            GameMode::Pyramid,
+            EngineCommand::RemovePyramidCards { first, second },
+        )
+        .changed
+    );
+    assert_eq!(state.pyramid(), &expected);
+}
+
+#[test]
+fn persisted_session_v2_round_trip_for_waste_build_runtimes() {
+    let seed = 3_131_u64;
+    for layout in [WasteBuildLayout::TriPeaks, WasteBuildLayout::Golf] {
+        let mut state = VariantStateStore::new(42);
+        let rules = WasteBuildRules {
+            wrap_ranks: true,
+            streak_scoring: true,
+        };
+        let mut game = WasteBuildGame::new_with_seed_and_rules(layout, seed, rules);
+        assert!(game.apply_move(WasteBuildMove::Draw));
+        *state.waste_build_mut(layout) = game.clone();
+
+        let encoded =
+            encode_persisted_session(&state, seed, layout.mode(), 2, 9, false, DrawMode::One);
+        let decoded = decode_persisted_session(&encoded).expect("decode waste-build session");
+        assert_eq!(decoded.mode, layout.mode());
+        match (layout, decoded.runtime) {
+            (
+                WasteBuildLayout::TriPeaks,
+                crate::engine::game_mode::VariantRuntime::TriPeaks(decoded),
+            )
+            | (WasteBuildLayout::Golf, crate::engine::game_mode::VariantRuntime::Golf(decoded)) => {
+                assert_eq!(decoded, game);
+            }
+            _ => panic!("expected {} runtime", layout.mode().label()),
+        }
+    }
+}
+
+#[test]
+fn boundary_play_to_waste_only_accepts_playable_slots() {
+    let mut state = VariantStateStore::new(1);
+    let (seed, slot) = (0..200_u64)
+        .find_map(|seed| {
+            WasteBuildGame::new_with_seed(WasteBuildLayout::TriPeaks, seed)
+                .legal_moves()
+                .into_iter()
+                .find_map(|candidate| match candidate {
+                    WasteBuildMove::Play(slot) => Some((seed, slot)),
+                    WasteBuildMove::Draw => None,
+                })
+        })
+        .expect("some early deal offers a play");
+    assert!(boundary::initialize_seeded(
+        &mut state,
+        GameMode::TriPeaks,
+        seed,
+        DrawMode::One
+    ));
+
+    // Peak cards start covered.
+    assert!(
+        !boundary::execute_command(
+            &mut state,
+            GameMode::TriPeaks,
+            EngineCommand::PlayToWaste { slot: 0 },
+        )
+        .changed
+    );
+
+    let mut expected = WasteBuildGame::new_with_seed(WasteBuildLayout::TriPeaks, seed);
+    assert!(expected.play(slot));
+    assert!(
+        boundary::execute_command(
+            &mut state,
+            GameMode::TriPeaks,
+            EngineCommand::PlayToWaste { slot },
+        )
+        .changed
+    );
+    assert_eq!(state.tripeaks(), &expected);
+}
+
+#[test]
+fn persisted_session_v2_round_trip_for_forty_thieves_runtime() {
+    let mut state = VariantStateStore::new(42);
+    let seed = 4_040_u64;
+    let mut game = FortyThievesGame::new_with_seed(seed);
+    let _ = game.draw();
+    state.set_forty_thieves(game.clone());
+
+    let encoded = encode_persisted_session(
+        &state,
+        seed,
+        GameMode::FortyThieves,
+        1,
+        5,
+        true,
+        DrawMode::One,
+    );
+    let decoded = decode_persisted_session(&encoded).expect("decode forty thieves session");
+    assert_eq!(decoded.mode, GameMode::FortyThieves);
+    match decoded.runtime {
+        crate::engine::game_mode::VariantRuntime::FortyThieves(decoded) => {
+            assert_eq!(decoded, game);
+        }
+        _ => panic!("expected forty thieves runtime"),
+    }
+}
+
+#[test]
+fn boundary_forty_thieves_draws_once_and_routes_foundation_moves() {
+    let mut state = VariantStateStore::new(1);
+    assert!(boundary::initialize_seeded(
+        &mut state,
+        GameMode::FortyThieves,
+        12,
+        DrawMode::Three
+    ));
+    assert_eq!(state.forty_thieves(), &FortyThievesGame::new_with_seed(12));
+
+    // Forty Thieves always turns a single card, whatever the Klondike draw mode.
+    let draw = boundary::execute_command(
+        &mut state,
+        GameMode::FortyThieves,
+        EngineCommand::DrawOrRecycle {
+            draw_mode: DrawMode::Three,
+        },
+    );
+    assert!(draw.changed);
+    assert_eq!(state.forty_thieves().waste().len(), 1);
+
+    // Two aces of the same suit fill both of that suit's foundation piles.
+    let ace = Card {
+        suit: Suit::Hearts,
+        rank: 1,
+        face_up: true,
+    };
+    let mut tableau: [Vec<Card>; 10] = std::array::from_fn(|_| Vec::new());
+    tableau[3] = vec![ace];
+    state.set_forty_thieves(FortyThievesGame::debug_new(
+        Vec::new(),
+        vec![ace],
+        std::array::from_fn(|_| Vec::new()),
+        tableau,
+    ));
+    assert!(
+        boundary::execute_command(
+            &mut state,
+            GameMode::FortyThieves,
+            EngineCommand::MoveWasteToFoundation,
+        )
+        .changed
+    );
+    assert!(
+        boundary::execute_command(
+            &mut state,
+            GameMode::FortyThieves,
+            EngineCommand::MoveTableauTopToFoundation { src: 3 },
+        )
+        .changed
+    );
+    let hearts = Suit::Hearts.foundation_index() * 2;
+    assert_eq!(state.forty_thieves().foundations()[hearts], vec![ace]);
+    assert_eq!(state.forty_thieves().foundations()[hearts + 1], vec![ace]);
+}
+
+#[test]
+fn persisted_session_v2_round_trip_for_scorpion_runtime() {
+    let mut state = VariantStateStore::new(42);
+    let seed = 3_535_u64;
+    let mut game = ScorpionGame::new_with_seed(seed);
+    let _ = game.deal_reserve();
+    state.set_scorpion(game.clone());
+
+    let encoded =
+        encode_persisted_session(&state, seed, GameMode::Scorpion, 1, 5, true, DrawMode::One);
+    let decoded = decode_persisted_session(&encoded).expect("decode scorpion session");
+    assert_eq!(decoded.mode, GameMode::Scorpion);
+    match decoded.runtime {
+        crate::engine::game_mode::VariantRuntime::Scorpion(decoded) => {
+            assert_eq!(decoded, game);
+        }
+        _ => panic!("expected scorpion runtime"),
+    }
+}
+
+#[test]
+fn boundary_scorpion_deals_the_reserve_once() {
+    let mut state = VariantStateStore::new(1);
+    assert!(boundary::initialize_seeded(
+        &mut state,
+        GameMode::Scorpion,
+        12,
+        DrawMode::Three
+    ));
+    assert_eq!(state.scorpion(), &ScorpionGame::new_with_seed(12));
+
+    let deal = EngineCommand::DrawOrRecycle {
+        draw_mode: DrawMode::Three,
+    };
+    let first = boundary::execute_command(&mut state, GameMode::Scorpion, deal);
+    assert!(first.changed);
+    assert_eq!(state.scorpion().stock_len(), 0);
+    assert!(state.scorpion().tableau()[..3]
+        .iter()
+        .all(|pile| pile.len() == 8));
+    assert!(!boundary::execute_command(&mut state, GameMode::Scorpion, deal).changed);
+}
+
+#[test]
+fn persisted_session_v2_round_trip_for_canfield_runtime() {
+    let mut state = VariantStateStore::new(42);
+    let seed = 3_636_u64;
+    let mut game = CanfieldGame::new_with_seed(seed);
+    let _ = game.draw();
+    state.set_canfield(game.clone());
+
+    let encoded = encode_persisted_session(
+        &state,
+        seed,
+        GameMode::Canfield,
+        1,
+        5,
+        true,
+        DrawMode::Three,
+    );
+    let decoded = decode_persisted_session(&encoded).expect("decode canfield session");
+    assert_eq!(decoded.mode, GameMode::Canfield);
+    match decoded.runtime {
+        crate::engine::game_mode::VariantRuntime::Canfield(decoded) => {
+            assert_eq!(decoded, game);
+        }
+        _ => panic!("expected canfield runtime"),
+    }
+}
+
+#[test]
+fn boundary_canfield_routes_reserve_through_free_cell_commands() {
+    let card = |suit, rank, face_up| Card {
+        suit,
+        rank,
+        face_up,
+    };
+    let mut foundations: [Vec<Card>; 4] = std::array::from_fn(|_| Vec::new());
+    foundations[Suit::Hearts.foundation_index()] = vec![card(Suit::Hearts, 5, true)];
+    let mut state = VariantStateStore::new(1);
+    state.set_canfield(CanfieldGame::debug_new(
+        5,
+        Vec::new(),
+        Vec::new(),
+        vec![
+            card(Suit::Clubs, 3, false),
+            card(Suit::Clubs, 5, false),
+            card(Suit::Hearts, 6, true),
+        ],
+        foundations,
+        [
+            vec![card(Suit::Diamonds, 6, true)],
+            vec![card(Suit::Spades, 10, true)],
+            vec![card(Suit::Clubs, 10, true)],
+            vec![card(Suit::Hearts, 11, true)],
+        ],
+    ));
+
+    let mode = GameMode::Canfield;
+    assert_eq!(
+        boundary::freecell_card(&state, mode, 0),
+        Some(card(Suit::Hearts, 6, true))
+    );
+    assert_eq!(boundary::freecell_card(&state, mode, 1), None);
+    assert!(
+        boundary::execute_command(
+            &mut state,
+            mode,
+            EngineCommand::MoveFreecellToFoundation { cell: 0 },
+        )
+        .changed
+    );
+    assert_eq!(
+        state.canfield().foundations()[Suit::Hearts.foundation_index()].len(),
+        2
+    );
+    assert!(
+        boundary::execute_command(
+            &mut state,
+            mode,
+            EngineCommand::MoveFreecellToTableau { cell: 0, dst: 0 },
+        )
+        .changed
+    );
+    assert_eq!(state.canfield().tableau()[0].len(), 2);
+    assert_eq!(
+        boundary::freecell_card(&state, mode, 0).map(|card| card.rank),
+        Some(3)
+    );
+}
+
+fn open_tableau_card(suit: Suit, rank: u8) -> Card {
+    Card {
+        suit,
+        rank,
+        face_up: true,
+    }
+}
+
+/// Foundations holding Ace through `top` of every suit.
+fn open_tableau_foundations(top: u8) -> [Vec<Card>; 4] {
+    std::array::from_fn(|pile| {
+        (1..=top)
+            .map(|rank| open_tableau_card(Suit::ALL[pile], rank))
+            .collect()
+    })
+}
+
+#[test]
+fn persisted_session_v2_round_trip_for_open_tableau_runtimes() {
+    for layout in [
+        OpenTableauLayout::BakersDozen,
+        OpenTableauLayout::BeleagueredCastle,
+    ] {
+        let mut state = VariantStateStore::new(42);
+        let seed = 1_313_u64;
+        let mut game = OpenTableauGame::new_with_seed(layout, seed);
+        if let Some(first) = game.legal_moves().first().copied() {
+            assert!(game.apply_move(first));
+        }
+        *state.open_tableau_mut(layout) = game.clone();
+
+        let encoded =
+            encode_persisted_session(&state, seed, layout.mode(), 1, 5, true, DrawMode::One);
+        let decoded = decode_persisted_session(&encoded).expect("decode open tableau session");
+        assert_eq!(decoded.mode, layout.mode());
+        match decoded.runtime {
+            crate::engine::game_mode::VariantRuntime::BakersDozen(decoded)
+            | crate::engine::game_mode::VariantRuntime::BeleagueredCastle(decoded) => {
+                assert_eq!(decoded, game);
+            }
+            _ => panic!("expected open tableau runtime"),
+        }
+    }
+}
+
+#[test]
+fn boundary_open_tableau_moves_single_cards_only() {
+    let mut state = VariantStateStore::new(1);
+    let mode = GameMode::BeleagueredCastle;
+    assert!(boundary::initialize_seeded(
+        &mut state,
+        mode,
+        9,
+        DrawMode::Three
+    ));
+    let layout = OpenTableauLayout::BeleagueredCastle;
+    assert_eq!(
+        state.open_tableau(layout),
+        &OpenTableauGame::new_with_seed(layout, 9)
+    );
+    assert!(
+        !boundary::execute_command(
+            &mut state,
+            mode,
+            EngineCommand::DrawOrRecycle {
+                draw_mode: DrawMode::One,
+            },
+        )
+        .changed
+    );
+
+    let mut tableau = vec![Vec::new(); 8];
+    tableau[0] = vec![
+        open_tableau_card(Suit::Spades, 13),
+        open_tableau_card(Suit::Hearts, 12),
+    ];
+    tableau[1] = vec![open_tableau_card(Suit::Clubs, 13)];
+    *state.open_tableau_mut(layout) =
+        OpenTableauGame::debug_new(layout, std::array::from_fn(|_| Vec::new()), tableau);
+    assert!(
+        !boundary::execute_command(
+            &mut state,
+            mode,
+            EngineCommand::MoveTableauRunToTableau {
+                src: 0,
+                start: 0,
+                dst: 2,
+            },
+        )
+        .changed
+    );
+    assert!(
+        boundary::execute_command(
+            &mut state,
+            mode,
+            EngineCommand::MoveTableauRunToTableau {
+                src: 0,
+                start: 1,
+                dst: 1,
+            },
+        )
+        .changed
+    );
+    assert_eq!(state.open_tableau(layout).tableau()[1].len(), 2);
+}
+
+#[test]
+fn open_tableau_planner_line_replays_to_a_win() {
+    // Every Queen but one covers a card that must go home first.
+    let tableau = vec![
+        vec![
+            open_tableau_card(Suit::Hearts, 11),
+            open_tableau_card(Suit::Spades, 12),
+        ],
+        vec![open_tableau_card(Suit::Hearts, 13)],
+        vec![
+            open_tableau_card(Suit::Spades, 11),
+            open_tableau_card(Suit::Spades, 13),
+        ],
+        vec![
+            open_tableau_card(Suit::Hearts, 12),
+            open_tableau_card(Suit::Diamonds, 11),
+        ],
+        vec![
+            open_tableau_card(Suit::Diamonds, 12),
+            open_tableau_card(Suit::Clubs, 11),
+        ],
+        vec![
+            open_tableau_card(Suit::Diamonds, 13),
+            open_tableau_card(Suit::Clubs, 12),
+        ],
+        vec![open_tableau_card(Suit::Clubs, 13)],
+        Vec::new(),
+    ];
+    let game = OpenTableauGame::debug_new(
+        OpenTableauLayout::BeleagueredCastle,
+        open_tableau_foundations(10),
+        tableau,
+    );
+
+    let result = open_tableau_planner::solve(&game, 10_000);
+    assert!(result.winnable);
+    assert!(!result.hit_state_limit);
+    let mut replay = game.clone();
+    for step in &result.line {
+        assert!(
+            replay.apply_move(*step),
+            "planner step {step:?} should be legal"
+        );
+    }
+    assert!(replay.is_won());
+}
+
+#[test]
+fn open_tableau_planner_proves_a_locked_deal_lost() {
+    // The Jacks sit under a King that can never move in Baker's Dozen; the
+    // Queen of Diamonds can only shuttle between Kings.
+    let mut tableau = vec![Vec::new(); 13];
+    tableau[0] = vec![open_tableau_card(Suit::Spades, 13)];
+    tableau[1] = vec![
+        open_tableau_card(Suit::Hearts, 13),
+        open_tableau_card(Suit::Diamonds, 12),
+    ];
+    tableau[2] = [
+        (Suit::Diamonds, 11),
+        (Suit::Clubs, 11),
+        (Suit::Hearts, 11),
+        (Suit::Spades, 11),
+        (Suit::Spades, 12),
+        (Suit::Hearts, 12),
+        (Suit::Clubs, 12),
+        (Suit::Diamonds, 13),
+        (Suit::Clubs, 13),
+    ]
+    .into_iter()
+    .map(|(suit, rank)| open_tableau_card(suit, rank))
+    .collect();
+    let game = OpenTableauGame::debug_new(
+        OpenTableauLayout::BakersDozen,
+        open_tableau_foundations(10),
+        tableau,
+    );
+
+    let result = open_tableau_planner::solve(&game, 10_000);
+    assert!(!result.winnable);
+    assert!(!result.hit_state_limit);
+    assert!(result.line.is_empty());
+}
+
 #[test]
 fn persisted_session_v2_round_trip_for_freecell_runtime_with_explicit_card_count() {
     let mut state = VariantStateStore::new(42);
@@ -709,6 +1540,68 @@ fn persisted_session_v2_round_trip_for_freecell_runtime_with_explicit_card_count
     }
 }
 
+#[test]
+fn persisted_session_v2_round_trip_for_freecell_layout_runtimes() {
+    for layout in [
+        FreecellLayout::EightOff,
+        FreecellLayout::SeahavenTowers,
+        FreecellLayout::BakersGame,
+    ] {
+        let mut state = VariantStateStore::new(42);
+        let seed = 2_024_u64;
+        let mut game = FreecellGame::new_with_seed_for_layout(layout, seed);
+        assert!(game.move_tableau_top_to_freecell(1, game.freecell_count() - 1));
+        state.set_freecell(game.clone());
+
+        let encoded =
+            encode_persisted_session(&state, seed, layout.mode(), 1, 5, true, DrawMode::One);
+        let decoded = decode_persisted_session(&encoded).expect("decode freecell layout session");
+        assert_eq!(decoded.mode, layout.mode());
+        assert_eq!(
+            decoded.freecell_card_count_mode,
+            FreecellCardCountMode::FiftyTwo
+        );
+        match decoded.runtime {
+            crate::engine::game_mode::VariantRuntime::EightOff(decoded)
+            | crate::engine::game_mode::VariantRuntime::SeahavenTowers(decoded)
+            | crate::engine::game_mode::VariantRuntime::BakersGame(decoded) => {
+                assert_eq!(decoded, game);
+            }
+            _ => panic!("expected freecell layout runtime"),
+        }
+    }
+}
+
+#[test]
+fn boundary_initialize_seeded_keeps_each_freecell_layout_separate() {
+    let mut state = VariantStateStore::new(5);
+    let classic_before = state.freecell_for(FreecellLayout::FreeCell).clone();
+
+    assert!(boundary::initialize_seeded(
+        &mut state,
+        GameMode::SeahavenTowers,
+        77,
+        DrawMode::One
+    ));
+    assert_eq!(
+        state.freecell_for(FreecellLayout::SeahavenTowers),
+        &FreecellGame::new_with_seed_for_layout(FreecellLayout::SeahavenTowers, 77)
+    );
+    assert_eq!(
+        state.freecell_for(FreecellLayout::FreeCell),
+        &classic_before
+    );
+
+    state.activate_freecell_layout(FreecellLayout::SeahavenTowers);
+    assert_eq!(state.freecell().layout(), FreecellLayout::SeahavenTowers);
+    assert_eq!(
+        boundary::tableau_len(&state, GameMode::SeahavenTowers, 9),
+        Some(5)
+    );
+    state.activate_freecell_layout(FreecellLayout::FreeCell);
+    assert_eq!(state.freecell(), &classic_before);
+}
+
 #[test]
 fn variant_state_set_runtime_spider_does_not_mutate_klondike() {
     let mut state = VariantStateStore::new(100);
@@ -959,10 +1852,634 @@ fn seed_ops_parse_and_random_fallback_work() {
     let _ = seed;
 }
 
+#[test]
+fn seed_ops_parse_microsoft_deal_numbers() {
+    use crate::engine::seed_ops::{parse_seed_input, seed_label};
+
+    let seed = parse_seed_input("ms:11982").unwrap().unwrap();
+    assert_eq!(crate::game::microsoft_deal_number(seed), Some(11_982));
+    assert_eq!(seed_label(seed), "v1:ms:11982");
+    assert_eq!(parse_seed_input(&seed_label(seed)).unwrap(), Some(seed));
+    assert_eq!(
+        parse_seed_input(" MS: 1_000 ").unwrap(),
+        crate::game::microsoft_deal_seed(1000)
+    );
+    assert!(parse_seed_input("ms:0").is_err());
+    assert!(parse_seed_input("ms:8589934592").is_err());
+    assert!(parse_seed_input("ms:").is_err());
+    assert_eq!(seed_label(42), "v1:42");
+}
+
+#[test]
+fn seed_ops_parse_deal_algorithm_prefix() {
+    use crate::engine::seed_ops::parse_seed_input;
+
+    assert_eq!(parse_seed_input("v1:12_345").unwrap(), Some(12345));
+    assert_eq!(
+        parse_seed_input("V1:hello").unwrap(),
+        parse_seed_input("hello").unwrap()
+    );
+    assert_eq!(
+        parse_seed_input("v1:ms:617").unwrap(),
+        crate::game::microsoft_deal_seed(617)
+    );
+    let error = parse_seed_input("v2:12345").unwrap_err();
+    assert!(error.contains("algorithm 2"));
+    assert!(parse_seed_input("v1:").is_err());
+}
+
+#[test]
+fn seed_ops_label_round_trips_through_the_parser() {
+    use crate::engine::seed_ops::{parse_seed_input, seed_label};
+
+    for seed in [
+        0,
+        42,
+        u64::MAX,
+        crate::game::microsoft_deal_seed(1).unwrap(),
+        parse_seed_input("hello").unwrap().unwrap(),
+    ] {
+        let label = seed_label(seed);
+        assert!(label.starts_with(&format!("v{}:", crate::game::DEAL_ALGORITHM)));
+        assert_eq!(parse_seed_input(&label).unwrap(), Some(seed), "{label}");
+    }
+}
+
+#[test]
+fn seed_history_store_writes_tagged_seeds_and_reads_bare_ones() {
+    use crate::engine::seed_history::SeedHistoryStore;
+
+    let mut store = SeedHistoryStore::default();
+    store.note_play_started(42, 10);
+    store.note_win(42);
+    let serialized = store.serialize();
+    assert!(serialized.starts_with("v1:42 1 1 "), "{serialized}");
+    let (entries, _) = SeedHistoryStore::load_from_string(&serialized, 10).dropdown_entries(10);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].0, 42);
+    assert_eq!(entries[0].1.wins, 1);
+
+    let (entries, _) =
+        SeedHistoryStore::load_from_string("7 2 0 3\nv2:8 1 0 4\n", 10).dropdown_entries(10);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].0, 7);
+}
+
 #[test]
 fn seed_ops_messages_are_stable() {
     let msg = crate::engine::seed_ops::msg_started_winnable_seed(42, 3, 9);
-    assert!(msg.contains("Seed 42"));
+    assert!(msg.contains("Seed v1:42"));
     assert!(msg.contains("Deal 3"));
     assert!(msg.contains("checked 9 seed(s)"));
 }
+
+#[test]
+fn scoring_standard_klondike_pays_for_foundation_reveal_and_waste_plays() {
+    let runtime = VariantRuntime::Klondike(KlondikeGame::new_with_seed(7));
+    let table = scoring::table_for(ScoringSystem::Standard, &runtime, DrawMode::One)
+        .expect("klondike is scored");
+    let before = ScoreFacts::from_runtime(&runtime).expect("klondike facts");
+    assert_eq!(before.face_down_cards, 21);
+    assert_eq!(before.stock_cards, 24);
+
+    let waste_to_foundation = ScoreFacts {
+        foundation_cards: 1,
+        ..before
+    };
+    let with_waste = ScoreFacts {
+        waste_cards: 1,
+        ..before
+    };
+    assert_eq!(
+        scoring::move_delta(&table, &with_waste, &waste_to_foundation),
+        10
+    );
+
+    let waste_to_tableau = ScoreFacts {
+        waste_cards: 0,
+        ..with_waste
+    };
+    assert_eq!(
+        scoring::move_delta(&table, &with_waste, &waste_to_tableau),
+        5
+    );
+
+    let tableau_to_foundation_with_reveal = ScoreFacts {
+        foundation_cards: 1,
+        face_down_cards: 20,
+        ..before
+    };
+    assert_eq!(
+        scoring::move_delta(&table, &before, &tableau_to_foundation_with_reveal),
+        15
+    );
+    assert_eq!(
+        scoring::move_delta(&table, &waste_to_foundation, &with_waste),
+        -15
+    );
+}
+
+#[test]
+fn scoring_klondike_recycle_charges_by_draw_mode() {
+    let mut game = KlondikeGame::new_with_seed(11);
+    while game.stock_len() > 0 {
+        game.draw_or_recycle_with_count(1);
+    }
+    let before = VariantRuntime::Klondike(game.clone());
+    assert_eq!(
+        game.draw_or_recycle_with_count(1),
+        DrawResult::RecycledWaste
+    );
+    let after = VariantRuntime::Klondike(game);
+    let before_facts = ScoreFacts::from_runtime(&before).expect("facts before recycle");
+    let after_facts = ScoreFacts::from_runtime(&after).expect("facts after recycle");
+
+    let deal_one = scoring::table_for(ScoringSystem::Standard, &before, DrawMode::One)
+        .expect("klondike is scored");
+    let deal_three = scoring::table_for(ScoringSystem::Standard, &before, DrawMode::Three)
+        .expect("klondike is scored");
+    assert_eq!(
+        scoring::move_delta(&deal_one, &before_facts, &after_facts),
+        -100
+    );
+    assert_eq!(
+        scoring::move_delta(&deal_three, &before_facts, &after_facts),
+        -20
+    );
+}
+
+#[test]
+fn scoring_vegas_opens_with_buy_in_and_cumulative_carries_the_bank() {
+    let klondike = VariantRuntime::Klondike(KlondikeGame::new_with_seed(3));
+    let vegas = scoring::table_for(ScoringSystem::Vegas, &klondike, DrawMode::Three);
+    assert_eq!(scoring::opening_score(ScoringSystem::Vegas, vegas, 40), -52);
+    assert_eq!(
+        scoring::opening_score(ScoringSystem::CumulativeVegas, vegas, 40),
+        -12
+    );
+    assert_eq!(
+        scoring::opening_score(ScoringSystem::CumulativeVegas, None, 40),
+        40
+    );
+
+    let spider = VariantRuntime::Spider(SpiderGame::new_with_seed(3));
+    let spider_vegas =
+        scoring::table_for(ScoringSystem::Vegas, &spider, DrawMode::One).expect("spider is scored");
+    assert_eq!(spider_vegas.opening, -104);
+
+    let yukon = VariantRuntime::Yukon(YukonGame::new_with_seed(3));
+    assert!(scoring::table_for(ScoringSystem::Vegas, &yukon, DrawMode::One).is_none());
+    assert!(!scoring::mode_is_scored(GameMode::Yukon));
+    assert!(scoring::mode_is_scored(GameMode::SeahavenTowers));
+}
+
+#[test]
+fn scoring_spider_pays_per_run_and_charges_per_move() {
+    let spider = VariantRuntime::Spider(SpiderGame::new_with_seed(5));
+    let table = scoring::table_for(ScoringSystem::Standard, &spider, DrawMode::One)
+        .expect("spider is scored");
+    assert_eq!(
+        scoring::opening_score(ScoringSystem::Standard, Some(table), 0),
+        500
+    );
+
+    let before = ScoreFacts::from_runtime(&spider).expect("spider facts");
+    assert_eq!(scoring::move_delta(&table, &before, &before), -1);
+    let run_completed = ScoreFacts {
+        foundation_cards: 13,
+        completed_runs: 1,
+        ..before
+    };
+    assert_eq!(scoring::move_delta(&table, &before, &run_completed), 99);
+}
+
+#[test]
+fn scoring_standard_display_applies_time_and_floors_at_zero() {
+    let klondike = VariantRuntime::Klondike(KlondikeGame::new_with_seed(1));
+    let table = scoring::table_for(ScoringSystem::Standard, &klondike, DrawMode::One)
+        .expect("klondike is scored");
+    assert_eq!(
+        scoring::displayed_score(ScoringSystem::Standard, &table, 100, 95, false),
+        82
+    );
+    assert_eq!(
+        scoring::displayed_score(ScoringSystem::Standard, &table, 10, 600, false),
+        0
+    );
+    assert_eq!(
+        scoring::displayed_score(ScoringSystem::Standard, &table, 100, 100, true),
+        7080
+    );
+
+    let vegas = scoring::table_for(ScoringSystem::Vegas, &klondike, DrawMode::One)
+        .expect("klondike is scored");
+    assert_eq!(
+        scoring::displayed_score(ScoringSystem::Vegas, &vegas, -47, 600, false),
+        -47
+    );
+}
+
+#[test]
+fn scoring_standard_floors_the_stored_score_as_moves_land() {
+    // A foundation card pulled back at zero costs nothing, so the next
+    // foundation play is paid in full rather than repaying the debt.
+    let score = scoring::add_move_score(ScoringSystem::Standard, 0, -15);
+    assert_eq!(score, 0);
+    assert_eq!(
+        scoring::add_move_score(ScoringSystem::Standard, score, 10),
+        10
+    );
+    assert_eq!(scoring::add_move_score(ScoringSystem::Vegas, -52, -5), -57);
+}
+
+#[test]
+fn stats_track_streaks_and_records_per_rule_set() {
+    let mut store = VariantStatsStore::default();
+    let draw_three = StatsKey::new(GameMode::Klondike, "draw=3;passes=unlimited;thoughtful=0");
+    let freecell = StatsKey::new(GameMode::Freecell, "cards=52;fc=4");
+
+    store.note_game_started(draw_three.clone());
+    store.note_win(draw_three.clone(), 200, 120);
+    store.note_game_started(draw_three.clone());
+    store.note_win(draw_three.clone(), 150, 130);
+    store.note_game_started(freecell.clone());
+    store.note_game_started(draw_three.clone());
+    store.note_game_started(draw_three.clone());
+
+    let rows = store.rows();
+    let (_, klondike) = rows
+        .iter()
+        .find(|(key, _)| **key == draw_three)
+        .expect("klondike entry");
+    assert_eq!(klondike.played, 4);
+    assert_eq!(klondike.won, 2);
+    assert_eq!(klondike.current_streak, 0);
+    assert_eq!(klondike.best_streak, 2);
+    assert_eq!(klondike.fastest_win_seconds, Some(150));
+    assert_eq!(klondike.fewest_moves, Some(120));
+    assert_eq!(klondike.average_apm(), Some(250.0 * 60.0 / 350.0));
+    assert!(store.is_in_progress(&draw_three));
+
+    let (_, freecell_stats) = rows
+        .iter()
+        .find(|(key, _)| **key == freecell)
+        .expect("freecell entry");
+    assert_eq!(freecell_stats.played, 1);
+    assert_eq!(freecell_stats.won, 0);
+}
+
+#[test]
+fn stats_store_round_trips_and_exports_csv() {
+    let mut store = VariantStatsStore::default();
+    let spider = StatsKey::new(GameMode::Spider, "rules=spiderette;mode=2");
+    store.note_game_started(spider.clone());
+    store.note_win(spider.clone(), 90, 300);
+    store.note_game_started(spider.clone());
+
+    let reloaded = VariantStatsStore::load_from_string(&store.serialize());
+    assert_eq!(reloaded.rows(), store.rows());
+    assert!(reloaded.is_in_progress(&spider));
+
+    let csv = reloaded.export_csv();
+    let mut lines = csv.lines();
+    assert!(lines
+        .next()
+        .expect("csv header")
+        .starts_with("variant,rules,played,won"));
+    assert_eq!(
+        lines.next(),
+        Some("Spider,\"Spiderette, 2 Suits\",2,1,50.0,1,1,90,300,200.0")
+    );
+    assert!(VariantStatsStore::load_from_string("garbage\nline").is_empty());
+}
+
+#[test]
+fn stats_keys_use_rule_ids_and_migrate_label_rows() {
+    let mut game = VariantStateStore::new(1);
+    *game.golf_mut() = WasteBuildGame::new_with_seed_and_rules(
+        WasteBuildLayout::Golf,
+        1,
+        WasteBuildRules {
+            wrap_ranks: true,
+            streak_scoring: true,
+        },
+    );
+    let golf = StatsKey::for_game(&game, GameMode::Golf, DrawMode::One);
+    assert_ne!(
+        golf,
+        StatsKey::for_game(&game, GameMode::TriPeaks, DrawMode::One)
+    );
+    assert_eq!(golf.rules_label(), "Wrap King\u{2013}Ace");
+    assert_eq!(
+        StatsKey::for_game(&game, GameMode::Pyramid, DrawMode::One).rules_label(),
+        "Standard"
+    );
+
+    let legacy = [
+        (
+            GameMode::Klondike,
+            "Deal 3, 3 Passes, Thoughtful",
+            "draw=3;passes=3;thoughtful=1",
+        ),
+        (
+            GameMode::Spider,
+            "Will o' the Wisp, 1 Suit",
+            "rules=wisp;mode=1",
+        ),
+        (GameMode::Yukon, "Russian", "rules=russian"),
+        (GameMode::Freecell, "39 Cards, 2 Cells", "cards=39;fc=2"),
+        (GameMode::TriPeaks, "Standard", "wrap=0"),
+        (GameMode::Canfield, "Standard", "standard"),
+    ];
+    let rows: String = legacy
+        .iter()
+        .map(|(mode, label, _)| {
+            format!("{}\t{label}\t2\t1\t1\t1\t90\t300\t300\t90\t0\n", mode.id())
+        })
+        .collect();
+    let store = VariantStatsStore::load_from_string(&rows);
+    for (mode, label, id) in legacy {
+        let key = StatsKey::new(mode, id);
+        assert!(
+            store.rows().iter().any(|(row, _)| **row == key),
+            "{label} migrates to {id}"
+        );
+        assert_eq!(key.rules_label(), label);
+    }
+
+    let daily =
+        DailyChallengeStore::load_from_string("2026-03-01\tspider\tSpider, 4 Suits\t7\twon");
+    assert_eq!(
+        daily.serialize(),
+        "2026-03-01\tspider\trules=spider;mode=4\t7\twon\n"
+    );
+}
+
+#[test]
+fn daily_dates_validate_and_round_trip() {
+    assert!(ChallengeDate::new(2024, 2, 29).is_some());
+    assert!(ChallengeDate::new(2023, 2, 29).is_none());
+    assert!(ChallengeDate::new(1900, 2, 29).is_none());
+    assert!(ChallengeDate::new(2000, 2, 29).is_some());
+    assert!(ChallengeDate::new(2026, 13, 1).is_none());
+    assert!(ChallengeDate::new(2026, 4, 31).is_none());
+
+    let date = ChallengeDate::new(2026, 3, 7).expect("valid date");
+    assert_eq!(date.key(), "2026-03-07");
+    assert_eq!(ChallengeDate::parse("2026-03-07"), Some(date));
+    assert_eq!(ChallengeDate::parse("2026-02-30"), None);
+    assert_eq!(ChallengeDate::parse("yesterday"), None);
+}
+
+#[test]
+fn daily_candidate_seeds_depend_only_on_date_and_profile() {
+    let date = ChallengeDate::new(2026, 3, 7).expect("valid date");
+    let next_day = ChallengeDate::new(2026, 3, 8).expect("valid date");
+    let mut game = VariantStateStore::new(1);
+    let draw_one = stats::rules_id(&game, GameMode::Klondike, DrawMode::One);
+    let draw_three = stats::rules_id(&game, GameMode::Klondike, DrawMode::Three);
+    assert_eq!(draw_one, "draw=1;passes=unlimited;thoughtful=0");
+    *game.tripeaks_mut() = WasteBuildGame::new_with_seed_and_rules(
+        WasteBuildLayout::TriPeaks,
+        1,
+        WasteBuildRules {
+            wrap_ranks: true,
+            streak_scoring: false,
+        },
+    );
+    assert_eq!(
+        stats::rules_id(&game, GameMode::TriPeaks, DrawMode::One),
+        "wrap=1"
+    );
+
+    // Pinned so the daily deal never drifts between releases or machines.
+    assert_eq!(
+        candidate_seed(date, GameMode::Klondike, &draw_one, 0),
+        3_575_754_381_612_869_554
+    );
+    let seeds: std::collections::HashSet<u64> = (0..DAILY_CANDIDATES)
+        .map(|attempt| candidate_seed(date, GameMode::Klondike, &draw_one, attempt))
+        .chain([
+            candidate_seed(next_day, GameMode::Klondike, &draw_one, 0),
+            candidate_seed(date, GameMode::Klondike, &draw_three, 0),
+            candidate_seed(date, GameMode::Spider, &draw_one, 0),
+        ])
+        .collect();
+    assert_eq!(seeds.len(), DAILY_CANDIDATES as usize + 3);
+}
+
+#[test]
+fn daily_store_allows_one_attempt_per_day_and_tracks_wins() {
+    let mut store = DailyChallengeStore::default();
+    let freecell = StatsKey::new(GameMode::Freecell, "cards=52;fc=4");
+    let klondike = StatsKey::new(GameMode::Klondike, "draw=1;passes=unlimited;thoughtful=0");
+    let first = ChallengeDate::new(2026, 3, 1).expect("valid date");
+    let second = ChallengeDate::new(2026, 3, 2).expect("valid date");
+    let april = ChallengeDate::new(2026, 4, 1).expect("valid date");
+
+    assert!(store.record_attempt(first, freecell.clone(), 11));
+    assert!(!store.record_attempt(first, freecell.clone(), 12));
+    assert!(store.record_attempt(first, klondike.clone(), 13));
+    assert!(store.record_attempt(second, freecell.clone(), 14));
+    assert!(store.record_attempt(april, freecell.clone(), 15));
+    assert!(store.record_win(first, &freecell));
+    assert!(!store.record_win(first, &freecell));
+    assert!(!store.record_win(
+        ChallengeDate::new(2026, 3, 3).expect("valid date"),
+        &freecell
+    ));
+    assert!(store.record_win(april, &freecell));
+
+    assert_eq!(
+        store
+            .entry(first, &freecell)
+            .map(|entry| (entry.seed, entry.outcome)),
+        Some((11, DailyOutcome::Won))
+    );
+    assert_eq!(
+        store.entry(second, &freecell).map(|entry| entry.outcome),
+        Some(DailyOutcome::Attempted)
+    );
+    assert_eq!(store.won_days_in_month(2026, 3, &freecell), vec![1]);
+    assert!(store.won_days_in_month(2026, 3, &klondike).is_empty());
+
+    let reloaded = DailyChallengeStore::load_from_string(&store.serialize());
+    assert_eq!(reloaded.serialize(), store.serialize());
+    assert_eq!(reloaded.won_days_in_month(2026, 4, &freecell), vec![1]);
+    assert!(
+        DailyChallengeStore::load_from_string("2026-02-30\tfreecell\tx\t1\twon")
+            .serialize()
+            .is_empty()
+    );
+}
+
+#[test]
+fn variant_store_installs_and_exports_text_boards() {
+    let text = FreecellGame::new_with_seed_for_layout(FreecellLayout::EightOff, 9).board_text();
+    let mut store = VariantStateStore::new(2);
+    let mode = store.set_text_board(TextBoard::parse(&text).expect("Eight Off board"));
+    assert_eq!(mode, GameMode::EightOff);
+    assert_eq!(store.freecell().layout(), FreecellLayout::EightOff);
+    assert_eq!(store.board_text(GameMode::EightOff), text);
+
+    let castle = OpenTableauGame::new_with_seed(OpenTableauLayout::BeleagueredCastle, 9);
+    store.set_text_board(TextBoard::OpenTableau(castle.clone()));
+    assert_eq!(
+        store.open_tableau(OpenTableauLayout::BeleagueredCastle),
+        &castle
+    );
+
+    let pyramid = PyramidGame::new_with_seed(9);
+    assert_eq!(
+        store.set_text_board(TextBoard::Pyramid(pyramid.clone())),
+        GameMode::Pyramid
+    );
+    assert_eq!(store.board_text(GameMode::Pyramid), pyramid.board_text());
+}
+
+/// Every command shape, in the order a test game tries them.
+fn replay_test_commands() -> Vec<EngineCommand> {
+    let mut commands = vec![EngineCommand::MoveWasteToFoundation];
+    for src in 0..10 {
+        commands.push(EngineCommand::MoveTableauTopToFoundation { src });
+    }
+    for cell in 0..4 {
+        commands.push(EngineCommand::MoveFreecellToFoundation { cell });
+    }
+    for first in 0..28 {
+        commands.push(EngineCommand::RemovePyramidCards {
+            first: PyramidCardRef::Pyramid(first),
+            second: None,
+        });
+        commands.push(EngineCommand::RemovePyramidCards {
+            first: PyramidCardRef::Pyramid(first),
+            second: Some(PyramidCardRef::Waste),
+        });
+        for second in first + 1..28 {
+            commands.push(EngineCommand::RemovePyramidCards {
+                first: PyramidCardRef::Pyramid(first),
+                second: Some(PyramidCardRef::Pyramid(second)),
+            });
+        }
+    }
+    for dst in 0..10 {
+        commands.push(EngineCommand::MoveWasteToTableau { dst });
+        for cell in 0..4 {
+            commands.push(EngineCommand::MoveFreecellToTableau { cell, dst });
+        }
+        for src in 0..10 {
+            for start in 0..20 {
+                commands.push(EngineCommand::MoveTableauRunToTableau { src, start, dst });
+            }
+        }
+    }
+    for src in 0..10 {
+        for cell in 0..4 {
+            commands.push(EngineCommand::MoveTableauTopToFreecell { src, cell });
+        }
+    }
+    commands.push(EngineCommand::DrawOrRecycle {
+        draw_mode: DrawMode::Three,
+    });
+    commands
+}
+
+fn record_replay_test_game(
+    mode: GameMode,
+    seed: u64,
+    moves: usize,
+) -> (VariantStateStore, ReplayLog) {
+    let mut state = VariantStateStore::new(seed);
+    boundary::initialize_seeded_with_draw_mode(&mut state, mode, seed, DrawMode::Three);
+    let mut log = ReplayLog::new(&state, mode, seed, "Standard", DrawMode::Three);
+    let commands = replay_test_commands();
+    for _ in 0..moves {
+        let Some(command) = commands
+            .iter()
+            .copied()
+            .find(|command| boundary::execute_command(&mut state, mode, *command).changed)
+        else {
+            break;
+        };
+        log.record(command, &state);
+    }
+    (state, log)
+}
+
+#[test]
+fn replay_logs_round_trip_recorded_games() {
+    for mode in [GameMode::Klondike, GameMode::Freecell, GameMode::Pyramid] {
+        let (state, log) = record_replay_test_game(mode, 31, 40);
+        assert!(log.len() > 10, "{mode:?} recorded only {} moves", log.len());
+        let text = log.to_text();
+        let parsed = ReplayLog::parse(&text).expect("recorded replay parses");
+        assert_eq!(parsed, log);
+        assert_eq!(
+            parsed
+                .state_after(parsed.len())
+                .expect("replay plays")
+                .encode_runtime_for_session(mode),
+            state.encode_runtime_for_session(mode)
+        );
+        assert_eq!(
+            parsed
+                .state_after(0)
+                .expect("start position")
+                .encode_runtime_for_session(mode),
+            log.start_state()
+                .expect("start position")
+                .encode_runtime_for_session(mode)
+        );
+    }
+
+    // A cyclone shuffle keeps the board it dealt.
+    let (mut state, mut log) = record_replay_test_game(GameMode::Klondike, 5, 3);
+    assert!(
+        boundary::execute_command(
+            &mut state,
+            GameMode::Klondike,
+            EngineCommand::CycloneShuffleTableau
+        )
+        .changed
+    );
+    log.record(EngineCommand::CycloneShuffleTableau, &state);
+    let text = log.to_text();
+    assert!(text.contains(" X:k:"));
+    let parsed = ReplayLog::parse(&text).expect("shuffled replay parses");
+    assert_eq!(
+        parsed
+            .state_after(parsed.len())
+            .expect("replay plays")
+            .encode_runtime_for_session(GameMode::Klondike),
+        state.encode_runtime_for_session(GameMode::Klondike)
+    );
+}
+
+#[test]
+fn replay_parse_rejects_broken_logs() {
+    let (_, log) = record_replay_test_game(GameMode::Freecell, 8, 6);
+    let text = log.to_text();
+    assert!(text.starts_with("# Cardthropic replay\nGame: freecell\nSeed: v1:8\n"));
+
+    let miscounted = text.replace("Moves: 6", "Moves: 7");
+    assert_eq!(
+        ReplayLog::parse(&miscounted).unwrap_err(),
+        "the replay lists 6 moves but says it has 7"
+    );
+    let illegal = format!(
+        "{}T1-F T1-F T1-F T1-F T1-F T1-F\n",
+        text.replace("Moves: 6", "Moves: 12")
+    );
+    assert!(ReplayLog::parse(&illegal)
+        .unwrap_err()
+        .contains("is not legal there"));
+    let garbled = format!("{text}T0-F\n");
+    assert_eq!(
+        ReplayLog::parse(&garbled).unwrap_err(),
+        "`T0-F` is not a move"
+    );
+    assert_eq!(
+        ReplayLog::parse("Game: freecell\nMoves: 0\n").unwrap_err(),
+        "the replay has no Seed: line"
+    );
+}
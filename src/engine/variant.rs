@@ -29,6 +29,42 @@ pub struct SpiderVariant;
 #[derive(Debug, Clone, Copy)]
 pub struct FreecellVariant;
 
+#[derive(Debug, Clone, Copy)]
+pub struct YukonVariant;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PyramidVariant;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TriPeaksVariant;
+
+#[derive(Debug, Clone, Copy)]
+pub struct GolfVariant;
+
+#[derive(Debug, Clone, Copy)]
+pub struct FortyThievesVariant;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ScorpionVariant;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CanfieldVariant;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BakersDozenVariant;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BeleagueredCastleVariant;
+
+#[derive(Debug, Clone, Copy)]
+pub struct EightOffVariant;
+
+#[derive(Debug, Clone, Copy)]
+pub struct SeahavenTowersVariant;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BakersGameVariant;
+
 impl SolitaireVariant for KlondikeVariant {
     fn spec(&self) -> VariantSpec {
         KLONDIKE_SPEC
@@ -47,6 +83,78 @@ impl SolitaireVariant for FreecellVariant {
     }
 }
 
+impl SolitaireVariant for YukonVariant {
+    fn spec(&self) -> VariantSpec {
+        YUKON_SPEC
+    }
+}
+
+impl SolitaireVariant for PyramidVariant {
+    fn spec(&self) -> VariantSpec {
+        PYRAMID_SPEC
+    }
+}
+
+impl SolitaireVariant for TriPeaksVariant {
+    fn spec(&self) -> VariantSpec {
+        TRIPEAKS_SPEC
+    }
+}
+
+impl SolitaireVariant for GolfVariant {
+    fn spec(&self) -> VariantSpec {
+        GOLF_SPEC
+    }
+}
+
+impl SolitaireVariant for FortyThievesVariant {
+    fn spec(&self) -> VariantSpec {
+        FORTY_THIEVES_SPEC
+    }
+}
+
+impl SolitaireVariant for ScorpionVariant {
+    fn spec(&self) -> VariantSpec {
+        SCORPION_SPEC
+    }
+}
+
+impl SolitaireVariant for CanfieldVariant {
+    fn spec(&self) -> VariantSpec {
+        CANFIELD_SPEC
+    }
+}
+
+impl SolitaireVariant for BakersDozenVariant {
+    fn spec(&self) -> VariantSpec {
+        BAKERS_DOZEN_SPEC
+    }
+}
+
+impl SolitaireVariant for BeleagueredCastleVariant {
+    fn spec(&self) -> VariantSpec {
+        BELEAGUERED_CASTLE_SPEC
+    }
+}
+
+impl SolitaireVariant for EightOffVariant {
+    fn spec(&self) -> VariantSpec {
+        EIGHT_OFF_SPEC
+    }
+}
+
+impl SolitaireVariant for SeahavenTowersVariant {
+    fn spec(&self) -> VariantSpec {
+        SEAHAVEN_TOWERS_SPEC
+    }
+}
+
+impl SolitaireVariant for BakersGameVariant {
+    fn spec(&self) -> VariantSpec {
+        BAKERS_GAME_SPEC
+    }
+}
+
 const KLONDIKE_SPEC: VariantSpec = VariantSpec {
     mode: GameMode::Klondike,
     id: "klondike",
@@ -74,15 +182,166 @@ const FREECELL_SPEC: VariantSpec = VariantSpec {
     settings_placeholder: "FreeCell currently has no mode-specific settings.",
 };
 
+const YUKON_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::Yukon,
+    id: "yukon",
+    label: "Yukon",
+    emoji: "🐻",
+    engine_ready: true,
+    settings_placeholder: "Yukon can switch to Russian Solitaire's same-suit building.",
+};
+
+const PYRAMID_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::Pyramid,
+    id: "pyramid",
+    label: "Pyramid",
+    emoji: "🔺",
+    engine_ready: true,
+    settings_placeholder: "Pyramid currently has no mode-specific settings.",
+};
+
+const TRIPEAKS_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::TriPeaks,
+    id: "tripeaks",
+    label: "TriPeaks",
+    emoji: "⛰️",
+    engine_ready: true,
+    settings_placeholder: "TriPeaks can wrap King to Ace and toggle streak scoring.",
+};
+
+const GOLF_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::Golf,
+    id: "golf",
+    label: "Golf",
+    emoji: "⛳",
+    engine_ready: true,
+    settings_placeholder: "Golf can wrap King to Ace and toggle streak scoring.",
+};
+
+const FORTY_THIEVES_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::FortyThieves,
+    id: "forty_thieves",
+    label: "Forty Thieves",
+    emoji: "🗡️",
+    engine_ready: true,
+    settings_placeholder: "Forty Thieves currently has no mode-specific settings.",
+};
+
+const SCORPION_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::Scorpion,
+    id: "scorpion",
+    label: "Scorpion",
+    emoji: "🦂",
+    engine_ready: true,
+    settings_placeholder: "Scorpion currently has no mode-specific settings.",
+};
+
+const CANFIELD_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::Canfield,
+    id: "canfield",
+    label: "Canfield",
+    emoji: "🎰",
+    engine_ready: true,
+    settings_placeholder: "Canfield currently has no mode-specific settings.",
+};
+
+const BAKERS_DOZEN_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::BakersDozen,
+    id: "bakers_dozen",
+    label: "Baker's Dozen",
+    emoji: "🥖",
+    engine_ready: true,
+    settings_placeholder: "Baker's Dozen currently has no mode-specific settings.",
+};
+
+const BELEAGUERED_CASTLE_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::BeleagueredCastle,
+    id: "beleaguered_castle",
+    label: "Beleaguered Castle",
+    emoji: "🏰",
+    engine_ready: true,
+    settings_placeholder: "Beleaguered Castle currently has no mode-specific settings.",
+};
+
+const EIGHT_OFF_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::EightOff,
+    id: "eight_off",
+    label: "Eight Off",
+    emoji: "🎱",
+    engine_ready: true,
+    settings_placeholder: "Eight Off currently has no mode-specific settings.",
+};
+
+const SEAHAVEN_TOWERS_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::SeahavenTowers,
+    id: "seahaven_towers",
+    label: "Seahaven Towers",
+    emoji: "🌊",
+    engine_ready: true,
+    settings_placeholder: "Seahaven Towers currently has no mode-specific settings.",
+};
+
+const BAKERS_GAME_SPEC: VariantSpec = VariantSpec {
+    mode: GameMode::BakersGame,
+    id: "bakers_game",
+    label: "Baker's Game",
+    emoji: "🧁",
+    engine_ready: true,
+    settings_placeholder: "Baker's Game currently has no mode-specific settings.",
+};
+
 const KLONDIKE_VARIANT: KlondikeVariant = KlondikeVariant;
 const SPIDER_VARIANT: SpiderVariant = SpiderVariant;
 const FREECELL_VARIANT: FreecellVariant = FreecellVariant;
+const YUKON_VARIANT: YukonVariant = YukonVariant;
+const PYRAMID_VARIANT: PyramidVariant = PyramidVariant;
+const TRIPEAKS_VARIANT: TriPeaksVariant = TriPeaksVariant;
+const GOLF_VARIANT: GolfVariant = GolfVariant;
+const FORTY_THIEVES_VARIANT: FortyThievesVariant = FortyThievesVariant;
+const SCORPION_VARIANT: ScorpionVariant = ScorpionVariant;
+const CANFIELD_VARIANT: CanfieldVariant = CanfieldVariant;
+const BAKERS_DOZEN_VARIANT: BakersDozenVariant = BakersDozenVariant;
+const BELEAGUERED_CASTLE_VARIANT: BeleagueredCastleVariant = BeleagueredCastleVariant;
+const EIGHT_OFF_VARIANT: EightOffVariant = EightOffVariant;
+const SEAHAVEN_TOWERS_VARIANT: SeahavenTowersVariant = SeahavenTowersVariant;
+const BAKERS_GAME_VARIANT: BakersGameVariant = BakersGameVariant;
 
 #[allow(dead_code)]
-const VARIANTS: [&'static dyn SolitaireVariant; 3] =
-    [&KLONDIKE_VARIANT, &SPIDER_VARIANT, &FREECELL_VARIANT];
+const VARIANTS: [&'static dyn SolitaireVariant; 15] = [
+    &KLONDIKE_VARIANT,
+    &SPIDER_VARIANT,
+    &FREECELL_VARIANT,
+    &YUKON_VARIANT,
+    &PYRAMID_VARIANT,
+    &TRIPEAKS_VARIANT,
+    &GOLF_VARIANT,
+    &FORTY_THIEVES_VARIANT,
+    &SCORPION_VARIANT,
+    &CANFIELD_VARIANT,
+    &BAKERS_DOZEN_VARIANT,
+    &BELEAGUERED_CASTLE_VARIANT,
+    &EIGHT_OFF_VARIANT,
+    &SEAHAVEN_TOWERS_VARIANT,
+    &BAKERS_GAME_VARIANT,
+];
 
-const VARIANT_SPECS: [VariantSpec; 3] = [KLONDIKE_SPEC, SPIDER_SPEC, FREECELL_SPEC];
+const VARIANT_SPECS: [VariantSpec; 15] = [
+    KLONDIKE_SPEC,
+    SPIDER_SPEC,
+    FREECELL_SPEC,
+    YUKON_SPEC,
+    PYRAMID_SPEC,
+    TRIPEAKS_SPEC,
+    GOLF_SPEC,
+    FORTY_THIEVES_SPEC,
+    SCORPION_SPEC,
+    CANFIELD_SPEC,
+    BAKERS_DOZEN_SPEC,
+    BELEAGUERED_CASTLE_SPEC,
+    EIGHT_OFF_SPEC,
+    SEAHAVEN_TOWERS_SPEC,
+    BAKERS_GAME_SPEC,
+];
 
 #[allow(dead_code)]
 pub fn all_variants() -> &'static [&'static dyn SolitaireVariant] {
@@ -94,6 +353,18 @@ pub fn variant_for_mode(mode: GameMode) -> &'static dyn SolitaireVariant {
         GameMode::Klondike => &KLONDIKE_VARIANT,
         GameMode::Spider => &SPIDER_VARIANT,
         GameMode::Freecell => &FREECELL_VARIANT,
+        GameMode::Yukon => &YUKON_VARIANT,
+        GameMode::Pyramid => &PYRAMID_VARIANT,
+        GameMode::TriPeaks => &TRIPEAKS_VARIANT,
+        GameMode::Golf => &GOLF_VARIANT,
+        GameMode::FortyThieves => &FORTY_THIEVES_VARIANT,
+        GameMode::Scorpion => &SCORPION_VARIANT,
+        GameMode::Canfield => &CANFIELD_VARIANT,
+        GameMode::BakersDozen => &BAKERS_DOZEN_VARIANT,
+        GameMode::BeleagueredCastle => &BELEAGUERED_CASTLE_VARIANT,
+        GameMode::EightOff => &EIGHT_OFF_VARIANT,
+        GameMode::SeahavenTowers => &SEAHAVEN_TOWERS_VARIANT,
+        GameMode::BakersGame => &BAKERS_GAME_VARIANT,
     }
 }
 
@@ -0,0 +1,161 @@
+use crate::engine::automation::{AutomationProfile, CANFIELD_AUTOMATION_PROFILE};
+use crate::engine::variant_engine::{VariantCapabilities, VariantEngine};
+use crate::engine::variant_state::VariantStateStore;
+use crate::game::{CanfieldGame, Card, DrawMode, DrawResult, GameMode};
+
+/// The reserve is the only holding cell, so it answers to cell 0 of the
+/// free-cell commands.
+pub const CANFIELD_RESERVE_CELL: usize = 0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CanfieldEngine;
+
+impl VariantEngine for CanfieldEngine {
+    fn mode(&self) -> GameMode {
+        GameMode::Canfield
+    }
+
+    fn engine_ready(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> VariantCapabilities {
+        VariantCapabilities {
+            draw: true,
+            undo_redo: true,
+            smart_move: true,
+            autoplay: true,
+            rapid_wand: true,
+            robot_mode: true,
+            winnability: false,
+            seeded_deals: true,
+            cyclone_shuffle: false,
+            peek: false,
+            draw_mode_selection: false,
+        }
+    }
+
+    fn automation_profile(&self) -> AutomationProfile {
+        CANFIELD_AUTOMATION_PROFILE
+    }
+
+    fn initialize_seeded(
+        &self,
+        state: &mut VariantStateStore,
+        seed: u64,
+        _draw_mode: DrawMode,
+    ) -> bool {
+        state.set_canfield(CanfieldGame::new_with_seed(seed));
+        true
+    }
+
+    fn draw_or_recycle(
+        &self,
+        state: &mut VariantStateStore,
+        _draw_mode: DrawMode,
+    ) -> Option<DrawResult> {
+        Some(state.canfield_mut().draw())
+    }
+
+    fn move_waste_to_foundation(&self, state: &mut VariantStateStore) -> bool {
+        state.canfield_mut().move_waste_to_foundation()
+    }
+
+    fn move_waste_to_tableau(&self, state: &mut VariantStateStore, dst: usize) -> bool {
+        state.canfield_mut().move_waste_to_tableau(dst)
+    }
+
+    fn move_tableau_run_to_tableau(
+        &self,
+        state: &mut VariantStateStore,
+        src: usize,
+        start: usize,
+        dst: usize,
+    ) -> bool {
+        state.canfield_mut().move_run(src, start, dst)
+    }
+
+    fn move_tableau_top_to_foundation(&self, state: &mut VariantStateStore, src: usize) -> bool {
+        state.canfield_mut().move_tableau_top_to_foundation(src)
+    }
+
+    fn move_freecell_to_foundation(&self, state: &mut VariantStateStore, cell: usize) -> bool {
+        cell == CANFIELD_RESERVE_CELL && state.canfield_mut().move_reserve_to_foundation()
+    }
+
+    fn move_freecell_to_tableau(
+        &self,
+        state: &mut VariantStateStore,
+        cell: usize,
+        dst: usize,
+    ) -> bool {
+        cell == CANFIELD_RESERVE_CELL && state.canfield_mut().move_reserve_to_tableau(dst)
+    }
+
+    fn can_move_waste_to_tableau(&self, state: &VariantStateStore, dst: usize) -> bool {
+        state.canfield().can_move_waste_to_tableau(dst)
+    }
+
+    fn can_move_waste_to_foundation(&self, state: &VariantStateStore) -> bool {
+        state.canfield().can_move_waste_to_foundation()
+    }
+
+    fn can_move_tableau_top_to_foundation(&self, state: &VariantStateStore, src: usize) -> bool {
+        state.canfield().can_move_tableau_top_to_foundation(src)
+    }
+
+    fn can_move_freecell_to_foundation(&self, state: &VariantStateStore, cell: usize) -> bool {
+        cell == CANFIELD_RESERVE_CELL && state.canfield().can_move_reserve_to_foundation()
+    }
+
+    fn can_move_freecell_to_tableau(
+        &self,
+        state: &VariantStateStore,
+        cell: usize,
+        dst: usize,
+    ) -> bool {
+        cell == CANFIELD_RESERVE_CELL && state.canfield().can_move_reserve_to_tableau(dst)
+    }
+
+    fn can_move_tableau_run_to_tableau(
+        &self,
+        state: &VariantStateStore,
+        src: usize,
+        start: usize,
+        dst: usize,
+    ) -> bool {
+        state.canfield().can_move_run(src, start, dst)
+    }
+
+    fn waste_top(&self, state: &VariantStateStore) -> Option<Card> {
+        state.canfield().waste_top()
+    }
+
+    fn freecell_card(&self, state: &VariantStateStore, cell: usize) -> Option<Card> {
+        if cell == CANFIELD_RESERVE_CELL {
+            state.canfield().reserve_top()
+        } else {
+            None
+        }
+    }
+
+    fn tableau_top(&self, state: &VariantStateStore, col: usize) -> Option<Card> {
+        state.canfield().tableau_top(col)
+    }
+
+    fn tableau_len(&self, state: &VariantStateStore, col: usize) -> Option<usize> {
+        state.canfield().tableau().get(col).map(Vec::len)
+    }
+
+    fn foundation_top_exists(&self, state: &VariantStateStore, foundation_idx: usize) -> bool {
+        state
+            .canfield()
+            .foundations()
+            .get(foundation_idx)
+            .is_some_and(|pile| !pile.is_empty())
+    }
+
+    fn is_won(&self, state: &VariantStateStore) -> bool {
+        state.canfield().is_won()
+    }
+}
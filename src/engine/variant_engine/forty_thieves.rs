@@ -0,0 +1,127 @@
+use crate::engine::automation::{AutomationProfile, FORTY_THIEVES_AUTOMATION_PROFILE};
+use crate::engine::variant_engine::{VariantCapabilities, VariantEngine};
+use crate::engine::variant_state::VariantStateStore;
+use crate::game::{Card, DrawMode, DrawResult, FortyThievesGame, GameMode};
+
+#[derive(Debug, Clone, Copy)]
+pub struct FortyThievesEngine;
+
+impl VariantEngine for FortyThievesEngine {
+    fn mode(&self) -> GameMode {
+        GameMode::FortyThieves
+    }
+
+    fn engine_ready(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> VariantCapabilities {
+        VariantCapabilities {
+            draw: true,
+            undo_redo: true,
+            smart_move: true,
+            autoplay: true,
+            rapid_wand: true,
+            robot_mode: true,
+            winnability: false,
+            seeded_deals: true,
+            cyclone_shuffle: false,
+            peek: false,
+            draw_mode_selection: false,
+        }
+    }
+
+    fn automation_profile(&self) -> AutomationProfile {
+        FORTY_THIEVES_AUTOMATION_PROFILE
+    }
+
+    fn initialize_seeded(
+        &self,
+        state: &mut VariantStateStore,
+        seed: u64,
+        _draw_mode: DrawMode,
+    ) -> bool {
+        state.set_forty_thieves(FortyThievesGame::new_with_seed(seed));
+        true
+    }
+
+    fn draw_or_recycle(
+        &self,
+        state: &mut VariantStateStore,
+        _draw_mode: DrawMode,
+    ) -> Option<DrawResult> {
+        Some(state.forty_thieves_mut().draw())
+    }
+
+    fn move_waste_to_foundation(&self, state: &mut VariantStateStore) -> bool {
+        state.forty_thieves_mut().move_waste_to_foundation()
+    }
+
+    fn move_waste_to_tableau(&self, state: &mut VariantStateStore, dst: usize) -> bool {
+        state.forty_thieves_mut().move_waste_to_tableau(dst)
+    }
+
+    fn move_tableau_run_to_tableau(
+        &self,
+        state: &mut VariantStateStore,
+        src: usize,
+        start: usize,
+        dst: usize,
+    ) -> bool {
+        state.forty_thieves_mut().move_run(src, start, dst)
+    }
+
+    fn move_tableau_top_to_foundation(&self, state: &mut VariantStateStore, src: usize) -> bool {
+        state
+            .forty_thieves_mut()
+            .move_tableau_top_to_foundation(src)
+    }
+
+    fn can_move_waste_to_tableau(&self, state: &VariantStateStore, dst: usize) -> bool {
+        state.forty_thieves().can_move_waste_to_tableau(dst)
+    }
+
+    fn can_move_waste_to_foundation(&self, state: &VariantStateStore) -> bool {
+        state.forty_thieves().can_move_waste_to_foundation()
+    }
+
+    fn can_move_tableau_top_to_foundation(&self, state: &VariantStateStore, src: usize) -> bool {
+        state
+            .forty_thieves()
+            .can_move_tableau_top_to_foundation(src)
+    }
+
+    fn can_move_tableau_run_to_tableau(
+        &self,
+        state: &VariantStateStore,
+        src: usize,
+        start: usize,
+        dst: usize,
+    ) -> bool {
+        state.forty_thieves().can_move_run(src, start, dst)
+    }
+
+    fn waste_top(&self, state: &VariantStateStore) -> Option<Card> {
+        state.forty_thieves().waste_top()
+    }
+
+    fn tableau_top(&self, state: &VariantStateStore, col: usize) -> Option<Card> {
+        state.forty_thieves().tableau_top(col)
+    }
+
+    fn tableau_len(&self, state: &VariantStateStore, col: usize) -> Option<usize> {
+        state.forty_thieves().tableau().get(col).map(Vec::len)
+    }
+
+    fn foundation_top_exists(&self, state: &VariantStateStore, foundation_idx: usize) -> bool {
+        state
+            .forty_thieves()
+            .foundations()
+            .get(foundation_idx)
+            .is_some_and(|pile| !pile.is_empty())
+    }
+
+    fn is_won(&self, state: &VariantStateStore) -> bool {
+        state.forty_thieves().is_won()
+    }
+}
@@ -47,8 +47,12 @@ impl VariantEngine for KlondikeEngine {
         seed: u64,
         draw_mode: DrawMode,
     ) -> bool {
+        let pass_limit = state.klondike().pass_limit();
+        let thoughtful = state.klondike().is_thoughtful();
         let mut game = KlondikeGame::new_with_seed(seed);
         game.set_draw_mode(draw_mode);
+        game.set_pass_limit(pass_limit);
+        game.set_thoughtful(thoughtful);
         state.set_klondike(game);
         true
     }
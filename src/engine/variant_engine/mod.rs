@@ -7,13 +7,30 @@
 
 use crate::engine::automation::AutomationProfile;
 use crate::engine::variant_state::VariantStateStore;
-use crate::game::{Card, DrawMode, DrawResult, GameMode, KlondikeGame};
+use crate::game::{
+    Card, DrawMode, DrawResult, FreecellLayout, GameMode, KlondikeGame, OpenTableauLayout,
+    PyramidCardRef, WasteBuildLayout,
+};
 
+mod canfield;
+mod forty_thieves;
 mod klondike;
+mod open_tableau;
+mod pyramid;
+mod scorpion;
 mod stubs;
+mod waste_build;
+mod yukon;
 
+pub use canfield::{CanfieldEngine, CANFIELD_RESERVE_CELL};
+pub use forty_thieves::FortyThievesEngine;
 pub use klondike::KlondikeEngine;
+pub use open_tableau::OpenTableauEngine;
+pub use pyramid::PyramidEngine;
+pub use scorpion::ScorpionEngine;
 pub use stubs::{FreecellEngine, SpiderEngine};
+pub use waste_build::WasteBuildEngine;
+pub use yukon::YukonEngine;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct VariantCapabilities {
@@ -156,6 +173,23 @@ pub trait VariantEngine: Sync {
         false
     }
 
+    /// Clears a king (`second` is `None`) or a pair summing to 13 in
+    /// pair-removal layouts such as Pyramid.
+    fn remove_pyramid_cards(
+        &self,
+        _state: &mut VariantStateStore,
+        _first: PyramidCardRef,
+        _second: Option<PyramidCardRef>,
+    ) -> bool {
+        false
+    }
+
+    /// Plays an exposed tableau slot onto the waste in waste-building
+    /// layouts such as TriPeaks and Golf.
+    fn play_to_waste(&self, _state: &mut VariantStateStore, _slot: usize) -> bool {
+        false
+    }
+
     fn can_move_waste_to_tableau(&self, _state: &VariantStateStore, _dst: usize) -> bool {
         false
     }
@@ -213,6 +247,12 @@ pub trait VariantEngine: Sync {
         None
     }
 
+    /// Card in a single-card holding cell. FreeCell exposes its free cells;
+    /// Canfield exposes its reserve top as cell 0.
+    fn freecell_card(&self, _state: &VariantStateStore, _cell: usize) -> Option<Card> {
+        None
+    }
+
     fn tableau_top(&self, _state: &VariantStateStore, _col: usize) -> Option<Card> {
         None
     }
@@ -240,10 +280,39 @@ pub trait VariantEngine: Sync {
 
 const KLONDIKE_ENGINE: KlondikeEngine = KlondikeEngine;
 const SPIDER_ENGINE: SpiderEngine = SpiderEngine;
-const FREECELL_ENGINE: FreecellEngine = FreecellEngine;
-
-const ENGINE_REGISTRY: [&'static dyn VariantEngine; 3] =
-    [&KLONDIKE_ENGINE, &SPIDER_ENGINE, &FREECELL_ENGINE];
+const FREECELL_ENGINE: FreecellEngine = FreecellEngine::new(FreecellLayout::FreeCell);
+const YUKON_ENGINE: YukonEngine = YukonEngine;
+const PYRAMID_ENGINE: PyramidEngine = PyramidEngine;
+const TRIPEAKS_ENGINE: WasteBuildEngine = WasteBuildEngine::new(WasteBuildLayout::TriPeaks);
+const GOLF_ENGINE: WasteBuildEngine = WasteBuildEngine::new(WasteBuildLayout::Golf);
+const FORTY_THIEVES_ENGINE: FortyThievesEngine = FortyThievesEngine;
+const SCORPION_ENGINE: ScorpionEngine = ScorpionEngine;
+const CANFIELD_ENGINE: CanfieldEngine = CanfieldEngine;
+const BAKERS_DOZEN_ENGINE: OpenTableauEngine =
+    OpenTableauEngine::new(OpenTableauLayout::BakersDozen);
+const BELEAGUERED_CASTLE_ENGINE: OpenTableauEngine =
+    OpenTableauEngine::new(OpenTableauLayout::BeleagueredCastle);
+const EIGHT_OFF_ENGINE: FreecellEngine = FreecellEngine::new(FreecellLayout::EightOff);
+const SEAHAVEN_TOWERS_ENGINE: FreecellEngine = FreecellEngine::new(FreecellLayout::SeahavenTowers);
+const BAKERS_GAME_ENGINE: FreecellEngine = FreecellEngine::new(FreecellLayout::BakersGame);
+
+const ENGINE_REGISTRY: [&'static dyn VariantEngine; 15] = [
+    &KLONDIKE_ENGINE,
+    &SPIDER_ENGINE,
+    &FREECELL_ENGINE,
+    &YUKON_ENGINE,
+    &PYRAMID_ENGINE,
+    &TRIPEAKS_ENGINE,
+    &GOLF_ENGINE,
+    &FORTY_THIEVES_ENGINE,
+    &SCORPION_ENGINE,
+    &CANFIELD_ENGINE,
+    &BAKERS_DOZEN_ENGINE,
+    &BELEAGUERED_CASTLE_ENGINE,
+    &EIGHT_OFF_ENGINE,
+    &SEAHAVEN_TOWERS_ENGINE,
+    &BAKERS_GAME_ENGINE,
+];
 
 pub fn all_engines() -> &'static [&'static dyn VariantEngine] {
     &ENGINE_REGISTRY
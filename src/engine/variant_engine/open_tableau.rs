@@ -0,0 +1,117 @@
+use crate::engine::automation::AutomationProfile;
+use crate::engine::variant_engine::{VariantCapabilities, VariantEngine};
+use crate::engine::variant_state::VariantStateStore;
+use crate::game::{Card, DrawMode, GameMode, OpenTableauGame, OpenTableauLayout};
+
+/// Shared engine for Baker's Dozen and Beleaguered Castle; the layout picks
+/// the mode.
+#[derive(Debug, Clone, Copy)]
+pub struct OpenTableauEngine {
+    layout: OpenTableauLayout,
+}
+
+impl OpenTableauEngine {
+    pub const fn new(layout: OpenTableauLayout) -> Self {
+        Self { layout }
+    }
+}
+
+impl VariantEngine for OpenTableauEngine {
+    fn mode(&self) -> GameMode {
+        self.layout.mode()
+    }
+
+    fn engine_ready(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> VariantCapabilities {
+        VariantCapabilities {
+            draw: false,
+            undo_redo: true,
+            smart_move: true,
+            autoplay: true,
+            rapid_wand: true,
+            robot_mode: true,
+            winnability: true,
+            seeded_deals: true,
+            cyclone_shuffle: false,
+            peek: false,
+            draw_mode_selection: false,
+        }
+    }
+
+    fn automation_profile(&self) -> AutomationProfile {
+        AutomationProfile::for_mode(self.mode())
+    }
+
+    fn initialize_seeded(
+        &self,
+        state: &mut VariantStateStore,
+        seed: u64,
+        _draw_mode: DrawMode,
+    ) -> bool {
+        *state.open_tableau_mut(self.layout) = OpenTableauGame::new_with_seed(self.layout, seed);
+        true
+    }
+
+    fn move_tableau_run_to_tableau(
+        &self,
+        state: &mut VariantStateStore,
+        src: usize,
+        start: usize,
+        dst: usize,
+    ) -> bool {
+        state
+            .open_tableau_mut(self.layout)
+            .move_run(src, start, dst)
+    }
+
+    fn move_tableau_top_to_foundation(&self, state: &mut VariantStateStore, src: usize) -> bool {
+        state
+            .open_tableau_mut(self.layout)
+            .move_tableau_top_to_foundation(src)
+    }
+
+    fn can_move_tableau_top_to_foundation(&self, state: &VariantStateStore, src: usize) -> bool {
+        state
+            .open_tableau(self.layout)
+            .can_move_tableau_top_to_foundation(src)
+    }
+
+    fn can_move_tableau_run_to_tableau(
+        &self,
+        state: &VariantStateStore,
+        src: usize,
+        start: usize,
+        dst: usize,
+    ) -> bool {
+        state
+            .open_tableau(self.layout)
+            .can_move_run(src, start, dst)
+    }
+
+    fn tableau_top(&self, state: &VariantStateStore, col: usize) -> Option<Card> {
+        state.open_tableau(self.layout).tableau_top(col)
+    }
+
+    fn tableau_len(&self, state: &VariantStateStore, col: usize) -> Option<usize> {
+        state
+            .open_tableau(self.layout)
+            .tableau()
+            .get(col)
+            .map(Vec::len)
+    }
+
+    fn foundation_top_exists(&self, state: &VariantStateStore, foundation_idx: usize) -> bool {
+        state
+            .open_tableau(self.layout)
+            .foundations()
+            .get(foundation_idx)
+            .is_some_and(|pile| !pile.is_empty())
+    }
+
+    fn is_won(&self, state: &VariantStateStore) -> bool {
+        state.open_tableau(self.layout).is_won()
+    }
+}
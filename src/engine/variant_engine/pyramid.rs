@@ -0,0 +1,80 @@
+use crate::engine::automation::{AutomationProfile, PYRAMID_AUTOMATION_PROFILE};
+use crate::engine::variant_engine::{VariantCapabilities, VariantEngine};
+use crate::engine::variant_state::VariantStateStore;
+use crate::game::{Card, DrawMode, DrawResult, GameMode, PyramidCardRef, PyramidGame, PyramidMove};
+
+#[derive(Debug, Clone, Copy)]
+pub struct PyramidEngine;
+
+impl VariantEngine for PyramidEngine {
+    fn mode(&self) -> GameMode {
+        GameMode::Pyramid
+    }
+
+    fn engine_ready(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> VariantCapabilities {
+        VariantCapabilities {
+            draw: true,
+            undo_redo: true,
+            smart_move: false,
+            autoplay: true,
+            rapid_wand: true,
+            robot_mode: true,
+            winnability: false,
+            seeded_deals: true,
+            cyclone_shuffle: false,
+            peek: false,
+            draw_mode_selection: false,
+        }
+    }
+
+    fn automation_profile(&self) -> AutomationProfile {
+        PYRAMID_AUTOMATION_PROFILE
+    }
+
+    fn initialize_seeded(
+        &self,
+        state: &mut VariantStateStore,
+        seed: u64,
+        _draw_mode: DrawMode,
+    ) -> bool {
+        state.set_pyramid(PyramidGame::new_with_seed(seed));
+        true
+    }
+
+    fn draw_or_recycle(
+        &self,
+        state: &mut VariantStateStore,
+        _draw_mode: DrawMode,
+    ) -> Option<DrawResult> {
+        Some(state.pyramid_mut().draw_or_recycle())
+    }
+
+    fn remove_pyramid_cards(
+        &self,
+        state: &mut VariantStateStore,
+        first: PyramidCardRef,
+        second: Option<PyramidCardRef>,
+    ) -> bool {
+        let pyramid_move = match second {
+            Some(second) => PyramidMove::RemovePair(first, second),
+            None => PyramidMove::RemoveKing(first),
+        };
+        state.pyramid_mut().apply_move(pyramid_move)
+    }
+
+    fn waste_top(&self, state: &VariantStateStore) -> Option<Card> {
+        state.pyramid().waste_top()
+    }
+
+    fn foundation_top_exists(&self, state: &VariantStateStore, foundation_idx: usize) -> bool {
+        foundation_idx == 0 && !state.pyramid().removed().is_empty()
+    }
+
+    fn is_won(&self, state: &VariantStateStore) -> bool {
+        state.pyramid().is_won()
+    }
+}
@@ -0,0 +1,87 @@
+use crate::engine::automation::{AutomationProfile, SCORPION_AUTOMATION_PROFILE};
+use crate::engine::variant_engine::{VariantCapabilities, VariantEngine};
+use crate::engine::variant_state::VariantStateStore;
+use crate::game::{Card, DrawMode, DrawResult, GameMode, ScorpionGame};
+
+#[derive(Debug, Clone, Copy)]
+pub struct ScorpionEngine;
+
+impl VariantEngine for ScorpionEngine {
+    fn mode(&self) -> GameMode {
+        GameMode::Scorpion
+    }
+
+    fn engine_ready(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> VariantCapabilities {
+        VariantCapabilities {
+            draw: true,
+            undo_redo: true,
+            smart_move: true,
+            autoplay: true,
+            rapid_wand: true,
+            robot_mode: true,
+            winnability: false,
+            seeded_deals: true,
+            cyclone_shuffle: false,
+            peek: false,
+            draw_mode_selection: false,
+        }
+    }
+
+    fn automation_profile(&self) -> AutomationProfile {
+        SCORPION_AUTOMATION_PROFILE
+    }
+
+    fn initialize_seeded(
+        &self,
+        state: &mut VariantStateStore,
+        seed: u64,
+        _draw_mode: DrawMode,
+    ) -> bool {
+        state.set_scorpion(ScorpionGame::new_with_seed(seed));
+        true
+    }
+
+    fn draw_or_recycle(
+        &self,
+        state: &mut VariantStateStore,
+        _draw_mode: DrawMode,
+    ) -> Option<DrawResult> {
+        Some(state.scorpion_mut().deal_reserve())
+    }
+
+    fn move_tableau_run_to_tableau(
+        &self,
+        state: &mut VariantStateStore,
+        src: usize,
+        start: usize,
+        dst: usize,
+    ) -> bool {
+        state.scorpion_mut().move_run(src, start, dst)
+    }
+
+    fn can_move_tableau_run_to_tableau(
+        &self,
+        state: &VariantStateStore,
+        src: usize,
+        start: usize,
+        dst: usize,
+    ) -> bool {
+        state.scorpion().can_move_run(src, start, dst)
+    }
+
+    fn tableau_top(&self, state: &VariantStateStore, col: usize) -> Option<Card> {
+        state.scorpion().tableau_top(col)
+    }
+
+    fn tableau_len(&self, state: &VariantStateStore, col: usize) -> Option<usize> {
+        state.scorpion().tableau().get(col).map(Vec::len)
+    }
+
+    fn is_won(&self, state: &VariantStateStore) -> bool {
+        state.scorpion().is_won()
+    }
+}
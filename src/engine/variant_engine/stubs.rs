@@ -3,7 +3,7 @@ use crate::engine::automation::{
 };
 use crate::engine::variant_engine::{VariantCapabilities, VariantEngine};
 use crate::engine::variant_state::VariantStateStore;
-use crate::game::{Card, DrawMode, DrawResult, FreecellGame, GameMode, SpiderGame};
+use crate::game::{Card, DrawMode, DrawResult, FreecellGame, FreecellLayout, GameMode, SpiderGame};
 
 #[derive(Debug, Clone, Copy)]
 pub struct SpiderEngine;
@@ -44,7 +44,10 @@ impl VariantEngine for SpiderEngine {
         _draw_mode: DrawMode,
     ) -> bool {
         let suit_mode = state.spider().suit_mode();
-        state.set_spider(SpiderGame::new_with_seed_and_mode(seed, suit_mode));
+        let rule_set = state.spider().rule_set();
+        state.set_spider(SpiderGame::new_with_seed_and_rules(
+            seed, suit_mode, rule_set,
+        ));
         true
     }
 
@@ -101,12 +104,22 @@ impl VariantEngine for SpiderEngine {
     }
 }
 
+/// Shared engine for FreeCell, Eight Off and Seahaven Towers; the layout
+/// picks the mode.
 #[derive(Debug, Clone, Copy)]
-pub struct FreecellEngine;
+pub struct FreecellEngine {
+    layout: FreecellLayout,
+}
+
+impl FreecellEngine {
+    pub const fn new(layout: FreecellLayout) -> Self {
+        Self { layout }
+    }
+}
 
 impl VariantEngine for FreecellEngine {
     fn mode(&self) -> GameMode {
-        GameMode::Freecell
+        self.layout.mode()
     }
 
     fn engine_ready(&self) -> bool {
@@ -139,8 +152,12 @@ impl VariantEngine for FreecellEngine {
         seed: u64,
         _draw_mode: DrawMode,
     ) -> bool {
-        let card_count_mode = state.freecell().card_count_mode();
-        let freecell_count = state.freecell().freecell_count() as u8;
+        if self.layout != FreecellLayout::FreeCell {
+            state.set_freecell(FreecellGame::new_with_seed_for_layout(self.layout, seed));
+            return true;
+        }
+        let card_count_mode = state.freecell_for(self.layout).card_count_mode();
+        let freecell_count = state.freecell_for(self.layout).freecell_count() as u8;
         state.set_freecell(FreecellGame::new_with_seed_and_card_count_and_cells(
             seed,
             card_count_mode,
@@ -157,16 +174,20 @@ impl VariantEngine for FreecellEngine {
         dst: usize,
     ) -> bool {
         state
-            .freecell_mut()
+            .freecell_for_mut(self.layout)
             .move_tableau_run_to_tableau(src, start, dst)
     }
 
     fn cyclone_shuffle_tableau(&self, state: &mut VariantStateStore) -> bool {
-        state.freecell_mut().cyclone_shuffle_tableau()
+        state
+            .freecell_for_mut(self.layout)
+            .cyclone_shuffle_tableau()
     }
 
     fn move_tableau_top_to_foundation(&self, state: &mut VariantStateStore, src: usize) -> bool {
-        state.freecell_mut().move_tableau_top_to_foundation(src)
+        state
+            .freecell_for_mut(self.layout)
+            .move_tableau_top_to_foundation(src)
     }
 
     fn move_tableau_top_to_freecell(
@@ -175,11 +196,15 @@ impl VariantEngine for FreecellEngine {
         src: usize,
         cell: usize,
     ) -> bool {
-        state.freecell_mut().move_tableau_top_to_freecell(src, cell)
+        state
+            .freecell_for_mut(self.layout)
+            .move_tableau_top_to_freecell(src, cell)
     }
 
     fn move_freecell_to_foundation(&self, state: &mut VariantStateStore, cell: usize) -> bool {
-        state.freecell_mut().move_freecell_to_foundation(cell)
+        state
+            .freecell_for_mut(self.layout)
+            .move_freecell_to_foundation(cell)
     }
 
     fn move_freecell_to_tableau(
@@ -188,7 +213,9 @@ impl VariantEngine for FreecellEngine {
         cell: usize,
         dst: usize,
     ) -> bool {
-        state.freecell_mut().move_freecell_to_tableau(cell, dst)
+        state
+            .freecell_for_mut(self.layout)
+            .move_freecell_to_tableau(cell, dst)
     }
 
     fn move_foundation_top_to_tableau(
@@ -198,12 +225,14 @@ impl VariantEngine for FreecellEngine {
         dst: usize,
     ) -> bool {
         state
-            .freecell_mut()
+            .freecell_for_mut(self.layout)
             .move_foundation_top_to_tableau(foundation_idx, dst)
     }
 
     fn can_move_tableau_top_to_foundation(&self, state: &VariantStateStore, src: usize) -> bool {
-        state.freecell().can_move_tableau_top_to_foundation(src)
+        state
+            .freecell_for(self.layout)
+            .can_move_tableau_top_to_foundation(src)
     }
 
     fn can_move_tableau_top_to_freecell(
@@ -212,11 +241,15 @@ impl VariantEngine for FreecellEngine {
         src: usize,
         cell: usize,
     ) -> bool {
-        state.freecell().can_move_tableau_top_to_freecell(src, cell)
+        state
+            .freecell_for(self.layout)
+            .can_move_tableau_top_to_freecell(src, cell)
     }
 
     fn can_move_freecell_to_foundation(&self, state: &VariantStateStore, cell: usize) -> bool {
-        state.freecell().can_move_freecell_to_foundation(cell)
+        state
+            .freecell_for(self.layout)
+            .can_move_freecell_to_foundation(cell)
     }
 
     fn can_move_freecell_to_tableau(
@@ -225,7 +258,9 @@ impl VariantEngine for FreecellEngine {
         cell: usize,
         dst: usize,
     ) -> bool {
-        state.freecell().can_move_freecell_to_tableau(cell, dst)
+        state
+            .freecell_for(self.layout)
+            .can_move_freecell_to_tableau(cell, dst)
     }
 
     fn can_move_tableau_run_to_tableau(
@@ -236,7 +271,7 @@ impl VariantEngine for FreecellEngine {
         dst: usize,
     ) -> bool {
         state
-            .freecell()
+            .freecell_for(self.layout)
             .can_move_tableau_run_to_tableau(src, start, dst)
     }
 
@@ -247,21 +282,29 @@ impl VariantEngine for FreecellEngine {
         dst: usize,
     ) -> bool {
         state
-            .freecell()
+            .freecell_for(self.layout)
             .can_move_foundation_top_to_tableau(foundation_idx, dst)
     }
 
+    fn freecell_card(&self, state: &VariantStateStore, cell: usize) -> Option<Card> {
+        state.freecell_for(self.layout).freecell_card(cell)
+    }
+
     fn tableau_top(&self, state: &VariantStateStore, col: usize) -> Option<Card> {
-        state.freecell().tableau_top(col)
+        state.freecell_for(self.layout).tableau_top(col)
     }
 
     fn tableau_len(&self, state: &VariantStateStore, col: usize) -> Option<usize> {
-        state.freecell().tableau().get(col).map(Vec::len)
+        state
+            .freecell_for(self.layout)
+            .tableau()
+            .get(col)
+            .map(Vec::len)
     }
 
     fn foundation_top_exists(&self, state: &VariantStateStore, foundation_idx: usize) -> bool {
         state
-            .freecell()
+            .freecell_for(self.layout)
             .foundations()
             .get(foundation_idx)
             .map(|pile| !pile.is_empty())
@@ -269,6 +312,6 @@ impl VariantEngine for FreecellEngine {
     }
 
     fn is_won(&self, state: &VariantStateStore) -> bool {
-        state.freecell().is_won()
+        state.freecell_for(self.layout).is_won()
     }
 }
@@ -0,0 +1,84 @@
+use crate::engine::automation::AutomationProfile;
+use crate::engine::variant_engine::{VariantCapabilities, VariantEngine};
+use crate::engine::variant_state::VariantStateStore;
+use crate::game::{Card, DrawMode, DrawResult, GameMode, WasteBuildGame, WasteBuildLayout};
+
+/// Shared engine for TriPeaks and Golf; the layout picks the mode.
+#[derive(Debug, Clone, Copy)]
+pub struct WasteBuildEngine {
+    layout: WasteBuildLayout,
+}
+
+impl WasteBuildEngine {
+    pub const fn new(layout: WasteBuildLayout) -> Self {
+        Self { layout }
+    }
+}
+
+impl VariantEngine for WasteBuildEngine {
+    fn mode(&self) -> GameMode {
+        self.layout.mode()
+    }
+
+    fn engine_ready(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> VariantCapabilities {
+        VariantCapabilities {
+            draw: true,
+            undo_redo: true,
+            smart_move: false,
+            autoplay: true,
+            rapid_wand: true,
+            robot_mode: true,
+            winnability: true,
+            seeded_deals: true,
+            cyclone_shuffle: false,
+            peek: false,
+            draw_mode_selection: false,
+        }
+    }
+
+    fn automation_profile(&self) -> AutomationProfile {
+        AutomationProfile::for_mode(self.mode())
+    }
+
+    fn initialize_seeded(
+        &self,
+        state: &mut VariantStateStore,
+        seed: u64,
+        _draw_mode: DrawMode,
+    ) -> bool {
+        let rules = state.waste_build(self.layout).rules();
+        *state.waste_build_mut(self.layout) =
+            WasteBuildGame::new_with_seed_and_rules(self.layout, seed, rules);
+        true
+    }
+
+    fn draw_or_recycle(
+        &self,
+        state: &mut VariantStateStore,
+        _draw_mode: DrawMode,
+    ) -> Option<DrawResult> {
+        // No redeals: an empty stock is simply the end of the line.
+        let drew = state.waste_build_mut(self.layout).draw();
+        Some(if drew {
+            DrawResult::DrewFromStock
+        } else {
+            DrawResult::NoOp
+        })
+    }
+
+    fn play_to_waste(&self, state: &mut VariantStateStore, slot: usize) -> bool {
+        state.waste_build_mut(self.layout).play(slot)
+    }
+
+    fn waste_top(&self, state: &VariantStateStore) -> Option<Card> {
+        state.waste_build(self.layout).waste_top()
+    }
+
+    fn is_won(&self, state: &VariantStateStore) -> bool {
+        state.waste_build(self.layout).is_won()
+    }
+}
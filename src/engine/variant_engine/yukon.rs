@@ -0,0 +1,122 @@
+use crate::engine::automation::{AutomationProfile, YUKON_AUTOMATION_PROFILE};
+use crate::engine::variant_engine::{VariantCapabilities, VariantEngine};
+use crate::engine::variant_state::VariantStateStore;
+use crate::game::{Card, DrawMode, GameMode, YukonGame};
+
+#[derive(Debug, Clone, Copy)]
+pub struct YukonEngine;
+
+impl VariantEngine for YukonEngine {
+    fn mode(&self) -> GameMode {
+        GameMode::Yukon
+    }
+
+    fn engine_ready(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> VariantCapabilities {
+        VariantCapabilities {
+            draw: false,
+            undo_redo: true,
+            smart_move: true,
+            autoplay: false,
+            rapid_wand: false,
+            robot_mode: false,
+            winnability: false,
+            seeded_deals: true,
+            cyclone_shuffle: true,
+            peek: true,
+            draw_mode_selection: false,
+        }
+    }
+
+    fn automation_profile(&self) -> AutomationProfile {
+        YUKON_AUTOMATION_PROFILE
+    }
+
+    fn initialize_seeded(
+        &self,
+        state: &mut VariantStateStore,
+        seed: u64,
+        _draw_mode: DrawMode,
+    ) -> bool {
+        let rule_set = state.yukon().rule_set();
+        state.set_yukon(YukonGame::new_with_seed_and_rules(seed, rule_set));
+        true
+    }
+
+    fn cyclone_shuffle_tableau(&self, state: &mut VariantStateStore) -> bool {
+        state.yukon_mut().cyclone_shuffle_tableau()
+    }
+
+    fn move_tableau_run_to_tableau(
+        &self,
+        state: &mut VariantStateStore,
+        src: usize,
+        start: usize,
+        dst: usize,
+    ) -> bool {
+        state.yukon_mut().move_run(src, start, dst)
+    }
+
+    fn move_tableau_top_to_foundation(&self, state: &mut VariantStateStore, src: usize) -> bool {
+        state.yukon_mut().move_tableau_top_to_foundation(src)
+    }
+
+    fn move_foundation_top_to_tableau(
+        &self,
+        state: &mut VariantStateStore,
+        foundation_idx: usize,
+        dst: usize,
+    ) -> bool {
+        state
+            .yukon_mut()
+            .move_foundation_top_to_tableau(foundation_idx, dst)
+    }
+
+    fn can_move_tableau_top_to_foundation(&self, state: &VariantStateStore, src: usize) -> bool {
+        state.yukon().can_move_tableau_top_to_foundation(src)
+    }
+
+    fn can_move_tableau_run_to_tableau(
+        &self,
+        state: &VariantStateStore,
+        src: usize,
+        start: usize,
+        dst: usize,
+    ) -> bool {
+        state.yukon().can_move_run(src, start, dst)
+    }
+
+    fn can_move_foundation_top_to_tableau(
+        &self,
+        state: &VariantStateStore,
+        foundation_idx: usize,
+        dst: usize,
+    ) -> bool {
+        state
+            .yukon()
+            .can_move_foundation_top_to_tableau(foundation_idx, dst)
+    }
+
+    fn tableau_top(&self, state: &VariantStateStore, col: usize) -> Option<Card> {
+        state.yukon().tableau_top(col)
+    }
+
+    fn tableau_len(&self, state: &VariantStateStore, col: usize) -> Option<usize> {
+        state.yukon().tableau().get(col).map(Vec::len)
+    }
+
+    fn foundation_top_exists(&self, state: &VariantStateStore, foundation_idx: usize) -> bool {
+        state
+            .yukon()
+            .foundations()
+            .get(foundation_idx)
+            .is_some_and(|pile| !pile.is_empty())
+    }
+
+    fn is_won(&self, state: &VariantStateStore) -> bool {
+        state.yukon().is_won()
+    }
+}
@@ -2,13 +2,31 @@ use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
 
 use crate::engine::game_mode::VariantRuntime;
-use crate::game::{FreecellGame, GameMode, KlondikeGame, SpiderGame};
+use crate::game::{
+    CanfieldGame, FortyThievesGame, FreecellGame, FreecellLayout, GameMode, KlondikeGame,
+    OpenTableauGame, OpenTableauLayout, PyramidGame, ScorpionGame, SpiderGame, TextBoard,
+    WasteBuildGame, WasteBuildLayout, YukonGame,
+};
 
 #[derive(Debug, Clone)]
 pub struct VariantStateStore {
     klondike: KlondikeGame,
     spider: SpiderGame,
     freecell: FreecellGame,
+    yukon: YukonGame,
+    pyramid: PyramidGame,
+    tripeaks: WasteBuildGame,
+    golf: WasteBuildGame,
+    forty_thieves: FortyThievesGame,
+    scorpion: ScorpionGame,
+    canfield: CanfieldGame,
+    bakers_dozen: OpenTableauGame,
+    beleaguered_castle: OpenTableauGame,
+    eight_off: FreecellGame,
+    seahaven_towers: FreecellGame,
+    bakers_game: FreecellGame,
+    /// Which FreeCell-family game `freecell()` hands out.
+    active_freecell: FreecellLayout,
     parked: HashMap<GameMode, VariantRuntime>,
 }
 
@@ -18,6 +36,25 @@ impl VariantStateStore {
             klondike: KlondikeGame::new_with_seed(seed),
             spider: SpiderGame::new_with_seed(seed),
             freecell: FreecellGame::new_with_seed(seed),
+            yukon: YukonGame::new_with_seed(seed),
+            pyramid: PyramidGame::new_with_seed(seed),
+            tripeaks: WasteBuildGame::new_with_seed(WasteBuildLayout::TriPeaks, seed),
+            golf: WasteBuildGame::new_with_seed(WasteBuildLayout::Golf, seed),
+            forty_thieves: FortyThievesGame::new_with_seed(seed),
+            scorpion: ScorpionGame::new_with_seed(seed),
+            canfield: CanfieldGame::new_with_seed(seed),
+            bakers_dozen: OpenTableauGame::new_with_seed(OpenTableauLayout::BakersDozen, seed),
+            beleaguered_castle: OpenTableauGame::new_with_seed(
+                OpenTableauLayout::BeleagueredCastle,
+                seed,
+            ),
+            eight_off: FreecellGame::new_with_seed_for_layout(FreecellLayout::EightOff, seed),
+            seahaven_towers: FreecellGame::new_with_seed_for_layout(
+                FreecellLayout::SeahavenTowers,
+                seed,
+            ),
+            bakers_game: FreecellGame::new_with_seed_for_layout(FreecellLayout::BakersGame, seed),
+            active_freecell: FreecellLayout::FreeCell,
             parked: HashMap::new(),
         }
     }
@@ -46,23 +83,231 @@ impl VariantStateStore {
         &mut self.spider
     }
 
+    /// Stores the game under its own layout.
     pub fn set_freecell(&mut self, game: FreecellGame) {
-        self.freecell = game;
+        let layout = game.layout();
+        *self.freecell_for_mut(layout) = game;
     }
 
+    /// The FreeCell-family game for the active layout; see
+    /// [`Self::activate_freecell_layout`].
     pub fn freecell(&self) -> &FreecellGame {
-        &self.freecell
+        self.freecell_for(self.active_freecell)
     }
 
     pub fn freecell_mut(&mut self) -> &mut FreecellGame {
-        &mut self.freecell
+        self.freecell_for_mut(self.active_freecell)
+    }
+
+    /// The FreeCell-family modes share one engine; pick the game for a
+    /// layout.
+    pub fn freecell_for(&self, layout: FreecellLayout) -> &FreecellGame {
+        match layout {
+            FreecellLayout::FreeCell => &self.freecell,
+            FreecellLayout::EightOff => &self.eight_off,
+            FreecellLayout::SeahavenTowers => &self.seahaven_towers,
+            FreecellLayout::BakersGame => &self.bakers_game,
+        }
+    }
+
+    pub fn freecell_for_mut(&mut self, layout: FreecellLayout) -> &mut FreecellGame {
+        match layout {
+            FreecellLayout::FreeCell => &mut self.freecell,
+            FreecellLayout::EightOff => &mut self.eight_off,
+            FreecellLayout::SeahavenTowers => &mut self.seahaven_towers,
+            FreecellLayout::BakersGame => &mut self.bakers_game,
+        }
+    }
+
+    /// Points `freecell()` at a layout's game. Mode switches call this so
+    /// the window's FreeCell paths follow the selected variant.
+    pub fn activate_freecell_layout(&mut self, layout: FreecellLayout) {
+        self.active_freecell = layout;
+    }
+
+    pub fn set_yukon(&mut self, game: YukonGame) {
+        self.yukon = game;
+    }
+
+    pub fn yukon(&self) -> &YukonGame {
+        &self.yukon
+    }
+
+    pub fn yukon_mut(&mut self) -> &mut YukonGame {
+        &mut self.yukon
+    }
+
+    pub fn set_pyramid(&mut self, game: PyramidGame) {
+        self.pyramid = game;
+    }
+
+    pub fn pyramid(&self) -> &PyramidGame {
+        &self.pyramid
+    }
+
+    pub fn pyramid_mut(&mut self) -> &mut PyramidGame {
+        &mut self.pyramid
+    }
+
+    pub fn set_tripeaks(&mut self, game: WasteBuildGame) {
+        self.tripeaks = game;
+    }
+
+    pub fn tripeaks(&self) -> &WasteBuildGame {
+        &self.tripeaks
+    }
+
+    pub fn tripeaks_mut(&mut self) -> &mut WasteBuildGame {
+        &mut self.tripeaks
+    }
+
+    pub fn set_golf(&mut self, game: WasteBuildGame) {
+        self.golf = game;
+    }
+
+    pub fn golf(&self) -> &WasteBuildGame {
+        &self.golf
+    }
+
+    pub fn golf_mut(&mut self) -> &mut WasteBuildGame {
+        &mut self.golf
+    }
+
+    pub fn set_forty_thieves(&mut self, game: FortyThievesGame) {
+        self.forty_thieves = game;
+    }
+
+    pub fn forty_thieves(&self) -> &FortyThievesGame {
+        &self.forty_thieves
+    }
+
+    pub fn forty_thieves_mut(&mut self) -> &mut FortyThievesGame {
+        &mut self.forty_thieves
+    }
+
+    pub fn set_scorpion(&mut self, game: ScorpionGame) {
+        self.scorpion = game;
+    }
+
+    pub fn scorpion(&self) -> &ScorpionGame {
+        &self.scorpion
+    }
+
+    pub fn scorpion_mut(&mut self) -> &mut ScorpionGame {
+        &mut self.scorpion
+    }
+
+    pub fn set_canfield(&mut self, game: CanfieldGame) {
+        self.canfield = game;
+    }
+
+    pub fn canfield(&self) -> &CanfieldGame {
+        &self.canfield
+    }
+
+    pub fn canfield_mut(&mut self) -> &mut CanfieldGame {
+        &mut self.canfield
+    }
+
+    /// TriPeaks and Golf share one engine; pick the game for a layout.
+    pub fn waste_build(&self, layout: WasteBuildLayout) -> &WasteBuildGame {
+        match layout {
+            WasteBuildLayout::TriPeaks => &self.tripeaks,
+            WasteBuildLayout::Golf => &self.golf,
+        }
+    }
+
+    pub fn waste_build_mut(&mut self, layout: WasteBuildLayout) -> &mut WasteBuildGame {
+        match layout {
+            WasteBuildLayout::TriPeaks => &mut self.tripeaks,
+            WasteBuildLayout::Golf => &mut self.golf,
+        }
+    }
+
+    /// Baker's Dozen and Beleaguered Castle share one engine; pick the game
+    /// for a layout.
+    pub fn open_tableau(&self, layout: OpenTableauLayout) -> &OpenTableauGame {
+        match layout {
+            OpenTableauLayout::BakersDozen => &self.bakers_dozen,
+            OpenTableauLayout::BeleagueredCastle => &self.beleaguered_castle,
+        }
+    }
+
+    pub fn open_tableau_mut(&mut self, layout: OpenTableauLayout) -> &mut OpenTableauGame {
+        match layout {
+            OpenTableauLayout::BakersDozen => &mut self.bakers_dozen,
+            OpenTableauLayout::BeleagueredCastle => &mut self.beleaguered_castle,
+        }
+    }
+
+    /// Installs a board read from text and returns its mode.
+    pub fn set_text_board(&mut self, board: TextBoard) -> GameMode {
+        let mode = board.mode();
+        match board {
+            TextBoard::Klondike(game) => self.klondike = game,
+            TextBoard::Spider(game) => self.spider = game,
+            TextBoard::Yukon(game) => self.yukon = game,
+            TextBoard::Freecell(game) => {
+                self.active_freecell = game.layout();
+                self.set_freecell(game);
+            }
+            TextBoard::OpenTableau(game) => {
+                let layout = game.layout();
+                *self.open_tableau_mut(layout) = game;
+            }
+            TextBoard::Pyramid(game) => self.pyramid = game,
+            TextBoard::WasteBuild(game) => {
+                let layout = game.layout();
+                *self.waste_build_mut(layout) = game;
+            }
+            TextBoard::FortyThieves(game) => self.forty_thieves = game,
+            TextBoard::Scorpion(game) => self.scorpion = game,
+            TextBoard::Canfield(game) => self.canfield = game,
+        }
+        mode
+    }
+
+    /// The mode's board in the plain-text format.
+    pub fn board_text(&self, mode: GameMode) -> String {
+        match mode {
+            GameMode::Klondike => self.klondike.board_text(),
+            GameMode::Spider => self.spider.board_text(),
+            GameMode::Freecell => self.freecell.board_text(),
+            GameMode::Yukon => self.yukon.board_text(),
+            GameMode::Pyramid => self.pyramid.board_text(),
+            GameMode::TriPeaks => self.tripeaks.board_text(),
+            GameMode::Golf => self.golf.board_text(),
+            GameMode::FortyThieves => self.forty_thieves.board_text(),
+            GameMode::Scorpion => self.scorpion.board_text(),
+            GameMode::Canfield => self.canfield.board_text(),
+            GameMode::BakersDozen => self.bakers_dozen.board_text(),
+            GameMode::BeleagueredCastle => self.beleaguered_castle.board_text(),
+            GameMode::EightOff => self.eight_off.board_text(),
+            GameMode::SeahavenTowers => self.seahaven_towers.board_text(),
+            GameMode::BakersGame => self.bakers_game.board_text(),
+        }
     }
 
     pub fn set_runtime(&mut self, runtime: VariantRuntime) {
         match runtime {
             VariantRuntime::Klondike(game) => self.klondike = game,
             VariantRuntime::Spider(game) => self.spider = game,
-            VariantRuntime::Freecell(game) => self.freecell = game,
+            VariantRuntime::Freecell(game)
+            | VariantRuntime::EightOff(game)
+            | VariantRuntime::SeahavenTowers(game)
+            | VariantRuntime::BakersGame(game) => {
+                self.active_freecell = game.layout();
+                self.set_freecell(game);
+            }
+            VariantRuntime::Yukon(game) => self.yukon = game,
+            VariantRuntime::Pyramid(game) => self.pyramid = game,
+            VariantRuntime::TriPeaks(game) => self.tripeaks = game,
+            VariantRuntime::Golf(game) => self.golf = game,
+            VariantRuntime::FortyThieves(game) => self.forty_thieves = game,
+            VariantRuntime::Scorpion(game) => self.scorpion = game,
+            VariantRuntime::Canfield(game) => self.canfield = game,
+            VariantRuntime::BakersDozen(game) => self.bakers_dozen = game,
+            VariantRuntime::BeleagueredCastle(game) => self.beleaguered_castle = game,
         }
     }
 
@@ -71,6 +316,22 @@ impl VariantStateStore {
             GameMode::Klondike => VariantRuntime::Klondike(self.klondike.clone()),
             GameMode::Spider => VariantRuntime::Spider(self.spider.clone()),
             GameMode::Freecell => VariantRuntime::Freecell(self.freecell.clone()),
+            GameMode::Yukon => VariantRuntime::Yukon(self.yukon.clone()),
+            GameMode::Pyramid => VariantRuntime::Pyramid(self.pyramid.clone()),
+            GameMode::TriPeaks => VariantRuntime::TriPeaks(self.tripeaks.clone()),
+            GameMode::Golf => VariantRuntime::Golf(self.golf.clone()),
+            GameMode::FortyThieves => VariantRuntime::FortyThieves(self.forty_thieves.clone()),
+            GameMode::Scorpion => VariantRuntime::Scorpion(self.scorpion.clone()),
+            GameMode::Canfield => VariantRuntime::Canfield(self.canfield.clone()),
+            GameMode::BakersDozen => VariantRuntime::BakersDozen(self.bakers_dozen.clone()),
+            GameMode::BeleagueredCastle => {
+                VariantRuntime::BeleagueredCastle(self.beleaguered_castle.clone())
+            }
+            GameMode::EightOff => VariantRuntime::EightOff(self.eight_off.clone()),
+            GameMode::SeahavenTowers => {
+                VariantRuntime::SeahavenTowers(self.seahaven_towers.clone())
+            }
+            GameMode::BakersGame => VariantRuntime::BakersGame(self.bakers_game.clone()),
         }
     }
 
@@ -87,6 +348,22 @@ impl VariantStateStore {
             GameMode::Klondike => format!("k:{}", self.klondike.encode_for_session()),
             GameMode::Spider => format!("s:{}", self.spider.encode_for_session()),
             GameMode::Freecell => format!("f:{}", self.freecell.encode_for_session()),
+            GameMode::Yukon => format!("y:{}", self.yukon.encode_for_session()),
+            GameMode::Pyramid => format!("p:{}", self.pyramid.encode_for_session()),
+            GameMode::TriPeaks => format!("t:{}", self.tripeaks.encode_for_session()),
+            GameMode::Golf => format!("g:{}", self.golf.encode_for_session()),
+            GameMode::FortyThieves => format!("ft:{}", self.forty_thieves.encode_for_session()),
+            GameMode::Scorpion => format!("sc:{}", self.scorpion.encode_for_session()),
+            GameMode::Canfield => format!("cf:{}", self.canfield.encode_for_session()),
+            GameMode::BakersDozen => format!("bd:{}", self.bakers_dozen.encode_for_session()),
+            GameMode::BeleagueredCastle => {
+                format!("bc:{}", self.beleaguered_castle.encode_for_session())
+            }
+            GameMode::EightOff => format!("eo:{}", self.eight_off.encode_for_session()),
+            GameMode::SeahavenTowers => {
+                format!("sh:{}", self.seahaven_towers.encode_for_session())
+            }
+            GameMode::BakersGame => format!("bg:{}", self.bakers_game.encode_for_session()),
         }
     }
 
@@ -101,7 +378,43 @@ impl VariantStateStore {
             if rest.is_empty() {
                 return Some(VariantRuntime::Freecell(FreecellGame::new_with_seed(0)));
             }
-            return FreecellGame::decode_from_session(rest).map(VariantRuntime::Freecell);
+            return Self::decode_freecell_layout(FreecellLayout::FreeCell, rest);
+        }
+        if let Some(rest) = encoded.strip_prefix("y:") {
+            return YukonGame::decode_from_session(rest).map(VariantRuntime::Yukon);
+        }
+        if let Some(rest) = encoded.strip_prefix("p:") {
+            return PyramidGame::decode_from_session(rest).map(VariantRuntime::Pyramid);
+        }
+        if let Some(rest) = encoded.strip_prefix("t:") {
+            return Self::decode_waste_build(WasteBuildLayout::TriPeaks, rest);
+        }
+        if let Some(rest) = encoded.strip_prefix("g:") {
+            return Self::decode_waste_build(WasteBuildLayout::Golf, rest);
+        }
+        if let Some(rest) = encoded.strip_prefix("ft:") {
+            return FortyThievesGame::decode_from_session(rest).map(VariantRuntime::FortyThieves);
+        }
+        if let Some(rest) = encoded.strip_prefix("sc:") {
+            return ScorpionGame::decode_from_session(rest).map(VariantRuntime::Scorpion);
+        }
+        if let Some(rest) = encoded.strip_prefix("cf:") {
+            return CanfieldGame::decode_from_session(rest).map(VariantRuntime::Canfield);
+        }
+        if let Some(rest) = encoded.strip_prefix("bd:") {
+            return Self::decode_open_tableau(OpenTableauLayout::BakersDozen, rest);
+        }
+        if let Some(rest) = encoded.strip_prefix("bc:") {
+            return Self::decode_open_tableau(OpenTableauLayout::BeleagueredCastle, rest);
+        }
+        if let Some(rest) = encoded.strip_prefix("eo:") {
+            return Self::decode_freecell_layout(FreecellLayout::EightOff, rest);
+        }
+        if let Some(rest) = encoded.strip_prefix("sh:") {
+            return Self::decode_freecell_layout(FreecellLayout::SeahavenTowers, rest);
+        }
+        if let Some(rest) = encoded.strip_prefix("bg:") {
+            return Self::decode_freecell_layout(FreecellLayout::BakersGame, rest);
         }
         if encoded == "f:" || encoded == "f" {
             return Some(VariantRuntime::Freecell(FreecellGame::new_with_seed(0)));
@@ -115,10 +428,71 @@ impl VariantStateStore {
             GameMode::Spider => {
                 SpiderGame::decode_from_session(encoded).map(VariantRuntime::Spider)
             }
-            GameMode::Freecell => {
-                FreecellGame::decode_from_session(encoded).map(VariantRuntime::Freecell)
+            GameMode::Freecell => Self::decode_freecell_layout(FreecellLayout::FreeCell, encoded),
+            GameMode::Yukon => YukonGame::decode_from_session(encoded).map(VariantRuntime::Yukon),
+            GameMode::Pyramid => {
+                PyramidGame::decode_from_session(encoded).map(VariantRuntime::Pyramid)
+            }
+            GameMode::TriPeaks => Self::decode_waste_build(WasteBuildLayout::TriPeaks, encoded),
+            GameMode::Golf => Self::decode_waste_build(WasteBuildLayout::Golf, encoded),
+            GameMode::FortyThieves => {
+                FortyThievesGame::decode_from_session(encoded).map(VariantRuntime::FortyThieves)
+            }
+            GameMode::Scorpion => {
+                ScorpionGame::decode_from_session(encoded).map(VariantRuntime::Scorpion)
+            }
+            GameMode::Canfield => {
+                CanfieldGame::decode_from_session(encoded).map(VariantRuntime::Canfield)
             }
+            GameMode::BakersDozen => {
+                Self::decode_open_tableau(OpenTableauLayout::BakersDozen, encoded)
+            }
+            GameMode::BeleagueredCastle => {
+                Self::decode_open_tableau(OpenTableauLayout::BeleagueredCastle, encoded)
+            }
+            GameMode::EightOff => Self::decode_freecell_layout(FreecellLayout::EightOff, encoded),
+            GameMode::SeahavenTowers => {
+                Self::decode_freecell_layout(FreecellLayout::SeahavenTowers, encoded)
+            }
+            GameMode::BakersGame => {
+                Self::decode_freecell_layout(FreecellLayout::BakersGame, encoded)
+            }
+        }
+    }
+
+    fn decode_freecell_layout(layout: FreecellLayout, encoded: &str) -> Option<VariantRuntime> {
+        let game = FreecellGame::decode_from_session(encoded)?;
+        if game.layout() != layout {
+            return None;
+        }
+        Some(match layout {
+            FreecellLayout::FreeCell => VariantRuntime::Freecell(game),
+            FreecellLayout::EightOff => VariantRuntime::EightOff(game),
+            FreecellLayout::SeahavenTowers => VariantRuntime::SeahavenTowers(game),
+            FreecellLayout::BakersGame => VariantRuntime::BakersGame(game),
+        })
+    }
+
+    fn decode_open_tableau(layout: OpenTableauLayout, encoded: &str) -> Option<VariantRuntime> {
+        let game = OpenTableauGame::decode_from_session(encoded)?;
+        if game.layout() != layout {
+            return None;
+        }
+        Some(match layout {
+            OpenTableauLayout::BakersDozen => VariantRuntime::BakersDozen(game),
+            OpenTableauLayout::BeleagueredCastle => VariantRuntime::BeleagueredCastle(game),
+        })
+    }
+
+    fn decode_waste_build(layout: WasteBuildLayout, encoded: &str) -> Option<VariantRuntime> {
+        let game = WasteBuildGame::decode_from_session(encoded)?;
+        if game.layout() != layout {
+            return None;
         }
+        Some(match layout {
+            WasteBuildLayout::TriPeaks => VariantRuntime::TriPeaks(game),
+            WasteBuildLayout::Golf => VariantRuntime::Golf(game),
+        })
     }
 }
 
@@ -0,0 +1,93 @@
+use crate::engine::hinting::HintNode;
+use crate::game::{WasteBuildGame, WasteBuildLayout, WasteBuildMove, WasteBuildSolveResult};
+
+#[derive(Debug, Clone)]
+pub struct WasteBuildHintSuggestion {
+    pub message: String,
+    pub source: Option<HintNode>,
+    pub target: Option<HintNode>,
+    pub waste_build_move: Option<WasteBuildMove>,
+}
+
+/// TriPeaks and Golf slots render into tableau column 0, indexed by slot;
+/// every move lands on the waste.
+pub fn hint_nodes_for_move(waste_build_move: WasteBuildMove) -> (HintNode, HintNode) {
+    match waste_build_move {
+        WasteBuildMove::Play(slot) => (
+            HintNode::Tableau {
+                col: 0,
+                index: Some(slot),
+            },
+            HintNode::Waste,
+        ),
+        WasteBuildMove::Draw => (HintNode::Stock, HintNode::Waste),
+    }
+}
+
+pub fn describe_move(game: &WasteBuildGame, waste_build_move: WasteBuildMove) -> String {
+    match waste_build_move {
+        WasteBuildMove::Play(slot) => {
+            let label = game
+                .tableau_card(slot)
+                .map(|card| card.label())
+                .unwrap_or_else(|| "?".to_string());
+            let place = match game.layout() {
+                WasteBuildLayout::TriPeaks => {
+                    format!("row {}", WasteBuildGame::tripeaks_row_pos(slot).0 + 1)
+                }
+                WasteBuildLayout::Golf => {
+                    format!("column {}", WasteBuildGame::golf_col_depth(slot).0 + 1)
+                }
+            };
+            format!("Play {label} ({place}) onto the waste.")
+        }
+        WasteBuildMove::Draw => format!("Draw from the stock ({} left).", game.stock_len()),
+    }
+}
+
+/// Suggests the first move of a solver line, falling back to the solver's
+/// move ordering when the search finds no win within `max_states`.
+pub fn suggest_move(game: &WasteBuildGame, max_states: usize) -> WasteBuildHintSuggestion {
+    if game.is_won() {
+        return WasteBuildHintSuggestion {
+            message: "Hint: game already won.".to_string(),
+            source: None,
+            target: None,
+            waste_build_move: None,
+        };
+    }
+    let result = game.solve(max_states);
+    suggestion_from_solve(game, &result)
+}
+
+pub fn suggestion_from_solve(
+    game: &WasteBuildGame,
+    result: &WasteBuildSolveResult,
+) -> WasteBuildHintSuggestion {
+    let (waste_build_move, prefix) = match result.line.first() {
+        Some(first) => (Some(*first), "Hint: winning line found. "),
+        None if result.hit_state_limit => (
+            game.solver_moves().first().copied(),
+            "Hint: no win found yet. ",
+        ),
+        None => (
+            game.solver_moves().first().copied(),
+            "Hint: this deal can no longer be won. ",
+        ),
+    };
+    let Some(waste_build_move) = waste_build_move else {
+        return WasteBuildHintSuggestion {
+            message: "Hint: no legal moves. Start a new deal or undo.".to_string(),
+            source: None,
+            target: None,
+            waste_build_move: None,
+        };
+    };
+    let (source, target) = hint_nodes_for_move(waste_build_move);
+    WasteBuildHintSuggestion {
+        message: format!("{prefix}{}", describe_move(game, waste_build_move)),
+        source: Some(source),
+        target: Some(target),
+        waste_build_move: Some(waste_build_move),
+    }
+}
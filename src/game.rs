@@ -1,9 +1,33 @@
+#[path = "game/board_text.rs"]
+mod board_text;
+#[path = "game/canfield.rs"]
+mod canfield;
+#[path = "game/canfield_solver.rs"]
+mod canfield_solver;
 #[path = "game/chess/mod.rs"]
 mod chess;
+#[path = "game/deal.rs"]
+mod deal;
+#[path = "game/forty_thieves.rs"]
+mod forty_thieves;
+#[path = "game/forty_thieves_solver.rs"]
+mod forty_thieves_solver;
 #[path = "game/freecell.rs"]
 mod freecell;
 #[path = "game/klondike_moves.rs"]
 mod klondike_moves;
+#[path = "game/open_tableau.rs"]
+mod open_tableau;
+#[path = "game/pyramid.rs"]
+mod pyramid;
+#[path = "game/pyramid_solver.rs"]
+mod pyramid_solver;
+#[path = "game/pysol.rs"]
+mod pysol;
+#[path = "game/scorpion.rs"]
+mod scorpion;
+#[path = "game/scorpion_solver.rs"]
+mod scorpion_solver;
 #[path = "game/session_codec.rs"]
 mod session_codec;
 #[path = "game/setup.rs"]
@@ -12,12 +36,36 @@ mod setup;
 mod solver;
 #[path = "game/spider.rs"]
 mod spider;
+#[path = "game/thoughtful_solver.rs"]
+mod thoughtful_solver;
 #[path = "game/types.rs"]
 mod types;
+#[path = "game/waste_build.rs"]
+mod waste_build;
+#[path = "game/waste_build_solver.rs"]
+mod waste_build_solver;
+#[path = "game/yukon.rs"]
+mod yukon;
+pub use board_text::TextBoard;
+pub use canfield::*;
+pub use canfield_solver::CanfieldSolveResult;
 pub use chess::*;
+pub use deal::{is_supported_deal_algorithm, DealRng, DEAL_ALGORITHM};
+pub use forty_thieves::*;
+pub use forty_thieves_solver::FortyThievesSolveResult;
 pub use freecell::*;
+pub use open_tableau::*;
+pub use pyramid::*;
+pub use pyramid_solver::PyramidSolveResult;
+pub use pysol::PysolGameNumber;
+pub use scorpion::*;
+pub use scorpion_solver::ScorpionSolveResult;
 pub use spider::*;
+pub use thoughtful_solver::{ThoughtfulMove, ThoughtfulVerdict};
 pub use types::*;
+pub use waste_build::*;
+pub use waste_build_solver::WasteBuildSolveResult;
+pub use yukon::*;
 
 pub fn rank_label(rank: u8) -> &'static str {
     match rank {
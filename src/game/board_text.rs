@@ -0,0 +1,1201 @@
+//! Plain-text boards for every solitaire variant, for trading positions with
+//! other solitaire programs and solvers.
+//!
+//! ```text
+//! Game: Klondike
+//! Rules: Deal 3, 3 Passes, Pass 2
+//! Foundations: C-A D-0 H-0 S-0
+//! Stock: 9H 2C 7S
+//! Waste: 8D
+//! [4C] [KD] 9H 8S
+//! : JD
+//! -
+//! ```
+//!
+//! Every line without a header is one tableau column from bottom to top,
+//! optionally after the `:` fc-solve writes; `-` is an empty column. Cards are
+//! a rank (`A`, `2`-`9`, `T` or `10`, `J`, `Q`, `K`) and a suit (`C`, `D`, `H`,
+//! `S`), and brackets mark face-down cards. Stock and waste also run bottom to
+//! top. The FreeCell family adds `Freecells: 4C - - -`, Spider and Scorpion list
+//! their completed runs by suit as `Completed: S H`, and Yukon takes
+//! `Rules: Russian` for Russian Solitaire. Klondike's `Rules:` gives the deal
+//! size, the pass limit, the pass under way when passes are limited, and
+//! `Thoughtful`; a board without one leaves those to whoever loads it.
+//!
+//! Pyramid and TriPeaks give one line per row of their layout, with `-` for a
+//! cleared slot; Pyramid cards missing from the board count as removed, and
+//! `Rules: Pass 2` says which of its three passes is under way. Golf lists its
+//! columns like any other tableau, and TriPeaks and Golf take
+//! `Rules: Wrap, No streak scoring` style rules; their faces follow the
+//! layout. Forty Thieves lists both foundations of each suit
+//! (`C-5 C-0 D-0 ...`). Scorpion and Canfield keep their reserve, bottom card
+//! first, in `Reserve:`, and Canfield names the rank its foundations start
+//! from in `Base: 7`, reading `Foundations:` as the top card of each pile.
+//!
+//! `Game:` names the variant; without it the column count picks Klondike (7),
+//! FreeCell (8) or Spider (10).
+
+use std::collections::HashMap;
+
+use super::scorpion::SCORPION_RESERVE;
+use super::spider::spider_deck;
+use super::{
+    CanfieldGame, Card, DrawMode, FortyThievesGame, FoundationBase, FreecellGame, FreecellLayout,
+    GameMode, KlondikeGame, KlondikePassLimit, OpenTableauGame, OpenTableauLayout, PyramidGame,
+    ScorpionGame, SpiderGame, SpiderSuitMode, Suit, WasteBuildGame, WasteBuildLayout,
+    WasteBuildRules, YukonGame, YukonRuleSet, CANFIELD_COLUMNS, FORTY_THIEVES_COLUMNS,
+    FORTY_THIEVES_FOUNDATIONS, FREECELL_DEFAULT_CELL_COUNT, FREECELL_MAX_CELL_COUNT,
+    FREECELL_MIN_CELL_COUNT, PYRAMID_MAX_RECYCLES, PYRAMID_ROWS, PYRAMID_SLOTS, SCORPION_COLUMNS,
+};
+
+const RANK_TOKENS: &[u8; 13] = b"A23456789TJQK";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextBoard {
+    Klondike(KlondikeGame),
+    Spider(SpiderGame),
+    Freecell(FreecellGame),
+    Yukon(YukonGame),
+    OpenTableau(OpenTableauGame),
+    Pyramid(PyramidGame),
+    WasteBuild(WasteBuildGame),
+    FortyThieves(FortyThievesGame),
+    Scorpion(ScorpionGame),
+    Canfield(CanfieldGame),
+}
+
+#[derive(Default)]
+struct RawBoard {
+    game: Option<String>,
+    rules: Option<String>,
+    foundations: Option<Vec<(Suit, u8)>>,
+    freecells: Option<Vec<Option<Card>>>,
+    stock: Vec<Card>,
+    waste: Vec<Card>,
+    reserve: Vec<Card>,
+    base: Option<u8>,
+    completed: Vec<Suit>,
+    /// Every line without a header, with `-` for a gap.
+    rows: Vec<Vec<Option<Card>>>,
+    /// The rows read as stacked columns, for variants dealt that way.
+    columns: Vec<Vec<Card>>,
+}
+
+impl TextBoard {
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let mut board = RawBoard::parse(raw)?;
+        let mode = match board.game.as_deref() {
+            Some(name) => {
+                let id = name
+                    .trim()
+                    .to_ascii_lowercase()
+                    .replace('\'', "")
+                    .replace([' ', '-'], "_");
+                GameMode::from_id(&id).ok_or_else(|| format!("unknown game '{name}'"))?
+            }
+            None => match board.rows.len() {
+                7 => GameMode::Klondike,
+                8 => GameMode::Freecell,
+                10 => GameMode::Spider,
+                count => {
+                    return Err(format!(
+                    "board has {count} columns; expected 7 (Klondike), 8 (FreeCell) or 10 (Spider)"
+                ))
+                }
+            },
+        };
+        let name = mode.label();
+        if board.rules.is_some()
+            && !matches!(
+                mode,
+                GameMode::Klondike
+                    | GameMode::Yukon
+                    | GameMode::Pyramid
+                    | GameMode::TriPeaks
+                    | GameMode::Golf
+            )
+        {
+            return Err(format!("{name} boards take no Rules: line"));
+        }
+        if !board.reserve.is_empty() && !matches!(mode, GameMode::Scorpion | GameMode::Canfield) {
+            return Err(format!("{name} boards have no reserve"));
+        }
+        if board.base.is_some() && mode != GameMode::Canfield {
+            return Err("only Canfield boards take a Base: line".to_string());
+        }
+        if !matches!(mode, GameMode::Pyramid | GameMode::TriPeaks) {
+            board.stack_rows()?;
+        }
+        match mode {
+            GameMode::Klondike => board.into_klondike().map(Self::Klondike),
+            GameMode::Spider => board.into_spider().map(Self::Spider),
+            GameMode::Yukon => board.into_yukon().map(Self::Yukon),
+            GameMode::Pyramid => board.into_pyramid().map(Self::Pyramid),
+            GameMode::FortyThieves => board.into_forty_thieves().map(Self::FortyThieves),
+            GameMode::Scorpion => board.into_scorpion().map(Self::Scorpion),
+            GameMode::Canfield => board.into_canfield().map(Self::Canfield),
+            _ => {
+                if let Some(layout) = FreecellLayout::for_mode(mode) {
+                    board.into_freecell(layout).map(Self::Freecell)
+                } else if let Some(layout) = OpenTableauLayout::for_mode(mode) {
+                    board.into_open_tableau(layout).map(Self::OpenTableau)
+                } else {
+                    let layout = WasteBuildLayout::for_mode(mode)
+                        .expect("every other mode is TriPeaks or Golf");
+                    board.into_waste_build(layout).map(Self::WasteBuild)
+                }
+            }
+        }
+    }
+
+    /// Whether the text carries a `Rules:` line. Klondike boards without one
+    /// parse under the default rules and are meant to take the player's.
+    pub fn has_rules_line(raw: &str) -> bool {
+        RawBoard::parse_lines(raw).0.rules.is_some()
+    }
+
+    /// Every line the format cannot read, numbered from 1, so an editor can
+    /// flag each one rather than only the first.
+    pub fn line_errors(raw: &str) -> Vec<(usize, String)> {
+        RawBoard::parse_lines(raw).1
+    }
+
+    pub fn mode(&self) -> GameMode {
+        match self {
+            Self::Klondike(_) => GameMode::Klondike,
+            Self::Spider(_) => GameMode::Spider,
+            Self::Freecell(game) => game.layout().mode(),
+            Self::Yukon(_) => GameMode::Yukon,
+            Self::OpenTableau(game) => game.layout().mode(),
+            Self::Pyramid(_) => GameMode::Pyramid,
+            Self::WasteBuild(game) => game.layout().mode(),
+            Self::FortyThieves(_) => GameMode::FortyThieves,
+            Self::Scorpion(_) => GameMode::Scorpion,
+            Self::Canfield(_) => GameMode::Canfield,
+        }
+    }
+}
+
+impl RawBoard {
+    fn parse(raw: &str) -> Result<Self, String> {
+        let (board, errors) = Self::parse_lines(raw);
+        match errors.into_iter().next() {
+            Some((_, err)) => Err(err),
+            None => Ok(board),
+        }
+    }
+
+    /// Reads every line, keeping going past bad ones and collecting their
+    /// errors with 1-based line numbers.
+    fn parse_lines(raw: &str) -> (Self, Vec<(usize, String)>) {
+        let mut board = Self::default();
+        let mut errors = Vec::new();
+        for (index, line) in raw.lines().enumerate() {
+            if let Err(err) = board.parse_line(line.trim()) {
+                errors.push((index + 1, err));
+            }
+        }
+        (board, errors)
+    }
+
+    fn parse_line(&mut self, line: &str) -> Result<(), String> {
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(());
+        }
+        let header = line
+            .split_once(':')
+            .filter(|(name, _)| !name.is_empty() && !name.contains(char::is_whitespace));
+        let Some((name, value)) = header else {
+            let row = line.strip_prefix(':').unwrap_or(line).trim();
+            self.rows.push(parse_slots(row)?);
+            return Ok(());
+        };
+        let value = value.trim();
+        match name.to_ascii_lowercase().as_str() {
+            "game" => self.game = Some(value.to_string()),
+            "rules" => self.rules = Some(value.to_string()),
+            "foundations" => {
+                self.foundations = Some(
+                    value
+                        .split_whitespace()
+                        .map(parse_foundation)
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            "freecells" => self.freecells = Some(parse_slots(value)?),
+            "stock" | "talon" => self.stock = parse_cards(value)?,
+            "waste" => self.waste = parse_cards(value)?,
+            "reserve" => self.reserve = parse_cards(value)?,
+            "base" => {
+                self.base =
+                    Some(parse_rank(value).ok_or_else(|| format!("'{value}' is not a rank"))?)
+            }
+            "completed" => {
+                self.completed = value
+                    .split_whitespace()
+                    .map(|token| {
+                        parse_suit(token).ok_or_else(|| format!("'{token}' is not a suit"))
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+            other => return Err(format!("unknown board line '{other}:'")),
+        }
+        Ok(())
+    }
+
+    /// Reads the rows as columns from bottom to top, where `-` alone is an
+    /// empty column and no other gaps are allowed.
+    fn stack_rows(&mut self) -> Result<(), String> {
+        self.columns = std::mem::take(&mut self.rows)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, row)| match row.as_slice() {
+                [None] => Ok(Vec::new()),
+                _ => row.into_iter().collect::<Option<Vec<_>>>().ok_or_else(|| {
+                    format!(
+                        "column {} has a gap; - stands for a whole empty column",
+                        idx + 1
+                    )
+                }),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    /// The `Rules:` line split at commas, lowercased.
+    fn rule_items(&self) -> Vec<String> {
+        self.rules
+            .iter()
+            .flat_map(|rules| rules.split(','))
+            .map(|item| item.trim().to_ascii_lowercase())
+            .filter(|item| !item.is_empty())
+            .collect()
+    }
+
+    fn foundation_piles(&self) -> [Vec<Card>; 4] {
+        let mut piles: [Vec<Card>; 4] = std::array::from_fn(|_| Vec::new());
+        for &(suit, top) in self.foundations.iter().flatten() {
+            piles[suit.foundation_index()] = suit_run(suit, 1, top);
+        }
+        piles
+    }
+
+    fn into_klondike(self) -> Result<KlondikeGame, String> {
+        if self.columns.len() != 7 {
+            return Err("Klondike boards need 7 columns".to_string());
+        }
+        if self.freecells.is_some() || !self.completed.is_empty() {
+            return Err("Klondike boards have no free cells or completed runs".to_string());
+        }
+        let mut draw_mode = DrawMode::One;
+        let mut pass_limit = KlondikePassLimit::Unlimited;
+        let mut pass = None;
+        let mut thoughtful = false;
+        for item in self.rule_items() {
+            let count = item
+                .strip_prefix("deal ")
+                .or_else(|| item.strip_prefix("draw "))
+                .and_then(|count| count.trim().parse().ok());
+            if let Some(count) = count {
+                draw_mode = DrawMode::from_count(count)
+                    .ok_or_else(|| "Klondike deals 1 to 5 cards at a time".to_string())?;
+            } else if let Some(limit) = [
+                KlondikePassLimit::Unlimited,
+                KlondikePassLimit::Three,
+                KlondikePassLimit::Single,
+            ]
+            .into_iter()
+            .find(|limit| limit.label().eq_ignore_ascii_case(&item))
+            {
+                pass_limit = limit;
+            } else if let Some(number) = pass_number(&item) {
+                pass = Some(number);
+            } else if item == "thoughtful" {
+                thoughtful = true;
+            } else {
+                return Err(format!(
+                    "'{item}' is not a Klondike rule; try Deal 3, 3 Passes, Pass 2 or Thoughtful"
+                ));
+            }
+        }
+        let recycles_used = match (pass, pass_limit.max_recycles()) {
+            (None, _) => 0,
+            (Some(pass), Some(max)) if (1..=max + 1).contains(&pass) => pass - 1,
+            (Some(_), Some(max)) => {
+                return Err(format!(
+                    "{} allows Pass 1 to Pass {}",
+                    pass_limit.label(),
+                    max + 1
+                ))
+            }
+            (Some(_), None) => {
+                return Err("unlimited passes are not counted".to_string());
+            }
+        };
+        check_face_down_order(&self.columns)?;
+        let foundations = self.foundation_piles();
+        let stock = face(self.stock, false);
+        let waste = face(self.waste, true);
+        let mut cards: Vec<Card> = stock.iter().chain(&waste).copied().collect();
+        cards.extend(foundations.iter().flatten());
+        cards.extend(self.columns.iter().flatten());
+        check_card_counts(&cards, &full_pack())?;
+        let mut columns = self.columns.into_iter();
+        Ok(KlondikeGame {
+            draw_mode,
+            pass_limit,
+            recycles_used,
+            thoughtful,
+            stock,
+            waste,
+            foundations,
+            tableau: std::array::from_fn(|_| columns.next().unwrap_or_default()),
+        })
+    }
+
+    fn into_spider(self) -> Result<SpiderGame, String> {
+        if self.columns.len() != 10 {
+            return Err("Spider boards need 10 columns".to_string());
+        }
+        if self.freecells.is_some() || self.foundations.is_some() || !self.waste.is_empty() {
+            return Err("Spider boards have no free cells, foundations or waste".to_string());
+        }
+        if !self.stock.len().is_multiple_of(10) {
+            return Err("a Spider stock deals ten cards at a time".to_string());
+        }
+        check_face_down_order(&self.columns)?;
+        let stock = face(self.stock, false);
+        let mut cards: Vec<Card> = stock.clone();
+        cards.extend(self.columns.iter().flatten());
+        for &suit in &self.completed {
+            cards.extend(suit_run(suit, 1, 13));
+        }
+        let suit_mode = match cards
+            .iter()
+            .fold([false; 4], |mut seen, card| {
+                seen[card.suit.foundation_index()] = true;
+                seen
+            })
+            .iter()
+            .filter(|&&seen| seen)
+            .count()
+        {
+            1 => SpiderSuitMode::One,
+            2 => SpiderSuitMode::Two,
+            3 => SpiderSuitMode::Three,
+            _ => SpiderSuitMode::Four,
+        };
+        check_card_counts(&cards, &spider_deck(suit_mode))?;
+        let mut columns = self.columns.into_iter();
+        Ok(SpiderGame::from_board(
+            suit_mode,
+            stock,
+            std::array::from_fn(|_| columns.next().unwrap_or_default()),
+            self.completed,
+        ))
+    }
+
+    fn into_freecell(self, layout: FreecellLayout) -> Result<FreecellGame, String> {
+        let name = layout.mode().label();
+        if self.columns.len() != layout.columns() {
+            return Err(format!("{name} boards need {} columns", layout.columns()));
+        }
+        if !self.stock.is_empty() || !self.waste.is_empty() || !self.completed.is_empty() {
+            return Err(format!(
+                "{name} boards have no stock, waste or completed runs"
+            ));
+        }
+        self.check_all_face_up(name)?;
+        let cells = self.freecells.clone().unwrap_or_default();
+        let cell_count = match layout.fixed_cell_count() {
+            Some(fixed) if cells.len() <= usize::from(fixed) => fixed,
+            Some(fixed) => return Err(format!("{name} has {fixed} free cells")),
+            None if cells.is_empty() => FREECELL_DEFAULT_CELL_COUNT,
+            None => u8::try_from(cells.len())
+                .ok()
+                .filter(|count| (FREECELL_MIN_CELL_COUNT..=FREECELL_MAX_CELL_COUNT).contains(count))
+                .ok_or_else(|| {
+                    format!(
+                        "{name} takes {FREECELL_MIN_CELL_COUNT} to {FREECELL_MAX_CELL_COUNT} free cells"
+                    )
+                })?,
+        };
+        let mut freecells = vec![None; usize::from(cell_count)];
+        for (slot, card) in freecells.iter_mut().zip(cells) {
+            *slot = card.map(|card| Card {
+                face_up: true,
+                ..card
+            });
+        }
+        let foundations = self.foundation_piles();
+        let mut cards: Vec<Card> = freecells.iter().flatten().copied().collect();
+        cards.extend(foundations.iter().flatten());
+        cards.extend(self.columns.iter().flatten());
+        check_card_counts(&cards, &full_pack())?;
+        Ok(FreecellGame::from_board(
+            layout,
+            foundations,
+            &freecells,
+            self.columns,
+        ))
+    }
+
+    fn into_yukon(self) -> Result<YukonGame, String> {
+        if self.columns.len() != 7 {
+            return Err("Yukon boards need 7 columns".to_string());
+        }
+        if self.freecells.is_some()
+            || !self.stock.is_empty()
+            || !self.waste.is_empty()
+            || !self.completed.is_empty()
+        {
+            return Err(
+                "Yukon boards have no free cells, stock, waste or completed runs".to_string(),
+            );
+        }
+        let rule_set = match self
+            .rules
+            .as_deref()
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            None | Some("yukon") => YukonRuleSet::Yukon,
+            Some("russian") => YukonRuleSet::Russian,
+            Some(_) => return Err("Yukon rules are Yukon or Russian".to_string()),
+        };
+        check_face_down_order(&self.columns)?;
+        let foundations = self.foundation_piles();
+        let mut cards: Vec<Card> = foundations.iter().flatten().copied().collect();
+        cards.extend(self.columns.iter().flatten());
+        check_card_counts(&cards, &full_pack())?;
+        let mut columns = self.columns.into_iter();
+        Ok(YukonGame::from_board(
+            rule_set,
+            foundations,
+            std::array::from_fn(|_| columns.next().unwrap_or_default()),
+        ))
+    }
+
+    fn into_open_tableau(self, layout: OpenTableauLayout) -> Result<OpenTableauGame, String> {
+        let name = layout.mode().label();
+        if self.columns.len() != layout.columns() {
+            return Err(format!("{name} boards need {} columns", layout.columns()));
+        }
+        if self.freecells.is_some()
+            || !self.stock.is_empty()
+            || !self.waste.is_empty()
+            || !self.completed.is_empty()
+        {
+            return Err(format!(
+                "{name} boards have no free cells, stock, waste or completed runs"
+            ));
+        }
+        self.check_all_face_up(name)?;
+        let foundations = self.foundation_piles();
+        let mut cards: Vec<Card> = foundations.iter().flatten().copied().collect();
+        cards.extend(self.columns.iter().flatten());
+        check_card_counts(&cards, &full_pack())?;
+        Ok(OpenTableauGame::from_board(
+            layout,
+            foundations,
+            self.columns,
+        ))
+    }
+
+    fn into_pyramid(self) -> Result<PyramidGame, String> {
+        if self.rows.len() != PYRAMID_ROWS {
+            return Err(format!("Pyramid boards need {PYRAMID_ROWS} rows"));
+        }
+        if self.freecells.is_some() || self.foundations.is_some() || !self.completed.is_empty() {
+            return Err(
+                "Pyramid boards have no free cells, foundations or completed runs".to_string(),
+            );
+        }
+        let mut recycles_used = 0;
+        for item in self.rule_items() {
+            match pass_number(&item) {
+                Some(pass) if (1..=PYRAMID_MAX_RECYCLES + 1).contains(&pass) => {
+                    recycles_used = pass - 1
+                }
+                _ => {
+                    return Err(format!(
+                        "Pyramid rules are Pass 1 to Pass {}",
+                        PYRAMID_MAX_RECYCLES + 1
+                    ))
+                }
+            }
+        }
+        let mut pyramid = [None; PYRAMID_SLOTS];
+        for (row, cards) in self.rows.into_iter().enumerate() {
+            if cards.len() != row + 1 {
+                return Err(format!(
+                    "Pyramid row {} needs {} cards or -",
+                    row + 1,
+                    row + 1
+                ));
+            }
+            for (pos, card) in cards.into_iter().enumerate() {
+                if let Some(slot) = PyramidGame::slot_index(row, pos) {
+                    pyramid[slot] = card.map(|card| Card {
+                        face_up: true,
+                        ..card
+                    });
+                }
+            }
+        }
+        let stock = face(self.stock, false);
+        let waste = face(self.waste, true);
+        let mut cards: Vec<Card> = pyramid.iter().flatten().copied().collect();
+        cards.extend(&stock);
+        cards.extend(&waste);
+        let removed = missing_cards(&cards)?;
+        // Kings go alone and everything else in pairs adding up to 13.
+        let count = |rank: u8| removed.iter().filter(|card| card.rank == rank).count();
+        if (1..=6).any(|rank| count(rank) != count(13 - rank)) {
+            return Err("the cards off the board do not pair up to 13".to_string());
+        }
+        Ok(PyramidGame::from_board(
+            pyramid,
+            stock,
+            waste,
+            removed,
+            recycles_used,
+        ))
+    }
+
+    fn into_waste_build(self, layout: WasteBuildLayout) -> Result<WasteBuildGame, String> {
+        let name = layout.mode().label();
+        if self.freecells.is_some() || self.foundations.is_some() || !self.completed.is_empty() {
+            return Err(format!(
+                "{name} boards have no free cells, foundations or completed runs"
+            ));
+        }
+        let mut rules = WasteBuildRules::default();
+        for item in self.rule_items() {
+            match item.as_str() {
+                "wrap" => rules.wrap_ranks = true,
+                "no wrap" => rules.wrap_ranks = false,
+                "streak scoring" => rules.streak_scoring = true,
+                "no streak scoring" => rules.streak_scoring = false,
+                _ => {
+                    return Err(format!(
+                        "{name} rules are Wrap or No wrap and Streak scoring or No streak scoring"
+                    ))
+                }
+            }
+        }
+        let lines = waste_build_lines(layout);
+        let mut tableau = vec![None; layout.slot_count()];
+        match layout {
+            WasteBuildLayout::TriPeaks => {
+                if self.rows.len() != lines.len() {
+                    return Err(format!("TriPeaks boards need {} rows", lines.len()));
+                }
+                for (row, (cards, slots)) in self.rows.into_iter().zip(&lines).enumerate() {
+                    if cards.len() != slots.len() {
+                        return Err(format!(
+                            "TriPeaks row {} needs {} cards or -",
+                            row + 1,
+                            slots.len()
+                        ));
+                    }
+                    for (card, &slot) in cards.into_iter().zip(slots) {
+                        tableau[slot] = card;
+                    }
+                }
+            }
+            WasteBuildLayout::Golf => {
+                if self.columns.len() != lines.len() {
+                    return Err(format!("Golf boards need {} columns", lines.len()));
+                }
+                for (col, (cards, slots)) in self.columns.into_iter().zip(&lines).enumerate() {
+                    if cards.len() > slots.len() {
+                        return Err(format!(
+                            "Golf column {} holds at most {} cards",
+                            col + 1,
+                            slots.len()
+                        ));
+                    }
+                    for (card, &slot) in cards.into_iter().zip(slots) {
+                        tableau[slot] = Some(card);
+                    }
+                }
+            }
+        }
+        let stock = face(self.stock, false);
+        let waste = face(self.waste, true);
+        let mut cards: Vec<Card> = tableau.iter().flatten().copied().collect();
+        cards.extend(&stock);
+        cards.extend(&waste);
+        check_card_counts(&cards, &full_pack())?;
+        Ok(WasteBuildGame::from_board(
+            layout, rules, tableau, stock, waste,
+        ))
+    }
+
+    fn into_forty_thieves(self) -> Result<FortyThievesGame, String> {
+        if self.columns.len() != FORTY_THIEVES_COLUMNS {
+            return Err(format!(
+                "Forty Thieves boards need {FORTY_THIEVES_COLUMNS} columns"
+            ));
+        }
+        if self.freecells.is_some() || !self.completed.is_empty() {
+            return Err("Forty Thieves boards have no free cells or completed runs".to_string());
+        }
+        self.check_all_face_up("Forty Thieves")?;
+        // Each suit owns two foundations, filled in the order they are listed.
+        let mut foundations: [Vec<Card>; FORTY_THIEVES_FOUNDATIONS] =
+            std::array::from_fn(|_| Vec::new());
+        let mut listed = [0_usize; 4];
+        for &(suit, top) in self.foundations.iter().flatten() {
+            let seen = &mut listed[suit.foundation_index()];
+            if *seen == 2 {
+                return Err(format!(
+                    "Forty Thieves has two {} foundations",
+                    suit.short()
+                ));
+            }
+            foundations[suit.foundation_index() * 2 + *seen] = suit_run(suit, 1, top);
+            *seen += 1;
+        }
+        let stock = face(self.stock, false);
+        let waste = face(self.waste, true);
+        let mut cards: Vec<Card> = stock.iter().chain(&waste).copied().collect();
+        cards.extend(foundations.iter().flatten());
+        cards.extend(self.columns.iter().flatten());
+        check_card_counts(&cards, &spider_deck(SpiderSuitMode::Four))?;
+        let mut columns = self.columns.into_iter();
+        Ok(FortyThievesGame::from_board(
+            stock,
+            waste,
+            foundations,
+            std::array::from_fn(|_| columns.next().unwrap_or_default()),
+        ))
+    }
+
+    fn into_scorpion(self) -> Result<ScorpionGame, String> {
+        if self.columns.len() != SCORPION_COLUMNS {
+            return Err(format!("Scorpion boards need {SCORPION_COLUMNS} columns"));
+        }
+        if self.freecells.is_some()
+            || self.foundations.is_some()
+            || !self.stock.is_empty()
+            || !self.waste.is_empty()
+        {
+            return Err(
+                "Scorpion boards have no free cells, foundations, stock or waste".to_string(),
+            );
+        }
+        if !self.reserve.is_empty() && self.reserve.len() != SCORPION_RESERVE {
+            return Err(format!(
+                "a Scorpion reserve holds {SCORPION_RESERVE} cards until it is dealt"
+            ));
+        }
+        check_face_down_order(&self.columns)?;
+        let reserve = face(self.reserve, false);
+        let mut cards: Vec<Card> = reserve.clone();
+        cards.extend(self.columns.iter().flatten());
+        for &suit in &self.completed {
+            cards.extend(suit_run(suit, 1, 13));
+        }
+        check_card_counts(&cards, &full_pack())?;
+        let mut columns = self.columns.into_iter();
+        Ok(ScorpionGame::from_board(
+            reserve,
+            std::array::from_fn(|_| columns.next().unwrap_or_default()),
+            self.completed,
+        ))
+    }
+
+    fn into_canfield(self) -> Result<CanfieldGame, String> {
+        if self.columns.len() != CANFIELD_COLUMNS {
+            return Err(format!("Canfield boards need {CANFIELD_COLUMNS} columns"));
+        }
+        if self.freecells.is_some() || !self.completed.is_empty() {
+            return Err("Canfield boards have no free cells or completed runs".to_string());
+        }
+        self.check_all_face_up("Canfield")?;
+        let base = self
+            .base
+            .and_then(FoundationBase::new)
+            .ok_or_else(|| "Canfield boards need a Base: line like Base: 7".to_string())?;
+        let mut foundations: [Vec<Card>; 4] = std::array::from_fn(|_| Vec::new());
+        for &(suit, top) in self.foundations.iter().flatten() {
+            foundations[suit.foundation_index()] = suit_run(suit, base.rank, top);
+        }
+        let stock = face(self.stock, false);
+        let waste = face(self.waste, true);
+        let mut reserve = face(self.reserve, false);
+        if let Some(top) = reserve.last_mut() {
+            top.face_up = true;
+        }
+        let mut cards: Vec<Card> = stock
+            .iter()
+            .chain(&waste)
+            .chain(&reserve)
+            .copied()
+            .collect();
+        cards.extend(foundations.iter().flatten());
+        cards.extend(self.columns.iter().flatten());
+        check_card_counts(&cards, &full_pack())?;
+        let mut columns = self.columns.into_iter();
+        Ok(CanfieldGame::from_board(
+            base,
+            stock,
+            waste,
+            reserve,
+            foundations,
+            std::array::from_fn(|_| columns.next().unwrap_or_default()),
+        ))
+    }
+
+    fn check_all_face_up(&self, name: &str) -> Result<(), String> {
+        if self.columns.iter().flatten().any(|card| !card.face_up) {
+            return Err(format!("{name} deals every card face up"));
+        }
+        Ok(())
+    }
+}
+
+impl KlondikeGame {
+    pub fn board_text(&self) -> String {
+        let mut rules = vec![
+            format!("Deal {}", self.draw_mode.count()),
+            self.pass_limit.label().to_string(),
+        ];
+        if self.pass_limit.max_recycles().is_some() {
+            rules.push(format!("Pass {}", self.recycles_used + 1));
+        }
+        if self.thoughtful {
+            rules.push("Thoughtful".to_string());
+        }
+        let mut lines = vec![
+            "Game: Klondike".to_string(),
+            format!("Rules: {}", rules.join(", ")),
+            foundations_line(&self.foundations),
+            format!("Stock: {}", cards_text(&face(self.stock.clone(), true))),
+            format!("Waste: {}", cards_text(&self.waste)),
+        ];
+        lines.extend(self.tableau.iter().map(|pile| column_text(pile)));
+        lines.join("\n")
+    }
+}
+
+impl SpiderGame {
+    pub fn board_text(&self) -> String {
+        let completed: Vec<&str> = self
+            .completed_run_suits()
+            .iter()
+            .map(|suit| suit.short())
+            .collect();
+        let mut lines = vec![
+            "Game: Spider".to_string(),
+            format!("Stock: {}", cards_text(&face(self.stock().to_vec(), true))),
+            format!("Completed: {}", completed.join(" ")),
+        ];
+        lines.extend(self.tableau().iter().map(|pile| column_text(pile)));
+        lines.join("\n")
+    }
+}
+
+impl FreecellGame {
+    pub fn board_text(&self) -> String {
+        let cells: Vec<String> = self
+            .freecells()
+            .iter()
+            .map(|slot| slot.map_or_else(|| "-".to_string(), card_text))
+            .collect();
+        let mut lines = vec![
+            format!("Game: {}", self.layout().mode().label()),
+            foundations_line(self.foundations()),
+            format!("Freecells: {}", cells.join(" ")),
+        ];
+        lines.extend(self.tableau().iter().map(|pile| column_text(pile)));
+        lines.join("\n")
+    }
+}
+
+impl YukonGame {
+    pub fn board_text(&self) -> String {
+        let mut lines = vec![
+            "Game: Yukon".to_string(),
+            format!("Rules: {}", self.rule_set().label()),
+            foundations_line(self.foundations()),
+        ];
+        lines.extend(self.tableau().iter().map(|pile| column_text(pile)));
+        lines.join("\n")
+    }
+}
+
+impl OpenTableauGame {
+    pub fn board_text(&self) -> String {
+        let mut lines = vec![
+            format!("Game: {}", self.layout().mode().label()),
+            foundations_line(self.foundations()),
+        ];
+        lines.extend(self.tableau().iter().map(|pile| column_text(pile)));
+        lines.join("\n")
+    }
+}
+
+impl PyramidGame {
+    pub fn board_text(&self) -> String {
+        let mut lines = vec![
+            "Game: Pyramid".to_string(),
+            format!("Rules: Pass {}", self.recycles_used() + 1),
+            format!("Stock: {}", cards_text(&face(self.stock().to_vec(), true))),
+            format!("Waste: {}", cards_text(self.waste())),
+        ];
+        lines.extend((0..PYRAMID_ROWS).map(|row| {
+            let start = row * (row + 1) / 2;
+            slots_text(&self.pyramid()[start..=start + row])
+        }));
+        lines.join("\n")
+    }
+}
+
+impl WasteBuildGame {
+    pub fn board_text(&self) -> String {
+        let rules = self.rules();
+        let mut lines = vec![
+            format!("Game: {}", self.layout().mode().label()),
+            format!(
+                "Rules: {}, {}",
+                if rules.wrap_ranks { "Wrap" } else { "No wrap" },
+                if rules.streak_scoring {
+                    "Streak scoring"
+                } else {
+                    "No streak scoring"
+                }
+            ),
+            format!("Stock: {}", cards_text(&face(self.stock().to_vec(), true))),
+            format!("Waste: {}", cards_text(self.waste())),
+        ];
+        for slots in waste_build_lines(self.layout()) {
+            let cards: Vec<Option<Card>> =
+                slots.iter().map(|&slot| self.tableau_card(slot)).collect();
+            lines.push(match self.layout() {
+                WasteBuildLayout::TriPeaks => slots_text(&cards),
+                WasteBuildLayout::Golf => {
+                    column_text(&cards.into_iter().flatten().collect::<Vec<_>>())
+                }
+            });
+        }
+        lines.join("\n")
+    }
+}
+
+impl FortyThievesGame {
+    pub fn board_text(&self) -> String {
+        let mut lines = vec![
+            "Game: Forty Thieves".to_string(),
+            pile_foundations_line(self.foundations()),
+            format!("Stock: {}", cards_text(&face(self.stock().to_vec(), true))),
+            format!("Waste: {}", cards_text(self.waste())),
+        ];
+        lines.extend(self.tableau().iter().map(|pile| column_text(pile)));
+        lines.join("\n")
+    }
+}
+
+impl ScorpionGame {
+    pub fn board_text(&self) -> String {
+        let completed: Vec<&str> = self
+            .completed_run_suits()
+            .iter()
+            .map(|suit| suit.short())
+            .collect();
+        let mut lines = vec![
+            "Game: Scorpion".to_string(),
+            format!(
+                "Reserve: {}",
+                cards_text(&face(self.reserve().to_vec(), true))
+            ),
+            format!("Completed: {}", completed.join(" ")),
+        ];
+        lines.extend(self.tableau().iter().map(|pile| column_text(pile)));
+        lines.join("\n")
+    }
+}
+
+impl CanfieldGame {
+    pub fn board_text(&self) -> String {
+        let mut lines = vec![
+            "Game: Canfield".to_string(),
+            format!("Base: {}", rank_token(self.base().rank)),
+            pile_foundations_line(self.foundations()),
+            format!(
+                "Reserve: {}",
+                cards_text(&face(self.reserve().to_vec(), true))
+            ),
+            format!("Stock: {}", cards_text(&face(self.stock().to_vec(), true))),
+            format!("Waste: {}", cards_text(self.waste())),
+        ];
+        lines.extend(self.tableau().iter().map(|pile| column_text(pile)));
+        lines.join("\n")
+    }
+}
+
+/// Slots of each TriPeaks row or Golf column, in text order.
+fn waste_build_lines(layout: WasteBuildLayout) -> Vec<Vec<usize>> {
+    let mut lines: Vec<Vec<usize>> = Vec::new();
+    for slot in 0..layout.slot_count() {
+        let (line, _) = match layout {
+            WasteBuildLayout::TriPeaks => WasteBuildGame::tripeaks_row_pos(slot),
+            WasteBuildLayout::Golf => WasteBuildGame::golf_col_depth(slot),
+        };
+        if lines.len() <= line {
+            lines.resize(line + 1, Vec::new());
+        }
+        lines[line].push(slot);
+    }
+    lines
+}
+
+fn foundations_line(foundations: &[Vec<Card>; 4]) -> String {
+    let tops: Vec<String> = Suit::ALL
+        .iter()
+        .map(|&suit| {
+            let top = foundations
+                .iter()
+                .flatten()
+                .filter(|card| card.suit == suit)
+                .map(|card| card.rank)
+                .max();
+            match top {
+                Some(rank) => format!("{}-{}", suit.short(), rank_token(rank)),
+                None => format!("{}-0", suit.short()),
+            }
+        })
+        .collect();
+    format!("Foundations: {}", tops.join(" "))
+}
+
+/// One entry per pile, naming its suit and top card, for games whose piles
+/// are laid out by suit but do not always build up from the ace.
+fn pile_foundations_line(piles: &[Vec<Card>]) -> String {
+    let tops: Vec<String> = piles
+        .iter()
+        .enumerate()
+        .map(|(idx, pile)| {
+            let suit = Suit::ALL[idx * Suit::ALL.len() / piles.len()];
+            match pile.last() {
+                Some(card) => format!("{}-{}", suit.short(), rank_token(card.rank)),
+                None => format!("{}-0", suit.short()),
+            }
+        })
+        .collect();
+    format!("Foundations: {}", tops.join(" "))
+}
+
+fn rank_token(rank: u8) -> char {
+    char::from(RANK_TOKENS[usize::from(rank - 1)])
+}
+
+fn card_text(card: Card) -> String {
+    let token = format!("{}{}", rank_token(card.rank), card.suit.short());
+    if card.face_up {
+        token
+    } else {
+        format!("[{token}]")
+    }
+}
+
+fn cards_text(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(|&card| card_text(card))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn slots_text(slots: &[Option<Card>]) -> String {
+    slots
+        .iter()
+        .map(|slot| slot.map_or_else(|| "-".to_string(), card_text))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn column_text(cards: &[Card]) -> String {
+    if cards.is_empty() {
+        "-".to_string()
+    } else {
+        cards_text(cards)
+    }
+}
+
+fn face(mut cards: Vec<Card>, face_up: bool) -> Vec<Card> {
+    for card in &mut cards {
+        card.face_up = face_up;
+    }
+    cards
+}
+
+fn parse_suit(token: &str) -> Option<Suit> {
+    match token.to_ascii_uppercase().as_str() {
+        "C" => Some(Suit::Clubs),
+        "D" => Some(Suit::Diamonds),
+        "H" => Some(Suit::Hearts),
+        "S" => Some(Suit::Spades),
+        _ => None,
+    }
+}
+
+fn parse_rank(token: &str) -> Option<u8> {
+    match token.to_ascii_uppercase().as_str() {
+        "10" => Some(10),
+        single if single.len() == 1 => RANK_TOKENS
+            .iter()
+            .position(|&rank| rank == single.as_bytes()[0])
+            .map(|idx| idx as u8 + 1),
+        _ => None,
+    }
+}
+
+fn parse_card(token: &str) -> Result<Card, String> {
+    let (inner, face_up) = match token.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        Some(inner) => (inner, false),
+        None => (token, true),
+    };
+    let split = inner.len().saturating_sub(1);
+    let parsed = inner
+        .is_char_boundary(split)
+        .then(|| Some((parse_rank(&inner[..split])?, parse_suit(&inner[split..])?)))
+        .flatten();
+    let (rank, suit) = parsed.ok_or_else(|| format!("'{token}' is not a card"))?;
+    Ok(Card {
+        suit,
+        rank,
+        face_up,
+    })
+}
+
+fn parse_cards(text: &str) -> Result<Vec<Card>, String> {
+    text.split_whitespace().map(parse_card).collect()
+}
+
+fn parse_slots(text: &str) -> Result<Vec<Option<Card>>, String> {
+    text.split_whitespace()
+        .map(|token| match token {
+            "-" => Ok(None),
+            _ => parse_card(token).map(Some),
+        })
+        .collect()
+}
+
+/// The pass a `Pass N` rule names.
+fn pass_number(item: &str) -> Option<u8> {
+    item.strip_prefix("pass ")?.trim().parse().ok()
+}
+
+fn parse_foundation(token: &str) -> Result<(Suit, u8), String> {
+    let invalid = || format!("'{token}' is not a foundation like H-0 or S-Q");
+    let (suit, rank) = token.split_once('-').ok_or_else(invalid)?;
+    let suit = parse_suit(suit).ok_or_else(invalid)?;
+    let rank = match rank {
+        "0" => 0,
+        rank => parse_rank(rank).ok_or_else(invalid)?,
+    };
+    Ok((suit, rank))
+}
+
+/// Face-down cards may only sit under the face-up run of a column.
+fn check_face_down_order(columns: &[Vec<Card>]) -> Result<(), String> {
+    for (idx, column) in columns.iter().enumerate() {
+        let face_down = column.iter().take_while(|card| !card.face_up).count();
+        if column[face_down..].iter().any(|card| !card.face_up)
+            || (!column.is_empty() && face_down == column.len())
+        {
+            return Err(format!(
+                "column {} has a face-down card above a face-up one or on top",
+                idx + 1
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Cards short (positive) or over (negative) against `expected`, in pack
+/// order.
+fn card_count_differences(cards: &[Card], expected: &[Card]) -> Vec<((Suit, u8), i32)> {
+    let mut counts: HashMap<(Suit, u8), i32> = HashMap::new();
+    for card in expected {
+        *counts.entry((card.suit, card.rank)).or_default() += 1;
+    }
+    for card in cards {
+        *counts.entry((card.suit, card.rank)).or_default() -= 1;
+    }
+    let mut wrong: Vec<((Suit, u8), i32)> = counts.into_iter().filter(|(_, n)| *n != 0).collect();
+    wrong.sort_by_key(|((suit, rank), _)| (suit.foundation_index(), *rank));
+    wrong
+}
+
+fn check_card_counts(cards: &[Card], expected: &[Card]) -> Result<(), String> {
+    match card_count_differences(cards, expected).first() {
+        None => Ok(()),
+        Some(&((suit, rank), missing)) => {
+            let card = format!("{}{}", rank_token(rank), suit.short());
+            Err(if missing > 0 {
+                format!("board is missing {card}")
+            } else {
+                format!("board has too many {card}")
+            })
+        }
+    }
+}
+
+/// The pack's cards that are not on the board, for Pyramid's removed pile.
+/// A card shown twice is still an error.
+fn missing_cards(cards: &[Card]) -> Result<Vec<Card>, String> {
+    let differences = card_count_differences(cards, &full_pack());
+    if let Some(((suit, rank), _)) = differences.iter().find(|(_, n)| *n < 0) {
+        return Err(format!(
+            "board has too many {}{}",
+            rank_token(*rank),
+            suit.short()
+        ));
+    }
+    Ok(differences
+        .into_iter()
+        .map(|((suit, rank), _)| Card {
+            suit,
+            rank,
+            face_up: true,
+        })
+        .collect())
+}
+
+/// A foundation pile of `suit` from `base` up to `top` (0 for empty),
+/// wrapping from King to Ace.
+fn suit_run(suit: Suit, base: u8, top: u8) -> Vec<Card> {
+    let mut pile = Vec::new();
+    if top == 0 {
+        return pile;
+    }
+    let mut rank = base;
+    loop {
+        pile.push(Card {
+            suit,
+            rank,
+            face_up: true,
+        });
+        if rank == top || pile.len() == 13 {
+            return pile;
+        }
+        rank = FoundationBase::next_rank(rank);
+    }
+}
+
+fn full_pack() -> Vec<Card> {
+    Suit::ALL
+        .iter()
+        .flat_map(|&suit| {
+            (1..=13).map(move |rank| Card {
+                suit,
+                rank,
+                face_up: true,
+            })
+        })
+        .collect()
+}
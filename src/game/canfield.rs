@@ -0,0 +1,476 @@
+use std::collections::HashMap;
+
+use super::setup::full_deck;
+use super::spider::{decode_spider_pile, encode_spider_pile};
+use super::{Card, DealRng, DrawResult, FoundationBase};
+
+pub const CANFIELD_COLUMNS: usize = 4;
+const CANFIELD_RESERVE: usize = 13;
+const CANFIELD_DRAW_COUNT: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CanfieldMove {
+    Draw,
+    WasteToFoundation,
+    WasteToTableau {
+        dst: usize,
+    },
+    ReserveToFoundation,
+    ReserveToTableau {
+        dst: usize,
+    },
+    TableauTopToFoundation {
+        src: usize,
+    },
+    TableauRunToTableau {
+        src: usize,
+        start: usize,
+        dst: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CanfieldGame {
+    stock: Vec<Card>,
+    waste: Vec<Card>,
+    /// Only the top card is face up and playable.
+    reserve: Vec<Card>,
+    /// Indexed by `Suit::foundation_index`; every pile starts at `base`.
+    foundations: [Vec<Card>; 4],
+    tableau: [Vec<Card>; CANFIELD_COLUMNS],
+    base: FoundationBase,
+}
+
+impl CanfieldGame {
+    pub fn new_with_seed(seed: u64) -> Self {
+        let mut deck = full_deck();
+        DealRng::from_seed(seed).shuffle(&mut deck);
+
+        let mut draw = deck.into_iter();
+        let mut reserve: Vec<Card> = draw.by_ref().take(CANFIELD_RESERVE).collect();
+        if let Some(top) = reserve.last_mut() {
+            top.face_up = true;
+        }
+        let mut first = draw.next().expect("canfield setup deals a foundation card");
+        first.face_up = true;
+        let mut foundations: [Vec<Card>; 4] = std::array::from_fn(|_| Vec::new());
+        foundations[first.suit.foundation_index()].push(first);
+        let tableau = std::array::from_fn(|_| {
+            let mut card = draw.next().expect("canfield setup consumes 18 cards");
+            card.face_up = true;
+            vec![card]
+        });
+
+        Self {
+            stock: draw.collect(),
+            waste: Vec::new(),
+            reserve,
+            foundations,
+            tableau,
+            base: FoundationBase { rank: first.rank },
+        }
+    }
+
+    pub(super) fn from_board(
+        base: FoundationBase,
+        stock: Vec<Card>,
+        waste: Vec<Card>,
+        reserve: Vec<Card>,
+        foundations: [Vec<Card>; 4],
+        tableau: [Vec<Card>; CANFIELD_COLUMNS],
+    ) -> Self {
+        Self {
+            stock,
+            waste,
+            reserve,
+            foundations,
+            tableau,
+            base,
+        }
+    }
+
+    pub fn base(&self) -> FoundationBase {
+        self.base
+    }
+
+    pub fn stock(&self) -> &[Card] {
+        &self.stock
+    }
+
+    pub fn stock_len(&self) -> usize {
+        self.stock.len()
+    }
+
+    pub fn waste(&self) -> &[Card] {
+        &self.waste
+    }
+
+    pub fn waste_top(&self) -> Option<Card> {
+        self.waste.last().copied()
+    }
+
+    pub fn reserve(&self) -> &[Card] {
+        &self.reserve
+    }
+
+    pub fn reserve_top(&self) -> Option<Card> {
+        self.reserve.last().copied()
+    }
+
+    pub fn foundations(&self) -> &[Vec<Card>; 4] {
+        &self.foundations
+    }
+
+    pub fn tableau(&self) -> &[Vec<Card>; CANFIELD_COLUMNS] {
+        &self.tableau
+    }
+
+    pub fn tableau_card(&self, col: usize, index: usize) -> Option<Card> {
+        self.tableau
+            .get(col)
+            .and_then(|pile| pile.get(index))
+            .copied()
+    }
+
+    pub fn tableau_top(&self, col: usize) -> Option<Card> {
+        self.tableau.get(col).and_then(|pile| pile.last().copied())
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.len() == 13)
+    }
+
+    pub fn has_legal_moves(&self) -> bool {
+        !self.is_won() && !self.legal_moves().is_empty()
+    }
+
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && !self.has_legal_moves()
+    }
+
+    pub fn foundation_target(&self, card: Card) -> Option<usize> {
+        let pile = card.suit.foundation_index();
+        self.base
+            .accepts(&self.foundations[pile], card)
+            .then_some(pile)
+    }
+
+    pub fn can_draw(&self) -> bool {
+        !self.stock.is_empty() || !self.waste.is_empty()
+    }
+
+    /// Turns up to three cards onto the waste, or turns the waste back over
+    /// once the stock runs out. Canfield allows any number of passes.
+    pub fn draw(&mut self) -> DrawResult {
+        if self.stock.is_empty() {
+            if self.waste.is_empty() {
+                return DrawResult::NoOp;
+            }
+            while let Some(mut card) = self.waste.pop() {
+                card.face_up = false;
+                self.stock.push(card);
+            }
+            return DrawResult::RecycledWaste;
+        }
+        for _ in 0..CANFIELD_DRAW_COUNT {
+            let Some(mut card) = self.stock.pop() else {
+                break;
+            };
+            card.face_up = true;
+            self.waste.push(card);
+        }
+        DrawResult::DrewFromStock
+    }
+
+    pub fn can_move_waste_to_foundation(&self) -> bool {
+        self.waste_top()
+            .is_some_and(|card| self.foundation_target(card).is_some())
+    }
+
+    pub fn move_waste_to_foundation(&mut self) -> bool {
+        let Some(pile) = self
+            .waste_top()
+            .and_then(|card| self.foundation_target(card))
+        else {
+            return false;
+        };
+        let Some(card) = self.waste.pop() else {
+            return false;
+        };
+        self.foundations[pile].push(card);
+        true
+    }
+
+    pub fn can_move_waste_to_tableau(&self, dst: usize) -> bool {
+        self.waste_top()
+            .is_some_and(|card| self.column_accepts(dst, card))
+    }
+
+    pub fn move_waste_to_tableau(&mut self, dst: usize) -> bool {
+        if !self.can_move_waste_to_tableau(dst) {
+            return false;
+        }
+        let Some(card) = self.waste.pop() else {
+            return false;
+        };
+        self.tableau[dst].push(card);
+        true
+    }
+
+    pub fn can_move_reserve_to_foundation(&self) -> bool {
+        self.reserve_top()
+            .is_some_and(|card| self.foundation_target(card).is_some())
+    }
+
+    pub fn move_reserve_to_foundation(&mut self) -> bool {
+        let Some(pile) = self
+            .reserve_top()
+            .and_then(|card| self.foundation_target(card))
+        else {
+            return false;
+        };
+        let Some(card) = self.pop_reserve() else {
+            return false;
+        };
+        self.foundations[pile].push(card);
+        self.fill_from_reserve();
+        true
+    }
+
+    pub fn can_move_reserve_to_tableau(&self, dst: usize) -> bool {
+        self.reserve_top()
+            .is_some_and(|card| self.column_accepts(dst, card))
+    }
+
+    pub fn move_reserve_to_tableau(&mut self, dst: usize) -> bool {
+        if !self.can_move_reserve_to_tableau(dst) {
+            return false;
+        }
+        let Some(card) = self.pop_reserve() else {
+            return false;
+        };
+        self.tableau[dst].push(card);
+        self.fill_from_reserve();
+        true
+    }
+
+    pub fn can_move_tableau_top_to_foundation(&self, src: usize) -> bool {
+        self.tableau_top(src)
+            .is_some_and(|card| self.foundation_target(card).is_some())
+    }
+
+    pub fn move_tableau_top_to_foundation(&mut self, src: usize) -> bool {
+        let Some(pile) = self
+            .tableau_top(src)
+            .and_then(|card| self.foundation_target(card))
+        else {
+            return false;
+        };
+        let Some(card) = self.tableau[src].pop() else {
+            return false;
+        };
+        self.foundations[pile].push(card);
+        self.fill_from_reserve();
+        true
+    }
+
+    /// Whether `src[start..]` is an alternating-colour run that fits `dst`.
+    /// Runs wrap like the foundations (a King may go on an Ace), and an empty
+    /// column only takes a single card.
+    pub fn can_move_run(&self, src: usize, start: usize, dst: usize) -> bool {
+        if src == dst || src >= self.tableau.len() || dst >= self.tableau.len() {
+            return false;
+        }
+        let pile = &self.tableau[src];
+        if start >= pile.len() || !is_alternating_run(&pile[start..]) {
+            return false;
+        }
+        if self.tableau[dst].is_empty() && pile.len() - start > 1 {
+            return false;
+        }
+        self.column_accepts(dst, pile[start])
+    }
+
+    pub fn move_run(&mut self, src: usize, start: usize, dst: usize) -> bool {
+        if !self.can_move_run(src, start, dst) {
+            return false;
+        }
+        let moved = self.tableau[src].split_off(start);
+        self.tableau[dst].extend(moved);
+        self.fill_from_reserve();
+        true
+    }
+
+    /// Every legal move, foundation plays first and the stock last.
+    pub fn legal_moves(&self) -> Vec<CanfieldMove> {
+        let mut moves = Vec::new();
+        if self.can_move_reserve_to_foundation() {
+            moves.push(CanfieldMove::ReserveToFoundation);
+        }
+        if self.can_move_waste_to_foundation() {
+            moves.push(CanfieldMove::WasteToFoundation);
+        }
+        for src in 0..self.tableau.len() {
+            if self.can_move_tableau_top_to_foundation(src) {
+                moves.push(CanfieldMove::TableauTopToFoundation { src });
+            }
+        }
+        for dst in 0..self.tableau.len() {
+            if self.can_move_reserve_to_tableau(dst) {
+                moves.push(CanfieldMove::ReserveToTableau { dst });
+            }
+        }
+        for dst in 0..self.tableau.len() {
+            if self.can_move_waste_to_tableau(dst) {
+                moves.push(CanfieldMove::WasteToTableau { dst });
+            }
+        }
+        for src in 0..self.tableau.len() {
+            for start in 0..self.tableau[src].len() {
+                for dst in 0..self.tableau.len() {
+                    if self.can_move_run(src, start, dst) {
+                        moves.push(CanfieldMove::TableauRunToTableau { src, start, dst });
+                    }
+                }
+            }
+        }
+        if self.can_draw() {
+            moves.push(CanfieldMove::Draw);
+        }
+        moves
+    }
+
+    pub fn apply_move(&mut self, canfield_move: CanfieldMove) -> bool {
+        match canfield_move {
+            CanfieldMove::Draw => self.draw() != DrawResult::NoOp,
+            CanfieldMove::WasteToFoundation => self.move_waste_to_foundation(),
+            CanfieldMove::WasteToTableau { dst } => self.move_waste_to_tableau(dst),
+            CanfieldMove::ReserveToFoundation => self.move_reserve_to_foundation(),
+            CanfieldMove::ReserveToTableau { dst } => self.move_reserve_to_tableau(dst),
+            CanfieldMove::TableauTopToFoundation { src } => {
+                self.move_tableau_top_to_foundation(src)
+            }
+            CanfieldMove::TableauRunToTableau { src, start, dst } => self.move_run(src, start, dst),
+        }
+    }
+
+    pub fn encode_for_session(&self) -> String {
+        let mut parts = vec![
+            format!("base={}", self.base.rank),
+            format!("s={}", encode_spider_pile(&self.stock)),
+            format!("w={}", encode_spider_pile(&self.waste)),
+            format!("r={}", encode_spider_pile(&self.reserve)),
+        ];
+        for (idx, pile) in self.foundations.iter().enumerate() {
+            parts.push(format!("f{idx}={}", encode_spider_pile(pile)));
+        }
+        for (idx, pile) in self.tableau.iter().enumerate() {
+            parts.push(format!("t{idx}={}", encode_spider_pile(pile)));
+        }
+        parts.join(";")
+    }
+
+    pub fn decode_from_session(data: &str) -> Option<Self> {
+        let mut fields = HashMap::<&str, &str>::new();
+        for part in data.split(';') {
+            let (key, value) = part.split_once('=')?;
+            fields.insert(key, value);
+        }
+
+        let base = FoundationBase::new(fields.get("base")?.parse::<u8>().ok()?)?;
+        let pile = |key: &str| decode_spider_pile(fields.get(key)?);
+        let stock = pile("s")?;
+        let waste = pile("w")?;
+        let reserve = pile("r")?;
+        let mut foundations: [Vec<Card>; 4] = std::array::from_fn(|_| Vec::new());
+        for (idx, slot) in foundations.iter_mut().enumerate() {
+            *slot = pile(&format!("f{idx}"))?;
+            if slot
+                .first()
+                .is_some_and(|card| card.rank != base.rank || card.suit.foundation_index() != idx)
+            {
+                return None;
+            }
+        }
+        let mut tableau: [Vec<Card>; CANFIELD_COLUMNS] = std::array::from_fn(|_| Vec::new());
+        for (idx, slot) in tableau.iter_mut().enumerate() {
+            *slot = pile(&format!("t{idx}"))?;
+        }
+
+        let foundations_count: usize = foundations.iter().map(Vec::len).sum();
+        let tableau_count: usize = tableau.iter().map(Vec::len).sum();
+        if stock.len() + waste.len() + reserve.len() + foundations_count + tableau_count != 52 {
+            return None;
+        }
+
+        Some(Self {
+            stock,
+            waste,
+            reserve,
+            foundations,
+            tableau,
+            base,
+        })
+    }
+
+    fn column_accepts(&self, dst: usize, card: Card) -> bool {
+        match self.tableau.get(dst) {
+            None => false,
+            Some(pile) => match pile.last() {
+                None => true,
+                Some(top) => stacks_on(card, *top),
+            },
+        }
+    }
+
+    fn pop_reserve(&mut self) -> Option<Card> {
+        let card = self.reserve.pop()?;
+        if let Some(top) = self.reserve.last_mut() {
+            top.face_up = true;
+        }
+        Some(card)
+    }
+
+    /// Empty tableau piles are refilled from the reserve straight away; once
+    /// the reserve is gone they wait for the player.
+    fn fill_from_reserve(&mut self) {
+        for col in 0..self.tableau.len() {
+            if !self.tableau[col].is_empty() {
+                continue;
+            }
+            let Some(card) = self.pop_reserve() else {
+                return;
+            };
+            self.tableau[col].push(card);
+        }
+    }
+}
+
+#[cfg(test)]
+impl CanfieldGame {
+    pub(crate) fn debug_new(
+        base_rank: u8,
+        stock: Vec<Card>,
+        waste: Vec<Card>,
+        reserve: Vec<Card>,
+        foundations: [Vec<Card>; 4],
+        tableau: [Vec<Card>; CANFIELD_COLUMNS],
+    ) -> Self {
+        Self {
+            stock,
+            waste,
+            reserve,
+            foundations,
+            tableau,
+            base: FoundationBase { rank: base_rank },
+        }
+    }
+}
+
+fn stacks_on(card: Card, onto: Card) -> bool {
+    card.color_red() != onto.color_red() && FoundationBase::next_rank(card.rank) == onto.rank
+}
+
+fn is_alternating_run(cards: &[Card]) -> bool {
+    cards.windows(2).all(|pair| stacks_on(pair[1], pair[0]))
+}
@@ -0,0 +1,179 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+use super::*;
+
+/// Longest line the search follows. A win needs 51 foundation moves; the
+/// rest is room for draws, which repeat across unlimited passes.
+const CANFIELD_MAX_LINE: usize = 500;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanfieldSolveResult {
+    pub winnable: bool,
+    pub explored_states: usize,
+    pub hit_state_limit: bool,
+    /// Winning line from the analysed position; empty unless `winnable`.
+    pub line: Vec<CanfieldMove>,
+}
+
+struct CanfieldSearch<'a> {
+    visited: HashSet<u64>,
+    max_states: usize,
+    cancel: &'a AtomicBool,
+    hit_state_limit: bool,
+    canceled: bool,
+    line: Vec<CanfieldMove>,
+}
+
+impl CanfieldGame {
+    pub fn solve(&self, max_states: usize) -> CanfieldSolveResult {
+        let cancel = AtomicBool::new(false);
+        self.solve_cancelable(max_states, &cancel)
+            .unwrap_or(CanfieldSolveResult {
+                winnable: false,
+                explored_states: 0,
+                hit_state_limit: true,
+                line: Vec::new(),
+            })
+    }
+
+    /// Depth-first search over the pruned move list. The search sees the
+    /// stock and reserve order, so a found line always wins.
+    pub fn solve_cancelable(
+        &self,
+        max_states: usize,
+        cancel: &AtomicBool,
+    ) -> Option<CanfieldSolveResult> {
+        let mut search = CanfieldSearch {
+            visited: HashSet::new(),
+            max_states: max_states.max(1),
+            cancel,
+            hit_state_limit: false,
+            canceled: false,
+            line: Vec::new(),
+        };
+        let winnable = search.search(self);
+        if search.canceled {
+            return None;
+        }
+        Some(CanfieldSolveResult {
+            winnable,
+            explored_states: search.visited.len(),
+            hit_state_limit: !winnable && search.hit_state_limit,
+            line: if winnable { search.line } else { Vec::new() },
+        })
+    }
+
+    fn solver_key(&self) -> u64 {
+        // The reserve refills columns in index order, so columns only become
+        // interchangeable once it is empty.
+        let mut columns: Vec<u64> = self
+            .tableau()
+            .iter()
+            .map(|column| {
+                let mut hasher = DefaultHasher::new();
+                column.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+        if self.reserve().is_empty() {
+            columns.sort_unstable();
+        }
+        let mut hasher = DefaultHasher::new();
+        columns.hash(&mut hasher);
+        self.stock_len().hash(&mut hasher);
+        self.waste().hash(&mut hasher);
+        self.reserve().len().hash(&mut hasher);
+        for pile in self.foundations() {
+            pile.len().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Candidate moves in search order: foundation plays, then emptying the
+    /// reserve, then tableau and waste building, with the stock last.
+    pub fn solver_moves(&self) -> Vec<CanfieldMove> {
+        let mut ordered: Vec<(i32, CanfieldMove)> = self
+            .legal_moves()
+            .into_iter()
+            .filter_map(|candidate| {
+                self.solver_move_priority(candidate)
+                    .map(|priority| (priority, candidate))
+            })
+            .collect();
+        ordered.sort_by_key(|(priority, _)| -priority);
+        ordered
+            .into_iter()
+            .map(|(_, candidate)| candidate)
+            .collect()
+    }
+
+    fn solver_move_priority(&self, candidate: CanfieldMove) -> Option<i32> {
+        match candidate {
+            CanfieldMove::ReserveToFoundation => Some(110),
+            CanfieldMove::WasteToFoundation | CanfieldMove::TableauTopToFoundation { .. } => {
+                Some(100)
+            }
+            CanfieldMove::ReserveToTableau { .. } => Some(60),
+            CanfieldMove::WasteToTableau { dst } => Some(if self.tableau()[dst].is_empty() {
+                5
+            } else {
+                30
+            }),
+            CanfieldMove::TableauRunToTableau { src, start, dst } => {
+                let dst_empty = self.tableau()[dst].is_empty();
+                if start == 0 && dst_empty {
+                    return None;
+                }
+                let mut priority = if dst_empty { 10 } else { 40 };
+                if start == 0 {
+                    priority += 20;
+                } else if self
+                    .tableau_card(src, start - 1)
+                    .is_some_and(|below| self.foundation_target(below).is_some())
+                {
+                    priority += 30;
+                }
+                Some(priority)
+            }
+            CanfieldMove::Draw => Some(0),
+        }
+    }
+}
+
+impl CanfieldSearch<'_> {
+    fn search(&mut self, state: &CanfieldGame) -> bool {
+        if state.is_won() {
+            return true;
+        }
+        if self.cancel.load(AtomicOrdering::Relaxed) {
+            self.canceled = true;
+            return false;
+        }
+        if self.visited.len() >= self.max_states || self.line.len() >= CANFIELD_MAX_LINE {
+            self.hit_state_limit = true;
+            return false;
+        }
+        if !self.visited.insert(state.solver_key()) {
+            return false;
+        }
+
+        for candidate in state.solver_moves() {
+            let mut next = state.clone();
+            if !next.apply_move(candidate) {
+                continue;
+            }
+            self.line.push(candidate);
+            if self.search(&next) {
+                return true;
+            }
+            self.line.pop();
+            if self.canceled {
+                return false;
+            }
+        }
+        false
+    }
+}
@@ -116,13 +116,10 @@ fn apply_move_atomic(position: &mut ChessPosition, chess_move: ChessMove) -> boo
     }
     let _ = position.set_piece(chess_move.to, Some(moving_piece));
 
-    if let Some(center) = capture_square {
-        // Captures explode the capture center and all adjacent non-pawns.
-        let mut blast = blast_zone(center);
-        if !blast.contains(&chess_move.to) {
-            blast.push(chess_move.to);
-        }
-        for sq in blast {
+    if capture_square.is_some() {
+        // Captures explode the landing square and all adjacent non-pawns; an
+        // en passant blast is centred where the capturing pawn lands too.
+        for sq in blast_zone(chess_move.to) {
             let Some(piece) = position.piece_at(sq) else {
                 continue;
             };
@@ -1,5 +1,8 @@
+use super::apply::apply_move;
+use super::attacks;
 use super::moves::ChessMove;
 use super::position::ChessPosition;
+use super::rules::ChessRuleset;
 use super::types::{file_of, rank_of, square, ChessColor, ChessPieceKind, Square};
 
 pub(super) fn blast_zone(center: Square) -> Vec<Square> {
@@ -36,7 +39,7 @@ pub(super) fn capture_center_for_move(
         let capture_square = square(file_of(chess_move.to), rank_of(chess_move.from))?;
         let captured = position.piece_at(capture_square)?;
         if captured.kind == ChessPieceKind::Pawn && captured.color != side_to_move {
-            return Some(capture_square);
+            return Some(chess_move.to);
         }
         return None;
     }
@@ -44,6 +47,46 @@ pub(super) fn capture_center_for_move(
     (target.color != side_to_move).then_some(chess_move.to)
 }
 
+/// What an Atomic capture would blow up, for previewing a move before it is played.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomicBlastPreview {
+    pub center: Square,
+    pub zone: Vec<Square>,
+    pub casualties: Vec<Square>,
+}
+
+/// Returns the blast of `chess_move` if it is a capture in an Atomic position.
+/// Casualties are the occupied squares the explosion empties, including the
+/// capturing piece's destination.
+pub fn atomic_blast_preview(
+    position: &ChessPosition,
+    chess_move: ChessMove,
+) -> Option<AtomicBlastPreview> {
+    if ChessRuleset::for_variant(position.variant()) != ChessRuleset::Atomic {
+        return None;
+    }
+    let center = capture_center_for_move(position, chess_move, position.side_to_move())?;
+    let mut next = position.clone();
+    if !apply_move(&mut next, chess_move) {
+        return None;
+    }
+    let mut casualties = (0_u8..64)
+        .filter(|sq| {
+            *sq != chess_move.from
+                && position.piece_at(*sq).is_some()
+                && next.piece_at(*sq).is_none()
+        })
+        .collect::<Vec<_>>();
+    if !casualties.contains(&chess_move.to) {
+        casualties.push(chess_move.to);
+    }
+    Some(AtomicBlastPreview {
+        center,
+        zone: blast_zone(center),
+        casualties,
+    })
+}
+
 pub(super) fn is_king_capture_move(
     position: &ChessPosition,
     chess_move: ChessMove,
@@ -71,189 +114,41 @@ pub(super) fn is_in_check(position: &ChessPosition, color: ChessColor) -> bool {
     let Some(king_square) = find_king_square(position, color) else {
         return false;
     };
-    square_attacked_by(position, king_square, color.opposite())
+    king_attacked_on(position, king_square, color)
 }
 
-pub(super) fn square_attacked_by(position: &ChessPosition, target: Square, by: ChessColor) -> bool {
-    for from in 0_u8..64 {
-        let Some(piece) = position.piece_at(from) else {
-            continue;
-        };
-        if piece.color != by || piece.kind == ChessPieceKind::King {
-            continue;
-        }
-        if piece_explodes_target(position, from, piece.kind, by, target) {
-            return true;
-        }
-    }
-    false
-}
-
-fn piece_explodes_target(
+/// Whether a `color` king standing on `king_square` would be in check.
+pub(super) fn king_attacked_on(
     position: &ChessPosition,
-    from: Square,
-    kind: ChessPieceKind,
+    king_square: Square,
     color: ChessColor,
-    target: Square,
 ) -> bool {
-    match kind {
-        ChessPieceKind::Pawn => pawn_explodes_target(position, from, color, target),
-        ChessPieceKind::Knight => leaper_explodes_target(
-            position,
-            from,
-            color,
-            target,
-            &[
-                (1, 2),
-                (2, 1),
-                (2, -1),
-                (1, -2),
-                (-1, -2),
-                (-2, -1),
-                (-2, 1),
-                (-1, 2),
-            ],
-        ),
-        ChessPieceKind::Bishop => slider_explodes_target(
-            position,
-            from,
-            color,
-            target,
-            &[(-1, -1), (1, -1), (-1, 1), (1, 1)],
-        ),
-        ChessPieceKind::Rook => slider_explodes_target(
-            position,
-            from,
-            color,
-            target,
-            &[(-1, 0), (1, 0), (0, -1), (0, 1)],
-        ),
-        ChessPieceKind::Queen => slider_explodes_target(
-            position,
-            from,
-            color,
-            target,
-            &[
-                (-1, -1),
-                (1, -1),
-                (-1, 1),
-                (1, 1),
-                (-1, 0),
-                (1, 0),
-                (0, -1),
-                (0, 1),
-            ],
-        ),
-        ChessPieceKind::King => false,
+    // Touching kings cannot be checked: any blast reaching one king takes the other too.
+    if find_king_square(position, color.opposite())
+        .is_some_and(|enemy_king| explosion_hits(enemy_king, king_square))
+    {
+        return false;
     }
+    square_attacked_by(position, king_square, color.opposite())
 }
 
-fn pawn_explodes_target(
-    position: &ChessPosition,
-    from: Square,
-    color: ChessColor,
-    target: Square,
-) -> bool {
-    let step = match color {
-        ChessColor::White => 1_i8,
-        ChessColor::Black => -1_i8,
-    };
-
-    for file_delta in [-1_i8, 1_i8] {
-        let Some(capture_square) = offset_square(from, file_delta, step) else {
+/// Kings never capture in Atomic, so only the other pieces attack a square.
+/// A capture whose blast would merely reach `target` does not count.
+pub(super) fn square_attacked_by(position: &ChessPosition, target: Square, by: ChessColor) -> bool {
+    for from in 0_u8..64 {
+        let Some(piece) = position.piece_at(from) else {
             continue;
         };
-        if position
-            .piece_at(capture_square)
-            .is_some_and(|piece| piece.color != color)
-            && explosion_hits(capture_square, target)
-        {
-            return true;
-        }
-    }
-
-    if position.side_to_move() == color {
-        if let Some(ep_target) = position.en_passant() {
-            let from_file = file_of(from) as i8;
-            let from_rank = rank_of(from) as i8;
-            let ep_file = file_of(ep_target) as i8;
-            let ep_rank = rank_of(ep_target) as i8;
-            if ep_rank == from_rank + step && (from_file - ep_file).unsigned_abs() == 1 {
-                if let Some(capture_center) = square(ep_file as u8, from_rank as u8) {
-                    if position.piece_at(capture_center).is_some_and(|piece| {
-                        piece.kind == ChessPieceKind::Pawn && piece.color != color
-                    }) && explosion_hits(capture_center, target)
-                    {
-                        return true;
-                    }
-                }
-            }
-        }
-    }
-
-    false
-}
-
-fn leaper_explodes_target(
-    position: &ChessPosition,
-    from: Square,
-    color: ChessColor,
-    target: Square,
-    deltas: &[(i8, i8)],
-) -> bool {
-    for &(file_delta, rank_delta) in deltas {
-        let Some(capture_square) = offset_square(from, file_delta, rank_delta) else {
+        if piece.color != by || piece.kind == ChessPieceKind::King {
             continue;
-        };
-        if position
-            .piece_at(capture_square)
-            .is_some_and(|piece| piece.color != color)
-            && explosion_hits(capture_square, target)
-        {
-            return true;
         }
-    }
-    false
-}
-
-fn slider_explodes_target(
-    position: &ChessPosition,
-    from: Square,
-    color: ChessColor,
-    target: Square,
-    directions: &[(i8, i8)],
-) -> bool {
-    for &(file_step, rank_step) in directions {
-        let mut file = file_of(from) as i8;
-        let mut rank = rank_of(from) as i8;
-        loop {
-            file += file_step;
-            rank += rank_step;
-            if !(0..8).contains(&file) || !(0..8).contains(&rank) {
-                break;
-            }
-            let capture_square = square(file as u8, rank as u8).expect("checked in bounds");
-            let Some(captured_piece) = position.piece_at(capture_square) else {
-                continue;
-            };
-            if captured_piece.color != color && explosion_hits(capture_square, target) {
-                return true;
-            }
-            break;
+        if attacks::piece_attacks_square(position, from, piece, target) {
+            return true;
         }
     }
     false
 }
 
-fn offset_square(from: Square, file_delta: i8, rank_delta: i8) -> Option<Square> {
-    let file = file_of(from) as i8 + file_delta;
-    let rank = rank_of(from) as i8 + rank_delta;
-    if !(0..8).contains(&file) || !(0..8).contains(&rank) {
-        return None;
-    }
-    square(file as u8, rank as u8)
-}
-
 fn explosion_hits(center: Square, target: Square) -> bool {
     let file_diff = (file_of(center) as i16 - file_of(target) as i16).unsigned_abs();
     let rank_diff = (rank_of(center) as i16 - rank_of(target) as i16).unsigned_abs();
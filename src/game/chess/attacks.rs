@@ -35,7 +35,7 @@ fn find_king(position: &ChessPosition, color: ChessColor) -> Option<Square> {
     None
 }
 
-fn piece_attacks_square(
+pub(super) fn piece_attacks_square(
     position: &ChessPosition,
     from: Square,
     piece: ChessPiece,
@@ -100,9 +100,8 @@ pub(super) fn can_castle(
         }
     }
 
-    let enemy = color.opposite();
     for sq in king_path {
-        if square_attacked_by_for_ruleset(&temp, sq, enemy, ruleset) {
+        if king_attacked_on_for_ruleset(&temp, sq, color, ruleset) {
             return None;
         }
     }
@@ -169,14 +168,16 @@ fn is_in_check_for_ruleset(
     }
 }
 
-fn square_attacked_by_for_ruleset(
+fn king_attacked_on_for_ruleset(
     position: &ChessPosition,
-    target: Square,
-    by: ChessColor,
+    king_square: Square,
+    color: ChessColor,
     ruleset: ChessRuleset,
 ) -> bool {
     match ruleset {
-        ChessRuleset::Classical => attacks::square_attacked_by(position, target, by),
-        ChessRuleset::Atomic => atomic::square_attacked_by(position, target, by),
+        ChessRuleset::Classical => {
+            attacks::square_attacked_by(position, king_square, color.opposite())
+        }
+        ChessRuleset::Atomic => atomic::king_attacked_on(position, king_square, color),
     }
 }
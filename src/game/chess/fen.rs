@@ -1,13 +1,16 @@
 use std::str::FromStr;
 
+use super::castling::castling_triplet;
 use super::position::{CastlingRights, ChessPosition};
-use super::types::{parse_square, square, square_name, ChessColor, ChessPiece, ChessPieceKind};
+use super::types::{
+    file_of, parse_square, square, square_name, ChessColor, ChessPiece, ChessPieceKind,
+};
 use super::ChessVariant;
 
 pub fn encode_fen(position: &ChessPosition) -> String {
     let board = encode_board(position);
     let side_to_move = position.side_to_move().fen_char();
-    let castling = encode_castling(position.castling_rights());
+    let castling = encode_castling(position);
     let en_passant = position
         .en_passant()
         .map(square_name)
@@ -30,7 +33,6 @@ pub fn decode_fen(raw: &str, variant: ChessVariant) -> Option<ChessPosition> {
     position.clear_board();
     decode_board(fields[0], &mut position)?;
     position.set_side_to_move(ChessColor::from_fen_char(fields[1].chars().next()?)?);
-    position.set_castling_rights(decode_castling(fields[2])?);
     position.set_en_passant(if fields[3] == "-" {
         None
     } else {
@@ -39,8 +41,28 @@ pub fn decode_fen(raw: &str, variant: ChessVariant) -> Option<ChessPosition> {
     position.set_halfmove_clock(u16::from_str(fields[4]).ok()?);
     position.set_fullmove_number(u16::from_str(fields[5]).ok()?);
 
-    let white_back_rank = collect_back_rank(&position, ChessColor::White);
-    let black_back_rank = collect_back_rank(&position, ChessColor::Black);
+    let mut white_back_rank = collect_back_rank(&position, ChessColor::White);
+    let mut black_back_rank = collect_back_rank(&position, ChessColor::Black);
+    let mut rights = CastlingRights::none();
+    if fields[2] != "-" {
+        for ch in fields[2].chars() {
+            let (color, back_rank) = if ch.is_ascii_uppercase() {
+                (ChessColor::White, &mut white_back_rank)
+            } else {
+                (ChessColor::Black, &mut black_back_rank)
+            };
+            let kingside = match ch.to_ascii_lowercase() {
+                'k' => true,
+                'q' => false,
+                file @ 'a'..='h' if variant.is_fischer_random() => {
+                    pin_castling_rook(back_rank, file as u8 - b'a')?
+                }
+                _ => return None,
+            };
+            set_castling_side(&mut rights, color, kingside);
+        }
+    }
+    position.set_castling_rights(rights);
     position.set_back_ranks(white_back_rank, black_back_rank);
     Some(position)
 }
@@ -104,43 +126,72 @@ fn decode_board(board: &str, position: &mut ChessPosition) -> Option<()> {
     Some(())
 }
 
-fn encode_castling(rights: CastlingRights) -> String {
+/// Classical games use `KQkq`; Fischer-random games name the rook files
+/// (Shredder-FEN, e.g. `HAha`) so asymmetric back ranks survive a round trip.
+fn encode_castling(position: &ChessPosition) -> String {
+    let rights = position.castling_rights();
     if !rights.has_any() {
         return "-".to_string();
     }
 
     let mut text = String::new();
-    if rights.white_king_side {
-        text.push('K');
-    }
-    if rights.white_queen_side {
-        text.push('Q');
-    }
-    if rights.black_king_side {
-        text.push('k');
-    }
-    if rights.black_queen_side {
-        text.push('q');
+    for (color, kingside, enabled, classical) in [
+        (ChessColor::White, true, rights.white_king_side, 'K'),
+        (ChessColor::White, false, rights.white_queen_side, 'Q'),
+        (ChessColor::Black, true, rights.black_king_side, 'k'),
+        (ChessColor::Black, false, rights.black_queen_side, 'q'),
+    ] {
+        if !enabled {
+            continue;
+        }
+        let shredder = position
+            .variant()
+            .is_fischer_random()
+            .then(|| castling_triplet(position, color, kingside))
+            .flatten()
+            .map(|layout| {
+                let letter = char::from(b'A' + file_of(layout.rook_from));
+                match color {
+                    ChessColor::White => letter,
+                    ChessColor::Black => letter.to_ascii_lowercase(),
+                }
+            });
+        text.push(shredder.unwrap_or(classical));
     }
     text
 }
 
-fn decode_castling(field: &str) -> Option<CastlingRights> {
-    if field == "-" {
-        return Some(CastlingRights::none());
+fn set_castling_side(rights: &mut CastlingRights, color: ChessColor, kingside: bool) {
+    match (color, kingside) {
+        (ChessColor::White, true) => rights.white_king_side = true,
+        (ChessColor::White, false) => rights.white_queen_side = true,
+        (ChessColor::Black, true) => rights.black_king_side = true,
+        (ChessColor::Black, false) => rights.black_queen_side = true,
     }
+}
 
-    let mut rights = CastlingRights::none();
-    for ch in field.chars() {
-        match ch {
-            'K' => rights.white_king_side = true,
-            'Q' => rights.white_queen_side = true,
-            'k' => rights.black_king_side = true,
-            'q' => rights.black_queen_side = true,
-            _ => return None,
+/// Makes `rook_file` the castling rook for its side of the king by dropping any
+/// rook between them from the back-rank layout. Returns whether it is kingside.
+fn pin_castling_rook(back_rank: &mut [ChessPieceKind; 8], rook_file: u8) -> Option<bool> {
+    let king_file = back_rank
+        .iter()
+        .position(|piece| *piece == ChessPieceKind::King)?;
+    let rook_file = usize::from(rook_file);
+    if rook_file == king_file {
+        return None;
+    }
+    let between = if rook_file > king_file {
+        king_file + 1..rook_file
+    } else {
+        rook_file + 1..king_file
+    };
+    for file in between {
+        if back_rank[file] == ChessPieceKind::Rook {
+            back_rank[file] = ChessPieceKind::Pawn;
         }
     }
-    Some(rights)
+    back_rank[rook_file] = ChessPieceKind::Rook;
+    Some(rook_file > king_file)
 }
 
 fn collect_back_rank(position: &ChessPosition, color: ChessColor) -> [ChessPieceKind; 8] {
@@ -67,7 +67,10 @@ fn legal_moves_atomic(position: &ChessPosition) -> Vec<ChessMove> {
         if atomic::find_king_square(&next, side).is_none() {
             continue;
         }
-        if !atomic::is_in_check(&next, side) {
+        // Blowing up the enemy king ends the game, so it answers any check.
+        if atomic::find_king_square(&next, side.opposite()).is_none()
+            || !atomic::is_in_check(&next, side)
+        {
             legal.push(chess_move);
         }
     }
@@ -11,19 +11,24 @@ mod movegen;
 mod moves;
 mod position;
 mod rules;
+mod san;
 mod setup;
 mod types;
 
 pub use apply::apply_move;
+pub use atomic::{atomic_blast_preview, AtomicBlastPreview};
 pub use fen::{decode_fen, encode_fen};
 pub use legal::{is_in_check, legal_moves, square_attacked_by, terminal_state, ChessTerminalState};
 pub use movegen::generate_pseudo_legal_moves;
 pub use moves::ChessMove;
 pub use position::{CastlingRights, ChessPosition};
 pub use rules::ChessRuleset;
+pub use san::move_to_san;
 pub use setup::{
-    atomic_position, chess960_back_rank_from_seed, chess960_position, is_valid_chess960_back_rank,
-    standard_position, STANDARD_BACK_RANK,
+    atomic_position, chess960_back_rank_from_scharnagl, chess960_back_rank_from_seed,
+    chess960_back_rank_label, chess960_position, chess960_position_from_scharnagl,
+    chess960_scharnagl_number, double_chess960_position, is_valid_chess960_back_rank,
+    standard_position, CHESS960_POSITION_COUNT, CHESS960_STANDARD_SCHARNAGL, STANDARD_BACK_RANK,
 };
 pub use types::{
     file_of, parse_square, rank_of, square, square_name, ChessColor, ChessPiece, ChessPieceKind,
@@ -9,7 +9,9 @@ pub enum ChessRuleset {
 impl ChessRuleset {
     pub const fn for_variant(variant: ChessVariant) -> Self {
         match variant {
-            ChessVariant::Standard | ChessVariant::Chess960 => Self::Classical,
+            ChessVariant::Standard | ChessVariant::Chess960 | ChessVariant::DoubleChess960 => {
+                Self::Classical
+            }
             ChessVariant::Atomic => Self::Atomic,
         }
     }
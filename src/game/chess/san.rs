@@ -0,0 +1,92 @@
+use super::apply::apply_move;
+use super::legal::{is_in_check, legal_moves, terminal_state, ChessTerminalState};
+use super::moves::ChessMove;
+use super::position::ChessPosition;
+use super::types::{file_of, rank_of, square_name, ChessColor, ChessPieceKind};
+
+/// Formats a legal move in Standard Algebraic Notation (`Nbd7`, `exd6`, `O-O`,
+/// `e8=Q+`, `Qh7#`). Returns `None` when the move is not legal in `position`.
+pub fn move_to_san(position: &ChessPosition, chess_move: ChessMove) -> Option<String> {
+    let legal = legal_moves(position);
+    if !legal.contains(&chess_move) {
+        return None;
+    }
+
+    let mut text = if chess_move.is_castle_kingside {
+        "O-O".to_string()
+    } else if chess_move.is_castle_queenside {
+        "O-O-O".to_string()
+    } else {
+        let piece = position.piece_at(chess_move.from)?;
+        let is_capture = chess_move.is_en_passant
+            || position
+                .piece_at(chess_move.to)
+                .is_some_and(|target| target.color != piece.color);
+        let mut text = String::new();
+        if piece.kind == ChessPieceKind::Pawn {
+            if is_capture {
+                text.push(file_char(file_of(chess_move.from)));
+            }
+        } else {
+            text.push(piece.kind.fen_char(ChessColor::White));
+            let rivals = legal
+                .iter()
+                .filter(|other| {
+                    other.to == chess_move.to
+                        && other.from != chess_move.from
+                        && !other.is_castle_kingside
+                        && !other.is_castle_queenside
+                        && position
+                            .piece_at(other.from)
+                            .is_some_and(|other_piece| other_piece.kind == piece.kind)
+                })
+                .map(|other| other.from)
+                .collect::<Vec<_>>();
+            if !rivals.is_empty() {
+                let shares_file = rivals
+                    .iter()
+                    .any(|from| file_of(*from) == file_of(chess_move.from));
+                let shares_rank = rivals
+                    .iter()
+                    .any(|from| rank_of(*from) == rank_of(chess_move.from));
+                if !shares_file {
+                    text.push(file_char(file_of(chess_move.from)));
+                } else if !shares_rank {
+                    text.push(rank_char(rank_of(chess_move.from)));
+                } else {
+                    text.push_str(&square_name(chess_move.from));
+                }
+            }
+        }
+        if is_capture {
+            text.push('x');
+        }
+        text.push_str(&square_name(chess_move.to));
+        if let Some(promotion) = chess_move.promotion {
+            text.push('=');
+            text.push(promotion.fen_char(ChessColor::White));
+        }
+        text
+    };
+
+    let mut next = position.clone();
+    if apply_move(&mut next, chess_move) {
+        if matches!(
+            terminal_state(&next),
+            Some(ChessTerminalState::Checkmate { .. })
+        ) {
+            text.push('#');
+        } else if is_in_check(&next, next.side_to_move()) {
+            text.push('+');
+        }
+    }
+    Some(text)
+}
+
+fn file_char(file: u8) -> char {
+    char::from(b'a' + file)
+}
+
+fn rank_char(rank: u8) -> char {
+    char::from(b'1' + rank)
+}
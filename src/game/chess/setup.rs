@@ -1,6 +1,4 @@
-use rand::rngs::StdRng;
-use rand::seq::SliceRandom;
-use rand::SeedableRng;
+use crate::game::DealRng;
 
 use super::position::{CastlingRights, ChessPosition};
 use super::types::{square, ChessColor, ChessPiece, ChessPieceKind, ChessVariant};
@@ -16,6 +14,29 @@ pub const STANDARD_BACK_RANK: [ChessPieceKind; 8] = [
     ChessPieceKind::Rook,
 ];
 
+/// Number of distinct Chess960 start positions (Scharnagl numbers 0..=959).
+pub const CHESS960_POSITION_COUNT: u16 = 960;
+/// Scharnagl number of the classical `RNBQKBNR` setup.
+pub const CHESS960_STANDARD_SCHARNAGL: u16 = 518;
+
+/// Mixed into the seed so Black's Double Fischer Random rank is independent of White's.
+const BLACK_BACK_RANK_SALT: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Knight placements over the five squares left after bishops and queen,
+/// indexed by the Scharnagl knight code (0..=9).
+const SCHARNAGL_KNIGHT_TABLE: [(usize, usize); 10] = [
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (0, 4),
+    (1, 2),
+    (1, 3),
+    (1, 4),
+    (2, 3),
+    (2, 4),
+    (3, 4),
+];
+
 pub fn standard_position() -> ChessPosition {
     position_from_back_rank(ChessVariant::Standard, STANDARD_BACK_RANK)
 }
@@ -25,33 +46,49 @@ pub fn chess960_position(seed: u64) -> ChessPosition {
     position_from_back_rank(ChessVariant::Chess960, back_rank)
 }
 
+pub fn chess960_position_from_scharnagl(number: u16) -> Option<ChessPosition> {
+    let back_rank = chess960_back_rank_from_scharnagl(number)?;
+    Some(position_from_back_rank(ChessVariant::Chess960, back_rank))
+}
+
+/// Double Fischer Random: each side gets its own independently seeded back rank.
+pub fn double_chess960_position(seed: u64) -> ChessPosition {
+    let white_back_rank = chess960_back_rank_from_seed(seed);
+    let black_back_rank = chess960_back_rank_from_seed(seed.rotate_left(32) ^ BLACK_BACK_RANK_SALT);
+    position_from_back_ranks(
+        ChessVariant::DoubleChess960,
+        white_back_rank,
+        black_back_rank,
+    )
+}
+
 pub fn atomic_position() -> ChessPosition {
     position_from_back_rank(ChessVariant::Atomic, STANDARD_BACK_RANK)
 }
 
 pub fn chess960_back_rank_from_seed(seed: u64) -> [ChessPieceKind; 8] {
-    let mut rng = StdRng::seed_from_u64(seed);
+    let mut rng = DealRng::from_seed(seed);
     let mut rank = [ChessPieceKind::Pawn; 8];
     let mut remaining: Vec<usize> = (0..8).collect();
 
     let light_square_files = [0_usize, 2, 4, 6];
     let dark_square_files = [1_usize, 3, 5, 7];
 
-    let bishop_light = *light_square_files
-        .choose(&mut rng)
+    let bishop_light = *rng
+        .choose(&light_square_files)
         .expect("light-square bishop index");
-    let bishop_dark = *dark_square_files
-        .choose(&mut rng)
+    let bishop_dark = *rng
+        .choose(&dark_square_files)
         .expect("dark-square bishop index");
     rank[bishop_light] = ChessPieceKind::Bishop;
     rank[bishop_dark] = ChessPieceKind::Bishop;
     remaining.retain(|&idx| idx != bishop_light && idx != bishop_dark);
 
-    let queen_file = *remaining.choose(&mut rng).expect("queen index");
+    let queen_file = *rng.choose(&remaining).expect("queen index");
     rank[queen_file] = ChessPieceKind::Queen;
     remaining.retain(|&idx| idx != queen_file);
 
-    remaining.shuffle(&mut rng);
+    rng.shuffle(&mut remaining);
     let knight_a = remaining.pop().expect("first knight index");
     let knight_b = remaining.pop().expect("second knight index");
     rank[knight_a] = ChessPieceKind::Knight;
@@ -69,6 +106,91 @@ pub fn chess960_back_rank_from_seed(seed: u64) -> [ChessPieceKind; 8] {
     rank
 }
 
+/// Builds the back rank for a Scharnagl start-position number (0..=959).
+pub fn chess960_back_rank_from_scharnagl(number: u16) -> Option<[ChessPieceKind; 8]> {
+    if number >= CHESS960_POSITION_COUNT {
+        return None;
+    }
+    let mut n = usize::from(number);
+    let mut rank = [ChessPieceKind::Pawn; 8];
+
+    // Light-square bishop on b/d/f/h, then dark-square bishop on a/c/e/g.
+    rank[(n % 4) * 2 + 1] = ChessPieceKind::Bishop;
+    n /= 4;
+    rank[(n % 4) * 2] = ChessPieceKind::Bishop;
+    n /= 4;
+
+    let queen_slot = n % 6;
+    n /= 6;
+    let queen_file = empty_files(&rank).nth(queen_slot)?;
+    rank[queen_file] = ChessPieceKind::Queen;
+
+    let (knight_a, knight_b) = SCHARNAGL_KNIGHT_TABLE[n];
+    let free = empty_files(&rank).collect::<Vec<_>>();
+    rank[free[knight_a]] = ChessPieceKind::Knight;
+    rank[free[knight_b]] = ChessPieceKind::Knight;
+
+    let free = empty_files(&rank).collect::<Vec<_>>();
+    rank[free[0]] = ChessPieceKind::Rook;
+    rank[free[1]] = ChessPieceKind::King;
+    rank[free[2]] = ChessPieceKind::Rook;
+
+    debug_assert!(is_valid_chess960_back_rank(&rank));
+    Some(rank)
+}
+
+/// Returns the Scharnagl number of a valid Chess960 back rank.
+pub fn chess960_scharnagl_number(back_rank: &[ChessPieceKind; 8]) -> Option<u16> {
+    if !is_valid_chess960_back_rank(back_rank) {
+        return None;
+    }
+    let files_of = |kind: ChessPieceKind| {
+        back_rank
+            .iter()
+            .enumerate()
+            .filter(move |(_, piece)| **piece == kind)
+            .map(|(file, _)| file)
+    };
+
+    let light_bishop = files_of(ChessPieceKind::Bishop).find(|file| file % 2 == 1)?;
+    let dark_bishop = files_of(ChessPieceKind::Bishop).find(|file| file % 2 == 0)?;
+    let queen = files_of(ChessPieceKind::Queen).next()?;
+
+    let without_bishops = (0..8)
+        .filter(|file| back_rank[*file] != ChessPieceKind::Bishop)
+        .collect::<Vec<_>>();
+    let queen_slot = without_bishops.iter().position(|file| *file == queen)?;
+
+    let remaining = without_bishops
+        .into_iter()
+        .filter(|file| *file != queen)
+        .collect::<Vec<_>>();
+    let knights = remaining
+        .iter()
+        .enumerate()
+        .filter(|(_, file)| back_rank[**file] == ChessPieceKind::Knight)
+        .map(|(slot, _)| slot)
+        .collect::<Vec<_>>();
+    let knight_code = SCHARNAGL_KNIGHT_TABLE
+        .iter()
+        .position(|pair| *pair == (knights[0], knights[1]))?;
+
+    let number = ((knight_code * 6 + queen_slot) * 4 + dark_bishop / 2) * 4 + light_bishop / 2;
+    u16::try_from(number).ok()
+}
+
+/// Compact back-rank text such as `RNBQKBNR`.
+pub fn chess960_back_rank_label(back_rank: &[ChessPieceKind; 8]) -> String {
+    back_rank
+        .iter()
+        .map(|kind| kind.fen_char(ChessColor::White))
+        .collect()
+}
+
+fn empty_files(rank: &[ChessPieceKind; 8]) -> impl Iterator<Item = usize> + '_ {
+    (0..8).filter(|file| rank[*file] == ChessPieceKind::Pawn)
+}
+
 pub fn is_valid_chess960_back_rank(back_rank: &[ChessPieceKind; 8]) -> bool {
     let mut king_idx = None;
     let mut rook_indices = Vec::new();
@@ -116,6 +238,14 @@ pub fn is_valid_chess960_back_rank(back_rank: &[ChessPieceKind; 8]) -> bool {
 }
 
 fn position_from_back_rank(variant: ChessVariant, back_rank: [ChessPieceKind; 8]) -> ChessPosition {
+    position_from_back_ranks(variant, back_rank, back_rank)
+}
+
+fn position_from_back_ranks(
+    variant: ChessVariant,
+    white_back_rank: [ChessPieceKind; 8],
+    black_back_rank: [ChessPieceKind; 8],
+) -> ChessPosition {
     let mut position = ChessPosition::empty(variant);
     position.clear_board();
     position.set_side_to_move(ChessColor::White);
@@ -123,16 +253,16 @@ fn position_from_back_rank(variant: ChessVariant, back_rank: [ChessPieceKind; 8]
     position.set_en_passant(None);
     position.set_halfmove_clock(0);
     position.set_fullmove_number(1);
-    position.set_back_ranks(back_rank, back_rank);
+    position.set_back_ranks(white_back_rank, black_back_rank);
 
     for file in 0..8_u8 {
         let white_piece = ChessPiece {
             color: ChessColor::White,
-            kind: back_rank[file as usize],
+            kind: white_back_rank[file as usize],
         };
         let black_piece = ChessPiece {
             color: ChessColor::Black,
-            kind: back_rank[file as usize],
+            kind: black_back_rank[file as usize],
         };
         let white_back = square(file, 0).expect("valid white back-rank square");
         let white_pawn = square(file, 1).expect("valid white pawn square");
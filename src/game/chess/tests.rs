@@ -1,7 +1,9 @@
 use super::{
-    apply_move, atomic_position, chess960_back_rank_from_seed, chess960_position, decode_fen,
-    encode_fen, file_of, generate_pseudo_legal_moves, is_in_check, is_valid_chess960_back_rank,
-    legal_moves, parse_square, rank_of, standard_position, terminal_state, ChessColor, ChessMove,
+    apply_move, atomic_blast_preview, atomic_position, chess960_back_rank_from_scharnagl,
+    chess960_back_rank_from_seed, chess960_back_rank_label, chess960_position,
+    chess960_scharnagl_number, decode_fen, double_chess960_position, encode_fen, file_of,
+    generate_pseudo_legal_moves, is_in_check, is_valid_chess960_back_rank, legal_moves,
+    move_to_san, parse_square, rank_of, standard_position, terminal_state, ChessColor, ChessMove,
     ChessPieceKind, ChessTerminalState, ChessVariant,
 };
 
@@ -37,6 +39,24 @@ fn atomic_capture_explodes_adjacent_non_pawns_but_keeps_adjacent_pawns() {
     assert!(position.piece_at(sq("c4")).is_some());
 }
 
+#[test]
+fn atomic_blast_preview_lists_casualties_but_spares_pawns() {
+    let position =
+        decode_fen("k7/8/8/3n4/2PRB3/8/8/7K w - - 0 1", ChessVariant::Atomic).expect("valid FEN");
+    let preview = atomic_blast_preview(&position, ChessMove::new(sq("d4"), sq("d5")))
+        .expect("capture has a blast");
+    assert_eq!(preview.center, sq("d5"));
+    assert_eq!(preview.zone.len(), 9);
+    assert!(preview.casualties.contains(&sq("d5")));
+    assert!(preview.casualties.contains(&sq("e4")));
+    assert!(!preview.casualties.contains(&sq("c4")));
+
+    assert!(atomic_blast_preview(&position, ChessMove::new(sq("d4"), sq("d3"))).is_none());
+    let classical =
+        decode_fen("k7/8/8/3n4/2PRB3/8/8/7K w - - 0 1", ChessVariant::Standard).expect("valid FEN");
+    assert!(atomic_blast_preview(&classical, ChessMove::new(sq("d4"), sq("d5"))).is_none());
+}
+
 #[test]
 fn atomic_king_capture_moves_are_not_legal() {
     let position =
@@ -67,7 +87,7 @@ fn atomic_terminal_state_reports_king_explosion_as_checkmate() {
 #[test]
 fn atomic_castling_is_blocked_while_in_atomic_check() {
     let position =
-        decode_fen("k2r4/8/8/8/8/8/3P4/4K2R w K - 0 1", ChessVariant::Atomic).expect("valid FEN");
+        decode_fen("k3r3/8/8/8/8/8/3P4/4K2R w K - 0 1", ChessVariant::Atomic).expect("valid FEN");
     assert!(is_in_check(&position, ChessColor::White));
     let legal = legal_moves(&position);
     assert!(!legal.iter().any(|mv| {
@@ -75,6 +95,123 @@ fn atomic_castling_is_blocked_while_in_atomic_check() {
     }));
 }
 
+#[test]
+fn atomic_kings_may_touch_and_touching_kings_are_never_in_check() {
+    let position =
+        decode_fen("8/8/8/8/8/4k3/r3K3/8 w - - 0 1", ChessVariant::Atomic).expect("valid FEN");
+    // The a2 rook eyes e2, but any blast there would also take the e3 king.
+    assert!(!is_in_check(&position, ChessColor::White));
+    let legal = legal_moves(&position);
+    assert!(legal
+        .iter()
+        .any(|mv| mv.from == sq("e2") && mv.to == sq("d2")));
+    assert!(!legal.iter().any(|mv| mv.to == sq("e3")));
+}
+
+#[test]
+fn atomic_exploding_enemy_king_answers_check() {
+    let mut position =
+        decode_fen("3nk3/8/8/4r3/8/8/8/3RK3 w - - 0 1", ChessVariant::Atomic).expect("valid FEN");
+    assert!(is_in_check(&position, ChessColor::White));
+    let blast = legal_moves(&position)
+        .into_iter()
+        .find(|mv| mv.from == sq("d1") && mv.to == sq("d8"))
+        .expect("capture next to the enemy king is legal while in check");
+    assert!(apply_move(&mut position, blast));
+    assert_eq!(
+        terminal_state(&position),
+        Some(ChessTerminalState::Checkmate {
+            winner: ChessColor::White
+        })
+    );
+}
+
+#[test]
+fn atomic_blast_threat_next_to_the_king_is_not_check() {
+    let position =
+        decode_fen("k2r4/8/8/8/8/8/3P4/4K2R w K - 0 1", ChessVariant::Atomic).expect("valid FEN");
+    // Rxd2 would blow up e1, but only a direct attack on the king is check.
+    assert!(!is_in_check(&position, ChessColor::White));
+    assert!(legal_moves(&position)
+        .iter()
+        .any(|mv| mv.is_castle_kingside));
+}
+
+#[test]
+fn atomic_king_may_castle_through_a_square_touching_the_enemy_king() {
+    let position =
+        decode_fen("6r1/8/8/8/8/8/7k/4K2R w K - 0 1", ChessVariant::Atomic).expect("valid FEN");
+    // g8 covers g1, but a king on g1 would touch h2 and cannot be checked there.
+    assert!(legal_moves(&position)
+        .iter()
+        .any(|mv| mv.is_castle_kingside));
+}
+
+#[test]
+fn atomic_en_passant_blast_is_centred_on_the_landing_square() {
+    let mut position = decode_fen("k7/2n5/8/3pP3/2N5/8/8/4K3 w - d6 0 1", ChessVariant::Atomic)
+        .expect("valid FEN");
+    let mut ep = ChessMove::new(sq("e5"), sq("d6"));
+    ep.is_en_passant = true;
+    assert_eq!(
+        atomic_blast_preview(&position, ep).map(|preview| preview.center),
+        Some(sq("d6"))
+    );
+    assert!(apply_move(&mut position, ep));
+    assert!(position.piece_at(sq("d5")).is_none());
+    assert!(position.piece_at(sq("d6")).is_none());
+    // c7 touches d6 and goes; c4 only touches the captured pawn's square and stays.
+    assert!(position.piece_at(sq("c7")).is_none());
+    assert!(position.piece_at(sq("c4")).is_some());
+}
+
+// Reference counts from the atomic.perft suite shared by shakmaty and python-chess.
+#[test]
+fn atomic_perft_from_start_matches_reference() {
+    let position = atomic_position();
+    assert_eq!(perft(&position, 1), 20);
+    assert_eq!(perft(&position, 2), 400);
+    assert_eq!(perft(&position, 3), 8_902);
+}
+
+#[test]
+fn atomic_perft_depth_4_matches_reference() {
+    assert_eq!(perft(&atomic_position(), 4), 197_326);
+}
+
+#[test]
+fn atomic_perft_programfox_positions_match_reference() {
+    let position = decode_fen(
+        "rn2kb1r/1pp1p2p/p2q1pp1/3P4/2P3b1/4PN2/PP3PPP/R2QKB1R b KQkq - 0 1",
+        ChessVariant::Atomic,
+    )
+    .expect("valid FEN");
+    assert_eq!(perft(&position, 1), 40);
+    assert_eq!(perft(&position, 2), 1_238);
+    assert_eq!(perft(&position, 3), 45_237);
+
+    let position = decode_fen(
+        "rn1qkb1r/p5pp/2p5/3p4/N3P3/5P2/PPP4P/R1BQK3 w Qkq - 0 1",
+        ChessVariant::Atomic,
+    )
+    .expect("valid FEN");
+    assert_eq!(perft(&position, 1), 28);
+    assert_eq!(perft(&position, 2), 833);
+    assert_eq!(perft(&position, 3), 23_353);
+}
+
+// Regression snapshot: produced by this implementation, not a published suite.
+#[test]
+fn atomic_perft_bare_kings_and_rooks_matches_snapshot() {
+    // Rb8 checks along the back rank: Kf8 escapes by touching e7, Kf7/Kg7/Kh7 leave it.
+    let position =
+        decode_fen("1R4kr/4K3/8/8/8/8/8/8 b k - 0 1", ChessVariant::Atomic).expect("valid FEN");
+    assert_eq!(perft(&position, 1), 4);
+    assert_eq!(perft(&position, 2), 77);
+    assert_eq!(perft(&position, 3), 1_021);
+    assert_eq!(perft(&position, 4), 17_915);
+}
+
 #[test]
 fn chess960_back_rank_generation_respects_fischer_rules() {
     for seed in 0_u64..512 {
@@ -94,6 +231,32 @@ fn chess960_position_uses_generated_back_rank() {
     assert_eq!(position.piece_count(ChessColor::Black), 16);
 }
 
+#[test]
+fn scharnagl_numbers_match_published_reference_positions() {
+    let label = |number| {
+        chess960_back_rank_label(&chess960_back_rank_from_scharnagl(number).expect("valid SP"))
+    };
+    assert_eq!(label(0), "BBQNNRKR");
+    assert_eq!(label(518), "RNBQKBNR");
+    assert_eq!(label(959), "RKRNNQBB");
+    assert!(chess960_back_rank_from_scharnagl(960).is_none());
+}
+
+#[test]
+fn scharnagl_numbering_roundtrips_all_960_positions() {
+    let mut seen = std::collections::HashSet::new();
+    for number in 0_u16..960 {
+        let rank = chess960_back_rank_from_scharnagl(number).expect("valid SP");
+        assert!(is_valid_chess960_back_rank(&rank));
+        assert_eq!(chess960_scharnagl_number(&rank), Some(number));
+        assert!(seen.insert(rank));
+    }
+    for seed in 0_u64..64 {
+        let rank = chess960_back_rank_from_seed(seed);
+        assert!(chess960_scharnagl_number(&rank).is_some());
+    }
+}
+
 #[test]
 fn fen_roundtrip_for_standard_start_is_stable() {
     let position = standard_position();
@@ -102,6 +265,14 @@ fn fen_roundtrip_for_standard_start_is_stable() {
     assert_eq!(decoded, position);
 }
 
+#[test]
+fn chess960_back_rank_from_seed_keeps_its_golden_ranks() {
+    assert_eq!(
+        chess960_back_rank_label(&chess960_back_rank_from_seed(1)),
+        "RKNNQRBB"
+    );
+}
+
 #[test]
 fn chess960_back_rank_has_expected_piece_inventory() {
     let rank = chess960_back_rank_from_seed(42);
@@ -282,6 +453,99 @@ fn chess960_queenside_castle_allows_king_to_stay_put() {
     assert!(position.piece_at(sq("a1")).is_none());
 }
 
+#[test]
+fn double_chess960_draws_independent_valid_back_ranks() {
+    let mut saw_asymmetric = false;
+    for seed in 0_u64..64 {
+        let position = double_chess960_position(seed);
+        assert_eq!(position.variant(), ChessVariant::DoubleChess960);
+        let white = *position.back_rank(ChessColor::White);
+        let black = *position.back_rank(ChessColor::Black);
+        assert!(is_valid_chess960_back_rank(&white));
+        assert!(is_valid_chess960_back_rank(&black));
+        saw_asymmetric |= white != black;
+    }
+    assert!(saw_asymmetric);
+}
+
+#[test]
+fn fischer_random_fen_uses_shredder_castling_files() {
+    let position = decode_fen(
+        "1rk3r1/8/8/8/8/8/8/R1K4R w KQkq - 0 1",
+        ChessVariant::DoubleChess960,
+    )
+    .expect("valid FEN");
+    let fen = encode_fen(&position);
+    assert!(fen.contains(" w HAgb - "), "{fen}");
+
+    let decoded = decode_fen(&fen, ChessVariant::DoubleChess960).expect("valid Shredder-FEN");
+    assert_eq!(encode_fen(&decoded), fen);
+    assert_eq!(
+        decode_fen(
+            "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1",
+            ChessVariant::Standard
+        )
+        .map(|position| encode_fen(&position)),
+        Some("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1".to_string())
+    );
+}
+
+#[test]
+fn shredder_castling_file_keeps_outer_rook_as_castling_rook() {
+    let position = decode_fen(
+        "4k3/8/8/8/8/8/8/1RK3RR w H - 0 1",
+        ChessVariant::DoubleChess960,
+    )
+    .expect("valid FEN");
+    assert!(position.castling_rights().white_king_side);
+    assert!(!position.castling_rights().white_queen_side);
+    // The g1 rook stands on the king's destination, so castling with h1 is blocked.
+    assert!(!legal_moves(&position)
+        .iter()
+        .any(|mv| mv.is_castle_kingside));
+    assert_eq!(encode_fen(&position), "4k3/8/8/8/8/8/8/1RK3RR w H - 0 1");
+}
+
+#[test]
+fn san_covers_disambiguation_promotion_castling_and_mate() {
+    let position = decode_fen(
+        "r3k2r/1P6/8/8/8/8/8/RN1NK2R w KQkq - 0 1",
+        ChessVariant::Standard,
+    )
+    .expect("valid FEN");
+    assert_eq!(
+        move_to_san(&position, ChessMove::new(sq("b1"), sq("c3"))).as_deref(),
+        Some("Nbc3")
+    );
+    assert_eq!(
+        move_to_san(
+            &position,
+            ChessMove::new(sq("e1"), sq("g1")).as_kingside_castle()
+        )
+        .as_deref(),
+        Some("O-O")
+    );
+    assert_eq!(
+        move_to_san(
+            &position,
+            ChessMove::new(sq("b7"), sq("a8")).with_promotion(ChessPieceKind::Queen)
+        )
+        .as_deref(),
+        Some("bxa8=Q+")
+    );
+    assert_eq!(
+        move_to_san(&position, ChessMove::new(sq("b1"), sq("b5"))),
+        None
+    );
+
+    let mate = decode_fen("6k1/5ppp/8/8/8/8/8/4R1K1 w - - 0 1", ChessVariant::Standard)
+        .expect("valid FEN");
+    assert_eq!(
+        move_to_san(&mate, ChessMove::new(sq("e1"), sq("e8"))).as_deref(),
+        Some("Re8#")
+    );
+}
+
 fn sq(name: &str) -> u8 {
     parse_square(name).expect("valid square")
 }
@@ -2,14 +2,21 @@
 pub enum ChessVariant {
     Standard,
     Chess960,
+    DoubleChess960,
     Atomic,
 }
 
 impl ChessVariant {
+    /// Variants whose back ranks are shuffled, so castling rooks need explicit files.
+    pub fn is_fischer_random(self) -> bool {
+        matches!(self, Self::Chess960 | Self::DoubleChess960)
+    }
+
     pub fn id(self) -> &'static str {
         match self {
             Self::Standard => "chess-standard",
             Self::Chess960 => "chess-960",
+            Self::DoubleChess960 => "chess-960-double",
             Self::Atomic => "chess-atomic",
         }
     }
@@ -18,6 +25,7 @@ impl ChessVariant {
         match self {
             Self::Standard => "Standard Chess",
             Self::Chess960 => "Chess960",
+            Self::DoubleChess960 => "Double Fischer Random",
             Self::Atomic => "Atomic Chess",
         }
     }
@@ -0,0 +1,129 @@
+//! Seeded shuffling behind every deal.
+//!
+//! Deal algorithm 1 reproduces what `rand` 0.8's `StdRng::seed_from_u64` and
+//! `SliceRandom::shuffle` produced, so seeds shared before the shuffle moved
+//! in tree still deal the same cards:
+//!
+//! - PCG32 (multiplier 6364136223846793005, increment 11634580027462260723)
+//!   stepped from the seed yields the eight words of a ChaCha key;
+//! - ChaCha with 12 rounds, a 64-bit block counter from zero and a zero nonce
+//!   yields a stream of `u32` words;
+//! - an index below `n` is the high half of the next word times `n`, drawing
+//!   again while the low half exceeds `(n << n.leading_zeros()) - 1`;
+//! - Fisher–Yates walks `i` down from the top, swapping slot `i` with an index
+//!   below `i + 1`.
+//!
+//! Any change to this sequence changes deals. Add a new algorithm number
+//! instead of editing this one.
+
+/// The algorithm new games deal with; written into saved sessions.
+pub const DEAL_ALGORITHM: u8 = 1;
+
+const PCG_MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+const PCG_INCREMENT: u64 = 11_634_580_027_462_260_723;
+const CHACHA_CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+const CHACHA_DOUBLE_ROUNDS: usize = 6;
+
+pub fn is_supported_deal_algorithm(algorithm: u8) -> bool {
+    algorithm == DEAL_ALGORITHM
+}
+
+/// Deal algorithm 1's random stream.
+#[derive(Debug, Clone)]
+pub struct DealRng {
+    key: [u32; 8],
+    counter: u64,
+    block: [u32; 16],
+    index: usize,
+}
+
+impl DealRng {
+    pub fn from_seed(seed: u64) -> Self {
+        let mut state = seed;
+        let key = std::array::from_fn(|_| {
+            state = state
+                .wrapping_mul(PCG_MULTIPLIER)
+                .wrapping_add(PCG_INCREMENT);
+            let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+            xorshifted.rotate_right((state >> 59) as u32)
+        });
+        Self {
+            key,
+            counter: 0,
+            block: [0; 16],
+            index: 16,
+        }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        if self.index == self.block.len() {
+            self.refill();
+        }
+        let word = self.block[self.index];
+        self.index += 1;
+        word
+    }
+
+    /// Uniform in `0..bound`; `bound` must be non-zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        let range = u32::try_from(bound).expect("deal ranges fit in u32");
+        assert!(range > 0, "empty deal range");
+        let zone = (range << range.leading_zeros()).wrapping_sub(1);
+        loop {
+            let wide = u64::from(self.next_u32()) * u64::from(range);
+            if wide as u32 <= zone {
+                return (wide >> 32) as usize;
+            }
+        }
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for top in (1..items.len()).rev() {
+            let pick = self.below(top + 1);
+            items.swap(top, pick);
+        }
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        items.get(self.below(items.len()))
+    }
+
+    fn refill(&mut self) {
+        let mut input = [0_u32; 16];
+        input[..4].copy_from_slice(&CHACHA_CONSTANTS);
+        input[4..12].copy_from_slice(&self.key);
+        input[12] = self.counter as u32;
+        input[13] = (self.counter >> 32) as u32;
+
+        let mut x = input;
+        for _ in 0..CHACHA_DOUBLE_ROUNDS {
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+        for (out, (mixed, original)) in self.block.iter_mut().zip(x.iter().zip(input)) {
+            *out = mixed.wrapping_add(original);
+        }
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+    }
+}
+
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+
+use super::spider::{decode_spider_pile, encode_spider_pile, spider_deck};
+use super::{Card, DealRng, DrawResult, SolverMove, SpiderSuitMode};
+
+pub const FORTY_THIEVES_COLUMNS: usize = 10;
+pub const FORTY_THIEVES_FOUNDATIONS: usize = 8;
+const FORTY_THIEVES_DEAL_DEPTH: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FortyThievesGame {
+    stock: Vec<Card>,
+    waste: Vec<Card>,
+    /// Two piles per suit: suit `s` owns piles `2 * s` and `2 * s + 1`.
+    foundations: [Vec<Card>; FORTY_THIEVES_FOUNDATIONS],
+    tableau: [Vec<Card>; FORTY_THIEVES_COLUMNS],
+}
+
+impl FortyThievesGame {
+    pub fn new_with_seed(seed: u64) -> Self {
+        // Two full decks: the four-suit Spider deck is exactly that.
+        let mut deck = spider_deck(SpiderSuitMode::Four);
+        DealRng::from_seed(seed).shuffle(&mut deck);
+
+        let mut game = Self {
+            stock: Vec::new(),
+            waste: Vec::new(),
+            foundations: std::array::from_fn(|_| Vec::new()),
+            tableau: std::array::from_fn(|_| Vec::new()),
+        };
+
+        let mut draw = deck.into_iter();
+        for _ in 0..FORTY_THIEVES_DEAL_DEPTH {
+            for pile in &mut game.tableau {
+                let mut card = draw.next().expect("forty thieves setup consumes 40 cards");
+                card.face_up = true;
+                pile.push(card);
+            }
+        }
+        game.stock = draw.collect();
+        game
+    }
+
+    pub(super) fn from_board(
+        stock: Vec<Card>,
+        waste: Vec<Card>,
+        foundations: [Vec<Card>; FORTY_THIEVES_FOUNDATIONS],
+        tableau: [Vec<Card>; FORTY_THIEVES_COLUMNS],
+    ) -> Self {
+        Self {
+            stock,
+            waste,
+            foundations,
+            tableau,
+        }
+    }
+
+    pub fn stock(&self) -> &[Card] {
+        &self.stock
+    }
+
+    pub fn stock_len(&self) -> usize {
+        self.stock.len()
+    }
+
+    pub fn waste(&self) -> &[Card] {
+        &self.waste
+    }
+
+    pub fn waste_top(&self) -> Option<Card> {
+        self.waste.last().copied()
+    }
+
+    pub fn foundations(&self) -> &[Vec<Card>; FORTY_THIEVES_FOUNDATIONS] {
+        &self.foundations
+    }
+
+    pub fn tableau(&self) -> &[Vec<Card>; FORTY_THIEVES_COLUMNS] {
+        &self.tableau
+    }
+
+    pub fn tableau_card(&self, col: usize, index: usize) -> Option<Card> {
+        self.tableau
+            .get(col)
+            .and_then(|pile| pile.get(index))
+            .copied()
+    }
+
+    pub fn tableau_top(&self, col: usize) -> Option<Card> {
+        self.tableau.get(col).and_then(|pile| pile.last().copied())
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.len() == 13)
+    }
+
+    pub fn has_legal_moves(&self) -> bool {
+        !self.is_won() && !self.legal_moves().is_empty()
+    }
+
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && !self.has_legal_moves()
+    }
+
+    pub fn empty_columns(&self) -> usize {
+        self.tableau.iter().filter(|pile| pile.is_empty()).count()
+    }
+
+    /// Cards are moved one at a time, so a run can only travel as far as the
+    /// empty columns let it be relayed: doubling per empty column, not
+    /// counting an empty destination.
+    pub fn max_movable_run(&self, dst: usize) -> usize {
+        let dst_empty = self.tableau.get(dst).is_some_and(Vec::is_empty);
+        let relays = self.empty_columns() - usize::from(dst_empty);
+        1_usize << relays.min(FORTY_THIEVES_COLUMNS)
+    }
+
+    /// Foundation pile `card` would land on, if any.
+    pub fn foundation_target(&self, card: Card) -> Option<usize> {
+        let first = card.suit.foundation_index() * 2;
+        (first..first + 2).find(|pile| self.foundation_accepts(*pile, card))
+    }
+
+    pub fn foundation_accepts(&self, pile: usize, card: Card) -> bool {
+        if pile / 2 != card.suit.foundation_index() {
+            return false;
+        }
+        let Some(pile) = self.foundations.get(pile) else {
+            return false;
+        };
+        match pile.last() {
+            None => card.rank == 1,
+            Some(top) => top.suit == card.suit && card.rank == top.rank + 1,
+        }
+    }
+
+    pub fn can_draw(&self) -> bool {
+        !self.stock.is_empty()
+    }
+
+    /// Turns one stock card onto the waste. Forty Thieves allows a single
+    /// pass, so an empty stock is final.
+    pub fn draw(&mut self) -> DrawResult {
+        let Some(mut card) = self.stock.pop() else {
+            return DrawResult::NoOp;
+        };
+        card.face_up = true;
+        self.waste.push(card);
+        DrawResult::DrewFromStock
+    }
+
+    pub fn can_move_waste_to_foundation(&self) -> bool {
+        self.waste_top()
+            .is_some_and(|card| self.foundation_target(card).is_some())
+    }
+
+    pub fn move_waste_to_foundation(&mut self) -> bool {
+        let Some(pile) = self
+            .waste_top()
+            .and_then(|card| self.foundation_target(card))
+        else {
+            return false;
+        };
+        let Some(card) = self.waste.pop() else {
+            return false;
+        };
+        self.foundations[pile].push(card);
+        true
+    }
+
+    pub fn can_move_waste_to_tableau(&self, dst: usize) -> bool {
+        self.waste_top()
+            .is_some_and(|card| self.column_accepts(dst, card))
+    }
+
+    pub fn move_waste_to_tableau(&mut self, dst: usize) -> bool {
+        if !self.can_move_waste_to_tableau(dst) {
+            return false;
+        }
+        let Some(card) = self.waste.pop() else {
+            return false;
+        };
+        self.tableau[dst].push(card);
+        true
+    }
+
+    pub fn can_move_tableau_top_to_foundation(&self, src: usize) -> bool {
+        self.tableau_top(src)
+            .is_some_and(|card| self.foundation_target(card).is_some())
+    }
+
+    pub fn move_tableau_top_to_foundation(&mut self, src: usize) -> bool {
+        let Some(pile) = self
+            .tableau_top(src)
+            .and_then(|card| self.foundation_target(card))
+        else {
+            return false;
+        };
+        let Some(card) = self.tableau[src].pop() else {
+            return false;
+        };
+        self.foundations[pile].push(card);
+        true
+    }
+
+    /// Whether `src[start..]` is a same-suit descending run that fits `dst`
+    /// and the current supermove capacity.
+    pub fn can_move_run(&self, src: usize, start: usize, dst: usize) -> bool {
+        if src == dst || src >= self.tableau.len() || dst >= self.tableau.len() {
+            return false;
+        }
+        let pile = &self.tableau[src];
+        if start >= pile.len() || !is_suited_run(&pile[start..]) {
+            return false;
+        }
+        pile.len() - start <= self.max_movable_run(dst) && self.column_accepts(dst, pile[start])
+    }
+
+    pub fn move_run(&mut self, src: usize, start: usize, dst: usize) -> bool {
+        if !self.can_move_run(src, start, dst) {
+            return false;
+        }
+        let moved = self.tableau[src].split_off(start);
+        self.tableau[dst].extend(moved);
+        true
+    }
+
+    /// Every legal move, foundation plays first and the stock last.
+    pub fn legal_moves(&self) -> Vec<SolverMove> {
+        let mut moves = Vec::new();
+        if self.can_move_waste_to_foundation() {
+            moves.push(SolverMove::WasteToFoundation);
+        }
+        for src in 0..self.tableau.len() {
+            if self.can_move_tableau_top_to_foundation(src) {
+                moves.push(SolverMove::TableauTopToFoundation { src });
+            }
+        }
+        for dst in 0..self.tableau.len() {
+            if self.can_move_waste_to_tableau(dst) {
+                moves.push(SolverMove::WasteToTableau { dst });
+            }
+        }
+        for src in 0..self.tableau.len() {
+            for start in 0..self.tableau[src].len() {
+                for dst in 0..self.tableau.len() {
+                    if self.can_move_run(src, start, dst) {
+                        moves.push(SolverMove::TableauRunToTableau { src, start, dst });
+                    }
+                }
+            }
+        }
+        if self.can_draw() {
+            moves.push(SolverMove::Draw);
+        }
+        moves
+    }
+
+    pub fn apply_move(&mut self, solver_move: SolverMove) -> bool {
+        match solver_move {
+            SolverMove::Draw => self.draw() == DrawResult::DrewFromStock,
+            SolverMove::WasteToFoundation => self.move_waste_to_foundation(),
+            SolverMove::WasteToTableau { dst } => self.move_waste_to_tableau(dst),
+            SolverMove::TableauTopToFoundation { src } => self.move_tableau_top_to_foundation(src),
+            SolverMove::TableauRunToTableau { src, start, dst } => self.move_run(src, start, dst),
+        }
+    }
+
+    pub fn encode_for_session(&self) -> String {
+        let mut parts = vec![
+            format!("s={}", encode_spider_pile(&self.stock)),
+            format!("w={}", encode_spider_pile(&self.waste)),
+        ];
+        for (idx, pile) in self.foundations.iter().enumerate() {
+            parts.push(format!("f{idx}={}", encode_spider_pile(pile)));
+        }
+        for (idx, pile) in self.tableau.iter().enumerate() {
+            parts.push(format!("t{idx}={}", encode_spider_pile(pile)));
+        }
+        parts.join(";")
+    }
+
+    pub fn decode_from_session(data: &str) -> Option<Self> {
+        let mut fields = HashMap::<&str, &str>::new();
+        for part in data.split(';') {
+            let (key, value) = part.split_once('=')?;
+            fields.insert(key, value);
+        }
+
+        let pile = |key: &str| decode_spider_pile(fields.get(key)?);
+        let stock = pile("s")?;
+        let waste = pile("w")?;
+        let mut foundations: [Vec<Card>; FORTY_THIEVES_FOUNDATIONS] =
+            std::array::from_fn(|_| Vec::new());
+        for (idx, slot) in foundations.iter_mut().enumerate() {
+            *slot = pile(&format!("f{idx}"))?;
+        }
+        let mut tableau: [Vec<Card>; FORTY_THIEVES_COLUMNS] = std::array::from_fn(|_| Vec::new());
+        for (idx, slot) in tableau.iter_mut().enumerate() {
+            *slot = pile(&format!("t{idx}"))?;
+        }
+
+        let foundations_count: usize = foundations.iter().map(Vec::len).sum();
+        let tableau_count: usize = tableau.iter().map(Vec::len).sum();
+        if stock.len() + waste.len() + foundations_count + tableau_count != 104 {
+            return None;
+        }
+
+        Some(Self {
+            stock,
+            waste,
+            foundations,
+            tableau,
+        })
+    }
+
+    fn column_accepts(&self, dst: usize, card: Card) -> bool {
+        match self.tableau.get(dst) {
+            None => false,
+            Some(pile) => match pile.last() {
+                None => true,
+                Some(top) => top.suit == card.suit && top.rank == card.rank + 1,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+impl FortyThievesGame {
+    pub(crate) fn debug_new(
+        stock: Vec<Card>,
+        waste: Vec<Card>,
+        foundations: [Vec<Card>; FORTY_THIEVES_FOUNDATIONS],
+        tableau: [Vec<Card>; FORTY_THIEVES_COLUMNS],
+    ) -> Self {
+        Self {
+            stock,
+            waste,
+            foundations,
+            tableau,
+        }
+    }
+}
+
+fn is_suited_run(cards: &[Card]) -> bool {
+    cards
+        .windows(2)
+        .all(|pair| pair[0].suit == pair[1].suit && pair[0].rank == pair[1].rank + 1)
+}
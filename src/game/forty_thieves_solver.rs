@@ -0,0 +1,180 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+use super::*;
+
+/// Longest line the search follows. A won deal needs 104 foundation moves
+/// and at most 64 draws, so this leaves ample room for tableau play while
+/// keeping the recursion shallow.
+const FORTY_THIEVES_MAX_LINE: usize = 400;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FortyThievesSolveResult {
+    pub winnable: bool,
+    pub explored_states: usize,
+    pub hit_state_limit: bool,
+    /// Winning line from the analysed position; empty unless `winnable`.
+    pub line: Vec<SolverMove>,
+}
+
+struct FortyThievesSearch<'a> {
+    visited: HashSet<u64>,
+    max_states: usize,
+    cancel: &'a AtomicBool,
+    hit_state_limit: bool,
+    canceled: bool,
+    line: Vec<SolverMove>,
+}
+
+impl FortyThievesGame {
+    pub fn solve(&self, max_states: usize) -> FortyThievesSolveResult {
+        let cancel = AtomicBool::new(false);
+        self.solve_cancelable(max_states, &cancel)
+            .unwrap_or(FortyThievesSolveResult {
+                winnable: false,
+                explored_states: 0,
+                hit_state_limit: true,
+                line: Vec::new(),
+            })
+    }
+
+    /// Depth-first search over the pruned move list. The stock order is
+    /// known, so a found line always wins; Forty Thieves rarely exhausts
+    /// within a hint budget, so most answers are "no win found yet".
+    pub fn solve_cancelable(
+        &self,
+        max_states: usize,
+        cancel: &AtomicBool,
+    ) -> Option<FortyThievesSolveResult> {
+        let mut search = FortyThievesSearch {
+            visited: HashSet::new(),
+            max_states: max_states.max(1),
+            cancel,
+            hit_state_limit: false,
+            canceled: false,
+            line: Vec::new(),
+        };
+        let winnable = search.search(self);
+        if search.canceled {
+            return None;
+        }
+        Some(FortyThievesSolveResult {
+            winnable,
+            explored_states: search.visited.len(),
+            hit_state_limit: !winnable && search.hit_state_limit,
+            line: if winnable { search.line } else { Vec::new() },
+        })
+    }
+
+    fn solver_key(&self) -> u64 {
+        // Columns are interchangeable, so hash them in a canonical order.
+        let mut columns: Vec<u64> = self
+            .tableau()
+            .iter()
+            .map(|column| {
+                let mut hasher = DefaultHasher::new();
+                column.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+        columns.sort_unstable();
+        let mut hasher = DefaultHasher::new();
+        columns.hash(&mut hasher);
+        self.stock_len().hash(&mut hasher);
+        self.waste().hash(&mut hasher);
+        for pile in self.foundations() {
+            pile.len().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Candidate moves in search order. Shuffles that only trade one column
+    /// shape for an equivalent one are left out.
+    pub fn solver_moves(&self) -> Vec<SolverMove> {
+        let mut ordered: Vec<(i32, SolverMove)> = self
+            .legal_moves()
+            .into_iter()
+            .filter_map(|candidate| {
+                self.solver_move_priority(candidate)
+                    .map(|priority| (priority, candidate))
+            })
+            .collect();
+        ordered.sort_by_key(|(priority, _)| -priority);
+        ordered
+            .into_iter()
+            .map(|(_, candidate)| candidate)
+            .collect()
+    }
+
+    fn solver_move_priority(&self, candidate: SolverMove) -> Option<i32> {
+        match candidate {
+            SolverMove::WasteToFoundation | SolverMove::TableauTopToFoundation { .. } => Some(100),
+            SolverMove::WasteToTableau { dst } => Some(if self.tableau()[dst].is_empty() {
+                5
+            } else {
+                30
+            }),
+            SolverMove::TableauRunToTableau { src, start, dst } => {
+                let pile = &self.tableau()[src];
+                let dst_empty = self.tableau()[dst].is_empty();
+                if start == 0 && dst_empty {
+                    return None;
+                }
+                // Splitting a suited run rarely helps and multiplies the
+                // branching.
+                if start > 0 {
+                    let below = pile[start - 1];
+                    let moved = pile[start];
+                    if below.suit == moved.suit && below.rank == moved.rank + 1 {
+                        return None;
+                    }
+                }
+                let mut priority = if dst_empty { 10 } else { 40 };
+                if start == 0 {
+                    priority += 20;
+                } else if self.foundation_target(pile[start - 1]).is_some() {
+                    priority += 30;
+                }
+                Some(priority)
+            }
+            SolverMove::Draw => Some(0),
+        }
+    }
+}
+
+impl FortyThievesSearch<'_> {
+    fn search(&mut self, state: &FortyThievesGame) -> bool {
+        if state.is_won() {
+            return true;
+        }
+        if self.cancel.load(AtomicOrdering::Relaxed) {
+            self.canceled = true;
+            return false;
+        }
+        if self.visited.len() >= self.max_states || self.line.len() >= FORTY_THIEVES_MAX_LINE {
+            self.hit_state_limit = true;
+            return false;
+        }
+        if !self.visited.insert(state.solver_key()) {
+            return false;
+        }
+
+        for candidate in state.solver_moves() {
+            let mut next = state.clone();
+            if !next.apply_move(candidate) {
+                continue;
+            }
+            self.line.push(candidate);
+            if self.search(&next) {
+                return true;
+            }
+            self.line.pop();
+            if self.canceled {
+                return false;
+            }
+        }
+        false
+    }
+}
@@ -1,14 +1,23 @@
-use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::SeedableRng;
 use std::collections::HashMap;
 
-use super::{Card, Suit};
+use super::{Card, DealRng, GameMode, Suit};
 
 pub const FREECELL_MIN_CELL_COUNT: u8 = 1;
 pub const FREECELL_DEFAULT_CELL_COUNT: u8 = 4;
 pub const FREECELL_MAX_CELL_COUNT: u8 = 6;
+/// Cell storage; Eight Off uses every slot.
+pub const FREECELL_CELL_SLOTS: usize = 8;
+/// Column storage; Seahaven Towers uses every column.
+pub const FREECELL_MAX_COLUMNS: usize = 10;
 const FREECELL_MAX_CELL_COUNT_USIZE: usize = FREECELL_MAX_CELL_COUNT as usize;
+/// Highest Microsoft FreeCell game number, counting the extended range past
+/// the classic 1 to 1,000,000.
+pub const MICROSOFT_DEAL_MAX: u64 = 8_589_934_591;
+/// Seeds whose bits above the game number spell "MS" deal that Microsoft
+/// game instead of a shuffle. A random seed lands here with odds of 1 in 2^31.
+const MICROSOFT_DEAL_TAG: u64 = 0x4d53 << 48;
+const MICROSOFT_DEAL_NUMBER_MASK: u64 = (1 << 33) - 1;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FreecellCardCountMode {
@@ -17,13 +26,231 @@ pub enum FreecellCardCountMode {
     FiftyTwo,
 }
 
+/// Deals sharing the FreeCell engine; each fixes its columns, cells and
+/// rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FreecellLayout {
+    /// 8 columns, a player-chosen row of cells, alternate-colour builds.
+    FreeCell,
+    /// 8 columns of 6 and 8 cells, four of them dealt full.
+    EightOff,
+    /// 10 columns of 5 and 4 cells, two of them dealt full.
+    SeahavenTowers,
+    /// The FreeCell deal with four cells, building in suit.
+    BakersGame,
+}
+
+impl FreecellLayout {
+    pub fn for_mode(mode: GameMode) -> Option<Self> {
+        match mode {
+            GameMode::Freecell => Some(Self::FreeCell),
+            GameMode::EightOff => Some(Self::EightOff),
+            GameMode::SeahavenTowers => Some(Self::SeahavenTowers),
+            GameMode::BakersGame => Some(Self::BakersGame),
+            _ => None,
+        }
+    }
+
+    pub fn mode(self) -> GameMode {
+        match self {
+            Self::FreeCell => GameMode::Freecell,
+            Self::EightOff => GameMode::EightOff,
+            Self::SeahavenTowers => GameMode::SeahavenTowers,
+            Self::BakersGame => GameMode::BakersGame,
+        }
+    }
+
+    pub fn columns(self) -> usize {
+        match self {
+            Self::FreeCell | Self::EightOff | Self::BakersGame => 8,
+            Self::SeahavenTowers => FREECELL_MAX_COLUMNS,
+        }
+    }
+
+    /// `None` when the player picks the cell count (classic FreeCell).
+    pub fn fixed_cell_count(self) -> Option<u8> {
+        match self {
+            Self::FreeCell => None,
+            Self::EightOff => Some(FREECELL_CELL_SLOTS as u8),
+            Self::SeahavenTowers | Self::BakersGame => Some(4),
+        }
+    }
+
+    /// Cards the deal leaves in the cells instead of the tableau.
+    fn prefilled_cells(self) -> usize {
+        match self {
+            Self::FreeCell | Self::BakersGame => 0,
+            Self::EightOff => 4,
+            Self::SeahavenTowers => 2,
+        }
+    }
+
+    /// Layouts that deal eight columns from a full deck, the way Microsoft
+    /// FreeCell numbers its games.
+    pub fn deals_microsoft_numbers(self) -> bool {
+        matches!(self, Self::FreeCell | Self::BakersGame)
+    }
+
+    pub fn rules(self) -> FreecellRules {
+        match self {
+            Self::FreeCell => FreecellRules::CLASSIC,
+            Self::EightOff | Self::SeahavenTowers => FreecellRules {
+                build: FreecellBuild::Suit,
+                empty_column: FreecellEmptyColumn::KingsOnly,
+                supermoves: true,
+            },
+            Self::BakersGame => FreecellRules {
+                build: FreecellBuild::Suit,
+                ..FreecellRules::CLASSIC
+            },
+        }
+    }
+
+    fn id(self) -> &'static str {
+        match self {
+            Self::FreeCell => "freecell",
+            Self::EightOff => "eight_off",
+            Self::SeahavenTowers => "seahaven_towers",
+            Self::BakersGame => "bakers_game",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "freecell" => Some(Self::FreeCell),
+            "eight_off" => Some(Self::EightOff),
+            "seahaven_towers" => Some(Self::SeahavenTowers),
+            "bakers_game" => Some(Self::BakersGame),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FreecellBuild {
+    AlternateColour,
+    Suit,
+}
+
+impl FreecellBuild {
+    fn id(self) -> &'static str {
+        match self {
+            Self::AlternateColour => "alternate",
+            Self::Suit => "suit",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "alternate" => Some(Self::AlternateColour),
+            "suit" => Some(Self::Suit),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FreecellEmptyColumn {
+    AnyCard,
+    KingsOnly,
+}
+
+impl FreecellEmptyColumn {
+    fn id(self) -> &'static str {
+        match self {
+            Self::AnyCard => "any",
+            Self::KingsOnly => "kings",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "any" => Some(Self::AnyCard),
+            "kings" => Some(Self::KingsOnly),
+            _ => None,
+        }
+    }
+}
+
+/// How the tableau builds; the game, the planner and the hint heuristics all
+/// read the same profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FreecellRules {
+    pub build: FreecellBuild,
+    pub empty_column: FreecellEmptyColumn,
+    /// When false every move carries a single card, however many cells
+    /// and columns are free.
+    pub supermoves: bool,
+}
+
+impl FreecellRules {
+    pub const CLASSIC: Self = Self {
+        build: FreecellBuild::AlternateColour,
+        empty_column: FreecellEmptyColumn::AnyCard,
+        supermoves: true,
+    };
+
+    pub fn builds_on(self, card: Card, onto: Card) -> bool {
+        if onto.rank != card.rank + 1 {
+            return false;
+        }
+        match self.build {
+            FreecellBuild::AlternateColour => onto.color_red() != card.color_red(),
+            FreecellBuild::Suit => onto.suit == card.suit,
+        }
+    }
+
+    pub fn fills_empty_column(self, card: Card) -> bool {
+        match self.empty_column {
+            FreecellEmptyColumn::AnyCard => true,
+            FreecellEmptyColumn::KingsOnly => card.rank == 13,
+        }
+    }
+
+    pub fn is_run(self, cards: &[Card]) -> bool {
+        cards
+            .windows(2)
+            .all(|pair| self.builds_on(pair[1], pair[0]))
+    }
+
+    /// Longest run a single move may carry. Kings-only columns cannot park
+    /// the lower part of a run, so only the cells help there.
+    pub fn supermove_capacity(self, empty_cells: usize, empty_columns: usize) -> usize {
+        if !self.supermoves {
+            return 1;
+        }
+        match self.empty_column {
+            FreecellEmptyColumn::AnyCard => (empty_cells + 1) * (1usize << empty_columns),
+            FreecellEmptyColumn::KingsOnly => empty_cells + 1,
+        }
+    }
+
+    /// True when no tableau card could still want to build on `card`, given
+    /// how many cards each foundation (by `Suit::foundation_index`) holds.
+    /// In-suit builds make every playable card safe: its only possible
+    /// follower is already home.
+    pub fn is_safe_foundation_play(self, card: Card, foundation_len: [usize; 4]) -> bool {
+        if card.rank <= 1 || self.build == FreecellBuild::Suit {
+            return true;
+        }
+        let needed = usize::from(card.rank - 1);
+        if card.color_red() {
+            foundation_len[0] >= needed && foundation_len[3] >= needed
+        } else {
+            foundation_len[1] >= needed && foundation_len[2] >= needed
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FreecellGame {
+    layout: FreecellLayout,
+    rules: FreecellRules,
     card_count_mode: FreecellCardCountMode,
     foundations: [Vec<Card>; 4],
     freecell_count: u8,
-    freecells: [Option<Card>; FREECELL_MAX_CELL_COUNT_USIZE],
-    tableau: [Vec<Card>; 8],
+    freecells: [Option<Card>; FREECELL_CELL_SLOTS],
+    tableau: [Vec<Card>; FREECELL_MAX_COLUMNS],
 }
 
 impl FreecellGame {
@@ -48,26 +275,82 @@ impl FreecellGame {
         card_count_mode: FreecellCardCountMode,
         freecell_count: u8,
     ) -> Self {
-        let mut deck = freecell_deck(card_count_mode);
-        let mut rng = StdRng::seed_from_u64(seed);
-        deck.shuffle(&mut rng);
+        Self::deal(
+            FreecellLayout::FreeCell,
+            seed,
+            card_count_mode,
+            freecell_count,
+        )
+    }
+
+    /// Deals one of the fixed layouts; they always use the full deck.
+    pub fn new_with_seed_for_layout(layout: FreecellLayout, seed: u64) -> Self {
+        Self::deal(
+            layout,
+            seed,
+            FreecellCardCountMode::FiftyTwo,
+            FREECELL_DEFAULT_CELL_COUNT,
+        )
+    }
+
+    fn deal(
+        layout: FreecellLayout,
+        seed: u64,
+        card_count_mode: FreecellCardCountMode,
+        freecell_count: u8,
+    ) -> Self {
+        let microsoft_number = microsoft_deal_number(seed).filter(|_| {
+            layout.deals_microsoft_numbers() && card_count_mode == FreecellCardCountMode::FiftyTwo
+        });
+        let deck = match microsoft_number {
+            Some(game_number) => microsoft_deal_order(game_number),
+            None => {
+                let mut deck = freecell_deck(card_count_mode);
+                DealRng::from_seed(seed).shuffle(&mut deck);
+                deck
+            }
+        };
 
         let mut game = Self {
+            layout,
+            rules: layout.rules(),
             card_count_mode,
             foundations: std::array::from_fn(|_| Vec::new()),
-            freecell_count: Self::normalize_freecell_cell_count(freecell_count),
-            freecells: [None; FREECELL_MAX_CELL_COUNT_USIZE],
+            freecell_count: layout
+                .fixed_cell_count()
+                .unwrap_or_else(|| Self::normalize_freecell_cell_count(freecell_count)),
+            freecells: [None; FREECELL_CELL_SLOTS],
             tableau: std::array::from_fn(|_| Vec::new()),
         };
 
+        let columns = layout.columns();
+        let tableau_cards = deck.len() - layout.prefilled_cells();
         for (idx, mut card) in deck.into_iter().enumerate() {
             card.face_up = true;
-            game.tableau[idx % 8].push(card);
+            if idx < tableau_cards {
+                game.tableau[idx % columns].push(card);
+            } else {
+                game.freecells[idx - tableau_cards] = Some(card);
+            }
         }
 
         game
     }
 
+    pub fn layout(&self) -> FreecellLayout {
+        self.layout
+    }
+
+    pub fn rules(&self) -> FreecellRules {
+        self.rules
+    }
+
+    /// Plays the deal under another rule profile; the cards stay put.
+    pub fn with_rules(mut self, rules: FreecellRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
     pub fn foundations(&self) -> &[Vec<Card>; 4] {
         &self.foundations
     }
@@ -83,7 +366,12 @@ impl FreecellGame {
             .count()
     }
 
+    /// Only classic FreeCell takes the cell-count setting; the fixed layouts
+    /// keep their row and report success.
     pub fn try_set_freecell_count(&mut self, freecell_count: u8) -> Result<(), usize> {
+        if self.layout.fixed_cell_count().is_some() {
+            return Ok(());
+        }
         let normalized = Self::normalize_freecell_cell_count(freecell_count);
         if normalized == self.freecell_count {
             return Ok(());
@@ -108,7 +396,7 @@ impl FreecellGame {
                     self.freecells[empty_idx] = Some(card);
                 }
             }
-            for idx in new_count..FREECELL_MAX_CELL_COUNT_USIZE {
+            for idx in new_count..FREECELL_CELL_SLOTS {
                 self.freecells[idx] = None;
             }
         }
@@ -121,23 +409,25 @@ impl FreecellGame {
         &self.freecells[..self.freecell_count()]
     }
 
-    pub fn freecells_storage(&self) -> &[Option<Card>; FREECELL_MAX_CELL_COUNT_USIZE] {
+    pub fn freecells_storage(&self) -> &[Option<Card>; FREECELL_CELL_SLOTS] {
         &self.freecells
     }
 
-    pub fn tableau(&self) -> &[Vec<Card>; 8] {
-        &self.tableau
+    pub fn tableau(&self) -> &[Vec<Card>] {
+        &self.tableau[..self.layout.columns()]
     }
 
     pub fn tableau_card(&self, col: usize, index: usize) -> Option<Card> {
-        self.tableau
+        self.tableau()
             .get(col)
             .and_then(|pile| pile.get(index))
             .copied()
     }
 
     pub fn tableau_top(&self, col: usize) -> Option<Card> {
-        self.tableau.get(col).and_then(|pile| pile.last().copied())
+        self.tableau()
+            .get(col)
+            .and_then(|pile| pile.last().copied())
     }
 
     pub fn freecell_card(&self, cell: usize) -> Option<Card> {
@@ -161,18 +451,19 @@ impl FreecellGame {
             return false;
         }
 
+        let columns = self.layout.columns();
         for cell in 0..self.freecell_count() {
             if self.can_move_freecell_to_foundation(cell) {
                 return true;
             }
-            for dst in 0..8 {
+            for dst in 0..columns {
                 if self.can_move_freecell_to_tableau(cell, dst) {
                     return true;
                 }
             }
         }
 
-        for src in 0..8 {
+        for src in 0..columns {
             if self.can_move_tableau_top_to_foundation(src) {
                 return true;
             }
@@ -181,9 +472,9 @@ impl FreecellGame {
                     return true;
                 }
             }
-            let len = self.tableau.get(src).map(Vec::len).unwrap_or(0);
+            let len = self.tableau[src].len();
             for start in 0..len {
-                for dst in 0..8 {
+                for dst in 0..columns {
                     if self.can_move_tableau_run_to_tableau(src, start, dst) {
                         return true;
                     }
@@ -236,7 +527,7 @@ impl FreecellGame {
     }
 
     pub fn can_move_tableau_top_to_freecell(&self, src: usize, cell: usize) -> bool {
-        if cell >= self.freecell_count() || src >= self.tableau.len() {
+        if cell >= self.freecell_count() || src >= self.layout.columns() {
             return false;
         }
         self.freecells[cell].is_none() && !self.tableau[src].is_empty()
@@ -254,7 +545,7 @@ impl FreecellGame {
     }
 
     pub fn can_move_freecell_to_tableau(&self, cell: usize, dst: usize) -> bool {
-        if dst >= self.tableau.len() {
+        if dst >= self.layout.columns() {
             return false;
         }
         let Some(card) = self.freecell_card(cell) else {
@@ -287,7 +578,8 @@ impl FreecellGame {
     }
 
     pub fn can_move_tableau_run_to_tableau(&self, src: usize, start: usize, dst: usize) -> bool {
-        if src == dst || src >= self.tableau.len() || dst >= self.tableau.len() {
+        let columns = self.layout.columns();
+        if src == dst || src >= columns || dst >= columns {
             return false;
         }
         let source = &self.tableau[src];
@@ -296,7 +588,7 @@ impl FreecellGame {
         }
 
         let run = &source[start..];
-        if run.is_empty() || !is_descending_alternating_run(run) {
+        if run.is_empty() || !self.rules().is_run(run) {
             return false;
         }
 
@@ -350,23 +642,22 @@ impl FreecellGame {
     }
 
     pub fn encode_for_session(&self) -> String {
-        let mut parts = Vec::with_capacity(17 + FREECELL_MAX_CELL_COUNT_USIZE);
+        let columns = self.layout.columns();
+        let mut parts = Vec::with_capacity(6 + FREECELL_CELL_SLOTS + columns);
+        parts.push(format!("layout={}", self.layout.id()));
+        parts.push(format!("build={}", self.rules.build.id()));
+        parts.push(format!("empty={}", self.rules.empty_column.id()));
+        parts.push(format!("supermoves={}", u8::from(self.rules.supermoves)));
         parts.push(format!("fc={}", self.freecell_count));
-        for idx in 0..FREECELL_MAX_CELL_COUNT_USIZE {
+        for idx in 0..FREECELL_CELL_SLOTS {
             parts.push(format!("c{idx}={}", encode_slot(self.freecells[idx])));
         }
-        parts.push(format!("f0={}", encode_pile(&self.foundations[0])));
-        parts.push(format!("f1={}", encode_pile(&self.foundations[1])));
-        parts.push(format!("f2={}", encode_pile(&self.foundations[2])));
-        parts.push(format!("f3={}", encode_pile(&self.foundations[3])));
-        parts.push(format!("t0={}", encode_pile(&self.tableau[0])));
-        parts.push(format!("t1={}", encode_pile(&self.tableau[1])));
-        parts.push(format!("t2={}", encode_pile(&self.tableau[2])));
-        parts.push(format!("t3={}", encode_pile(&self.tableau[3])));
-        parts.push(format!("t4={}", encode_pile(&self.tableau[4])));
-        parts.push(format!("t5={}", encode_pile(&self.tableau[5])));
-        parts.push(format!("t6={}", encode_pile(&self.tableau[6])));
-        parts.push(format!("t7={}", encode_pile(&self.tableau[7])));
+        for (idx, pile) in self.foundations.iter().enumerate() {
+            parts.push(format!("f{idx}={}", encode_pile(pile)));
+        }
+        for (idx, pile) in self.tableau().iter().enumerate() {
+            parts.push(format!("t{idx}={}", encode_pile(pile)));
+        }
         parts.join(";")
     }
 
@@ -377,7 +668,28 @@ impl FreecellGame {
             fields.insert(key, value);
         }
 
-        let mut freecells = [None; FREECELL_MAX_CELL_COUNT_USIZE];
+        // Sessions saved before the other layouts existed are classic deals.
+        let layout = match fields.get("layout") {
+            Some(raw) => FreecellLayout::from_id(raw)?,
+            None => FreecellLayout::FreeCell,
+        };
+        // Older sessions carry no rule fields and play their layout's rules.
+        let mut rules = layout.rules();
+        if let Some(raw) = fields.get("build") {
+            rules.build = FreecellBuild::from_id(raw)?;
+        }
+        if let Some(raw) = fields.get("empty") {
+            rules.empty_column = FreecellEmptyColumn::from_id(raw)?;
+        }
+        if let Some(raw) = fields.get("supermoves") {
+            rules.supermoves = match *raw {
+                "0" => false,
+                "1" => true,
+                _ => return None,
+            };
+        }
+
+        let mut freecells = [None; FREECELL_CELL_SLOTS];
         for (idx, slot) in freecells.iter_mut().enumerate() {
             let key = format!("c{idx}");
             if let Some(raw) = fields.get(key.as_str()) {
@@ -387,19 +699,30 @@ impl FreecellGame {
             }
         }
 
-        let mut freecell_count = fields
-            .get("fc")
-            .and_then(|raw| raw.parse::<u8>().ok())
-            .map(Self::normalize_freecell_cell_count)
-            .unwrap_or(FREECELL_DEFAULT_CELL_COUNT);
         let highest_occupied = freecells
             .iter()
             .rposition(|slot| slot.is_some())
             .map(|idx| idx + 1)
             .unwrap_or(0);
-        if highest_occupied > usize::from(freecell_count) {
-            freecell_count = highest_occupied.min(FREECELL_MAX_CELL_COUNT_USIZE) as u8;
-        }
+        let freecell_count = match layout.fixed_cell_count() {
+            Some(count) => {
+                if highest_occupied > usize::from(count) {
+                    return None;
+                }
+                count
+            }
+            None => {
+                let count = fields
+                    .get("fc")
+                    .and_then(|raw| raw.parse::<u8>().ok())
+                    .map(Self::normalize_freecell_cell_count)
+                    .unwrap_or(FREECELL_DEFAULT_CELL_COUNT);
+                if highest_occupied > FREECELL_MAX_CELL_COUNT_USIZE {
+                    return None;
+                }
+                count.max(highest_occupied as u8)
+            }
+        };
 
         let foundations = [
             decode_pile(fields.get("f0")?)?,
@@ -407,27 +730,24 @@ impl FreecellGame {
             decode_pile(fields.get("f2")?)?,
             decode_pile(fields.get("f3")?)?,
         ];
-        let tableau = [
-            decode_pile(fields.get("t0")?)?,
-            decode_pile(fields.get("t1")?)?,
-            decode_pile(fields.get("t2")?)?,
-            decode_pile(fields.get("t3")?)?,
-            decode_pile(fields.get("t4")?)?,
-            decode_pile(fields.get("t5")?)?,
-            decode_pile(fields.get("t6")?)?,
-            decode_pile(fields.get("t7")?)?,
-        ];
+        let mut tableau: [Vec<Card>; FREECELL_MAX_COLUMNS] = std::array::from_fn(|_| Vec::new());
+        for (idx, pile) in tableau.iter_mut().take(layout.columns()).enumerate() {
+            *pile = decode_pile(fields.get(format!("t{idx}").as_str())?)?;
+        }
 
         let freecell_cards = freecells.iter().filter(|card| card.is_some()).count();
         let foundations_count: usize = foundations.iter().map(Vec::len).sum();
         let tableau_count: usize = tableau.iter().map(Vec::len).sum();
         let total_cards = freecell_cards + foundations_count + tableau_count;
-        let Some(card_count_mode) = FreecellCardCountMode::from_card_count(total_cards as u8)
-        else {
+        let card_count_mode = FreecellCardCountMode::from_card_count(total_cards as u8)?;
+        if layout != FreecellLayout::FreeCell && card_count_mode != FreecellCardCountMode::FiftyTwo
+        {
             return None;
-        };
+        }
 
         Some(Self {
+            layout,
+            rules,
             card_count_mode,
             freecell_count,
             foundations,
@@ -442,11 +762,17 @@ impl FreecellGame {
             .iter()
             .filter(|slot| slot.is_none())
             .count();
-        let mut empty_tableau = self.tableau.iter().filter(|pile| pile.is_empty()).count();
-        if self.tableau.get(dst).is_some_and(|pile| pile.is_empty()) {
+        let mut empty_tableau = self.tableau().iter().filter(|pile| pile.is_empty()).count();
+        if self.tableau().get(dst).is_some_and(|pile| pile.is_empty()) {
             empty_tableau = empty_tableau.saturating_sub(1);
         }
-        (free_empty + 1) * (1usize << empty_tableau)
+        self.rules().supermove_capacity(free_empty, empty_tableau)
+    }
+
+    /// Whether sending `card` home now can never cost a move later.
+    pub fn is_safe_foundation_play(&self, card: Card) -> bool {
+        self.rules()
+            .is_safe_foundation_play(card, self.foundations.each_ref().map(Vec::len))
     }
 
     fn can_place_on_foundation(&self, card: Card) -> bool {
@@ -458,28 +784,60 @@ impl FreecellGame {
     }
 
     fn can_place_on_tableau(&self, card: Card, dst: usize) -> bool {
-        match self.tableau.get(dst).and_then(|pile| pile.last()).copied() {
-            None => true,
-            Some(top) => top.rank == card.rank + 1 && top.color_red() != card.color_red(),
+        match self
+            .tableau()
+            .get(dst)
+            .and_then(|pile| pile.last())
+            .copied()
+        {
+            None => self.rules().fills_empty_column(card),
+            Some(top) => self.rules().builds_on(card, top),
         }
     }
 
-    pub(crate) fn from_parts_unchecked_with_cell_count(
+    /// Rebuilds a game from planner state; nothing is validated.
+    pub(crate) fn from_parts_unchecked(
+        layout: FreecellLayout,
+        rules: FreecellRules,
         card_count_mode: FreecellCardCountMode,
         foundations: [Vec<Card>; 4],
         freecell_count: u8,
-        freecells: [Option<Card>; FREECELL_MAX_CELL_COUNT_USIZE],
-        tableau: [Vec<Card>; 8],
+        freecells: [Option<Card>; FREECELL_CELL_SLOTS],
+        tableau: [Vec<Card>; FREECELL_MAX_COLUMNS],
     ) -> Self {
         Self {
+            layout,
+            rules,
             card_count_mode,
-            freecell_count: Self::normalize_freecell_cell_count(freecell_count),
+            freecell_count,
             foundations,
             freecells,
             tableau,
         }
     }
 
+    /// A position with one cell per `freecells` entry; `tableau` fills the
+    /// first columns. Card counts are the caller's to check.
+    pub(super) fn from_board(
+        layout: FreecellLayout,
+        foundations: [Vec<Card>; 4],
+        freecells: &[Option<Card>],
+        tableau: Vec<Vec<Card>>,
+    ) -> Self {
+        let mut freecells_storage = [None; FREECELL_CELL_SLOTS];
+        freecells_storage[..freecells.len()].copy_from_slice(freecells);
+        let mut tableau = tableau.into_iter();
+        Self::from_parts_unchecked(
+            layout,
+            layout.rules(),
+            FreecellCardCountMode::FiftyTwo,
+            foundations,
+            freecells.len() as u8,
+            freecells_storage,
+            std::array::from_fn(|_| tableau.next().unwrap_or_default()),
+        )
+    }
+
     fn normalize_freecell_cell_count(value: u8) -> u8 {
         if (FREECELL_MIN_CELL_COUNT..=FREECELL_MAX_CELL_COUNT).contains(&value) {
             value
@@ -491,6 +849,27 @@ impl FreecellGame {
 
 #[cfg(test)]
 impl FreecellGame {
+    pub(crate) fn from_parts_unchecked_with_cell_count(
+        card_count_mode: FreecellCardCountMode,
+        foundations: [Vec<Card>; 4],
+        freecell_count: u8,
+        freecells: [Option<Card>; FREECELL_MAX_CELL_COUNT_USIZE],
+        tableau: [Vec<Card>; 8],
+    ) -> Self {
+        let mut freecells_storage = [None; FREECELL_CELL_SLOTS];
+        freecells_storage[..FREECELL_MAX_CELL_COUNT_USIZE].copy_from_slice(&freecells);
+        let mut tableau = tableau.into_iter();
+        Self::from_parts_unchecked(
+            FreecellLayout::FreeCell,
+            FreecellRules::CLASSIC,
+            card_count_mode,
+            foundations,
+            Self::normalize_freecell_cell_count(freecell_count),
+            freecells_storage,
+            std::array::from_fn(|_| tableau.next().unwrap_or_default()),
+        )
+    }
+
     pub(crate) fn debug_new(
         foundations: [Vec<Card>; 4],
         freecells: [Option<Card>; 4],
@@ -529,16 +908,93 @@ impl FreecellGame {
     ) -> Self {
         let mut freecells_storage = [None; FREECELL_MAX_CELL_COUNT_USIZE];
         freecells_storage[..4].copy_from_slice(&freecells);
-        Self {
+        Self::from_parts_unchecked_with_cell_count(
             card_count_mode,
-            freecell_count: Self::normalize_freecell_cell_count(freecell_count),
             foundations,
-            freecells: freecells_storage,
+            freecell_count,
+            freecells_storage,
             tableau,
+        )
+    }
+
+    /// Test deal for the fixed layouts: `tableau` fills the first columns,
+    /// `freecells` the first cells.
+    pub(crate) fn debug_new_with_layout(
+        layout: FreecellLayout,
+        foundations: [Vec<Card>; 4],
+        freecells: &[Option<Card>],
+        tableau: Vec<Vec<Card>>,
+    ) -> Self {
+        let mut freecells_storage = [None; FREECELL_CELL_SLOTS];
+        freecells_storage[..freecells.len()].copy_from_slice(freecells);
+        let mut tableau = tableau.into_iter();
+        Self::from_parts_unchecked(
+            layout,
+            layout.rules(),
+            FreecellCardCountMode::FiftyTwo,
+            foundations,
+            layout
+                .fixed_cell_count()
+                .unwrap_or(FREECELL_DEFAULT_CELL_COUNT),
+            freecells_storage,
+            std::array::from_fn(|_| tableau.next().unwrap_or_default()),
+        )
+    }
+}
+
+/// Seed that deals Microsoft FreeCell game `game_number`.
+pub fn microsoft_deal_seed(game_number: u64) -> Option<u64> {
+    (1..=MICROSOFT_DEAL_MAX)
+        .contains(&game_number)
+        .then_some(MICROSOFT_DEAL_TAG | game_number)
+}
+
+/// The Microsoft game number a seed stands for, if any.
+pub fn microsoft_deal_number(seed: u64) -> Option<u64> {
+    let game_number = seed & MICROSOFT_DEAL_NUMBER_MASK;
+    (seed & !MICROSOFT_DEAL_NUMBER_MASK == MICROSOFT_DEAL_TAG && game_number != 0)
+        .then_some(game_number)
+}
+
+/// Dealing order of Microsoft FreeCell: the C runtime `rand()` LCG picks
+/// from an A-K deck interleaved clubs, diamonds, hearts, spades. Games past
+/// 2^31 follow the extended-range rules FreeCell Pro introduced.
+/// The Microsoft generator for `game_number`, extended ranges included.
+pub(super) fn microsoft_rand(game_number: u64) -> impl FnMut() -> u64 {
+    let mut state = if game_number < 1 << 32 {
+        game_number
+    } else {
+        game_number - (1 << 32)
+    };
+    move || {
+        state = state.wrapping_mul(214_013).wrapping_add(2_531_011);
+        if game_number >= 1 << 32 {
+            ((state >> 16) & 0xffff) + 1
+        } else if game_number >= 1 << 31 {
+            ((state >> 16) & 0x7fff) | 0x8000
+        } else {
+            (state >> 16) & 0x7fff
         }
     }
 }
 
+fn microsoft_deal_order(game_number: u64) -> Vec<Card> {
+    let mut next = microsoft_rand(game_number);
+    let mut deck: Vec<Card> = (0..52_u8)
+        .map(|idx| Card {
+            suit: Suit::ALL[usize::from(idx % 4)],
+            rank: idx / 4 + 1,
+            face_up: true,
+        })
+        .collect();
+    let mut order = Vec::with_capacity(deck.len());
+    while !deck.is_empty() {
+        let pick = (next() % deck.len() as u64) as usize;
+        order.push(deck.swap_remove(pick));
+    }
+    order
+}
+
 fn freecell_deck(card_count_mode: FreecellCardCountMode) -> Vec<Card> {
     let mut deck = Vec::with_capacity(card_count_mode.card_count() as usize);
     let suit_count = card_count_mode.suit_count() as usize;
@@ -577,14 +1033,6 @@ impl FreecellCardCountMode {
     }
 }
 
-fn is_descending_alternating_run(cards: &[Card]) -> bool {
-    cards.windows(2).all(|pair| {
-        let a = pair[0];
-        let b = pair[1];
-        a.rank == b.rank + 1 && a.color_red() != b.color_red()
-    })
-}
-
 fn encode_slot(card: Option<Card>) -> String {
     match card {
         Some(card) => encode_card(card),
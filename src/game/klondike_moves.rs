@@ -191,6 +191,10 @@ impl KlondikeGame {
             .unwrap_or(0)
     }
 
+    pub fn stock_top(&self) -> Option<Card> {
+        self.stock.last().copied()
+    }
+
     pub fn waste_top(&self) -> Option<Card> {
         self.waste.last().copied()
     }
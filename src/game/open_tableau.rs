@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+
+use super::setup::full_deck;
+use super::spider::{decode_spider_pile, encode_spider_pile};
+use super::{Card, DealRng, GameMode, SolverMove};
+
+pub const BAKERS_DOZEN_COLUMNS: usize = 13;
+pub const BELEAGUERED_CASTLE_COLUMNS: usize = 8;
+
+/// Open-information deals sharing one engine: no stock, no hidden cards,
+/// single-card moves building down regardless of suit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpenTableauLayout {
+    /// 13 columns of 4 with kings moved to the bottom; empty columns stay
+    /// empty.
+    BakersDozen,
+    /// Aces start on the foundations; the other 48 cards form 8 rows of 6,
+    /// and any card may fill an empty row.
+    BeleagueredCastle,
+}
+
+impl OpenTableauLayout {
+    pub fn for_mode(mode: GameMode) -> Option<Self> {
+        match mode {
+            GameMode::BakersDozen => Some(Self::BakersDozen),
+            GameMode::BeleagueredCastle => Some(Self::BeleagueredCastle),
+            _ => None,
+        }
+    }
+
+    pub fn mode(self) -> GameMode {
+        match self {
+            Self::BakersDozen => GameMode::BakersDozen,
+            Self::BeleagueredCastle => GameMode::BeleagueredCastle,
+        }
+    }
+
+    pub fn columns(self) -> usize {
+        match self {
+            Self::BakersDozen => BAKERS_DOZEN_COLUMNS,
+            Self::BeleagueredCastle => BELEAGUERED_CASTLE_COLUMNS,
+        }
+    }
+
+    fn deal_depth(self) -> usize {
+        match self {
+            Self::BakersDozen => 4,
+            Self::BeleagueredCastle => 6,
+        }
+    }
+
+    pub fn empty_columns_fillable(self) -> bool {
+        match self {
+            Self::BakersDozen => false,
+            Self::BeleagueredCastle => true,
+        }
+    }
+
+    fn id(self) -> &'static str {
+        match self {
+            Self::BakersDozen => "bakers_dozen",
+            Self::BeleagueredCastle => "beleaguered_castle",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "bakers_dozen" => Some(Self::BakersDozen),
+            "beleaguered_castle" => Some(Self::BeleagueredCastle),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OpenTableauGame {
+    layout: OpenTableauLayout,
+    /// Indexed by `Suit::foundation_index`.
+    foundations: [Vec<Card>; 4],
+    tableau: Vec<Vec<Card>>,
+}
+
+impl OpenTableauGame {
+    pub fn new_with_seed(layout: OpenTableauLayout, seed: u64) -> Self {
+        let mut deck = full_deck();
+        for card in &mut deck {
+            card.face_up = true;
+        }
+        DealRng::from_seed(seed).shuffle(&mut deck);
+
+        let mut foundations: [Vec<Card>; 4] = std::array::from_fn(|_| Vec::new());
+        if layout == OpenTableauLayout::BeleagueredCastle {
+            deck.retain(|card| {
+                if card.rank != 1 {
+                    return true;
+                }
+                foundations[card.suit.foundation_index()].push(*card);
+                false
+            });
+        }
+
+        let mut tableau = vec![Vec::new(); layout.columns()];
+        let mut draw = deck.into_iter();
+        for _ in 0..layout.deal_depth() {
+            for pile in &mut tableau {
+                pile.push(
+                    draw.next()
+                        .expect("open tableau deals consume the whole deck"),
+                );
+            }
+        }
+        if layout == OpenTableauLayout::BakersDozen {
+            // Kings sink to the bottom of their column; the rest keep order.
+            for pile in &mut tableau {
+                pile.sort_by_key(|card| card.rank != 13);
+            }
+        }
+
+        Self {
+            layout,
+            foundations,
+            tableau,
+        }
+    }
+
+    pub(super) fn from_board(
+        layout: OpenTableauLayout,
+        foundations: [Vec<Card>; 4],
+        tableau: Vec<Vec<Card>>,
+    ) -> Self {
+        Self {
+            layout,
+            foundations,
+            tableau,
+        }
+    }
+
+    pub fn layout(&self) -> OpenTableauLayout {
+        self.layout
+    }
+
+    pub fn foundations(&self) -> &[Vec<Card>; 4] {
+        &self.foundations
+    }
+
+    pub fn foundation_cards(&self) -> usize {
+        self.foundations.iter().map(Vec::len).sum()
+    }
+
+    pub fn tableau(&self) -> &[Vec<Card>] {
+        &self.tableau
+    }
+
+    pub fn tableau_card(&self, col: usize, index: usize) -> Option<Card> {
+        self.tableau
+            .get(col)
+            .and_then(|pile| pile.get(index))
+            .copied()
+    }
+
+    pub fn tableau_top(&self, col: usize) -> Option<Card> {
+        self.tableau.get(col).and_then(|pile| pile.last().copied())
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.len() == 13)
+    }
+
+    pub fn has_legal_moves(&self) -> bool {
+        !self.is_won() && !self.legal_moves().is_empty()
+    }
+
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && !self.has_legal_moves()
+    }
+
+    /// Foundation pile `card` would land on, if any.
+    pub fn foundation_target(&self, card: Card) -> Option<usize> {
+        let pile = card.suit.foundation_index();
+        (self.foundations[pile].len() + 1 == usize::from(card.rank)).then_some(pile)
+    }
+
+    pub fn can_move_tableau_top_to_foundation(&self, src: usize) -> bool {
+        self.tableau_top(src)
+            .is_some_and(|card| self.foundation_target(card).is_some())
+    }
+
+    pub fn move_tableau_top_to_foundation(&mut self, src: usize) -> bool {
+        let Some(pile) = self
+            .tableau_top(src)
+            .and_then(|card| self.foundation_target(card))
+        else {
+            return false;
+        };
+        let Some(card) = self.tableau[src].pop() else {
+            return false;
+        };
+        self.foundations[pile].push(card);
+        true
+    }
+
+    /// Only the top card moves, so a "run" is always the last card.
+    pub fn can_move_run(&self, src: usize, start: usize, dst: usize) -> bool {
+        if src == dst || dst >= self.tableau.len() {
+            return false;
+        }
+        let Some(pile) = self.tableau.get(src) else {
+            return false;
+        };
+        start + 1 == pile.len() && self.column_accepts(dst, pile[start])
+    }
+
+    pub fn move_run(&mut self, src: usize, start: usize, dst: usize) -> bool {
+        if !self.can_move_run(src, start, dst) {
+            return false;
+        }
+        let Some(card) = self.tableau[src].pop() else {
+            return false;
+        };
+        self.tableau[dst].push(card);
+        true
+    }
+
+    /// Every legal move, foundation plays first.
+    pub fn legal_moves(&self) -> Vec<SolverMove> {
+        let mut moves = Vec::new();
+        for src in 0..self.tableau.len() {
+            if self.can_move_tableau_top_to_foundation(src) {
+                moves.push(SolverMove::TableauTopToFoundation { src });
+            }
+        }
+        for src in 0..self.tableau.len() {
+            let Some(start) = self.tableau[src].len().checked_sub(1) else {
+                continue;
+            };
+            for dst in 0..self.tableau.len() {
+                if self.can_move_run(src, start, dst) {
+                    moves.push(SolverMove::TableauRunToTableau { src, start, dst });
+                }
+            }
+        }
+        moves
+    }
+
+    pub fn apply_move(&mut self, solver_move: SolverMove) -> bool {
+        match solver_move {
+            SolverMove::TableauTopToFoundation { src } => self.move_tableau_top_to_foundation(src),
+            SolverMove::TableauRunToTableau { src, start, dst } => self.move_run(src, start, dst),
+            SolverMove::Draw
+            | SolverMove::WasteToFoundation
+            | SolverMove::WasteToTableau { .. } => false,
+        }
+    }
+
+    pub fn encode_for_session(&self) -> String {
+        let mut parts = vec![format!("layout={}", self.layout.id())];
+        for (idx, pile) in self.foundations.iter().enumerate() {
+            parts.push(format!("f{idx}={}", encode_spider_pile(pile)));
+        }
+        for (idx, pile) in self.tableau.iter().enumerate() {
+            parts.push(format!("t{idx}={}", encode_spider_pile(pile)));
+        }
+        parts.join(";")
+    }
+
+    pub fn decode_from_session(data: &str) -> Option<Self> {
+        let mut fields = HashMap::<&str, &str>::new();
+        for part in data.split(';') {
+            let (key, value) = part.split_once('=')?;
+            fields.insert(key, value);
+        }
+
+        let layout = OpenTableauLayout::from_id(fields.get("layout")?)?;
+        let pile = |key: &str| decode_spider_pile(fields.get(key)?);
+        let mut foundations: [Vec<Card>; 4] = std::array::from_fn(|_| Vec::new());
+        for (idx, slot) in foundations.iter_mut().enumerate() {
+            *slot = pile(&format!("f{idx}"))?;
+            let in_order = slot.iter().enumerate().all(|(rank, card)| {
+                card.suit.foundation_index() == idx && usize::from(card.rank) == rank + 1
+            });
+            if !in_order {
+                return None;
+            }
+        }
+        let mut tableau = Vec::with_capacity(layout.columns());
+        for idx in 0..layout.columns() {
+            tableau.push(pile(&format!("t{idx}"))?);
+        }
+
+        let foundation_count: usize = foundations.iter().map(Vec::len).sum();
+        let tableau_count: usize = tableau.iter().map(Vec::len).sum();
+        if foundation_count + tableau_count != 52 {
+            return None;
+        }
+
+        Some(Self {
+            layout,
+            foundations,
+            tableau,
+        })
+    }
+
+    fn column_accepts(&self, dst: usize, card: Card) -> bool {
+        match self.tableau.get(dst) {
+            None => false,
+            Some(pile) => match pile.last() {
+                None => self.layout.empty_columns_fillable(),
+                Some(top) => top.rank == card.rank + 1,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+impl OpenTableauGame {
+    pub(crate) fn debug_new(
+        layout: OpenTableauLayout,
+        foundations: [Vec<Card>; 4],
+        tableau: Vec<Vec<Card>>,
+    ) -> Self {
+        Self {
+            layout,
+            foundations,
+            tableau,
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+
+use super::setup::full_deck;
+use super::spider::{
+    decode_spider_pile, decode_spider_slots, encode_spider_pile, encode_spider_slots,
+};
+use super::{Card, DealRng, DrawResult};
+
+pub const PYRAMID_ROWS: usize = 7;
+pub const PYRAMID_SLOTS: usize = 28;
+/// Times the waste may be turned back into the stock (three passes in total).
+pub const PYRAMID_MAX_RECYCLES: u8 = 2;
+
+/// A card that can take part in a removal: a pyramid slot (row-major, apex
+/// first) or the top of the waste.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PyramidCardRef {
+    Pyramid(usize),
+    Waste,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PyramidMove {
+    RemoveKing(PyramidCardRef),
+    RemovePair(PyramidCardRef, PyramidCardRef),
+    Draw,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PyramidGame {
+    pyramid: [Option<Card>; PYRAMID_SLOTS],
+    stock: Vec<Card>,
+    waste: Vec<Card>,
+    removed: Vec<Card>,
+    recycles_used: u8,
+}
+
+impl PyramidGame {
+    pub fn new_with_seed(seed: u64) -> Self {
+        let mut deck = full_deck();
+        DealRng::from_seed(seed).shuffle(&mut deck);
+
+        let mut pyramid = [None; PYRAMID_SLOTS];
+        let mut draw = deck.into_iter();
+        for slot in &mut pyramid {
+            let mut card = draw.next().expect("pyramid setup consumes 28 cards");
+            card.face_up = true;
+            *slot = Some(card);
+        }
+        let stock = draw.collect();
+
+        Self {
+            pyramid,
+            stock,
+            waste: Vec::new(),
+            removed: Vec::new(),
+            recycles_used: 0,
+        }
+    }
+
+    pub(super) fn from_board(
+        pyramid: [Option<Card>; PYRAMID_SLOTS],
+        stock: Vec<Card>,
+        waste: Vec<Card>,
+        removed: Vec<Card>,
+        recycles_used: u8,
+    ) -> Self {
+        Self {
+            pyramid,
+            stock,
+            waste,
+            removed,
+            recycles_used,
+        }
+    }
+
+    /// Row and position within the row of a pyramid slot.
+    pub fn slot_row_pos(slot: usize) -> (usize, usize) {
+        let mut row = 0;
+        while (row + 1) * (row + 2) / 2 <= slot {
+            row += 1;
+        }
+        (row, slot - row * (row + 1) / 2)
+    }
+
+    pub fn slot_index(row: usize, pos: usize) -> Option<usize> {
+        (row < PYRAMID_ROWS && pos <= row).then(|| row * (row + 1) / 2 + pos)
+    }
+
+    /// The two slots resting on `slot`, or `None` for the bottom row.
+    pub fn covering_slots(slot: usize) -> Option<(usize, usize)> {
+        let (row, pos) = Self::slot_row_pos(slot);
+        let left = Self::slot_index(row + 1, pos)?;
+        Some((left, left + 1))
+    }
+
+    pub fn pyramid(&self) -> &[Option<Card>; PYRAMID_SLOTS] {
+        &self.pyramid
+    }
+
+    pub fn pyramid_card(&self, slot: usize) -> Option<Card> {
+        self.pyramid.get(slot).copied().flatten()
+    }
+
+    pub fn pyramid_remaining(&self) -> usize {
+        self.pyramid.iter().filter(|slot| slot.is_some()).count()
+    }
+
+    pub fn stock(&self) -> &[Card] {
+        &self.stock
+    }
+
+    pub fn stock_len(&self) -> usize {
+        self.stock.len()
+    }
+
+    pub fn waste(&self) -> &[Card] {
+        &self.waste
+    }
+
+    pub fn waste_top(&self) -> Option<Card> {
+        self.waste.last().copied()
+    }
+
+    pub fn removed(&self) -> &[Card] {
+        &self.removed
+    }
+
+    pub fn recycles_used(&self) -> u8 {
+        self.recycles_used
+    }
+
+    pub fn recycles_remaining(&self) -> u8 {
+        PYRAMID_MAX_RECYCLES.saturating_sub(self.recycles_used)
+    }
+
+    pub fn card(&self, card_ref: PyramidCardRef) -> Option<Card> {
+        match card_ref {
+            PyramidCardRef::Pyramid(slot) => self.pyramid_card(slot),
+            PyramidCardRef::Waste => self.waste_top(),
+        }
+    }
+
+    /// A pyramid card is exposed once both cards resting on it are gone.
+    pub fn is_exposed(&self, slot: usize) -> bool {
+        self.is_exposed_ignoring(slot, None)
+    }
+
+    fn is_exposed_ignoring(&self, slot: usize, ignored: Option<usize>) -> bool {
+        if self.pyramid_card(slot).is_none() {
+            return false;
+        }
+        match Self::covering_slots(slot) {
+            None => true,
+            Some((left, right)) => [left, right]
+                .into_iter()
+                .all(|cover| Some(cover) == ignored || self.pyramid[cover].is_none()),
+        }
+    }
+
+    pub fn is_available(&self, card_ref: PyramidCardRef) -> bool {
+        match card_ref {
+            PyramidCardRef::Pyramid(slot) => self.is_exposed(slot),
+            PyramidCardRef::Waste => !self.waste.is_empty(),
+        }
+    }
+
+    pub fn can_remove_king(&self, card_ref: PyramidCardRef) -> bool {
+        self.is_available(card_ref) && self.card(card_ref).is_some_and(|card| card.rank == 13)
+    }
+
+    /// Two cards summing to 13 leave together. A card may pair with the one
+    /// card still resting on it, since both are free once the pair is gone.
+    pub fn can_remove_pair(&self, a: PyramidCardRef, b: PyramidCardRef) -> bool {
+        if a == b {
+            return false;
+        }
+        let (Some(first), Some(second)) = (self.card(a), self.card(b)) else {
+            return false;
+        };
+        if first.rank + second.rank != 13 {
+            return false;
+        }
+        let partner_slot = |card_ref: PyramidCardRef| match card_ref {
+            PyramidCardRef::Pyramid(slot) => Some(slot),
+            PyramidCardRef::Waste => None,
+        };
+        let free = |card_ref: PyramidCardRef, other: PyramidCardRef| match card_ref {
+            PyramidCardRef::Pyramid(slot) => self.is_exposed_ignoring(slot, partner_slot(other)),
+            PyramidCardRef::Waste => !self.waste.is_empty(),
+        };
+        free(a, b) && free(b, a)
+    }
+
+    pub fn can_draw(&self) -> bool {
+        !self.stock.is_empty() || (!self.waste.is_empty() && self.recycles_remaining() > 0)
+    }
+
+    pub fn draw_or_recycle(&mut self) -> DrawResult {
+        if let Some(mut card) = self.stock.pop() {
+            card.face_up = true;
+            self.waste.push(card);
+            return DrawResult::DrewFromStock;
+        }
+        if self.waste.is_empty() || self.recycles_remaining() == 0 {
+            return DrawResult::NoOp;
+        }
+        while let Some(mut card) = self.waste.pop() {
+            card.face_up = false;
+            self.stock.push(card);
+        }
+        self.recycles_used += 1;
+        DrawResult::RecycledWaste
+    }
+
+    pub fn can_apply_move(&self, pyramid_move: PyramidMove) -> bool {
+        match pyramid_move {
+            PyramidMove::RemoveKing(card_ref) => self.can_remove_king(card_ref),
+            PyramidMove::RemovePair(a, b) => self.can_remove_pair(a, b),
+            PyramidMove::Draw => self.can_draw(),
+        }
+    }
+
+    pub fn apply_move(&mut self, pyramid_move: PyramidMove) -> bool {
+        if !self.can_apply_move(pyramid_move) {
+            return false;
+        }
+        match pyramid_move {
+            PyramidMove::RemoveKing(card_ref) => self.take(card_ref),
+            PyramidMove::RemovePair(a, b) => {
+                self.take(a);
+                self.take(b);
+            }
+            PyramidMove::Draw => {
+                let _ = self.draw_or_recycle();
+            }
+        }
+        true
+    }
+
+    fn take(&mut self, card_ref: PyramidCardRef) {
+        let card = match card_ref {
+            PyramidCardRef::Pyramid(slot) => self.pyramid[slot].take(),
+            PyramidCardRef::Waste => self.waste.pop(),
+        };
+        if let Some(card) = card {
+            self.removed.push(card);
+        }
+    }
+
+    /// Every legal move: kings, pyramid pairs, waste pairs, then the stock.
+    pub fn legal_moves(&self) -> Vec<PyramidMove> {
+        let mut moves = Vec::new();
+        let mut refs: Vec<PyramidCardRef> = (0..PYRAMID_SLOTS)
+            .filter(|slot| self.pyramid[*slot].is_some())
+            .map(PyramidCardRef::Pyramid)
+            .collect();
+        refs.push(PyramidCardRef::Waste);
+
+        for card_ref in &refs {
+            if self.can_remove_king(*card_ref) {
+                moves.push(PyramidMove::RemoveKing(*card_ref));
+            }
+        }
+        for (idx, a) in refs.iter().enumerate() {
+            for b in &refs[idx + 1..] {
+                if self.can_remove_pair(*a, *b) {
+                    moves.push(PyramidMove::RemovePair(*a, *b));
+                }
+            }
+        }
+        if self.can_draw() {
+            moves.push(PyramidMove::Draw);
+        }
+        moves
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.pyramid.iter().all(Option::is_none)
+    }
+
+    pub fn has_legal_moves(&self) -> bool {
+        !self.is_won() && !self.legal_moves().is_empty()
+    }
+
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && !self.has_legal_moves()
+    }
+
+    pub fn encode_for_session(&self) -> String {
+        let parts = [
+            format!("p={}", encode_spider_slots(&self.pyramid)),
+            format!("s={}", encode_spider_pile(&self.stock)),
+            format!("w={}", encode_spider_pile(&self.waste)),
+            format!("r={}", encode_spider_pile(&self.removed)),
+            format!("rc={}", self.recycles_used),
+        ];
+        parts.join(";")
+    }
+
+    pub fn decode_from_session(data: &str) -> Option<Self> {
+        let mut fields = HashMap::<&str, &str>::new();
+        for part in data.split(';') {
+            let (key, value) = part.split_once('=')?;
+            fields.insert(key, value);
+        }
+
+        let pyramid: [Option<Card>; PYRAMID_SLOTS] =
+            decode_spider_slots(fields.get("p")?)?.try_into().ok()?;
+        let stock = decode_spider_pile(fields.get("s")?)?;
+        let waste = decode_spider_pile(fields.get("w")?)?;
+        let removed = decode_spider_pile(fields.get("r")?)?;
+        let recycles_used = fields.get("rc")?.parse::<u8>().ok()?;
+        if recycles_used > PYRAMID_MAX_RECYCLES {
+            return None;
+        }
+
+        let pyramid_count = pyramid.iter().filter(|slot| slot.is_some()).count();
+        if pyramid_count + stock.len() + waste.len() + removed.len() != 52 {
+            return None;
+        }
+
+        Some(Self {
+            pyramid,
+            stock,
+            waste,
+            removed,
+            recycles_used,
+        })
+    }
+}
+
+#[cfg(test)]
+impl PyramidGame {
+    pub(crate) fn debug_new(
+        pyramid: [Option<Card>; PYRAMID_SLOTS],
+        stock: Vec<Card>,
+        waste: Vec<Card>,
+        recycles_used: u8,
+    ) -> Self {
+        Self {
+            pyramid,
+            stock,
+            waste,
+            removed: Vec::new(),
+            recycles_used,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PyramidSolveResult {
+    pub winnable: bool,
+    pub explored_states: usize,
+    pub hit_state_limit: bool,
+    /// Winning line from the analysed position; empty unless `winnable`.
+    pub line: Vec<PyramidMove>,
+}
+
+struct PyramidSearch<'a> {
+    visited: HashSet<u64>,
+    max_states: usize,
+    cancel: &'a AtomicBool,
+    hit_state_limit: bool,
+    canceled: bool,
+    line: Vec<PyramidMove>,
+}
+
+impl PyramidGame {
+    pub fn solve(&self, max_states: usize) -> PyramidSolveResult {
+        let cancel = AtomicBool::new(false);
+        self.solve_cancelable(max_states, &cancel)
+            .unwrap_or(PyramidSolveResult {
+                winnable: false,
+                explored_states: 0,
+                hit_state_limit: true,
+                line: Vec::new(),
+            })
+    }
+
+    /// Depth-first search over removals and stock turns. Pyramid has no hidden
+    /// information, so a found line always wins and an exhausted search (no
+    /// state limit hit) proves the deal lost.
+    pub fn solve_cancelable(
+        &self,
+        max_states: usize,
+        cancel: &AtomicBool,
+    ) -> Option<PyramidSolveResult> {
+        let mut search = PyramidSearch {
+            visited: HashSet::new(),
+            max_states: max_states.max(1),
+            cancel,
+            hit_state_limit: false,
+            canceled: false,
+            line: Vec::new(),
+        };
+        let winnable = search.search(self);
+        if search.canceled {
+            return None;
+        }
+        Some(PyramidSolveResult {
+            winnable,
+            explored_states: search.visited.len(),
+            hit_state_limit: !winnable && search.hit_state_limit,
+            line: if winnable { search.line } else { Vec::new() },
+        })
+    }
+
+    fn solver_key(&self) -> u64 {
+        // The removed pile is implied by everything else.
+        let mut hasher = DefaultHasher::new();
+        self.pyramid().hash(&mut hasher);
+        self.stock().hash(&mut hasher);
+        self.waste().hash(&mut hasher);
+        self.recycles_used().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Candidate moves in search order. An available king is always safe to
+    /// clear, so it is the only candidate when present.
+    pub fn solver_moves(&self) -> Vec<PyramidMove> {
+        let moves = self.legal_moves();
+        if let Some(king) = moves
+            .iter()
+            .copied()
+            .find(|candidate| matches!(candidate, PyramidMove::RemoveKing(_)))
+        {
+            return vec![king];
+        }
+        let mut ordered: Vec<(i32, PyramidMove)> = moves
+            .into_iter()
+            .map(|candidate| (self.solver_move_priority(candidate), candidate))
+            .collect();
+        ordered.sort_by_key(|(priority, _)| -priority);
+        ordered
+            .into_iter()
+            .map(|(_, candidate)| candidate)
+            .collect()
+    }
+
+    fn solver_move_priority(&self, candidate: PyramidMove) -> i32 {
+        // Prefer clearing deep pyramid rows: they unlock the most cards.
+        let slot_weight = |card_ref: PyramidCardRef| match card_ref {
+            PyramidCardRef::Pyramid(slot) => 10 + Self::slot_row_pos(slot).0 as i32,
+            PyramidCardRef::Waste => 0,
+        };
+        match candidate {
+            PyramidMove::RemoveKing(card_ref) => 100 + slot_weight(card_ref),
+            PyramidMove::RemovePair(a, b) => slot_weight(a) + slot_weight(b),
+            PyramidMove::Draw => -1,
+        }
+    }
+}
+
+impl PyramidSearch<'_> {
+    fn search(&mut self, state: &PyramidGame) -> bool {
+        if state.is_won() {
+            return true;
+        }
+        if self.cancel.load(AtomicOrdering::Relaxed) {
+            self.canceled = true;
+            return false;
+        }
+        if self.visited.len() >= self.max_states {
+            self.hit_state_limit = true;
+            return false;
+        }
+        if !self.visited.insert(state.solver_key()) {
+            return false;
+        }
+
+        for candidate in state.solver_moves() {
+            let mut next = state.clone();
+            if !next.apply_move(candidate) {
+                continue;
+            }
+            self.line.push(candidate);
+            if self.search(&next) {
+                return true;
+            }
+            self.line.pop();
+            if self.canceled {
+                return false;
+            }
+        }
+        false
+    }
+}
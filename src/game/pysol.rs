@@ -0,0 +1,260 @@
+//! PySol and PySolFC game numbers.
+//!
+//! PySol shuffles its pack with one of two generators. Game numbers up to
+//! 32000, and any number written with an `ms` prefix, use the Microsoft
+//! FreeCell generator; a 52-card pack is first reordered to Microsoft's
+//! rank-major Clubs, Diamonds, Hearts, Spades order, so FreeCell deals match
+//! the Microsoft game of the same number. Larger numbers seed Python's
+//! Mersenne Twister and draw each index as `int(random() * (n + 1))`.
+//!
+//! Either way the pack starts as PySol creates it (each deck Clubs, Spades,
+//! Hearts, Diamonds, Ace to King), is shuffled from the top index down and
+//! lands on the talon with its last card on top. The deal functions below
+//! then copy each game's `startGame` order.
+
+use super::freecell::microsoft_rand;
+use super::{
+    Card, DrawMode, FreecellGame, FreecellLayout, KlondikeGame, KlondikePassLimit, SpiderGame,
+    SpiderSuitMode, Suit,
+};
+
+/// PySolFC numbers games with up to twenty digits.
+const PYSOL_GAME_NUMBER_MAX: u128 = 99_999_999_999_999_999_999;
+/// Highest number PySol still deals with the Microsoft generator.
+const PYSOL_CLASSIC_MAX: u128 = 32_000;
+const PYSOL_SUIT_ORDER: [Suit; 4] = [Suit::Clubs, Suit::Spades, Suit::Hearts, Suit::Diamonds];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PysolGameNumber {
+    number: u128,
+    microsoft: bool,
+}
+
+impl PysolGameNumber {
+    /// `123`, `ms123` or `ms:123`; underscores are ignored.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let normalized = raw.trim().replace('_', "").to_ascii_lowercase();
+        let (digits, prefixed) = match normalized.strip_prefix("ms") {
+            Some(rest) => (rest.strip_prefix(':').unwrap_or(rest), true),
+            None => (normalized.as_str(), false),
+        };
+        if digits.is_empty() || !digits.chars().all(|ch| ch.is_ascii_digit()) {
+            return None;
+        }
+        let number = digits.parse::<u128>().ok()?;
+        if prefixed {
+            return (number <= u128::from(super::MICROSOFT_DEAL_MAX)).then_some(Self {
+                number,
+                microsoft: true,
+            });
+        }
+        (number <= PYSOL_GAME_NUMBER_MAX).then_some(Self {
+            number,
+            microsoft: number <= PYSOL_CLASSIC_MAX,
+        })
+    }
+
+    pub fn label(self) -> String {
+        if self.microsoft && self.number > PYSOL_CLASSIC_MAX {
+            format!("ms{}", self.number)
+        } else {
+            self.number.to_string()
+        }
+    }
+
+    /// PySol's talon for `decks` packs, top card last.
+    fn talon(self, decks: usize) -> Vec<Card> {
+        let mut cards = Vec::with_capacity(decks * 52);
+        for _ in 0..decks {
+            for suit in PYSOL_SUIT_ORDER {
+                for rank in 1..=13 {
+                    cards.push(Card {
+                        suit,
+                        rank,
+                        face_up: false,
+                    });
+                }
+            }
+        }
+
+        if self.microsoft {
+            if cards.len() == 52 {
+                cards = (0..52)
+                    .map(|idx| cards[[0, 39, 26, 13][idx % 4] + idx / 4])
+                    .collect();
+            }
+            let mut next = microsoft_rand(self.number as u64);
+            for top in (1..cards.len()).rev() {
+                let pick = (next() % (top as u64 + 1)) as usize;
+                cards.swap(top, pick);
+            }
+        } else {
+            let mut twister = MersenneTwister::from_python_seed(self.number);
+            for top in (1..cards.len()).rev() {
+                let pick = (twister.next_f64() * (top + 1) as f64) as usize;
+                cards.swap(top, pick);
+            }
+        }
+        cards
+    }
+}
+
+/// Deals from the talon onto `rows`, left to right, like PySol's `dealRow`.
+fn deal_row(talon: &mut Vec<Card>, piles: &mut [Vec<Card>], rows: &[usize], face_up: bool) {
+    for &row in rows {
+        let mut card = talon.pop().expect("PySol deals fit in the talon");
+        card.face_up = face_up;
+        piles[row].push(card);
+    }
+}
+
+impl KlondikeGame {
+    /// PySol's Klondike: rows dealt right to left, then the first card
+    /// turned onto the waste.
+    pub fn from_pysol_game(number: PysolGameNumber) -> Self {
+        let mut talon = number.talon(1);
+        let mut tableau: [Vec<Card>; 7] = std::array::from_fn(|_| Vec::new());
+        for first in 1..7 {
+            let rows: Vec<usize> = (first..7).rev().collect();
+            deal_row(&mut talon, &mut tableau, &rows, false);
+        }
+        let rows: Vec<usize> = (0..7).rev().collect();
+        deal_row(&mut talon, &mut tableau, &rows, true);
+        let mut first_waste = talon.pop().expect("Klondike talon has cards left");
+        first_waste.face_up = true;
+        Self {
+            draw_mode: DrawMode::One,
+            pass_limit: KlondikePassLimit::Unlimited,
+            recycles_used: 0,
+            thoughtful: false,
+            stock: talon,
+            waste: vec![first_waste],
+            foundations: std::array::from_fn(|_| Vec::new()),
+            tableau,
+        }
+    }
+}
+
+impl SpiderGame {
+    /// PySol's four-suit Spider: the extra face-down cards go to the first,
+    /// fourth, seventh and tenth columns.
+    pub fn from_pysol_game(number: PysolGameNumber) -> Self {
+        let mut talon = number.talon(2);
+        let mut tableau: [Vec<Card>; 10] = std::array::from_fn(|_| Vec::new());
+        let all_rows: Vec<usize> = (0..10).collect();
+        for _ in 0..4 {
+            deal_row(&mut talon, &mut tableau, &all_rows, false);
+        }
+        deal_row(&mut talon, &mut tableau, &[0, 3, 6, 9], false);
+        deal_row(&mut talon, &mut tableau, &all_rows, true);
+        SpiderGame::from_board(SpiderSuitMode::Four, talon, tableau, Vec::new())
+    }
+}
+
+impl FreecellGame {
+    /// PySol's FreeCell: full rows left to right until the talon runs out.
+    pub fn from_pysol_game(number: PysolGameNumber) -> Self {
+        let mut talon = number.talon(1);
+        let mut tableau: Vec<Vec<Card>> = vec![Vec::new(); 8];
+        let all_rows: Vec<usize> = (0..8).collect();
+        for _ in 0..6 {
+            deal_row(&mut talon, &mut tableau, &all_rows, true);
+        }
+        deal_row(&mut talon, &mut tableau, &[0, 1, 2, 3], true);
+        FreecellGame::from_board(
+            FreecellLayout::FreeCell,
+            std::array::from_fn(|_| Vec::new()),
+            &[None; 4],
+            tableau,
+        )
+    }
+}
+
+/// MT19937 seeded the way CPython's `random.seed(int)` does.
+struct MersenneTwister {
+    state: [u32; 624],
+    index: usize,
+}
+
+impl MersenneTwister {
+    fn from_python_seed(seed: u128) -> Self {
+        let mut key: Vec<u32> = Vec::new();
+        let mut rest = seed;
+        while rest > 0 {
+            key.push(rest as u32);
+            rest >>= 32;
+        }
+        if key.is_empty() {
+            key.push(0);
+        }
+
+        let mut mt = Self {
+            state: [0; 624],
+            index: 624,
+        };
+        mt.state[0] = 19_650_218;
+        for idx in 1..624 {
+            let prev = mt.state[idx - 1];
+            mt.state[idx] = 1_812_433_253_u32
+                .wrapping_mul(prev ^ (prev >> 30))
+                .wrapping_add(idx as u32);
+        }
+
+        let (mut i, mut j) = (1_usize, 0_usize);
+        for _ in 0..624.max(key.len()) {
+            let prev = mt.state[i - 1];
+            mt.state[i] = (mt.state[i] ^ (prev ^ (prev >> 30)).wrapping_mul(1_664_525))
+                .wrapping_add(key[j])
+                .wrapping_add(j as u32);
+            i += 1;
+            j += 1;
+            if i >= 624 {
+                mt.state[0] = mt.state[623];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+        for _ in 0..623 {
+            let prev = mt.state[i - 1];
+            mt.state[i] = (mt.state[i] ^ (prev ^ (prev >> 30)).wrapping_mul(1_566_083_941))
+                .wrapping_sub(i as u32);
+            i += 1;
+            if i >= 624 {
+                mt.state[0] = mt.state[623];
+                i = 1;
+            }
+        }
+        mt.state[0] = 0x8000_0000;
+        mt
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= 624 {
+            for idx in 0..624 {
+                let y =
+                    (self.state[idx] & 0x8000_0000) | (self.state[(idx + 1) % 624] & 0x7fff_ffff);
+                let mut next = self.state[(idx + 397) % 624] ^ (y >> 1);
+                if y & 1 != 0 {
+                    next ^= 0x9908_b0df;
+                }
+                self.state[idx] = next;
+            }
+            self.index = 0;
+        }
+        let mut y = self.state[self.index];
+        self.index += 1;
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c_5680;
+        y ^= (y << 15) & 0xefc6_0000;
+        y ^ (y >> 18)
+    }
+
+    /// `random.random()`: 53 random bits in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        let high = f64::from(self.next_u32() >> 5);
+        let low = f64::from(self.next_u32() >> 6);
+        (high * 67_108_864.0 + low) / 9_007_199_254_740_992.0
+    }
+}
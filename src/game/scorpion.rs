@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use super::setup::full_deck;
+use super::spider::{
+    decode_spider_completed_run_suits, decode_spider_pile, encode_spider_completed_run_suits,
+    encode_spider_pile, remove_completed_suited_runs,
+};
+use super::{Card, DealRng, DrawResult, SolverMove, Suit};
+
+pub const SCORPION_COLUMNS: usize = 7;
+const SCORPION_DEAL_DEPTH: usize = 7;
+/// Columns whose bottom three cards are dealt face down.
+const SCORPION_HIDDEN_COLUMNS: usize = 4;
+const SCORPION_HIDDEN_DEPTH: usize = 3;
+/// The reserve lands on the first three columns.
+pub(super) const SCORPION_RESERVE: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScorpionGame {
+    reserve: Vec<Card>,
+    tableau: [Vec<Card>; SCORPION_COLUMNS],
+    completed_run_suits: Vec<Suit>,
+}
+
+impl ScorpionGame {
+    pub fn new_with_seed(seed: u64) -> Self {
+        let mut deck = full_deck();
+        DealRng::from_seed(seed).shuffle(&mut deck);
+
+        let mut game = Self {
+            reserve: Vec::new(),
+            tableau: std::array::from_fn(|_| Vec::new()),
+            completed_run_suits: Vec::new(),
+        };
+
+        let mut draw = deck.into_iter();
+        for (col, pile) in game.tableau.iter_mut().enumerate() {
+            for row in 0..SCORPION_DEAL_DEPTH {
+                let mut card = draw.next().expect("scorpion setup consumes 49 cards");
+                card.face_up = col >= SCORPION_HIDDEN_COLUMNS || row >= SCORPION_HIDDEN_DEPTH;
+                pile.push(card);
+            }
+        }
+        game.reserve = draw.collect();
+        game
+    }
+
+    pub(super) fn from_board(
+        reserve: Vec<Card>,
+        tableau: [Vec<Card>; SCORPION_COLUMNS],
+        completed_run_suits: Vec<Suit>,
+    ) -> Self {
+        Self {
+            reserve,
+            tableau,
+            completed_run_suits,
+        }
+    }
+
+    pub fn reserve(&self) -> &[Card] {
+        &self.reserve
+    }
+
+    pub fn stock_len(&self) -> usize {
+        self.reserve.len()
+    }
+
+    pub fn tableau(&self) -> &[Vec<Card>; SCORPION_COLUMNS] {
+        &self.tableau
+    }
+
+    pub fn tableau_card(&self, col: usize, index: usize) -> Option<Card> {
+        self.tableau
+            .get(col)
+            .and_then(|pile| pile.get(index))
+            .copied()
+    }
+
+    pub fn tableau_top(&self, col: usize) -> Option<Card> {
+        self.tableau.get(col).and_then(|pile| pile.last().copied())
+    }
+
+    pub fn completed_runs(&self) -> usize {
+        self.completed_run_suits.len()
+    }
+
+    pub fn completed_run_suits(&self) -> &[Suit] {
+        &self.completed_run_suits
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.completed_runs() >= 4
+    }
+
+    pub fn has_legal_moves(&self) -> bool {
+        !self.is_won() && !self.legal_moves().is_empty()
+    }
+
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && !self.has_legal_moves()
+    }
+
+    pub fn can_deal_reserve(&self) -> bool {
+        !self.reserve.is_empty()
+    }
+
+    /// Deals the reserve face up onto the first three columns. There is only
+    /// one reserve, so this can happen once per deal.
+    pub fn deal_reserve(&mut self) -> DrawResult {
+        if !self.can_deal_reserve() {
+            return DrawResult::NoOp;
+        }
+        let reserve = std::mem::take(&mut self.reserve);
+        for (pile, mut card) in self.tableau.iter_mut().zip(reserve) {
+            card.face_up = true;
+            pile.push(card);
+        }
+        self.remove_completed_runs();
+        DrawResult::DrewFromStock
+    }
+
+    /// Any face-up card moves together with everything stacked on it, as in
+    /// Yukon; only the moved card has to build down in suit on the
+    /// destination, and only kings may fill an empty column.
+    pub fn can_move_run(&self, src: usize, start: usize, dst: usize) -> bool {
+        if src == dst || src >= self.tableau.len() || dst >= self.tableau.len() {
+            return false;
+        }
+        let Some(card) = self.tableau_card(src, start) else {
+            return false;
+        };
+        if !card.face_up {
+            return false;
+        }
+        match self.tableau[dst].last() {
+            None => card.rank == 13 && start > 0,
+            Some(top) => top.face_up && top.suit == card.suit && top.rank == card.rank + 1,
+        }
+    }
+
+    pub fn move_run(&mut self, src: usize, start: usize, dst: usize) -> bool {
+        if !self.can_move_run(src, start, dst) {
+            return false;
+        }
+        let moved = self.tableau[src].split_off(start);
+        self.tableau[dst].extend(moved);
+        if let Some(card) = self.tableau[src].last_mut() {
+            card.face_up = true;
+        }
+        self.remove_completed_runs();
+        true
+    }
+
+    /// Every legal move, with the one-time reserve deal last.
+    pub fn legal_moves(&self) -> Vec<SolverMove> {
+        let mut moves = Vec::new();
+        for src in 0..self.tableau.len() {
+            for start in 0..self.tableau[src].len() {
+                for dst in 0..self.tableau.len() {
+                    if self.can_move_run(src, start, dst) {
+                        moves.push(SolverMove::TableauRunToTableau { src, start, dst });
+                    }
+                }
+            }
+        }
+        if self.can_deal_reserve() {
+            moves.push(SolverMove::Draw);
+        }
+        moves
+    }
+
+    pub fn apply_move(&mut self, solver_move: SolverMove) -> bool {
+        match solver_move {
+            SolverMove::Draw => self.deal_reserve() == DrawResult::DrewFromStock,
+            SolverMove::TableauRunToTableau { src, start, dst } => self.move_run(src, start, dst),
+            SolverMove::WasteToFoundation
+            | SolverMove::WasteToTableau { .. }
+            | SolverMove::TableauTopToFoundation { .. } => false,
+        }
+    }
+
+    pub fn encode_for_session(&self) -> String {
+        let mut parts = vec![
+            format!(
+                "runs={}",
+                encode_spider_completed_run_suits(&self.completed_run_suits)
+            ),
+            format!("r={}", encode_spider_pile(&self.reserve)),
+        ];
+        for (idx, pile) in self.tableau.iter().enumerate() {
+            parts.push(format!("t{idx}={}", encode_spider_pile(pile)));
+        }
+        parts.join(";")
+    }
+
+    pub fn decode_from_session(data: &str) -> Option<Self> {
+        let mut fields = HashMap::<&str, &str>::new();
+        for part in data.split(';') {
+            let (key, value) = part.split_once('=')?;
+            fields.insert(key, value);
+        }
+
+        let encoded_runs = fields.get("runs")?;
+        let run_count = if *encoded_runs == "-" {
+            0
+        } else {
+            encoded_runs.len()
+        };
+        if run_count > 4 {
+            return None;
+        }
+        let completed_run_suits = decode_spider_completed_run_suits(encoded_runs, run_count)?;
+        let reserve = decode_spider_pile(fields.get("r")?)?;
+        if !reserve.is_empty() && reserve.len() != SCORPION_RESERVE {
+            return None;
+        }
+        let mut tableau: [Vec<Card>; SCORPION_COLUMNS] = std::array::from_fn(|_| Vec::new());
+        for (idx, slot) in tableau.iter_mut().enumerate() {
+            *slot = decode_spider_pile(fields.get(format!("t{idx}").as_str())?)?;
+        }
+
+        let tableau_count: usize = tableau.iter().map(Vec::len).sum();
+        if reserve.len() + tableau_count + (run_count * 13) != 52 {
+            return None;
+        }
+
+        Some(Self {
+            reserve,
+            tableau,
+            completed_run_suits,
+        })
+    }
+
+    fn remove_completed_runs(&mut self) {
+        remove_completed_suited_runs(&mut self.tableau, &mut self.completed_run_suits);
+    }
+}
+
+#[cfg(test)]
+impl ScorpionGame {
+    pub(crate) fn debug_new(
+        reserve: Vec<Card>,
+        tableau: [Vec<Card>; SCORPION_COLUMNS],
+        completed_run_suits: Vec<Suit>,
+    ) -> Self {
+        Self {
+            reserve,
+            tableau,
+            completed_run_suits,
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+use super::*;
+
+/// Longest line the search follows. Each card has a single building
+/// target, so winning lines stay short; this mainly stops king shuffles
+/// between empty columns from running away.
+const SCORPION_MAX_LINE: usize = 300;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScorpionSolveResult {
+    pub winnable: bool,
+    pub explored_states: usize,
+    pub hit_state_limit: bool,
+    /// Winning line from the analysed position; empty unless `winnable`.
+    pub line: Vec<SolverMove>,
+}
+
+struct ScorpionSearch<'a> {
+    visited: HashSet<u64>,
+    max_states: usize,
+    cancel: &'a AtomicBool,
+    hit_state_limit: bool,
+    canceled: bool,
+    line: Vec<SolverMove>,
+}
+
+impl ScorpionGame {
+    pub fn solve(&self, max_states: usize) -> ScorpionSolveResult {
+        let cancel = AtomicBool::new(false);
+        self.solve_cancelable(max_states, &cancel)
+            .unwrap_or(ScorpionSolveResult {
+                winnable: false,
+                explored_states: 0,
+                hit_state_limit: true,
+                line: Vec::new(),
+            })
+    }
+
+    /// Depth-first search over the pruned move list. The search sees the
+    /// face-down cards, so a found line always wins.
+    pub fn solve_cancelable(
+        &self,
+        max_states: usize,
+        cancel: &AtomicBool,
+    ) -> Option<ScorpionSolveResult> {
+        let mut search = ScorpionSearch {
+            visited: HashSet::new(),
+            max_states: max_states.max(1),
+            cancel,
+            hit_state_limit: false,
+            canceled: false,
+            line: Vec::new(),
+        };
+        let winnable = search.search(self);
+        if search.canceled {
+            return None;
+        }
+        Some(ScorpionSolveResult {
+            winnable,
+            explored_states: search.visited.len(),
+            hit_state_limit: !winnable && search.hit_state_limit,
+            line: if winnable { search.line } else { Vec::new() },
+        })
+    }
+
+    fn solver_key(&self) -> u64 {
+        let mut columns: Vec<u64> = self
+            .tableau()
+            .iter()
+            .map(|column| {
+                let mut hasher = DefaultHasher::new();
+                column.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+        // The reserve lands on the first three columns, so columns only
+        // become interchangeable once it has been dealt.
+        if self.reserve().is_empty() {
+            columns.sort_unstable();
+        }
+        let mut hasher = DefaultHasher::new();
+        columns.hash(&mut hasher);
+        self.stock_len().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Candidate moves in search order: uncovering face-down cards first,
+    /// the reserve deal last.
+    pub fn solver_moves(&self) -> Vec<SolverMove> {
+        let mut ordered: Vec<(i32, SolverMove)> = self
+            .legal_moves()
+            .into_iter()
+            .map(|candidate| (self.solver_move_priority(candidate), candidate))
+            .collect();
+        ordered.sort_by_key(|(priority, _)| -priority);
+        ordered
+            .into_iter()
+            .map(|(_, candidate)| candidate)
+            .collect()
+    }
+
+    fn solver_move_priority(&self, candidate: SolverMove) -> i32 {
+        let SolverMove::TableauRunToTableau { src, start, dst } = candidate else {
+            return 0;
+        };
+        let pile = &self.tableau()[src];
+        let mut priority = if self.tableau()[dst].is_empty() {
+            10
+        } else {
+            30
+        };
+        match start.checked_sub(1).map(|below| pile[below]) {
+            Some(below) if !below.face_up => priority += 40,
+            None => priority += 20,
+            Some(_) => {}
+        }
+        priority
+    }
+}
+
+impl ScorpionSearch<'_> {
+    fn search(&mut self, state: &ScorpionGame) -> bool {
+        if state.is_won() {
+            return true;
+        }
+        if self.cancel.load(AtomicOrdering::Relaxed) {
+            self.canceled = true;
+            return false;
+        }
+        if self.visited.len() >= self.max_states || self.line.len() >= SCORPION_MAX_LINE {
+            self.hit_state_limit = true;
+            return false;
+        }
+        if !self.visited.insert(state.solver_key()) {
+            return false;
+        }
+
+        for candidate in state.solver_moves() {
+            let mut next = state.clone();
+            if !next.apply_move(candidate) {
+                continue;
+            }
+            self.line.push(candidate);
+            if self.search(&next) {
+                return true;
+            }
+            self.line.pop();
+            if self.canceled {
+                return false;
+            }
+        }
+        false
+    }
+}
@@ -4,6 +4,9 @@ impl KlondikeGame {
     pub fn encode_for_session(&self) -> String {
         let parts = [
             format!("draw={}", self.draw_mode.count()),
+            format!("passes={}", self.pass_limit.session_tag()),
+            format!("rc={}", self.recycles_used),
+            format!("thoughtful={}", u8::from(self.thoughtful)),
             format!("stock={}", encode_pile(&self.stock)),
             format!("waste={}", encode_pile(&self.waste)),
             format!("f0={}", encode_pile(&self.foundations[0])),
@@ -29,6 +32,26 @@ impl KlondikeGame {
         }
 
         let draw_mode = DrawMode::from_count(fields.get("draw")?.parse::<u8>().ok()?)?;
+        // Sessions saved before pass limits existed turn the stock forever.
+        let pass_limit = match fields.get("passes") {
+            Some(raw) => KlondikePassLimit::from_session_tag(raw)?,
+            None => KlondikePassLimit::Unlimited,
+        };
+        let recycles_used = match fields.get("rc") {
+            Some(_) if pass_limit.max_recycles().is_none() => 0,
+            Some(raw) => raw.parse::<u8>().ok()?,
+            None => 0,
+        };
+        let thoughtful = match fields.get("thoughtful") {
+            Some(raw) => raw.parse::<u8>().ok()? == 1,
+            None => false,
+        };
+        if pass_limit
+            .max_recycles()
+            .is_some_and(|max| recycles_used > max)
+        {
+            return None;
+        }
         let stock = decode_pile(fields.get("stock")?)?;
         let waste = decode_pile(fields.get("waste")?)?;
         let foundations = [
@@ -55,6 +78,9 @@ impl KlondikeGame {
 
         Some(Self {
             draw_mode,
+            pass_limit,
+            recycles_used,
+            thoughtful,
             stock,
             waste,
             foundations,
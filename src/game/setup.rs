@@ -1,6 +1,4 @@
-use rand::rngs::StdRng;
-use rand::seq::SliceRandom;
-use rand::{Rng, SeedableRng};
+use rand::Rng;
 
 use super::*;
 
@@ -12,11 +10,13 @@ impl KlondikeGame {
 
     pub fn new_with_seed(seed: u64) -> Self {
         let mut deck = full_deck();
-        let mut rng = StdRng::seed_from_u64(seed);
-        deck.shuffle(&mut rng);
+        DealRng::from_seed(seed).shuffle(&mut deck);
 
         let mut game = Self {
             draw_mode: DrawMode::One,
+            pass_limit: KlondikePassLimit::Unlimited,
+            recycles_used: 0,
+            thoughtful: false,
             stock: Vec::new(),
             waste: Vec::new(),
             foundations: std::array::from_fn(|_| Vec::new()),
@@ -53,7 +53,7 @@ impl KlondikeGame {
             return DrawResult::DrewFromStock;
         }
 
-        if self.waste.is_empty() {
+        if self.waste.is_empty() || self.passes_exhausted() {
             return DrawResult::NoOp;
         }
 
@@ -61,9 +61,48 @@ impl KlondikeGame {
             card.face_up = false;
             self.stock.push(card);
         }
+        // Unlimited passes leave the counter alone so a recycled position
+        // equals (and hashes like) the one it came round to.
+        if self.pass_limit.max_recycles().is_some() {
+            self.recycles_used = self.recycles_used.saturating_add(1);
+        }
         DrawResult::RecycledWaste
     }
 
+    /// Whether the stock can deal or the waste can still be turned over.
+    pub fn can_draw_or_recycle(&self) -> bool {
+        !self.stock.is_empty() || (!self.waste.is_empty() && !self.passes_exhausted())
+    }
+
+    /// Whether the stock is spent and the pass limit forbids another recycle.
+    pub fn passes_exhausted(&self) -> bool {
+        self.stock.is_empty()
+            && self
+                .pass_limit
+                .max_recycles()
+                .is_some_and(|max| self.recycles_used >= max)
+    }
+
+    pub fn set_pass_limit(&mut self, pass_limit: KlondikePassLimit) {
+        self.pass_limit = pass_limit;
+    }
+
+    pub fn pass_limit(&self) -> KlondikePassLimit {
+        self.pass_limit
+    }
+
+    pub fn recycles_used(&self) -> u8 {
+        self.recycles_used
+    }
+
+    pub fn set_thoughtful(&mut self, thoughtful: bool) {
+        self.thoughtful = thoughtful;
+    }
+
+    pub fn is_thoughtful(&self) -> bool {
+        self.thoughtful
+    }
+
     pub fn draw_or_recycle(&mut self) -> DrawResult {
         self.draw_or_recycle_with_count(self.draw_mode.count())
     }
@@ -97,7 +136,9 @@ impl KlondikeGame {
     }
 }
 
-fn full_deck() -> Vec<Card> {
+/// One pack in suit-then-rank order, face down: the deal every single-deck
+/// variant shuffles.
+pub(super) fn full_deck() -> Vec<Card> {
     let mut deck = Vec::with_capacity(52);
     for suit in Suit::ALL {
         for rank in 1..=13 {
@@ -1,9 +1,7 @@
-use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::SeedableRng;
 use std::collections::HashMap;
 
-use super::{Card, Suit};
+use super::{Card, DealRng, Suit};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SpiderSuitMode {
@@ -13,8 +11,22 @@ pub enum SpiderSuitMode {
     Four,
 }
 
+/// Spider-family deals sharing the run-clearing engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpiderRuleSet {
+    /// Two decks in ten columns of 6 and 5; only suited runs move.
+    Spider,
+    /// One deck in seven Klondike-shaped columns.
+    Spiderette,
+    /// One deck in seven columns of three.
+    WillOTheWisp,
+    /// Spider's deal, but runs of one colour move together.
+    Tarantula,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SpiderGame {
+    rule_set: SpiderRuleSet,
     suit_mode: SpiderSuitMode,
     stock: Vec<Card>,
     tableau: [Vec<Card>; 10],
@@ -28,11 +40,19 @@ impl SpiderGame {
     }
 
     pub fn new_with_seed_and_mode(seed: u64, suit_mode: SpiderSuitMode) -> Self {
-        let mut deck = spider_deck(suit_mode);
-        let mut rng = StdRng::seed_from_u64(seed);
-        deck.shuffle(&mut rng);
+        Self::new_with_seed_and_rules(seed, suit_mode, SpiderRuleSet::Spider)
+    }
+
+    pub fn new_with_seed_and_rules(
+        seed: u64,
+        suit_mode: SpiderSuitMode,
+        rule_set: SpiderRuleSet,
+    ) -> Self {
+        let mut deck = spider_deck_for_decks(suit_mode, rule_set.decks());
+        DealRng::from_seed(seed).shuffle(&mut deck);
 
         let mut game = Self {
+            rule_set,
             suit_mode,
             stock: Vec::new(),
             tableau: std::array::from_fn(|_| Vec::new()),
@@ -41,10 +61,10 @@ impl SpiderGame {
         };
 
         let mut draw = deck.into_iter();
-        for col in 0..10 {
-            let col_size = if col < 4 { 6 } else { 5 };
+        for col in 0..rule_set.columns() {
+            let col_size = rule_set.dealt_column_len(col);
             for row in 0..col_size {
-                let mut card = draw.next().expect("spider setup consumes 54 cards");
+                let mut card = draw.next().expect("spider setup fits in the deck");
                 card.face_up = row == col_size - 1;
                 game.tableau[col].push(card);
             }
@@ -58,6 +78,10 @@ impl SpiderGame {
         game
     }
 
+    pub fn rule_set(&self) -> SpiderRuleSet {
+        self.rule_set
+    }
+
     pub fn suit_mode(&self) -> SpiderSuitMode {
         self.suit_mode
     }
@@ -66,8 +90,13 @@ impl SpiderGame {
         self.stock.len()
     }
 
-    pub fn tableau(&self) -> &[Vec<Card>; 10] {
-        &self.tableau
+    /// Stock cards, bottom first; the last card is dealt first.
+    pub fn stock(&self) -> &[Card] {
+        &self.stock
+    }
+
+    pub fn tableau(&self) -> &[Vec<Card>] {
+        &self.tableau[..self.rule_set.columns()]
     }
 
     pub fn completed_runs(&self) -> usize {
@@ -79,11 +108,17 @@ impl SpiderGame {
     }
 
     pub fn is_won(&self) -> bool {
-        self.completed_runs >= 8
+        self.completed_runs >= self.rule_set.goal_runs()
+    }
+
+    /// Cards the next stock deal hands out: one per column, or whatever is
+    /// left when a one-deck stock runs short.
+    pub fn next_deal_len(&self) -> usize {
+        self.stock.len().min(self.rule_set.columns())
     }
 
     pub fn can_deal_from_stock(&self) -> bool {
-        self.stock.len() >= 10 && self.tableau.iter().all(|pile| !pile.is_empty())
+        !self.stock.is_empty() && self.tableau().iter().all(|pile| !pile.is_empty())
     }
 
     pub fn deal_from_stock(&mut self) -> bool {
@@ -91,7 +126,7 @@ impl SpiderGame {
             return false;
         }
 
-        for col in 0..10 {
+        for col in 0..self.next_deal_len() {
             let Some(mut card) = self.stock.pop() else {
                 return false;
             };
@@ -104,7 +139,8 @@ impl SpiderGame {
     }
 
     pub fn can_move_run(&self, src: usize, start: usize, dst: usize) -> bool {
-        if src == dst || src >= self.tableau.len() || dst >= self.tableau.len() {
+        let columns = self.rule_set.columns();
+        if src == dst || src >= columns || dst >= columns {
             return false;
         }
 
@@ -113,7 +149,7 @@ impl SpiderGame {
             return false;
         }
 
-        if !is_descending_run(&source[start..]) {
+        if !self.rule_set.is_movable_run(&source[start..]) {
             return false;
         }
 
@@ -155,7 +191,7 @@ impl SpiderGame {
             .collect();
 
         let mut cards: Vec<Card> = self
-            .tableau
+            .tableau()
             .iter()
             .flat_map(|pile| pile.iter().copied())
             .collect();
@@ -197,6 +233,7 @@ impl SpiderGame {
 
     pub fn encode_for_session(&self) -> String {
         let parts = [
+            format!("rules={}", self.rule_set.session_tag()),
             format!("mode={}", self.suit_mode.session_tag()),
             format!("done={}", self.completed_runs),
             format!(
@@ -225,9 +262,14 @@ impl SpiderGame {
             fields.insert(key, value);
         }
 
+        // Sessions saved before the other rule sets existed are two-deck Spider.
+        let rule_set = match fields.get("rules") {
+            Some(raw) => SpiderRuleSet::from_session_tag(raw)?,
+            None => SpiderRuleSet::Spider,
+        };
         let suit_mode = SpiderSuitMode::from_session_tag(fields.get("mode")?)?;
         let completed_runs = fields.get("done")?.parse::<usize>().ok()?;
-        if completed_runs > 8 {
+        if completed_runs > rule_set.goal_runs() {
             return None;
         }
         let completed_run_suits = match fields.get("runs") {
@@ -249,12 +291,19 @@ impl SpiderGame {
             decode_spider_pile(fields.get("t9")?)?,
         ];
 
+        if tableau[rule_set.columns()..]
+            .iter()
+            .any(|pile| !pile.is_empty())
+        {
+            return None;
+        }
         let tableau_count: usize = tableau.iter().map(Vec::len).sum();
-        if stock.len() + tableau_count + (completed_runs * 13) != 104 {
+        if stock.len() + tableau_count + (completed_runs * 13) != rule_set.decks() * 52 {
             return None;
         }
 
         Some(Self {
+            rule_set,
             suit_mode,
             stock,
             tableau,
@@ -270,17 +319,86 @@ impl SpiderGame {
     }
 
     fn remove_completed_runs(&mut self) {
-        for col in 0..self.tableau.len() {
-            while let Some(suit) = complete_suited_run_suit(&self.tableau[col]) {
-                let new_len = self.tableau[col]
-                    .len()
-                    .checked_sub(13)
-                    .expect("complete run requires at least 13 cards");
-                self.tableau[col].truncate(new_len);
-                self.completed_runs += 1;
-                self.completed_run_suits.push(suit);
-                self.flip_top_if_needed(col);
+        let columns = self.rule_set.columns();
+        self.completed_runs += remove_completed_suited_runs(
+            &mut self.tableau[..columns],
+            &mut self.completed_run_suits,
+        );
+    }
+}
+
+impl SpiderRuleSet {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Spider => "Spider",
+            Self::Spiderette => "Spiderette",
+            Self::WillOTheWisp => "Will o' the Wisp",
+            Self::Tarantula => "Tarantula",
+        }
+    }
+
+    pub fn decks(self) -> usize {
+        match self {
+            Self::Spider | Self::Tarantula => 2,
+            Self::Spiderette | Self::WillOTheWisp => 1,
+        }
+    }
+
+    pub fn columns(self) -> usize {
+        match self {
+            Self::Spider | Self::Tarantula => 10,
+            Self::Spiderette | Self::WillOTheWisp => 7,
+        }
+    }
+
+    /// Completed K→A runs needed to win: every card in the deal.
+    pub fn goal_runs(self) -> usize {
+        self.decks() * 4
+    }
+
+    fn dealt_column_len(self, col: usize) -> usize {
+        match self {
+            Self::Spider | Self::Tarantula => {
+                if col < 4 {
+                    6
+                } else {
+                    5
+                }
             }
+            Self::Spiderette => col + 1,
+            Self::WillOTheWisp => 3,
+        }
+    }
+
+    /// Whether `cards` may be picked up together. Runs must still be one suit
+    /// to clear, whatever moves together.
+    pub fn is_movable_run(self, cards: &[Card]) -> bool {
+        match self {
+            Self::Spider | Self::Spiderette | Self::WillOTheWisp => is_descending_run(cards),
+            Self::Tarantula => cards.windows(2).all(|pair| {
+                let a = pair[0];
+                let b = pair[1];
+                a.face_up && b.face_up && a.color_red() == b.color_red() && a.rank == b.rank + 1
+            }),
+        }
+    }
+
+    pub fn session_tag(self) -> &'static str {
+        match self {
+            Self::Spider => "spider",
+            Self::Spiderette => "spiderette",
+            Self::WillOTheWisp => "wisp",
+            Self::Tarantula => "tarantula",
+        }
+    }
+
+    pub fn from_session_tag(value: &str) -> Option<Self> {
+        match value {
+            "spider" => Some(Self::Spider),
+            "spiderette" => Some(Self::Spiderette),
+            "wisp" => Some(Self::WillOTheWisp),
+            "tarantula" => Some(Self::Tarantula),
+            _ => None,
         }
     }
 }
@@ -305,7 +423,7 @@ impl SpiderSuitMode {
         }
     }
 
-    fn session_tag(self) -> &'static str {
+    pub fn session_tag(self) -> &'static str {
         match self {
             Self::One => "1",
             Self::Two => "2",
@@ -314,7 +432,7 @@ impl SpiderSuitMode {
         }
     }
 
-    fn from_session_tag(value: &str) -> Option<Self> {
+    pub fn from_session_tag(value: &str) -> Option<Self> {
         match value {
             "1" => Some(Self::One),
             "2" => Some(Self::Two),
@@ -325,6 +443,25 @@ impl SpiderSuitMode {
     }
 }
 
+impl SpiderGame {
+    /// A Spider position; card counts are the caller's to check.
+    pub(super) fn from_board(
+        suit_mode: SpiderSuitMode,
+        stock: Vec<Card>,
+        tableau: [Vec<Card>; 10],
+        completed_run_suits: Vec<Suit>,
+    ) -> Self {
+        Self {
+            rule_set: SpiderRuleSet::Spider,
+            suit_mode,
+            stock,
+            tableau,
+            completed_runs: completed_run_suits.len(),
+            completed_run_suits,
+        }
+    }
+}
+
 #[cfg(test)]
 impl SpiderGame {
     pub(crate) fn debug_new(
@@ -332,8 +469,25 @@ impl SpiderGame {
         stock: Vec<Card>,
         tableau: [Vec<Card>; 10],
         completed_runs: usize,
+    ) -> Self {
+        Self::debug_new_with_rules(
+            SpiderRuleSet::Spider,
+            suit_mode,
+            stock,
+            tableau,
+            completed_runs,
+        )
+    }
+
+    pub(crate) fn debug_new_with_rules(
+        rule_set: SpiderRuleSet,
+        suit_mode: SpiderSuitMode,
+        stock: Vec<Card>,
+        tableau: [Vec<Card>; 10],
+        completed_runs: usize,
     ) -> Self {
         Self {
+            rule_set,
             suit_mode,
             stock,
             tableau,
@@ -343,11 +497,17 @@ impl SpiderGame {
     }
 }
 
-fn spider_deck(suit_mode: SpiderSuitMode) -> Vec<Card> {
-    let mut deck = Vec::with_capacity(104);
+pub(super) fn spider_deck(suit_mode: SpiderSuitMode) -> Vec<Card> {
+    spider_deck_for_decks(suit_mode, 2)
+}
+
+/// `decks` full packs' worth of cards spread over the suit mode's suits.
+fn spider_deck_for_decks(suit_mode: SpiderSuitMode, decks: usize) -> Vec<Card> {
+    let card_count = decks * 52;
+    let mut deck = Vec::with_capacity(card_count);
     match suit_mode {
         SpiderSuitMode::One => {
-            for _ in 0..8 {
+            for _ in 0..decks * 4 {
                 for rank in 1..=13 {
                     deck.push(Card {
                         suit: Suit::Spades,
@@ -358,7 +518,7 @@ fn spider_deck(suit_mode: SpiderSuitMode) -> Vec<Card> {
             }
         }
         SpiderSuitMode::Two => {
-            for _ in 0..4 {
+            for _ in 0..decks * 2 {
                 for suit in [Suit::Spades, Suit::Hearts] {
                     for rank in 1..=13 {
                         deck.push(Card {
@@ -371,7 +531,7 @@ fn spider_deck(suit_mode: SpiderSuitMode) -> Vec<Card> {
             }
         }
         SpiderSuitMode::Four => {
-            for _ in 0..2 {
+            for _ in 0..decks {
                 for suit in Suit::ALL {
                     for rank in 1..=13 {
                         deck.push(Card {
@@ -386,8 +546,8 @@ fn spider_deck(suit_mode: SpiderSuitMode) -> Vec<Card> {
         SpiderSuitMode::Three => {
             let suits = [Suit::Spades, Suit::Hearts, Suit::Clubs];
             let full_set = suits.len() * 13;
-            let base_copies = 104 / full_set;
-            let remainder = 104 % full_set;
+            let base_copies = card_count / full_set;
+            let remainder = card_count % full_set;
 
             for _ in 0..base_copies {
                 for suit in suits {
@@ -422,6 +582,30 @@ fn is_descending_run(cards: &[Card]) -> bool {
     })
 }
 
+/// Lifts every finished K→A suited run off the columns, recording its suit
+/// and turning up the card underneath. Returns how many runs were removed.
+pub(super) fn remove_completed_suited_runs(
+    tableau: &mut [Vec<Card>],
+    completed_run_suits: &mut Vec<Suit>,
+) -> usize {
+    let mut removed = 0;
+    for pile in tableau.iter_mut() {
+        while let Some(suit) = complete_suited_run_suit(pile) {
+            let new_len = pile
+                .len()
+                .checked_sub(13)
+                .expect("complete run requires at least 13 cards");
+            pile.truncate(new_len);
+            removed += 1;
+            completed_run_suits.push(suit);
+            if let Some(card) = pile.last_mut() {
+                card.face_up = true;
+            }
+        }
+    }
+    removed
+}
+
 fn complete_suited_run_suit(pile: &[Card]) -> Option<Suit> {
     if pile.len() < 13 {
         return None;
@@ -447,7 +631,7 @@ fn complete_suited_run_suit(pile: &[Card]) -> Option<Suit> {
     }
 }
 
-fn encode_spider_pile(cards: &[Card]) -> String {
+pub(super) fn encode_spider_pile(cards: &[Card]) -> String {
     if cards.is_empty() {
         return "-".to_string();
     }
@@ -467,7 +651,7 @@ fn encode_spider_pile(cards: &[Card]) -> String {
         .join(".")
 }
 
-fn decode_spider_pile(encoded: &str) -> Option<Vec<Card>> {
+pub(super) fn decode_spider_pile(encoded: &str) -> Option<Vec<Card>> {
     if encoded == "-" {
         return Some(Vec::new());
     }
@@ -503,7 +687,27 @@ fn decode_spider_pile(encoded: &str) -> Option<Vec<Card>> {
     Some(cards)
 }
 
-fn encode_spider_completed_run_suits(suits: &[Suit]) -> String {
+/// Fixed slots that may be empty, written as one card token or `-` each.
+pub(super) fn encode_spider_slots(slots: &[Option<Card>]) -> String {
+    slots
+        .iter()
+        .map(|slot| encode_spider_pile(slot.as_slice()))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+pub(super) fn decode_spider_slots(encoded: &str) -> Option<Vec<Option<Card>>> {
+    encoded
+        .split('.')
+        .map(|token| match decode_spider_pile(token)?[..] {
+            [] => Some(None),
+            [card] => Some(Some(card)),
+            _ => None,
+        })
+        .collect()
+}
+
+pub(super) fn encode_spider_completed_run_suits(suits: &[Suit]) -> String {
     if suits.is_empty() {
         return "-".to_string();
     }
@@ -519,7 +723,10 @@ fn encode_spider_completed_run_suits(suits: &[Suit]) -> String {
         .collect()
 }
 
-fn decode_spider_completed_run_suits(encoded: &str, completed_runs: usize) -> Option<Vec<Suit>> {
+pub(super) fn decode_spider_completed_run_suits(
+    encoded: &str,
+    completed_runs: usize,
+) -> Option<Vec<Suit>> {
     if encoded == "-" {
         return if completed_runs == 0 {
             Some(Vec::new())
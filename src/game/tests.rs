@@ -12,6 +12,9 @@ fn card(suit: Suit, rank: u8, face_up: bool) -> Card {
 fn empty_game() -> KlondikeGame {
     KlondikeGame {
         draw_mode: DrawMode::One,
+        pass_limit: KlondikePassLimit::Unlimited,
+        recycles_used: 0,
+        thoughtful: false,
         stock: Vec::new(),
         waste: Vec::new(),
         foundations: std::array::from_fn(|_| Vec::new()),
@@ -71,6 +74,36 @@ fn draw_recycles_waste_back_to_stock_face_down() {
     assert!(game.stock.iter().all(|c| !c.face_up));
 }
 
+#[test]
+fn single_pass_stock_refuses_to_recycle_the_waste() {
+    let mut game = empty_game();
+    game.set_pass_limit(KlondikePassLimit::Single);
+    game.stock.push(card(Suit::Hearts, 2, false));
+
+    assert_eq!(game.draw_or_recycle(), DrawResult::DrewFromStock);
+    assert!(game.passes_exhausted());
+    assert!(!game.can_draw_or_recycle());
+    assert_eq!(game.draw_or_recycle(), DrawResult::NoOp);
+    assert_eq!(game.waste.len(), 1);
+    assert!(crate::engine::hinting::enumerate_hint_candidates(&game)
+        .iter()
+        .all(|hint| !matches!(hint.hint_move, Some(crate::engine::moves::HintMove::Draw))));
+}
+
+#[test]
+fn three_pass_stock_recycles_twice() {
+    let mut game = empty_game();
+    game.set_pass_limit(KlondikePassLimit::Three);
+    game.waste.push(card(Suit::Hearts, 2, true));
+
+    for _ in 0..2 {
+        assert_eq!(game.draw_or_recycle(), DrawResult::RecycledWaste);
+        assert_eq!(game.draw_or_recycle(), DrawResult::DrewFromStock);
+    }
+    assert_eq!(game.recycles_used(), 2);
+    assert_eq!(game.draw_or_recycle(), DrawResult::NoOp);
+}
+
 #[test]
 fn draw_three_moves_up_to_three_cards_from_stock() {
     let mut game = empty_game();
@@ -214,6 +247,153 @@ fn winnability_marks_completed_game_as_won() {
     assert_eq!(result.explored_states, 1);
 }
 
+#[test]
+fn winnability_follows_the_pass_limit() {
+    // Clubs stop at ten with the jack buried under the queen in the waste:
+    // only a recycle lets the jack come up first.
+    let mut game = empty_game();
+    for suit in Suit::ALL {
+        let top = if suit == Suit::Clubs { 10 } else { 13 };
+        for rank in 1..=top {
+            game.foundations[suit.foundation_index()].push(card(suit, rank, true));
+        }
+    }
+    game.tableau[0].push(card(Suit::Clubs, 13, true));
+    game.waste.push(card(Suit::Clubs, 11, true));
+    game.waste.push(card(Suit::Clubs, 12, true));
+
+    let mut three_pass = game.clone();
+    three_pass.set_pass_limit(KlondikePassLimit::Three);
+    game.set_pass_limit(KlondikePassLimit::Single);
+
+    let single = game.analyze_winnability(1_000);
+    assert!(!single.winnable);
+    assert!(!single.hit_state_limit);
+    assert!(!game.guided_winnability(1_000).winnable);
+    assert!(three_pass.analyze_winnability(1_000).winnable);
+    assert!(three_pass.guided_winnability(1_000).winnable);
+}
+
+#[test]
+fn thoughtful_solver_proves_a_locked_position_unwinnable() {
+    // The heart ace is buried under the two and every other heart top is
+    // red on red; the full foundations cannot reach a column either.
+    let mut game = empty_game();
+    for suit in [Suit::Clubs, Suit::Diamonds, Suit::Spades] {
+        for rank in 1..=13 {
+            game.foundations[suit.foundation_index()].push(card(suit, rank, true));
+        }
+    }
+    game.tableau[0].push(card(Suit::Hearts, 1, false));
+    game.tableau[0].push(card(Suit::Hearts, 2, true));
+    for (col, rank) in (1..6).zip(3..=7) {
+        game.tableau[col].push(card(Suit::Hearts, rank, true));
+    }
+    for rank in (8..=13).rev() {
+        game.tableau[6].push(card(Suit::Hearts, rank, rank == 8));
+    }
+
+    let result = game.solve_thoughtful(1_000);
+    assert_eq!(result.verdict, ThoughtfulVerdict::Unwinnable);
+    assert!(result.line.is_empty());
+}
+
+#[test]
+fn thoughtful_solver_takes_cards_back_off_the_foundations() {
+    // Only a black three pulled down from the foundations frees the heart
+    // ace under the two.
+    let mut game = empty_game();
+    for suit in [Suit::Clubs, Suit::Diamonds, Suit::Spades] {
+        for rank in 1..=13 {
+            game.foundations[suit.foundation_index()].push(card(suit, rank, true));
+        }
+    }
+    game.tableau[0].push(card(Suit::Hearts, 1, false));
+    game.tableau[0].push(card(Suit::Hearts, 2, true));
+    for rank in (3..=13).rev() {
+        game.tableau[1].push(card(Suit::Hearts, rank, rank == 3));
+    }
+
+    let result = game.solve_thoughtful(200_000);
+    assert_eq!(result.verdict, ThoughtfulVerdict::Winnable);
+    assert!(result
+        .line
+        .iter()
+        .any(|step| matches!(step, ThoughtfulMove::FoundationToTableau { .. })));
+    assert!(game.thoughtful_line_wins(&result.line));
+}
+
+#[test]
+fn thoughtful_solver_follows_the_pass_limit() {
+    // The heart ace sits under the two in the waste and every column is
+    // blocked red on red, so only a recycle brings the ace up first.
+    let mut game = empty_game();
+    for suit in [Suit::Clubs, Suit::Diamonds, Suit::Spades] {
+        for rank in 1..=13 {
+            game.foundations[suit.foundation_index()].push(card(suit, rank, true));
+        }
+    }
+    game.waste.push(card(Suit::Hearts, 1, true));
+    game.waste.push(card(Suit::Hearts, 2, true));
+    for (col, rank) in (0..6).zip(3..=8) {
+        game.tableau[col].push(card(Suit::Hearts, rank, true));
+    }
+    for rank in (9..=13).rev() {
+        game.tableau[6].push(card(Suit::Hearts, rank, rank == 9));
+    }
+
+    let mut three_pass = game.clone();
+    three_pass.set_pass_limit(KlondikePassLimit::Three);
+    game.set_pass_limit(KlondikePassLimit::Single);
+
+    assert_eq!(
+        game.solve_thoughtful(1_000).verdict,
+        ThoughtfulVerdict::Unwinnable
+    );
+    let result = three_pass.solve_thoughtful(1_000);
+    assert_eq!(result.verdict, ThoughtfulVerdict::Winnable);
+    assert!(three_pass.thoughtful_line_wins(&result.line));
+}
+
+#[test]
+fn thoughtful_solver_handles_deal_three_talons() {
+    // A Deal 3 waste that is not a multiple of three never returns to its
+    // starting split, so the talon walk has to stop on the first repeat.
+    for seed in [1_u64, 2, 7] {
+        let mut game = KlondikeGame::new_with_seed(seed);
+        game.set_draw_mode(DrawMode::Three);
+        game.set_thoughtful(true);
+        let result = game.solve_thoughtful(20_000);
+        if result.verdict == ThoughtfulVerdict::Winnable {
+            assert!(game.thoughtful_line_wins(&result.line));
+        }
+    }
+
+    let mut game = KlondikeGame::new_with_seed(1);
+    game.set_draw_mode(DrawMode::Three);
+    game.set_thoughtful(true);
+    let canceled = std::sync::atomic::AtomicBool::new(true);
+    assert!(game
+        .solve_thoughtful_cancelable(20_000, &canceled)
+        .is_none());
+}
+
+#[test]
+fn thoughtful_solver_settles_fresh_deals_or_reports_the_budget() {
+    for seed in [3_u64, 11, 29] {
+        let mut game = KlondikeGame::new_with_seed(seed);
+        game.set_thoughtful(true);
+        let result = game.solve_thoughtful(50_000);
+        match result.verdict {
+            ThoughtfulVerdict::Winnable => assert!(game.thoughtful_line_wins(&result.line)),
+            ThoughtfulVerdict::Unwinnable => assert!(result.explored_states < 50_000),
+            ThoughtfulVerdict::Unknown => assert_eq!(result.explored_states, 50_000),
+        }
+    }
+    let starved = KlondikeGame::new_with_seed(3).solve_thoughtful(1);
+    assert_eq!(starved.verdict, ThoughtfulVerdict::Unknown);
+}
+
 #[test]
 fn winnability_honors_state_limit() {
     let game = KlondikeGame::new_with_seed(7);
@@ -509,6 +689,63 @@ fn spider_session_codec_accepts_legacy_payload_without_run_suits() {
     assert_eq!(decoded.completed_run_suits(), &[Suit::Spades]);
 }
 
+#[test]
+fn unlimited_passes_come_back_round_to_the_same_position() {
+    let game = KlondikeGame::new_with_seed(41);
+    let mut cycled = game.clone();
+    while cycled.draw_or_recycle() != DrawResult::RecycledWaste {}
+    assert_eq!(cycled.recycles_used(), 0);
+    assert_eq!(cycled, game);
+
+    let mut visited = std::collections::HashSet::new();
+    visited.insert(game.clone());
+    assert!(!visited.insert(cycled));
+
+    let legacy = game.encode_for_session().replace("rc=0", "rc=3");
+    let decoded = KlondikeGame::decode_from_session(&legacy).expect("decode klondike session");
+    assert_eq!(decoded, game);
+}
+
+#[test]
+fn klondike_session_codec_round_trip_keeps_the_pass_limit() {
+    let mut game = KlondikeGame::new_with_seed(41);
+    game.set_pass_limit(KlondikePassLimit::Three);
+    while game.draw_or_recycle() != DrawResult::RecycledWaste {}
+    let encoded = game.encode_for_session();
+    let decoded = KlondikeGame::decode_from_session(&encoded).expect("decode klondike session");
+    assert_eq!(decoded.pass_limit(), KlondikePassLimit::Three);
+    assert_eq!(decoded.recycles_used(), 1);
+    assert_eq!(decoded, game);
+
+    let legacy: String = KlondikeGame::new_with_seed(41)
+        .encode_for_session()
+        .split(';')
+        .filter(|part| !part.starts_with("passes=") && !part.starts_with("rc="))
+        .collect::<Vec<_>>()
+        .join(";");
+    let decoded = KlondikeGame::decode_from_session(&legacy).expect("decode legacy session");
+    assert_eq!(decoded.pass_limit(), KlondikePassLimit::Unlimited);
+}
+
+#[test]
+fn klondike_session_codec_round_trip_keeps_thoughtful() {
+    let mut game = KlondikeGame::new_with_seed(41);
+    game.set_thoughtful(true);
+    let decoded = KlondikeGame::decode_from_session(&game.encode_for_session())
+        .expect("decode klondike session");
+    assert!(decoded.is_thoughtful());
+    assert_eq!(decoded, game);
+
+    let legacy: String = game
+        .encode_for_session()
+        .split(';')
+        .filter(|part| !part.starts_with("thoughtful="))
+        .collect::<Vec<_>>()
+        .join(";");
+    let decoded = KlondikeGame::decode_from_session(&legacy).expect("decode legacy session");
+    assert!(!decoded.is_thoughtful());
+}
+
 #[test]
 fn spider_session_codec_round_trip_preserves_state() {
     let mut game = SpiderGame::new_with_seed_and_mode(777, SpiderSuitMode::Two);
@@ -529,6 +766,90 @@ fn spider_session_codec_round_trip_preserves_three_suit_mode() {
     assert_eq!(decoded, game);
 }
 
+#[test]
+fn spiderette_deals_one_deck_into_seven_klondike_columns() {
+    let game =
+        SpiderGame::new_with_seed_and_rules(11, SpiderSuitMode::Four, SpiderRuleSet::Spiderette);
+    assert_eq!(game.tableau().len(), 7);
+    for (col, pile) in game.tableau().iter().enumerate() {
+        assert_eq!(pile.len(), col + 1);
+        assert_eq!(pile.iter().filter(|card| card.face_up).count(), 1);
+    }
+    assert_eq!(game.stock_len(), 24);
+    assert_eq!(game.rule_set().goal_runs(), 4);
+}
+
+#[test]
+fn will_o_the_wisp_stock_ends_with_a_short_deal() {
+    let mut game =
+        SpiderGame::new_with_seed_and_rules(11, SpiderSuitMode::Two, SpiderRuleSet::WillOTheWisp);
+    assert!(game.tableau().iter().all(|pile| pile.len() == 3));
+    assert_eq!(game.stock_len(), 31);
+    for _ in 0..4 {
+        assert_eq!(game.next_deal_len(), 7);
+        assert!(game.deal_from_stock());
+    }
+    assert_eq!(game.next_deal_len(), 3);
+    assert!(game.deal_from_stock());
+    assert_eq!(game.stock_len(), 0);
+    let lengths: Vec<usize> = game.tableau().iter().map(Vec::len).collect();
+    assert_eq!(lengths, vec![8, 8, 8, 7, 7, 7, 7]);
+}
+
+#[test]
+fn tarantula_moves_runs_of_one_colour() {
+    let mut tableau: [Vec<Card>; 10] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = vec![card(Suit::Spades, 9, true), card(Suit::Clubs, 8, true)];
+    tableau[1] = vec![card(Suit::Hearts, 10, true)];
+    tableau[2] = vec![card(Suit::Hearts, 9, true), card(Suit::Spades, 8, true)];
+    let spider = SpiderGame::debug_new(SpiderSuitMode::Four, Vec::new(), tableau.clone(), 0);
+    let tarantula = SpiderGame::debug_new_with_rules(
+        SpiderRuleSet::Tarantula,
+        SpiderSuitMode::Four,
+        Vec::new(),
+        tableau,
+        0,
+    );
+
+    assert!(!spider.can_move_run(0, 0, 1));
+    assert!(tarantula.can_move_run(0, 0, 1));
+    assert!(!tarantula.can_move_run(2, 0, 1));
+}
+
+#[test]
+fn spiderette_is_won_after_four_runs() {
+    let tableau: [Vec<Card>; 10] = std::array::from_fn(|_| Vec::new());
+    let game = SpiderGame::debug_new_with_rules(
+        SpiderRuleSet::Spiderette,
+        SpiderSuitMode::Four,
+        Vec::new(),
+        tableau,
+        4,
+    );
+    assert!(game.is_won());
+    assert!(!game.can_move_run(0, 0, 7));
+}
+
+#[test]
+fn spider_session_codec_round_trip_preserves_rule_set() {
+    let mut game =
+        SpiderGame::new_with_seed_and_rules(321, SpiderSuitMode::One, SpiderRuleSet::Spiderette);
+    let _ = game.deal_from_stock();
+    let encoded = game.encode_for_session();
+    let decoded = SpiderGame::decode_from_session(&encoded).expect("decode spiderette session");
+    assert_eq!(decoded.rule_set(), SpiderRuleSet::Spiderette);
+    assert_eq!(decoded, game);
+
+    let legacy: String = SpiderGame::new_with_seed(321)
+        .encode_for_session()
+        .split(';')
+        .filter(|part| !part.starts_with("rules="))
+        .collect::<Vec<_>>()
+        .join(";");
+    let decoded = SpiderGame::decode_from_session(&legacy).expect("decode legacy spider session");
+    assert_eq!(decoded.rule_set(), SpiderRuleSet::Spider);
+}
+
 // Spider rollout scaffolding stubs (see SPIDER_TEST_PLAN.md).
 // Keep ignored until each case is implemented.
 
@@ -811,6 +1132,78 @@ fn freecell_seeded_setup_respects_card_count_modes() {
     }
 }
 
+#[test]
+fn freecell_microsoft_deal_numbers_match_the_classic_game() {
+    fn rows(game: &FreecellGame) -> Vec<String> {
+        let label = |card: Card| {
+            let rank = b"A23456789TJQK"[usize::from(card.rank - 1)] as char;
+            let suit = match card.suit {
+                Suit::Clubs => 'C',
+                Suit::Diamonds => 'D',
+                Suit::Hearts => 'H',
+                Suit::Spades => 'S',
+            };
+            format!("{rank}{suit}")
+        };
+        (0..7)
+            .map(|row| {
+                (0..8)
+                    .filter_map(|col| game.tableau_card(col, row))
+                    .map(label)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
+    }
+
+    let deal_one = FreecellGame::new_with_seed(microsoft_deal_seed(1).expect("game 1"));
+    assert_eq!(
+        rows(&deal_one),
+        [
+            "JD 2D 9H JC 5D 7H 7C 5H",
+            "KD KC 9S 5S AD QC KH 3H",
+            "2S KS 9D QD JS AS AH 3C",
+            "4C 5C TS QH 4H AC 4D 7S",
+            "3S TD 4S TH 8H 2C JH 7D",
+            "6D 8S 8D QS 6C 3D 8C TC",
+            "6S 9C 2H 6H",
+        ]
+    );
+    let deal_617 = FreecellGame::new_with_seed(microsoft_deal_seed(617).expect("game 617"));
+    assert_eq!(
+        rows(&deal_617),
+        [
+            "7D AD 5C 3S 5S 8C 2D AH",
+            "TD 7S QD AC 6D 8H AS KH",
+            "TH QC 3H 9D 6S 8D 3D TC",
+            "KD 5H 9S 3C 8S 7H 4D JS",
+            "4C QS 9C 9H 7C 6H 2C 2S",
+            "4S TS 2H 5D JC 6C JH QH",
+            "JD KS KC 4H",
+        ]
+    );
+
+    // Baker's Game shares the deal; other layouts and short decks shuffle.
+    let bakers = FreecellGame::new_with_seed_for_layout(
+        FreecellLayout::BakersGame,
+        microsoft_deal_seed(1).expect("game 1"),
+    );
+    assert_eq!(bakers.tableau(), deal_one.tableau());
+    let seed = microsoft_deal_seed(MICROSOFT_DEAL_MAX).expect("last extended game");
+    assert_eq!(microsoft_deal_number(seed), Some(MICROSOFT_DEAL_MAX));
+    let short = FreecellGame::new_with_seed_and_card_count(seed, FreecellCardCountMode::TwentySix);
+    assert_eq!(short.tableau().iter().map(Vec::len).sum::<usize>(), 26);
+    let extended = FreecellGame::new_with_seed(seed);
+    let mut cards: Vec<Card> = extended.tableau().iter().flatten().copied().collect();
+    cards.sort_by_key(|card| (card.suit.foundation_index(), card.rank));
+    cards.dedup();
+    assert_eq!(cards.len(), 52);
+
+    assert_eq!(microsoft_deal_seed(0), None);
+    assert_eq!(microsoft_deal_seed(MICROSOFT_DEAL_MAX + 1), None);
+    assert_eq!(microsoft_deal_number(11982), None);
+}
+
 #[test]
 fn freecell_cell_count_reduction_rejects_when_too_many_cells_are_occupied() {
     let mut freecells = [None; FREECELL_MAX_CELL_COUNT as usize];
@@ -1012,3 +1405,1614 @@ fn freecell_loss_detection_when_no_legal_moves() {
     assert!(!game.has_legal_moves());
     assert!(game.is_lost());
 }
+
+#[test]
+fn eight_off_seeded_setup_fills_half_the_cells() {
+    let game = FreecellGame::new_with_seed_for_layout(FreecellLayout::EightOff, 808);
+    assert_eq!(
+        game,
+        FreecellGame::new_with_seed_for_layout(FreecellLayout::EightOff, 808)
+    );
+    assert_eq!(game.layout(), FreecellLayout::EightOff);
+    assert_eq!(game.tableau().len(), 8);
+    assert!(game.tableau().iter().all(|pile| pile.len() == 6));
+    assert_eq!(game.freecell_count(), 8);
+    let occupied: Vec<bool> = game.freecells().iter().map(Option::is_some).collect();
+    assert_eq!(
+        occupied,
+        vec![true, true, true, true, false, false, false, false]
+    );
+}
+
+#[test]
+fn seahaven_towers_seeded_setup_deals_ten_columns_and_two_cells() {
+    let game = FreecellGame::new_with_seed_for_layout(FreecellLayout::SeahavenTowers, 4);
+    assert_eq!(game.layout(), FreecellLayout::SeahavenTowers);
+    assert_eq!(game.tableau().len(), 10);
+    assert!(game.tableau().iter().all(|pile| pile.len() == 5));
+    assert_eq!(game.freecell_count(), 4);
+    assert_eq!(game.occupied_freecell_cells(), 2);
+    assert!(game.foundations().iter().all(Vec::is_empty));
+}
+
+#[test]
+fn suit_build_layouts_build_in_suit_and_fill_empty_columns_with_kings() {
+    let mut tableau = vec![Vec::new(); 10];
+    tableau[0] = vec![card(Suit::Hearts, 9, true)];
+    tableau[1] = vec![card(Suit::Spades, 10, true)];
+    tableau[2] = vec![card(Suit::Hearts, 10, true)];
+    tableau[3] = vec![card(Suit::Clubs, 13, true), card(Suit::Clubs, 12, true)];
+    tableau[4] = vec![card(Suit::Diamonds, 5, true)];
+    tableau[5] = vec![card(Suit::Diamonds, 13, true)];
+    let mut game = FreecellGame::debug_new_with_layout(
+        FreecellLayout::SeahavenTowers,
+        std::array::from_fn(|_| Vec::new()),
+        &[None; 4],
+        tableau,
+    );
+
+    assert!(!game.can_move_tableau_run_to_tableau(0, 0, 1));
+    assert!(game.can_move_tableau_run_to_tableau(0, 0, 2));
+    assert!(!game.can_move_tableau_run_to_tableau(4, 0, 6));
+    assert!(!game.can_move_tableau_run_to_tableau(3, 1, 6));
+    assert!(game.can_move_tableau_run_to_tableau(3, 0, 6));
+    assert!(game.can_move_tableau_run_to_tableau(5, 0, 6));
+    assert!(game.move_tableau_run_to_tableau(0, 0, 2));
+    assert!(game.rules().is_run(&game.tableau()[2]));
+}
+
+#[test]
+fn kings_only_supermoves_ignore_empty_columns() {
+    let rules = FreecellLayout::SeahavenTowers.rules();
+    assert_eq!(rules.supermove_capacity(2, 0), 3);
+    assert_eq!(rules.supermove_capacity(2, 3), 3);
+    assert_eq!(FreecellRules::CLASSIC.supermove_capacity(2, 1), 6);
+
+    let mut tableau = vec![Vec::new(); 8];
+    tableau[0] = vec![
+        card(Suit::Spades, 9, true),
+        card(Suit::Spades, 8, true),
+        card(Suit::Spades, 7, true),
+    ];
+    tableau[1] = vec![card(Suit::Spades, 10, true)];
+    // Six empty columns, but only the free cells can hold the run's tail.
+    let mut cells = [Some(card(Suit::Hearts, 2, true)); 8];
+    cells[0] = None;
+    let one_cell = FreecellGame::debug_new_with_layout(
+        FreecellLayout::EightOff,
+        std::array::from_fn(|_| Vec::new()),
+        &cells,
+        tableau.clone(),
+    );
+    assert!(!one_cell.can_move_tableau_run_to_tableau(0, 0, 1));
+
+    cells[1] = None;
+    let two_cells = FreecellGame::debug_new_with_layout(
+        FreecellLayout::EightOff,
+        std::array::from_fn(|_| Vec::new()),
+        &cells,
+        tableau,
+    );
+    assert!(two_cells.can_move_tableau_run_to_tableau(0, 0, 1));
+}
+
+#[test]
+fn suit_build_foundation_plays_are_always_safe() {
+    let foundations = std::array::from_fn(|_| Vec::new());
+    let rules = FreecellLayout::EightOff.rules();
+    assert!(rules.is_safe_foundation_play(card(Suit::Hearts, 9, true), [0, 8, 8, 0]));
+    let mut classic_foundations = [0; 4];
+    classic_foundations[Suit::Hearts.foundation_index()] = 8;
+    assert!(!FreecellRules::CLASSIC
+        .is_safe_foundation_play(card(Suit::Hearts, 9, true), classic_foundations));
+    let game =
+        FreecellGame::debug_new_with_layout(FreecellLayout::EightOff, foundations, &[], Vec::new());
+    assert!(game.is_safe_foundation_play(card(Suit::Clubs, 5, true)));
+}
+
+#[test]
+fn freecell_session_codec_round_trip_keeps_the_layout() {
+    for layout in [FreecellLayout::EightOff, FreecellLayout::SeahavenTowers] {
+        let mut game = FreecellGame::new_with_seed_for_layout(layout, 91);
+        let empty_cell = game
+            .freecells()
+            .iter()
+            .position(Option::is_none)
+            .expect("layout leaves a cell free");
+        assert!(game.move_tableau_top_to_freecell(0, empty_cell));
+        let encoded = game.encode_for_session();
+        let decoded = FreecellGame::decode_from_session(&encoded).expect("decode layout session");
+        assert_eq!(decoded, game);
+        assert_eq!(decoded.layout(), layout);
+    }
+
+    let classic = FreecellGame::new_with_seed(91);
+    let legacy = classic
+        .encode_for_session()
+        .split(';')
+        .filter(|part| {
+            !["layout=", "build=", "empty=", "supermoves="]
+                .iter()
+                .any(|key| part.starts_with(key))
+        })
+        .collect::<Vec<_>>()
+        .join(";");
+    let decoded = FreecellGame::decode_from_session(&legacy).expect("decode legacy session");
+    assert_eq!(decoded.layout(), FreecellLayout::FreeCell);
+    assert_eq!(decoded, classic);
+}
+
+#[test]
+fn bakers_game_deals_the_freecell_board_and_builds_in_suit() {
+    let game = FreecellGame::new_with_seed_for_layout(FreecellLayout::BakersGame, 17);
+    assert_eq!(game.freecell_count(), 4);
+    assert!(game.freecells().iter().all(Option::is_none));
+    let lengths: Vec<usize> = game.tableau().iter().map(Vec::len).collect();
+    assert_eq!(lengths, vec![7, 7, 7, 7, 6, 6, 6, 6]);
+    assert_eq!(
+        game.rules(),
+        FreecellRules {
+            build: FreecellBuild::Suit,
+            empty_column: FreecellEmptyColumn::AnyCard,
+            supermoves: true,
+        }
+    );
+
+    let mut tableau = vec![Vec::new(); 8];
+    tableau[0] = vec![card(Suit::Hearts, 9, true), card(Suit::Hearts, 8, true)];
+    tableau[1] = vec![card(Suit::Spades, 10, true)];
+    tableau[2] = vec![card(Suit::Hearts, 10, true)];
+    tableau[3] = vec![card(Suit::Clubs, 4, true)];
+    let game = FreecellGame::debug_new_with_layout(
+        FreecellLayout::BakersGame,
+        std::array::from_fn(|_| Vec::new()),
+        &[None; 4],
+        tableau,
+    );
+    assert!(!game.can_move_tableau_run_to_tableau(0, 0, 1));
+    assert!(game.can_move_tableau_run_to_tableau(0, 0, 2));
+    assert!(game.can_move_tableau_run_to_tableau(3, 0, 4));
+}
+
+#[test]
+fn single_card_rules_move_runs_one_card_at_a_time() {
+    let rules = FreecellRules {
+        supermoves: false,
+        ..FreecellRules::CLASSIC
+    };
+    assert_eq!(rules.supermove_capacity(4, 3), 1);
+
+    let mut tableau = vec![Vec::new(); 8];
+    tableau[0] = vec![card(Suit::Spades, 9, true), card(Suit::Hearts, 8, true)];
+    tableau[1] = vec![card(Suit::Diamonds, 10, true)];
+    tableau[2] = vec![card(Suit::Clubs, 9, true)];
+    let mut game = FreecellGame::debug_new_with_layout(
+        FreecellLayout::FreeCell,
+        std::array::from_fn(|_| Vec::new()),
+        &[None; 4],
+        tableau,
+    );
+    assert!(game.can_move_tableau_run_to_tableau(0, 0, 1));
+
+    game = game.with_rules(rules);
+    assert!(!game.can_move_tableau_run_to_tableau(0, 0, 1));
+    assert!(game.can_move_tableau_run_to_tableau(0, 1, 2));
+    assert!(game.move_tableau_run_to_tableau(0, 1, 2));
+    assert!(game.move_tableau_run_to_tableau(0, 0, 1));
+    assert!(game.move_tableau_run_to_tableau(2, 1, 1));
+    assert_eq!(game.tableau()[1].len(), 3);
+}
+
+#[test]
+fn freecell_session_codec_round_trip_keeps_the_rules() {
+    let rules = FreecellRules {
+        build: FreecellBuild::Suit,
+        empty_column: FreecellEmptyColumn::KingsOnly,
+        supermoves: false,
+    };
+    let game = FreecellGame::new_with_seed(23).with_rules(rules);
+    let encoded = game.encode_for_session();
+    let decoded = FreecellGame::decode_from_session(&encoded).expect("decode ruled session");
+    assert_eq!(decoded.rules(), rules);
+    assert_eq!(decoded, game);
+
+    let corrupt = encoded.replace("supermoves=0", "supermoves=maybe");
+    assert!(FreecellGame::decode_from_session(&corrupt).is_none());
+
+    let legacy = FreecellGame::new_with_seed_for_layout(FreecellLayout::EightOff, 23)
+        .encode_for_session()
+        .split(';')
+        .filter(|part| {
+            !["build=", "empty=", "supermoves="]
+                .iter()
+                .any(|key| part.starts_with(key))
+        })
+        .collect::<Vec<_>>()
+        .join(";");
+    let decoded = FreecellGame::decode_from_session(&legacy).expect("decode pre-rules session");
+    assert_eq!(decoded.rules(), FreecellLayout::EightOff.rules());
+}
+
+#[test]
+fn yukon_seeded_setup_deals_all_cards_to_the_tableau() {
+    let game = YukonGame::new_with_seed(31);
+    assert_eq!(game, YukonGame::new_with_seed(31));
+    assert_eq!(game.rule_set(), YukonRuleSet::Yukon);
+
+    let lengths: Vec<usize> = game.tableau().iter().map(Vec::len).collect();
+    assert_eq!(lengths, vec![1, 6, 7, 8, 9, 10, 11]);
+    for (col, pile) in game.tableau().iter().enumerate() {
+        let face_down = pile.iter().filter(|card| !card.face_up).count();
+        assert_eq!(face_down, col);
+        assert!(pile[col..].iter().all(|card| card.face_up));
+    }
+    assert!(game.foundations().iter().all(Vec::is_empty));
+}
+
+#[test]
+fn yukon_moves_any_face_up_card_with_everything_on_top() {
+    let mut tableau: [Vec<Card>; 7] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = vec![
+        card(Suit::Clubs, 4, false),
+        card(Suit::Hearts, 6, true),
+        card(Suit::Spades, 2, true),
+        card(Suit::Diamonds, 11, true),
+    ];
+    tableau[1] = vec![card(Suit::Clubs, 7, true)];
+    let mut game = YukonGame::debug_new(
+        YukonRuleSet::Yukon,
+        std::array::from_fn(|_| Vec::new()),
+        tableau,
+    );
+
+    // 6H fits on 7C even though the cards above it are out of sequence.
+    assert!(!game.can_move_run(0, 0, 1));
+    assert!(game.move_run(0, 1, 1));
+    assert_eq!(game.tableau()[1].len(), 4);
+    assert_eq!(game.tableau()[0].len(), 1);
+    assert!(game.tableau()[0][0].face_up);
+}
+
+#[test]
+fn yukon_empty_column_takes_kings_only() {
+    let mut tableau: [Vec<Card>; 7] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = vec![
+        card(Suit::Clubs, 4, true),
+        card(Suit::Hearts, 13, true),
+        card(Suit::Spades, 12, true),
+    ];
+    tableau[1] = vec![card(Suit::Clubs, 1, true)];
+    let mut game = YukonGame::debug_new(
+        YukonRuleSet::Yukon,
+        std::array::from_fn(|_| Vec::new()),
+        tableau,
+    );
+
+    assert!(!game.can_move_run(0, 2, 2));
+    assert!(game.move_tableau_top_to_foundation(1));
+    assert!(game.move_run(0, 1, 1));
+    assert_eq!(game.tableau()[1].len(), 2);
+}
+
+#[test]
+fn russian_rule_set_builds_down_in_suit() {
+    let mut tableau: [Vec<Card>; 7] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = vec![card(Suit::Hearts, 6, true)];
+    tableau[1] = vec![card(Suit::Clubs, 7, true)];
+    tableau[2] = vec![card(Suit::Hearts, 7, true)];
+    let yukon = YukonGame::debug_new(
+        YukonRuleSet::Yukon,
+        std::array::from_fn(|_| Vec::new()),
+        tableau.clone(),
+    );
+    let russian = YukonGame::debug_new(
+        YukonRuleSet::Russian,
+        std::array::from_fn(|_| Vec::new()),
+        tableau,
+    );
+
+    assert!(yukon.can_move_run(0, 0, 1));
+    assert!(!yukon.can_move_run(0, 0, 2));
+    assert!(!russian.can_move_run(0, 0, 1));
+    assert!(russian.can_move_run(0, 0, 2));
+}
+
+#[test]
+fn yukon_session_codec_round_trip_preserves_rule_set() {
+    let mut game = YukonGame::new_with_seed_and_rules(404, YukonRuleSet::Russian);
+    let _ = (0..7).any(|src| {
+        (0..game.tableau()[src].len()).any(|start| (0..7).any(|dst| game.move_run(src, start, dst)))
+    });
+    let encoded = game.encode_for_session();
+    let decoded = YukonGame::decode_from_session(&encoded).expect("decode yukon session");
+    assert_eq!(decoded, game);
+    assert_eq!(decoded.rule_set(), YukonRuleSet::Russian);
+    assert!(YukonGame::decode_from_session("rules=yukon;f0=-").is_none());
+}
+
+fn pyramid_slots(cards: &[(usize, Card)]) -> [Option<Card>; PYRAMID_SLOTS] {
+    let mut slots = [None; PYRAMID_SLOTS];
+    for (slot, card) in cards {
+        slots[*slot] = Some(*card);
+    }
+    slots
+}
+
+#[test]
+fn pyramid_seeded_setup_exposes_only_the_bottom_row() {
+    let game = PyramidGame::new_with_seed(32);
+    assert_eq!(game, PyramidGame::new_with_seed(32));
+    assert_eq!(game.pyramid_remaining(), PYRAMID_SLOTS);
+    assert_eq!(game.stock_len(), 24);
+    assert!(game.waste().is_empty());
+    assert!(game.pyramid().iter().flatten().all(|card| card.face_up));
+
+    let exposed: Vec<usize> = (0..PYRAMID_SLOTS)
+        .filter(|&slot| game.is_exposed(slot))
+        .collect();
+    assert_eq!(exposed, (21..28).collect::<Vec<_>>());
+    assert_eq!(PyramidGame::covering_slots(0), Some((1, 2)));
+    assert_eq!(PyramidGame::covering_slots(21), None);
+}
+
+#[test]
+fn pyramid_card_pairs_with_the_card_resting_on_it() {
+    let pyramid = pyramid_slots(&[
+        (15, card(Suit::Hearts, 6, true)),
+        (21, card(Suit::Clubs, 7, true)),
+    ]);
+    let game = PyramidGame::debug_new(pyramid, Vec::new(), vec![card(Suit::Spades, 7, true)], 0);
+
+    assert!(!game.is_exposed(15));
+    assert!(game.can_remove_pair(PyramidCardRef::Pyramid(15), PyramidCardRef::Pyramid(21)));
+    assert!(!game.can_remove_pair(PyramidCardRef::Pyramid(15), PyramidCardRef::Waste));
+
+    let mut game = game;
+    assert!(game.apply_move(PyramidMove::RemovePair(
+        PyramidCardRef::Pyramid(21),
+        PyramidCardRef::Pyramid(15)
+    )));
+    assert_eq!(game.removed().len(), 2);
+    assert!(game.is_won());
+}
+
+#[test]
+fn pyramid_kings_leave_alone_and_pairs_must_sum_to_thirteen() {
+    let pyramid = pyramid_slots(&[
+        (21, card(Suit::Diamonds, 13, true)),
+        (22, card(Suit::Clubs, 12, true)),
+        (23, card(Suit::Hearts, 2, true)),
+    ]);
+    let mut game =
+        PyramidGame::debug_new(pyramid, Vec::new(), vec![card(Suit::Spades, 1, true)], 0);
+
+    assert!(game.can_remove_king(PyramidCardRef::Pyramid(21)));
+    assert!(!game.can_remove_king(PyramidCardRef::Pyramid(22)));
+    assert!(!game.can_remove_pair(PyramidCardRef::Pyramid(22), PyramidCardRef::Pyramid(23)));
+    assert!(game.apply_move(PyramidMove::RemoveKing(PyramidCardRef::Pyramid(21))));
+    assert!(game.apply_move(PyramidMove::RemovePair(
+        PyramidCardRef::Pyramid(22),
+        PyramidCardRef::Waste
+    )));
+    assert!(game.is_lost());
+}
+
+#[test]
+fn pyramid_stock_recycles_a_limited_number_of_times() {
+    let pyramid = pyramid_slots(&[(21, card(Suit::Clubs, 5, true))]);
+    let mut game =
+        PyramidGame::debug_new(pyramid, vec![card(Suit::Hearts, 3, false)], Vec::new(), 0);
+
+    assert_eq!(game.draw_or_recycle(), DrawResult::DrewFromStock);
+    for used in 1..=PYRAMID_MAX_RECYCLES {
+        assert_eq!(game.draw_or_recycle(), DrawResult::RecycledWaste);
+        assert_eq!(game.recycles_used(), used);
+        assert_eq!(game.draw_or_recycle(), DrawResult::DrewFromStock);
+    }
+    assert!(!game.can_draw());
+    assert_eq!(game.draw_or_recycle(), DrawResult::NoOp);
+    assert!(game.is_lost());
+}
+
+#[test]
+fn pyramid_session_codec_round_trip_preserves_state() {
+    let mut game = PyramidGame::new_with_seed(808);
+    for _ in 0..5 {
+        let next = game.solver_moves().first().copied();
+        let Some(next) = next else {
+            break;
+        };
+        assert!(game.apply_move(next));
+    }
+    let encoded = game.encode_for_session();
+    let decoded = PyramidGame::decode_from_session(&encoded).expect("decode pyramid session");
+    assert_eq!(decoded, game);
+    assert!(PyramidGame::decode_from_session("p=-;s=;w=;r=;rc=0").is_none());
+}
+
+#[test]
+fn pyramid_and_waste_build_sessions_keep_their_slot_format() {
+    let tokens: Vec<String> = Suit::ALL
+        .iter()
+        .flat_map(|suit| (1..=13).map(move |rank| format!("{}{rank}U", suit.short())))
+        .collect();
+    let mut slots: Vec<&str> = tokens[..28].iter().map(String::as_str).collect();
+    slots[0] = "-";
+    let rest = tokens[28..].join(".");
+
+    let pyramid = format!("p={};s={rest};w=-;r={};rc=0", slots.join("."), tokens[0]);
+    let game = PyramidGame::decode_from_session(&pyramid).expect("decode pyramid session");
+    assert_eq!(game.encode_for_session(), pyramid);
+
+    let tripeaks = format!(
+        "layout=tripeaks;wrap=0;scoring=1;t={};s={rest};w={};streak=0;best=0;score=0",
+        slots.join("."),
+        tokens[0]
+    );
+    let game = WasteBuildGame::decode_from_session(&tripeaks).expect("decode tripeaks session");
+    assert_eq!(game.encode_for_session(), tripeaks);
+}
+
+#[test]
+fn pyramid_solver_line_replays_to_a_win() {
+    // 5S needs the 8D buried under the 9C, which first has to clear the 4H.
+    let pyramid = pyramid_slots(&[
+        (0, card(Suit::Spades, 5, true)),
+        (1, card(Suit::Hearts, 4, true)),
+        (2, card(Suit::Diamonds, 13, true)),
+    ]);
+    let stock = vec![card(Suit::Clubs, 9, false), card(Suit::Diamonds, 8, false)];
+    let game = PyramidGame::debug_new(pyramid, stock, Vec::new(), 0);
+
+    let result = game.solve(10_000);
+    assert!(result.winnable);
+    assert!(!result.hit_state_limit);
+    let mut replay = game.clone();
+    for step in &result.line {
+        assert!(
+            replay.apply_move(*step),
+            "solver step {step:?} should be legal"
+        );
+    }
+    assert!(replay.is_won());
+
+    let stuck = PyramidGame::debug_new(
+        pyramid_slots(&[(0, card(Suit::Spades, 5, true))]),
+        vec![card(Suit::Clubs, 9, false)],
+        Vec::new(),
+        0,
+    );
+    let result = stuck.solve(10_000);
+    assert!(!result.winnable);
+    assert!(!result.hit_state_limit);
+    assert!(result.line.is_empty());
+}
+
+fn waste_build_slots(layout: WasteBuildLayout, cards: &[(usize, Card)]) -> Vec<Option<Card>> {
+    let mut slots = vec![None; layout.slot_count()];
+    for (slot, card) in cards {
+        slots[*slot] = Some(*card);
+    }
+    slots
+}
+
+#[test]
+fn waste_build_seeded_setup_exposes_only_uncovered_cards() {
+    let tripeaks = WasteBuildGame::new_with_seed(WasteBuildLayout::TriPeaks, 71);
+    assert_eq!(
+        tripeaks,
+        WasteBuildGame::new_with_seed(WasteBuildLayout::TriPeaks, 71)
+    );
+    assert_eq!(tripeaks.tableau_remaining(), TRIPEAKS_SLOTS);
+    assert_eq!(tripeaks.stock_len(), 23);
+    assert_eq!(tripeaks.waste().len(), 1);
+    let exposed: Vec<usize> = (0..TRIPEAKS_SLOTS)
+        .filter(|&slot| tripeaks.is_exposed(slot))
+        .collect();
+    assert_eq!(exposed, (18..28).collect::<Vec<_>>());
+    assert!((0..TRIPEAKS_SLOTS).all(|slot| {
+        tripeaks.tableau_card(slot).map(|card| card.face_up) == Some(tripeaks.is_exposed(slot))
+    }));
+    assert_eq!(
+        WasteBuildGame::covering_slots(WasteBuildLayout::TriPeaks, 0),
+        [Some(3), Some(4)]
+    );
+    assert_eq!(
+        WasteBuildGame::covering_slots(WasteBuildLayout::TriPeaks, 5),
+        [Some(12), Some(13)]
+    );
+    assert_eq!(
+        WasteBuildGame::covering_slots(WasteBuildLayout::TriPeaks, 17),
+        [Some(26), Some(27)]
+    );
+    assert_eq!(
+        WasteBuildGame::covering_slots(WasteBuildLayout::TriPeaks, 27),
+        [None, None]
+    );
+
+    let golf = WasteBuildGame::new_with_seed(WasteBuildLayout::Golf, 71);
+    assert_eq!(golf.tableau_remaining(), GOLF_SLOTS);
+    assert_eq!(golf.stock_len(), 16);
+    let exposed: Vec<usize> = (0..GOLF_SLOTS)
+        .filter(|&slot| golf.is_exposed(slot))
+        .collect();
+    assert_eq!(
+        exposed,
+        (0..GOLF_COLUMNS)
+            .map(|col| col * GOLF_COLUMN_DEPTH + GOLF_COLUMN_DEPTH - 1)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn waste_build_plays_one_rank_apart_and_wraps_only_when_enabled() {
+    let tableau = waste_build_slots(
+        WasteBuildLayout::TriPeaks,
+        &[
+            (18, card(Suit::Hearts, 1, false)),
+            (19, card(Suit::Clubs, 7, false)),
+        ],
+    );
+    let waste = vec![card(Suit::Spades, 13, true)];
+    let plain = WasteBuildGame::debug_new(
+        WasteBuildLayout::TriPeaks,
+        WasteBuildRules::default(),
+        tableau.clone(),
+        Vec::new(),
+        waste.clone(),
+    );
+    assert!(!plain.can_play(18));
+    assert!(!plain.can_play(19));
+    assert!(plain.is_lost());
+
+    let wrapping = WasteBuildGame::debug_new(
+        WasteBuildLayout::TriPeaks,
+        WasteBuildRules {
+            wrap_ranks: true,
+            ..WasteBuildRules::default()
+        },
+        tableau,
+        Vec::new(),
+        waste,
+    );
+    assert_eq!(wrapping.legal_moves(), vec![WasteBuildMove::Play(18)]);
+}
+
+#[test]
+fn golf_closes_the_waste_on_a_king_unless_wrapping() {
+    let tableau = waste_build_slots(
+        WasteBuildLayout::Golf,
+        &[
+            (4, card(Suit::Hearts, 12, false)),
+            (9, card(Suit::Clubs, 1, false)),
+        ],
+    );
+    let waste = vec![card(Suit::Spades, 13, true)];
+    let plain = WasteBuildGame::debug_new(
+        WasteBuildLayout::Golf,
+        WasteBuildRules::default(),
+        tableau.clone(),
+        Vec::new(),
+        waste.clone(),
+    );
+    assert!(!plain.can_play(4));
+    assert!(!plain.can_play(9));
+
+    let wrapping = WasteBuildGame::debug_new(
+        WasteBuildLayout::Golf,
+        WasteBuildRules {
+            wrap_ranks: true,
+            ..WasteBuildRules::default()
+        },
+        tableau,
+        Vec::new(),
+        waste,
+    );
+    assert!(wrapping.can_play(4));
+    assert!(wrapping.can_play(9));
+}
+
+#[test]
+fn tripeaks_streak_scoring_resets_on_draw_and_pays_peak_bonus() {
+    let tableau = waste_build_slots(
+        WasteBuildLayout::TriPeaks,
+        &[
+            (0, card(Suit::Hearts, 9, false)),
+            (18, card(Suit::Hearts, 5, false)),
+            (19, card(Suit::Diamonds, 6, false)),
+        ],
+    );
+    let mut game = WasteBuildGame::debug_new(
+        WasteBuildLayout::TriPeaks,
+        WasteBuildRules::default(),
+        tableau,
+        vec![card(Suit::Clubs, 8, false)],
+        vec![card(Suit::Clubs, 4, true)],
+    );
+    assert!(game.is_exposed(0));
+    assert!(game.play(18));
+    assert!(game.play(19));
+    assert_eq!((game.streak(), game.score()), (2, 3));
+    assert!(!game.can_play(0));
+    assert!(game.draw());
+    assert_eq!(game.streak(), 0);
+    assert!(game.play(0));
+    assert_eq!(game.streak(), 1);
+    assert_eq!(game.best_streak(), 2);
+    assert_eq!(game.score(), 3 + 1 + TRIPEAKS_PEAK_BONUS);
+    assert!(game.is_won());
+
+    let mut unscored = WasteBuildGame::debug_new(
+        WasteBuildLayout::TriPeaks,
+        WasteBuildRules {
+            streak_scoring: false,
+            ..WasteBuildRules::default()
+        },
+        waste_build_slots(
+            WasteBuildLayout::TriPeaks,
+            &[(18, card(Suit::Hearts, 5, false))],
+        ),
+        Vec::new(),
+        vec![card(Suit::Clubs, 4, true)],
+    );
+    assert!(unscored.play(18));
+    assert_eq!((unscored.streak(), unscored.score()), (1, 0));
+}
+
+#[test]
+fn waste_build_session_codec_round_trip_preserves_rules() {
+    let rules = WasteBuildRules {
+        wrap_ranks: true,
+        streak_scoring: false,
+    };
+    let mut game = WasteBuildGame::new_with_seed_and_rules(WasteBuildLayout::Golf, 505, rules);
+    for _ in 0..5 {
+        let Some(next) = game.solver_moves().first().copied() else {
+            break;
+        };
+        assert!(game.apply_move(next));
+    }
+    let encoded = game.encode_for_session();
+    let decoded = WasteBuildGame::decode_from_session(&encoded).expect("decode golf session");
+    assert_eq!(decoded, game);
+    assert_eq!(decoded.rules(), rules);
+
+    // A Golf tableau cannot be read back as TriPeaks.
+    let relabeled = encoded.replace("layout=golf", "layout=tripeaks");
+    assert!(WasteBuildGame::decode_from_session(&relabeled).is_none());
+}
+
+#[test]
+fn waste_build_solver_line_replays_to_a_win() {
+    // The 8C under the 6H only connects once the 7D comes off the stock.
+    let tableau = waste_build_slots(
+        WasteBuildLayout::Golf,
+        &[
+            (3, card(Suit::Clubs, 8, false)),
+            (4, card(Suit::Hearts, 6, false)),
+        ],
+    );
+    let game = WasteBuildGame::debug_new(
+        WasteBuildLayout::Golf,
+        WasteBuildRules::default(),
+        tableau,
+        vec![card(Suit::Diamonds, 7, false)],
+        vec![card(Suit::Clubs, 5, true)],
+    );
+
+    let result = game.solve(10_000);
+    assert!(result.winnable);
+    assert!(!result.hit_state_limit);
+    let mut replay = game.clone();
+    for step in &result.line {
+        assert!(
+            replay.apply_move(*step),
+            "solver step {step:?} should be legal"
+        );
+    }
+    assert!(replay.is_won());
+
+    let stuck = WasteBuildGame::debug_new(
+        WasteBuildLayout::Golf,
+        WasteBuildRules::default(),
+        waste_build_slots(
+            WasteBuildLayout::Golf,
+            &[(4, card(Suit::Hearts, 10, false))],
+        ),
+        vec![card(Suit::Diamonds, 2, false)],
+        vec![card(Suit::Clubs, 5, true)],
+    );
+    let result = stuck.solve(10_000);
+    assert!(!result.winnable);
+    assert!(!result.hit_state_limit);
+    assert!(result.line.is_empty());
+}
+
+fn forty_thieves_foundation(suit: Suit, top: u8) -> Vec<Card> {
+    (1..=top).map(|rank| card(suit, rank, true)).collect()
+}
+
+#[test]
+fn forty_thieves_seeded_setup_deals_forty_face_up_cards() {
+    let game = FortyThievesGame::new_with_seed(2026);
+    assert_eq!(game, FortyThievesGame::new_with_seed(2026));
+    assert_ne!(game, FortyThievesGame::new_with_seed(2027));
+
+    assert_eq!(game.tableau().len(), FORTY_THIEVES_COLUMNS);
+    assert!(game.tableau().iter().all(|pile| pile.len() == 4));
+    assert!(game.tableau().iter().flatten().all(|card| card.face_up));
+    assert_eq!(game.stock_len(), 64);
+    assert!(game.waste().is_empty());
+    assert!(game.foundations().iter().all(Vec::is_empty));
+
+    let mut copies = std::collections::HashMap::new();
+    for card in game.stock().iter().chain(game.tableau().iter().flatten()) {
+        *copies.entry((card.suit, card.rank)).or_insert(0) += 1;
+    }
+    assert_eq!(copies.len(), 52);
+    assert!(copies.values().all(|&count| count == 2));
+}
+
+#[test]
+fn forty_thieves_runs_move_only_as_far_as_empty_columns_allow() {
+    let mut tableau: [Vec<Card>; FORTY_THIEVES_COLUMNS] =
+        std::array::from_fn(|col| vec![card(Suit::Spades, col as u8 + 1, true)]);
+    tableau[0] = vec![card(Suit::Hearts, 9, true), card(Suit::Hearts, 8, true)];
+    tableau[1] = vec![card(Suit::Hearts, 10, true)];
+    tableau[2] = vec![card(Suit::Clubs, 10, true)];
+    let game = FortyThievesGame::debug_new(
+        Vec::new(),
+        Vec::new(),
+        std::array::from_fn(|_| Vec::new()),
+        tableau.clone(),
+    );
+
+    // No free column to relay the 8H through, and the 10C is the wrong suit.
+    assert_eq!(game.max_movable_run(1), 1);
+    assert!(!game.can_move_run(0, 0, 1));
+    assert!(!game.can_move_run(0, 0, 2));
+
+    tableau[9].clear();
+    let mut game = FortyThievesGame::debug_new(
+        Vec::new(),
+        Vec::new(),
+        std::array::from_fn(|_| Vec::new()),
+        tableau,
+    );
+    assert_eq!(game.max_movable_run(1), 2);
+    assert_eq!(game.max_movable_run(9), 1);
+    assert!(game.move_run(0, 0, 1));
+    assert_eq!(game.tableau()[1].len(), 3);
+    assert!(game.tableau()[0].is_empty());
+
+    // An empty column takes any single card.
+    assert!(game.can_move_run(2, 0, 0));
+}
+
+#[test]
+fn forty_thieves_foundations_hold_two_piles_per_suit() {
+    let mut tableau: [Vec<Card>; FORTY_THIEVES_COLUMNS] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = vec![card(Suit::Hearts, 1, true)];
+    tableau[1] = vec![card(Suit::Hearts, 1, true)];
+    tableau[2] = vec![card(Suit::Hearts, 2, true)];
+    tableau[3] = vec![card(Suit::Clubs, 2, true)];
+    let mut game = FortyThievesGame::debug_new(
+        Vec::new(),
+        Vec::new(),
+        std::array::from_fn(|_| Vec::new()),
+        tableau,
+    );
+    let hearts = Suit::Hearts.foundation_index() * 2;
+
+    assert!(!game.can_move_tableau_top_to_foundation(3));
+    assert!(game.move_tableau_top_to_foundation(0));
+    assert_eq!(game.foundations()[hearts].len(), 1);
+    assert!(game.move_tableau_top_to_foundation(1));
+    assert_eq!(game.foundations()[hearts + 1].len(), 1);
+    assert!(!game.foundation_accepts(hearts + 2, card(Suit::Hearts, 2, true)));
+    assert_eq!(
+        game.foundation_target(card(Suit::Hearts, 2, true)),
+        Some(hearts)
+    );
+    assert!(game.move_tableau_top_to_foundation(2));
+    assert_eq!(game.foundations()[hearts].len(), 2);
+}
+
+#[test]
+fn forty_thieves_stock_deals_a_single_pass() {
+    let mut game = FortyThievesGame::debug_new(
+        vec![card(Suit::Clubs, 5, false), card(Suit::Diamonds, 9, false)],
+        Vec::new(),
+        std::array::from_fn(|_| Vec::new()),
+        std::array::from_fn(|_| vec![card(Suit::Spades, 13, true)]),
+    );
+
+    assert_eq!(game.draw(), DrawResult::DrewFromStock);
+    assert_eq!(game.waste_top(), Some(card(Suit::Diamonds, 9, true)));
+    assert_eq!(game.draw(), DrawResult::DrewFromStock);
+    assert!(!game.can_draw());
+    assert_eq!(game.draw(), DrawResult::NoOp);
+    assert_eq!(game.waste().len(), 2);
+    assert!(game.is_lost());
+}
+
+#[test]
+fn forty_thieves_session_codec_round_trip_preserves_state() {
+    let mut game = FortyThievesGame::new_with_seed(77);
+    for _ in 0..5 {
+        let _ = game.draw();
+    }
+    if let Some(first) = game
+        .legal_moves()
+        .into_iter()
+        .find(|candidate| !matches!(candidate, SolverMove::Draw))
+    {
+        assert!(game.apply_move(first));
+    }
+    let encoded = game.encode_for_session();
+    let decoded = FortyThievesGame::decode_from_session(&encoded).expect("decode forty thieves");
+    assert_eq!(decoded, game);
+    assert!(FortyThievesGame::decode_from_session("s=-;w=-").is_none());
+}
+
+#[test]
+fn forty_thieves_solver_line_replays_to_a_win() {
+    // Everything is home except the two Kings of Spades; one is buried under
+    // a Queen that is still missing from the second pile.
+    let spades = Suit::Spades.foundation_index() * 2;
+    let mut foundations: [Vec<Card>; FORTY_THIEVES_FOUNDATIONS] =
+        std::array::from_fn(|pile| forty_thieves_foundation(Suit::ALL[pile / 2], 13));
+    foundations[spades] = forty_thieves_foundation(Suit::Spades, 12);
+    foundations[spades + 1] = forty_thieves_foundation(Suit::Spades, 11);
+    let mut tableau: [Vec<Card>; FORTY_THIEVES_COLUMNS] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = vec![card(Suit::Spades, 13, true), card(Suit::Spades, 12, true)];
+    let game = FortyThievesGame::debug_new(
+        vec![card(Suit::Spades, 13, false)],
+        Vec::new(),
+        foundations,
+        tableau,
+    );
+
+    let result = game.solve(10_000);
+    assert!(result.winnable);
+    assert!(!result.hit_state_limit);
+    let mut replay = game.clone();
+    for step in &result.line {
+        assert!(
+            replay.apply_move(*step),
+            "solver step {step:?} should be legal"
+        );
+    }
+    assert!(replay.is_won());
+}
+
+fn scorpion_run(suit: Suit, high: u8, low: u8) -> Vec<Card> {
+    (low..=high)
+        .rev()
+        .map(|rank| card(suit, rank, true))
+        .collect()
+}
+
+#[test]
+fn scorpion_seeded_setup_hides_the_first_four_columns() {
+    let game = ScorpionGame::new_with_seed(2026);
+    assert_eq!(game, ScorpionGame::new_with_seed(2026));
+    assert_ne!(game, ScorpionGame::new_with_seed(2027));
+
+    assert_eq!(game.tableau().len(), SCORPION_COLUMNS);
+    assert!(game.tableau().iter().all(|pile| pile.len() == 7));
+    for (col, pile) in game.tableau().iter().enumerate() {
+        let hidden = pile.iter().filter(|card| !card.face_up).count();
+        assert_eq!(hidden, if col < 4 { 3 } else { 0 });
+        assert!(pile[3..].iter().all(|card| card.face_up));
+    }
+    assert_eq!(game.stock_len(), 3);
+    assert_eq!(game.completed_runs(), 0);
+
+    let mut seen = std::collections::HashSet::new();
+    for card in game.reserve().iter().chain(game.tableau().iter().flatten()) {
+        assert!(seen.insert((card.suit, card.rank)));
+    }
+    assert_eq!(seen.len(), 52);
+}
+
+#[test]
+fn scorpion_any_face_up_card_moves_with_the_cards_above_it() {
+    let mut tableau: [Vec<Card>; SCORPION_COLUMNS] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = vec![
+        card(Suit::Clubs, 4, false),
+        card(Suit::Hearts, 8, true),
+        card(Suit::Diamonds, 2, true),
+        card(Suit::Spades, 11, true),
+    ];
+    tableau[1] = vec![card(Suit::Hearts, 9, true)];
+    tableau[2] = vec![card(Suit::Diamonds, 9, true)];
+    let mut game = ScorpionGame::debug_new(Vec::new(), tableau, Vec::new());
+
+    // Only the moved card has to follow suit; the cards above it come along.
+    assert!(!game.can_move_run(0, 0, 1));
+    assert!(!game.can_move_run(0, 1, 2));
+    assert!(game.can_move_run(0, 1, 1));
+    assert!(game.move_run(0, 1, 1));
+    assert_eq!(game.tableau()[1].len(), 4);
+    assert_eq!(game.tableau()[0], vec![card(Suit::Clubs, 4, true)]);
+    assert!(!game.can_move_run(1, 3, 0));
+}
+
+#[test]
+fn scorpion_only_buried_kings_may_fill_empty_columns() {
+    let mut tableau: [Vec<Card>; SCORPION_COLUMNS] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = vec![card(Suit::Spades, 13, true)];
+    tableau[1] = vec![card(Suit::Clubs, 3, true), card(Suit::Hearts, 13, true)];
+    tableau[2] = vec![card(Suit::Diamonds, 12, true)];
+    let game = ScorpionGame::debug_new(Vec::new(), tableau, Vec::new());
+
+    assert!(!game.can_move_run(0, 0, 3));
+    assert!(game.can_move_run(1, 1, 3));
+    assert!(!game.can_move_run(2, 0, 3));
+}
+
+#[test]
+fn scorpion_reserve_deals_once_and_completed_runs_leave_the_table() {
+    let mut tableau: [Vec<Card>; SCORPION_COLUMNS] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = scorpion_run(Suit::Hearts, 13, 2);
+    tableau[1] = vec![card(Suit::Clubs, 9, false), card(Suit::Clubs, 5, true)];
+    let mut game = ScorpionGame::debug_new(
+        vec![
+            card(Suit::Hearts, 1, false),
+            card(Suit::Clubs, 4, false),
+            card(Suit::Spades, 7, false),
+        ],
+        tableau,
+        Vec::new(),
+    );
+
+    assert_eq!(game.deal_reserve(), DrawResult::DrewFromStock);
+    assert!(!game.can_deal_reserve());
+    assert_eq!(game.deal_reserve(), DrawResult::NoOp);
+    assert_eq!(game.completed_run_suits(), &[Suit::Hearts]);
+    assert!(game.tableau()[0].is_empty());
+    assert_eq!(game.tableau_top(1), Some(card(Suit::Clubs, 4, true)));
+    assert_eq!(game.tableau_top(2), Some(card(Suit::Spades, 7, true)));
+    assert!(!game.legal_moves().contains(&SolverMove::Draw));
+}
+
+#[test]
+fn scorpion_session_codec_round_trip_preserves_state() {
+    let mut game = ScorpionGame::new_with_seed(77);
+    if let Some(first) = game
+        .legal_moves()
+        .into_iter()
+        .find(|candidate| !matches!(candidate, SolverMove::Draw))
+    {
+        assert!(game.apply_move(first));
+    }
+    let encoded = game.encode_for_session();
+    let decoded = ScorpionGame::decode_from_session(&encoded).expect("decode scorpion");
+    assert_eq!(decoded, game);
+
+    assert!(game.apply_move(SolverMove::Draw));
+    let encoded = game.encode_for_session();
+    assert_eq!(ScorpionGame::decode_from_session(&encoded), Some(game));
+    assert!(ScorpionGame::decode_from_session("runs=-;r=-").is_none());
+}
+
+#[test]
+fn scorpion_solver_line_replays_to_a_win() {
+    let mut tableau: [Vec<Card>; SCORPION_COLUMNS] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = scorpion_run(Suit::Spades, 13, 8);
+    tableau[1] = scorpion_run(Suit::Spades, 4, 1);
+    tableau[2] = scorpion_run(Suit::Spades, 7, 5);
+    let game = ScorpionGame::debug_new(
+        Vec::new(),
+        tableau,
+        vec![Suit::Clubs, Suit::Diamonds, Suit::Hearts],
+    );
+
+    let result = game.solve(10_000);
+    assert!(result.winnable);
+    assert!(!result.hit_state_limit);
+    let mut replay = game.clone();
+    for step in &result.line {
+        assert!(
+            replay.apply_move(*step),
+            "solver step {step:?} should be legal"
+        );
+    }
+    assert!(replay.is_won());
+}
+
+fn canfield_foundation(suit: Suit, base: u8, len: u8) -> Vec<Card> {
+    (0..len)
+        .map(|step| card(suit, (base - 1 + step) % 13 + 1, true))
+        .collect()
+}
+
+#[test]
+fn canfield_seeded_setup_deals_reserve_base_card_and_tableau() {
+    let game = CanfieldGame::new_with_seed(2026);
+    assert_eq!(game, CanfieldGame::new_with_seed(2026));
+    assert_ne!(game, CanfieldGame::new_with_seed(2027));
+
+    assert_eq!(game.reserve().len(), 13);
+    assert_eq!(game.reserve().iter().filter(|card| card.face_up).count(), 1);
+    assert!(game.reserve_top().is_some_and(|card| card.face_up));
+    assert!(game.tableau().iter().all(|pile| pile.len() == 1));
+    assert_eq!(game.stock_len(), 34);
+    let placed: Vec<&Card> = game.foundations().iter().flatten().collect();
+    assert_eq!(placed.len(), 1);
+    assert_eq!(placed[0].rank, game.base().rank);
+    assert!(game.foundations()[placed[0].suit.foundation_index()].len() == 1);
+}
+
+#[test]
+fn foundation_base_builds_up_in_suit_and_wraps_king_to_ace() {
+    let base = FoundationBase::new(12).expect("valid base rank");
+    assert!(FoundationBase::new(0).is_none());
+    assert!(base.accepts(&[], card(Suit::Hearts, 12, true)));
+    assert!(!base.accepts(&[], card(Suit::Hearts, 1, true)));
+
+    let pile = canfield_foundation(Suit::Hearts, 12, 2);
+    assert_eq!(pile.last().map(|card| card.rank), Some(13));
+    assert!(base.accepts(&pile, card(Suit::Hearts, 1, true)));
+    assert!(!base.accepts(&pile, card(Suit::Spades, 1, true)));
+    assert!(!base.accepts(
+        &canfield_foundation(Suit::Hearts, 12, 13),
+        card(Suit::Hearts, 12, true)
+    ));
+    assert!(FoundationBase::ACE.accepts(&[], card(Suit::Clubs, 1, true)));
+}
+
+#[test]
+fn canfield_tableau_builds_alternating_colours_and_refills_from_reserve() {
+    let mut tableau: [Vec<Card>; CANFIELD_COLUMNS] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = vec![card(Suit::Hearts, 1, true)];
+    tableau[1] = vec![card(Suit::Spades, 13, true), card(Suit::Diamonds, 12, true)];
+    tableau[2] = vec![card(Suit::Clubs, 5, true)];
+    tableau[3] = vec![card(Suit::Clubs, 8, true)];
+    let mut game = CanfieldGame::debug_new(
+        3,
+        Vec::new(),
+        Vec::new(),
+        vec![card(Suit::Diamonds, 7, false), card(Suit::Hearts, 4, true)],
+        std::array::from_fn(|_| Vec::new()),
+        tableau,
+    );
+
+    // Building wraps: a black King goes on a red Ace.
+    assert!(game.can_move_run(1, 0, 0));
+    assert!(!game.can_move_run(1, 1, 2));
+    assert!(game.move_run(1, 0, 0));
+    assert_eq!(game.tableau()[0].len(), 3);
+    // The emptied column takes the reserve top, exposing the next card.
+    assert_eq!(game.tableau()[1], vec![card(Suit::Hearts, 4, true)]);
+    assert_eq!(game.reserve_top(), Some(card(Suit::Diamonds, 7, true)));
+    assert!(game.can_move_reserve_to_tableau(3));
+
+    assert!(game.move_run(1, 0, 2));
+    assert_eq!(game.tableau()[1], vec![card(Suit::Diamonds, 7, true)]);
+    assert!(game.reserve().is_empty());
+
+    // With the reserve spent, empty columns wait and take single cards only.
+    assert!(game.move_run(1, 0, 3));
+    assert!(game.tableau()[1].is_empty());
+    assert!(!game.can_move_run(0, 0, 1));
+    assert!(game.can_move_run(0, 2, 1));
+}
+
+#[test]
+fn canfield_draws_three_and_recycles_without_limit() {
+    let stock: Vec<Card> = (1..=4)
+        .map(|rank| card(Suit::Spades, rank, false))
+        .collect();
+    let mut game = CanfieldGame::debug_new(
+        1,
+        stock,
+        Vec::new(),
+        Vec::new(),
+        std::array::from_fn(|_| Vec::new()),
+        std::array::from_fn(|_| vec![card(Suit::Hearts, 13, true)]),
+    );
+
+    assert_eq!(game.draw(), DrawResult::DrewFromStock);
+    assert_eq!(game.waste().len(), 3);
+    assert_eq!(game.waste_top(), Some(card(Suit::Spades, 2, true)));
+    assert_eq!(game.draw(), DrawResult::DrewFromStock);
+    assert_eq!(game.waste_top(), Some(card(Suit::Spades, 1, true)));
+    for _ in 0..3 {
+        assert_eq!(game.draw(), DrawResult::RecycledWaste);
+        assert_eq!(game.stock_len(), 4);
+        assert_eq!(game.draw(), DrawResult::DrewFromStock);
+        assert_eq!(game.draw(), DrawResult::DrewFromStock);
+    }
+    assert!(game.can_move_waste_to_foundation());
+}
+
+#[test]
+fn canfield_session_codec_round_trip_preserves_state() {
+    let mut game = CanfieldGame::new_with_seed(77);
+    let _ = game.draw();
+    if let Some(first) = game
+        .legal_moves()
+        .into_iter()
+        .find(|candidate| *candidate != CanfieldMove::Draw)
+    {
+        assert!(game.apply_move(first));
+    }
+    let encoded = game.encode_for_session();
+    let decoded = CanfieldGame::decode_from_session(&encoded).expect("decode canfield");
+    assert_eq!(decoded, game);
+    let wrong_base = format!("base={}", game.base().rank % 13 + 1);
+    let mismatched = encoded.replacen(&format!("base={}", game.base().rank), &wrong_base, 1);
+    assert!(CanfieldGame::decode_from_session(&mismatched).is_none());
+    assert!(CanfieldGame::decode_from_session("base=3;s=-;w=-;r=-").is_none());
+}
+
+#[test]
+fn canfield_solver_line_replays_to_a_win() {
+    // From a Jack base every pile ends on a Ten. The last Spades are split
+    // between the reserve and the stock, and the Ten of Hearts waits on the
+    // tableau.
+    let mut foundations: [Vec<Card>; 4] =
+        std::array::from_fn(|pile| canfield_foundation(Suit::ALL[pile], 11, 13));
+    foundations[Suit::Spades.foundation_index()] = canfield_foundation(Suit::Spades, 11, 10);
+    foundations[Suit::Hearts.foundation_index()].pop();
+    let mut tableau: [Vec<Card>; CANFIELD_COLUMNS] = std::array::from_fn(|_| Vec::new());
+    tableau[0] = vec![card(Suit::Hearts, 10, true)];
+    let game = CanfieldGame::debug_new(
+        11,
+        vec![card(Suit::Spades, 9, false)],
+        Vec::new(),
+        vec![card(Suit::Spades, 10, false), card(Suit::Spades, 8, true)],
+        foundations,
+        tableau,
+    );
+
+    let result = game.solve(10_000);
+    assert!(result.winnable);
+    assert!(!result.hit_state_limit);
+    let mut replay = game.clone();
+    for step in &result.line {
+        assert!(
+            replay.apply_move(*step),
+            "solver step {step:?} should be legal"
+        );
+    }
+    assert!(replay.is_won());
+}
+
+#[test]
+fn bakers_dozen_seeded_setup_deals_thirteen_columns_with_kings_at_the_bottom() {
+    let layout = OpenTableauLayout::BakersDozen;
+    let game = OpenTableauGame::new_with_seed(layout, 2026);
+    assert_eq!(game, OpenTableauGame::new_with_seed(layout, 2026));
+    assert_ne!(game, OpenTableauGame::new_with_seed(layout, 2027));
+
+    assert_eq!(game.tableau().len(), BAKERS_DOZEN_COLUMNS);
+    assert!(game.tableau().iter().all(|pile| pile.len() == 4));
+    assert!(game.tableau().iter().flatten().all(|card| card.face_up));
+    assert_eq!(game.foundation_cards(), 0);
+    for pile in game.tableau() {
+        let kings = pile.iter().take_while(|card| card.rank == 13).count();
+        assert!(pile[kings..].iter().all(|card| card.rank != 13));
+    }
+}
+
+#[test]
+fn beleaguered_castle_seeded_setup_places_aces_on_the_foundations() {
+    let game = OpenTableauGame::new_with_seed(OpenTableauLayout::BeleagueredCastle, 2026);
+    assert_eq!(game.tableau().len(), BELEAGUERED_CASTLE_COLUMNS);
+    assert!(game.tableau().iter().all(|pile| pile.len() == 6));
+    for (idx, pile) in game.foundations().iter().enumerate() {
+        assert_eq!(pile.len(), 1);
+        assert_eq!(pile[0].rank, 1);
+        assert_eq!(pile[0].suit.foundation_index(), idx);
+    }
+}
+
+#[test]
+fn open_tableau_moves_single_cards_down_in_rank_regardless_of_suit() {
+    let tableau = vec![
+        vec![card(Suit::Diamonds, 2, true), card(Suit::Hearts, 9, true)],
+        vec![card(Suit::Spades, 10, true)],
+        vec![card(Suit::Diamonds, 1, true)],
+        Vec::new(),
+    ];
+    for layout in [
+        OpenTableauLayout::BakersDozen,
+        OpenTableauLayout::BeleagueredCastle,
+    ] {
+        let mut game = OpenTableauGame::debug_new(
+            layout,
+            std::array::from_fn(|_| Vec::new()),
+            tableau.clone(),
+        );
+        assert!(!game.can_move_run(0, 0, 1));
+        assert!(game.can_move_run(0, 1, 1));
+        // Only Beleaguered Castle lets any card fill an empty column.
+        assert_eq!(game.can_move_run(0, 1, 3), layout.empty_columns_fillable());
+        assert!(game.move_tableau_top_to_foundation(2));
+        assert!(!game.can_move_tableau_top_to_foundation(0));
+        assert!(game.move_run(0, 1, 1));
+        assert!(game.move_tableau_top_to_foundation(0));
+        assert_eq!(game.foundation_cards(), 2);
+        assert_eq!(game.tableau()[1].len(), 2);
+    }
+}
+
+#[test]
+fn open_tableau_session_codec_round_trip_preserves_state() {
+    let mut game = OpenTableauGame::new_with_seed(OpenTableauLayout::BakersDozen, 77);
+    if let Some(first) = game.legal_moves().first().copied() {
+        assert!(game.apply_move(first));
+    }
+    let encoded = game.encode_for_session();
+    let decoded = OpenTableauGame::decode_from_session(&encoded).expect("decode open tableau");
+    assert_eq!(decoded, game);
+    // Thirteen columns do not fit an eight-column layout.
+    let mismatched = encoded.replacen("layout=bakers_dozen", "layout=beleaguered_castle", 1);
+    assert!(OpenTableauGame::decode_from_session(&mismatched).is_none());
+    assert!(OpenTableauGame::decode_from_session("layout=bakers_dozen").is_none());
+}
+
+#[test]
+fn deal_algorithm_one_keeps_its_golden_deals() {
+    fn fingerprint(encoded: &str) -> u64 {
+        encoded.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x00000100000001b3)
+        })
+    }
+
+    let mut rng = DealRng::from_seed(0);
+    let words: Vec<u32> = (0..4).map(|_| rng.next_u32()).collect();
+    assert_eq!(words, [3442241407, 3140108210, 2384947579, 3321986196]);
+
+    let klondike = KlondikeGame::new_with_seed(1);
+    let tops: Vec<String> = klondike
+        .tableau()
+        .iter()
+        .map(|pile| pile.last().expect("dealt column").label())
+        .collect();
+    assert_eq!(tops, ["4C", "AS", "QD", "AC", "4S", "AD", "7S"]);
+
+    // Changing one of these breaks every shared seed: add a new deal
+    // algorithm instead.
+    let deals = [
+        (klondike.encode_for_session(), 9148912056814811525),
+        (
+            SpiderGame::new_with_seed_and_mode(1, SpiderSuitMode::Four).encode_for_session(),
+            653598547124576715,
+        ),
+        (
+            FreecellGame::new_with_seed(1).encode_for_session(),
+            2277717800199923894,
+        ),
+        (
+            YukonGame::new_with_seed(1).encode_for_session(),
+            222638878785931661,
+        ),
+        (
+            PyramidGame::new_with_seed(1).encode_for_session(),
+            11168423015745157833,
+        ),
+        (
+            FortyThievesGame::new_with_seed(1).encode_for_session(),
+            17961242876117489118,
+        ),
+        (
+            ScorpionGame::new_with_seed(1).encode_for_session(),
+            18003869991194021545,
+        ),
+        (
+            CanfieldGame::new_with_seed(1).encode_for_session(),
+            16903507292733255836,
+        ),
+    ];
+    for (encoded, expected) in deals {
+        assert_eq!(fingerprint(&encoded), expected, "{encoded}");
+    }
+}
+
+#[test]
+fn pysol_game_numbers_follow_pysol_deals() {
+    for number in [1, 617, 11982, 32000] {
+        let pysol = PysolGameNumber::parse(&number.to_string()).expect("classic number");
+        let microsoft = microsoft_deal_seed(number).expect("microsoft number");
+        assert_eq!(
+            FreecellGame::from_pysol_game(pysol).tableau(),
+            FreecellGame::new_with_seed(microsoft).tableau(),
+            "game {number}"
+        );
+    }
+    let prefixed = PysolGameNumber::parse("ms:100000").expect("prefixed number");
+    assert_eq!(prefixed.label(), "ms100000");
+    assert_eq!(
+        FreecellGame::from_pysol_game(prefixed).tableau(),
+        FreecellGame::new_with_seed(microsoft_deal_seed(100_000).unwrap()).tableau()
+    );
+
+    // Past 32000 PySol seeds Python's Mersenne Twister.
+    let twister = FreecellGame::from_pysol_game(PysolGameNumber::parse("123456789012").unwrap());
+    let first: Vec<String> = twister.tableau()[0].iter().map(|c| c.label()).collect();
+    assert_eq!(first, ["7S", "10H", "AC", "KH", "7D", "QS", "8S"]);
+    assert!(PysolGameNumber::parse("100000000000000000000").is_none());
+    assert!(PysolGameNumber::parse("ms").is_none());
+
+    let klondike = KlondikeGame::from_pysol_game(PysolGameNumber::parse("5").unwrap());
+    let heights: Vec<usize> = klondike.tableau().iter().map(Vec::len).collect();
+    assert_eq!(heights, [1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!((klondike.stock_len(), klondike.waste_len()), (23, 1));
+
+    let spider = SpiderGame::from_pysol_game(PysolGameNumber::parse("5").unwrap());
+    let heights: Vec<usize> = spider.tableau().iter().map(Vec::len).collect();
+    assert_eq!(heights, [6, 5, 5, 6, 5, 5, 6, 5, 5, 6]);
+    assert_eq!(spider.stock_len(), 50);
+}
+
+#[test]
+fn board_text_round_trips_klondike_spider_and_freecell() {
+    let mut klondike = KlondikeGame::new_with_seed(7);
+    klondike.draw_or_recycle();
+    let text = klondike.board_text();
+    assert_eq!(TextBoard::parse(&text), Ok(TextBoard::Klondike(klondike)));
+
+    let spider = SpiderGame::new_with_seed_and_mode(7, SpiderSuitMode::Two);
+    let text = spider.board_text();
+    assert_eq!(TextBoard::parse(&text), Ok(TextBoard::Spider(spider)));
+
+    let freecell = FreecellGame::new_with_seed(7);
+    let text = freecell.board_text();
+    assert!(text.starts_with("Game: FreeCell\nFoundations: C-0 D-0 H-0 S-0\nFreecells: - - - -"));
+    assert_eq!(TextBoard::parse(&text), Ok(TextBoard::Freecell(freecell)));
+}
+
+#[test]
+fn board_text_keeps_klondike_rules() {
+    let mut klondike = KlondikeGame::new_with_seed(7);
+    klondike.set_draw_mode(DrawMode::Three);
+    klondike.set_pass_limit(KlondikePassLimit::Three);
+    klondike.set_thoughtful(true);
+    while klondike.draw_or_recycle() != DrawResult::RecycledWaste {}
+    let text = klondike.board_text();
+    assert!(text.starts_with("Game: Klondike\nRules: Deal 3, 3 Passes, Pass 2, Thoughtful\n"));
+    assert!(TextBoard::has_rules_line(&text));
+    assert_eq!(TextBoard::parse(&text), Ok(TextBoard::Klondike(klondike)));
+
+    let unruled: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.starts_with("Rules:"))
+        .collect();
+    let unruled = unruled.join("\n");
+    assert!(!TextBoard::has_rules_line(&unruled));
+    let Ok(TextBoard::Klondike(parsed)) = TextBoard::parse(&unruled) else {
+        panic!("a Klondike board without rules should load");
+    };
+    assert_eq!(parsed.draw_mode(), DrawMode::One);
+    assert_eq!(parsed.pass_limit(), KlondikePassLimit::Unlimited);
+    assert!(!parsed.is_thoughtful());
+
+    for (rules, err) in [
+        ("Deal 3, 1 Pass, Pass 2", "1 Pass allows Pass 1 to Pass 1"),
+        ("Deal 2, Pass 2", "unlimited passes are not counted"),
+        ("Deal 9", "Klondike deals 1 to 5 cards at a time"),
+        (
+            "Vegas",
+            "'vegas' is not a Klondike rule; try Deal 3, 3 Passes, Pass 2 or Thoughtful",
+        ),
+    ] {
+        let text = text.replace("Deal 3, 3 Passes, Pass 2, Thoughtful", rules);
+        assert_eq!(TextBoard::parse(&text), Err(err.to_string()));
+    }
+}
+
+#[test]
+fn board_text_reads_solver_style_columns_and_rejects_broken_boards() {
+    let columns = FreecellGame::new_with_seed(3)
+        .tableau()
+        .iter()
+        .map(|pile| {
+            let cards: Vec<String> = pile.iter().map(|c| c.label()).collect();
+            format!(": {}", cards.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let Ok(TextBoard::Freecell(parsed)) = TextBoard::parse(&format!("# fc-solve\n{columns}"))
+    else {
+        panic!("fc-solve columns should load as FreeCell");
+    };
+    assert_eq!(parsed.tableau(), FreecellGame::new_with_seed(3).tableau());
+
+    let dropped = columns.replacen(": ", ": 2x ", 1);
+    assert_eq!(
+        TextBoard::parse(&dropped),
+        Err("'2x' is not a card".to_string())
+    );
+    let two_bad = format!("{dropped}\nFoo: 1\n: ZZ");
+    assert_eq!(
+        TextBoard::line_errors(&two_bad),
+        vec![
+            (1, "'2x' is not a card".to_string()),
+            (9, "unknown board line 'foo:'".to_string()),
+            (10, "'ZZ' is not a card".to_string()),
+        ]
+    );
+    assert!(TextBoard::line_errors(&columns).is_empty());
+    let doubled = format!("{columns} AS");
+    assert_eq!(
+        TextBoard::parse(&doubled),
+        Err("board has too many AS".to_string())
+    );
+    let short = columns.lines().skip(2).collect::<Vec<_>>().join("\n");
+    assert!(TextBoard::parse(&short)
+        .unwrap_err()
+        .starts_with("board has 6 columns"));
+
+    let mut lines: Vec<String> = KlondikeGame::new_with_seed(3)
+        .board_text()
+        .lines()
+        .map(str::to_string)
+        .collect();
+    lines[5] = format!("[{}]", lines[5]);
+    assert_eq!(
+        TextBoard::parse(&lines.join("\n")),
+        Err("column 1 has a face-down card above a face-up one or on top".to_string())
+    );
+}
+
+#[test]
+fn board_text_covers_yukon_freecell_layouts_and_open_tableau() {
+    let russian = YukonGame::new_with_seed_and_rules(4, YukonRuleSet::Russian);
+    let text = russian.board_text();
+    assert!(text.starts_with("Game: Yukon\nRules: Russian\n"));
+    assert_eq!(TextBoard::parse(&text), Ok(TextBoard::Yukon(russian)));
+
+    for layout in [
+        FreecellLayout::EightOff,
+        FreecellLayout::SeahavenTowers,
+        FreecellLayout::BakersGame,
+    ] {
+        let game = FreecellGame::new_with_seed_for_layout(layout, 4);
+        let parsed = TextBoard::parse(&game.board_text()).expect("layout board");
+        assert_eq!(parsed.mode(), layout.mode());
+        assert_eq!(parsed, TextBoard::Freecell(game));
+    }
+
+    for layout in [
+        OpenTableauLayout::BakersDozen,
+        OpenTableauLayout::BeleagueredCastle,
+    ] {
+        let game = OpenTableauGame::new_with_seed(layout, 4);
+        let parsed = TextBoard::parse(&game.board_text()).expect("open tableau board");
+        assert_eq!(parsed, TextBoard::OpenTableau(game));
+    }
+
+    let six_cells = FreecellGame::new_with_seed(4)
+        .board_text()
+        .replace("Freecells: - - - -", "Freecells: - - - - - -");
+    let Ok(TextBoard::Freecell(game)) = TextBoard::parse(&six_cells) else {
+        panic!("six free cells should load");
+    };
+    assert_eq!(game.freecell_count(), 6);
+
+    assert_eq!(
+        TextBoard::parse(
+            &SpiderGame::new_with_seed(4)
+                .board_text()
+                .replace("Game: Spider", "Game: Spider\nRules: Russian")
+        ),
+        Err("Spider boards take no Rules: line".to_string())
+    );
+    let face_down_castle = OpenTableauGame::new_with_seed(OpenTableauLayout::BeleagueredCastle, 4)
+        .board_text()
+        .replacen("\nKS", "\n[KS]", 1)
+        .replacen(" KS", " [KS]", 1);
+    assert_eq!(
+        TextBoard::parse(&face_down_castle),
+        Err("Beleaguered Castle deals every card face up".to_string())
+    );
+}
+
+#[test]
+fn board_text_covers_reserve_row_and_double_foundation_variants() {
+    let mut pyramid = PyramidGame::new_with_seed(5);
+    while pyramid.draw_or_recycle() != DrawResult::RecycledWaste {}
+    pyramid.draw_or_recycle();
+    let text = pyramid.board_text();
+    assert!(text.starts_with("Game: Pyramid\nRules: Pass 2\n"));
+    assert_eq!(
+        TextBoard::parse(&text),
+        Ok(TextBoard::Pyramid(pyramid.clone()))
+    );
+    let removal = pyramid
+        .legal_moves()
+        .into_iter()
+        .find(|candidate| *candidate != PyramidMove::Draw)
+        .expect("a seeded pyramid has a removal");
+    assert!(pyramid.apply_move(removal));
+    let Ok(TextBoard::Pyramid(parsed)) = TextBoard::parse(&pyramid.board_text()) else {
+        panic!("a played pyramid should load");
+    };
+    assert_eq!(parsed.pyramid(), pyramid.pyramid());
+    assert_eq!(parsed.removed().len(), pyramid.removed().len());
+    let mut rows: Vec<String> = text.lines().map(str::to_string).collect();
+    rows[5] = "- -".to_string();
+    assert_eq!(
+        TextBoard::parse(&rows.join("\n")),
+        Err("the cards off the board do not pair up to 13".to_string())
+    );
+    rows[5] = "-".to_string();
+    assert_eq!(
+        TextBoard::parse(&rows.join("\n")),
+        Err("Pyramid row 2 needs 2 cards or -".to_string())
+    );
+
+    for layout in [WasteBuildLayout::TriPeaks, WasteBuildLayout::Golf] {
+        let rules = WasteBuildRules {
+            wrap_ranks: true,
+            streak_scoring: false,
+        };
+        let mut game = WasteBuildGame::new_with_seed_and_rules(layout, 5, rules);
+        assert!(game.draw());
+        if let Some(play) = game
+            .legal_moves()
+            .into_iter()
+            .find(|candidate| *candidate != WasteBuildMove::Draw)
+        {
+            assert!(game.apply_move(play));
+        }
+        let Ok(TextBoard::WasteBuild(parsed)) = TextBoard::parse(&game.board_text()) else {
+            panic!("{} board should load", layout.mode().label());
+        };
+        assert_eq!(parsed.rules(), rules);
+        assert_eq!(parsed.tableau(), game.tableau());
+        assert_eq!(parsed.stock(), game.stock());
+        assert_eq!(parsed.waste(), game.waste());
+    }
+    let mut golf: Vec<String> = WasteBuildGame::new_with_seed(WasteBuildLayout::Golf, 5)
+        .board_text()
+        .lines()
+        .map(str::to_string)
+        .collect();
+    golf[4] = format!("- {}", golf[4]);
+    assert!(TextBoard::parse(&golf.join("\n"))
+        .unwrap_err()
+        .starts_with("column 1 has a gap"));
+
+    let mut forty = FortyThievesGame::new_with_seed(5);
+    forty.draw();
+    let text = forty.board_text();
+    assert!(text.contains("Foundations: C-0 C-0 D-0 D-0 H-0 H-0 S-0 S-0"));
+    assert_eq!(TextBoard::parse(&text), Ok(TextBoard::FortyThieves(forty)));
+    assert_eq!(
+        TextBoard::parse(&text.replace("D-0 D-0", "C-0 D-0")),
+        Err("Forty Thieves has two C foundations".to_string())
+    );
+
+    let mut scorpion = ScorpionGame::new_with_seed(5);
+    let text = scorpion.board_text();
+    assert_eq!(
+        TextBoard::parse(&text),
+        Ok(TextBoard::Scorpion(scorpion.clone()))
+    );
+    scorpion.deal_reserve();
+    let text = scorpion.board_text();
+    assert!(text.contains("\nReserve: \n"));
+    assert_eq!(TextBoard::parse(&text), Ok(TextBoard::Scorpion(scorpion)));
+
+    let mut canfield = CanfieldGame::new_with_seed(5);
+    canfield.draw();
+    let text = canfield.board_text();
+    assert!(text.starts_with(&format!(
+        "Game: Canfield\nBase: {}\n",
+        rank_label(canfield.base().rank).replace("10", "T")
+    )));
+    assert_eq!(TextBoard::parse(&text), Ok(TextBoard::Canfield(canfield)));
+    let no_base: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.starts_with("Base:"))
+        .collect();
+    assert_eq!(
+        TextBoard::parse(&no_base.join("\n")),
+        Err("Canfield boards need a Base: line like Base: 7".to_string())
+    );
+}
@@ -0,0 +1,437 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+use super::*;
+
+/// One step of a Thoughtful Klondike line. The exact search may also take a
+/// card back off a foundation, which the hint solvers never do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThoughtfulMove {
+    Play(SolverMove),
+    FoundationToTableau { foundation_idx: usize, dst: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThoughtfulVerdict {
+    /// `line` wins from the analysed position.
+    Winnable,
+    /// Every position reachable from the analysed one was searched.
+    Unwinnable,
+    /// The state budget ran out first.
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThoughtfulSolveResult {
+    pub verdict: ThoughtfulVerdict,
+    pub explored_states: usize,
+    /// Winning line from the analysed position; empty unless `Winnable`.
+    pub line: Vec<ThoughtfulMove>,
+}
+
+/// One search edge: deal `draws` cards, then play `action`. Folding the
+/// draws into the play keeps stock-only positions out of the search.
+#[derive(Debug, Clone, Copy)]
+struct ThoughtfulStep {
+    draws: u16,
+    action: ThoughtfulMove,
+}
+
+struct ThoughtfulFrame {
+    state: KlondikeGame,
+    steps: Vec<ThoughtfulStep>,
+    next: usize,
+}
+
+struct ThoughtfulSearch<'a> {
+    exhaustive: bool,
+    visited: HashSet<Box<[u8]>>,
+    max_states: usize,
+    cancel: &'a AtomicBool,
+    hit_state_limit: bool,
+    canceled: bool,
+}
+
+impl KlondikeGame {
+    pub fn solve_thoughtful(&self, max_states: usize) -> ThoughtfulSolveResult {
+        let cancel = AtomicBool::new(false);
+        self.solve_thoughtful_cancelable(max_states, &cancel)
+            .unwrap_or(ThoughtfulSolveResult {
+                verdict: ThoughtfulVerdict::Unknown,
+                explored_states: 0,
+                line: Vec::new(),
+            })
+    }
+
+    /// Depth-first search with every card position known. A narrowed first
+    /// pass finds most wins quickly. The second pass starts over with every
+    /// move: only provably safe foundation plays are forced and only moves
+    /// back to a seen position are dropped, so when it finishes inside the
+    /// budget without a line the position is lost.
+    pub fn solve_thoughtful_cancelable(
+        &self,
+        max_states: usize,
+        cancel: &AtomicBool,
+    ) -> Option<ThoughtfulSolveResult> {
+        let (quick_line, quick_states) = {
+            let mut quick = ThoughtfulSearch::new(false, (max_states / 4).max(1), cancel);
+            let line = quick.run(self);
+            if quick.canceled {
+                return None;
+            }
+            (line, quick.visited.len())
+        };
+        if let Some(line) = quick_line {
+            return Some(ThoughtfulSolveResult {
+                verdict: ThoughtfulVerdict::Winnable,
+                explored_states: quick_states,
+                line,
+            });
+        }
+
+        let mut full = ThoughtfulSearch::new(true, max_states.max(1), cancel);
+        let line = full.run(self);
+        if full.canceled {
+            return None;
+        }
+        let verdict = match line {
+            Some(_) => ThoughtfulVerdict::Winnable,
+            None if full.hit_state_limit => ThoughtfulVerdict::Unknown,
+            None => ThoughtfulVerdict::Unwinnable,
+        };
+        Some(ThoughtfulSolveResult {
+            verdict,
+            explored_states: quick_states + full.visited.len(),
+            line: line.unwrap_or_default(),
+        })
+    }
+
+    pub fn apply_thoughtful_move(&mut self, candidate: ThoughtfulMove) -> bool {
+        match candidate {
+            ThoughtfulMove::Play(SolverMove::Draw) => self.draw_or_recycle() != DrawResult::NoOp,
+            ThoughtfulMove::Play(SolverMove::WasteToFoundation) => self.move_waste_to_foundation(),
+            ThoughtfulMove::Play(SolverMove::WasteToTableau { dst }) => {
+                self.move_waste_to_tableau(dst)
+            }
+            ThoughtfulMove::Play(SolverMove::TableauTopToFoundation { src }) => {
+                self.move_tableau_top_to_foundation(src)
+            }
+            ThoughtfulMove::Play(SolverMove::TableauRunToTableau { src, start, dst }) => {
+                self.move_tableau_run_to_tableau(src, start, dst)
+            }
+            ThoughtfulMove::FoundationToTableau {
+                foundation_idx,
+                dst,
+            } => self.move_foundation_top_to_tableau(foundation_idx, dst),
+        }
+    }
+
+    /// Checks a solver certificate: every move is legal and the last one wins.
+    pub fn thoughtful_line_wins(&self, line: &[ThoughtfulMove]) -> bool {
+        let mut game = self.clone();
+        line.iter()
+            .all(|candidate| game.apply_thoughtful_move(*candidate))
+            && game.is_won()
+    }
+
+    /// Position key. Columns are interchangeable, so they are sorted;
+    /// recycles only matter while the pass limit can still run out.
+    fn thoughtful_key(&self) -> Box<[u8]> {
+        let card_byte = |card: &Card| {
+            let byte = (card.suit.foundation_index() * 13) as u8 + card.rank - 1;
+            if card.face_up {
+                byte | 0x40
+            } else {
+                byte
+            }
+        };
+        let mut columns: Vec<Vec<u8>> = self
+            .tableau
+            .iter()
+            .map(|column| column.iter().map(card_byte).collect())
+            .collect();
+        columns.sort_unstable();
+
+        let mut key = Vec::with_capacity(80);
+        for column in columns {
+            key.extend(column);
+            key.push(0xff);
+        }
+        key.extend(self.foundations.iter().map(|pile| pile.len() as u8));
+        if self.draw_mode == DrawMode::One && self.pass_limit.max_recycles().is_none() {
+            // Dealing singly forever reaches every split of the same talon
+            // order, so only the order counts.
+            key.extend(
+                self.waste
+                    .iter()
+                    .chain(self.stock.iter().rev())
+                    .map(|card| card_byte(card) & 0x3f),
+            );
+        } else {
+            key.extend(self.stock.iter().map(|card| card_byte(card) & 0x3f));
+            key.push(0xff);
+            key.extend(self.waste.iter().map(|card| card_byte(card) & 0x3f));
+            if self.pass_limit.max_recycles().is_some() {
+                key.push(self.recycles_used);
+            }
+        }
+        key.into_boxed_slice()
+    }
+
+    /// A card may go up for good once nothing left can need it as a
+    /// holder: both opposite-colour cards one rank down are home, and so is
+    /// the other same-colour card two ranks down.
+    fn is_safe_foundation_card(&self, card: Card) -> bool {
+        if card.rank <= 2 {
+            return true;
+        }
+        Suit::ALL
+            .into_iter()
+            .filter(|suit| *suit != card.suit)
+            .all(|suit| {
+                let home = self.foundation_top_rank(suit);
+                if suit.is_red() == card.suit.is_red() {
+                    home + 2 >= card.rank
+                } else {
+                    home + 1 >= card.rank
+                }
+            })
+    }
+
+    /// Successors in search order. A safe foundation play is taken alone;
+    /// from the waste only in Deal 1, where removing a card never changes
+    /// which cards later draws reach. Stock cards are offered as draws
+    /// followed by the play they make possible. The quick pass never lifts
+    /// foundation cards and only splits a run to send the card under it home.
+    fn thoughtful_steps(&self, exhaustive: bool, cancel: &AtomicBool) -> Vec<ThoughtfulStep> {
+        let step = |draws: u16, action: ThoughtfulMove| ThoughtfulStep { draws, action };
+        for src in 0..7 {
+            if self.can_move_tableau_top_to_foundation(src)
+                && self
+                    .tableau_top(src)
+                    .is_some_and(|card| self.is_safe_foundation_card(card))
+            {
+                return vec![step(
+                    0,
+                    ThoughtfulMove::Play(SolverMove::TableauTopToFoundation { src }),
+                )];
+            }
+        }
+        if self.draw_mode == DrawMode::One
+            && self.can_move_waste_to_foundation()
+            && self
+                .waste_top()
+                .is_some_and(|card| self.is_safe_foundation_card(card))
+        {
+            return vec![step(0, ThoughtfulMove::Play(SolverMove::WasteToFoundation))];
+        }
+
+        let mut ordered: Vec<(i32, ThoughtfulStep)> = Vec::new();
+        for src in 0..7 {
+            if self.can_move_tableau_top_to_foundation(src) {
+                let reveals = self.tableau[src]
+                    .len()
+                    .checked_sub(2)
+                    .is_some_and(|below| !self.tableau[src][below].face_up);
+                ordered.push((
+                    if reveals { 110 } else { 90 },
+                    step(
+                        0,
+                        ThoughtfulMove::Play(SolverMove::TableauTopToFoundation { src }),
+                    ),
+                ));
+            }
+        }
+        for src in 0..7 {
+            for start in 0..self.tableau[src].len() {
+                for dst in 0..7 {
+                    if !self.can_move_tableau_run_to_tableau(src, start, dst) {
+                        continue;
+                    }
+                    let dst_empty = self.tableau[dst].is_empty();
+                    // A whole column into an empty one only renames columns.
+                    if start == 0 && dst_empty {
+                        continue;
+                    }
+                    let exposes_face_up = start > 0 && self.tableau[src][start - 1].face_up;
+                    if !exhaustive
+                        && exposes_face_up
+                        && !self.tableau_card(src, start - 1).is_some_and(|below| {
+                            self.foundation_top_rank(below.suit) + 1 == below.rank
+                        })
+                    {
+                        continue;
+                    }
+                    let priority = if start == 0 {
+                        70
+                    } else if !self.tableau[src][start - 1].face_up {
+                        80
+                    } else if dst_empty {
+                        20
+                    } else {
+                        40
+                    };
+                    ordered.push((
+                        priority,
+                        step(
+                            0,
+                            ThoughtfulMove::Play(SolverMove::TableauRunToTableau {
+                                src,
+                                start,
+                                dst,
+                            }),
+                        ),
+                    ));
+                }
+            }
+        }
+        for foundation_idx in (0..4).filter(|_| exhaustive) {
+            // A card that would be forced straight back is never worth lifting.
+            if self.foundations[foundation_idx]
+                .last()
+                .is_some_and(|card| self.is_safe_foundation_card(*card))
+            {
+                continue;
+            }
+            for dst in 0..7 {
+                if self.can_move_foundation_top_to_tableau(foundation_idx, dst) {
+                    ordered.push((
+                        10,
+                        step(
+                            0,
+                            ThoughtfulMove::FoundationToTableau {
+                                foundation_idx,
+                                dst,
+                            },
+                        ),
+                    ));
+                }
+            }
+        }
+
+        // Walk the talon until a stock/waste split comes round again or the
+        // passes run out, offering every waste card that turns up with
+        // somewhere to go. The cards keep their order through recycles, so
+        // the waste length names the split; in Deal 3 the starting one may
+        // never return.
+        let mut talon = self.clone();
+        let mut draws = 0_u16;
+        let mut seen_splits = vec![false; talon.stock.len() + talon.waste.len() + 1];
+        loop {
+            if cancel.load(AtomicOrdering::Relaxed) {
+                break;
+            }
+            seen_splits[talon.waste.len()] = true;
+            let depth_penalty = i32::from(draws.min(50));
+            if talon.can_move_waste_to_foundation() {
+                ordered.push((
+                    100 - depth_penalty,
+                    step(draws, ThoughtfulMove::Play(SolverMove::WasteToFoundation)),
+                ));
+            }
+            for dst in 0..7 {
+                if talon.can_move_waste_to_tableau(dst) {
+                    let priority = if talon.tableau[dst].is_empty() {
+                        30
+                    } else {
+                        50
+                    };
+                    ordered.push((
+                        priority - depth_penalty,
+                        step(
+                            draws,
+                            ThoughtfulMove::Play(SolverMove::WasteToTableau { dst }),
+                        ),
+                    ));
+                }
+            }
+            if talon.draw_or_recycle() == DrawResult::NoOp {
+                break;
+            }
+            draws += 1;
+            if seen_splits[talon.waste.len()] {
+                break;
+            }
+        }
+
+        ordered.sort_by_key(|(priority, _)| -priority);
+        ordered.into_iter().map(|(_, step)| step).collect()
+    }
+
+    fn apply_thoughtful_step(&mut self, step: ThoughtfulStep) -> bool {
+        (0..step.draws).all(|_| self.draw_or_recycle() != DrawResult::NoOp)
+            && self.apply_thoughtful_move(step.action)
+    }
+}
+
+impl<'a> ThoughtfulSearch<'a> {
+    fn new(exhaustive: bool, max_states: usize, cancel: &'a AtomicBool) -> Self {
+        Self {
+            exhaustive,
+            visited: HashSet::new(),
+            max_states,
+            cancel,
+            hit_state_limit: false,
+            canceled: false,
+        }
+    }
+
+    /// Iterative so that long lines through many stock passes cannot
+    /// exhaust the thread's stack.
+    fn run(&mut self, root: &KlondikeGame) -> Option<Vec<ThoughtfulMove>> {
+        if root.is_won() {
+            return Some(Vec::new());
+        }
+        self.visited.insert(root.thoughtful_key());
+        let mut stack = vec![ThoughtfulFrame {
+            steps: root.thoughtful_steps(self.exhaustive, self.cancel),
+            state: root.clone(),
+            next: 0,
+        }];
+        let mut path: Vec<ThoughtfulStep> = Vec::new();
+
+        while let Some(frame) = stack.last_mut() {
+            let Some(step) = frame.steps.get(frame.next).copied() else {
+                stack.pop();
+                path.pop();
+                continue;
+            };
+            frame.next += 1;
+            let mut next = frame.state.clone();
+            if !next.apply_thoughtful_step(step) {
+                continue;
+            }
+            if next.is_won() {
+                path.push(step);
+                return Some(flatten_steps(&path));
+            }
+            if self.cancel.load(AtomicOrdering::Relaxed) {
+                self.canceled = true;
+                return None;
+            }
+            if self.visited.len() >= self.max_states {
+                self.hit_state_limit = true;
+                return None;
+            }
+            if !self.visited.insert(next.thoughtful_key()) {
+                continue;
+            }
+            path.push(step);
+            stack.push(ThoughtfulFrame {
+                steps: next.thoughtful_steps(self.exhaustive, self.cancel),
+                state: next,
+                next: 0,
+            });
+        }
+        None
+    }
+}
+
+fn flatten_steps(path: &[ThoughtfulStep]) -> Vec<ThoughtfulMove> {
+    let mut line = Vec::new();
+    for step in path {
+        line.extend((0..step.draws).map(|_| ThoughtfulMove::Play(SolverMove::Draw)));
+        line.push(step.action);
+    }
+    line
+}
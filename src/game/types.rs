@@ -5,6 +5,18 @@ pub enum GameMode {
     Klondike,
     Spider,
     Freecell,
+    Yukon,
+    Pyramid,
+    TriPeaks,
+    Golf,
+    FortyThieves,
+    Scorpion,
+    Canfield,
+    BakersDozen,
+    BeleagueredCastle,
+    EightOff,
+    SeahavenTowers,
+    BakersGame,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -39,12 +51,68 @@ impl DrawMode {
     }
 }
 
+/// How many times a Klondike stock may be worked through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KlondikePassLimit {
+    Unlimited,
+    Three,
+    Single,
+}
+
+impl KlondikePassLimit {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Unlimited => "Unlimited",
+            Self::Three => "3 Passes",
+            Self::Single => "1 Pass",
+        }
+    }
+
+    /// Waste recycles allowed, or `None` when the stock turns forever.
+    pub fn max_recycles(self) -> Option<u8> {
+        match self {
+            Self::Unlimited => None,
+            Self::Three => Some(2),
+            Self::Single => Some(0),
+        }
+    }
+
+    pub fn session_tag(self) -> &'static str {
+        match self {
+            Self::Unlimited => "unlimited",
+            Self::Three => "3",
+            Self::Single => "1",
+        }
+    }
+
+    pub fn from_session_tag(value: &str) -> Option<Self> {
+        match value {
+            "unlimited" => Some(Self::Unlimited),
+            "3" => Some(Self::Three),
+            "1" => Some(Self::Single),
+            _ => None,
+        }
+    }
+}
+
 impl GameMode {
     pub fn from_id(id: &str) -> Option<Self> {
         match id {
             "klondike" => Some(Self::Klondike),
             "spider" => Some(Self::Spider),
             "freecell" => Some(Self::Freecell),
+            "yukon" => Some(Self::Yukon),
+            "pyramid" => Some(Self::Pyramid),
+            "tripeaks" => Some(Self::TriPeaks),
+            "golf" => Some(Self::Golf),
+            "forty_thieves" => Some(Self::FortyThieves),
+            "scorpion" => Some(Self::Scorpion),
+            "canfield" => Some(Self::Canfield),
+            "bakers_dozen" => Some(Self::BakersDozen),
+            "beleaguered_castle" => Some(Self::BeleagueredCastle),
+            "eight_off" => Some(Self::EightOff),
+            "seahaven_towers" => Some(Self::SeahavenTowers),
+            "bakers_game" => Some(Self::BakersGame),
             _ => None,
         }
     }
@@ -54,6 +122,18 @@ impl GameMode {
             Self::Klondike => "klondike",
             Self::Spider => "spider",
             Self::Freecell => "freecell",
+            Self::Yukon => "yukon",
+            Self::Pyramid => "pyramid",
+            Self::TriPeaks => "tripeaks",
+            Self::Golf => "golf",
+            Self::FortyThieves => "forty_thieves",
+            Self::Scorpion => "scorpion",
+            Self::Canfield => "canfield",
+            Self::BakersDozen => "bakers_dozen",
+            Self::BeleagueredCastle => "beleaguered_castle",
+            Self::EightOff => "eight_off",
+            Self::SeahavenTowers => "seahaven_towers",
+            Self::BakersGame => "bakers_game",
         }
     }
 
@@ -62,6 +142,18 @@ impl GameMode {
             Self::Klondike => "Klondike",
             Self::Spider => "Spider",
             Self::Freecell => "FreeCell",
+            Self::Yukon => "Yukon",
+            Self::Pyramid => "Pyramid",
+            Self::TriPeaks => "TriPeaks",
+            Self::Golf => "Golf",
+            Self::FortyThieves => "Forty Thieves",
+            Self::Scorpion => "Scorpion",
+            Self::Canfield => "Canfield",
+            Self::BakersDozen => "Baker's Dozen",
+            Self::BeleagueredCastle => "Beleaguered Castle",
+            Self::EightOff => "Eight Off",
+            Self::SeahavenTowers => "Seahaven Towers",
+            Self::BakersGame => "Baker's Game",
         }
     }
 
@@ -70,11 +162,48 @@ impl GameMode {
             Self::Klondike => "🥇",
             Self::Spider => "🕷️",
             Self::Freecell => "🗽",
+            Self::Yukon => "🐻",
+            Self::Pyramid => "🔺",
+            Self::TriPeaks => "⛰️",
+            Self::Golf => "⛳",
+            Self::FortyThieves => "🗡️",
+            Self::Scorpion => "🦂",
+            Self::Canfield => "🎰",
+            Self::BakersDozen => "🥖",
+            Self::BeleagueredCastle => "🏰",
+            Self::EightOff => "🎱",
+            Self::SeahavenTowers => "🌊",
+            Self::BakersGame => "🧁",
         }
     }
 
     pub fn engine_ready(self) -> bool {
-        matches!(self, Self::Klondike | Self::Spider | Self::Freecell)
+        matches!(
+            self,
+            Self::Klondike
+                | Self::Spider
+                | Self::Freecell
+                | Self::Yukon
+                | Self::Pyramid
+                | Self::TriPeaks
+                | Self::Golf
+                | Self::FortyThieves
+                | Self::Scorpion
+                | Self::Canfield
+                | Self::BakersDozen
+                | Self::BeleagueredCastle
+                | Self::EightOff
+                | Self::SeahavenTowers
+                | Self::BakersGame
+        )
+    }
+
+    /// Modes played on the shared FreeCell board (cells, four foundations, open columns).
+    pub fn is_freecell_family(self) -> bool {
+        matches!(
+            self,
+            Self::Freecell | Self::EightOff | Self::SeahavenTowers | Self::BakersGame
+        )
     }
 }
 
@@ -129,9 +258,44 @@ impl Card {
     }
 }
 
+/// Starting rank of a suit foundation. Most variants build from the Ace;
+/// Canfield starts every foundation at a dealt rank and wraps from King back
+/// to Ace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FoundationBase {
+    pub rank: u8,
+}
+
+impl FoundationBase {
+    pub const ACE: Self = Self { rank: 1 };
+
+    pub fn new(rank: u8) -> Option<Self> {
+        (1..=13).contains(&rank).then_some(Self { rank })
+    }
+
+    /// Rank that follows `rank` when building up, wrapping King to Ace.
+    pub fn next_rank(rank: u8) -> u8 {
+        rank % 13 + 1
+    }
+
+    /// Whether `card` may go onto a suit foundation holding `pile`.
+    pub fn accepts(self, pile: &[Card], card: Card) -> bool {
+        match pile.last() {
+            None => card.rank == self.rank,
+            Some(top) => {
+                pile.len() < 13 && top.suit == card.suit && card.rank == Self::next_rank(top.rank)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KlondikeGame {
     pub(super) draw_mode: DrawMode,
+    pub(super) pass_limit: KlondikePassLimit,
+    pub(super) recycles_used: u8,
+    /// Thoughtful Klondike: every tableau and stock card is shown face up.
+    pub(super) thoughtful: bool,
     pub(super) stock: Vec<Card>,
     pub(super) waste: Vec<Card>,
     pub(super) foundations: [Vec<Card>; 4],
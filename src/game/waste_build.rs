@@ -0,0 +1,443 @@
+use std::collections::HashMap;
+
+use super::setup::full_deck;
+use super::spider::{
+    decode_spider_pile, decode_spider_slots, encode_spider_pile, encode_spider_slots,
+};
+use super::{Card, DealRng, GameMode};
+
+pub const TRIPEAKS_SLOTS: usize = 28;
+pub const GOLF_COLUMNS: usize = 7;
+pub const GOLF_COLUMN_DEPTH: usize = 5;
+pub const GOLF_SLOTS: usize = GOLF_COLUMNS * GOLF_COLUMN_DEPTH;
+/// TriPeaks bonus for clearing one of the three peak cards.
+pub const TRIPEAKS_PEAK_BONUS: u32 = 15;
+
+/// First slot of each TriPeaks row: three peaks, then 6, 9 and 10 cards.
+const TRIPEAKS_ROW_STARTS: [usize; 4] = [0, 3, 9, 18];
+
+/// Tableau shapes sharing the "play onto the waste by one rank" engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WasteBuildLayout {
+    TriPeaks,
+    Golf,
+}
+
+impl WasteBuildLayout {
+    pub fn for_mode(mode: GameMode) -> Option<Self> {
+        match mode {
+            GameMode::TriPeaks => Some(Self::TriPeaks),
+            GameMode::Golf => Some(Self::Golf),
+            _ => None,
+        }
+    }
+
+    pub fn mode(self) -> GameMode {
+        match self {
+            Self::TriPeaks => GameMode::TriPeaks,
+            Self::Golf => GameMode::Golf,
+        }
+    }
+
+    pub fn slot_count(self) -> usize {
+        match self {
+            Self::TriPeaks => TRIPEAKS_SLOTS,
+            Self::Golf => GOLF_SLOTS,
+        }
+    }
+
+    fn id(self) -> &'static str {
+        match self {
+            Self::TriPeaks => "tripeaks",
+            Self::Golf => "golf",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "tripeaks" => Some(Self::TriPeaks),
+            "golf" => Some(Self::Golf),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WasteBuildRules {
+    /// Kings and aces count as adjacent ranks.
+    pub wrap_ranks: bool,
+    /// Each card played scores the length of the current streak.
+    pub streak_scoring: bool,
+}
+
+impl Default for WasteBuildRules {
+    fn default() -> Self {
+        Self {
+            wrap_ranks: false,
+            streak_scoring: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WasteBuildMove {
+    /// Play an exposed tableau slot onto the waste.
+    Play(usize),
+    Draw,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WasteBuildGame {
+    layout: WasteBuildLayout,
+    rules: WasteBuildRules,
+    tableau: Vec<Option<Card>>,
+    stock: Vec<Card>,
+    waste: Vec<Card>,
+    streak: u32,
+    best_streak: u32,
+    score: u32,
+}
+
+impl WasteBuildGame {
+    pub fn new_with_seed(layout: WasteBuildLayout, seed: u64) -> Self {
+        Self::new_with_seed_and_rules(layout, seed, WasteBuildRules::default())
+    }
+
+    pub fn new_with_seed_and_rules(
+        layout: WasteBuildLayout,
+        seed: u64,
+        rules: WasteBuildRules,
+    ) -> Self {
+        let mut deck = full_deck();
+        DealRng::from_seed(seed).shuffle(&mut deck);
+
+        let mut draw = deck.into_iter();
+        let mut tableau = Vec::with_capacity(layout.slot_count());
+        for _ in 0..layout.slot_count() {
+            tableau.push(draw.next());
+        }
+        let mut stock: Vec<Card> = draw.collect();
+        let mut waste = Vec::new();
+        if let Some(mut card) = stock.pop() {
+            card.face_up = true;
+            waste.push(card);
+        }
+
+        let mut game = Self {
+            layout,
+            rules,
+            tableau,
+            stock,
+            waste,
+            streak: 0,
+            best_streak: 0,
+            score: 0,
+        };
+        game.turn_up_exposed();
+        game
+    }
+
+    /// Faces follow the layout: exposed slots are face up, covered ones down.
+    pub(super) fn from_board(
+        layout: WasteBuildLayout,
+        rules: WasteBuildRules,
+        mut tableau: Vec<Option<Card>>,
+        stock: Vec<Card>,
+        waste: Vec<Card>,
+    ) -> Self {
+        for card in tableau.iter_mut().flatten() {
+            card.face_up = false;
+        }
+        let mut game = Self {
+            layout,
+            rules,
+            tableau,
+            stock,
+            waste,
+            streak: 0,
+            best_streak: 0,
+            score: 0,
+        };
+        game.turn_up_exposed();
+        game
+    }
+
+    /// Row and position within the row of a TriPeaks slot.
+    pub fn tripeaks_row_pos(slot: usize) -> (usize, usize) {
+        let row = TRIPEAKS_ROW_STARTS
+            .iter()
+            .rposition(|start| *start <= slot)
+            .unwrap_or(0);
+        (row, slot - TRIPEAKS_ROW_STARTS[row])
+    }
+
+    /// Column and depth (0 is the back card) of a Golf slot.
+    pub fn golf_col_depth(slot: usize) -> (usize, usize) {
+        (slot / GOLF_COLUMN_DEPTH, slot % GOLF_COLUMN_DEPTH)
+    }
+
+    /// Slots resting on `slot`; it is playable once they are all gone.
+    pub fn covering_slots(layout: WasteBuildLayout, slot: usize) -> [Option<usize>; 2] {
+        match layout {
+            WasteBuildLayout::TriPeaks => {
+                let (row, pos) = Self::tripeaks_row_pos(slot);
+                let first = match row {
+                    0 => TRIPEAKS_ROW_STARTS[1] + 2 * pos,
+                    1 => TRIPEAKS_ROW_STARTS[2] + 3 * (pos / 2) + pos % 2,
+                    2 => TRIPEAKS_ROW_STARTS[3] + pos,
+                    _ => return [None, None],
+                };
+                [Some(first), Some(first + 1)]
+            }
+            WasteBuildLayout::Golf => {
+                let (_, depth) = Self::golf_col_depth(slot);
+                [(depth + 1 < GOLF_COLUMN_DEPTH).then_some(slot + 1), None]
+            }
+        }
+    }
+
+    pub fn layout(&self) -> WasteBuildLayout {
+        self.layout
+    }
+
+    pub fn rules(&self) -> WasteBuildRules {
+        self.rules
+    }
+
+    /// Scoring can be switched mid-deal; rank wrapping needs a fresh deal.
+    pub fn set_streak_scoring(&mut self, enabled: bool) {
+        self.rules.streak_scoring = enabled;
+    }
+
+    pub fn tableau(&self) -> &[Option<Card>] {
+        &self.tableau
+    }
+
+    pub fn tableau_card(&self, slot: usize) -> Option<Card> {
+        self.tableau.get(slot).copied().flatten()
+    }
+
+    pub fn tableau_remaining(&self) -> usize {
+        self.tableau.iter().filter(|slot| slot.is_some()).count()
+    }
+
+    pub fn stock(&self) -> &[Card] {
+        &self.stock
+    }
+
+    pub fn stock_len(&self) -> usize {
+        self.stock.len()
+    }
+
+    pub fn waste(&self) -> &[Card] {
+        &self.waste
+    }
+
+    pub fn waste_top(&self) -> Option<Card> {
+        self.waste.last().copied()
+    }
+
+    pub fn streak(&self) -> u32 {
+        self.streak
+    }
+
+    pub fn best_streak(&self) -> u32 {
+        self.best_streak
+    }
+
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    pub fn is_exposed(&self, slot: usize) -> bool {
+        self.tableau_card(slot).is_some()
+            && Self::covering_slots(self.layout, slot)
+                .into_iter()
+                .flatten()
+                .all(|cover| self.tableau[cover].is_none())
+    }
+
+    /// Whether `card` may go onto `onto`: one rank apart, with K–A only when
+    /// wrapping. Golf without wrapping also closes the waste on a king.
+    pub fn ranks_connect(&self, onto: Card, card: Card) -> bool {
+        if self.layout == WasteBuildLayout::Golf && !self.rules.wrap_ranks && onto.rank == 13 {
+            return false;
+        }
+        let diff = onto.rank.abs_diff(card.rank);
+        diff == 1 || (self.rules.wrap_ranks && diff == 12)
+    }
+
+    pub fn can_play(&self, slot: usize) -> bool {
+        let (Some(card), Some(top)) = (self.tableau_card(slot), self.waste_top()) else {
+            return false;
+        };
+        self.is_exposed(slot) && self.ranks_connect(top, card)
+    }
+
+    pub fn play(&mut self, slot: usize) -> bool {
+        if !self.can_play(slot) {
+            return false;
+        }
+        let Some(mut card) = self.tableau[slot].take() else {
+            return false;
+        };
+        card.face_up = true;
+        self.waste.push(card);
+        self.streak += 1;
+        self.best_streak = self.best_streak.max(self.streak);
+        if self.rules.streak_scoring {
+            self.score += self.streak;
+            if self.layout == WasteBuildLayout::TriPeaks && slot < TRIPEAKS_ROW_STARTS[1] {
+                self.score += TRIPEAKS_PEAK_BONUS;
+            }
+        }
+        self.turn_up_exposed();
+        true
+    }
+
+    pub fn can_draw(&self) -> bool {
+        !self.stock.is_empty()
+    }
+
+    /// Turns the next stock card onto the waste. There is no redeal, and a
+    /// draw ends the current streak.
+    pub fn draw(&mut self) -> bool {
+        let Some(mut card) = self.stock.pop() else {
+            return false;
+        };
+        card.face_up = true;
+        self.waste.push(card);
+        self.streak = 0;
+        true
+    }
+
+    pub fn can_apply_move(&self, waste_build_move: WasteBuildMove) -> bool {
+        match waste_build_move {
+            WasteBuildMove::Play(slot) => self.can_play(slot),
+            WasteBuildMove::Draw => self.can_draw(),
+        }
+    }
+
+    pub fn apply_move(&mut self, waste_build_move: WasteBuildMove) -> bool {
+        match waste_build_move {
+            WasteBuildMove::Play(slot) => self.play(slot),
+            WasteBuildMove::Draw => self.draw(),
+        }
+    }
+
+    /// Every playable slot in slot order, then the stock.
+    pub fn legal_moves(&self) -> Vec<WasteBuildMove> {
+        let mut moves: Vec<WasteBuildMove> = (0..self.tableau.len())
+            .filter(|slot| self.can_play(*slot))
+            .map(WasteBuildMove::Play)
+            .collect();
+        if self.can_draw() {
+            moves.push(WasteBuildMove::Draw);
+        }
+        moves
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.tableau.iter().all(Option::is_none)
+    }
+
+    pub fn has_legal_moves(&self) -> bool {
+        !self.is_won() && !self.legal_moves().is_empty()
+    }
+
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && !self.has_legal_moves()
+    }
+
+    fn turn_up_exposed(&mut self) {
+        for slot in 0..self.tableau.len() {
+            if self.is_exposed(slot) {
+                if let Some(card) = self.tableau[slot].as_mut() {
+                    card.face_up = true;
+                }
+            }
+        }
+    }
+
+    pub fn encode_for_session(&self) -> String {
+        let parts = [
+            format!("layout={}", self.layout.id()),
+            format!("wrap={}", u8::from(self.rules.wrap_ranks)),
+            format!("scoring={}", u8::from(self.rules.streak_scoring)),
+            format!("t={}", encode_spider_slots(&self.tableau)),
+            format!("s={}", encode_spider_pile(&self.stock)),
+            format!("w={}", encode_spider_pile(&self.waste)),
+            format!("streak={}", self.streak),
+            format!("best={}", self.best_streak),
+            format!("score={}", self.score),
+        ];
+        parts.join(";")
+    }
+
+    pub fn decode_from_session(data: &str) -> Option<Self> {
+        let mut fields = HashMap::<&str, &str>::new();
+        for part in data.split(';') {
+            let (key, value) = part.split_once('=')?;
+            fields.insert(key, value);
+        }
+
+        let layout = WasteBuildLayout::from_id(fields.get("layout")?)?;
+        let flag = |key: &str| match *fields.get(key)? {
+            "0" => Some(false),
+            "1" => Some(true),
+            _ => None,
+        };
+        let rules = WasteBuildRules {
+            wrap_ranks: flag("wrap")?,
+            streak_scoring: flag("scoring")?,
+        };
+        let tableau = decode_spider_slots(fields.get("t")?)?;
+        if tableau.len() != layout.slot_count() {
+            return None;
+        }
+        let stock = decode_spider_pile(fields.get("s")?)?;
+        let waste = decode_spider_pile(fields.get("w")?)?;
+        let number = |key: &str| fields.get(key)?.parse::<u32>().ok();
+
+        let tableau_count = tableau.iter().filter(|slot| slot.is_some()).count();
+        if tableau_count + stock.len() + waste.len() != 52 {
+            return None;
+        }
+
+        Some(Self {
+            layout,
+            rules,
+            tableau,
+            stock,
+            waste,
+            streak: number("streak")?,
+            best_streak: number("best")?,
+            score: number("score")?,
+        })
+    }
+}
+
+#[cfg(test)]
+impl WasteBuildGame {
+    pub(crate) fn debug_new(
+        layout: WasteBuildLayout,
+        rules: WasteBuildRules,
+        tableau: Vec<Option<Card>>,
+        stock: Vec<Card>,
+        waste: Vec<Card>,
+    ) -> Self {
+        assert_eq!(tableau.len(), layout.slot_count());
+        let mut game = Self {
+            layout,
+            rules,
+            tableau,
+            stock,
+            waste,
+            streak: 0,
+            best_streak: 0,
+            score: 0,
+        };
+        game.turn_up_exposed();
+        game
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasteBuildSolveResult {
+    pub winnable: bool,
+    pub explored_states: usize,
+    pub hit_state_limit: bool,
+    /// Winning line from the analysed position; empty unless `winnable`.
+    pub line: Vec<WasteBuildMove>,
+}
+
+struct WasteBuildSearch<'a> {
+    visited: HashSet<u64>,
+    max_states: usize,
+    cancel: &'a AtomicBool,
+    hit_state_limit: bool,
+    canceled: bool,
+    line: Vec<WasteBuildMove>,
+}
+
+impl WasteBuildGame {
+    pub fn solve(&self, max_states: usize) -> WasteBuildSolveResult {
+        let cancel = AtomicBool::new(false);
+        self.solve_cancelable(max_states, &cancel)
+            .unwrap_or(WasteBuildSolveResult {
+                winnable: false,
+                explored_states: 0,
+                hit_state_limit: true,
+                line: Vec::new(),
+            })
+    }
+
+    /// Depth-first search over plays and draws. The stock order is fixed, so
+    /// a position is just which slots are cleared, how far the stock has run
+    /// and the waste top; both layouts stay small enough to exhaust.
+    pub fn solve_cancelable(
+        &self,
+        max_states: usize,
+        cancel: &AtomicBool,
+    ) -> Option<WasteBuildSolveResult> {
+        let mut search = WasteBuildSearch {
+            visited: HashSet::new(),
+            max_states: max_states.max(1),
+            cancel,
+            hit_state_limit: false,
+            canceled: false,
+            line: Vec::new(),
+        };
+        let winnable = search.search(self);
+        if search.canceled {
+            return None;
+        }
+        Some(WasteBuildSolveResult {
+            winnable,
+            explored_states: search.visited.len(),
+            hit_state_limit: !winnable && search.hit_state_limit,
+            line: if winnable { search.line } else { Vec::new() },
+        })
+    }
+
+    fn solver_key(&self) -> u64 {
+        let cleared = self
+            .tableau()
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.is_none())
+            .fold(0_u64, |mask, (slot, _)| mask | (1 << slot));
+        let mut hasher = DefaultHasher::new();
+        cleared.hash(&mut hasher);
+        self.stock_len().hash(&mut hasher);
+        self.waste_top().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Candidate moves in search order: plays that uncover the most cards
+    /// first, the stock last.
+    pub fn solver_moves(&self) -> Vec<WasteBuildMove> {
+        let mut ordered: Vec<(i32, WasteBuildMove)> = self
+            .legal_moves()
+            .into_iter()
+            .map(|candidate| (self.solver_move_priority(candidate), candidate))
+            .collect();
+        ordered.sort_by_key(|(priority, _)| -priority);
+        ordered
+            .into_iter()
+            .map(|(_, candidate)| candidate)
+            .collect()
+    }
+
+    fn solver_move_priority(&self, candidate: WasteBuildMove) -> i32 {
+        let WasteBuildMove::Play(slot) = candidate else {
+            return -1;
+        };
+        let mut after = self.clone();
+        if !after.play(slot) {
+            return -1;
+        }
+        // Favour plays that free covered cards and keep the streak going.
+        let exposed = |game: &WasteBuildGame| {
+            (0..game.tableau().len())
+                .filter(|candidate| game.is_exposed(*candidate))
+                .count() as i32
+        };
+        let follow_ups = after
+            .legal_moves()
+            .iter()
+            .filter(|next| matches!(next, WasteBuildMove::Play(_)))
+            .count() as i32;
+        10 + 4 * (exposed(&after) - exposed(self)) + follow_ups
+    }
+}
+
+impl WasteBuildSearch<'_> {
+    fn search(&mut self, state: &WasteBuildGame) -> bool {
+        if state.is_won() {
+            return true;
+        }
+        if self.cancel.load(AtomicOrdering::Relaxed) {
+            self.canceled = true;
+            return false;
+        }
+        if self.visited.len() >= self.max_states {
+            self.hit_state_limit = true;
+            return false;
+        }
+        if !self.visited.insert(state.solver_key()) {
+            return false;
+        }
+
+        for candidate in state.solver_moves() {
+            let mut next = state.clone();
+            if !next.apply_move(candidate) {
+                continue;
+            }
+            self.line.push(candidate);
+            if self.search(&next) {
+                return true;
+            }
+            self.line.pop();
+            if self.canceled {
+                return false;
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,349 @@
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+
+use super::setup::full_deck;
+use super::spider::{decode_spider_pile, encode_spider_pile};
+use super::{Card, DealRng};
+
+/// Building rule for Yukon-family deals. Russian Solitaire uses the Yukon
+/// layout but builds down in suit instead of alternating colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YukonRuleSet {
+    Yukon,
+    Russian,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct YukonGame {
+    rule_set: YukonRuleSet,
+    foundations: [Vec<Card>; 4],
+    tableau: [Vec<Card>; 7],
+}
+
+impl YukonGame {
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self::new_with_seed_and_rules(seed, YukonRuleSet::Yukon)
+    }
+
+    pub fn new_with_seed_and_rules(seed: u64, rule_set: YukonRuleSet) -> Self {
+        let mut deck = full_deck();
+        DealRng::from_seed(seed).shuffle(&mut deck);
+
+        let mut game = Self {
+            rule_set,
+            foundations: std::array::from_fn(|_| Vec::new()),
+            tableau: std::array::from_fn(|_| Vec::new()),
+        };
+
+        // Klondike-shaped face-down triangle, then the 24 cards Klondike would
+        // keep in the stock are dealt face up onto columns 2-7 (five each).
+        let mut draw = deck.into_iter();
+        for col in 0..7 {
+            let col_size = if col == 0 { 1 } else { col + 5 };
+            for row in 0..col_size {
+                let mut card = draw.next().expect("yukon setup consumes 52 cards");
+                card.face_up = row >= col;
+                game.tableau[col].push(card);
+            }
+        }
+
+        game
+    }
+
+    pub(super) fn from_board(
+        rule_set: YukonRuleSet,
+        foundations: [Vec<Card>; 4],
+        tableau: [Vec<Card>; 7],
+    ) -> Self {
+        Self {
+            rule_set,
+            foundations,
+            tableau,
+        }
+    }
+
+    pub fn rule_set(&self) -> YukonRuleSet {
+        self.rule_set
+    }
+
+    pub fn foundations(&self) -> &[Vec<Card>; 4] {
+        &self.foundations
+    }
+
+    pub fn tableau(&self) -> &[Vec<Card>; 7] {
+        &self.tableau
+    }
+
+    pub fn tableau_card(&self, col: usize, index: usize) -> Option<Card> {
+        self.tableau
+            .get(col)
+            .and_then(|pile| pile.get(index))
+            .copied()
+    }
+
+    pub fn tableau_top(&self, col: usize) -> Option<Card> {
+        self.tableau.get(col).and_then(|pile| pile.last().copied())
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.len() == 13)
+    }
+
+    pub fn has_legal_moves(&self) -> bool {
+        if self.is_won() {
+            return false;
+        }
+        for src in 0..self.tableau.len() {
+            if self.can_move_tableau_top_to_foundation(src) {
+                return true;
+            }
+            for start in 0..self.tableau[src].len() {
+                for dst in 0..self.tableau.len() {
+                    if self.can_move_run(src, start, dst) {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && !self.has_legal_moves()
+    }
+
+    /// Any face-up card moves together with everything stacked on it; only the
+    /// moved card has to fit the destination.
+    pub fn can_move_run(&self, src: usize, start: usize, dst: usize) -> bool {
+        if src == dst || src >= self.tableau.len() || dst >= self.tableau.len() {
+            return false;
+        }
+        let Some(card) = self.tableau_card(src, start) else {
+            return false;
+        };
+        if !card.face_up {
+            return false;
+        }
+        match self.tableau[dst].last() {
+            None => card.rank == 13 && start > 0,
+            Some(top) => top.face_up && self.builds_on(card, *top),
+        }
+    }
+
+    pub fn move_run(&mut self, src: usize, start: usize, dst: usize) -> bool {
+        if !self.can_move_run(src, start, dst) {
+            return false;
+        }
+        let moved = self.tableau[src].split_off(start);
+        self.tableau[dst].extend(moved);
+        self.flip_top_if_needed(src);
+        true
+    }
+
+    pub fn can_move_tableau_top_to_foundation(&self, src: usize) -> bool {
+        self.tableau_top(src)
+            .is_some_and(|card| card.face_up && self.can_place_on_foundation(card))
+    }
+
+    pub fn move_tableau_top_to_foundation(&mut self, src: usize) -> bool {
+        if !self.can_move_tableau_top_to_foundation(src) {
+            return false;
+        }
+        let Some(card) = self.tableau[src].pop() else {
+            return false;
+        };
+        self.foundations[card.suit.foundation_index()].push(card);
+        self.flip_top_if_needed(src);
+        true
+    }
+
+    pub fn can_move_foundation_top_to_tableau(&self, foundation_idx: usize, dst: usize) -> bool {
+        let Some(card) = self
+            .foundations
+            .get(foundation_idx)
+            .and_then(|pile| pile.last().copied())
+        else {
+            return false;
+        };
+        let Some(pile) = self.tableau.get(dst) else {
+            return false;
+        };
+        match pile.last() {
+            None => card.rank == 13,
+            Some(top) => top.face_up && self.builds_on(card, *top),
+        }
+    }
+
+    pub fn move_foundation_top_to_tableau(&mut self, foundation_idx: usize, dst: usize) -> bool {
+        if !self.can_move_foundation_top_to_tableau(foundation_idx, dst) {
+            return false;
+        }
+        let Some(card) = self.foundations[foundation_idx].pop() else {
+            return false;
+        };
+        self.tableau[dst].push(card);
+        true
+    }
+
+    pub fn cyclone_shuffle_tableau(&mut self) -> bool {
+        let original = self.tableau.clone();
+        let column_geometry: Vec<(usize, usize)> = self
+            .tableau
+            .iter()
+            .map(|pile| {
+                let face_down = pile.iter().filter(|card| !card.face_up).count();
+                (face_down, pile.len().saturating_sub(face_down))
+            })
+            .collect();
+
+        let mut cards: Vec<Card> = self
+            .tableau
+            .iter()
+            .flat_map(|pile| pile.iter().copied())
+            .collect();
+        if cards.len() < 2 {
+            return false;
+        }
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..8 {
+            cards.shuffle(&mut rng);
+
+            let mut cursor = 0_usize;
+            for (col, pile) in self.tableau.iter_mut().enumerate() {
+                let (face_down, face_up) = column_geometry[col];
+                pile.clear();
+                for idx in 0..face_down + face_up {
+                    let mut card = cards[cursor];
+                    cursor += 1;
+                    card.face_up = idx >= face_down;
+                    pile.push(card);
+                }
+            }
+
+            if self.tableau != original {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn encode_for_session(&self) -> String {
+        let parts = [
+            format!("rules={}", self.rule_set.session_tag()),
+            format!("f0={}", encode_spider_pile(&self.foundations[0])),
+            format!("f1={}", encode_spider_pile(&self.foundations[1])),
+            format!("f2={}", encode_spider_pile(&self.foundations[2])),
+            format!("f3={}", encode_spider_pile(&self.foundations[3])),
+            format!("t0={}", encode_spider_pile(&self.tableau[0])),
+            format!("t1={}", encode_spider_pile(&self.tableau[1])),
+            format!("t2={}", encode_spider_pile(&self.tableau[2])),
+            format!("t3={}", encode_spider_pile(&self.tableau[3])),
+            format!("t4={}", encode_spider_pile(&self.tableau[4])),
+            format!("t5={}", encode_spider_pile(&self.tableau[5])),
+            format!("t6={}", encode_spider_pile(&self.tableau[6])),
+        ];
+        parts.join(";")
+    }
+
+    pub fn decode_from_session(data: &str) -> Option<Self> {
+        let mut fields = HashMap::<&str, &str>::new();
+        for part in data.split(';') {
+            let (key, value) = part.split_once('=')?;
+            fields.insert(key, value);
+        }
+
+        let rule_set = YukonRuleSet::from_session_tag(fields.get("rules")?)?;
+        let foundations = [
+            decode_spider_pile(fields.get("f0")?)?,
+            decode_spider_pile(fields.get("f1")?)?,
+            decode_spider_pile(fields.get("f2")?)?,
+            decode_spider_pile(fields.get("f3")?)?,
+        ];
+        let tableau = [
+            decode_spider_pile(fields.get("t0")?)?,
+            decode_spider_pile(fields.get("t1")?)?,
+            decode_spider_pile(fields.get("t2")?)?,
+            decode_spider_pile(fields.get("t3")?)?,
+            decode_spider_pile(fields.get("t4")?)?,
+            decode_spider_pile(fields.get("t5")?)?,
+            decode_spider_pile(fields.get("t6")?)?,
+        ];
+
+        let foundations_count: usize = foundations.iter().map(Vec::len).sum();
+        let tableau_count: usize = tableau.iter().map(Vec::len).sum();
+        if foundations_count + tableau_count != 52 {
+            return None;
+        }
+
+        Some(Self {
+            rule_set,
+            foundations,
+            tableau,
+        })
+    }
+
+    fn builds_on(&self, card: Card, target: Card) -> bool {
+        if target.rank != card.rank + 1 {
+            return false;
+        }
+        match self.rule_set {
+            YukonRuleSet::Yukon => target.color_red() != card.color_red(),
+            YukonRuleSet::Russian => target.suit == card.suit,
+        }
+    }
+
+    fn can_place_on_foundation(&self, card: Card) -> bool {
+        let pile = &self.foundations[card.suit.foundation_index()];
+        match pile.last() {
+            None => card.rank == 1,
+            Some(top) => top.suit == card.suit && card.rank == top.rank + 1,
+        }
+    }
+
+    fn flip_top_if_needed(&mut self, col: usize) {
+        if let Some(card) = self.tableau[col].last_mut() {
+            card.face_up = true;
+        }
+    }
+}
+
+impl YukonRuleSet {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Yukon => "Yukon",
+            Self::Russian => "Russian",
+        }
+    }
+
+    pub fn session_tag(self) -> &'static str {
+        match self {
+            Self::Yukon => "yukon",
+            Self::Russian => "russian",
+        }
+    }
+
+    pub fn from_session_tag(value: &str) -> Option<Self> {
+        match value {
+            "yukon" => Some(Self::Yukon),
+            "russian" => Some(Self::Russian),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+impl YukonGame {
+    pub(crate) fn debug_new(
+        rule_set: YukonRuleSet,
+        foundations: [Vec<Card>; 4],
+        tableau: [Vec<Card>; 7],
+    ) -> Self {
+        Self {
+            rule_set,
+            foundations,
+            tableau,
+        }
+    }
+}
@@ -30,7 +30,7 @@ mod winnability;
 use self::application::CardthropicApplication;
 use self::window::CardthropicWindow;
 use crate::engine::automation::FREECELL_AUTOMATION_PROFILE;
-use crate::game::FreecellCardCountMode;
+use crate::game::{FreecellCardCountMode, FreecellLayout, FreecellRules, DEAL_ALGORITHM};
 
 use config::{GETTEXT_PACKAGE, LOCALEDIR, PKGDATADIR};
 use gettextrs::{bind_textdomain_codeset, bindtextdomain, textdomain};
@@ -186,6 +186,7 @@ fn run_freecell_benchmark(options: &FreecellBenchmarkOptions) -> Result<(), Stri
             attempts,
             profile.dialog_seed_guided_budget,
             profile.dialog_seed_exhaustive_budget,
+            FreecellLayout::FreeCell,
             card_count_mode,
             thread_cancel,
             Some(thread_progress),
@@ -255,6 +256,8 @@ fn run_freecell_benchmark(options: &FreecellBenchmarkOptions) -> Result<(), Stri
         thread::spawn(move || {
             let result = winnability::is_freecell_seed_winnable(
                 seed,
+                FreecellLayout::FreeCell,
+                FreecellRules::CLASSIC,
                 wcheck_card_count_mode,
                 wcheck_guided_budget,
                 wcheck_exhaustive_budget,
@@ -303,7 +306,7 @@ fn run_freecell_benchmark(options: &FreecellBenchmarkOptions) -> Result<(), Stri
         .unwrap_or(start_unix);
 
     let payload = format!(
-        "{{\n  \"version\": 1,\n  \"generated_at_unix\": {generated_at},\n  \"mode\": \"freecell\",\n  \"card_count\": {card_count},\n  \"start_seed\": {},\n  \"attempts\": {},\n  \"find_winnable\": {{\n    \"checked\": {checked_final},\n    \"elapsed_seconds\": {:.3},\n    \"seeds_per_second\": {:.3},\n    \"found\": {found_seed_json}\n  }},\n  \"wcheck\": {{\n    \"checked\": {wcheck_attempts},\n    \"wins\": {wcheck_wins},\n    \"win_rate\": {:.6},\n    \"timed_out\": {wcheck_timed_out},\n    \"seed_timeout_ms\": {},\n    \"elapsed_seconds\": {:.3}\n  }},\n  \"memory\": {{\n    \"peak_rss_mib\": {peak_rss_mib}\n  }}\n}}\n",
+        "{{\n  \"version\": 1,\n  \"deal_algorithm\": {DEAL_ALGORITHM},\n  \"generated_at_unix\": {generated_at},\n  \"mode\": \"freecell\",\n  \"card_count\": {card_count},\n  \"start_seed\": {},\n  \"attempts\": {},\n  \"find_winnable\": {{\n    \"checked\": {checked_final},\n    \"elapsed_seconds\": {:.3},\n    \"seeds_per_second\": {:.3},\n    \"found\": {found_seed_json}\n  }},\n  \"wcheck\": {{\n    \"checked\": {wcheck_attempts},\n    \"wins\": {wcheck_wins},\n    \"win_rate\": {:.6},\n    \"timed_out\": {wcheck_timed_out},\n    \"seed_timeout_ms\": {},\n    \"elapsed_seconds\": {:.3}\n  }},\n  \"memory\": {{\n    \"peak_rss_mib\": {peak_rss_mib}\n  }}\n}}\n",
         options.start_seed,
         options.attempts,
         find_elapsed_s,
@@ -32,20 +32,26 @@ use gtk::{gdk, gdk_pixbuf, gio, glib};
 
 use crate::deck::AngloDeck;
 use crate::engine::automation::AutomationProfile;
+use crate::engine::daily::{ChallengeDate, DailyChallengeStore};
 use crate::engine::freecell_planner::FreecellPlannerAction;
 use crate::engine::hinting::{HintNode, HintSuggestion};
 use crate::engine::keyboard_nav::KeyboardTarget;
 use crate::engine::loss_analysis::LossVerdict;
 use crate::engine::moves::{apply_hint_move_to_game, map_solver_line_to_hint_line, HintMove};
+use crate::engine::replay::ReplayLog;
 use crate::engine::robot::RobotPlayback;
+use crate::engine::scoring::ScoringSystem;
 use crate::engine::seed_history::SeedHistoryStore;
+use crate::engine::stats::{StatsKey, VariantStatsStore};
 use crate::engine::variant::variant_for_mode;
-use crate::engine::variant_engine::engine_for_mode;
+use crate::engine::variant_engine::{engine_for_mode, CANFIELD_RESERVE_CELL};
 use crate::engine::variant_state::VariantStateStore;
 use crate::game::{
-    Card, ChessPosition, ChessVariant, DrawMode, FreecellCardCountMode, GameMode, KlondikeGame,
-    SolverMove, SpiderSuitMode, Square, Suit, FREECELL_DEFAULT_CELL_COUNT, FREECELL_MAX_CELL_COUNT,
-    FREECELL_MIN_CELL_COUNT,
+    CanfieldMove, Card, ChessPosition, ChessVariant, DrawMode, FreecellCardCountMode,
+    FreecellLayout, GameMode, KlondikeGame, KlondikePassLimit, OpenTableauLayout, PyramidCardRef,
+    PyramidMove, SolverMove, SpiderRuleSet, SpiderSuitMode, Square, Suit, WasteBuildLayout,
+    WasteBuildMove, YukonRuleSet, CANFIELD_COLUMNS, FREECELL_DEFAULT_CELL_COUNT,
+    FREECELL_MAX_CELL_COUNT, FREECELL_MIN_CELL_COUNT,
 };
 use crate::startup_trace;
 use crate::winnability;
@@ -62,6 +68,10 @@ mod ai;
 mod ai_winnability_check;
 #[path = "window/chess/mod.rs"]
 mod chess;
+#[path = "window/daily.rs"]
+mod daily;
+#[path = "window/deal_editor.rs"]
+mod deal_editor;
 #[path = "window/dialogs_apm.rs"]
 mod dialogs_apm;
 #[path = "window/dialogs_command_search.rs"]
@@ -114,8 +124,12 @@ mod render_cards;
 mod render_stock_waste_foundation;
 #[path = "window/render_tableau.rs"]
 mod render_tableau;
+#[path = "window/replay.rs"]
+mod replay;
 #[path = "window/robot.rs"]
 mod robot;
+#[path = "window/scoring.rs"]
+mod scoring;
 #[path = "window/seed.rs"]
 mod seed;
 #[path = "window/seed_history.rs"]
@@ -128,6 +142,8 @@ mod session;
 mod shortcut_labels;
 #[path = "window/state.rs"]
 mod state;
+#[path = "window/stats.rs"]
+mod stats;
 #[path = "window/theme_color.rs"]
 mod theme_color;
 #[path = "window/theme_core.rs"]
@@ -190,6 +206,10 @@ mod imp {
         #[template_child]
         pub waste_picture_6: TemplateChild<gtk::Picture>,
         #[template_child]
+        pub waste_picture_7: TemplateChild<gtk::Picture>,
+        #[template_child]
+        pub waste_picture_8: TemplateChild<gtk::Picture>,
+        #[template_child]
         pub waste_placeholder_box: TemplateChild<gtk::Box>,
         #[template_child]
         pub waste_placeholder_label: TemplateChild<gtk::Label>,
@@ -301,6 +321,12 @@ mod imp {
         #[template_child]
         pub tableau_stack_10: TemplateChild<gtk::Fixed>,
         #[template_child]
+        pub tableau_stack_11: TemplateChild<gtk::Fixed>,
+        #[template_child]
+        pub tableau_stack_12: TemplateChild<gtk::Fixed>,
+        #[template_child]
+        pub tableau_stack_13: TemplateChild<gtk::Fixed>,
+        #[template_child]
         pub tableau_scroller: TemplateChild<gtk::ScrolledWindow>,
         #[template_child]
         pub tableau_canvas: TemplateChild<gtk::Fixed>,
@@ -344,9 +370,11 @@ mod imp {
         pub chess_last_move_to: Cell<Option<Square>>,
         pub chess_square_size: Cell<i32>,
         pub chess_drag_hover_row_from_top: Cell<Option<i32>>,
+        pub chess_blast_preview_target: Cell<Option<Square>>,
         pub chess_board_rotation_degrees: Cell<i32>,
         pub chess_history: RefCell<Vec<ChessPosition>>,
         pub chess_future: RefCell<Vec<ChessPosition>>,
+        pub chess960_start_number: Cell<Option<u16>>,
         pub(crate) chess_ai_pending_search: RefCell<Option<crate::engine::chess::ai::AsyncSearch>>,
         pub(crate) chess_ai_search_poll_timer: RefCell<Option<glib::SourceId>>,
         pub(crate) chess_ai_pending_kind: Cell<Option<ChessAiPendingKind>>,
@@ -357,9 +385,43 @@ mod imp {
         pub(crate) chess_ai_pending_legal_moves: Cell<u32>,
         pub(crate) chess_ai_pending_limits: Cell<Option<crate::engine::chess::ai::SearchLimits>>,
         pub(crate) chess_ai_pending_wait_log_step: Cell<u32>,
+        pub(crate) chess_ai_ponder_search: RefCell<Option<crate::engine::chess::ai::AsyncSearch>>,
+        pub(crate) chess_ai_ponder_position_hash: Cell<Option<u64>>,
+        pub(crate) chess_ai_ponder_started_mono_us: Cell<i64>,
+        pub(crate) chess_ai_transposition_table: crate::engine::chess::ai::SharedTranspositionTable,
         pub(crate) chess_last_system_sound_mono_us: Cell<i64>,
         pub current_seed: Cell<u64>,
         pub current_seed_win_recorded: Cell<bool>,
+        pub seedless_board: Cell<bool>,
+        pub(super) variant_stats: RefCell<VariantStatsStore>,
+        pub(super) stats_game_key: RefCell<Option<StatsKey>>,
+        pub stats_game_assisted: Cell<bool>,
+        pub stats_dialog: RefCell<Option<gtk::Window>>,
+        pub stats_grid: RefCell<Option<gtk::Grid>>,
+        pub(super) daily_challenges: RefCell<DailyChallengeStore>,
+        pub(super) daily_game: RefCell<Option<(ChallengeDate, StatsKey)>>,
+        pub daily_dialog: RefCell<Option<gtk::Window>>,
+        pub daily_calendar: RefCell<Option<gtk::Calendar>>,
+        pub daily_status_label: RefCell<Option<gtk::Label>>,
+        pub deal_editor_dialog: RefCell<Option<gtk::Window>>,
+        pub deal_editor_buffer: RefCell<Option<gtk::TextBuffer>>,
+        pub deal_editor_status_label: RefCell<Option<gtk::Label>>,
+        pub deal_editor_check_cancel: RefCell<Option<Arc<AtomicBool>>>,
+        pub(super) replay_log: RefCell<Option<ReplayLog>>,
+        pub replay_log_id: Cell<u64>,
+        pub replay_board_id: Cell<u64>,
+        pub replay_base_moves: Cell<u32>,
+        pub(super) replay_last_game: RefCell<Option<ReplayLog>>,
+        pub(super) replay_stash: RefCell<Option<ReplayStash>>,
+        pub(super) replay_viewer_log: RefCell<Option<ReplayLog>>,
+        pub replay_viewer_position: Cell<usize>,
+        pub replay_play_timer: RefCell<Option<glib::SourceId>>,
+        pub replay_dialog: RefCell<Option<gtk::Window>>,
+        pub replay_scale: RefCell<Option<gtk::Scale>>,
+        pub replay_position_label: RefCell<Option<gtk::Label>>,
+        pub replay_info_label: RefCell<Option<gtk::Label>>,
+        pub replay_play_button: RefCell<Option<gtk::Button>>,
+        pub replay_status_label: RefCell<Option<gtk::Label>>,
         pub(super) seed_history: RefCell<SeedHistoryStore>,
         pub seed_history_dirty: Cell<bool>,
         pub seed_history_dropdown_dirty: Cell<bool>,
@@ -403,6 +465,8 @@ mod imp {
         pub(super) apm_samples: RefCell<Vec<ApmSample>>,
         pub apm_elapsed_offset_seconds: Cell<u32>,
         pub move_count: Cell<u32>,
+        pub scoring_system: Cell<ScoringSystem>,
+        pub score: Cell<i32>,
         pub elapsed_seconds: Cell<u32>,
         pub timer_started: Cell<bool>,
         pub style_provider: RefCell<Option<gtk::CssProvider>>,
@@ -522,6 +586,18 @@ mod imp {
         pub(super) robot_freecell_planner_cooldown_ticks: Cell<u32>,
         pub(super) robot_freecell_planner_restart_debounce_ticks: Cell<u32>,
         pub(super) robot_freecell_planner_last_start_marker: Cell<u64>,
+        pub(super) robot_pyramid_plan: RefCell<VecDeque<PyramidMove>>,
+        pub(super) robot_pyramid_plan_anchor_hash: Cell<u64>,
+        pub(super) robot_waste_build_plan: RefCell<VecDeque<WasteBuildMove>>,
+        pub(super) robot_waste_build_plan_anchor_hash: Cell<u64>,
+        pub(super) robot_forty_thieves_plan: RefCell<VecDeque<SolverMove>>,
+        pub(super) robot_forty_thieves_plan_anchor_hash: Cell<u64>,
+        pub(super) robot_scorpion_plan: RefCell<VecDeque<SolverMove>>,
+        pub(super) robot_scorpion_plan_anchor_hash: Cell<u64>,
+        pub(super) robot_canfield_plan: RefCell<VecDeque<CanfieldMove>>,
+        pub(super) robot_canfield_plan_anchor_hash: Cell<u64>,
+        pub(super) robot_open_tableau_plan: RefCell<VecDeque<SolverMove>>,
+        pub(super) robot_open_tableau_plan_anchor_hash: Cell<u64>,
         pub(super) robot_cpu_last_exec_ns: Cell<u64>,
         pub(super) robot_cpu_last_mono_us: Cell<i64>,
         pub(super) robot_cpu_last_pct: Cell<f64>,
@@ -580,6 +656,8 @@ mod imp {
                 waste_picture_4: TemplateChild::default(),
                 waste_picture_5: TemplateChild::default(),
                 waste_picture_6: TemplateChild::default(),
+                waste_picture_7: TemplateChild::default(),
+                waste_picture_8: TemplateChild::default(),
                 waste_placeholder_box: TemplateChild::default(),
                 waste_placeholder_label: TemplateChild::default(),
                 stock_label: TemplateChild::default(),
@@ -635,6 +713,9 @@ mod imp {
                 tableau_stack_8: TemplateChild::default(),
                 tableau_stack_9: TemplateChild::default(),
                 tableau_stack_10: TemplateChild::default(),
+                tableau_stack_11: TemplateChild::default(),
+                tableau_stack_12: TemplateChild::default(),
+                tableau_stack_13: TemplateChild::default(),
                 tableau_scroller: TemplateChild::default(),
                 tableau_canvas: TemplateChild::default(),
                 tableau_row: TemplateChild::default(),
@@ -662,9 +743,11 @@ mod imp {
                 chess_last_move_to: Cell::new(None),
                 chess_square_size: Cell::new(64),
                 chess_drag_hover_row_from_top: Cell::new(None),
+                chess_blast_preview_target: Cell::new(None),
                 chess_board_rotation_degrees: Cell::new(0),
                 chess_history: RefCell::new(Vec::new()),
                 chess_future: RefCell::new(Vec::new()),
+                chess960_start_number: Cell::new(None),
                 chess_ai_pending_search: RefCell::new(None),
                 chess_ai_search_poll_timer: RefCell::new(None),
                 chess_ai_pending_kind: Cell::new(None),
@@ -675,9 +758,43 @@ mod imp {
                 chess_ai_pending_legal_moves: Cell::new(0),
                 chess_ai_pending_limits: Cell::new(None),
                 chess_ai_pending_wait_log_step: Cell::new(0),
+                chess_ai_ponder_search: RefCell::new(None),
+                chess_ai_ponder_position_hash: Cell::new(None),
+                chess_ai_ponder_started_mono_us: Cell::new(0),
+                chess_ai_transposition_table: Default::default(),
                 chess_last_system_sound_mono_us: Cell::new(0),
                 current_seed: Cell::new(seed),
                 current_seed_win_recorded: Cell::new(false),
+                seedless_board: Cell::new(false),
+                variant_stats: RefCell::new(VariantStatsStore::default()),
+                stats_game_key: RefCell::new(None),
+                stats_game_assisted: Cell::new(false),
+                stats_dialog: RefCell::new(None),
+                stats_grid: RefCell::new(None),
+                daily_challenges: RefCell::new(DailyChallengeStore::default()),
+                daily_game: RefCell::new(None),
+                daily_dialog: RefCell::new(None),
+                daily_calendar: RefCell::new(None),
+                daily_status_label: RefCell::new(None),
+                deal_editor_dialog: RefCell::new(None),
+                deal_editor_buffer: RefCell::new(None),
+                deal_editor_status_label: RefCell::new(None),
+                deal_editor_check_cancel: RefCell::new(None),
+                replay_log: RefCell::new(None),
+                replay_log_id: Cell::new(0),
+                replay_board_id: Cell::new(0),
+                replay_base_moves: Cell::new(0),
+                replay_last_game: RefCell::new(None),
+                replay_stash: RefCell::new(None),
+                replay_viewer_log: RefCell::new(None),
+                replay_viewer_position: Cell::new(0),
+                replay_play_timer: RefCell::new(None),
+                replay_dialog: RefCell::new(None),
+                replay_scale: RefCell::new(None),
+                replay_position_label: RefCell::new(None),
+                replay_info_label: RefCell::new(None),
+                replay_play_button: RefCell::new(None),
+                replay_status_label: RefCell::new(None),
                 seed_history: RefCell::new(SeedHistoryStore::default()),
                 seed_history_dirty: Cell::new(false),
                 seed_history_dropdown_dirty: Cell::new(false),
@@ -720,6 +837,8 @@ mod imp {
                 apm_samples: RefCell::new(Vec::new()),
                 apm_elapsed_offset_seconds: Cell::new(0),
                 move_count: Cell::new(0),
+                scoring_system: Cell::new(ScoringSystem::Standard),
+                score: Cell::new(0),
                 elapsed_seconds: Cell::new(0),
                 timer_started: Cell::new(false),
                 style_provider: RefCell::new(None),
@@ -760,8 +879,8 @@ mod imp {
                 perf_last_report_deck_clears: Cell::new(0),
                 pending_deal_instructions: Cell::new(true),
                 last_metrics_key: Cell::new(0),
-                tableau_card_pictures: RefCell::new(vec![Vec::new(); 10]),
-                tableau_picture_state_cache: RefCell::new(vec![Vec::new(); 10]),
+                tableau_card_pictures: RefCell::new(vec![Vec::new(); 13]),
+                tableau_picture_state_cache: RefCell::new(vec![Vec::new(); 13]),
                 last_stock_waste_foundation_size: Cell::new((0, 0, GameMode::Klondike, 0)),
                 hint_timeouts: RefCell::new(Vec::new()),
                 hint_widgets: RefCell::new(Vec::new()),
@@ -837,6 +956,18 @@ mod imp {
                 robot_freecell_planner_cooldown_ticks: Cell::new(0),
                 robot_freecell_planner_restart_debounce_ticks: Cell::new(0),
                 robot_freecell_planner_last_start_marker: Cell::new(0),
+                robot_pyramid_plan: RefCell::new(VecDeque::new()),
+                robot_pyramid_plan_anchor_hash: Cell::new(0),
+                robot_waste_build_plan: RefCell::new(VecDeque::new()),
+                robot_waste_build_plan_anchor_hash: Cell::new(0),
+                robot_forty_thieves_plan: RefCell::new(VecDeque::new()),
+                robot_forty_thieves_plan_anchor_hash: Cell::new(0),
+                robot_scorpion_plan: RefCell::new(VecDeque::new()),
+                robot_scorpion_plan_anchor_hash: Cell::new(0),
+                robot_canfield_plan: RefCell::new(VecDeque::new()),
+                robot_canfield_plan_anchor_hash: Cell::new(0),
+                robot_open_tableau_plan: RefCell::new(VecDeque::new()),
+                robot_open_tableau_plan_anchor_hash: Cell::new(0),
                 robot_cpu_last_exec_ns: Cell::new(0),
                 robot_cpu_last_mono_us: Cell::new(0),
                 robot_cpu_last_pct: Cell::new(0.0),
@@ -928,6 +1059,15 @@ mod imp {
             klass.install_action("win.copy-game-state", None, |window, _, _| {
                 window.copy_game_state_to_clipboard();
             });
+            klass.install_action("win.copy-board-text", None, |window, _, _| {
+                window.copy_board_text_to_clipboard();
+            });
+            klass.install_action("win.deal-editor", None, |window, _, _| {
+                window.show_deal_editor_dialog();
+            });
+            klass.install_action("win.replay-viewer", None, |window, _, _| {
+                window.show_replay_viewer_dialog();
+            });
             klass.install_action("win.paste-game-state", None, |window, _, _| {
                 window.paste_game_state_from_clipboard();
             });
@@ -962,6 +1102,12 @@ mod imp {
             klass.install_action("win.apm-graph", None, |window, _, _| {
                 window.show_apm_graph_dialog();
             });
+            klass.install_action("win.statistics", None, |window, _, _| {
+                window.show_stats_dialog();
+            });
+            klass.install_action("win.daily-challenge", None, |window, _, _| {
+                window.show_daily_dialog();
+            });
             klass.install_action("win.status-history", None, |window, _, _| {
                 window.show_status_history_dialog();
             });
@@ -1007,6 +1153,18 @@ mod imp {
             klass.install_action("win.mode-spider-suit-4", None, |window, _, _| {
                 window.select_spider_suit_mode(SpiderSuitMode::Four);
             });
+            klass.install_action("win.mode-spider-rules", None, |window, _, _| {
+                window.select_spider_rule_set(SpiderRuleSet::Spider);
+            });
+            klass.install_action("win.mode-spiderette", None, |window, _, _| {
+                window.select_spider_rule_set(SpiderRuleSet::Spiderette);
+            });
+            klass.install_action("win.mode-will-o-the-wisp", None, |window, _, _| {
+                window.select_spider_rule_set(SpiderRuleSet::WillOTheWisp);
+            });
+            klass.install_action("win.mode-tarantula", None, |window, _, _| {
+                window.select_spider_rule_set(SpiderRuleSet::Tarantula);
+            });
             klass.install_action("win.mode-freecell-card-26", None, |window, _, _| {
                 window.select_freecell_card_count_mode(FreecellCardCountMode::TwentySix);
             });
@@ -1019,15 +1177,64 @@ mod imp {
             klass.install_action("win.freecell-cell-count-dialog", None, |window, _, _| {
                 window.show_freecell_cell_count_dialog();
             });
+            klass.install_action("win.mode-yukon", None, |window, _, _| {
+                window.select_yukon_rule_set(YukonRuleSet::Yukon);
+            });
+            klass.install_action("win.mode-yukon-russian", None, |window, _, _| {
+                window.select_yukon_rule_set(YukonRuleSet::Russian);
+            });
+            klass.install_action("win.mode-pyramid", None, |window, _, _| {
+                window.select_game_mode("pyramid");
+            });
+            klass.install_action("win.mode-tripeaks", None, |window, _, _| {
+                window.select_game_mode("tripeaks");
+            });
+            klass.install_action("win.mode-golf", None, |window, _, _| {
+                window.select_game_mode("golf");
+            });
+            klass.install_action("win.mode-forty-thieves", None, |window, _, _| {
+                window.select_game_mode("forty_thieves");
+            });
+            klass.install_action("win.mode-scorpion", None, |window, _, _| {
+                window.select_game_mode("scorpion");
+            });
+            klass.install_action("win.mode-canfield", None, |window, _, _| {
+                window.select_game_mode("canfield");
+            });
+            klass.install_action("win.mode-bakers-dozen", None, |window, _, _| {
+                window.select_game_mode("bakers_dozen");
+            });
+            klass.install_action("win.mode-beleaguered-castle", None, |window, _, _| {
+                window.select_game_mode("beleaguered_castle");
+            });
+            klass.install_action("win.mode-eight-off", None, |window, _, _| {
+                window.select_game_mode("eight_off");
+            });
+            klass.install_action("win.mode-seahaven-towers", None, |window, _, _| {
+                window.select_game_mode("seahaven_towers");
+            });
+            klass.install_action("win.mode-bakers-game", None, |window, _, _| {
+                window.select_game_mode("bakers_game");
+            });
             klass.install_action("win.mode-chess-standard", None, |window, _, _| {
                 window.launch_chess_standard_placeholder();
             });
             klass.install_action("win.mode-chess-960", None, |window, _, _| {
                 window.launch_chess960_placeholder();
             });
+            klass.install_action("win.mode-chess-960-double", None, |window, _, _| {
+                window.launch_double_chess960_placeholder();
+            });
             klass.install_action("win.mode-chess-atomic", None, |window, _, _| {
                 window.launch_chess_atomic_placeholder();
             });
+            klass.install_action(
+                "win.chess960-start-position-dialog",
+                None,
+                |window, _, _| {
+                    window.show_chess960_start_position_dialog();
+                },
+            );
             klass.install_action("win.chess-rotate-board-dialog", None, |window, _, _| {
                 window.show_chess_board_rotation_dialog();
             });
@@ -1130,6 +1337,8 @@ mod imp {
             obj.set_size_request(MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT);
             obj.load_seed_history();
             obj.refresh_seed_history_dropdown();
+            obj.load_variant_stats();
+            obj.load_daily_challenges();
             obj.setup_styles();
             obj.setup_hud_action();
             obj.setup_forever_mode_action();
@@ -1149,7 +1358,10 @@ mod imp {
             let restored = obj.should_persist_shared_state() && obj.try_restore_saved_session();
             if !restored {
                 obj.note_seed_play_started(self.current_seed.get());
-                obj.set_seed_input_text(&self.current_seed.get().to_string());
+                obj.set_seed_input_text(&crate::engine::seed_ops::seed_label(
+                    self.current_seed.get(),
+                ));
+                obj.begin_replay_recording();
             }
             startup_trace::mark("window:after-restore-session");
             obj.setup_handlers();
@@ -1201,6 +1413,7 @@ const SETTINGS_KEY_SMART_MOVE_MODE: &str = "smart-move-mode";
 const SETTINGS_KEY_SPIDER_SUIT_MODE: &str = "spider-suit-mode";
 const SETTINGS_KEY_FREECELL_CARD_COUNT_MODE: &str = "freecell-card-count-mode";
 const SETTINGS_KEY_FREECELL_CELL_COUNT: &str = "freecell-cell-count";
+const SETTINGS_KEY_SCORING_SYSTEM: &str = "scoring-system";
 const SETTINGS_KEY_CHESS_BOARD_ROTATION_DEGREES: &str = "chess-board-rotation-degrees";
 const SETTINGS_KEY_CHESS_AI_STRENGTH: &str = "chess-ai-strength";
 const SETTINGS_KEY_CHESS_WAND_AI_STRENGTH: &str = "chess-wand-ai-strength";
@@ -1225,6 +1438,8 @@ const SETTINGS_KEY_ROBOT_DEBUG_ENABLED: &str = "robot-debug-enabled";
 const SETTINGS_KEY_ROBOT_STRICT_DEBUG_INVARIANTS: &str = "robot-strict-debug-invariants";
 const SETTINGS_KEY_INTERFACE_EMOJI_FONT: &str = "interface-emoji-font";
 const SETTINGS_KEY_SEED_HISTORY: &str = "seed-history";
+const SETTINGS_KEY_VARIANT_STATS: &str = "variant-stats";
+const SETTINGS_KEY_DAILY_CHALLENGES: &str = "daily-challenges";
 const SETTINGS_KEY_CLOSE_PALETTE_ON_COMMAND: &str = "close-palette-on-command";
 const SETTINGS_KEY_COMMAND_PALETTE_WIDTH: &str = "command-palette-width";
 const SETTINGS_KEY_COMMAND_PALETTE_HEIGHT: &str = "command-palette-height";
@@ -1323,6 +1538,7 @@ impl CardthropicWindow {
     }
 
     pub(super) fn close_auxiliary_windows(&self) {
+        self.close_replay_viewer();
         let imp = self.imp();
         let mut to_close: Vec<gtk::Window> = Vec::new();
 
@@ -1347,6 +1563,15 @@ impl CardthropicWindow {
         if let Some(w) = imp.apm_graph_dialog.borrow().as_ref() {
             to_close.push(w.clone());
         }
+        if let Some(w) = imp.stats_dialog.borrow().as_ref() {
+            to_close.push(w.clone());
+        }
+        if let Some(w) = imp.daily_dialog.borrow().as_ref() {
+            to_close.push(w.clone());
+        }
+        if let Some(w) = imp.deal_editor_dialog.borrow().as_ref() {
+            to_close.push(w.clone());
+        }
 
         *imp.custom_userstyle_dialog.borrow_mut() = None;
         *imp.theme_presets_window.borrow_mut() = None;
@@ -1356,6 +1581,14 @@ impl CardthropicWindow {
         *imp.command_search_filter_entry.borrow_mut() = None;
         *imp.memory_guard_dialog.borrow_mut() = None;
         *imp.apm_graph_dialog.borrow_mut() = None;
+        *imp.stats_dialog.borrow_mut() = None;
+        *imp.stats_grid.borrow_mut() = None;
+        *imp.daily_dialog.borrow_mut() = None;
+        *imp.daily_calendar.borrow_mut() = None;
+        *imp.daily_status_label.borrow_mut() = None;
+        *imp.deal_editor_dialog.borrow_mut() = None;
+        *imp.deal_editor_buffer.borrow_mut() = None;
+        *imp.deal_editor_status_label.borrow_mut() = None;
         *imp.status_history_buffer.borrow_mut() = None;
         *imp.apm_graph_area.borrow_mut() = None;
         *imp.apm_peak_label.borrow_mut() = None;
@@ -1375,4 +1608,8 @@ impl CardthropicWindow {
         let mode = self.imp().current_game_mode.get();
         variant_for_mode(mode).spec()
     }
+
+    pub(super) fn mode_capabilities(&self) -> crate::engine::variant_engine::VariantCapabilities {
+        engine_for_mode(self.imp().current_game_mode.get()).capabilities()
+    }
 }
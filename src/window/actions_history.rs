@@ -17,11 +17,13 @@ impl CardthropicWindow {
             selected_run: *imp.selected_run.borrow(),
             selected_waste: imp.waste_selected.get(),
             move_count: imp.move_count.get(),
+            score: imp.score.get(),
             elapsed_seconds: imp.elapsed_seconds.get(),
             timer_started: imp.timer_started.get(),
             apm_elapsed_offset_seconds: imp.apm_elapsed_offset_seconds.get(),
             apm_samples: imp.apm_samples.borrow().clone(),
             foundation_slot_suits: self.foundation_slot_suits_snapshot(),
+            replay_id: imp.replay_board_id.get(),
             chess_mode_active,
             chess_variant: imp.chess_variant.get(),
             chess_position: if chess_mode_active {
@@ -63,10 +65,12 @@ impl CardthropicWindow {
             self.clear_hint_effects();
             imp.waste_selected.set(false);
             imp.selected_freecell.set(None);
+            self.score_applied_move(&snapshot.runtime);
             imp.history.borrow_mut().push(snapshot);
             imp.future.borrow_mut().clear();
             imp.move_count.set(imp.move_count.get() + 1);
             imp.timer_started.set(true);
+            self.note_stats_move_applied();
             if !(imp.robot_mode_running.get() || imp.auto_playing_move.get()) {
                 *imp.status_override.borrow_mut() = None;
             }
@@ -120,6 +124,48 @@ impl CardthropicWindow {
                     self.imp().game.borrow().spider().suit_mode().suit_count()
                 ),
                 GameMode::Freecell => "suits=na".to_string(),
+                GameMode::EightOff | GameMode::SeahavenTowers | GameMode::BakersGame => format!(
+                    "cells={}",
+                    self.imp().game.borrow().freecell().freecell_count()
+                ),
+                GameMode::Yukon => format!(
+                    "rules={}",
+                    self.imp().game.borrow().yukon().rule_set().label()
+                ),
+                GameMode::Pyramid => format!(
+                    "recycles={}",
+                    self.imp().game.borrow().pyramid().recycles_used()
+                ),
+                GameMode::TriPeaks => {
+                    format!("streak={}", self.imp().game.borrow().tripeaks().streak())
+                }
+                GameMode::Golf => {
+                    format!("streak={}", self.imp().game.borrow().golf().streak())
+                }
+                GameMode::FortyThieves => format!(
+                    "stock={}",
+                    self.imp().game.borrow().forty_thieves().stock_len()
+                ),
+                GameMode::Scorpion => format!(
+                    "runs={}",
+                    self.imp().game.borrow().scorpion().completed_runs()
+                ),
+                GameMode::Canfield => format!(
+                    "reserve={}",
+                    self.imp().game.borrow().canfield().reserve().len()
+                ),
+                GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                    let layout = OpenTableauLayout::for_mode(restored_mode)
+                        .unwrap_or(OpenTableauLayout::BakersDozen);
+                    format!(
+                        "foundations={}",
+                        self.imp()
+                            .game
+                            .borrow()
+                            .open_tableau(layout)
+                            .foundation_cards()
+                    )
+                }
             };
             *imp.status_override.borrow_mut() = Some(format!(
                 "Undo restored mode={} {} moves={} elapsed={}.",
@@ -168,6 +214,48 @@ impl CardthropicWindow {
                     self.imp().game.borrow().spider().suit_mode().suit_count()
                 ),
                 GameMode::Freecell => "suits=na".to_string(),
+                GameMode::EightOff | GameMode::SeahavenTowers | GameMode::BakersGame => format!(
+                    "cells={}",
+                    self.imp().game.borrow().freecell().freecell_count()
+                ),
+                GameMode::Yukon => format!(
+                    "rules={}",
+                    self.imp().game.borrow().yukon().rule_set().label()
+                ),
+                GameMode::Pyramid => format!(
+                    "recycles={}",
+                    self.imp().game.borrow().pyramid().recycles_used()
+                ),
+                GameMode::TriPeaks => {
+                    format!("streak={}", self.imp().game.borrow().tripeaks().streak())
+                }
+                GameMode::Golf => {
+                    format!("streak={}", self.imp().game.borrow().golf().streak())
+                }
+                GameMode::FortyThieves => format!(
+                    "stock={}",
+                    self.imp().game.borrow().forty_thieves().stock_len()
+                ),
+                GameMode::Scorpion => format!(
+                    "runs={}",
+                    self.imp().game.borrow().scorpion().completed_runs()
+                ),
+                GameMode::Canfield => format!(
+                    "reserve={}",
+                    self.imp().game.borrow().canfield().reserve().len()
+                ),
+                GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                    let layout = OpenTableauLayout::for_mode(restored_mode)
+                        .unwrap_or(OpenTableauLayout::BakersDozen);
+                    format!(
+                        "foundations={}",
+                        self.imp()
+                            .game
+                            .borrow()
+                            .open_tableau(layout)
+                            .foundation_cards()
+                    )
+                }
             };
             *imp.status_override.borrow_mut() = Some(format!(
                 "Redo restored mode={} {} moves={} elapsed={}.",
@@ -196,6 +284,9 @@ impl CardthropicWindow {
         imp.selected_freecell.set(None);
         imp.waste_selected.set(snapshot.selected_waste);
         self.set_foundation_slot_suits(snapshot.foundation_slot_suits);
+        if snapshot.chess_variant != imp.chess_variant.get() {
+            imp.chess_ai_transposition_table.clear();
+        }
         imp.chess_mode_active.set(snapshot.chess_mode_active);
         imp.chess_variant.set(snapshot.chess_variant);
         *imp.chess_position.borrow_mut() = snapshot
@@ -225,7 +316,10 @@ impl CardthropicWindow {
             });
         *imp.chess_history.borrow_mut() = snapshot.chess_history;
         *imp.chess_future.borrow_mut() = snapshot.chess_future;
+        self.sync_chess960_start_number();
         imp.move_count.set(snapshot.move_count);
+        imp.replay_board_id.set(snapshot.replay_id);
+        imp.score.set(snapshot.score);
         imp.elapsed_seconds.set(snapshot.elapsed_seconds);
         imp.timer_started.set(snapshot.timer_started);
         imp.apm_elapsed_offset_seconds
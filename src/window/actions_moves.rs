@@ -1,6 +1,7 @@
 use super::*;
 use crate::engine::boundary;
 use crate::engine::commands::EngineCommand;
+use crate::game::{FreecellBuild, FreecellRules};
 use crate::window::motion::MotionTarget;
 
 impl CardthropicWindow {
@@ -24,20 +25,40 @@ impl CardthropicWindow {
         {
             empty_tableau = empty_tableau.saturating_sub(1);
         }
-        (free_empty + 1) * (1usize << empty_tableau)
+        freecell
+            .rules()
+            .supermove_capacity(free_empty, empty_tableau)
     }
 
-    fn is_descending_alternating(cards: &[Card]) -> bool {
-        cards.windows(2).all(|pair| {
-            let a = pair[0];
-            let b = pair[1];
-            a.rank == b.rank + 1 && a.color_red() != b.color_red()
-        })
+    fn freecell_build_failure_message(
+        rules: FreecellRules,
+        card: Card,
+        onto: Option<Card>,
+    ) -> String {
+        match onto {
+            Some(top) => match rules.build {
+                FreecellBuild::AlternateColour => format!(
+                    "{} cannot move to {}. Need opposite color and one rank higher.",
+                    card.label(),
+                    top.label()
+                ),
+                FreecellBuild::Suit => format!(
+                    "{} cannot move to {}. Need the same suit and one rank higher.",
+                    card.label(),
+                    top.label()
+                ),
+            },
+            None => format!(
+                "{} cannot fill an empty column. Only kings can.",
+                card.label()
+            ),
+        }
     }
 
     fn freecell_tableau_run_failure_message(&self, src: usize, start: usize, dst: usize) -> String {
         let game = self.imp().game.borrow();
         let freecell = game.freecell();
+        let rules = freecell.rules();
         if src == dst {
             return "Source and destination tableau columns are the same.".to_string();
         }
@@ -51,18 +72,25 @@ impl CardthropicWindow {
             return "That run start is outside the source column.".to_string();
         }
         let run = &source[start..];
-        if run.is_empty() || !Self::is_descending_alternating(run) {
-            return "The selected run must descend by one and alternate colors.".to_string();
+        if run.is_empty() || !rules.is_run(run) {
+            return match rules.build {
+                FreecellBuild::AlternateColour => {
+                    "The selected run must descend by one and alternate colors.".to_string()
+                }
+                FreecellBuild::Suit => "The selected run must descend by one in suit.".to_string(),
+            };
         }
         let first = run[0];
-        if let Some(top) = dest.last().copied() {
-            if top.rank != first.rank + 1 || top.color_red() == first.color_red() {
-                return format!(
-                    "{} cannot move to {}. Need opposite color and one rank higher.",
-                    first.label(),
-                    top.label()
-                );
-            }
+        let top = dest.last().copied();
+        let fits = match top {
+            Some(top) => rules.builds_on(first, top),
+            None => rules.fills_empty_column(first),
+        };
+        if !fits {
+            return Self::freecell_build_failure_message(rules, first, top);
+        }
+        if !rules.supermoves && run.len() > 1 {
+            return "These rules move one card at a time.".to_string();
         }
         let capacity = self.freecell_movable_capacity_for_dst(dst);
         if run.len() > capacity {
@@ -78,20 +106,20 @@ impl CardthropicWindow {
     fn freecell_freecell_to_tableau_failure_message(&self, cell: usize, dst: usize) -> String {
         let game = self.imp().game.borrow();
         let freecell = game.freecell();
+        let rules = freecell.rules();
         let Some(card) = freecell.freecell_card(cell) else {
             return format!("Free cell F{} is empty.", cell + 1);
         };
         let Some(dest) = freecell.tableau().get(dst) else {
             return "That destination tableau column does not exist.".to_string();
         };
-        if let Some(top) = dest.last().copied() {
-            if top.rank != card.rank + 1 || top.color_red() == card.color_red() {
-                return format!(
-                    "{} cannot move to {}. Need opposite color and one rank higher.",
-                    card.label(),
-                    top.label()
-                );
-            }
+        let top = dest.last().copied();
+        let fits = match top {
+            Some(top) => rules.builds_on(card, top),
+            None => rules.fills_empty_column(card),
+        };
+        if !fits {
+            return Self::freecell_build_failure_message(rules, card, top);
         }
         format!("{} cannot move to T{}.", card.label(), dst + 1)
     }
@@ -109,7 +137,10 @@ impl CardthropicWindow {
                 }
                 (start..len).all(|idx| spider.tableau_card(col, idx).is_some_and(|c| c.face_up))
             }
-            GameMode::Freecell => {
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => {
                 let game = self.imp().game.borrow();
                 let freecell = game.freecell();
                 let Some(len) = freecell.tableau().get(col).map(Vec::len) else {
@@ -117,6 +148,52 @@ impl CardthropicWindow {
                 };
                 start < len
             }
+            GameMode::Yukon => {
+                let game = self.imp().game.borrow();
+                let yukon = game.yukon();
+                let Some(len) = yukon.tableau().get(col).map(Vec::len) else {
+                    return false;
+                };
+                if start >= len {
+                    return false;
+                }
+                (start..len).all(|idx| yukon.tableau_card(col, idx).is_some_and(|c| c.face_up))
+            }
+            GameMode::FortyThieves => {
+                let game = self.imp().game.borrow();
+                let Some(len) = game.forty_thieves().tableau().get(col).map(Vec::len) else {
+                    return false;
+                };
+                start < len
+            }
+            GameMode::Canfield => {
+                let game = self.imp().game.borrow();
+                let Some(len) = game.canfield().tableau().get(col).map(Vec::len) else {
+                    return false;
+                };
+                start < len
+            }
+            GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                let Some(layout) = OpenTableauLayout::for_mode(self.active_game_mode()) else {
+                    return false;
+                };
+                let game = self.imp().game.borrow();
+                let Some(len) = game.open_tableau(layout).tableau().get(col).map(Vec::len) else {
+                    return false;
+                };
+                start + 1 == len
+            }
+            GameMode::Scorpion => {
+                let game = self.imp().game.borrow();
+                let scorpion = game.scorpion();
+                let Some(len) = scorpion.tableau().get(col).map(Vec::len) else {
+                    return false;
+                };
+                if start >= len {
+                    return false;
+                }
+                (start..len).all(|idx| scorpion.tableau_card(col, idx).is_some_and(|c| c.face_up))
+            }
             _ => {
                 let Some(game) = boundary::clone_klondike_for_automation(
                     &self.imp().game.borrow(),
@@ -142,21 +219,26 @@ impl CardthropicWindow {
         if !self.guard_mode_engine("Draw") {
             return false;
         }
+        if !self.guard_mode_feature("Draw", self.mode_capabilities().draw) {
+            return false;
+        }
         let mode = self.active_game_mode();
         let should_animate = self.should_play_non_drag_move_animation();
         let animation_from = self.capture_motion_source(MotionTarget::Stock);
         let animation_to = self.capture_motion_source(MotionTarget::WasteTop);
         let draw_mode = self.current_klondike_draw_mode();
         let snapshot = self.snapshot();
-        let changed = boundary::execute_command(
-            &mut self.imp().game.borrow_mut(),
-            mode,
-            EngineCommand::DrawOrRecycle { draw_mode },
-        )
-        .changed;
+        let changed =
+            self.execute_recorded_command(mode, EngineCommand::DrawOrRecycle { draw_mode });
 
         if !self.apply_changed_move(snapshot, changed) {
-            *self.imp().status_override.borrow_mut() = Some("Nothing to draw.".to_string());
+            let passes_exhausted = mode == GameMode::Klondike
+                && self.imp().game.borrow().klondike().passes_exhausted();
+            *self.imp().status_override.borrow_mut() = Some(if passes_exhausted {
+                "No more passes through the stock.".to_string()
+            } else {
+                "Nothing to draw.".to_string()
+            });
         }
         self.render();
         if changed && should_animate {
@@ -177,12 +259,7 @@ impl CardthropicWindow {
 
         let mode = self.active_game_mode();
         let snapshot = self.snapshot();
-        let changed = boundary::execute_command(
-            &mut self.imp().game.borrow_mut(),
-            mode,
-            EngineCommand::CycloneShuffleTableau,
-        )
-        .changed;
+        let changed = self.execute_recorded_command(mode, EngineCommand::CycloneShuffleTableau);
         let changed = self.apply_changed_move(snapshot, changed);
         if changed {
             *self.imp().selected_run.borrow_mut() = None;
@@ -249,12 +326,7 @@ impl CardthropicWindow {
         let animation_from = self.capture_motion_source(MotionTarget::WasteTop);
         let animation_to = self.capture_motion_source(MotionTarget::Foundation(target_slot));
         let snapshot = self.snapshot();
-        let changed = boundary::execute_command(
-            &mut self.imp().game.borrow_mut(),
-            mode,
-            EngineCommand::MoveWasteToFoundation,
-        )
-        .changed;
+        let changed = self.execute_recorded_command(mode, EngineCommand::MoveWasteToFoundation);
         let changed = self.apply_changed_move(snapshot, changed);
         if changed {
             self.establish_foundation_slot_for_card(card, target_slot);
@@ -280,12 +352,8 @@ impl CardthropicWindow {
         let animation_from = self.capture_motion_source(MotionTarget::WasteTop);
         let animation_to = self.capture_tableau_landing_point(dst);
         let snapshot = self.snapshot();
-        let changed = boundary::execute_command(
-            &mut self.imp().game.borrow_mut(),
-            mode,
-            EngineCommand::MoveWasteToTableau { dst },
-        )
-        .changed;
+        let changed =
+            self.execute_recorded_command(mode, EngineCommand::MoveWasteToTableau { dst });
         let changed = self.apply_changed_move(snapshot, changed);
         self.render();
         if changed && should_animate {
@@ -317,14 +385,12 @@ impl CardthropicWindow {
         let animation_to = self.capture_tableau_landing_point(dst);
         let run_texture = self.glitched_texture_for_tableau_run_motion(src, start);
         let snapshot = self.snapshot();
-        let changed = boundary::execute_command(
-            &mut self.imp().game.borrow_mut(),
+        let changed = self.execute_recorded_command(
             mode,
             EngineCommand::MoveTableauRunToTableau { src, start, dst },
-        )
-        .changed;
+        );
         let changed = self.apply_changed_move(snapshot, changed);
-        if !changed && mode == GameMode::Freecell {
+        if !changed && mode.is_freecell_family() {
             *self.imp().status_override.borrow_mut() =
                 Some(self.freecell_tableau_run_failure_message(src, start, dst));
         }
@@ -367,12 +433,8 @@ impl CardthropicWindow {
             });
         let animation_to = self.capture_motion_source(MotionTarget::Foundation(target_slot));
         let snapshot = self.snapshot();
-        let changed = boundary::execute_command(
-            &mut self.imp().game.borrow_mut(),
-            mode,
-            EngineCommand::MoveTableauTopToFoundation { src },
-        )
-        .changed;
+        let changed =
+            self.execute_recorded_command(mode, EngineCommand::MoveTableauTopToFoundation { src });
         let changed = self.apply_changed_move(snapshot, changed);
         if changed {
             self.establish_foundation_slot_for_card(card, target_slot);
@@ -401,7 +463,7 @@ impl CardthropicWindow {
         };
         let mode = self.active_game_mode();
         let should_animate = self.should_play_non_drag_move_animation();
-        let card = if mode == GameMode::Freecell {
+        let card = if mode.is_freecell_family() {
             self.imp()
                 .game
                 .borrow()
@@ -409,6 +471,14 @@ impl CardthropicWindow {
                 .foundations()
                 .get(foundation_idx)
                 .and_then(|pile| pile.last().copied())
+        } else if mode == GameMode::Yukon {
+            self.imp()
+                .game
+                .borrow()
+                .yukon()
+                .foundations()
+                .get(foundation_idx)
+                .and_then(|pile| pile.last().copied())
         } else {
             boundary::clone_klondike_for_automation(
                 &self.imp().game.borrow(),
@@ -421,15 +491,13 @@ impl CardthropicWindow {
             self.capture_motion_source(MotionTarget::Foundation(foundation_slot_idx));
         let animation_to = self.capture_tableau_landing_point(dst);
         let snapshot = self.snapshot();
-        let changed = boundary::execute_command(
-            &mut self.imp().game.borrow_mut(),
+        let changed = self.execute_recorded_command(
             mode,
             EngineCommand::MoveFoundationTopToTableau {
                 foundation_idx,
                 dst,
             },
-        )
-        .changed;
+        );
         let changed = self.apply_changed_move(snapshot, changed);
         self.render();
         if changed && should_animate {
@@ -448,12 +516,8 @@ impl CardthropicWindow {
         }
         let mode = self.active_game_mode();
         let snapshot = self.snapshot();
-        let changed = boundary::execute_command(
-            &mut self.imp().game.borrow_mut(),
-            mode,
-            EngineCommand::MoveTableauTopToFreecell { src, cell },
-        )
-        .changed;
+        let changed = self
+            .execute_recorded_command(mode, EngineCommand::MoveTableauTopToFreecell { src, cell });
         let changed = self.apply_changed_move(snapshot, changed);
         self.render();
         changed
@@ -465,14 +529,10 @@ impl CardthropicWindow {
         }
         let mode = self.active_game_mode();
         let snapshot = self.snapshot();
-        let changed = boundary::execute_command(
-            &mut self.imp().game.borrow_mut(),
-            mode,
-            EngineCommand::MoveFreecellToTableau { cell, dst },
-        )
-        .changed;
+        let changed =
+            self.execute_recorded_command(mode, EngineCommand::MoveFreecellToTableau { cell, dst });
         let changed = self.apply_changed_move(snapshot, changed);
-        if !changed && mode == GameMode::Freecell {
+        if !changed && mode.is_freecell_family() {
             *self.imp().status_override.borrow_mut() =
                 Some(self.freecell_freecell_to_tableau_failure_message(cell, dst));
         }
@@ -493,7 +553,7 @@ impl CardthropicWindow {
             return false;
         }
         let mode = self.active_game_mode();
-        let card = self.imp().game.borrow().freecell().freecell_card(cell);
+        let card = boundary::freecell_card(&self.imp().game.borrow(), mode, cell);
         let Some(card) = card else {
             return false;
         };
@@ -501,12 +561,8 @@ impl CardthropicWindow {
             return false;
         };
         let snapshot = self.snapshot();
-        let changed = boundary::execute_command(
-            &mut self.imp().game.borrow_mut(),
-            mode,
-            EngineCommand::MoveFreecellToFoundation { cell },
-        )
-        .changed;
+        let changed =
+            self.execute_recorded_command(mode, EngineCommand::MoveFreecellToFoundation { cell });
         let changed = self.apply_changed_move(snapshot, changed);
         if changed {
             self.establish_foundation_slot_for_card(card, target_slot);
@@ -514,4 +570,74 @@ impl CardthropicWindow {
         self.render();
         changed
     }
+
+    pub(super) fn remove_pyramid_cards(
+        &self,
+        first: PyramidCardRef,
+        second: Option<PyramidCardRef>,
+    ) -> bool {
+        if !self.guard_mode_engine("Pyramid removal") {
+            return false;
+        }
+        let mode = self.active_game_mode();
+        let snapshot = self.snapshot();
+        let changed = self
+            .execute_recorded_command(mode, EngineCommand::RemovePyramidCards { first, second });
+        let changed = self.apply_changed_move(snapshot, changed);
+        if changed {
+            *self.imp().selected_run.borrow_mut() = None;
+            self.imp().waste_selected.set(false);
+        }
+        self.render();
+        changed
+    }
+
+    pub(super) fn apply_pyramid_move(&self, pyramid_move: PyramidMove) -> bool {
+        match pyramid_move {
+            PyramidMove::RemoveKing(card_ref) => self.remove_pyramid_cards(card_ref, None),
+            PyramidMove::RemovePair(first, second) => {
+                self.remove_pyramid_cards(first, Some(second))
+            }
+            PyramidMove::Draw => self.draw_card(),
+        }
+    }
+
+    /// Canfield's reserve answers to free cell 0; everything else is a
+    /// regular waste or tableau move.
+    pub(super) fn apply_canfield_move(&self, canfield_move: CanfieldMove) -> bool {
+        match canfield_move {
+            CanfieldMove::Draw => self.draw_card(),
+            CanfieldMove::WasteToFoundation => self.move_waste_to_foundation(),
+            CanfieldMove::WasteToTableau { dst } => self.move_waste_to_tableau(dst),
+            CanfieldMove::ReserveToFoundation => {
+                self.move_freecell_to_foundation(CANFIELD_RESERVE_CELL)
+            }
+            CanfieldMove::ReserveToTableau { dst } => {
+                self.move_freecell_to_tableau(CANFIELD_RESERVE_CELL, dst)
+            }
+            CanfieldMove::TableauTopToFoundation { src } => self.move_tableau_to_foundation(src),
+            CanfieldMove::TableauRunToTableau { src, start, dst } => {
+                self.move_tableau_run_to_tableau(src, start, dst)
+            }
+        }
+    }
+
+    pub(super) fn play_waste_build_slot(&self, slot: usize) -> bool {
+        if !self.guard_mode_engine("Waste play") {
+            return false;
+        }
+        let mode = self.active_game_mode();
+        let snapshot = self.snapshot();
+        let changed = self.execute_recorded_command(mode, EngineCommand::PlayToWaste { slot });
+        self.apply_changed_move(snapshot, changed);
+        self.render();
+        changed
+    }
+
+    pub(super) fn apply_waste_build_move(&self, waste_build_move: WasteBuildMove) -> bool {
+        match waste_build_move {
+            WasteBuildMove::Play(slot) => self.play_waste_build_slot(slot),
+            WasteBuildMove::Draw => self.draw_card(),
+        }
+    }
 }
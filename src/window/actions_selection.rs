@@ -12,7 +12,14 @@ impl CardthropicWindow {
         }
         let imp = self.imp();
         let mode = self.active_game_mode();
-        if mode == GameMode::Freecell {
+        if matches!(
+            mode,
+            GameMode::Freecell
+                | GameMode::EightOff
+                | GameMode::SeahavenTowers
+                | GameMode::BakersGame
+                | GameMode::Canfield
+        ) {
             if let Some(cell) = imp.selected_freecell.get() {
                 imp.selected_freecell.set(None);
                 if self.move_freecell_to_tableau(cell, clicked) {
@@ -68,13 +75,21 @@ impl CardthropicWindow {
             return;
         }
         let imp = self.imp();
-        if self.active_game_mode() == GameMode::Freecell {
+        if self.active_game_mode().is_freecell_family() {
             self.activate_freecell_slot(0, n_press);
             return;
         }
         if imp.suppress_waste_click_once.replace(false) {
             return;
         }
+        if self.active_game_mode() == GameMode::Pyramid {
+            self.activate_pyramid_card(PyramidCardRef::Waste);
+            return;
+        }
+        if WasteBuildLayout::for_mode(self.active_game_mode()).is_some() {
+            // The waste only receives cards; there is nothing to pick up.
+            return;
+        }
         let has_waste = boundary::waste_top(&imp.game.borrow(), self.active_game_mode()).is_some();
         if !has_waste {
             imp.waste_selected.set(false);
@@ -101,10 +116,152 @@ impl CardthropicWindow {
         }
 
         *imp.selected_run.borrow_mut() = None;
+        imp.selected_freecell.set(None);
         imp.waste_selected.set(!imp.waste_selected.get());
         self.render();
     }
 
+    /// Click/keyboard activation for the Canfield reserve. Its top card is
+    /// picked up like a free-cell card, but nothing can be placed on it.
+    pub(super) fn activate_canfield_reserve(&self, n_press: i32) {
+        if !self.guard_mode_engine("Reserve selection") {
+            return;
+        }
+        let imp = self.imp();
+        *imp.selected_run.borrow_mut() = None;
+        imp.waste_selected.set(false);
+        let has_card = boundary::freecell_card(
+            &imp.game.borrow(),
+            GameMode::Canfield,
+            CANFIELD_RESERVE_CELL,
+        )
+        .is_some();
+        if !has_card {
+            imp.selected_freecell.set(None);
+            *imp.status_override.borrow_mut() = Some("The reserve is empty.".to_string());
+            self.render();
+            return;
+        }
+
+        match self.smart_move_mode() {
+            SmartMoveMode::DoubleClick if n_press == 2 => {
+                let _ = self.try_smart_move_from_freecell(CANFIELD_RESERVE_CELL);
+                return;
+            }
+            SmartMoveMode::SingleClick if n_press == 1 => {
+                let _ = self.try_smart_move_from_freecell(CANFIELD_RESERVE_CELL);
+                return;
+            }
+            SmartMoveMode::Disabled
+            | SmartMoveMode::DoubleClick
+            | SmartMoveMode::SingleClick
+            | SmartMoveMode::RightClick => {}
+        }
+
+        let next = if imp.selected_freecell.get() == Some(CANFIELD_RESERVE_CELL) {
+            None
+        } else {
+            Some(CANFIELD_RESERVE_CELL)
+        };
+        imp.selected_freecell.set(next);
+        self.render();
+    }
+
+    /// Click/keyboard activation for a Pyramid card: kings leave at once,
+    /// anything else pairs with the current selection or becomes it.
+    pub(super) fn activate_pyramid_card(&self, card_ref: PyramidCardRef) {
+        if !self.guard_mode_engine("Pyramid selection") {
+            return;
+        }
+        let imp = self.imp();
+        let selected = if imp.waste_selected.get() {
+            Some(PyramidCardRef::Waste)
+        } else {
+            imp.selected_run
+                .borrow()
+                .map(|run| PyramidCardRef::Pyramid(run.start))
+        };
+        let (available, is_king, pairs) = {
+            let game = imp.game.borrow();
+            let pyramid = game.pyramid();
+            (
+                pyramid.is_available(card_ref),
+                pyramid.can_remove_king(card_ref),
+                selected.is_some_and(|selected| pyramid.can_remove_pair(selected, card_ref)),
+            )
+        };
+
+        *imp.selected_run.borrow_mut() = None;
+        imp.waste_selected.set(false);
+        if selected == Some(card_ref) {
+            self.render();
+            return;
+        }
+        if is_king {
+            self.remove_pyramid_cards(card_ref, None);
+            return;
+        }
+        if let Some(selected) = selected.filter(|_| pairs) {
+            self.remove_pyramid_cards(selected, Some(card_ref));
+            return;
+        }
+        if !available {
+            *imp.status_override.borrow_mut() = Some("That card is still covered.".to_string());
+            self.render();
+            return;
+        }
+        if selected.is_some() {
+            *imp.status_override.borrow_mut() =
+                Some("Those cards do not add up to 13.".to_string());
+        }
+        match card_ref {
+            PyramidCardRef::Pyramid(slot) => {
+                *imp.selected_run.borrow_mut() = Some(SelectedRun {
+                    col: 0,
+                    start: slot,
+                });
+            }
+            PyramidCardRef::Waste => imp.waste_selected.set(true),
+        }
+        self.render();
+    }
+
+    /// Click/keyboard activation for a TriPeaks or Golf slot: a connecting
+    /// card goes straight to the waste, anything else explains why not.
+    pub(super) fn activate_waste_build_slot(&self, slot: usize) {
+        if !self.guard_mode_engine("Waste play") {
+            return;
+        }
+        let Some(layout) = WasteBuildLayout::for_mode(self.active_game_mode()) else {
+            return;
+        };
+        let (exposed, playable, card, waste_top) = {
+            let game = self.imp().game.borrow();
+            let waste_build = game.waste_build(layout);
+            (
+                waste_build.is_exposed(slot),
+                waste_build.can_play(slot),
+                waste_build.tableau_card(slot),
+                waste_build.waste_top(),
+            )
+        };
+        if playable {
+            self.play_waste_build_slot(slot);
+            return;
+        }
+        let message = match (card, waste_top) {
+            (Some(_), _) if !exposed => "That card is still covered.".to_string(),
+            (Some(card), Some(top)) => format!(
+                "{} does not connect to {} on the waste.",
+                card.label(),
+                top.label()
+            ),
+            _ => "Nothing to play there.".to_string(),
+        };
+        *self.imp().status_override.borrow_mut() = Some(message);
+        self.render();
+    }
+
     pub(super) fn handle_freecell_click_x(&self, _n_press: i32, x: Option<f64>) {
         let imp = self.imp();
         let n_press = _n_press;
@@ -112,19 +269,19 @@ impl CardthropicWindow {
             .map(|x| self.freecell_slot_index_from_waste_x(x))
             .or_else(|| imp.selected_freecell.get())
             .unwrap_or(0)
-            .min(self.current_freecell_cell_count().saturating_sub(1) as usize);
+            .min(self.freecell_board_cell_count().saturating_sub(1) as usize);
         self.activate_freecell_slot(idx, n_press);
     }
 
     pub(super) fn activate_freecell_slot(&self, idx: usize, n_press: i32) {
         let imp = self.imp();
-        let max_idx = self.current_freecell_cell_count().saturating_sub(1) as usize;
+        let max_idx = self.freecell_board_cell_count().saturating_sub(1) as usize;
         let idx = idx.min(max_idx);
 
         let selected_run = { *imp.selected_run.borrow() };
         if let Some(selected) = selected_run {
             let is_top =
-                boundary::tableau_len(&imp.game.borrow(), GameMode::Freecell, selected.col)
+                boundary::tableau_len(&imp.game.borrow(), self.active_game_mode(), selected.col)
                     .map(|len| selected.start + 1 == len)
                     .unwrap_or(false);
             if !is_top {
@@ -135,7 +292,7 @@ impl CardthropicWindow {
             }
             let can_move = boundary::can_move_tableau_top_to_freecell(
                 &imp.game.borrow(),
-                GameMode::Freecell,
+                self.active_game_mode(),
                 selected.col,
                 idx,
             );
@@ -222,7 +379,7 @@ impl CardthropicWindow {
                     && self.move_tableau_to_foundation_into_slot(src, Some(foundation_idx))
             })
         } else if let Some(cell) = parse_freecell_payload(payload) {
-            let card = self.imp().game.borrow().freecell().freecell_card(cell);
+            let card = boundary::freecell_card(&self.imp().game.borrow(), mode, cell);
             card.is_some_and(|card| {
                 boundary::can_move_freecell_to_foundation(&self.imp().game.borrow(), mode, cell)
                     && self.foundation_slot_accepts_card(card, foundation_idx)
@@ -249,6 +406,21 @@ impl CardthropicWindow {
         let mode = self.active_game_mode();
         let mut did_move = false;
 
+        if mode == GameMode::Pyramid {
+            // The removed pile only takes a selected king.
+            let selected = if imp.waste_selected.get() {
+                Some(PyramidCardRef::Waste)
+            } else {
+                imp.selected_run
+                    .borrow()
+                    .map(|run| PyramidCardRef::Pyramid(run.start))
+            };
+            if let Some(selected) = selected {
+                self.activate_pyramid_card(selected);
+            }
+            return;
+        }
+
         if imp.waste_selected.get() {
             let card = boundary::waste_top(&imp.game.borrow(), mode);
             if card.is_some_and(|card| {
@@ -287,14 +459,17 @@ impl CardthropicWindow {
             }
         }
 
-        if mode == GameMode::Freecell {
+        if matches!(
+            mode,
+            GameMode::Freecell
+                | GameMode::EightOff
+                | GameMode::SeahavenTowers
+                | GameMode::BakersGame
+                | GameMode::Canfield
+        ) {
             if let Some(cell) = imp.selected_freecell.get() {
-                let suit_ok = imp
-                    .game
-                    .borrow()
-                    .freecell()
-                    .freecell_card(cell)
-                    .is_some_and(|card| {
+                let suit_ok =
+                    boundary::freecell_card(&imp.game.borrow(), mode, cell).is_some_and(|card| {
                         boundary::can_move_freecell_to_foundation(&imp.game.borrow(), mode, cell)
                             && self.foundation_slot_accepts_card(card, foundation_idx)
                     });
@@ -311,8 +486,9 @@ impl CardthropicWindow {
                 boundary::foundation_top_exists(&imp.game.borrow(), mode, suit_foundation_idx);
             if foundation_top_exists {
                 let tableau_columns = match mode {
-                    GameMode::Spider => 10,
-                    GameMode::Freecell => 8,
+                    GameMode::Spider => imp.game.borrow().spider().tableau().len(),
+                    GameMode::SeahavenTowers => 10,
+                    GameMode::Freecell | GameMode::EightOff | GameMode::BakersGame => 8,
                     _ => 7,
                 };
                 for dst in 0..tableau_columns {
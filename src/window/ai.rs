@@ -8,6 +8,8 @@ const SPIDER_FIND_WINNABLE_ATTEMPTS_ONE_SUIT: u32 = 20_000;
 const SPIDER_FIND_WINNABLE_ATTEMPTS_TWO_SUIT: u32 = 4_000;
 const SPIDER_FIND_WINNABLE_ATTEMPTS_THREE_SUIT: u32 = 8_000;
 const SPIDER_FIND_WINNABLE_ATTEMPTS_FOUR_SUIT: u32 = 20_000;
+const WASTE_BUILD_FIND_WINNABLE_ATTEMPTS: u32 = 2_000;
+const OPEN_TABLEAU_FIND_WINNABLE_ATTEMPTS: u32 = 500;
 
 impl CardthropicWindow {
     pub(super) fn cancel_winnable_seed_search(&self, status: Option<&str>) {
@@ -30,6 +32,12 @@ impl CardthropicWindow {
         if !self.guard_mode_engine("Starting a winnable deal") {
             return;
         }
+        if !self.guard_mode_feature(
+            "Starting a winnable deal",
+            self.mode_capabilities().winnability,
+        ) {
+            return;
+        }
         if self.imp().seed_search_in_progress.get() {
             self.cancel_winnable_seed_search(Some("Canceled winnable-seed search."));
             return;
@@ -38,10 +46,14 @@ impl CardthropicWindow {
         self.cancel_seed_winnable_check(None);
         self.clear_seed_entry_feedback();
         let start_seed = seed_ops::random_seed();
-        self.set_seed_input_text(&start_seed.to_string());
+        self.set_seed_input_text(&seed_ops::seed_label(start_seed));
 
-        let attempts = if self.active_game_mode() == GameMode::Freecell {
+        let attempts = if self.active_game_mode().is_freecell_family() {
             10_000
+        } else if WasteBuildLayout::for_mode(self.active_game_mode()).is_some() {
+            WASTE_BUILD_FIND_WINNABLE_ATTEMPTS
+        } else if OpenTableauLayout::for_mode(self.active_game_mode()).is_some() {
+            OPEN_TABLEAU_FIND_WINNABLE_ATTEMPTS
         } else if self.active_game_mode() == GameMode::Spider {
             match self.current_spider_suit_mode() {
                 SpiderSuitMode::One => SPIDER_FIND_WINNABLE_ATTEMPTS_ONE_SUIT,
@@ -75,21 +87,42 @@ impl CardthropicWindow {
         }
 
         let draw_mode = self.current_klondike_draw_mode();
+        let klondike_pass_limit = self.current_klondike_pass_limit();
         let deal_count = draw_mode.count();
         let mode = self.active_game_mode();
         let spider_suit_mode = self.current_spider_suit_mode();
+        let spider_rule_set = self.current_spider_rule_set();
         let spider_suit_count = spider_suit_mode.suit_count();
         let spider_step_budget =
             winnability::spider_find_step_budget(spider_suit_mode, max_states, 0);
         let freecell_card_count_mode = self.current_freecell_card_count_mode();
-        let freecell_card_count = freecell_card_count_mode.card_count();
-        *imp.status_override.borrow_mut() = Some(if mode == GameMode::Freecell {
+        let freecell_layout = FreecellLayout::for_mode(mode);
+        let freecell_label = if mode == GameMode::Freecell {
+            format!("FreeCell {}", freecell_card_count_mode.card_count())
+        } else {
+            mode.label().to_string()
+        };
+        let waste_build_layout = WasteBuildLayout::for_mode(mode);
+        let waste_build_rules = waste_build_layout
+            .map(|layout| imp.game.borrow().waste_build(layout).rules())
+            .unwrap_or_default();
+        let open_tableau_layout = OpenTableauLayout::for_mode(mode);
+        let exact_search = waste_build_layout.is_some() || open_tableau_layout.is_some();
+        let variant_label = mode.label();
+        *imp.status_override.borrow_mut() = Some(if mode.is_freecell_family() {
             format!(
-                "Searching FreeCell {freecell_card_count} winnable seed from {start_seed} (attempts: {attempts}, one-pass wand per seed)..."
+                "Searching {freecell_label} winnable seed from {start_seed} (attempts: {attempts}, one-pass wand per seed)...",
+                start_seed = seed_ops::seed_label(start_seed),
             )
         } else if mode == GameMode::Spider {
             format!(
-                "Searching Spider {spider_suit_count}-suit winnable seed from {start_seed} (attempts: {attempts}, 2-path ensemble per seed, step budget: {spider_step_budget})..."
+                "Searching Spider {spider_suit_count}-suit winnable seed from {start_seed} (attempts: {attempts}, 2-path ensemble per seed, step budget: {spider_step_budget})...",
+                start_seed = seed_ops::seed_label(start_seed),
+            )
+        } else if exact_search {
+            format!(
+                "Searching {variant_label} winnable seed from {start_seed} (attempts: {attempts}, max states: {max_states})...",
+                start_seed = seed_ops::seed_label(start_seed),
             )
         } else {
             seed_ops::msg_searching_winnable_seed(start_seed, deal_count, attempts, max_states)
@@ -112,6 +145,8 @@ impl CardthropicWindow {
                 tested: u32,
                 line: Vec<HintMove>,
             },
+            /// Exact solvers: the robot re-solves from the dealt position.
+            Exact { seed: u64, tested: u32 },
         }
         let memory_limit_mib = self
             .current_memory_mib()
@@ -138,7 +173,7 @@ impl CardthropicWindow {
         let (sender, receiver) = mpsc::channel::<Option<WinnableSeedSearchResult>>();
         thread::spawn(move || {
             let result =
-                if mode == GameMode::Freecell {
+                if let Some(layout) = freecell_layout {
                     let profile = AutomationProfile::for_mode(mode);
                     let guided_budget = profile.dialog_seed_guided_budget;
                     let exhaustive_budget = profile.dialog_seed_exhaustive_budget;
@@ -147,6 +182,7 @@ impl CardthropicWindow {
                         attempts,
                         guided_budget,
                         exhaustive_budget,
+                        layout,
                         freecell_card_count_mode,
                         Arc::clone(&search_cancel_worker),
                         Some(Arc::clone(&freecell_progress_checked_worker)),
@@ -162,6 +198,7 @@ impl CardthropicWindow {
                         max_states,
                         0,
                         spider_suit_mode,
+                        spider_rule_set,
                         Arc::clone(&search_cancel_worker),
                         Some(Arc::clone(&spider_progress_checked_worker)),
                         Some(Arc::clone(&spider_progress_stats_worker)),
@@ -169,12 +206,32 @@ impl CardthropicWindow {
                     .map(|(seed, tested, line)| {
                         WinnableSeedSearchResult::Spider { seed, tested, line }
                     })
+                } else if let Some(layout) = waste_build_layout {
+                    winnability::find_winnable_waste_build_seed_parallel(
+                        start_seed,
+                        attempts,
+                        max_states,
+                        layout,
+                        waste_build_rules,
+                        Arc::clone(&search_cancel_worker),
+                    )
+                    .map(|(seed, tested)| WinnableSeedSearchResult::Exact { seed, tested })
+                } else if let Some(layout) = open_tableau_layout {
+                    winnability::find_winnable_open_tableau_seed_parallel(
+                        start_seed,
+                        attempts,
+                        max_states,
+                        layout,
+                        Arc::clone(&search_cancel_worker),
+                    )
+                    .map(|(seed, tested)| WinnableSeedSearchResult::Exact { seed, tested })
                 } else {
                     winnability::find_winnable_seed_parallel(
                         start_seed,
                         attempts,
                         max_states,
                         draw_mode,
+                        klondike_pass_limit,
                         Arc::clone(&search_cancel_worker),
                     )
                     .map(|(seed, tested, solver_line)| {
@@ -213,7 +270,7 @@ impl CardthropicWindow {
                 glib::ControlFlow::Break,
                 move || match receiver.try_recv() {
                     Err(mpsc::TryRecvError::Empty) => {
-                        if mode == GameMode::Freecell {
+                        if mode.is_freecell_family() {
                             let checked = freecell_progress_checked.load(Ordering::Relaxed);
                             let last = freecell_last_progress_shown.get();
                             let now_us = glib::monotonic_time();
@@ -241,9 +298,10 @@ impl CardthropicWindow {
                                         .last_stop_reason
                                         .load(Ordering::Relaxed),
                                 );
-                                window.set_seed_input_text(&current_seed.to_string());
+                                window.set_seed_input_text(&seed_ops::seed_label(current_seed));
                                 *window.imp().status_override.borrow_mut() = Some(format!(
-                                    "Searching FreeCell {freecell_card_count} winnable seed from {start_seed} (attempts: {attempts}, one-pass wand per seed)... checked {checked}/{attempts}, current seed {current_seed}, expanded={expanded}, branches={branches}, elapsed_ms={elapsed_ms}, stop={stop_reason}"
+                                    "Searching {freecell_label} winnable seed from {start_seed} (attempts: {attempts}, one-pass wand per seed)... checked {checked}/{attempts}, current seed {current_seed}, expanded={expanded}, branches={branches}, elapsed_ms={elapsed_ms}, stop={stop_reason}",
+                                    start_seed = seed_ops::seed_label(start_seed),
                                 ));
                                 window.render();
                             }
@@ -273,7 +331,7 @@ impl CardthropicWindow {
                                 let stock_cards = spider_progress_stats
                                     .last_stock_cards
                                     .load(Ordering::Relaxed);
-                                let deals_left = stock_cards / 10;
+                                let deals_left = stock_cards.div_ceil(spider_rule_set.columns());
                                 let face_down = spider_progress_stats
                                     .last_face_down_cards
                                     .load(Ordering::Relaxed);
@@ -332,9 +390,10 @@ impl CardthropicWindow {
                                 } else {
                                     String::new()
                                 };
-                                window.set_seed_input_text(&current_seed.to_string());
+                                window.set_seed_input_text(&seed_ops::seed_label(current_seed));
                                 *window.imp().status_override.borrow_mut() = Some(format!(
-                                    "Searching Spider {spider_suit_count}-suit winnable seed from {start_seed} (attempts: {attempts}, 2-path ensemble per seed, step budget: {spider_step_budget})... checked {checked}/{attempts}, current seed {current_seed}, expanded={expanded}, branches={branches}, runs={completed_runs}, stock={stock_cards}({deals_left} deals), facedown={face_down}, empty={empty_cols}, suited_edges={suited_edges}, tail_run={max_tail_run}, draws={draw_moves}, t_moves={tableau_moves}, reveals={reveal_moves}, peak_edges={peak_suited_edges}, peak_tail={peak_tail_run}, peak_empty={peak_empty_cols}, empty_creates={empty_creates}, best_peak_empty={best_peak_empty}, attempts_with_empty={attempts_with_empty_create}, adapt={adapt_events}, stop={stop_reason}{cycle_suffix}"
+                                    "Searching Spider {spider_suit_count}-suit winnable seed from {start_seed} (attempts: {attempts}, 2-path ensemble per seed, step budget: {spider_step_budget})... checked {checked}/{attempts}, current seed {current_seed}, expanded={expanded}, branches={branches}, runs={completed_runs}, stock={stock_cards}({deals_left} deals), facedown={face_down}, empty={empty_cols}, suited_edges={suited_edges}, tail_run={max_tail_run}, draws={draw_moves}, t_moves={tableau_moves}, reveals={reveal_moves}, peak_edges={peak_suited_edges}, peak_tail={peak_tail_run}, peak_empty={peak_empty_cols}, empty_creates={empty_creates}, best_peak_empty={best_peak_empty}, attempts_with_empty={attempts_with_empty_create}, adapt={adapt_events}, stop={stop_reason}{cycle_suffix}",
+                                    start_seed = seed_ops::seed_label(start_seed),
                                 ));
                                 window.render();
                             }
@@ -376,7 +435,8 @@ impl CardthropicWindow {
                         imp.seed_search_in_progress.set(false);
                         imp.seed_search_cancel.borrow_mut().take();
                         let status = format!(
-                            "Started FreeCell {freecell_card_count} winnable game. Seed {seed} (checked {tested} seed(s)). Use Robot as first action to see win."
+                            "Started {freecell_label} winnable game. Seed {seed} (checked {tested} seed(s)). Use Robot as first action to see win.",
+                            seed = seed_ops::seed_label(seed),
                         );
                         if preserve_robot {
                             window.start_new_game_with_seed_internal(seed, status, true);
@@ -393,7 +453,8 @@ impl CardthropicWindow {
                         imp.seed_search_cancel.borrow_mut().take();
                         let spider_suit_count = window.current_spider_suit_mode().suit_count();
                         let status = format!(
-                            "Started Spider {spider_suit_count}-suit winnable game. Seed {seed} (checked {tested} seed(s)). Use Robot as first action to see win."
+                            "Started Spider {spider_suit_count}-suit winnable game. Seed {seed} (checked {tested} seed(s)). Use Robot as first action to see win.",
+                            seed = seed_ops::seed_label(seed),
                         );
                         if preserve_robot {
                             window.start_new_game_with_seed_internal(seed, status, true);
@@ -404,15 +465,31 @@ impl CardthropicWindow {
                         window.trim_process_memory_if_supported();
                         glib::ControlFlow::Break
                     }
+                    Ok(Some(WinnableSeedSearchResult::Exact { seed, tested })) => {
+                        let imp = window.imp();
+                        imp.seed_search_in_progress.set(false);
+                        imp.seed_search_cancel.borrow_mut().take();
+                        let status = format!(
+                            "Started {variant_label} winnable game. Seed {seed} (checked {tested} seed(s)). Use Robot as first action to see win.",
+                            seed = seed_ops::seed_label(seed),
+                        );
+                        if preserve_robot {
+                            window.start_new_game_with_seed_internal(seed, status, true);
+                        } else {
+                            window.start_new_game_with_seed(seed, status);
+                        }
+                        window.trim_process_memory_if_supported();
+                        glib::ControlFlow::Break
+                    }
                     Ok(None) => {
                         let imp = window.imp();
                         imp.seed_search_in_progress.set(false);
                         imp.seed_search_cancel.borrow_mut().take();
                         let search_canceled = search_cancel.load(Ordering::Relaxed);
                         *imp.status_override.borrow_mut() = Some(if memory_guard_triggered.get() {
-                            if mode == GameMode::Freecell {
+                            if mode.is_freecell_family() {
                                 format!(
-                                    "Find winnable stopped by memory guard at ~{} MiB (FreeCell {freecell_card_count}).",
+                                    "Find winnable stopped by memory guard at ~{} MiB ({freecell_label}).",
                                     memory_limit_mib
                                 )
                             } else if mode == GameMode::Spider {
@@ -422,6 +499,11 @@ impl CardthropicWindow {
                                     "Find winnable stopped by memory guard at ~{} MiB (Spider {spider_suit_count}-suit).",
                                     memory_limit_mib
                                 )
+                            } else if exact_search {
+                                format!(
+                                    "Find winnable stopped by memory guard at ~{} MiB ({variant_label}).",
+                                    memory_limit_mib
+                                )
                             } else {
                                 format!(
                                     "Find winnable stopped by memory guard at ~{} MiB (Deal {deal_count}).",
@@ -429,29 +511,46 @@ impl CardthropicWindow {
                                 )
                             }
                         } else if search_canceled {
-                            if mode == GameMode::Freecell {
+                            if mode.is_freecell_family() {
                                 let checked = freecell_progress_checked.load(Ordering::Relaxed);
                                 format!(
-                                    "Canceled FreeCell {freecell_card_count} winnable-seed search after checking {checked} seed(s) from seed {start_seed}."
+                                    "Canceled {freecell_label} winnable-seed search after checking {checked} seed(s) from seed {start_seed}.",
+                                    start_seed = seed_ops::seed_label(start_seed),
                                 )
                             } else if mode == GameMode::Spider {
                                 let checked = spider_progress_checked.load(Ordering::Relaxed);
                                 let spider_suit_count =
                                     window.current_spider_suit_mode().suit_count();
                                 format!(
-                                    "Canceled Spider {spider_suit_count}-suit winnable-seed search after checking {checked} seed(s) from seed {start_seed}."
+                                    "Canceled Spider {spider_suit_count}-suit winnable-seed search after checking {checked} seed(s) from seed {start_seed}.",
+                                    start_seed = seed_ops::seed_label(start_seed),
+                                )
+                            } else if exact_search {
+                                format!(
+                                    "Canceled {variant_label} winnable-seed search from seed {start_seed}.",
+                                    start_seed = seed_ops::seed_label(start_seed),
                                 )
                             } else {
-                                format!("Canceled winnable-seed search from seed {start_seed}.")
+                                format!(
+                                    "Canceled winnable-seed search from seed {start_seed}.",
+                                    start_seed = seed_ops::seed_label(start_seed)
+                                )
                             }
-                        } else if mode == GameMode::Freecell {
+                        } else if mode.is_freecell_family() {
                             format!(
-                                "No FreeCell {freecell_card_count} winnable seed found in {attempts} attempt(s) from seed {start_seed}."
+                                "No {freecell_label} winnable seed found in {attempts} attempt(s) from seed {start_seed}.",
+                                start_seed = seed_ops::seed_label(start_seed),
                             )
                         } else if mode == GameMode::Spider {
                             let spider_suit_count = window.current_spider_suit_mode().suit_count();
                             format!(
-                                "No Spider {spider_suit_count}-suit winnable seed found in {attempts} attempt(s) from seed {start_seed}."
+                                "No Spider {spider_suit_count}-suit winnable seed found in {attempts} attempt(s) from seed {start_seed}.",
+                                start_seed = seed_ops::seed_label(start_seed),
+                            )
+                        } else if exact_search {
+                            format!(
+                                "No {variant_label} winnable seed found in {attempts} attempt(s) from seed {start_seed}.",
+                                start_seed = seed_ops::seed_label(start_seed),
                             )
                         } else {
                             seed_ops::msg_no_winnable_seed(start_seed, deal_count, attempts)
@@ -466,9 +565,9 @@ impl CardthropicWindow {
                         imp.seed_search_cancel.borrow_mut().take();
                         let search_canceled = search_cancel.load(Ordering::Relaxed);
                         *imp.status_override.borrow_mut() = Some(if memory_guard_triggered.get() {
-                            if mode == GameMode::Freecell {
+                            if mode.is_freecell_family() {
                                 format!(
-                                    "Find winnable stopped by memory guard at ~{} MiB (FreeCell {freecell_card_count}).",
+                                    "Find winnable stopped by memory guard at ~{} MiB ({freecell_label}).",
                                     memory_limit_mib
                                 )
                             } else if mode == GameMode::Spider {
@@ -478,6 +577,11 @@ impl CardthropicWindow {
                                     "Find winnable stopped by memory guard at ~{} MiB (Spider {spider_suit_count}-suit).",
                                     memory_limit_mib
                                 )
+                            } else if exact_search {
+                                format!(
+                                    "Find winnable stopped by memory guard at ~{} MiB ({variant_label}).",
+                                    memory_limit_mib
+                                )
                             } else {
                                 format!(
                                     "Find winnable stopped by memory guard at ~{} MiB (Deal {deal_count}).",
@@ -485,30 +589,40 @@ impl CardthropicWindow {
                                 )
                             }
                         } else if search_canceled {
-                            if mode == GameMode::Freecell {
+                            if mode.is_freecell_family() {
                                 let checked = freecell_progress_checked.load(Ordering::Relaxed);
                                 format!(
-                                    "Canceled FreeCell {freecell_card_count} winnable-seed search after checking {checked} seed(s) from seed {start_seed}."
+                                    "Canceled {freecell_label} winnable-seed search after checking {checked} seed(s) from seed {start_seed}.",
+                                    start_seed = seed_ops::seed_label(start_seed),
                                 )
                             } else if mode == GameMode::Spider {
                                 let checked = spider_progress_checked.load(Ordering::Relaxed);
                                 let spider_suit_count =
                                     window.current_spider_suit_mode().suit_count();
                                 format!(
-                                    "Canceled Spider {spider_suit_count}-suit winnable-seed search after checking {checked} seed(s) from seed {start_seed}."
+                                    "Canceled Spider {spider_suit_count}-suit winnable-seed search after checking {checked} seed(s) from seed {start_seed}.",
+                                    start_seed = seed_ops::seed_label(start_seed),
+                                )
+                            } else if exact_search {
+                                format!(
+                                    "Canceled {variant_label} winnable-seed search from seed {start_seed}.",
+                                    start_seed = seed_ops::seed_label(start_seed),
                                 )
                             } else {
-                                format!("Canceled winnable-seed search from seed {start_seed}.")
+                                format!(
+                                    "Canceled winnable-seed search from seed {start_seed}.",
+                                    start_seed = seed_ops::seed_label(start_seed)
+                                )
                             }
-                        } else if mode == GameMode::Freecell {
-                            format!(
-                                "FreeCell {freecell_card_count} seed search stopped unexpectedly."
-                            )
+                        } else if mode.is_freecell_family() {
+                            format!("{freecell_label} seed search stopped unexpectedly.")
                         } else if mode == GameMode::Spider {
                             let spider_suit_count = window.current_spider_suit_mode().suit_count();
                             format!(
                                 "Spider {spider_suit_count}-suit seed search stopped unexpectedly."
                             )
+                        } else if exact_search {
+                            format!("{variant_label} seed search stopped unexpectedly.")
                         } else {
                             seed_ops::msg_seed_search_stopped_unexpectedly(deal_count)
                         });
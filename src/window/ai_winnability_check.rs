@@ -2,12 +2,16 @@ use super::*;
 use crate::engine::chess::ai::{self as chess_ai, api::SearchTermination, AiConfig};
 use crate::engine::seed_ops;
 use crate::game::{
-    is_in_check, legal_moves, square_name, ChessColor, ChessPieceKind, ChessPosition, Square,
+    is_in_check, legal_moves, square_name, ChessColor, ChessPieceKind, ChessPosition,
+    FreecellRules, Square,
 };
 
-const SEED_WINNABILITY_TIMEOUT_SECS: u32 = 300;
+pub(super) const SEED_WINNABILITY_TIMEOUT_SECS: u32 = 300;
 const SEED_WINNABILITY_MEMORY_HEADROOM_MIB: u64 = 512;
 const SEED_WINNABILITY_MEMORY_MAX_MIB: u64 = 1024;
+// The Thoughtful search is exact, so it gets a larger budget to finish its
+// proofs; the timeout and memory guard still apply.
+pub(super) const THOUGHTFUL_WINNABILITY_STATE_BUDGET: usize = 2_000_000;
 
 impl CardthropicWindow {
     pub(super) fn cancel_seed_winnable_check(&self, status: Option<&str>) {
@@ -65,12 +69,23 @@ impl CardthropicWindow {
             return;
         }
         let mode = self.active_game_mode();
+        // Only Klondike, Spider, the FreeCell family, TriPeaks, Golf, Baker's
+        // Dozen and Beleaguered Castle have solvers; past this guard the catch-all arms
+        // below are Klondike.
+        if !self.guard_mode_feature("Winnability analysis", self.mode_capabilities().winnability) {
+            return;
+        }
         if self.imp().seed_check_running.get() {
             let cancel_message = if mode == GameMode::Spider {
                 let suit_count = self.current_spider_suit_mode().suit_count();
                 format!("Winnability check canceled (Spider {suit_count}-suit).")
             } else if mode == GameMode::Freecell {
                 "Winnability check canceled (FreeCell).".to_string()
+            } else if WasteBuildLayout::for_mode(mode).is_some()
+                || OpenTableauLayout::for_mode(mode).is_some()
+                || mode.is_freecell_family()
+            {
+                format!("Winnability check canceled ({}).", mode.label())
             } else {
                 seed_ops::msg_winnability_check_canceled(self.current_klondike_draw_mode().count())
             };
@@ -159,24 +174,51 @@ impl CardthropicWindow {
 
         let (sender, receiver) = mpsc::channel::<Option<winnability::SeedWinnabilityCheckResult>>();
         let draw_mode = self.current_klondike_draw_mode();
+        let klondike_pass_limit = self.current_klondike_pass_limit();
+        let klondike_thoughtful = self.current_klondike_thoughtful();
         let deal_count = draw_mode.count();
         let spider_suit_mode = self.current_spider_suit_mode();
+        let spider_rule_set = self.current_spider_rule_set();
         let spider_suit_count = spider_suit_mode.suit_count();
         let freecell_card_count_mode = self.current_freecell_card_count_mode();
         let freecell_card_count = freecell_card_count_mode.card_count();
+        let freecell_layout = FreecellLayout::for_mode(mode);
+        let freecell_rules = freecell_layout
+            .map(|layout| self.imp().game.borrow().freecell_for(layout).rules())
+            .unwrap_or(FreecellRules::CLASSIC);
+        let waste_build_layout = WasteBuildLayout::for_mode(mode);
+        let waste_build_rules = waste_build_layout
+            .map(|layout| self.imp().game.borrow().waste_build(layout).rules())
+            .unwrap_or_default();
+        let open_tableau_layout = OpenTableauLayout::for_mode(mode);
+        let variant_label = mode.label();
         let profile = self.automation_profile();
         *self.imp().status_override.borrow_mut() = Some(match mode {
             GameMode::Spider => format!(
                 "W? checking seed {seed} for Spider {spider_suit_count}-suit (up to {}s)...",
-                SEED_WINNABILITY_TIMEOUT_SECS
+                SEED_WINNABILITY_TIMEOUT_SECS,
+                seed = seed_ops::seed_label(seed),
             ),
             GameMode::Freecell => format!(
                 "W? checking seed {seed} for FreeCell {freecell_card_count} (up to {}s)...",
-                SEED_WINNABILITY_TIMEOUT_SECS
+                SEED_WINNABILITY_TIMEOUT_SECS,
+                seed = seed_ops::seed_label(seed),
+            ),
+            GameMode::TriPeaks
+            | GameMode::Golf
+            | GameMode::BakersDozen
+            | GameMode::BeleagueredCastle
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => format!(
+                "W? checking seed {seed} for {variant_label} (up to {}s)...",
+                SEED_WINNABILITY_TIMEOUT_SECS,
+                seed = seed_ops::seed_label(seed),
             ),
-            GameMode::Klondike => format!(
+            _ => format!(
                 "W? checking seed {seed} for Deal {deal_count} (up to {}s)...",
-                SEED_WINNABILITY_TIMEOUT_SECS
+                SEED_WINNABILITY_TIMEOUT_SECS,
+                seed = seed_ops::seed_label(seed),
             ),
         });
         self.render();
@@ -185,22 +227,49 @@ impl CardthropicWindow {
                 winnability::is_spider_seed_winnable(
                     seed,
                     spider_suit_mode,
+                    spider_rule_set,
                     profile.dialog_seed_guided_budget,
                     profile.dialog_seed_exhaustive_budget,
                     &cancel_flag,
                 )
-            } else if mode == GameMode::Freecell {
+            } else if let Some(layout) = freecell_layout {
                 winnability::is_freecell_seed_winnable(
                     seed,
+                    layout,
+                    freecell_rules,
                     freecell_card_count_mode,
                     profile.dialog_seed_guided_budget,
                     profile.dialog_seed_exhaustive_budget,
                     &cancel_flag,
                 )
+            } else if let Some(layout) = waste_build_layout {
+                winnability::is_waste_build_seed_winnable(
+                    seed,
+                    layout,
+                    waste_build_rules,
+                    profile.dialog_seed_exhaustive_budget,
+                    &cancel_flag,
+                )
+            } else if let Some(layout) = open_tableau_layout {
+                winnability::is_open_tableau_seed_winnable(
+                    seed,
+                    layout,
+                    profile.dialog_seed_exhaustive_budget,
+                    &cancel_flag,
+                )
+            } else if mode == GameMode::Klondike && klondike_thoughtful {
+                winnability::is_thoughtful_seed_winnable(
+                    seed,
+                    draw_mode,
+                    klondike_pass_limit,
+                    THOUGHTFUL_WINNABILITY_STATE_BUDGET,
+                    &cancel_flag,
+                )
             } else {
                 winnability::is_seed_winnable(
                     seed,
                     draw_mode,
+                    klondike_pass_limit,
                     profile.dialog_seed_guided_budget,
                     profile.dialog_seed_exhaustive_budget,
                     &cancel_flag,
@@ -251,7 +320,16 @@ impl CardthropicWindow {
                                             "Winnability check stopped by memory guard at ~{} MiB (FreeCell {freecell_card_count}): solver found no winning line within {} iterations.",
                                             memory_limit, result.iterations
                                         ),
-                                        GameMode::Klondike => format!(
+                                        GameMode::TriPeaks
+| GameMode::Golf
+| GameMode::BakersDozen
+| GameMode::BeleagueredCastle
+| GameMode::EightOff
+| GameMode::SeahavenTowers | GameMode::BakersGame => format!(
+                                            "Winnability check stopped by memory guard at ~{} MiB ({variant_label}): solver found no winning line within {} iterations.",
+                                            memory_limit, result.iterations
+                                        ),
+                                        _ => format!(
                                             "Winnability check stopped by memory guard at ~{} MiB (Deal {deal_count}): solver found no winning line within {} iterations.",
                                             memory_limit, result.iterations
                                         ),
@@ -266,7 +344,16 @@ impl CardthropicWindow {
                                             "Winnability check timed out after {}s (FreeCell {freecell_card_count}): solver found no winning line within {} iterations.",
                                             SEED_WINNABILITY_TIMEOUT_SECS, result.iterations
                                         ),
-                                        GameMode::Klondike => seed_ops::msg_winnability_check_timed_out(
+                                        GameMode::TriPeaks
+| GameMode::Golf
+| GameMode::BakersDozen
+| GameMode::BeleagueredCastle
+| GameMode::EightOff
+| GameMode::SeahavenTowers | GameMode::BakersGame => format!(
+                                            "Winnability check timed out after {}s ({variant_label}): solver found no winning line within {} iterations.",
+                                            SEED_WINNABILITY_TIMEOUT_SECS, result.iterations
+                                        ),
+                                        _ => seed_ops::msg_winnability_check_timed_out(
                                             deal_count,
                                             SEED_WINNABILITY_TIMEOUT_SECS,
                                             result.iterations,
@@ -283,7 +370,7 @@ impl CardthropicWindow {
                                 if let Some(entry) = window.seed_text_entry() {
                                     entry.add_css_class("seed-winnable");
                                 }
-                                if mode == GameMode::Freecell {
+                                if mode.is_freecell_family() {
                                     if window.imp().move_count.get() == 0 {
                                         if let Some(line) = result.freecell_line.clone() {
                                             window
@@ -304,17 +391,30 @@ impl CardthropicWindow {
                                 let message = match mode {
                                     GameMode::Spider => format!(
                                         "Seed {seed} is winnable for Spider {spider_suit_count}-suit from a fresh deal (solver line: {moves} moves, {} iterations). Use Robot as first action to see win.",
-                                        result.iterations
+                                        result.iterations,
+                                        seed = seed_ops::seed_label(seed),
                                     ),
                                     GameMode::Freecell if window.imp().move_count.get() == 0 => format!(
                                         "Seed {seed} is winnable for FreeCell {freecell_card_count} from a fresh deal (solver line: {moves} moves, {} iterations). Use Robot as first action to see win.",
-                                        result.iterations
+                                        result.iterations,
+                                        seed = seed_ops::seed_label(seed),
                                     ),
                                     GameMode::Freecell => format!(
                                         "Seed {seed} is winnable for FreeCell {freecell_card_count} from a fresh deal (solver line: {moves} moves, {} iterations). Start a fresh deal and use Robot as first action to see win.",
-                                        result.iterations
+                                        result.iterations,
+                                        seed = seed_ops::seed_label(seed),
+                                    ),
+                                    GameMode::TriPeaks
+| GameMode::Golf
+| GameMode::BakersDozen
+| GameMode::BeleagueredCastle
+| GameMode::EightOff
+| GameMode::SeahavenTowers | GameMode::BakersGame => format!(
+                                        "Seed {seed} is winnable for {variant_label} from a fresh deal (solver line: {moves} moves, {} iterations). Use Robot as first action to see win.",
+                                        result.iterations,
+                                        seed = seed_ops::seed_label(seed),
                                     ),
-                                    GameMode::Klondike => seed_ops::msg_seed_winnable(
+                                    _ => seed_ops::msg_seed_winnable(
                                         seed,
                                         deal_count,
                                         moves,
@@ -329,28 +429,61 @@ impl CardthropicWindow {
                                 let message = match mode {
                                     GameMode::Spider if result.hit_state_limit => format!(
                                         "Seed {seed} not proven winnable for Spider {spider_suit_count}-suit from a fresh deal ({} iterations, limits hit).",
-                                        result.iterations
+                                        result.iterations,
+                                        seed = seed_ops::seed_label(seed),
                                     ),
                                     GameMode::Spider => format!(
                                         "Seed {seed}: solver found no winning line for Spider {spider_suit_count}-suit from a fresh deal ({} iterations).",
-                                        result.iterations
+                                        result.iterations,
+                                        seed = seed_ops::seed_label(seed),
                                     ),
                                     GameMode::Freecell if result.hit_state_limit => format!(
                                         "Seed {seed} not proven winnable for FreeCell {freecell_card_count} from a fresh deal ({} iterations, limits hit).",
-                                        result.iterations
+                                        result.iterations,
+                                        seed = seed_ops::seed_label(seed),
                                     ),
                                     GameMode::Freecell => format!(
                                         "Seed {seed}: solver found no winning line for FreeCell {freecell_card_count} from a fresh deal ({} iterations).",
-                                        result.iterations
+                                        result.iterations,
+                                        seed = seed_ops::seed_label(seed),
                                     ),
-                                    GameMode::Klondike if result.hit_state_limit => {
+                                    GameMode::TriPeaks
+| GameMode::Golf
+| GameMode::BakersDozen
+| GameMode::BeleagueredCastle
+| GameMode::EightOff
+| GameMode::SeahavenTowers | GameMode::BakersGame
+                                        if result.hit_state_limit =>
+                                    {
+                                        format!(
+                                            "Seed {seed} not proven winnable for {variant_label} from a fresh deal ({} iterations, limits hit).",
+                                            result.iterations,
+                                            seed = seed_ops::seed_label(seed),
+                                        )
+                                    }
+                                    GameMode::TriPeaks
+| GameMode::Golf
+| GameMode::BakersDozen
+| GameMode::BeleagueredCastle
+| GameMode::EightOff
+| GameMode::SeahavenTowers | GameMode::BakersGame => format!(
+                                        "Seed {seed} cannot be won in {variant_label} from a fresh deal ({} iterations).",
+                                        result.iterations,
+                                        seed = seed_ops::seed_label(seed),
+                                    ),
+                                    _ if klondike_thoughtful && !result.hit_state_limit => format!(
+                                        "Seed {seed} cannot be won in Thoughtful Deal {deal_count}: every reachable position was searched ({} states).",
+                                        result.iterations,
+                                        seed = seed_ops::seed_label(seed),
+                                    ),
+                                    _ if result.hit_state_limit => {
                                         seed_ops::msg_seed_unwinnable_limited(
                                             seed,
                                             deal_count,
                                             result.iterations,
                                         )
                                     }
-                                    GameMode::Klondike => {
+                                    _ => {
                                         seed_ops::msg_seed_unwinnable(
                                             seed,
                                             deal_count,
@@ -379,7 +512,15 @@ impl CardthropicWindow {
                                             "Winnability check stopped unexpectedly (FreeCell {freecell_card_count})."
                                         )
                                     }
-                                    GameMode::Klondike => {
+                                    GameMode::TriPeaks
+| GameMode::Golf
+| GameMode::BakersDozen
+| GameMode::BeleagueredCastle
+| GameMode::EightOff
+| GameMode::SeahavenTowers | GameMode::BakersGame => format!(
+                                        "Winnability check stopped unexpectedly ({variant_label})."
+                                    ),
+                                    _ => {
                                         seed_ops::msg_winnability_check_stopped_unexpectedly(
                                             deal_count,
                                         )
@@ -1,5 +1,6 @@
 use crate::engine::chess::boundary as chess_boundary;
 use crate::engine::chess::commands::ChessCommand;
+use crate::engine::seed_ops;
 use crate::game::{legal_moves, ChessColor, ChessVariant};
 use crate::CardthropicWindow;
 use adw::subclass::prelude::ObjectSubclassIsExt;
@@ -15,6 +16,10 @@ impl CardthropicWindow {
         self.start_chess_variant(ChessVariant::Chess960);
     }
 
+    pub(crate) fn launch_double_chess960_placeholder(&self) {
+        self.start_chess_variant(ChessVariant::DoubleChess960);
+    }
+
     pub(crate) fn launch_chess_atomic_placeholder(&self) {
         self.start_chess_variant(ChessVariant::Atomic);
     }
@@ -25,6 +30,7 @@ impl CardthropicWindow {
         let seed = imp.current_seed.get();
         imp.chess_variant.set(variant);
         imp.chess_mode_active.set(true);
+        imp.seedless_board.set(false);
         self.reset_chess_session_state();
         imp.move_count.set(0);
         imp.elapsed_seconds.set(0);
@@ -50,11 +56,14 @@ impl CardthropicWindow {
             }
             legal_moves(&position).len()
         };
+        self.sync_chess960_start_number();
+        let variant_label = match self.chess960_start_number_label() {
+            Some(start_label) => format!("{} {start_label}", variant.label()),
+            None => variant.label().to_string(),
+        };
         *imp.status_override.borrow_mut() = Some(format!(
-            "{} initialized from seed {}. {} legal opening moves. White to move.",
-            variant.label(),
-            seed,
-            legal_count
+            "{variant_label} initialized from seed {seed}. {legal_count} legal opening moves. White to move.",
+            seed = seed_ops::seed_label(seed),
         ));
         self.append_status_history_only(&self.new_game_started_timestamp_status());
         imp.history.borrow_mut().push(undo_anchor);
@@ -84,11 +93,42 @@ impl CardthropicWindow {
         self.start_new_chess_game_with_seed_internal(seed, status, true);
     }
 
+    /// Starts the Chess960 layout with the given Scharnagl number (0..=959).
+    pub(in crate::window) fn start_chess960_from_scharnagl(&self, number: u16) {
+        let imp = self.imp();
+        if !imp.chess_mode_active.get() || imp.chess_variant.get() != ChessVariant::Chess960 {
+            self.start_chess_variant(ChessVariant::Chess960);
+        }
+        let seed = imp.current_seed.get();
+        self.start_new_chess_game_internal(
+            seed,
+            ChessCommand::NewChess960 { scharnagl: number },
+            "Started a new Chess960 game".to_string(),
+            false,
+        );
+    }
+
     fn start_new_chess_game_with_seed_internal(
         &self,
         seed: u64,
         status: String,
         preserve_robot: bool,
+    ) {
+        let variant = self.imp().chess_variant.get();
+        self.start_new_chess_game_internal(
+            seed,
+            ChessCommand::NewGame { seed, variant },
+            status,
+            preserve_robot,
+        );
+    }
+
+    fn start_new_chess_game_internal(
+        &self,
+        seed: u64,
+        command: ChessCommand,
+        status: String,
+        preserve_robot: bool,
     ) {
         let imp = self.imp();
         let variant = imp.chess_variant.get();
@@ -105,7 +145,8 @@ impl CardthropicWindow {
         self.reset_chess_session_state();
 
         imp.current_seed.set(seed);
-        self.set_seed_input_text(&seed.to_string());
+        imp.seedless_board.set(false);
+        self.set_seed_input_text(&seed_ops::seed_label(seed));
         self.clear_seed_entry_feedback();
         *imp.selected_run.borrow_mut() = None;
         imp.selected_freecell.set(None);
@@ -116,7 +157,9 @@ impl CardthropicWindow {
         imp.move_count.set(0);
         imp.elapsed_seconds.set(0);
         imp.timer_started.set(false);
-        self.note_seed_play_started(seed);
+        if matches!(command, ChessCommand::NewGame { .. }) {
+            self.note_seed_play_started(seed);
+        }
         self.reset_hint_cycle_memory();
         self.reset_auto_play_memory();
         if preserve_robot {
@@ -125,8 +168,7 @@ impl CardthropicWindow {
 
         let changed = {
             let mut position = imp.chess_position.borrow_mut();
-            let result =
-                chess_boundary::execute(&mut position, ChessCommand::NewGame { seed, variant });
+            let result = chess_boundary::execute(&mut position, command);
             if result.changed && position.side_to_move() != ChessColor::White {
                 position.set_side_to_move(ChessColor::White);
                 self.append_status_history_only(
@@ -135,6 +177,7 @@ impl CardthropicWindow {
             }
             result.changed
         };
+        self.sync_chess960_start_number();
         if !changed {
             *imp.status_override.borrow_mut() = Some(format!(
                 "{} initialization is not available yet.",
@@ -151,6 +194,9 @@ impl CardthropicWindow {
             if !decorated.ends_with('.') {
                 decorated.push('.');
             }
+            if let Some(start_label) = self.chess960_start_number_label() {
+                decorated.push_str(&format!(" {start_label}."));
+            }
             decorated.push_str(" White to move.");
             decorated
         };
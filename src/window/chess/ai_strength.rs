@@ -138,7 +138,7 @@ impl CardthropicWindow {
     ) -> (u8, u64, u64) {
         match variant {
             ChessVariant::Atomic => Self::chess_ai_strength_triplet_atomic(strength),
-            ChessVariant::Standard | ChessVariant::Chess960 => {
+            ChessVariant::Standard | ChessVariant::Chess960 | ChessVariant::DoubleChess960 => {
                 Self::chess_ai_strength_triplet_classical(strength)
             }
         }
@@ -1,9 +1,10 @@
 use crate::engine::chess::ai::{self as chess_ai, AiConfig};
 use crate::engine::chess::boundary as chess_boundary;
 use crate::engine::chess::commands::ChessCommand;
+use crate::engine::chess::explain::explain_move;
 use crate::game::{
-    file_of, is_in_check, legal_moves, rank_of, square, square_name, terminal_state, ChessColor,
-    ChessMove, ChessPiece, ChessPieceKind, ChessPosition, ChessTerminalState, Square,
+    apply_move, file_of, is_in_check, legal_moves, rank_of, square, square_name, terminal_state,
+    ChessColor, ChessMove, ChessPiece, ChessPieceKind, ChessPosition, ChessTerminalState, Square,
 };
 use crate::window::types::ChessAiPendingKind;
 use crate::CardthropicWindow;
@@ -86,6 +87,110 @@ impl CardthropicWindow {
         if let Some(source_id) = imp.chess_ai_search_poll_timer.borrow_mut().take() {
             Self::remove_source_if_present(source_id);
         }
+        self.cancel_chess_ai_ponder();
+    }
+
+    fn cancel_chess_ai_ponder(&self) {
+        let imp = self.imp();
+        imp.chess_ai_ponder_position_hash.set(None);
+        imp.chess_ai_ponder_started_mono_us.set(0);
+        // The worker notices the flag and exits on its own; joining here would
+        // hold up the move that made the ponder stale.
+        if let Some(search) = imp.chess_ai_ponder_search.borrow_mut().take() {
+            search.cancel();
+        }
+    }
+
+    /// Ponders the reply the human is expected to play (second move of the PV)
+    /// so the auto-response can answer from a head start.
+    fn maybe_start_chess_ai_ponder(&self, pv: &[ChessMove]) {
+        let imp = self.imp();
+        self.cancel_chess_ai_ponder();
+        if imp.robot_mode_running.get() || !self.chess_wand_ai_opponent_auto_response_enabled() {
+            return;
+        }
+        let Some(expected_reply) = pv.get(1).copied() else {
+            return;
+        };
+        let mut pondered = imp.chess_position.borrow().clone();
+        if self.chess_auto_response_side_matches(pondered.side_to_move()) {
+            return;
+        }
+        if !legal_moves(&pondered).contains(&expected_reply)
+            || !apply_move(&mut pondered, expected_reply)
+            || legal_moves(&pondered).is_empty()
+        {
+            return;
+        }
+
+        let kind = ChessAiPendingKind::Wand {
+            include_opponent_auto_response: false,
+        };
+        let limits = self.chess_ai_search_limits_for_kind(kind);
+        let pondered_hash = Self::chess_position_hash(&pondered);
+        let search = chess_ai::spawn_ponder_search(
+            pondered,
+            limits,
+            AiConfig::default(),
+            imp.chess_ai_transposition_table.clone(),
+        );
+        *imp.chess_ai_ponder_search.borrow_mut() = Some(search);
+        imp.chess_ai_ponder_position_hash.set(Some(pondered_hash));
+        imp.chess_ai_ponder_started_mono_us
+            .set(glib::monotonic_time());
+        if imp.robot_debug_enabled.get() {
+            self.append_status_history_only(&format!(
+                "chess_ai_v=1 event=ponder_start source={} expected={} depth_limit={} time_budget_ms={} node_budget={} expected_hash={}",
+                Self::chess_ai_kind_source(kind),
+                Self::chess_ai_format_move(expected_reply),
+                limits.max_depth,
+                limits.time_budget_ms,
+                limits.node_budget,
+                pondered_hash,
+            ));
+        }
+    }
+
+    /// Hands over the ponder search when the human played the expected move;
+    /// otherwise discards it. The search's time budget starts at the hit.
+    fn take_chess_ai_ponder_hit(
+        &self,
+        kind: ChessAiPendingKind,
+        position_hash: u64,
+    ) -> Option<chess_ai::AsyncSearch> {
+        let imp = self.imp();
+        let pondered_hash = imp.chess_ai_ponder_position_hash.get();
+        let hit = matches!(
+            kind,
+            ChessAiPendingKind::Wand {
+                include_opponent_auto_response: false
+            }
+        ) && pondered_hash == Some(position_hash);
+        if !hit {
+            if pondered_hash.is_some() && imp.robot_debug_enabled.get() {
+                self.append_status_history_only(&format!(
+                    "chess_ai_v=1 event=ponder_miss source={} expected_hash={} current_hash={}",
+                    Self::chess_ai_kind_source(kind),
+                    pondered_hash.unwrap_or_default(),
+                    position_hash,
+                ));
+            }
+            self.cancel_chess_ai_ponder();
+            return None;
+        }
+        let search = imp.chess_ai_ponder_search.borrow_mut().take()?;
+        let started_mono_us = imp.chess_ai_ponder_started_mono_us.replace(0);
+        imp.chess_ai_ponder_position_hash.set(None);
+        search.ponder_hit();
+        if imp.robot_debug_enabled.get() {
+            self.append_status_history_only(&format!(
+                "chess_ai_v=1 event=ponder_hit source={} pondered_ms={} expected_hash={}",
+                Self::chess_ai_kind_source(kind),
+                Self::chess_ai_elapsed_ms(started_mono_us),
+                position_hash,
+            ));
+        }
+        Some(search)
     }
 
     fn queue_chess_ai_search(&self, kind: ChessAiPendingKind) -> bool {
@@ -147,12 +252,21 @@ impl CardthropicWindow {
         let expected_hash = Self::chess_position_hash(&position);
         let limits = self.chess_ai_search_limits_for_kind(kind);
         let started_elapsed_seconds = imp.elapsed_seconds.get();
-        let search = chess_ai::spawn_search(position.clone(), limits, AiConfig::default());
+        let search = self
+            .take_chess_ai_ponder_hit(kind, expected_hash)
+            .unwrap_or_else(|| {
+                chess_ai::spawn_search(
+                    position.clone(),
+                    limits,
+                    AiConfig::default(),
+                    imp.chess_ai_transposition_table.clone(),
+                )
+            });
+        let started_mono_us = glib::monotonic_time();
         *imp.chess_ai_pending_search.borrow_mut() = Some(search);
         imp.chess_ai_pending_kind.set(Some(kind));
         imp.chess_ai_pending_position_hash.set(Some(expected_hash));
-        imp.chess_ai_pending_started_mono_us
-            .set(glib::monotonic_time());
+        imp.chess_ai_pending_started_mono_us.set(started_mono_us);
         imp.chess_ai_pending_started_elapsed_seconds
             .set(started_elapsed_seconds);
         imp.chess_ai_pending_poll_count.set(0);
@@ -319,7 +433,17 @@ impl CardthropicWindow {
                 let source = self.chess_ai_status_prefix(ChessAiPendingKind::Wand {
                     include_opponent_auto_response,
                 });
-                applied = self.apply_chess_ai_move(chosen_move, &source);
+                // Only the player's wand gets a reason; the AI's reply stays terse.
+                let explanation = include_opponent_auto_response
+                    .then(|| {
+                        let position = imp.chess_position.borrow();
+                        explain_move(&position, chosen_move, &result.pv)
+                    })
+                    .flatten();
+                applied = self.apply_chess_ai_move(chosen_move, &source, explanation.as_deref());
+                if applied && !include_opponent_auto_response {
+                    self.maybe_start_chess_ai_ponder(&result.pv);
+                }
                 if applied
                     && include_opponent_auto_response
                     && self.chess_wand_ai_opponent_auto_response_enabled()
@@ -337,7 +461,7 @@ impl CardthropicWindow {
             ChessAiPendingKind::Robot { side_to_move } => {
                 let source =
                     self.chess_ai_status_prefix(ChessAiPendingKind::Robot { side_to_move });
-                if self.apply_chess_ai_move(chosen_move, &source) {
+                if self.apply_chess_ai_move(chosen_move, &source, None) {
                     applied = true;
                     let next_moves = imp.robot_moves_applied.get().saturating_add(1);
                     imp.robot_moves_applied.set(next_moves);
@@ -564,7 +688,12 @@ impl CardthropicWindow {
         let _ = self.play_chess_ai_hint_move_single();
     }
 
-    fn apply_chess_ai_move(&self, chosen_move: ChessMove, source: &str) -> bool {
+    fn apply_chess_ai_move(
+        &self,
+        chosen_move: ChessMove,
+        source: &str,
+        explanation: Option<&str>,
+    ) -> bool {
         let imp = self.imp();
         let position = imp.chess_position.borrow().clone();
         let side_to_move = position.side_to_move();
@@ -597,7 +726,7 @@ impl CardthropicWindow {
 
         let after = imp.chess_position.borrow().clone();
         let next_side = after.side_to_move();
-        let status = if let Some(terminal_status) = chess_terminal_status_text(&after) {
+        let mut status = if let Some(terminal_status) = chess_terminal_status_text(&after) {
             imp.timer_started.set(false);
             terminal_status
         } else if is_in_check(&after, next_side) {
@@ -612,6 +741,10 @@ impl CardthropicWindow {
                 capture_suffix,
             )
         };
+        if let Some(explanation) = explanation {
+            status.push(' ');
+            status.push_str(explanation);
+        }
         *imp.status_override.borrow_mut() = Some(status);
         self.maybe_play_chess_system_move_sound();
         let rendered_by_flip = self.maybe_auto_flip_chess_board_to_side_to_move(false);
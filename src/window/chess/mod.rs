@@ -6,3 +6,4 @@ mod input;
 mod render;
 mod rotation;
 mod session;
+mod start_position;
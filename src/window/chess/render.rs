@@ -1,9 +1,10 @@
 use crate::game::{
-    file_of, is_in_check, legal_moves, rank_of, square, square_name, ChessColor, ChessPiece,
-    ChessPieceKind, Square,
+    atomic_blast_preview, file_of, is_in_check, legal_moves, rank_of, square, square_name,
+    AtomicBlastPreview, ChessColor, ChessPiece, ChessPieceKind, Square, BOARD_SQUARES,
 };
 use crate::CardthropicWindow;
 use adw::subclass::prelude::ObjectSubclassIsExt;
+use gtk::glib;
 use gtk::prelude::*;
 use std::collections::HashSet;
 
@@ -30,6 +31,70 @@ impl CardthropicWindow {
         }
     }
 
+    /// Blast of the selected piece capturing on the hovered (or keyboard-focused) square.
+    fn chess_blast_preview(&self) -> Option<AtomicBlastPreview> {
+        let imp = self.imp();
+        let from = imp.chess_selected_square.get()?;
+        let to = imp
+            .chess_blast_preview_target
+            .get()
+            .or_else(|| self.chess_keyboard_square())?;
+        let position = imp.chess_position.borrow();
+        legal_moves(&position)
+            .into_iter()
+            .find(|mv| mv.from == from && mv.to == to)
+            .and_then(|mv| atomic_blast_preview(&position, mv))
+    }
+
+    pub(in crate::window) fn apply_chess_blast_preview_classes(&self) {
+        let preview = self.chess_blast_preview();
+        for sq in 0..BOARD_SQUARES as Square {
+            let Some(widget) = self.chess_widget_for_square(sq) else {
+                continue;
+            };
+            let in_zone = preview
+                .as_ref()
+                .is_some_and(|blast| blast.zone.contains(&sq));
+            let casualty = preview
+                .as_ref()
+                .is_some_and(|blast| blast.casualties.contains(&sq));
+            if in_zone {
+                widget.add_css_class("chess-square-blast-zone");
+            } else {
+                widget.remove_css_class("chess-square-blast-zone");
+            }
+            if casualty {
+                widget.add_css_class("chess-square-blast-casualty");
+            } else {
+                widget.remove_css_class("chess-square-blast-casualty");
+            }
+        }
+    }
+
+    fn attach_chess_blast_preview_hover(&self, square_widget: &gtk::Overlay, sq: Square) {
+        let motion = gtk::EventControllerMotion::new();
+        motion.connect_enter(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_, _, _| {
+                window.imp().chess_blast_preview_target.set(Some(sq));
+                window.apply_chess_blast_preview_classes();
+            }
+        ));
+        motion.connect_leave(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                let imp = window.imp();
+                if imp.chess_blast_preview_target.get() == Some(sq) {
+                    imp.chess_blast_preview_target.set(None);
+                    window.apply_chess_blast_preview_classes();
+                }
+            }
+        ));
+        square_widget.add_controller(motion);
+    }
+
     pub(in crate::window) fn render_chess_board(&self) {
         self.configure_chess_playfield_chrome();
         self.update_tableau_metrics();
@@ -68,6 +133,7 @@ impl CardthropicWindow {
             white_in_check,
             black_in_check,
         );
+        self.apply_chess_blast_preview_classes();
         self.apply_chess_board_rotation_transform(square_size);
         self.set_chess_controls_enabled();
         self.update_stats_label();
@@ -338,6 +404,15 @@ impl CardthropicWindow {
         let stacks = self.tableau_stacks();
         let drag_hover_row = self.imp().chess_drag_hover_row_from_top.get();
         let show_edge_markers = self.chess_show_board_coordinates_enabled();
+        let blast_previews = selected
+            .map(|from| {
+                legal_moves(position)
+                    .into_iter()
+                    .filter(|mv| mv.from == from)
+                    .filter_map(|mv| Some((mv.to, atomic_blast_preview(position, mv)?)))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
         for (file_idx, stack) in stacks.iter().enumerate() {
             if file_idx >= CHESS_BOARD_SIZE as usize {
                 stack.set_visible(false);
@@ -382,6 +457,13 @@ impl CardthropicWindow {
                 if drag_hover_row == Some(row_from_top) {
                     square_widget.add_css_class("chess-square-drop-row");
                 }
+                let blast = blast_previews
+                    .iter()
+                    .find(|(to, _)| *to == sq)
+                    .map(|(_, blast)| blast);
+                if blast.is_some() {
+                    self.attach_chess_blast_preview_hover(&square_widget, sq);
+                }
 
                 let piece_label = gtk::Label::new(None);
                 piece_label.set_xalign(0.5);
@@ -443,10 +525,14 @@ impl CardthropicWindow {
                         {
                             square_widget.add_css_class("chess-square-in-check");
                         }
+                        let blast_note = blast
+                            .map(|blast| format!(" (capture explodes {})", blast.casualties.len()))
+                            .unwrap_or_default();
                         square_widget.set_tooltip_text(Some(&format!(
-                            "{} {}",
+                            "{} {}{}",
                             square_name(sq),
-                            piece_name(piece)
+                            piece_name(piece),
+                            blast_note
                         )));
                     }
                     None => {
@@ -653,6 +739,24 @@ impl CardthropicWindow {
     0 0 38px rgba(86, 217, 255, 0.72);
 }
 
+/* Atomic capture preview - blast radius and the pieces it would destroy */
+.chess-square.chess-square-blast-zone {
+  background-image:
+    radial-gradient(circle at 50% 50%,
+      rgba(255, 150, 70, 0.22) 0%,
+      rgba(255, 110, 40, 0.08) 70%,
+      transparent 90%);
+  box-shadow: inset 0 0 0 2px rgba(255, 150, 70, 0.55);
+}
+
+.chess-square.chess-square-blast-casualty {
+  background-color: rgba(255, 90, 40, 0.22);
+  box-shadow:
+    inset 0 0 0 3px rgba(255, 120, 50, 0.95),
+    inset 0 0 24px rgba(255, 90, 40, 0.45),
+    0 0 24px rgba(255, 110, 40, 0.4);
+}
+
 /* Check warning - Magenta emergency protocol */
 .chess-square-in-check {
   box-shadow:
@@ -864,7 +968,7 @@ fn piece_name(piece: ChessPiece) -> &'static str {
     }
 }
 
-fn piece_glyph(piece: ChessPiece) -> &'static str {
+pub(super) fn piece_glyph(piece: ChessPiece) -> &'static str {
     match (piece.color, piece.kind) {
         (ChessColor::White, ChessPieceKind::King) => "♔",
         (ChessColor::White, ChessPieceKind::Queen) => "♕",
@@ -1,4 +1,7 @@
-use crate::game::{apply_move, legal_moves};
+use crate::game::{
+    apply_move, chess960_back_rank_from_scharnagl, chess960_back_rank_label,
+    chess960_scharnagl_number, legal_moves, ChessColor, ChessVariant,
+};
 use crate::CardthropicWindow;
 use adw::subclass::prelude::ObjectSubclassIsExt;
 
@@ -23,12 +26,14 @@ impl CardthropicWindow {
     pub(in crate::window) fn reset_chess_session_state(&self) {
         let imp = self.imp();
         self.cancel_pending_chess_ai_search();
+        imp.chess_ai_transposition_table.clear();
         imp.chess_selected_square.set(None);
         imp.chess_keyboard_square.set(None);
         imp.chess_last_move_from.set(None);
         imp.chess_last_move_to.set(None);
         imp.chess_history.borrow_mut().clear();
         imp.chess_future.borrow_mut().clear();
+        imp.chess960_start_number.set(None);
     }
 
     pub(in crate::window) fn push_chess_history_position(
@@ -100,4 +105,35 @@ impl CardthropicWindow {
         }
         true
     }
+
+    /// Re-derives the Chess960 start-position number from the game's first position.
+    pub(in crate::window) fn sync_chess960_start_number(&self) {
+        let imp = self.imp();
+        let number = if imp.chess_variant.get() == ChessVariant::Chess960 {
+            let history = imp.chess_history.borrow();
+            let start = history
+                .first()
+                .cloned()
+                .unwrap_or_else(|| imp.chess_position.borrow().clone());
+            chess960_scharnagl_number(start.back_rank(ChessColor::White))
+                .or(imp.chess960_start_number.get())
+        } else {
+            None
+        };
+        imp.chess960_start_number.set(number);
+    }
+
+    /// `SP 518 (RNBQKBNR)` for the current Chess960 game, if known.
+    pub(in crate::window) fn chess960_start_number_label(&self) -> Option<String> {
+        let imp = self.imp();
+        if !imp.chess_mode_active.get() || imp.chess_variant.get() != ChessVariant::Chess960 {
+            return None;
+        }
+        let number = imp.chess960_start_number.get()?;
+        let back_rank = chess960_back_rank_from_scharnagl(number)?;
+        Some(format!(
+            "SP {number} ({})",
+            chess960_back_rank_label(&back_rank)
+        ))
+    }
 }
@@ -0,0 +1,216 @@
+use super::render::piece_glyph;
+use crate::game::{
+    chess960_back_rank_from_scharnagl, chess960_back_rank_label, ChessColor, ChessPiece,
+    ChessPieceKind, CHESS960_POSITION_COUNT, CHESS960_STANDARD_SCHARNAGL,
+};
+use crate::CardthropicWindow;
+use adw::subclass::prelude::ObjectSubclassIsExt;
+use gtk::glib;
+use gtk::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn chess960_back_rank_glyphs(back_rank: &[ChessPieceKind; 8]) -> String {
+    back_rank
+        .iter()
+        .map(|kind| {
+            piece_glyph(ChessPiece {
+                color: ChessColor::White,
+                kind: *kind,
+            })
+        })
+        .collect()
+}
+
+/// Matches a search query against `518`, `SP 518` or a back-rank fragment like `RNBQ`.
+fn chess960_start_position_matches(number: u16, label: &str, query: &str) -> bool {
+    let query = query.trim();
+    if query.is_empty() {
+        return true;
+    }
+    let digits = query
+        .trim_start_matches(|ch: char| ch.is_ascii_alphabetic() || ch.is_whitespace())
+        .trim();
+    if !digits.is_empty() && digits.chars().all(|ch| ch.is_ascii_digit()) {
+        return number.to_string().starts_with(digits);
+    }
+    label.contains(&query.to_ascii_uppercase())
+}
+
+impl CardthropicWindow {
+    pub(in crate::window) fn show_chess960_start_position_dialog(&self) {
+        self.popdown_main_menu_later();
+
+        let dialog = gtk::Window::builder()
+            .title("Chess960 Start Position")
+            .modal(true)
+            .transient_for(self)
+            .default_width(380)
+            .default_height(520)
+            .build();
+        dialog.set_destroy_with_parent(true);
+
+        let root = gtk::Box::new(gtk::Orientation::Vertical, 10);
+        root.set_margin_top(14);
+        root.set_margin_bottom(14);
+        root.set_margin_start(14);
+        root.set_margin_end(14);
+
+        let heading = gtk::Label::new(Some("Pick a Chess960 Start Position"));
+        heading.set_xalign(0.0);
+        heading.add_css_class("title-4");
+        root.append(&heading);
+
+        let body = gtk::Label::new(Some(
+            "Positions use standard Scharnagl numbering (0-959; 518 is the classical setup). Search by number or by back rank, e.g. RNBQ.",
+        ));
+        body.set_xalign(0.0);
+        body.set_wrap(true);
+        body.set_wrap_mode(gtk::pango::WrapMode::WordChar);
+        root.append(&body);
+
+        let search = gtk::SearchEntry::new();
+        search.set_placeholder_text(Some("Number or back rank"));
+        root.append(&search);
+
+        let listbox = gtk::ListBox::new();
+        listbox.set_selection_mode(gtk::SelectionMode::Single);
+        listbox.set_activate_on_single_click(false);
+        let labels = Rc::new(
+            (0..CHESS960_POSITION_COUNT)
+                .map(|number| {
+                    let back_rank = chess960_back_rank_from_scharnagl(number)
+                        .expect("Scharnagl number in range");
+                    (number, back_rank)
+                })
+                .map(|(number, back_rank)| {
+                    let label = chess960_back_rank_label(&back_rank);
+                    let row_label = gtk::Label::new(Some(&format!(
+                        "{number:>3}   {}   {label}",
+                        chess960_back_rank_glyphs(&back_rank)
+                    )));
+                    row_label.set_xalign(0.0);
+                    row_label.add_css_class("monospace");
+                    let row = gtk::ListBoxRow::new();
+                    row.set_child(Some(&row_label));
+                    listbox.append(&row);
+                    label
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let query = Rc::new(RefCell::new(String::new()));
+        listbox.set_filter_func(glib::clone!(
+            #[strong]
+            labels,
+            #[strong]
+            query,
+            move |row| {
+                let index = row.index().max(0) as usize;
+                let Some(label) = labels.get(index) else {
+                    return false;
+                };
+                chess960_start_position_matches(index as u16, label, &query.borrow())
+            }
+        ));
+        search.connect_search_changed(glib::clone!(
+            #[weak]
+            listbox,
+            #[strong]
+            query,
+            move |entry| {
+                *query.borrow_mut() = entry.text().to_string();
+                listbox.invalidate_filter();
+            }
+        ));
+
+        let current = self
+            .imp()
+            .chess960_start_number
+            .get()
+            .unwrap_or(CHESS960_STANDARD_SCHARNAGL);
+        if let Some(row) = listbox.row_at_index(i32::from(current)) {
+            listbox.select_row(Some(&row));
+        }
+
+        listbox.connect_row_activated(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[weak]
+            dialog,
+            move |_, row| {
+                let Ok(number) = u16::try_from(row.index()) else {
+                    return;
+                };
+                dialog.close();
+                window.start_chess960_from_scharnagl(number);
+            }
+        ));
+        search.connect_activate(glib::clone!(
+            #[weak]
+            listbox,
+            move |_| {
+                let mut index = 0;
+                while let Some(row) = listbox.row_at_index(index) {
+                    if row.is_child_visible() {
+                        let _ = row.activate();
+                        return;
+                    }
+                    index += 1;
+                }
+            }
+        ));
+
+        let scroller = gtk::ScrolledWindow::new();
+        scroller.set_vexpand(true);
+        scroller.set_policy(gtk::PolicyType::Never, gtk::PolicyType::Automatic);
+        scroller.set_child(Some(&listbox));
+        root.append(&scroller);
+
+        let actions = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        actions.set_halign(gtk::Align::End);
+
+        let random = gtk::Button::with_label("Random");
+        random.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[weak]
+            dialog,
+            move |_| {
+                let number = (rand::random::<u32>() % u32::from(CHESS960_POSITION_COUNT)) as u16;
+                dialog.close();
+                window.start_chess960_from_scharnagl(number);
+            }
+        ));
+        actions.append(&random);
+
+        let close = gtk::Button::with_label("Close");
+        close.connect_clicked(glib::clone!(
+            #[weak]
+            dialog,
+            move |_| {
+                dialog.close();
+            }
+        ));
+        actions.append(&close);
+
+        let start = gtk::Button::with_label("Start");
+        start.add_css_class("suggested-action");
+        start.connect_clicked(glib::clone!(
+            #[weak]
+            listbox,
+            move |_| {
+                if let Some(row) = listbox.selected_row().filter(|row| row.is_child_visible()) {
+                    let _ = row.activate();
+                }
+            }
+        ));
+        actions.append(&start);
+        root.append(&actions);
+
+        dialog.set_default_widget(Some(&start));
+        dialog.set_child(Some(&root));
+        dialog.present();
+        let _ = search.grab_focus();
+    }
+}
@@ -0,0 +1,441 @@
+use super::*;
+use crate::engine::boundary;
+use crate::engine::daily::{self, ChallengeDate, DailyChallengeStore, DailyOutcome};
+use crate::engine::seed_ops;
+use crate::game::FreecellRules;
+
+impl CardthropicWindow {
+    pub(super) fn load_daily_challenges(&self) {
+        let raw = self
+            .imp()
+            .settings
+            .borrow()
+            .as_ref()
+            .filter(|settings| Self::settings_has_daily_challenges_key(settings))
+            .map(|settings| settings.string(SETTINGS_KEY_DAILY_CHALLENGES).to_string())
+            .unwrap_or_default();
+        *self.imp().daily_challenges.borrow_mut() = DailyChallengeStore::load_from_string(&raw);
+    }
+
+    fn settings_has_daily_challenges_key(settings: &gio::Settings) -> bool {
+        settings
+            .settings_schema()
+            .map(|schema| schema.has_key(SETTINGS_KEY_DAILY_CHALLENGES))
+            .unwrap_or(false)
+    }
+
+    fn persist_daily_challenges(&self) {
+        if !self.should_persist_shared_state() {
+            return;
+        }
+        let imp = self.imp();
+        if let Some(settings) = imp.settings.borrow().as_ref() {
+            if Self::settings_has_daily_challenges_key(settings) {
+                let payload = imp.daily_challenges.borrow().serialize();
+                let _ = settings.set_string(SETTINGS_KEY_DAILY_CHALLENGES, &payload);
+            }
+        }
+    }
+
+    fn daily_today() -> Option<ChallengeDate> {
+        let now = glib::DateTime::now_local().ok()?;
+        ChallengeDate::new(
+            now.year(),
+            u8::try_from(now.month()).ok()?,
+            u8::try_from(now.day_of_month()).ok()?,
+        )
+    }
+
+    /// Key of the daily challenge on the board, written to the saved session.
+    pub(super) fn daily_game_session_value(&self) -> Option<String> {
+        self.imp()
+            .daily_game
+            .borrow()
+            .as_ref()
+            .map(|(date, _)| date.key())
+    }
+
+    /// Picks the daily challenge back up after a restart, as long as the
+    /// restored deal is still that day's unfinished attempt.
+    pub(super) fn resume_daily_game(&self, raw: Option<&str>) {
+        let imp = self.imp();
+        *imp.daily_game.borrow_mut() = None;
+        if imp.seedless_board.get() {
+            return;
+        }
+        let (Some(date), Some(key)) =
+            (raw.and_then(ChallengeDate::parse), self.current_stats_key())
+        else {
+            return;
+        };
+        let Some(entry) = imp.daily_challenges.borrow().entry(date, &key) else {
+            return;
+        };
+        if entry.outcome == DailyOutcome::Attempted && entry.seed == imp.current_seed.get() {
+            *imp.daily_game.borrow_mut() = Some((date, key));
+        }
+    }
+
+    pub(super) fn clear_daily_game(&self) {
+        *self.imp().daily_game.borrow_mut() = None;
+    }
+
+    /// Winnable-checks today's candidate seeds for the current rules in the
+    /// background and deals the first one that passes. Each rule profile gets
+    /// one attempt per day.
+    pub(super) fn start_daily_challenge(&self) {
+        let imp = self.imp();
+        if imp.chess_mode_active.get() {
+            *imp.status_override.borrow_mut() =
+                Some("Daily Challenge not applicable to Chess".to_string());
+            self.render();
+            return;
+        }
+        if !self.guard_mode_engine("Daily Challenge") {
+            return;
+        }
+        if imp.seed_search_in_progress.get() {
+            *imp.status_override.borrow_mut() = Some(seed_ops::msg_seed_search_running());
+            self.render();
+            return;
+        }
+        let (Some(date), Some(key)) = (Self::daily_today(), self.current_stats_key()) else {
+            return;
+        };
+        if let Some(entry) = imp.daily_challenges.borrow().entry(date, &key) {
+            *imp.status_override.borrow_mut() = Some(match entry.outcome {
+                DailyOutcome::Won => format!(
+                    "Today's {} challenge is already won (seed {}).",
+                    key.mode.label(),
+                    seed_ops::seed_label(entry.seed)
+                ),
+                DailyOutcome::Attempted => format!(
+                    "Today's {} challenge was already attempted (seed {}). Come back tomorrow.",
+                    key.mode.label(),
+                    seed_ops::seed_label(entry.seed)
+                ),
+            });
+            self.render();
+            return;
+        }
+
+        self.cancel_seed_winnable_check(None);
+        imp.seed_search_in_progress.set(true);
+        let cancel = Arc::new(AtomicBool::new(false));
+        *imp.seed_search_cancel.borrow_mut() = Some(Arc::clone(&cancel));
+        let mode = key.mode;
+        let rules_id = key.rules.clone();
+        let profile = AutomationProfile::for_mode(mode);
+        let draw_mode = self.current_klondike_draw_mode();
+        let klondike_pass_limit = self.current_klondike_pass_limit();
+        let klondike_thoughtful = self.current_klondike_thoughtful();
+        let spider_suit_mode = self.current_spider_suit_mode();
+        let spider_rule_set = self.current_spider_rule_set();
+        let freecell_card_count_mode = self.current_freecell_card_count_mode();
+        let freecell_layout = FreecellLayout::for_mode(mode);
+        let freecell_rules = freecell_layout
+            .map(|layout| imp.game.borrow().freecell_for(layout).rules())
+            .unwrap_or(FreecellRules::CLASSIC);
+        let checked =
+            mode == GameMode::Klondike || mode == GameMode::Spider || freecell_layout.is_some();
+        *imp.status_override.borrow_mut() = Some(if checked {
+            format!(
+                "Preparing the {} daily challenge for {}...",
+                mode.label(),
+                date.key()
+            )
+        } else {
+            format!(
+                "Dealing the {} daily challenge for {}...",
+                mode.label(),
+                date.key()
+            )
+        });
+        self.render();
+
+        let (sender, receiver) = mpsc::channel::<Option<(u64, bool)>>();
+        thread::spawn(move || {
+            let is_winnable = |seed: u64| {
+                let result = if mode == GameMode::Spider {
+                    winnability::is_spider_seed_winnable(
+                        seed,
+                        spider_suit_mode,
+                        spider_rule_set,
+                        profile.dialog_seed_guided_budget,
+                        profile.dialog_seed_exhaustive_budget,
+                        &cancel,
+                    )
+                } else if let Some(layout) = freecell_layout {
+                    winnability::is_freecell_seed_winnable(
+                        seed,
+                        layout,
+                        freecell_rules,
+                        freecell_card_count_mode,
+                        profile.dialog_seed_guided_budget,
+                        profile.dialog_seed_exhaustive_budget,
+                        &cancel,
+                    )
+                } else if klondike_thoughtful {
+                    winnability::is_thoughtful_seed_winnable(
+                        seed,
+                        draw_mode,
+                        klondike_pass_limit,
+                        profile.dialog_seed_exhaustive_budget,
+                        &cancel,
+                    )
+                } else {
+                    winnability::is_seed_winnable(
+                        seed,
+                        draw_mode,
+                        klondike_pass_limit,
+                        profile.dialog_seed_guided_budget,
+                        profile.dialog_seed_exhaustive_budget,
+                        &cancel,
+                    )
+                };
+                result.map(|result| result.winnable && !result.canceled)
+            };
+            let fallback = daily::candidate_seed(date, mode, &rules_id, 0);
+            let mut picked = Some((fallback, false));
+            if checked {
+                for attempt in 0..daily::DAILY_CANDIDATES {
+                    let seed = daily::candidate_seed(date, mode, &rules_id, attempt);
+                    match is_winnable(seed) {
+                        Some(true) => {
+                            picked = Some((seed, true));
+                            break;
+                        }
+                        Some(false) => {}
+                        None => {
+                            picked = None;
+                            break;
+                        }
+                    }
+                    // A cancel mid-check would otherwise look like an
+                    // unwinnable candidate and change which seed is picked.
+                    if cancel.load(Ordering::Relaxed) {
+                        picked = None;
+                        break;
+                    }
+                }
+            }
+            let _ = sender.send(picked);
+        });
+
+        glib::timeout_add_local(
+            Duration::from_millis(40),
+            glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                #[upgrade_or]
+                glib::ControlFlow::Break,
+                move || {
+                    let picked = match receiver.try_recv() {
+                        Err(mpsc::TryRecvError::Empty) => return glib::ControlFlow::Continue,
+                        Ok(picked) => picked,
+                        Err(mpsc::TryRecvError::Disconnected) => None,
+                    };
+                    let imp = window.imp();
+                    imp.seed_search_in_progress.set(false);
+                    imp.seed_search_cancel.borrow_mut().take();
+                    let Some((seed, verified)) = picked else {
+                        *imp.status_override.borrow_mut() =
+                            Some("Daily challenge canceled.".to_string());
+                        window.render();
+                        return glib::ControlFlow::Break;
+                    };
+                    if window.current_stats_key().as_ref() != Some(&key) {
+                        *imp.status_override.borrow_mut() = Some(
+                            "Rules changed while preparing the daily challenge. Start it again."
+                                .to_string(),
+                        );
+                        window.render();
+                        return glib::ControlFlow::Break;
+                    }
+                    imp.daily_challenges
+                        .borrow_mut()
+                        .record_attempt(date, key.clone(), seed);
+                    window.persist_daily_challenges();
+                    let status = if verified {
+                        format!(
+                            "Daily challenge {} ({}): seed {seed}, checked winnable. One attempt today.",
+                            date.key(),
+                            key.rules_label(),
+                            seed = seed_ops::seed_label(seed),
+                        )
+                    } else {
+                        format!(
+                            "Daily challenge {} ({}): seed {seed}. One attempt today.",
+                            date.key(),
+                            key.rules_label(),
+                            seed = seed_ops::seed_label(seed),
+                        )
+                    };
+                    window.start_new_game_with_seed(seed, status);
+                    *imp.daily_game.borrow_mut() = Some((date, key.clone()));
+                    window.refresh_daily_dialog();
+                    window.trim_process_memory_if_supported();
+                    glib::ControlFlow::Break
+                }
+            ),
+        );
+    }
+
+    /// Marks today's challenge won. Games the robot touched do not count.
+    pub(super) fn note_daily_win_if_needed(&self) {
+        let imp = self.imp();
+        let mode = self.active_game_mode();
+        if !boundary::is_won(&imp.game.borrow(), mode) {
+            return;
+        }
+        let Some((date, key)) = imp.daily_game.borrow_mut().take() else {
+            return;
+        };
+        if imp.seedless_board.get()
+            || imp.stats_game_assisted.get()
+            || self.current_stats_key().as_ref() != Some(&key)
+        {
+            return;
+        }
+        let seed_matches = imp
+            .daily_challenges
+            .borrow()
+            .entry(date, &key)
+            .is_some_and(|entry| entry.seed == imp.current_seed.get());
+        if seed_matches && imp.daily_challenges.borrow_mut().record_win(date, &key) {
+            self.persist_daily_challenges();
+            self.refresh_daily_dialog();
+        }
+    }
+
+    pub(super) fn refresh_daily_dialog(&self) {
+        let imp = self.imp();
+        let Some(calendar) = imp.daily_calendar.borrow().clone() else {
+            return;
+        };
+        let key = self.current_stats_key();
+        calendar.clear_marks();
+        let shown = calendar.date();
+        if let (Some(key), Ok(month)) = (key.as_ref(), u8::try_from(shown.month())) {
+            for day in imp
+                .daily_challenges
+                .borrow()
+                .won_days_in_month(shown.year(), month, key)
+            {
+                calendar.mark_day(u32::from(day));
+            }
+        }
+
+        let Some(label) = imp.daily_status_label.borrow().clone() else {
+            return;
+        };
+        let text = match (key, Self::daily_today()) {
+            (Some(key), Some(today)) => {
+                let profile = format!("{} ({})", key.mode.label(), key.rules_label());
+                match imp.daily_challenges.borrow().entry(today, &key) {
+                    Some(entry) if entry.outcome == DailyOutcome::Won => {
+                        format!("{profile}: today's challenge is won. Marked days are wins.")
+                    }
+                    Some(_) => {
+                        format!("{profile}: today's challenge was attempted. Marked days are wins.")
+                    }
+                    None => format!("{profile}: today's challenge is open. Marked days are wins."),
+                }
+            }
+            (None, _) => "Daily challenges are not available in Chess.".to_string(),
+            (_, None) => "The local date is unavailable.".to_string(),
+        };
+        label.set_text(&text);
+    }
+
+    pub(super) fn show_daily_dialog(&self) {
+        if let Some(existing) = self.imp().daily_dialog.borrow().as_ref() {
+            self.refresh_daily_dialog();
+            existing.present();
+            return;
+        }
+
+        let dialog = gtk::Window::builder()
+            .title("Daily Challenge")
+            .transient_for(self)
+            .modal(false)
+            .default_width(360)
+            .build();
+        dialog.set_destroy_with_parent(true);
+        dialog.set_hide_on_close(false);
+        dialog.connect_close_request(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[upgrade_or]
+            glib::Propagation::Proceed,
+            move |_| {
+                *window.imp().daily_dialog.borrow_mut() = None;
+                *window.imp().daily_calendar.borrow_mut() = None;
+                *window.imp().daily_status_label.borrow_mut() = None;
+                glib::Propagation::Proceed
+            }
+        ));
+        let dialog_keys = gtk::EventControllerKey::new();
+        dialog_keys.set_propagation_phase(gtk::PropagationPhase::Capture);
+        dialog_keys.connect_key_pressed(glib::clone!(
+            #[weak]
+            dialog,
+            #[upgrade_or]
+            glib::Propagation::Proceed,
+            move |_, key, _, _| {
+                if key == gdk::Key::Escape {
+                    dialog.close();
+                    return glib::Propagation::Stop;
+                }
+                glib::Propagation::Proceed
+            }
+        ));
+        dialog.add_controller(dialog_keys);
+
+        let calendar = gtk::Calendar::new();
+        let refresh = glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_: &gtk::Calendar| {
+                window.refresh_daily_dialog();
+            }
+        );
+        calendar.connect_next_month(refresh.clone());
+        calendar.connect_prev_month(refresh.clone());
+        calendar.connect_next_year(refresh.clone());
+        calendar.connect_prev_year(refresh);
+
+        let status_label = gtk::Label::new(None);
+        status_label.set_xalign(0.0);
+        status_label.set_wrap(true);
+
+        let root = gtk::Box::new(gtk::Orientation::Vertical, 8);
+        root.set_margin_top(10);
+        root.set_margin_bottom(10);
+        root.set_margin_start(10);
+        root.set_margin_end(10);
+        root.append(&calendar);
+        root.append(&status_label);
+
+        let actions_row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        actions_row.set_halign(gtk::Align::End);
+        let play_button = gtk::Button::with_label("Play Today's Challenge");
+        play_button.add_css_class("suggested-action");
+        play_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.start_daily_challenge();
+            }
+        ));
+        actions_row.append(&play_button);
+        root.append(&actions_row);
+        dialog.set_child(Some(&root));
+
+        *self.imp().daily_calendar.borrow_mut() = Some(calendar);
+        *self.imp().daily_status_label.borrow_mut() = Some(status_label);
+        *self.imp().daily_dialog.borrow_mut() = Some(dialog.clone());
+        self.refresh_daily_dialog();
+        dialog.present();
+    }
+}
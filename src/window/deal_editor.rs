@@ -0,0 +1,477 @@
+use super::ai_winnability_check::{
+    SEED_WINNABILITY_TIMEOUT_SECS, THOUGHTFUL_WINNABILITY_STATE_BUDGET,
+};
+use super::*;
+use crate::game::TextBoard;
+
+const DEAL_EDITOR_RANKS: [&str; 13] = [
+    "A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K",
+];
+const DEAL_EDITOR_ERROR_TAG: &str = "deal-editor-error";
+
+impl CardthropicWindow {
+    /// Loads the active board into the editor.
+    fn load_deal_editor_current_board(&self) {
+        let Some(buffer) = self.imp().deal_editor_buffer.borrow().clone() else {
+            return;
+        };
+        let text = self.imp().game.borrow().board_text(self.active_game_mode());
+        buffer.set_text(&text);
+    }
+
+    fn deal_editor_text(&self) -> Option<String> {
+        let buffer = self.imp().deal_editor_buffer.borrow().clone()?;
+        let (start, end) = buffer.bounds();
+        Some(buffer.text(&start, &end, false).to_string())
+    }
+
+    fn set_deal_editor_status(&self, text: &str, error: bool) {
+        let Some(label) = self.imp().deal_editor_status_label.borrow().clone() else {
+            return;
+        };
+        label.set_text(text);
+        if error {
+            label.add_css_class("error");
+        } else {
+            label.remove_css_class("error");
+        }
+    }
+
+    /// Parses the editor text; a Klondike board without a Rules: line takes
+    /// the current draw, pass and Thoughtful settings. The error lists every
+    /// line that cannot be read, or else what is wrong with the board as a
+    /// whole.
+    fn deal_editor_board(&self) -> Result<TextBoard, String> {
+        let text = self.deal_editor_text().unwrap_or_default();
+        let line_errors = TextBoard::line_errors(&text);
+        if !line_errors.is_empty() {
+            let lines: Vec<String> = line_errors
+                .iter()
+                .map(|(line, err)| format!("Line {line}: {err}."))
+                .collect();
+            return Err(format!("Invalid board:\n{}", lines.join("\n")));
+        }
+        let mut board = TextBoard::parse(&text).map_err(|err| format!("Invalid board: {err}."))?;
+        if !TextBoard::has_rules_line(&text) {
+            self.apply_current_klondike_rules(&mut board);
+        }
+        Ok(board)
+    }
+
+    /// Underlines the lines the board text cannot read.
+    fn mark_deal_editor_line_errors(&self) {
+        let Some(buffer) = self.imp().deal_editor_buffer.borrow().clone() else {
+            return;
+        };
+        let (start, end) = buffer.bounds();
+        buffer.remove_tag_by_name(DEAL_EDITOR_ERROR_TAG, &start, &end);
+        let text = buffer.text(&start, &end, false);
+        for (line, _) in TextBoard::line_errors(&text) {
+            let Some(line_start) = buffer.iter_at_line(line as i32 - 1) else {
+                continue;
+            };
+            let mut line_end = line_start;
+            if !line_end.ends_line() {
+                line_end.forward_to_line_end();
+            }
+            buffer.apply_tag_by_name(DEAL_EDITOR_ERROR_TAG, &line_start, &line_end);
+        }
+    }
+
+    fn validate_deal_editor(&self) {
+        self.mark_deal_editor_line_errors();
+        match self.deal_editor_board() {
+            Ok(board) => self
+                .set_deal_editor_status(&format!("Valid {} board.", board.mode().label()), false),
+            Err(err) => self.set_deal_editor_status(&err, true),
+        }
+    }
+
+    fn cancel_deal_editor_check(&self) {
+        if let Some(cancel) = self.imp().deal_editor_check_cancel.borrow_mut().take() {
+            cancel.store(true, Ordering::Relaxed);
+        }
+    }
+
+    fn play_deal_editor_board(&self) {
+        let board = match self.deal_editor_board() {
+            Ok(board) => board,
+            Err(err) => {
+                self.set_deal_editor_status(&err, true);
+                return;
+            }
+        };
+        self.cancel_deal_editor_check();
+        let label = board.mode().label();
+        match self.start_text_board(board, &format!("Started an edited {label} board."), true) {
+            Ok(()) => {
+                self.set_deal_editor_status(&format!("Playing the edited {label} board."), false);
+                self.render();
+            }
+            Err(err) => self.set_deal_editor_status(&format!("Could not start: {err}."), true),
+        }
+    }
+
+    fn check_deal_editor_board(&self) {
+        let board = match self.deal_editor_board() {
+            Ok(board) => board,
+            Err(err) => {
+                self.set_deal_editor_status(&err, true);
+                return;
+            }
+        };
+        if matches!(board, TextBoard::Yukon(_)) {
+            self.set_deal_editor_status(
+                "Yukon has no solver to check this board with; it can still be played.",
+                true,
+            );
+            return;
+        }
+        self.cancel_deal_editor_check();
+        let cancel = Arc::new(AtomicBool::new(false));
+        *self.imp().deal_editor_check_cancel.borrow_mut() = Some(Arc::clone(&cancel));
+        let label = board.mode().label();
+        self.set_deal_editor_status(
+            &format!("Checking the {label} board (up to {SEED_WINNABILITY_TIMEOUT_SECS}s)..."),
+            false,
+        );
+
+        let profile = self.automation_profile();
+        let (sender, receiver) = mpsc::channel();
+        let worker_cancel = Arc::clone(&cancel);
+        thread::spawn(move || {
+            let _ = sender.send(solve_edited_board(board, profile, &worker_cancel));
+        });
+        let timeout_cancel = Arc::clone(&cancel);
+        glib::timeout_add_seconds_local_once(SEED_WINNABILITY_TIMEOUT_SECS, move || {
+            timeout_cancel.store(true, Ordering::Relaxed);
+        });
+
+        glib::timeout_add_local(
+            Duration::from_millis(40),
+            glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                #[upgrade_or]
+                glib::ControlFlow::Break,
+                move || {
+                    let result = match receiver.try_recv() {
+                        Err(mpsc::TryRecvError::Empty) => return glib::ControlFlow::Continue,
+                        Ok(result) => result,
+                        Err(mpsc::TryRecvError::Disconnected) => None,
+                    };
+                    let current = window
+                        .imp()
+                        .deal_editor_check_cancel
+                        .borrow()
+                        .as_ref()
+                        .is_some_and(|flag| Arc::ptr_eq(flag, &cancel));
+                    if !current {
+                        return glib::ControlFlow::Break;
+                    }
+                    window.imp().deal_editor_check_cancel.borrow_mut().take();
+                    let message = match result {
+                        None => format!("The {label} check stopped unexpectedly."),
+                        Some(result) if result.canceled => format!(
+                            "The {label} check stopped without a verdict after {} states.",
+                            result.iterations
+                        ),
+                        Some(result) if result.winnable => match result.moves_to_win {
+                            Some(moves) => format!(
+                                "Winnable: the solver found a {moves}-move line ({} states).",
+                                result.iterations
+                            ),
+                            None => format!("Winnable ({} states).", result.iterations),
+                        },
+                        Some(result) if result.hit_state_limit => format!(
+                            "Not proven winnable: the solver ran out of budget after {} states.",
+                            result.iterations
+                        ),
+                        Some(result) => format!(
+                            "The solver found no winning line from this board ({} states).",
+                            result.iterations
+                        ),
+                    };
+                    window.set_deal_editor_status(&message, false);
+                    glib::ControlFlow::Break
+                }
+            ),
+        );
+    }
+
+    pub(super) fn show_deal_editor_dialog(&self) {
+        if let Some(existing) = self.imp().deal_editor_dialog.borrow().as_ref() {
+            existing.present();
+            return;
+        }
+
+        let dialog = gtk::Window::builder()
+            .title("Deal Editor")
+            .transient_for(self)
+            .modal(false)
+            .default_width(720)
+            .default_height(560)
+            .build();
+        dialog.set_destroy_with_parent(true);
+        dialog.set_hide_on_close(false);
+        dialog.connect_close_request(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[upgrade_or]
+            glib::Propagation::Proceed,
+            move |_| {
+                window.cancel_deal_editor_check();
+                *window.imp().deal_editor_dialog.borrow_mut() = None;
+                *window.imp().deal_editor_buffer.borrow_mut() = None;
+                *window.imp().deal_editor_status_label.borrow_mut() = None;
+                glib::Propagation::Proceed
+            }
+        ));
+        let dialog_keys = gtk::EventControllerKey::new();
+        dialog_keys.set_propagation_phase(gtk::PropagationPhase::Capture);
+        dialog_keys.connect_key_pressed(glib::clone!(
+            #[weak]
+            dialog,
+            #[upgrade_or]
+            glib::Propagation::Proceed,
+            move |_, key, _, _| {
+                if key == gdk::Key::Escape {
+                    dialog.close();
+                    return glib::Propagation::Stop;
+                }
+                glib::Propagation::Proceed
+            }
+        ));
+        dialog.add_controller(dialog_keys);
+
+        let hint = gtk::Label::new(Some(
+            "One column per line, bottom card first; [4C] is face down and - is an empty column. \
+             Pyramid and TriPeaks take one line per row with - for a cleared slot. Header lines \
+             set the game, rules, foundations (H-0, S-Q), free cells, stock, waste, reserve and \
+             Canfield's foundation base.",
+        ));
+        hint.set_xalign(0.0);
+        hint.set_wrap(true);
+        hint.add_css_class("dim-label");
+
+        let buffer = gtk::TextBuffer::new(None);
+        buffer.create_tag(
+            Some(DEAL_EDITOR_ERROR_TAG),
+            &[("underline", &gtk::pango::Underline::Error)],
+        );
+        buffer.connect_changed(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.validate_deal_editor();
+            }
+        ));
+        let text_view = gtk::TextView::with_buffer(&buffer);
+        text_view.set_monospace(true);
+        text_view.set_wrap_mode(gtk::WrapMode::None);
+        let scroller = gtk::ScrolledWindow::new();
+        scroller.set_hexpand(true);
+        scroller.set_vexpand(true);
+        scroller.set_child(Some(&text_view));
+
+        // Clicking a card types it at the cursor.
+        let face_down = gtk::CheckButton::with_label("Face down");
+        let palette = gtk::Grid::new();
+        palette.set_column_spacing(2);
+        palette.set_row_spacing(2);
+        for (row, suit) in Suit::ALL.iter().enumerate() {
+            for (col, rank) in DEAL_EDITOR_RANKS.iter().enumerate() {
+                let token = format!("{rank}{}", suit.short());
+                let button = gtk::Button::with_label(&token);
+                button.add_css_class("flat");
+                button.connect_clicked(glib::clone!(
+                    #[weak]
+                    buffer,
+                    #[weak]
+                    face_down,
+                    #[weak]
+                    text_view,
+                    move |_| {
+                        let text = if face_down.is_active() {
+                            format!("[{token}] ")
+                        } else {
+                            format!("{token} ")
+                        };
+                        buffer.insert_at_cursor(&text);
+                        text_view.grab_focus();
+                    }
+                ));
+                palette.attach(&button, col as i32, row as i32, 1, 1);
+            }
+        }
+
+        let status_label = gtk::Label::new(None);
+        status_label.set_xalign(0.0);
+        status_label.set_wrap(true);
+
+        let root = gtk::Box::new(gtk::Orientation::Vertical, 8);
+        root.set_margin_top(10);
+        root.set_margin_bottom(10);
+        root.set_margin_start(10);
+        root.set_margin_end(10);
+        root.append(&hint);
+        root.append(&scroller);
+        root.append(&palette);
+        root.append(&face_down);
+        root.append(&status_label);
+
+        let actions_row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        actions_row.set_halign(gtk::Align::End);
+        let current_button = gtk::Button::with_label("Load Current Board");
+        current_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.load_deal_editor_current_board();
+            }
+        ));
+        let check_button = gtk::Button::with_label("Check Winnability");
+        check_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.check_deal_editor_board();
+            }
+        ));
+        let play_button = gtk::Button::with_label("Play");
+        play_button.add_css_class("suggested-action");
+        play_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.play_deal_editor_board();
+            }
+        ));
+        actions_row.append(&current_button);
+        actions_row.append(&check_button);
+        actions_row.append(&play_button);
+        root.append(&actions_row);
+        dialog.set_child(Some(&root));
+
+        *self.imp().deal_editor_buffer.borrow_mut() = Some(buffer);
+        *self.imp().deal_editor_status_label.borrow_mut() = Some(status_label);
+        *self.imp().deal_editor_dialog.borrow_mut() = Some(dialog.clone());
+        self.load_deal_editor_current_board();
+        dialog.present();
+    }
+}
+
+/// Runs the variant's seed check from an edited position.
+fn solve_edited_board(
+    board: TextBoard,
+    profile: AutomationProfile,
+    cancel: &AtomicBool,
+) -> Option<winnability::SeedWinnabilityCheckResult> {
+    match board {
+        TextBoard::Klondike(game) if game.is_thoughtful() => {
+            winnability::is_thoughtful_position_winnable(
+                &game,
+                THOUGHTFUL_WINNABILITY_STATE_BUDGET,
+                cancel,
+            )
+        }
+        TextBoard::Klondike(game) => winnability::is_klondike_position_winnable(
+            &game,
+            profile.dialog_seed_guided_budget,
+            profile.dialog_seed_exhaustive_budget,
+            cancel,
+        ),
+        TextBoard::Spider(game) => winnability::is_spider_position_winnable(
+            game,
+            profile.dialog_seed_guided_budget,
+            profile.dialog_seed_exhaustive_budget,
+            cancel,
+        ),
+        TextBoard::Freecell(game) => winnability::is_freecell_position_winnable(
+            game,
+            profile.dialog_seed_guided_budget,
+            profile.dialog_seed_exhaustive_budget,
+            cancel,
+        ),
+        TextBoard::OpenTableau(game) => winnability::is_open_tableau_position_winnable(
+            &game,
+            profile.dialog_seed_exhaustive_budget,
+            cancel,
+        ),
+        TextBoard::Yukon(_) => None,
+        TextBoard::Pyramid(game) => Some(exhaustive_check(
+            game.solve_cancelable(profile.dialog_seed_exhaustive_budget, cancel)
+                .map(|result| {
+                    (
+                        result.winnable,
+                        result.explored_states,
+                        result.hit_state_limit,
+                        result.line.len(),
+                    )
+                }),
+        )),
+        TextBoard::WasteBuild(game) => Some(exhaustive_check(
+            game.solve_cancelable(profile.dialog_seed_exhaustive_budget, cancel)
+                .map(|result| {
+                    (
+                        result.winnable,
+                        result.explored_states,
+                        result.hit_state_limit,
+                        result.line.len(),
+                    )
+                }),
+        )),
+        TextBoard::FortyThieves(game) => Some(exhaustive_check(
+            game.solve_cancelable(profile.dialog_seed_exhaustive_budget, cancel)
+                .map(|result| {
+                    (
+                        result.winnable,
+                        result.explored_states,
+                        result.hit_state_limit,
+                        result.line.len(),
+                    )
+                }),
+        )),
+        TextBoard::Scorpion(game) => Some(exhaustive_check(
+            game.solve_cancelable(profile.dialog_seed_exhaustive_budget, cancel)
+                .map(|result| {
+                    (
+                        result.winnable,
+                        result.explored_states,
+                        result.hit_state_limit,
+                        result.line.len(),
+                    )
+                }),
+        )),
+        TextBoard::Canfield(game) => Some(exhaustive_check(
+            game.solve_cancelable(profile.dialog_seed_exhaustive_budget, cancel)
+                .map(|result| {
+                    (
+                        result.winnable,
+                        result.explored_states,
+                        result.hit_state_limit,
+                        result.line.len(),
+                    )
+                }),
+        )),
+    }
+}
+
+/// A check result from one of the single-pass solvers, given as (winnable,
+/// states, hit the budget, line length) or `None` when canceled. They report
+/// no line for the hint or robot paths.
+fn exhaustive_check(
+    outcome: Option<(bool, usize, bool, usize)>,
+) -> winnability::SeedWinnabilityCheckResult {
+    let (winnable, iterations, hit_state_limit, line_len) = outcome.unwrap_or((false, 0, true, 0));
+    winnability::SeedWinnabilityCheckResult {
+        winnable,
+        iterations,
+        moves_to_win: winnable.then(|| u32::try_from(line_len).unwrap_or(u32::MAX)),
+        hit_state_limit,
+        solver_line: None,
+        hint_line: None,
+        freecell_line: None,
+        canceled: outcome.is_none(),
+    }
+}
@@ -428,4 +428,122 @@ impl CardthropicWindow {
         let idx = (y / step).floor().max(0.0) as usize;
         Some(idx.min(len - 1))
     }
+
+    pub(super) fn drag_icon_widget_for_tableau_piles(
+        &self,
+        tableau: &[Vec<Card>],
+        deck: Option<&AngloDeck>,
+        col: usize,
+        start: usize,
+        card_width: i32,
+        card_height: i32,
+    ) -> Option<gtk::Widget> {
+        let pile = tableau.get(col)?;
+        if start >= pile.len() {
+            return None;
+        }
+        let face_up_step = self.imp().face_up_step.get();
+        let face_down_step = self.imp().face_down_step.get();
+        let mut layers: Vec<(Card, bool, i32)> = Vec::new();
+        let mut y = 0_i32;
+        for card in &pile[start..] {
+            layers.push((*card, card.face_up, y));
+            y += if card.face_up {
+                face_up_step
+            } else {
+                face_down_step
+            };
+        }
+        self.drag_icon_widget_from_layers(&layers, deck, card_width, card_height)
+    }
+
+    pub(super) fn tableau_card_y_offset_piles(
+        &self,
+        tableau: &[Vec<Card>],
+        col: usize,
+        index: usize,
+    ) -> i32 {
+        let face_up_step = self.imp().face_up_step.get();
+        let face_down_step = self.imp().face_down_step.get();
+        tableau
+            .get(col)
+            .map(|pile| {
+                pile.iter()
+                    .take(index)
+                    .map(|card| {
+                        if card.face_up {
+                            face_up_step
+                        } else {
+                            face_down_step
+                        }
+                    })
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+
+    pub(super) fn pyramid_slot_from_point(&self, x: f64, y: f64) -> Option<usize> {
+        let imp = self.imp();
+        let game = imp.game.borrow();
+        Self::pyramid_slot_at(
+            game.pyramid().pyramid(),
+            x,
+            self.adjusted_tableau_hit_y(y),
+            imp.card_width.get(),
+            imp.card_height.get(),
+            imp.tableau_row.spacing(),
+        )
+    }
+
+    pub(super) fn waste_build_slot_from_point(
+        &self,
+        layout: WasteBuildLayout,
+        x: f64,
+        y: f64,
+    ) -> Option<usize> {
+        let imp = self.imp();
+        let game = imp.game.borrow();
+        Self::waste_build_slot_at(
+            layout,
+            game.waste_build(layout).tableau(),
+            x,
+            self.adjusted_tableau_hit_y(y),
+            imp.card_width.get(),
+            imp.card_height.get(),
+            imp.tableau_row.spacing(),
+        )
+    }
+
+    /// Face-up card under `y` in a stepped cascade, for variants that render
+    /// through `render_tableau_piles`.
+    pub(super) fn tableau_run_start_from_y_piles(
+        &self,
+        tableau: &[Vec<Card>],
+        col: usize,
+        y: f64,
+    ) -> Option<usize> {
+        let y = self.adjusted_tableau_hit_y(y);
+        let pile = tableau.get(col)?;
+        if pile.is_empty() {
+            return None;
+        }
+
+        let face_up_step = f64::from(self.imp().face_up_step.get());
+        let face_down_step = f64::from(self.imp().face_down_step.get());
+        let mut start = 0;
+        let mut y_pos = 0.0_f64;
+        for (idx, card) in pile.iter().enumerate() {
+            if y < y_pos {
+                break;
+            }
+            start = idx;
+            y_pos += if card.face_up {
+                face_up_step
+            } else {
+                face_down_step
+            };
+        }
+
+        pile[start].face_up.then_some(start)
+    }
 }
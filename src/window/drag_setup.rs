@@ -24,7 +24,7 @@ impl CardthropicWindow {
                     freecell_drag_slot.set(None);
                     return None;
                 }
-                if window.active_game_mode() == GameMode::Freecell {
+                if window.active_game_mode().is_freecell_family() {
                     let idx = window.freecell_slot_index_from_waste_x(x);
                     freecell_drag_slot.set(Some(idx));
                     if window
@@ -46,6 +46,30 @@ impl CardthropicWindow {
                     }
                     return None;
                 }
+                freecell_drag_slot.set(None);
+                if window.active_game_mode() == GameMode::Canfield
+                    && window.canfield_reserve_contains_waste_x(x)
+                {
+                    boundary::freecell_card(
+                        &window.imp().game.borrow(),
+                        GameMode::Canfield,
+                        CANFIELD_RESERVE_CELL,
+                    )?;
+                    freecell_drag_slot.set(Some(CANFIELD_RESERVE_CELL));
+                    let imp = window.imp();
+                    let step = imp.card_width.get().max(1);
+                    let max_x = (imp.card_width.get() - 1).max(0);
+                    let max_y = (imp.card_height.get() - 1).max(0);
+                    let hot_x = ((x.round() as i32) - step).clamp(0, max_x);
+                    let hot_y = (y.round() as i32).clamp(0, max_y);
+                    waste_hotspot.set((hot_x, hot_y));
+                    let payload = format!("freecell:{CANFIELD_RESERVE_CELL}");
+                    return Some(gdk::ContentProvider::for_value(&payload.to_value()));
+                }
+                if Self::uses_slot_canvas(window.active_game_mode()) {
+                    // Slot-canvas layouts play by clicking; nothing is dropped.
+                    return None;
+                }
                 if boundary::waste_top(&window.imp().game.borrow(), window.active_game_mode())
                     .is_some()
                 {
@@ -74,11 +98,17 @@ impl CardthropicWindow {
                     return;
                 }
                 let imp = window.imp();
-                let card = if window.active_game_mode() == GameMode::Freecell {
+                let mode = window.active_game_mode();
+                let card = if mode.is_freecell_family() || freecell_drag_slot.get().is_some() {
                     let Some(idx) = freecell_drag_slot.get() else {
                         return;
                     };
-                    let Some(card) = imp.game.borrow().freecell().freecell_card(idx) else {
+                    let Some(card) = boundary::freecell_card(&imp.game.borrow(), mode, idx) else {
+                        return;
+                    };
+                    card
+                } else if mode == GameMode::Canfield {
+                    let Some(card) = boundary::waste_top(&imp.game.borrow(), mode) else {
                         return;
                     };
                     card
@@ -193,7 +223,10 @@ impl CardthropicWindow {
                                     (start, top)
                                 })
                         }
-                        GameMode::Freecell => {
+                        GameMode::Freecell
+                        | GameMode::EightOff
+                        | GameMode::SeahavenTowers
+                        | GameMode::BakersGame => {
                             let game = window.imp().game.borrow().freecell().clone();
                             window
                                 .tableau_run_start_from_y_freecell(&game, index, y)
@@ -203,6 +236,74 @@ impl CardthropicWindow {
                                     (start, top)
                                 })
                         }
+                        GameMode::Yukon => {
+                            let game = window.imp().game.borrow().yukon().clone();
+                            window
+                                .tableau_run_start_from_y_piles(game.tableau(), index, y)
+                                .map(|start| {
+                                    let top = window.tableau_card_y_offset_piles(
+                                        game.tableau(),
+                                        index,
+                                        start,
+                                    );
+                                    (start, top)
+                                })
+                        }
+                        GameMode::FortyThieves => {
+                            let game = window.imp().game.borrow().forty_thieves().clone();
+                            window
+                                .tableau_run_start_from_y_piles(game.tableau(), index, y)
+                                .map(|start| {
+                                    let top = window.tableau_card_y_offset_piles(
+                                        game.tableau(),
+                                        index,
+                                        start,
+                                    );
+                                    (start, top)
+                                })
+                        }
+                        GameMode::Scorpion => {
+                            let game = window.imp().game.borrow().scorpion().clone();
+                            window
+                                .tableau_run_start_from_y_piles(game.tableau(), index, y)
+                                .map(|start| {
+                                    let top = window.tableau_card_y_offset_piles(
+                                        game.tableau(),
+                                        index,
+                                        start,
+                                    );
+                                    (start, top)
+                                })
+                        }
+                        GameMode::Canfield => {
+                            let game = window.imp().game.borrow().canfield().clone();
+                            window
+                                .tableau_run_start_from_y_piles(game.tableau(), index, y)
+                                .map(|start| {
+                                    let top = window.tableau_card_y_offset_piles(
+                                        game.tableau(),
+                                        index,
+                                        start,
+                                    );
+                                    (start, top)
+                                })
+                        }
+                        GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                            let layout = OpenTableauLayout::for_mode(mode)?;
+                            let game = window.imp().game.borrow().open_tableau(layout).clone();
+                            // Only the top card of a column can be picked up.
+                            window
+                                .tableau_run_start_from_y_piles(game.tableau(), index, y)
+                                .filter(|&start| start + 1 == game.tableau()[index].len())
+                                .map(|start| {
+                                    let top = window.tableau_card_y_offset_piles(
+                                        game.tableau(),
+                                        index,
+                                        start,
+                                    );
+                                    (start, top)
+                                })
+                        }
                         _ => boundary::clone_klondike_for_automation(
                             &window.imp().game.borrow(),
                             mode,
@@ -306,7 +407,10 @@ impl CardthropicWindow {
                                 card_height,
                             )
                         }
-                        GameMode::Freecell => {
+                        GameMode::Freecell
+                        | GameMode::EightOff
+                        | GameMode::SeahavenTowers
+                        | GameMode::BakersGame => {
                             let game = imp.game.borrow().freecell().clone();
                             window.drag_icon_widget_for_tableau_run_freecell(
                                 &game,
@@ -317,6 +421,66 @@ impl CardthropicWindow {
                                 card_height,
                             )
                         }
+                        GameMode::Yukon => {
+                            let game = imp.game.borrow().yukon().clone();
+                            window.drag_icon_widget_for_tableau_piles(
+                                game.tableau(),
+                                deck,
+                                index,
+                                start,
+                                card_width,
+                                card_height,
+                            )
+                        }
+                        GameMode::FortyThieves => {
+                            let game = imp.game.borrow().forty_thieves().clone();
+                            window.drag_icon_widget_for_tableau_piles(
+                                game.tableau(),
+                                deck,
+                                index,
+                                start,
+                                card_width,
+                                card_height,
+                            )
+                        }
+                        GameMode::Scorpion => {
+                            let game = imp.game.borrow().scorpion().clone();
+                            window.drag_icon_widget_for_tableau_piles(
+                                game.tableau(),
+                                deck,
+                                index,
+                                start,
+                                card_width,
+                                card_height,
+                            )
+                        }
+                        GameMode::Canfield => {
+                            let game = imp.game.borrow().canfield().clone();
+                            window.drag_icon_widget_for_tableau_piles(
+                                game.tableau(),
+                                deck,
+                                index,
+                                start,
+                                card_width,
+                                card_height,
+                            )
+                        }
+                        GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                            let Some(layout) =
+                                OpenTableauLayout::for_mode(window.active_game_mode())
+                            else {
+                                return;
+                            };
+                            let game = imp.game.borrow().open_tableau(layout).clone();
+                            window.drag_icon_widget_for_tableau_piles(
+                                game.tableau(),
+                                deck,
+                                index,
+                                start,
+                                card_width,
+                                card_height,
+                            )
+                        }
                         _ => {
                             let Some(game) = boundary::clone_klondike_for_automation(
                                 &imp.game.borrow(),
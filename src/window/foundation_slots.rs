@@ -1,5 +1,6 @@
 use super::*;
 use crate::engine::boundary;
+use crate::game::FoundationBase;
 
 impl CardthropicWindow {
     pub(super) fn foundation_slot_suit(&self, slot: usize) -> Option<Suit> {
@@ -47,12 +48,45 @@ impl CardthropicWindow {
                     },
                 )
             }
-            GameMode::Freecell => {
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => {
                 let game = self.imp().game.borrow();
                 let f = game.freecell().foundations();
                 Some([f[0].len(), f[1].len(), f[2].len(), f[3].len()])
             }
-            GameMode::Spider => None,
+            GameMode::Yukon => {
+                let game = self.imp().game.borrow();
+                let f = game.yukon().foundations();
+                Some([f[0].len(), f[1].len(), f[2].len(), f[3].len()])
+            }
+            GameMode::Canfield => {
+                let game = self.imp().game.borrow();
+                let f = game.canfield().foundations();
+                Some([f[0].len(), f[1].len(), f[2].len(), f[3].len()])
+            }
+            GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                let layout = OpenTableauLayout::for_mode(mode)?;
+                let game = self.imp().game.borrow();
+                let f = game.open_tableau(layout).foundations();
+                Some([f[0].len(), f[1].len(), f[2].len(), f[3].len()])
+            }
+            GameMode::Spider
+            | GameMode::Pyramid
+            | GameMode::TriPeaks
+            | GameMode::Golf
+            | GameMode::FortyThieves
+            | GameMode::Scorpion => None,
+        }
+    }
+
+    /// Rank that starts an empty suit foundation: the Ace, except in Canfield
+    /// where the deal picks it.
+    fn foundation_base_rank(&self) -> u8 {
+        match self.active_game_mode() {
+            GameMode::Canfield => self.imp().game.borrow().canfield().base().rank,
+            _ => FoundationBase::ACE.rank,
         }
     }
 
@@ -98,6 +132,15 @@ impl CardthropicWindow {
     }
 
     pub(super) fn foundation_slot_accepts_card(&self, card: Card, slot: usize) -> bool {
+        if self.active_game_mode() == GameMode::FortyThieves {
+            // Forty Thieves slots are the game's own piles, two per suit.
+            return self
+                .imp()
+                .game
+                .borrow()
+                .forty_thieves()
+                .foundation_accepts(slot, card);
+        }
         if slot >= 4 {
             return false;
         }
@@ -108,8 +151,9 @@ impl CardthropicWindow {
             return existing == card.suit;
         }
 
-        // Empty slot can only start a suit with an Ace that is not already assigned.
-        card.rank == 1 && !slots.contains(&Some(card.suit))
+        // Empty slot can only start a suit with its base card (usually the
+        // Ace) when that suit is not already assigned.
+        card.rank == self.foundation_base_rank() && !slots.contains(&Some(card.suit))
     }
 
     pub(super) fn resolve_foundation_slot_for_card(
@@ -117,6 +161,14 @@ impl CardthropicWindow {
         card: Card,
         preferred_slot: Option<usize>,
     ) -> Option<usize> {
+        if self.active_game_mode() == GameMode::FortyThieves {
+            let game = self.imp().game.borrow();
+            let game = game.forty_thieves();
+            if preferred_slot.is_some_and(|slot| !game.foundation_accepts(slot, card)) {
+                return None;
+            }
+            return game.foundation_target(card);
+        }
         self.sync_foundation_slots_with_state();
         let slots = self.foundation_slot_suits_snapshot();
 
@@ -127,7 +179,7 @@ impl CardthropicWindow {
             return Some(existing_slot);
         }
 
-        if card.rank != 1 {
+        if card.rank != self.foundation_base_rank() {
             return None;
         }
 
@@ -142,7 +194,7 @@ impl CardthropicWindow {
     }
 
     pub(super) fn establish_foundation_slot_for_card(&self, card: Card, slot: usize) {
-        if slot >= 4 {
+        if slot >= 4 || self.active_game_mode() == GameMode::FortyThieves {
             return;
         }
         self.sync_foundation_slots_with_state();
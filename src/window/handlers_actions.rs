@@ -19,6 +19,19 @@ impl CardthropicWindow {
         self.render();
     }
 
+    pub(super) fn copy_board_text_to_clipboard(&self) {
+        let status_message = match self.build_board_text() {
+            Some(payload) => {
+                self.clipboard().set_text(&payload);
+                self.append_game_state_payload_history("copy_board_text_payload", &payload);
+                "Copied board to clipboard as text."
+            }
+            None => "Board text covers the solitaire variants, not chess.",
+        };
+        *self.imp().status_override.borrow_mut() = Some(status_message.to_string());
+        self.render();
+    }
+
     pub(super) fn copy_all_cardthropic_gsettings_variables_to_clipboard(&self) {
         let settings = self
             .imp()
@@ -430,6 +443,11 @@ impl CardthropicWindow {
 
         self.load_seed_history();
         self.refresh_seed_history_dropdown();
+        self.load_variant_stats();
+        self.refresh_stats_dialog();
+        self.load_daily_challenges();
+        self.clear_daily_game();
+        self.refresh_daily_dialog();
 
         let board_color = settings.string(SETTINGS_KEY_BOARD_COLOR).to_string();
         self.set_board_color(&board_color, false);
@@ -486,6 +504,14 @@ impl CardthropicWindow {
         let smart_move_mode =
             SmartMoveMode::from_setting(settings.string(SETTINGS_KEY_SMART_MOVE_MODE).as_ref());
         self.set_smart_move_mode(smart_move_mode, false, false);
+        self.set_scoring_system(
+            if Self::settings_has_scoring_system_key(&settings) {
+                ScoringSystem::from_setting(settings.string(SETTINGS_KEY_SCORING_SYSTEM).as_ref())
+            } else {
+                ScoringSystem::Standard
+            },
+            false,
+        );
         self.set_hud_enabled(settings.boolean(SETTINGS_KEY_ENABLE_HUD), false);
         self.set_robot_forever_enabled(settings.boolean(SETTINGS_KEY_FOREVER_MODE), false, false);
         self.set_robot_auto_new_game_on_loss_enabled(
@@ -897,7 +923,7 @@ impl CardthropicWindow {
                     return;
                 }
                 let current_button = gesture.current_button();
-                if window.active_game_mode() == GameMode::Freecell {
+                if window.active_game_mode().is_freecell_family() {
                     if window.smart_move_mode() == SmartMoveMode::RightClick
                         && current_button == gdk::BUTTON_SECONDARY
                     {
@@ -906,6 +932,16 @@ impl CardthropicWindow {
                         return;
                     }
                     window.handle_freecell_click_x(n_press, Some(x));
+                } else if window.active_game_mode() == GameMode::Canfield
+                    && window.canfield_reserve_contains_waste_x(x)
+                {
+                    if window.smart_move_mode() == SmartMoveMode::RightClick
+                        && current_button == gdk::BUTTON_SECONDARY
+                    {
+                        let _ = window.try_smart_move_from_freecell(CANFIELD_RESERVE_CELL);
+                        return;
+                    }
+                    window.activate_canfield_reserve(n_press);
                 } else {
                     if window.smart_move_mode() == SmartMoveMode::RightClick
                         && current_button == gdk::BUTTON_SECONDARY
@@ -925,15 +961,69 @@ impl CardthropicWindow {
             click.connect_released(glib::clone!(
                 #[weak(rename_to = window)]
                 self,
-                move |gesture, n_press, _, y| {
+                move |gesture, n_press, x, y| {
                     let current_button = gesture.current_button();
                     if window.imp().chess_mode_active.get() {
                         window.handle_chess_board_stack_click(index, y);
                         return;
                     }
-                    if window.active_game_mode() == GameMode::Spider {
-                        let game = window.imp().game.borrow().spider().clone();
-                        let start = window.tableau_run_start_from_y_spider(&game, index, y);
+                    if window.active_game_mode() == GameMode::Pyramid {
+                        if n_press == 1 {
+                            if let Some(slot) = window.pyramid_slot_from_point(x, y) {
+                                window.activate_pyramid_card(PyramidCardRef::Pyramid(slot));
+                            }
+                        }
+                        return;
+                    }
+                    if let Some(layout) = WasteBuildLayout::for_mode(window.active_game_mode()) {
+                        if n_press == 1 {
+                            if let Some(slot) = window.waste_build_slot_from_point(layout, x, y) {
+                                window.activate_waste_build_slot(slot);
+                            }
+                        }
+                        return;
+                    }
+                    if matches!(
+                        window.active_game_mode(),
+                        GameMode::Spider
+                            | GameMode::Yukon
+                            | GameMode::FortyThieves
+                            | GameMode::Scorpion
+                            | GameMode::Canfield
+                            | GameMode::BakersDozen
+                            | GameMode::BeleagueredCastle
+                    ) {
+                        let start = match window.active_game_mode() {
+                            GameMode::Spider => {
+                                let game = window.imp().game.borrow().spider().clone();
+                                window.tableau_run_start_from_y_spider(&game, index, y)
+                            }
+                            GameMode::FortyThieves => {
+                                let game = window.imp().game.borrow().forty_thieves().clone();
+                                window.tableau_run_start_from_y_piles(game.tableau(), index, y)
+                            }
+                            GameMode::Scorpion => {
+                                let game = window.imp().game.borrow().scorpion().clone();
+                                window.tableau_run_start_from_y_piles(game.tableau(), index, y)
+                            }
+                            GameMode::Canfield => {
+                                let game = window.imp().game.borrow().canfield().clone();
+                                window.tableau_run_start_from_y_piles(game.tableau(), index, y)
+                            }
+                            GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                                let layout = OpenTableauLayout::for_mode(window.active_game_mode())
+                                    .unwrap_or(OpenTableauLayout::BakersDozen);
+                                let game = window.imp().game.borrow().open_tableau(layout).clone();
+                                // Any card in a column stands in for its top card.
+                                window
+                                    .tableau_run_start_from_y_piles(game.tableau(), index, y)
+                                    .map(|_| game.tableau()[index].len() - 1)
+                            }
+                            _ => {
+                                let game = window.imp().game.borrow().yukon().clone();
+                                window.tableau_run_start_from_y_piles(game.tableau(), index, y)
+                            }
+                        };
                         match window.smart_move_mode() {
                             SmartMoveMode::DoubleClick if n_press == 2 => {
                                 if let Some(start) = start {
@@ -963,7 +1053,7 @@ impl CardthropicWindow {
                         }
                         return;
                     }
-                    if window.active_game_mode() == GameMode::Freecell {
+                    if window.active_game_mode().is_freecell_family() {
                         let game = window.imp().game.borrow().freecell().clone();
                         let start = window.tableau_run_start_from_y_freecell(&game, index, y);
                         match window.smart_move_mode() {
@@ -275,9 +275,19 @@ impl CardthropicWindow {
         let cap = match self.active_game_mode() {
             // Shared FreeCell wand policy needs a long memory window to avoid
             // re-entering medium/long oscillation cycles.
-            GameMode::Freecell => 4096,
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => 4096,
             GameMode::Spider => 128,
             GameMode::Klondike => 128,
+            GameMode::Yukon => 128,
+            GameMode::Pyramid => 128,
+            GameMode::TriPeaks | GameMode::Golf => 128,
+            GameMode::FortyThieves => 128,
+            GameMode::Scorpion => 128,
+            GameMode::Canfield => 128,
+            GameMode::BakersDozen | GameMode::BeleagueredCastle => 128,
         };
         recent.push_back(hash);
         while recent.len() > cap {
@@ -350,11 +360,83 @@ impl CardthropicWindow {
         let game = self.imp().game.borrow();
         match self.active_game_mode() {
             GameMode::Spider => winnability::spider_solver_state_hash(game.spider()),
-            GameMode::Freecell => Self::hash_freecell_game_state(game.freecell()),
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => Self::hash_freecell_game_state(game.freecell()),
             GameMode::Klondike => hash_game_state(&game),
+            GameMode::Yukon => Self::hash_yukon_game_state(game.yukon()),
+            GameMode::Pyramid => Self::hash_pyramid_game_state(game.pyramid()),
+            GameMode::TriPeaks => Self::hash_waste_build_game_state(game.tripeaks()),
+            GameMode::Golf => Self::hash_waste_build_game_state(game.golf()),
+            GameMode::FortyThieves => Self::hash_forty_thieves_game_state(game.forty_thieves()),
+            GameMode::Scorpion => Self::hash_scorpion_game_state(game.scorpion()),
+            GameMode::Canfield => Self::hash_canfield_game_state(game.canfield()),
+            GameMode::BakersDozen => Self::hash_open_tableau_game_state(
+                game.open_tableau(OpenTableauLayout::BakersDozen),
+            ),
+            GameMode::BeleagueredCastle => Self::hash_open_tableau_game_state(
+                game.open_tableau(OpenTableauLayout::BeleagueredCastle),
+            ),
         }
     }
 
+    pub(super) fn hash_forty_thieves_game_state(game: &crate::game::FortyThievesGame) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        game.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub(super) fn hash_scorpion_game_state(game: &crate::game::ScorpionGame) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        game.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub(super) fn hash_canfield_game_state(game: &crate::game::CanfieldGame) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        game.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub(super) fn hash_open_tableau_game_state(game: &crate::game::OpenTableauGame) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        game.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub(super) fn hash_waste_build_game_state(game: &crate::game::WasteBuildGame) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        game.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub(super) fn hash_pyramid_game_state(game: &crate::game::PyramidGame) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        game.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub(super) fn hash_yukon_game_state(game: &crate::game::YukonGame) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        game.hash(&mut hasher);
+        hasher.finish()
+    }
+
     pub(super) fn hash_freecell_game_state(game: &crate::game::FreecellGame) -> u64 {
         crate::engine::freecell_planner::zobrist_hash(game)
     }
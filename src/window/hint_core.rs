@@ -1,7 +1,13 @@
 use super::*;
 use crate::engine::boundary;
+use crate::engine::canfield_hinting;
+use crate::engine::forty_thieves_hinting;
 use crate::engine::freecell_planner::FreecellPlannerAction;
-use crate::game::{Card, FreecellGame};
+use crate::engine::open_tableau_hinting;
+use crate::engine::pyramid_hinting;
+use crate::engine::scorpion_hinting;
+use crate::engine::waste_build_hinting;
+use crate::game::{Card, FreecellGame, OpenTableauLayout};
 use crate::winnability::{
     freecell_wand_best_action, freecell_wand_best_action_avoiding_seen, freecell_wand_state_hash,
 };
@@ -85,7 +91,7 @@ impl CardthropicWindow {
         if !self.guard_mode_engine("Hint") {
             return;
         }
-        if self.active_game_mode() == GameMode::Freecell {
+        if self.active_game_mode().is_freecell_family() {
             let (message, source, target, _action, _score) =
                 self.compute_freecell_hint_action(false);
             *self.imp().status_override.borrow_mut() = Some(message);
@@ -95,6 +101,60 @@ impl CardthropicWindow {
             }
             return;
         }
+        if self.active_game_mode() == GameMode::Pyramid {
+            let suggestion = self.compute_pyramid_hint_suggestion();
+            *self.imp().status_override.borrow_mut() = Some(suggestion.message);
+            self.render();
+            if let (Some(source), Some(target)) = (suggestion.source, suggestion.target) {
+                self.play_hint_animation(source, target);
+            }
+            return;
+        }
+        if let Some(layout) = WasteBuildLayout::for_mode(self.active_game_mode()) {
+            let suggestion = self.compute_waste_build_hint_suggestion(layout);
+            *self.imp().status_override.borrow_mut() = Some(suggestion.message);
+            self.render();
+            if let (Some(source), Some(target)) = (suggestion.source, suggestion.target) {
+                self.play_hint_animation(source, target);
+            }
+            return;
+        }
+        if self.active_game_mode() == GameMode::FortyThieves {
+            let suggestion = self.compute_forty_thieves_hint_suggestion();
+            *self.imp().status_override.borrow_mut() = Some(suggestion.message);
+            self.render();
+            if let (Some(source), Some(target)) = (suggestion.source, suggestion.target) {
+                self.play_hint_animation(source, target);
+            }
+            return;
+        }
+        if self.active_game_mode() == GameMode::Scorpion {
+            let suggestion = self.compute_scorpion_hint_suggestion();
+            *self.imp().status_override.borrow_mut() = Some(suggestion.message);
+            self.render();
+            if let (Some(source), Some(target)) = (suggestion.source, suggestion.target) {
+                self.play_hint_animation(source, target);
+            }
+            return;
+        }
+        if self.active_game_mode() == GameMode::Canfield {
+            let suggestion = self.compute_canfield_hint_suggestion();
+            *self.imp().status_override.borrow_mut() = Some(suggestion.message);
+            self.render();
+            if let (Some(source), Some(target)) = (suggestion.source, suggestion.target) {
+                self.play_hint_animation(source, target);
+            }
+            return;
+        }
+        if let Some(layout) = OpenTableauLayout::for_mode(self.active_game_mode()) {
+            let suggestion = self.compute_open_tableau_hint_suggestion(layout);
+            *self.imp().status_override.borrow_mut() = Some(suggestion.message);
+            self.render();
+            if let (Some(source), Some(target)) = (suggestion.source, suggestion.target) {
+                self.play_hint_animation(source, target);
+            }
+            return;
+        }
         let suggestion = self.compute_hint_suggestion();
         *self.imp().status_override.borrow_mut() = Some(suggestion.message);
         self.render();
@@ -117,9 +177,30 @@ impl CardthropicWindow {
         if !self.guard_mode_engine("Play hint move") {
             return false;
         }
-        if self.active_game_mode() == GameMode::Freecell {
+        if !self.guard_mode_feature("Play hint move", self.mode_capabilities().autoplay) {
+            return false;
+        }
+        if self.active_game_mode().is_freecell_family() {
             return self.play_freecell_hint_for_player();
         }
+        if self.active_game_mode() == GameMode::Pyramid {
+            return self.play_pyramid_hint_for_player();
+        }
+        if let Some(layout) = WasteBuildLayout::for_mode(self.active_game_mode()) {
+            return self.play_waste_build_hint_for_player(layout);
+        }
+        if self.active_game_mode() == GameMode::FortyThieves {
+            return self.play_forty_thieves_hint_for_player();
+        }
+        if self.active_game_mode() == GameMode::Scorpion {
+            return self.play_scorpion_hint_for_player();
+        }
+        if self.active_game_mode() == GameMode::Canfield {
+            return self.play_canfield_hint_for_player();
+        }
+        if let Some(layout) = OpenTableauLayout::for_mode(self.active_game_mode()) {
+            return self.play_open_tableau_hint_for_player(layout);
+        }
         self.clear_hint_effects();
         let suggestion = self.compute_auto_play_suggestion();
         let wand_message = Self::wand_status_message(&suggestion.message);
@@ -156,6 +237,210 @@ impl CardthropicWindow {
         }
     }
 
+    fn compute_pyramid_hint_suggestion(&self) -> pyramid_hinting::PyramidHintSuggestion {
+        let game = self.imp().game.borrow().pyramid().clone();
+        let budget = self
+            .automation_profile_with_strategy()
+            .hint_guided_analysis_budget;
+        pyramid_hinting::suggest_move(&game, budget)
+    }
+
+    fn play_pyramid_hint_for_player(&self) -> bool {
+        self.clear_hint_effects();
+        let suggestion = self.compute_pyramid_hint_suggestion();
+        let wand_message = Self::wand_status_message(&suggestion.message);
+        let Some(pyramid_move) = suggestion.pyramid_move else {
+            *self.imp().status_override.borrow_mut() = Some(format!("Wand Wave: {wand_message}"));
+            self.render();
+            return false;
+        };
+
+        self.imp().auto_playing_move.set(true);
+        let changed = self.apply_pyramid_move(pyramid_move);
+        self.imp().auto_playing_move.set(false);
+        *self.imp().status_override.borrow_mut() = Some(if changed {
+            format!("Wand Wave: {wand_message}")
+        } else {
+            "Wand Wave: move was not legal anymore.".to_string()
+        });
+        self.render();
+        changed
+    }
+
+    fn compute_waste_build_hint_suggestion(
+        &self,
+        layout: WasteBuildLayout,
+    ) -> waste_build_hinting::WasteBuildHintSuggestion {
+        let game = self.imp().game.borrow().waste_build(layout).clone();
+        let budget = self
+            .automation_profile_with_strategy()
+            .hint_guided_analysis_budget;
+        waste_build_hinting::suggest_move(&game, budget)
+    }
+
+    fn play_waste_build_hint_for_player(&self, layout: WasteBuildLayout) -> bool {
+        self.clear_hint_effects();
+        let suggestion = self.compute_waste_build_hint_suggestion(layout);
+        let wand_message = Self::wand_status_message(&suggestion.message);
+        let Some(waste_build_move) = suggestion.waste_build_move else {
+            *self.imp().status_override.borrow_mut() = Some(format!("Wand Wave: {wand_message}"));
+            self.render();
+            return false;
+        };
+
+        self.imp().auto_playing_move.set(true);
+        let changed = self.apply_waste_build_move(waste_build_move);
+        self.imp().auto_playing_move.set(false);
+        *self.imp().status_override.borrow_mut() = Some(if changed {
+            format!("Wand Wave: {wand_message}")
+        } else {
+            "Wand Wave: move was not legal anymore.".to_string()
+        });
+        self.render();
+        changed
+    }
+
+    fn compute_forty_thieves_hint_suggestion(
+        &self,
+    ) -> forty_thieves_hinting::FortyThievesHintSuggestion {
+        let game = self.imp().game.borrow().forty_thieves().clone();
+        let budget = self
+            .automation_profile_with_strategy()
+            .hint_guided_analysis_budget;
+        forty_thieves_hinting::suggest_move(&game, budget)
+    }
+
+    fn play_forty_thieves_hint_for_player(&self) -> bool {
+        self.clear_hint_effects();
+        let suggestion = self.compute_forty_thieves_hint_suggestion();
+        let wand_message = Self::wand_status_message(&suggestion.message);
+        let Some(hint_move) = suggestion.hint_move else {
+            *self.imp().status_override.borrow_mut() = Some(format!("Wand Wave: {wand_message}"));
+            self.render();
+            return false;
+        };
+
+        self.imp().auto_playing_move.set(true);
+        let changed = self.apply_hint_move(hint_move);
+        self.imp().auto_playing_move.set(false);
+        if changed {
+            *self.imp().selected_run.borrow_mut() = None;
+            self.imp().waste_selected.set(false);
+        }
+        *self.imp().status_override.borrow_mut() = Some(if changed {
+            format!("Wand Wave: {wand_message}")
+        } else {
+            "Wand Wave: move was not legal anymore.".to_string()
+        });
+        self.render();
+        changed
+    }
+
+    fn compute_open_tableau_hint_suggestion(
+        &self,
+        layout: OpenTableauLayout,
+    ) -> open_tableau_hinting::OpenTableauHintSuggestion {
+        let game = self.imp().game.borrow().open_tableau(layout).clone();
+        let budget = self
+            .automation_profile_with_strategy()
+            .hint_guided_analysis_budget;
+        open_tableau_hinting::suggest_move(&game, budget)
+    }
+
+    fn play_open_tableau_hint_for_player(&self, layout: OpenTableauLayout) -> bool {
+        self.clear_hint_effects();
+        let suggestion = self.compute_open_tableau_hint_suggestion(layout);
+        let wand_message = Self::wand_status_message(&suggestion.message);
+        let Some(hint_move) = suggestion.hint_move else {
+            *self.imp().status_override.borrow_mut() = Some(format!("Wand Wave: {wand_message}"));
+            self.render();
+            return false;
+        };
+
+        self.imp().auto_playing_move.set(true);
+        let changed = self.apply_hint_move(hint_move);
+        self.imp().auto_playing_move.set(false);
+        if changed {
+            *self.imp().selected_run.borrow_mut() = None;
+            self.imp().waste_selected.set(false);
+        }
+        *self.imp().status_override.borrow_mut() = Some(if changed {
+            format!("Wand Wave: {wand_message}")
+        } else {
+            "Wand Wave: move was not legal anymore.".to_string()
+        });
+        self.render();
+        changed
+    }
+
+    fn compute_scorpion_hint_suggestion(&self) -> scorpion_hinting::ScorpionHintSuggestion {
+        let game = self.imp().game.borrow().scorpion().clone();
+        let budget = self
+            .automation_profile_with_strategy()
+            .hint_guided_analysis_budget;
+        scorpion_hinting::suggest_move(&game, budget)
+    }
+
+    fn play_scorpion_hint_for_player(&self) -> bool {
+        self.clear_hint_effects();
+        let suggestion = self.compute_scorpion_hint_suggestion();
+        let wand_message = Self::wand_status_message(&suggestion.message);
+        let Some(hint_move) = suggestion.hint_move else {
+            *self.imp().status_override.borrow_mut() = Some(format!("Wand Wave: {wand_message}"));
+            self.render();
+            return false;
+        };
+
+        self.imp().auto_playing_move.set(true);
+        let changed = self.apply_hint_move(hint_move);
+        self.imp().auto_playing_move.set(false);
+        if changed {
+            *self.imp().selected_run.borrow_mut() = None;
+        }
+        *self.imp().status_override.borrow_mut() = Some(if changed {
+            format!("Wand Wave: {wand_message}")
+        } else {
+            "Wand Wave: move was not legal anymore.".to_string()
+        });
+        self.render();
+        changed
+    }
+
+    fn compute_canfield_hint_suggestion(&self) -> canfield_hinting::CanfieldHintSuggestion {
+        let game = self.imp().game.borrow().canfield().clone();
+        let budget = self
+            .automation_profile_with_strategy()
+            .hint_guided_analysis_budget;
+        canfield_hinting::suggest_move(&game, budget)
+    }
+
+    fn play_canfield_hint_for_player(&self) -> bool {
+        self.clear_hint_effects();
+        let suggestion = self.compute_canfield_hint_suggestion();
+        let wand_message = Self::wand_status_message(&suggestion.message);
+        let Some(canfield_move) = suggestion.canfield_move else {
+            *self.imp().status_override.borrow_mut() = Some(format!("Wand Wave: {wand_message}"));
+            self.render();
+            return false;
+        };
+
+        self.imp().auto_playing_move.set(true);
+        let changed = self.apply_canfield_move(canfield_move);
+        self.imp().auto_playing_move.set(false);
+        if changed {
+            *self.imp().selected_run.borrow_mut() = None;
+            self.imp().waste_selected.set(false);
+            self.imp().selected_freecell.set(None);
+        }
+        *self.imp().status_override.borrow_mut() = Some(if changed {
+            format!("Wand Wave: {wand_message}")
+        } else {
+            "Wand Wave: move was not legal anymore.".to_string()
+        });
+        self.render();
+        changed
+    }
+
     fn play_freecell_hint_for_player(&self) -> bool {
         self.clear_hint_effects();
         self.note_current_state_for_hint_cycle();
@@ -256,13 +541,13 @@ impl CardthropicWindow {
             if game.can_move_freecell_to_foundation(cell) {
                 count = count.saturating_add(1);
             }
-            for dst in 0..8 {
+            for dst in 0..game.tableau().len() {
                 if game.can_move_freecell_to_tableau(cell, dst) {
                     count = count.saturating_add(1);
                 }
             }
         }
-        for src in 0..8 {
+        for src in 0..game.tableau().len() {
             if game.can_move_tableau_top_to_foundation(src) {
                 count = count.saturating_add(1);
             }
@@ -273,7 +558,7 @@ impl CardthropicWindow {
             }
             let len = game.tableau().get(src).map(Vec::len).unwrap_or(0);
             for start in 0..len {
-                for dst in 0..8 {
+                for dst in 0..game.tableau().len() {
                     if game.can_move_tableau_run_to_tableau(src, start, dst) {
                         count = count.saturating_add(1);
                     }
@@ -290,7 +575,7 @@ impl CardthropicWindow {
             .filter(|slot| slot.is_none())
             .count();
         let et = game.tableau().iter().filter(|col| col.is_empty()).count();
-        ((ef + 1) * (1usize << et)) as u32
+        game.rules().supermove_capacity(ef, et) as u32
     }
 
     fn wand_has_strong_progress_for_action(
@@ -1430,7 +1715,7 @@ impl CardthropicWindow {
 
         for cell in 0..game.freecell_count() {
             let legal = if let Some(state) = state_ref {
-                boundary::can_move_freecell_to_foundation(state, GameMode::Freecell, cell)
+                boundary::can_move_freecell_to_foundation(state, game.layout().mode(), cell)
             } else {
                 game.can_move_freecell_to_foundation(cell)
             };
@@ -1463,7 +1748,7 @@ impl CardthropicWindow {
             });
         }
 
-        for src in 0..8 {
+        for src in 0..game.tableau().len() {
             if !game.can_move_tableau_top_to_foundation(src) {
                 continue;
             }
@@ -1505,9 +1790,9 @@ impl CardthropicWindow {
         }
 
         for cell in 0..game.freecell_count() {
-            for dst in 0..8 {
+            for dst in 0..game.tableau().len() {
                 let legal = if let Some(state) = state_ref {
-                    boundary::can_move_freecell_to_tableau(state, GameMode::Freecell, cell, dst)
+                    boundary::can_move_freecell_to_tableau(state, game.layout().mode(), cell, dst)
                 } else {
                     game.can_move_freecell_to_tableau(cell, dst)
                 };
@@ -1541,10 +1826,10 @@ impl CardthropicWindow {
             }
         }
 
-        for src in 0..8 {
+        for src in 0..game.tableau().len() {
             let len = game.tableau().get(src).map(Vec::len).unwrap_or(0);
             for start in 0..len {
-                for dst in 0..8 {
+                for dst in 0..game.tableau().len() {
                     if !game.can_move_tableau_run_to_tableau(src, start, dst) {
                         continue;
                     }
@@ -1586,7 +1871,7 @@ impl CardthropicWindow {
             }
         }
 
-        for src in 0..8 {
+        for src in 0..game.tableau().len() {
             let Some(top) = game
                 .tableau()
                 .get(src)
@@ -1763,7 +2048,7 @@ impl CardthropicWindow {
         if next.can_move_tableau_top_to_foundation(src) {
             bias += 360;
         }
-        let can_new_top_move_to_tableau = (0..8)
+        let can_new_top_move_to_tableau = (0..next.tableau().len())
             .filter(|&dst| dst != src)
             .any(|dst| next.can_move_tableau_run_to_tableau(src, next_len.saturating_sub(1), dst));
         if can_new_top_move_to_tableau {
@@ -1805,23 +2090,14 @@ impl CardthropicWindow {
 
     fn freecell_has_foundation_push(game: &FreecellGame) -> bool {
         (0..game.freecell_count()).any(|cell| game.can_move_freecell_to_foundation(cell))
-            || (0..8).any(|src| game.can_move_tableau_top_to_foundation(src))
+            || (0..game.tableau().len()).any(|src| game.can_move_tableau_top_to_foundation(src))
     }
 
     fn freecell_safe_foundation_bias(game: &FreecellGame, card: Option<Card>) -> i64 {
         let Some(card) = card else {
             return 0;
         };
-        if card.rank <= 1 {
-            return 90_000;
-        }
-        let needed = usize::from(card.rank.saturating_sub(1));
-        let opposite_ok = if card.color_red() {
-            game.foundations()[0].len() >= needed && game.foundations()[3].len() >= needed
-        } else {
-            game.foundations()[1].len() >= needed && game.foundations()[2].len() >= needed
-        };
-        if opposite_ok {
+        if game.is_safe_foundation_play(card) {
             90_000
         } else {
             0
@@ -1852,6 +2128,7 @@ impl CardthropicWindow {
     }
 
     fn freecell_tableau_order_score(game: &FreecellGame) -> i64 {
+        let rules = game.rules();
         let mut total = 0_i64;
         for col in game.tableau() {
             if col.is_empty() {
@@ -1859,11 +2136,7 @@ impl CardthropicWindow {
             }
             let mut run_len = 1_i64;
             for idx in (1..col.len()).rev() {
-                let below = col[idx];
-                let above = col[idx - 1];
-                let ok_rank = above.rank == below.rank.saturating_add(1);
-                let ok_color = above.color_red() != below.color_red();
-                if ok_rank && ok_color {
+                if rules.builds_on(col[idx], col[idx - 1]) {
                     run_len += 1;
                 } else {
                     break;
@@ -1882,7 +2155,7 @@ impl CardthropicWindow {
             .filter(|slot| slot.is_none())
             .count();
         let et = game.tableau().iter().filter(|col| col.is_empty()).count();
-        (ef + 1) * (1usize << et)
+        game.rules().supermove_capacity(ef, et)
     }
 
     fn freecell_tableau_main_area_cell_bias(
@@ -1918,13 +2191,13 @@ impl CardthropicWindow {
             if game.can_move_freecell_to_foundation(cell) {
                 count += 1;
             }
-            for dst in 0..8 {
+            for dst in 0..game.tableau().len() {
                 if game.can_move_freecell_to_tableau(cell, dst) {
                     count += 1;
                 }
             }
         }
-        for src in 0..8 {
+        for src in 0..game.tableau().len() {
             if game.can_move_tableau_top_to_foundation(src) {
                 count += 1;
             }
@@ -1935,7 +2208,7 @@ impl CardthropicWindow {
             }
             let len = game.tableau().get(src).map(Vec::len).unwrap_or(0);
             for start in 0..len {
-                for dst in 0..8 {
+                for dst in 0..game.tableau().len() {
                     if game.can_move_tableau_run_to_tableau(src, start, dst) {
                         count += 1;
                     }
@@ -1949,7 +2222,7 @@ impl CardthropicWindow {
         let from_freecells = (0..game.freecell_count())
             .filter(|&cell| game.can_move_freecell_to_foundation(cell))
             .count();
-        let from_tableau = (0..8)
+        let from_tableau = (0..game.tableau().len())
             .filter(|&src| game.can_move_tableau_top_to_foundation(src))
             .count();
         from_freecells + from_tableau
@@ -1987,7 +2260,7 @@ impl CardthropicWindow {
 
     fn freecell_exposed_top_delta(current: &FreecellGame, next: &FreecellGame) -> i64 {
         let mut delta = 0_i64;
-        for col in 0..8 {
+        for col in 0..current.tableau().len() {
             let c_col = &current.tableau()[col];
             let n_col = &next.tableau()[col];
             let c_len = c_col.len();
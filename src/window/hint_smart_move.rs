@@ -7,7 +7,7 @@ impl CardthropicWindow {
         if !self.guard_mode_engine("Smart Move") {
             return false;
         }
-        if self.active_game_mode() == GameMode::Freecell {
+        if self.active_game_mode().is_freecell_family() {
             return self.try_smart_move_from_tableau_freecell(col, start);
         }
         if !self.is_face_up_tableau_run(col, start) {
@@ -178,7 +178,10 @@ impl CardthropicWindow {
         if !self.guard_mode_engine("Smart Move") {
             return false;
         }
-        if self.active_game_mode() != GameMode::Freecell {
+        if self.active_game_mode() == GameMode::Canfield {
+            return self.try_smart_move_from_canfield_reserve(cell);
+        }
+        if !self.active_game_mode().is_freecell_family() {
             return false;
         }
         let allowed = [HintNode::Freecell(cell)];
@@ -226,6 +229,44 @@ impl CardthropicWindow {
         }
     }
 
+    /// The reserve top goes to its foundation when it can, otherwise to the
+    /// first tableau column that takes it.
+    fn try_smart_move_from_canfield_reserve(&self, cell: usize) -> bool {
+        let mode = self.active_game_mode();
+        let (to_foundation, dst) = {
+            let game = self.imp().game.borrow();
+            (
+                boundary::can_move_freecell_to_foundation(&game, mode, cell),
+                (0..CANFIELD_COLUMNS)
+                    .find(|dst| boundary::can_move_freecell_to_tableau(&game, mode, cell, *dst)),
+            )
+        };
+        let (changed, message) = if to_foundation {
+            (
+                self.move_freecell_to_foundation(cell),
+                "moved reserve to foundation.".to_string(),
+            )
+        } else if let Some(dst) = dst {
+            (
+                self.move_freecell_to_tableau(cell, dst),
+                format!("moved reserve to T{}.", dst + 1),
+            )
+        } else {
+            (false, "no legal move from the reserve.".to_string())
+        };
+        let message = if changed || (!to_foundation && dst.is_none()) {
+            message
+        } else {
+            "move was not legal anymore.".to_string()
+        };
+        if changed {
+            self.imp().selected_freecell.set(None);
+        }
+        *self.imp().status_override.borrow_mut() = Some(format!("Smart Move: {message}"));
+        self.render();
+        changed
+    }
+
     fn try_smart_move_from_tableau_freecell(&self, col: usize, start: usize) -> bool {
         if !self.is_face_up_tableau_run(col, start) {
             self.flash_smart_move_fail_tableau_run(col, start);
@@ -234,7 +275,7 @@ impl CardthropicWindow {
             self.render();
             return false;
         }
-        let is_top = boundary::tableau_len(&self.imp().game.borrow(), GameMode::Freecell, col)
+        let is_top = boundary::tableau_len(&self.imp().game.borrow(), self.active_game_mode(), col)
             .map(|len| start + 1 == len)
             .unwrap_or(false);
         let mut allowed = vec![HintNode::Tableau {
@@ -244,8 +285,12 @@ impl CardthropicWindow {
         if is_top {
             allowed.push(HintNode::Tableau {
                 col,
-                index: boundary::tableau_len(&self.imp().game.borrow(), GameMode::Freecell, col)
-                    .and_then(|len| len.checked_sub(1)),
+                index: boundary::tableau_len(
+                    &self.imp().game.borrow(),
+                    self.active_game_mode(),
+                    col,
+                )
+                .and_then(|len| len.checked_sub(1)),
             });
         }
         let Some((message, _source, _target, action, score)) =
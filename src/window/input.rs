@@ -1,7 +1,10 @@
 use super::*;
 use crate::engine::boundary;
 use crate::engine::keyboard_nav;
-use crate::game::SpiderGame;
+use crate::game::{
+    PyramidCardRef, SpiderGame, WasteBuildLayout, FORTY_THIEVES_COLUMNS, FORTY_THIEVES_FOUNDATIONS,
+    PYRAMID_SLOTS, SCORPION_COLUMNS,
+};
 
 impl CardthropicWindow {
     pub(super) fn handle_variant_shortcut_key(
@@ -163,9 +166,30 @@ impl CardthropicWindow {
             GameMode::Spider => {
                 self.normalize_spider_keyboard_target(self.imp().keyboard_target.get())
             }
-            GameMode::Freecell => {
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => {
                 self.normalize_freecell_keyboard_target(self.imp().keyboard_target.get())
             }
+            GameMode::Yukon => {
+                self.normalize_yukon_keyboard_target(self.imp().keyboard_target.get())
+            }
+            GameMode::FortyThieves => {
+                self.normalize_forty_thieves_keyboard_target(self.imp().keyboard_target.get())
+            }
+            GameMode::Scorpion => {
+                self.normalize_scorpion_keyboard_target(self.imp().keyboard_target.get())
+            }
+            GameMode::Canfield => {
+                self.normalize_canfield_keyboard_target(self.imp().keyboard_target.get())
+            }
+            GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                self.normalize_open_tableau_keyboard_target(self.imp().keyboard_target.get())
+            }
+            GameMode::Pyramid | GameMode::TriPeaks | GameMode::Golf => {
+                self.normalize_slot_canvas_keyboard_target(self.imp().keyboard_target.get())
+            }
             GameMode::Klondike => self.imp().keyboard_target.get(),
         };
         self.imp().keyboard_target.set(target);
@@ -181,7 +205,12 @@ impl CardthropicWindow {
                 }
             }
             KeyboardTarget::Freecell(idx) => {
-                if self.is_mode_engine_ready() {
+                if !self.is_mode_engine_ready() {
+                    return;
+                }
+                if self.active_game_mode() == GameMode::Canfield {
+                    self.activate_canfield_reserve(1);
+                } else {
                     self.activate_freecell_slot(idx, 1);
                 }
             }
@@ -191,15 +220,25 @@ impl CardthropicWindow {
                 }
             }
             KeyboardTarget::Tableau { col, start } => {
-                if self.is_mode_engine_ready() {
-                    self.select_or_move_tableau_with_start(col, start);
+                if !self.is_mode_engine_ready() {
+                    return;
+                }
+                match (self.active_game_mode(), start) {
+                    (GameMode::Pyramid, Some(slot)) => {
+                        self.activate_pyramid_card(PyramidCardRef::Pyramid(slot));
+                    }
+                    (GameMode::TriPeaks | GameMode::Golf, Some(slot)) => {
+                        self.activate_waste_build_slot(slot);
+                    }
+                    (GameMode::Pyramid | GameMode::TriPeaks | GameMode::Golf, None) => {}
+                    _ => self.select_or_move_tableau_with_start(col, start),
                 }
             }
         }
     }
 
     fn move_keyboard_focus_horizontal(&self, delta: i32) {
-        if self.active_game_mode() == GameMode::Freecell {
+        if self.active_game_mode().is_freecell_family() {
             self.move_keyboard_focus_horizontal_freecell(delta);
             return;
         }
@@ -207,6 +246,30 @@ impl CardthropicWindow {
             self.move_keyboard_focus_horizontal_spider(delta);
             return;
         }
+        if self.active_game_mode() == GameMode::Yukon {
+            self.move_keyboard_focus_horizontal_yukon(delta);
+            return;
+        }
+        if self.active_game_mode() == GameMode::FortyThieves {
+            self.move_keyboard_focus_horizontal_forty_thieves(delta);
+            return;
+        }
+        if self.active_game_mode() == GameMode::Scorpion {
+            self.move_keyboard_focus_horizontal_scorpion(delta);
+            return;
+        }
+        if self.active_game_mode() == GameMode::Canfield {
+            self.move_keyboard_focus_horizontal_canfield(delta);
+            return;
+        }
+        if OpenTableauLayout::for_mode(self.active_game_mode()).is_some() {
+            self.move_keyboard_focus_horizontal_open_tableau(delta);
+            return;
+        }
+        if Self::uses_slot_canvas(self.active_game_mode()) {
+            self.move_keyboard_focus_horizontal_slot_canvas(delta);
+            return;
+        }
         let Some(game) = boundary::clone_klondike_for_automation(
             &self.imp().game.borrow(),
             self.active_game_mode(),
@@ -220,7 +283,7 @@ impl CardthropicWindow {
     }
 
     fn move_keyboard_focus_vertical(&self, delta: i32) {
-        if self.active_game_mode() == GameMode::Freecell {
+        if self.active_game_mode().is_freecell_family() {
             self.move_keyboard_focus_vertical_freecell(delta);
             return;
         }
@@ -228,6 +291,30 @@ impl CardthropicWindow {
             self.move_keyboard_focus_vertical_spider(delta);
             return;
         }
+        if self.active_game_mode() == GameMode::Yukon {
+            self.move_keyboard_focus_vertical_yukon(delta);
+            return;
+        }
+        if self.active_game_mode() == GameMode::FortyThieves {
+            self.move_keyboard_focus_vertical_forty_thieves(delta);
+            return;
+        }
+        if self.active_game_mode() == GameMode::Scorpion {
+            self.move_keyboard_focus_vertical_scorpion(delta);
+            return;
+        }
+        if self.active_game_mode() == GameMode::Canfield {
+            self.move_keyboard_focus_vertical_canfield(delta);
+            return;
+        }
+        if OpenTableauLayout::for_mode(self.active_game_mode()).is_some() {
+            self.move_keyboard_focus_vertical_open_tableau(delta);
+            return;
+        }
+        if Self::uses_slot_canvas(self.active_game_mode()) {
+            self.move_keyboard_focus_vertical_slot_canvas(delta);
+            return;
+        }
         let Some(game) = boundary::clone_klondike_for_automation(
             &self.imp().game.borrow(),
             self.active_game_mode(),
@@ -258,8 +345,15 @@ impl CardthropicWindow {
                 picture.remove_css_class("keyboard-focus-card");
             }
         }
-        if self.active_game_mode() == GameMode::Spider {
-            let target = self.normalize_spider_keyboard_target(imp.keyboard_target.get());
+        if matches!(
+            self.active_game_mode(),
+            GameMode::Spider | GameMode::Scorpion
+        ) {
+            let target = if self.active_game_mode() == GameMode::Scorpion {
+                self.normalize_scorpion_keyboard_target(imp.keyboard_target.get())
+            } else {
+                self.normalize_spider_keyboard_target(imp.keyboard_target.get())
+            };
             imp.keyboard_target.set(target);
             match target {
                 KeyboardTarget::Stock => {
@@ -285,7 +379,7 @@ impl CardthropicWindow {
             }
             return;
         }
-        if self.active_game_mode() == GameMode::Freecell {
+        if self.active_game_mode().is_freecell_family() {
             let target = self.normalize_freecell_keyboard_target(imp.keyboard_target.get());
             imp.keyboard_target.set(target);
             match target {
@@ -317,6 +411,125 @@ impl CardthropicWindow {
             }
             return;
         }
+        if matches!(
+            self.active_game_mode(),
+            GameMode::Yukon | GameMode::BakersDozen | GameMode::BeleagueredCastle
+        ) {
+            let target = if self.active_game_mode() == GameMode::Yukon {
+                self.normalize_yukon_keyboard_target(imp.keyboard_target.get())
+            } else {
+                self.normalize_open_tableau_keyboard_target(imp.keyboard_target.get())
+            };
+            imp.keyboard_target.set(target);
+            match target {
+                KeyboardTarget::Foundation(idx) => {
+                    if let Some(picture) = self.foundation_pictures().get(idx) {
+                        picture.add_css_class("keyboard-focus-card");
+                    }
+                }
+                KeyboardTarget::Tableau { col, start } => {
+                    if let Some(start) = start {
+                        if let Some(picture) = imp
+                            .tableau_card_pictures
+                            .borrow()
+                            .get(col)
+                            .and_then(|cards| cards.get(start))
+                        {
+                            picture.add_css_class("keyboard-focus-card");
+                        }
+                    } else if let Some(stack) = self.tableau_stacks().get(col) {
+                        stack.add_css_class("keyboard-focus-empty");
+                    }
+                }
+                KeyboardTarget::Stock | KeyboardTarget::Waste | KeyboardTarget::Freecell(_) => {}
+            }
+            return;
+        }
+        if self.active_game_mode() == GameMode::FortyThieves {
+            let target = self.normalize_forty_thieves_keyboard_target(imp.keyboard_target.get());
+            imp.keyboard_target.set(target);
+            match target {
+                KeyboardTarget::Stock => imp.stock_picture.add_css_class("keyboard-focus-card"),
+                KeyboardTarget::Waste => imp.waste_picture.add_css_class("keyboard-focus-card"),
+                KeyboardTarget::Foundation(idx) => {
+                    if let Some(picture) = self.foundation_pictures().get(idx) {
+                        picture.add_css_class("keyboard-focus-card");
+                    }
+                }
+                KeyboardTarget::Tableau { col, start } => {
+                    if let Some(start) = start {
+                        if let Some(picture) = imp
+                            .tableau_card_pictures
+                            .borrow()
+                            .get(col)
+                            .and_then(|cards| cards.get(start))
+                        {
+                            picture.add_css_class("keyboard-focus-card");
+                        }
+                    } else if let Some(stack) = self.tableau_stacks().get(col) {
+                        stack.add_css_class("keyboard-focus-empty");
+                    }
+                }
+                KeyboardTarget::Freecell(_) => {}
+            }
+            return;
+        }
+        if self.active_game_mode() == GameMode::Canfield {
+            let target = self.normalize_canfield_keyboard_target(imp.keyboard_target.get());
+            imp.keyboard_target.set(target);
+            match target {
+                KeyboardTarget::Stock => imp.stock_picture.add_css_class("keyboard-focus-card"),
+                KeyboardTarget::Waste => imp.waste_picture.add_css_class("keyboard-focus-card"),
+                KeyboardTarget::Freecell(idx) => {
+                    if let Some(slot) = self.freecell_slot_pictures().get(idx) {
+                        slot.add_css_class("keyboard-focus-card");
+                    }
+                }
+                KeyboardTarget::Foundation(idx) => {
+                    if let Some(picture) = self.foundation_pictures().get(idx) {
+                        picture.add_css_class("keyboard-focus-card");
+                    }
+                }
+                KeyboardTarget::Tableau { col, start } => {
+                    if let Some(start) = start {
+                        if let Some(picture) = imp
+                            .tableau_card_pictures
+                            .borrow()
+                            .get(col)
+                            .and_then(|cards| cards.get(start))
+                        {
+                            picture.add_css_class("keyboard-focus-card");
+                        }
+                    } else if let Some(stack) = self.tableau_stacks().get(col) {
+                        stack.add_css_class("keyboard-focus-empty");
+                    }
+                }
+            }
+            return;
+        }
+        if Self::uses_slot_canvas(self.active_game_mode()) {
+            let target = self.normalize_slot_canvas_keyboard_target(imp.keyboard_target.get());
+            imp.keyboard_target.set(target);
+            match target {
+                KeyboardTarget::Stock => imp.stock_picture.add_css_class("keyboard-focus-card"),
+                KeyboardTarget::Waste => imp.waste_picture.add_css_class("keyboard-focus-card"),
+                KeyboardTarget::Tableau {
+                    col,
+                    start: Some(start),
+                } => {
+                    if let Some(picture) = imp
+                        .tableau_card_pictures
+                        .borrow()
+                        .get(col)
+                        .and_then(|cards| cards.get(start))
+                    {
+                        picture.add_css_class("keyboard-focus-card");
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
 
         let Some(game) = boundary::clone_klondike_for_automation(
             &imp.game.borrow(),
@@ -364,13 +577,13 @@ impl CardthropicWindow {
     fn normalize_freecell_keyboard_target(&self, target: KeyboardTarget) -> KeyboardTarget {
         let game = self.imp().game.borrow();
         let freecell = game.freecell();
-        let freecell_slots = self.current_freecell_cell_count() as usize;
+        let freecell_slots = self.freecell_board_cell_count() as usize;
         let max_freecell_idx = freecell_slots.saturating_sub(1);
         match target {
             KeyboardTarget::Freecell(idx) => KeyboardTarget::Freecell(idx.min(max_freecell_idx)),
             KeyboardTarget::Foundation(idx) => KeyboardTarget::Foundation(idx.min(3)),
             KeyboardTarget::Tableau { col, start } => {
-                let col = col.min(7);
+                let col = col.min(freecell.tableau().len().saturating_sub(1));
                 let len = freecell.tableau().get(col).map(Vec::len).unwrap_or(0);
                 if len == 0 {
                     KeyboardTarget::Tableau { col, start: None }
@@ -392,8 +605,9 @@ impl CardthropicWindow {
 
     fn move_keyboard_focus_horizontal_freecell(&self, delta: i32) {
         let current = self.normalize_freecell_keyboard_target(self.imp().keyboard_target.get());
-        let freecell_slots = self.current_freecell_cell_count() as i32;
+        let freecell_slots = self.freecell_board_cell_count() as i32;
         let top_slots = (freecell_slots + 4).max(1);
+        let last_col = self.imp().game.borrow().freecell().tableau().len() as i32 - 1;
         let next = match current {
             KeyboardTarget::Freecell(idx) => {
                 let idx = (idx as i32 + delta).clamp(0, top_slots - 1);
@@ -412,7 +626,7 @@ impl CardthropicWindow {
                 }
             }
             KeyboardTarget::Tableau { col, start } => {
-                let new_col = (col as i32 + delta).clamp(0, 7) as usize;
+                let new_col = (col as i32 + delta).clamp(0, last_col.max(0)) as usize;
                 KeyboardTarget::Tableau {
                     col: new_col,
                     start,
@@ -426,7 +640,7 @@ impl CardthropicWindow {
 
     fn move_keyboard_focus_vertical_freecell(&self, delta: i32) {
         let current = self.normalize_freecell_keyboard_target(self.imp().keyboard_target.get());
-        let freecell_slots = self.current_freecell_cell_count() as usize;
+        let freecell_slots = self.freecell_board_cell_count() as usize;
         let next = match current {
             KeyboardTarget::Freecell(idx) => {
                 if delta > 0 {
@@ -447,8 +661,9 @@ impl CardthropicWindow {
             }
             KeyboardTarget::Foundation(idx) => {
                 if delta > 0 {
-                    let col = (idx + freecell_slots).min(7);
                     let game = self.imp().game.borrow();
+                    let col = (idx + freecell_slots)
+                        .min(game.freecell().tableau().len().saturating_sub(1));
                     let len = game
                         .freecell()
                         .tableau()
@@ -671,4 +886,543 @@ impl CardthropicWindow {
             .map(|pos| faceups.len().saturating_sub(pos + 1))
             .unwrap_or(0)
     }
+
+    fn normalize_yukon_keyboard_target(&self, target: KeyboardTarget) -> KeyboardTarget {
+        let game = self.imp().game.borrow().yukon().clone();
+        let last_col = game.tableau().len() - 1;
+        match target {
+            KeyboardTarget::Foundation(idx) => KeyboardTarget::Foundation(idx.min(3)),
+            KeyboardTarget::Tableau { col, start } => {
+                let col = col.min(last_col);
+                let faceups = Self::tableau_face_up_indices(game.tableau(), col);
+                match start {
+                    Some(start) if faceups.contains(&start) => KeyboardTarget::Tableau {
+                        col,
+                        start: Some(start),
+                    },
+                    _ => KeyboardTarget::Tableau {
+                        col,
+                        start: faceups.last().copied(),
+                    },
+                }
+            }
+            KeyboardTarget::Stock | KeyboardTarget::Waste | KeyboardTarget::Freecell(_) => {
+                Self::face_up_tableau_target_for_column(game.tableau(), 0, Some(0))
+            }
+        }
+    }
+
+    fn move_keyboard_focus_horizontal_yukon(&self, delta: i32) {
+        let game = self.imp().game.borrow().yukon().clone();
+        let last_col = game.tableau().len() as i32 - 1;
+        let current = self.normalize_yukon_keyboard_target(self.imp().keyboard_target.get());
+        let next = match current {
+            KeyboardTarget::Foundation(idx) => {
+                KeyboardTarget::Foundation((idx as i32 + delta).clamp(0, 3) as usize)
+            }
+            KeyboardTarget::Tableau { col, start } => {
+                let new_col = (col as i32 + delta).clamp(0, last_col) as usize;
+                let offset = Self::face_up_tableau_offset_from_top(game.tableau(), col, start);
+                Self::face_up_tableau_target_for_column(game.tableau(), new_col, Some(offset))
+            }
+            other => other,
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    fn move_keyboard_focus_vertical_yukon(&self, delta: i32) {
+        let game = self.imp().game.borrow().yukon().clone();
+        let current = self.normalize_yukon_keyboard_target(self.imp().keyboard_target.get());
+        let next = match current {
+            KeyboardTarget::Foundation(idx) => {
+                if delta > 0 {
+                    Self::face_up_tableau_target_for_column(game.tableau(), idx, Some(0))
+                } else {
+                    KeyboardTarget::Foundation(idx)
+                }
+            }
+            KeyboardTarget::Tableau { col, start } => {
+                let faceups = Self::tableau_face_up_indices(game.tableau(), col);
+                let pos = start.and_then(|curr| faceups.iter().position(|&idx| idx == curr));
+                match pos {
+                    Some(pos) if delta < 0 && pos + 1 < faceups.len() => KeyboardTarget::Tableau {
+                        col,
+                        start: Some(faceups[pos + 1]),
+                    },
+                    Some(pos) if delta > 0 && pos > 0 => KeyboardTarget::Tableau {
+                        col,
+                        start: Some(faceups[pos - 1]),
+                    },
+                    // Foundations sit above the first four columns.
+                    _ if delta < 0 => KeyboardTarget::Foundation(col.min(3)),
+                    _ => KeyboardTarget::Tableau { col, start },
+                }
+            }
+            other => other,
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    /// Baker's Dozen and Beleaguered Castle only move top cards, so tableau
+    /// focus always rests on a column's last card.
+    fn normalize_open_tableau_keyboard_target(&self, target: KeyboardTarget) -> KeyboardTarget {
+        let Some(layout) = OpenTableauLayout::for_mode(self.active_game_mode()) else {
+            return target;
+        };
+        let game = self.imp().game.borrow();
+        let tableau = game.open_tableau(layout).tableau();
+        let top_of = |col: usize| KeyboardTarget::Tableau {
+            col,
+            start: tableau[col].len().checked_sub(1),
+        };
+        match target {
+            KeyboardTarget::Foundation(idx) => KeyboardTarget::Foundation(idx.min(3)),
+            KeyboardTarget::Tableau { col, .. } => top_of(col.min(tableau.len() - 1)),
+            KeyboardTarget::Stock | KeyboardTarget::Waste | KeyboardTarget::Freecell(_) => {
+                top_of(0)
+            }
+        }
+    }
+
+    fn move_keyboard_focus_horizontal_open_tableau(&self, delta: i32) {
+        let current = self.normalize_open_tableau_keyboard_target(self.imp().keyboard_target.get());
+        let next = match current {
+            KeyboardTarget::Foundation(idx) => {
+                KeyboardTarget::Foundation((idx as i32 + delta).clamp(0, 3) as usize)
+            }
+            KeyboardTarget::Tableau { col, .. } => {
+                let new_col = (col as i32 + delta).max(0) as usize;
+                self.normalize_open_tableau_keyboard_target(KeyboardTarget::Tableau {
+                    col: new_col,
+                    start: None,
+                })
+            }
+            other => other,
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    fn move_keyboard_focus_vertical_open_tableau(&self, delta: i32) {
+        let current = self.normalize_open_tableau_keyboard_target(self.imp().keyboard_target.get());
+        let next = match current {
+            KeyboardTarget::Foundation(idx) if delta > 0 => self
+                .normalize_open_tableau_keyboard_target(KeyboardTarget::Tableau {
+                    col: idx,
+                    start: None,
+                }),
+            // Foundations sit above the first four columns.
+            KeyboardTarget::Tableau { col, .. } if delta < 0 => {
+                KeyboardTarget::Foundation(col.min(3))
+            }
+            other => other,
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    /// The Forty Thieves top row (stock, waste, eight foundations) lines up
+    /// with the ten tableau columns, so vertical moves keep the column index.
+    fn forty_thieves_top_row_target(idx: usize) -> KeyboardTarget {
+        match idx {
+            0 => KeyboardTarget::Stock,
+            1 => KeyboardTarget::Waste,
+            idx => KeyboardTarget::Foundation((idx - 2).min(FORTY_THIEVES_FOUNDATIONS - 1)),
+        }
+    }
+
+    fn forty_thieves_top_row_index(target: KeyboardTarget) -> Option<usize> {
+        match target {
+            KeyboardTarget::Stock => Some(0),
+            KeyboardTarget::Waste => Some(1),
+            KeyboardTarget::Foundation(idx) => Some(idx + 2),
+            KeyboardTarget::Tableau { .. } | KeyboardTarget::Freecell(_) => None,
+        }
+    }
+
+    fn normalize_forty_thieves_keyboard_target(&self, target: KeyboardTarget) -> KeyboardTarget {
+        let game = self.imp().game.borrow();
+        let tableau = game.forty_thieves().tableau();
+        match target {
+            KeyboardTarget::Stock | KeyboardTarget::Waste => target,
+            KeyboardTarget::Foundation(idx) => {
+                KeyboardTarget::Foundation(idx.min(FORTY_THIEVES_FOUNDATIONS - 1))
+            }
+            KeyboardTarget::Tableau { col, start } => {
+                let col = col.min(FORTY_THIEVES_COLUMNS - 1);
+                let faceups = Self::tableau_face_up_indices(tableau, col);
+                match start {
+                    Some(start) if faceups.contains(&start) => KeyboardTarget::Tableau {
+                        col,
+                        start: Some(start),
+                    },
+                    _ => KeyboardTarget::Tableau {
+                        col,
+                        start: faceups.last().copied(),
+                    },
+                }
+            }
+            KeyboardTarget::Freecell(_) => KeyboardTarget::Stock,
+        }
+    }
+
+    fn move_keyboard_focus_horizontal_forty_thieves(&self, delta: i32) {
+        let current =
+            self.normalize_forty_thieves_keyboard_target(self.imp().keyboard_target.get());
+        let last = FORTY_THIEVES_COLUMNS as i32 - 1;
+        let next = match Self::forty_thieves_top_row_index(current) {
+            Some(idx) => {
+                Self::forty_thieves_top_row_target((idx as i32 + delta).clamp(0, last) as usize)
+            }
+            None => {
+                let KeyboardTarget::Tableau { col, start } = current else {
+                    return;
+                };
+                let game = self.imp().game.borrow();
+                let tableau = game.forty_thieves().tableau();
+                let new_col = (col as i32 + delta).clamp(0, last) as usize;
+                let offset = Self::face_up_tableau_offset_from_top(tableau, col, start);
+                Self::face_up_tableau_target_for_column(tableau, new_col, Some(offset))
+            }
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    fn move_keyboard_focus_vertical_forty_thieves(&self, delta: i32) {
+        let current =
+            self.normalize_forty_thieves_keyboard_target(self.imp().keyboard_target.get());
+        let next = {
+            let game = self.imp().game.borrow();
+            let tableau = game.forty_thieves().tableau();
+            match current {
+                KeyboardTarget::Tableau { col, start } => {
+                    let faceups = Self::tableau_face_up_indices(tableau, col);
+                    let pos = start.and_then(|curr| faceups.iter().position(|&idx| idx == curr));
+                    match pos {
+                        Some(pos) if delta < 0 && pos + 1 < faceups.len() => {
+                            KeyboardTarget::Tableau {
+                                col,
+                                start: Some(faceups[pos + 1]),
+                            }
+                        }
+                        Some(pos) if delta > 0 && pos > 0 => KeyboardTarget::Tableau {
+                            col,
+                            start: Some(faceups[pos - 1]),
+                        },
+                        _ if delta < 0 => Self::forty_thieves_top_row_target(col),
+                        _ => current,
+                    }
+                }
+                other if delta > 0 => match Self::forty_thieves_top_row_index(other) {
+                    Some(col) => Self::face_up_tableau_target_for_column(tableau, col, Some(0)),
+                    None => other,
+                },
+                other => other,
+            }
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    /// Scorpion's only interactive top-row slot is the reserve, which sits
+    /// above the first column.
+    fn normalize_scorpion_keyboard_target(&self, target: KeyboardTarget) -> KeyboardTarget {
+        let game = self.imp().game.borrow();
+        let tableau = game.scorpion().tableau();
+        match target {
+            KeyboardTarget::Stock => KeyboardTarget::Stock,
+            KeyboardTarget::Tableau { col, start } => {
+                let col = col.min(SCORPION_COLUMNS - 1);
+                let faceups = Self::tableau_face_up_indices(tableau, col);
+                match start {
+                    Some(start) if faceups.contains(&start) => KeyboardTarget::Tableau {
+                        col,
+                        start: Some(start),
+                    },
+                    _ => KeyboardTarget::Tableau {
+                        col,
+                        start: faceups.last().copied(),
+                    },
+                }
+            }
+            KeyboardTarget::Waste | KeyboardTarget::Foundation(_) | KeyboardTarget::Freecell(_) => {
+                KeyboardTarget::Stock
+            }
+        }
+    }
+
+    fn move_keyboard_focus_horizontal_scorpion(&self, delta: i32) {
+        let current = self.normalize_scorpion_keyboard_target(self.imp().keyboard_target.get());
+        let next = {
+            let game = self.imp().game.borrow();
+            let tableau = game.scorpion().tableau();
+            match current {
+                KeyboardTarget::Tableau { col, start } => {
+                    let last = SCORPION_COLUMNS as i32 - 1;
+                    let new_col = (col as i32 + delta).clamp(0, last) as usize;
+                    let offset = Self::face_up_tableau_offset_from_top(tableau, col, start);
+                    Self::face_up_tableau_target_for_column(tableau, new_col, Some(offset))
+                }
+                _ if delta > 0 => Self::face_up_tableau_target_for_column(tableau, 0, Some(0)),
+                other => other,
+            }
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    fn move_keyboard_focus_vertical_scorpion(&self, delta: i32) {
+        let current = self.normalize_scorpion_keyboard_target(self.imp().keyboard_target.get());
+        let next = {
+            let game = self.imp().game.borrow();
+            let tableau = game.scorpion().tableau();
+            match current {
+                KeyboardTarget::Tableau { col, start } => {
+                    let faceups = Self::tableau_face_up_indices(tableau, col);
+                    let pos = start.and_then(|curr| faceups.iter().position(|&idx| idx == curr));
+                    match pos {
+                        Some(pos) if delta < 0 && pos + 1 < faceups.len() => {
+                            KeyboardTarget::Tableau {
+                                col,
+                                start: Some(faceups[pos + 1]),
+                            }
+                        }
+                        Some(pos) if delta > 0 && pos > 0 => KeyboardTarget::Tableau {
+                            col,
+                            start: Some(faceups[pos - 1]),
+                        },
+                        _ if delta < 0 => KeyboardTarget::Stock,
+                        _ => current,
+                    }
+                }
+                _ if delta > 0 => Self::face_up_tableau_target_for_column(tableau, 0, Some(0)),
+                other => other,
+            }
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    /// The Canfield top row reads stock, waste, reserve, then the four
+    /// foundations; vertical moves pair each tableau column with the top-row
+    /// stop of the same index.
+    fn canfield_top_row_target(idx: usize) -> KeyboardTarget {
+        match idx {
+            0 => KeyboardTarget::Stock,
+            1 => KeyboardTarget::Waste,
+            2 => KeyboardTarget::Freecell(CANFIELD_RESERVE_CELL),
+            idx => KeyboardTarget::Foundation((idx - 3).min(3)),
+        }
+    }
+
+    fn canfield_top_row_index(target: KeyboardTarget) -> Option<usize> {
+        match target {
+            KeyboardTarget::Stock => Some(0),
+            KeyboardTarget::Waste => Some(1),
+            KeyboardTarget::Freecell(_) => Some(2),
+            KeyboardTarget::Foundation(idx) => Some(idx + 3),
+            KeyboardTarget::Tableau { .. } => None,
+        }
+    }
+
+    fn normalize_canfield_keyboard_target(&self, target: KeyboardTarget) -> KeyboardTarget {
+        let game = self.imp().game.borrow();
+        let tableau = game.canfield().tableau();
+        match target {
+            KeyboardTarget::Stock | KeyboardTarget::Waste => target,
+            KeyboardTarget::Freecell(_) => KeyboardTarget::Freecell(CANFIELD_RESERVE_CELL),
+            KeyboardTarget::Foundation(idx) => KeyboardTarget::Foundation(idx.min(3)),
+            KeyboardTarget::Tableau { col, start } => {
+                let col = col.min(CANFIELD_COLUMNS - 1);
+                let faceups = Self::tableau_face_up_indices(tableau, col);
+                match start {
+                    Some(start) if faceups.contains(&start) => KeyboardTarget::Tableau {
+                        col,
+                        start: Some(start),
+                    },
+                    _ => KeyboardTarget::Tableau {
+                        col,
+                        start: faceups.last().copied(),
+                    },
+                }
+            }
+        }
+    }
+
+    fn move_keyboard_focus_horizontal_canfield(&self, delta: i32) {
+        let current = self.normalize_canfield_keyboard_target(self.imp().keyboard_target.get());
+        let next = match Self::canfield_top_row_index(current) {
+            Some(idx) => Self::canfield_top_row_target((idx as i32 + delta).clamp(0, 6) as usize),
+            None => {
+                let KeyboardTarget::Tableau { col, start } = current else {
+                    return;
+                };
+                let game = self.imp().game.borrow();
+                let tableau = game.canfield().tableau();
+                let last = CANFIELD_COLUMNS as i32 - 1;
+                let new_col = (col as i32 + delta).clamp(0, last) as usize;
+                let offset = Self::face_up_tableau_offset_from_top(tableau, col, start);
+                Self::face_up_tableau_target_for_column(tableau, new_col, Some(offset))
+            }
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    fn move_keyboard_focus_vertical_canfield(&self, delta: i32) {
+        let current = self.normalize_canfield_keyboard_target(self.imp().keyboard_target.get());
+        let next = {
+            let game = self.imp().game.borrow();
+            let tableau = game.canfield().tableau();
+            match current {
+                KeyboardTarget::Tableau { col, start } => {
+                    let faceups = Self::tableau_face_up_indices(tableau, col);
+                    let pos = start.and_then(|curr| faceups.iter().position(|&idx| idx == curr));
+                    match pos {
+                        Some(pos) if delta < 0 && pos + 1 < faceups.len() => {
+                            KeyboardTarget::Tableau {
+                                col,
+                                start: Some(faceups[pos + 1]),
+                            }
+                        }
+                        Some(pos) if delta > 0 && pos > 0 => KeyboardTarget::Tableau {
+                            col,
+                            start: Some(faceups[pos - 1]),
+                        },
+                        _ if delta < 0 => Self::canfield_top_row_target(col),
+                        _ => current,
+                    }
+                }
+                other if delta > 0 => match Self::canfield_top_row_index(other) {
+                    Some(idx) => Self::face_up_tableau_target_for_column(
+                        tableau,
+                        idx.min(CANFIELD_COLUMNS - 1),
+                        Some(0),
+                    ),
+                    None => other,
+                },
+                other => other,
+            }
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    /// Slot-canvas layouts (Pyramid, TriPeaks, Golf) stop in reading order:
+    /// stock, waste, then every uncovered card in slot order.
+    fn slot_canvas_keyboard_targets(&self) -> Vec<KeyboardTarget> {
+        let game = self.imp().game.borrow();
+        let exposed: Vec<usize> = match WasteBuildLayout::for_mode(self.active_game_mode()) {
+            Some(layout) => {
+                let waste_build = game.waste_build(layout);
+                (0..layout.slot_count())
+                    .filter(|&slot| waste_build.is_exposed(slot))
+                    .collect()
+            }
+            None => (0..PYRAMID_SLOTS)
+                .filter(|&slot| game.pyramid().is_exposed(slot))
+                .collect(),
+        };
+        let mut targets = vec![KeyboardTarget::Stock, KeyboardTarget::Waste];
+        targets.extend(exposed.into_iter().map(|slot| KeyboardTarget::Tableau {
+            col: 0,
+            start: Some(slot),
+        }));
+        targets
+    }
+
+    fn normalize_slot_canvas_keyboard_target(&self, target: KeyboardTarget) -> KeyboardTarget {
+        let targets = self.slot_canvas_keyboard_targets();
+        if targets.contains(&target) {
+            return target;
+        }
+        match target {
+            // A removed card hands focus to the next uncovered card along.
+            KeyboardTarget::Tableau {
+                start: Some(slot), ..
+            } => targets
+                .iter()
+                .copied()
+                .find(|candidate| {
+                    matches!(candidate, KeyboardTarget::Tableau { start: Some(next), .. } if *next >= slot)
+                })
+                .or_else(|| targets.last().copied())
+                .unwrap_or(KeyboardTarget::Stock),
+            _ => KeyboardTarget::Stock,
+        }
+    }
+
+    fn move_keyboard_focus_horizontal_slot_canvas(&self, delta: i32) {
+        let current = self.normalize_slot_canvas_keyboard_target(self.imp().keyboard_target.get());
+        let targets = self.slot_canvas_keyboard_targets();
+        let pos = targets
+            .iter()
+            .position(|target| *target == current)
+            .unwrap_or(0) as i32;
+        let next = targets[(pos + delta).clamp(0, targets.len() as i32 - 1) as usize];
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    fn move_keyboard_focus_vertical_slot_canvas(&self, delta: i32) {
+        let current = self.normalize_slot_canvas_keyboard_target(self.imp().keyboard_target.get());
+        let targets = self.slot_canvas_keyboard_targets();
+        let next = match current {
+            KeyboardTarget::Stock | KeyboardTarget::Waste if delta > 0 => {
+                targets.get(2).copied().unwrap_or(current)
+            }
+            KeyboardTarget::Tableau { .. } if delta < 0 => KeyboardTarget::Waste,
+            other => other,
+        };
+        self.imp().keyboard_target.set(next);
+        self.update_keyboard_focus_style();
+    }
+
+    fn tableau_face_up_indices(tableau: &[Vec<Card>], col: usize) -> Vec<usize> {
+        tableau
+            .get(col)
+            .map(|pile| {
+                pile.iter()
+                    .enumerate()
+                    .filter_map(|(idx, card)| card.face_up.then_some(idx))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    }
+
+    fn face_up_tableau_target_for_column(
+        tableau: &[Vec<Card>],
+        col: usize,
+        prefer_offset_from_top: Option<usize>,
+    ) -> KeyboardTarget {
+        let faceups = Self::tableau_face_up_indices(tableau, col);
+        if faceups.is_empty() {
+            return KeyboardTarget::Tableau { col, start: None };
+        }
+        let offset = prefer_offset_from_top.unwrap_or(0).min(faceups.len() - 1);
+        let pos = faceups.len() - 1 - offset;
+        KeyboardTarget::Tableau {
+            col,
+            start: Some(faceups[pos]),
+        }
+    }
+
+    fn face_up_tableau_offset_from_top(
+        tableau: &[Vec<Card>],
+        col: usize,
+        start: Option<usize>,
+    ) -> usize {
+        let faceups = Self::tableau_face_up_indices(tableau, col);
+        let Some(start) = start else {
+            return 0;
+        };
+        faceups
+            .iter()
+            .position(|&idx| idx == start)
+            .map(|pos| faceups.len().saturating_sub(pos + 1))
+            .unwrap_or(0)
+    }
 }
@@ -1,4 +1,7 @@
 use super::*;
+use crate::game::{
+    PyramidGame, WasteBuildGame, WasteBuildLayout, GOLF_COLUMNS, GOLF_COLUMN_DEPTH, PYRAMID_ROWS,
+};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
@@ -9,8 +12,15 @@ impl CardthropicWindow {
             8
         } else {
             match self.active_game_mode() {
-                GameMode::Spider => 10,
-                GameMode::Freecell => 8,
+                GameMode::BakersDozen => 13,
+                GameMode::Spider => {
+                    i32::try_from(self.imp().game.borrow().spider().tableau().len()).unwrap_or(10)
+                }
+                GameMode::TriPeaks | GameMode::FortyThieves | GameMode::SeahavenTowers => 10,
+                GameMode::Freecell
+                | GameMode::EightOff
+                | GameMode::BakersGame
+                | GameMode::BeleagueredCastle => 8,
                 _ => 7,
             }
         };
@@ -92,7 +102,7 @@ impl CardthropicWindow {
             card_width = (card_width - (card_width % 2)).max(profile.min_card_width);
         }
 
-        if !mobile_phone_mode && self.active_game_mode() == GameMode::Freecell && columns > 1 {
+        if !mobile_phone_mode && self.active_game_mode().is_freecell_family() && columns > 1 {
             let adaptive_gap = Self::adaptive_freecell_tableau_gap(
                 available_width,
                 columns,
@@ -128,10 +138,8 @@ impl CardthropicWindow {
         profile.min_card_height.hash(&mut metrics_hasher);
         mobile_phone_mode.hash(&mut metrics_hasher);
         imp.observed_scroller_width.get().hash(&mut metrics_hasher);
-        if self.active_game_mode() == GameMode::Freecell {
-            self.current_freecell_cell_count()
-                .clamp(FREECELL_MIN_CELL_COUNT, FREECELL_MAX_CELL_COUNT)
-                .hash(&mut metrics_hasher);
+        if self.active_game_mode().is_freecell_family() {
+            self.freecell_board_cell_count().hash(&mut metrics_hasher);
         }
         TABLEAU_FACE_UP_STEP_PX.hash(&mut metrics_hasher);
         TABLEAU_FACE_DOWN_STEP_PX.hash(&mut metrics_hasher);
@@ -171,8 +179,20 @@ impl CardthropicWindow {
             0usize
         } else {
             match self.active_game_mode() {
-                GameMode::Spider => 8usize,
-                GameMode::Klondike | GameMode::Freecell => 4usize,
+                GameMode::Spider => self.imp().game.borrow().spider().rule_set().goal_runs(),
+                GameMode::FortyThieves => 8usize,
+                GameMode::Klondike
+                | GameMode::Freecell
+                | GameMode::EightOff
+                | GameMode::SeahavenTowers
+                | GameMode::BakersGame
+                | GameMode::Yukon
+                | GameMode::Scorpion
+                | GameMode::Canfield
+                | GameMode::BakersDozen
+                | GameMode::BeleagueredCastle => 4usize,
+                GameMode::Pyramid => 1usize,
+                GameMode::TriPeaks | GameMode::Golf => 0usize,
             }
         };
         let foundation_slots_ok = visible_foundation_slots == expected_foundation_slots;
@@ -399,22 +419,32 @@ impl CardthropicWindow {
         let mut hi = (usable / 4).min(900);
         let mode = self.active_game_mode();
         let spider_mode = mode == GameMode::Spider;
-        let freecell_mode = mode == GameMode::Freecell;
+        let spider_run_slots = if spider_mode {
+            i32::try_from(self.imp().game.borrow().spider().rule_set().goal_runs()).unwrap_or(8)
+        } else {
+            0
+        };
+        let freecell_mode = mode.is_freecell_family();
+        let forty_thieves_mode = mode == GameMode::FortyThieves;
+        let canfield_mode = mode == GameMode::Canfield;
 
         while lo <= hi {
             let mid = (lo + hi) / 2;
             let waste_step = (mid / 6).clamp(8, 22);
             let top_row_width = if spider_mode {
-                // Spider shows stock + 8 completed-run slots.
-                // Width = stock + one row gap + foundations(8 cards, no inner gap).
-                (9 * mid) + 16
+                // Spider shows stock + one slot per completed run it needs.
+                // Width = stock + one row gap + run slots (no inner gap).
+                ((1 + spider_run_slots) * mid) + 16
+            } else if forty_thieves_mode {
+                // Forty Thieves shows stock + waste + 8 foundations.
+                (10 * mid) + 32
+            } else if canfield_mode {
+                // Canfield shows stock + waste/reserve strip + 4 foundations.
+                (7 * mid) + 56
             } else if freecell_mode {
                 // FreeCell shows free-cell strip + 4 foundations.
                 // Width = freecell strip + one row gap + foundations + 8px foundation inset.
-                let free_cells = i32::from(
-                    self.current_freecell_cell_count()
-                        .clamp(FREECELL_MIN_CELL_COUNT, FREECELL_MAX_CELL_COUNT),
-                );
+                let free_cells = i32::from(self.freecell_board_cell_count());
                 ((free_cells + 4) * mid) + 48
             } else {
                 (6 * mid) + (4 * waste_step) + 56
@@ -475,6 +505,140 @@ impl CardthropicWindow {
         let face_down_step = (face_up_step * 7 / 16).clamp(3, 8);
         (face_up_step, face_down_step)
     }
+
+    /// Modes whose tableau is one slot-addressed canvas in the first stack
+    /// rather than a row of piles.
+    pub(super) fn uses_slot_canvas(mode: GameMode) -> bool {
+        matches!(
+            mode,
+            GameMode::Pyramid | GameMode::TriPeaks | GameMode::Golf
+        )
+    }
+
+    /// Vertical offset between Pyramid rows. Each row covers the lower part of
+    /// the row above it, leaving the corner index of every card readable.
+    pub(super) fn pyramid_row_step(card_height: i32) -> i32 {
+        (card_height * 3 / 10).max(1)
+    }
+
+    /// Top-left corner of a Pyramid slot on the tableau canvas. Rows are
+    /// centred over the seven-card base, so every card straddles the two cards
+    /// that cover it.
+    pub(super) fn pyramid_slot_origin(
+        slot: usize,
+        card_width: i32,
+        card_height: i32,
+        gap: i32,
+    ) -> (i32, i32) {
+        let (row, pos) = PyramidGame::slot_row_pos(slot);
+        let step_x = card_width + gap;
+        let indent = (PYRAMID_ROWS - 1 - row) as i32 * step_x / 2;
+        (
+            indent + pos as i32 * step_x,
+            row as i32 * Self::pyramid_row_step(card_height),
+        )
+    }
+
+    pub(super) fn pyramid_canvas_size(card_width: i32, card_height: i32, gap: i32) -> (i32, i32) {
+        let rows = PYRAMID_ROWS as i32;
+        (
+            rows * card_width + (rows - 1) * gap,
+            (rows - 1) * Self::pyramid_row_step(card_height) + card_height,
+        )
+    }
+
+    /// Pyramid slot drawn on top at a canvas point. Deeper rows paint over
+    /// shallower ones, so they win where cards overlap.
+    pub(super) fn pyramid_slot_at(
+        pyramid: &[Option<Card>],
+        x: f64,
+        y: f64,
+        card_width: i32,
+        card_height: i32,
+        gap: i32,
+    ) -> Option<usize> {
+        (0..pyramid.len()).rev().find(|&slot| {
+            if pyramid[slot].is_none() {
+                return false;
+            }
+            let (left, top) = Self::pyramid_slot_origin(slot, card_width, card_height, gap);
+            x >= f64::from(left)
+                && x < f64::from(left + card_width)
+                && y >= f64::from(top)
+                && y < f64::from(top + card_height)
+        })
+    }
+
+    /// Top-left corner of a TriPeaks or Golf slot on the tableau canvas.
+    /// TriPeaks works in half-card steps so each card straddles the two that
+    /// cover it; Golf columns fan downwards with the Pyramid row overlap.
+    pub(super) fn waste_build_slot_origin(
+        layout: WasteBuildLayout,
+        slot: usize,
+        card_width: i32,
+        card_height: i32,
+        gap: i32,
+    ) -> (i32, i32) {
+        let step_x = card_width + gap;
+        let row_step = Self::pyramid_row_step(card_height);
+        match layout {
+            WasteBuildLayout::TriPeaks => {
+                let (row, pos) = WasteBuildGame::tripeaks_row_pos(slot);
+                let (peak, offset) = (pos as i32 / 2, pos as i32 % 2);
+                let half_steps = match row {
+                    0 => 6 * pos as i32 + 3,
+                    1 => 6 * peak + 2 * offset + 2,
+                    2 => 2 * pos as i32 + 1,
+                    _ => 2 * pos as i32,
+                };
+                (half_steps * step_x / 2, row as i32 * row_step)
+            }
+            WasteBuildLayout::Golf => {
+                let (col, depth) = WasteBuildGame::golf_col_depth(slot);
+                (col as i32 * step_x, depth as i32 * row_step)
+            }
+        }
+    }
+
+    pub(super) fn waste_build_canvas_size(
+        layout: WasteBuildLayout,
+        card_width: i32,
+        card_height: i32,
+        gap: i32,
+    ) -> (i32, i32) {
+        let (columns, rows) = match layout {
+            WasteBuildLayout::TriPeaks => (10, 4),
+            WasteBuildLayout::Golf => (GOLF_COLUMNS as i32, GOLF_COLUMN_DEPTH as i32),
+        };
+        (
+            columns * card_width + (columns - 1) * gap,
+            (rows - 1) * Self::pyramid_row_step(card_height) + card_height,
+        )
+    }
+
+    /// Slot drawn on top at a canvas point; later slots paint over earlier
+    /// ones in both layouts.
+    pub(super) fn waste_build_slot_at(
+        layout: WasteBuildLayout,
+        tableau: &[Option<Card>],
+        x: f64,
+        y: f64,
+        card_width: i32,
+        card_height: i32,
+        gap: i32,
+    ) -> Option<usize> {
+        (0..tableau.len()).rev().find(|&slot| {
+            if tableau[slot].is_none() {
+                return false;
+            }
+            let (left, top) =
+                Self::waste_build_slot_origin(layout, slot, card_width, card_height, gap);
+            x >= f64::from(left)
+                && x < f64::from(left + card_width)
+                && y >= f64::from(top)
+                && y < f64::from(top + card_height)
+        })
+    }
 }
 
 #[cfg(test)]
@@ -483,12 +647,26 @@ mod tests {
 
     fn desktop_columns(mode: GameMode) -> i32 {
         match mode {
-            GameMode::Spider => 10,
-            GameMode::Freecell => 8,
+            GameMode::BakersDozen => 13,
+            GameMode::Spider
+            | GameMode::TriPeaks
+            | GameMode::FortyThieves
+            | GameMode::SeahavenTowers => 10,
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::BakersGame
+            | GameMode::BeleagueredCastle => 8,
             _ => 7,
         }
     }
 
+    fn freecell_cell_counts(mode: GameMode) -> std::ops::RangeInclusive<u8> {
+        match FreecellLayout::for_mode(mode).and_then(FreecellLayout::fixed_cell_count) {
+            Some(cells) => cells..=cells,
+            None => FREECELL_MIN_CELL_COUNT..=FREECELL_MAX_CELL_COUNT,
+        }
+    }
+
     fn desktop_vertical_layout_reserve(window_height: i32) -> i32 {
         if window_height <= 600 {
             196
@@ -602,7 +780,12 @@ mod tests {
         let waste_step = (card_width / 6).clamp(8, 22);
         match mode {
             GameMode::Spider => (9 * card_width) + 16,
-            GameMode::Freecell => ((i32::from(freecell_cells) + 4) * card_width) + 48,
+            GameMode::FortyThieves => (10 * card_width) + 32,
+            GameMode::Canfield => (7 * card_width) + 56,
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => ((i32::from(freecell_cells) + 4) * card_width) + 48,
             _ => (6 * card_width) + (4 * waste_step) + 56,
         }
     }
@@ -624,6 +807,118 @@ mod tests {
         assert!(used <= available_width);
     }
 
+    #[test]
+    fn pyramid_rows_centre_over_the_base() {
+        let (card_width, card_height, gap) = (70, 108, 4);
+        let (canvas_width, canvas_height) =
+            CardthropicWindow::pyramid_canvas_size(card_width, card_height, gap);
+        let (apex_x, apex_y) =
+            CardthropicWindow::pyramid_slot_origin(0, card_width, card_height, gap);
+        assert_eq!(apex_y, 0);
+        assert_eq!(apex_x * 2 + card_width, canvas_width);
+
+        let (last_x, last_y) =
+            CardthropicWindow::pyramid_slot_origin(27, card_width, card_height, gap);
+        assert_eq!(last_x + card_width, canvas_width);
+        assert_eq!(last_y + card_height, canvas_height);
+
+        // A card sits halfway between the two cards that cover it.
+        let (parent_x, _) = CardthropicWindow::pyramid_slot_origin(4, card_width, card_height, gap);
+        let (left_x, _) = CardthropicWindow::pyramid_slot_origin(7, card_width, card_height, gap);
+        let (right_x, _) = CardthropicWindow::pyramid_slot_origin(8, card_width, card_height, gap);
+        assert_eq!(parent_x * 2, left_x + right_x);
+    }
+
+    #[test]
+    fn pyramid_hit_test_prefers_the_covering_card() {
+        let (card_width, card_height, gap) = (70, 108, 4);
+        let card = Card {
+            suit: Suit::Spades,
+            rank: 5,
+            face_up: true,
+        };
+        let mut pyramid = [Some(card); 28];
+        let (_, row_one_y) =
+            CardthropicWindow::pyramid_slot_origin(1, card_width, card_height, gap);
+        let (apex_x, _) = CardthropicWindow::pyramid_slot_origin(0, card_width, card_height, gap);
+        let x = f64::from(apex_x + 2);
+        let y = f64::from(row_one_y + 2);
+        assert_eq!(
+            CardthropicWindow::pyramid_slot_at(&pyramid, x, y, card_width, card_height, gap),
+            Some(1)
+        );
+        pyramid[1] = None;
+        assert_eq!(
+            CardthropicWindow::pyramid_slot_at(&pyramid, x, y, card_width, card_height, gap),
+            Some(0)
+        );
+        assert_eq!(
+            CardthropicWindow::pyramid_slot_at(&pyramid, -1.0, y, card_width, card_height, gap),
+            None
+        );
+    }
+
+    #[test]
+    fn tripeaks_cards_straddle_their_covers_inside_the_canvas() {
+        let (card_width, card_height, gap) = (70, 108, 4);
+        let layout = WasteBuildLayout::TriPeaks;
+        let origin = |slot| {
+            CardthropicWindow::waste_build_slot_origin(layout, slot, card_width, card_height, gap)
+        };
+        let (canvas_width, canvas_height) =
+            CardthropicWindow::waste_build_canvas_size(layout, card_width, card_height, gap);
+        let (last_x, last_y) = origin(27);
+        assert_eq!(last_x + card_width, canvas_width);
+        assert_eq!(last_y + card_height, canvas_height);
+
+        for slot in 0..18 {
+            let [Some(left), Some(right)] = WasteBuildGame::covering_slots(layout, slot) else {
+                panic!("slot {slot} should be covered by two cards");
+            };
+            assert_eq!(origin(slot).0 * 2, origin(left).0 + origin(right).0);
+        }
+    }
+
+    #[test]
+    fn golf_hit_test_prefers_the_exposed_card() {
+        let (card_width, card_height, gap) = (70, 108, 4);
+        let layout = WasteBuildLayout::Golf;
+        let card = Card {
+            suit: Suit::Hearts,
+            rank: 9,
+            face_up: true,
+        };
+        let mut tableau = vec![Some(card); 35];
+        let (x, y) =
+            CardthropicWindow::waste_build_slot_origin(layout, 4, card_width, card_height, gap);
+        let (x, y) = (f64::from(x + 2), f64::from(y + 2));
+        assert_eq!(
+            CardthropicWindow::waste_build_slot_at(
+                layout,
+                &tableau,
+                x,
+                y,
+                card_width,
+                card_height,
+                gap
+            ),
+            Some(4)
+        );
+        tableau[4] = None;
+        assert_eq!(
+            CardthropicWindow::waste_build_slot_at(
+                layout,
+                &tableau,
+                x,
+                y,
+                card_width,
+                card_height,
+                gap
+            ),
+            Some(3)
+        );
+    }
+
     #[test]
     fn freecell_adaptive_tableau_gap_caps_at_half_card_width() {
         let gap = CardthropicWindow::adaptive_freecell_tableau_gap(2000, 8, 120, 4);
@@ -725,12 +1020,28 @@ mod tests {
             (1920, 1080, true),
             (2560, 1440, true),
         ];
-        let modes = [GameMode::Klondike, GameMode::Spider, GameMode::Freecell];
+        let modes = [
+            GameMode::Klondike,
+            GameMode::Spider,
+            GameMode::Freecell,
+            GameMode::Yukon,
+            GameMode::Pyramid,
+            GameMode::TriPeaks,
+            GameMode::Golf,
+            GameMode::FortyThieves,
+            GameMode::Scorpion,
+            GameMode::Canfield,
+            GameMode::BakersDozen,
+            GameMode::BeleagueredCastle,
+            GameMode::EightOff,
+            GameMode::SeahavenTowers,
+            GameMode::BakersGame,
+        ];
 
         for mode in modes {
             for (w, h, maximized) in cases {
-                if mode == GameMode::Freecell {
-                    for freecell_cells in FREECELL_MIN_CELL_COUNT..=FREECELL_MAX_CELL_COUNT {
+                if mode.is_freecell_family() {
+                    for freecell_cells in freecell_cell_counts(mode) {
                         let (card_width, used, available, gap) =
                             compute_desktop_metrics(mode, w, h, maximized, freecell_cells);
                         assert!(
@@ -773,12 +1084,28 @@ mod tests {
             (1920, 1080, false),
             (2560, 1440, true),
         ];
-        let modes = [GameMode::Klondike, GameMode::Spider, GameMode::Freecell];
+        let modes = [
+            GameMode::Klondike,
+            GameMode::Spider,
+            GameMode::Freecell,
+            GameMode::Yukon,
+            GameMode::Pyramid,
+            GameMode::TriPeaks,
+            GameMode::Golf,
+            GameMode::FortyThieves,
+            GameMode::Scorpion,
+            GameMode::Canfield,
+            GameMode::BakersDozen,
+            GameMode::BeleagueredCastle,
+            GameMode::EightOff,
+            GameMode::SeahavenTowers,
+            GameMode::BakersGame,
+        ];
 
         for mode in modes {
             for (w, h, maximized) in cases {
-                if mode == GameMode::Freecell {
-                    for freecell_cells in FREECELL_MIN_CELL_COUNT..=FREECELL_MAX_CELL_COUNT {
+                if mode.is_freecell_family() {
+                    for freecell_cells in freecell_cell_counts(mode) {
                         let (card_width, _, available, _) =
                             compute_desktop_metrics(mode, w, h, maximized, freecell_cells);
                         let usable = available.saturating_sub(24).max(120);
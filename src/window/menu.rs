@@ -1,4 +1,5 @@
 use super::*;
+use crate::engine::scoring;
 use crate::engine::variant::variant_for_mode;
 use crate::engine::variant_engine::engine_for_mode;
 
@@ -36,6 +37,16 @@ impl CardthropicWindow {
                 Some(action),
             );
         }
+        let spider_rules = gio::Menu::new();
+        for (rule_set, action) in [
+            (SpiderRuleSet::Spider, "win.mode-spider-rules"),
+            (SpiderRuleSet::Spiderette, "win.mode-spiderette"),
+            (SpiderRuleSet::WillOTheWisp, "win.mode-will-o-the-wisp"),
+            (SpiderRuleSet::Tarantula, "win.mode-tarantula"),
+        ] {
+            spider_rules.append(Some(rule_set.label()), Some(action));
+        }
+        spider.append_section(None, &spider_rules);
         section.append_submenu(Some("Spider"), &spider);
 
         let freecell = gio::Menu::new();
@@ -61,12 +72,40 @@ impl CardthropicWindow {
         );
         section.append_submenu(Some("FreeCell"), &freecell);
 
+        let yukon = gio::Menu::new();
+        yukon.append(Some("Yukon"), Some("win.mode-yukon"));
+        yukon.append(Some("Russian Solitaire"), Some("win.mode-yukon-russian"));
+        section.append_submenu(Some("Yukon"), &yukon);
+
+        section.append(Some("Pyramid"), Some("win.mode-pyramid"));
+        section.append(Some("TriPeaks"), Some("win.mode-tripeaks"));
+        section.append(Some("Golf"), Some("win.mode-golf"));
+        section.append(Some("Forty Thieves"), Some("win.mode-forty-thieves"));
+        section.append(Some("Scorpion"), Some("win.mode-scorpion"));
+        section.append(Some("Canfield"), Some("win.mode-canfield"));
+        section.append(Some("Baker's Dozen"), Some("win.mode-bakers-dozen"));
+        section.append(
+            Some("Beleaguered Castle"),
+            Some("win.mode-beleaguered-castle"),
+        );
+        section.append(Some("Eight Off"), Some("win.mode-eight-off"));
+        section.append(Some("Seahaven Towers"), Some("win.mode-seahaven-towers"));
+        section.append(Some("Baker's Game"), Some("win.mode-bakers-game"));
+
         let chess = gio::Menu::new();
         chess.append(
             Some("Standard Chess (Preview)"),
             Some("win.mode-chess-standard"),
         );
         chess.append(Some("Chess960 (Preview)"), Some("win.mode-chess-960"));
+        chess.append(
+            Some("Chess960 Start Position…"),
+            Some("win.chess960-start-position-dialog"),
+        );
+        chess.append(
+            Some("Double Fischer Random (Preview)"),
+            Some("win.mode-chess-960-double"),
+        );
         chess.append(
             Some("Atomic Chess (Preview)"),
             Some("win.mode-chess-atomic"),
@@ -125,7 +164,10 @@ impl CardthropicWindow {
             Some("win.check-seed-winnable"),
         );
         deal.append(Some("Copy Game State"), Some("win.copy-game-state"));
+        deal.append(Some("Copy Board as Text"), Some("win.copy-board-text"));
         deal.append(Some("Load Game State"), Some("win.paste-game-state"));
+        deal.append(Some("Deal Editor"), Some("win.deal-editor"));
+        deal.append(Some("Replay Viewer"), Some("win.replay-viewer"));
         deal.append(Some("Insert Note"), Some("win.insert-note"));
         deal.append(Some("Clear Seed History"), Some("win.clear-seed-history"));
         section.append_submenu(Some("Game State"), &deal);
@@ -190,6 +232,8 @@ impl CardthropicWindow {
         view_help.append(Some("Custom CSS"), Some("win.open-custom-css"));
         view_help.append(Some("Status History"), Some("win.status-history"));
         view_help.append(Some("APM Graph"), Some("win.apm-graph"));
+        view_help.append(Some("Statistics"), Some("win.statistics"));
+        view_help.append(Some("Daily Challenge"), Some("win.daily-challenge"));
         section.append_submenu(Some("View"), &view_help);
 
         section.append(Some("Command Palette"), Some("win.command-search"));
@@ -243,6 +287,23 @@ impl CardthropicWindow {
                     self.set_freecell_card_count_mode(mode, true);
                 }
             }
+            GameMode::Yukon => {
+                let rule_sets = [YukonRuleSet::Yukon, YukonRuleSet::Russian];
+                if let Some(rule_set) = rule_sets.get(idx).copied() {
+                    self.set_yukon_rule_set(rule_set);
+                }
+            }
+            GameMode::Pyramid
+            | GameMode::TriPeaks
+            | GameMode::Golf
+            | GameMode::FortyThieves
+            | GameMode::Scorpion
+            | GameMode::Canfield
+            | GameMode::BakersDozen
+            | GameMode::BeleagueredCastle
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => {}
         }
         self.refresh_main_menu_model();
     }
@@ -315,6 +376,44 @@ impl CardthropicWindow {
                 suit_row.append(&button);
             }
             content_box.append(&suit_row);
+
+            let rules_label = gtk::Label::new(Some("Rules"));
+            rules_label.set_xalign(0.0);
+            rules_label.add_css_class("dim-label");
+            content_box.append(&rules_label);
+
+            let rules_row = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+            rules_row.set_hexpand(true);
+            let current_rule_set = self.current_spider_rule_set();
+            let mut group_anchor: Option<gtk::CheckButton> = None;
+
+            for rule_set in [
+                SpiderRuleSet::Spider,
+                SpiderRuleSet::Spiderette,
+                SpiderRuleSet::WillOTheWisp,
+                SpiderRuleSet::Tarantula,
+            ] {
+                let button = gtk::CheckButton::with_label(rule_set.label());
+                if let Some(anchor) = group_anchor.as_ref() {
+                    button.set_group(Some(anchor));
+                } else {
+                    group_anchor = Some(button.clone());
+                }
+                if rule_set == current_rule_set {
+                    button.set_active(true);
+                }
+                button.connect_toggled(glib::clone!(
+                    #[weak(rename_to = window)]
+                    self,
+                    move |btn| {
+                        if btn.is_active() {
+                            window.set_spider_rule_set(rule_set);
+                        }
+                    }
+                ));
+                rules_row.append(&button);
+            }
+            content_box.append(&rules_row);
         } else if mode == GameMode::Freecell {
             let card_count_label = gtk::Label::new(Some("Card Count"));
             card_count_label.set_xalign(0.0);
@@ -354,6 +453,74 @@ impl CardthropicWindow {
                 card_count_row.append(&button);
             }
             content_box.append(&card_count_row);
+        } else if mode == GameMode::Yukon {
+            let rules_label = gtk::Label::new(Some("Building"));
+            rules_label.set_xalign(0.0);
+            rules_label.add_css_class("dim-label");
+            content_box.append(&rules_label);
+
+            let rules_row = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+            rules_row.set_hexpand(true);
+            let current_rule_set = self.current_yukon_rule_set();
+            let mut group_anchor: Option<gtk::CheckButton> = None;
+
+            for (rule_set, label) in [
+                (YukonRuleSet::Yukon, "Alternate Colors"),
+                (YukonRuleSet::Russian, "Same Suit (Russian)"),
+            ] {
+                let button = gtk::CheckButton::with_label(label);
+                if let Some(anchor) = group_anchor.as_ref() {
+                    button.set_group(Some(anchor));
+                } else {
+                    group_anchor = Some(button.clone());
+                }
+                if rule_set == current_rule_set {
+                    button.set_active(true);
+                }
+                button.connect_toggled(glib::clone!(
+                    #[weak(rename_to = window)]
+                    self,
+                    move |btn| {
+                        if btn.is_active() {
+                            window.set_yukon_rule_set(rule_set);
+                        }
+                    }
+                ));
+                rules_row.append(&button);
+            }
+            content_box.append(&rules_row);
+        } else if let Some(layout) = WasteBuildLayout::for_mode(mode) {
+            let rules_label = gtk::Label::new(Some("Rules"));
+            rules_label.set_xalign(0.0);
+            rules_label.add_css_class("dim-label");
+            content_box.append(&rules_label);
+
+            let rules_row = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+            rules_row.set_hexpand(true);
+            let rules = self.current_waste_build_rules(layout);
+
+            let wrap_button = gtk::CheckButton::with_label("Wrap King\u{2013}Ace");
+            wrap_button.set_active(rules.wrap_ranks);
+            wrap_button.connect_toggled(glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                move |btn| {
+                    window.set_waste_build_wrap_ranks(layout, btn.is_active());
+                }
+            ));
+            rules_row.append(&wrap_button);
+
+            let scoring_button = gtk::CheckButton::with_label("Streak Scoring");
+            scoring_button.set_active(rules.streak_scoring);
+            scoring_button.connect_toggled(glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                move |btn| {
+                    window.set_waste_build_streak_scoring(layout, btn.is_active());
+                }
+            ));
+            rules_row.append(&scoring_button);
+            content_box.append(&rules_row);
         } else if caps.draw_mode_selection {
             let draw_label = gtk::Label::new(Some("Deal"));
             draw_label.set_xalign(0.0);
@@ -396,6 +563,54 @@ impl CardthropicWindow {
                 draw_row.append(&button);
             }
             content_box.append(&draw_row);
+
+            let passes_label = gtk::Label::new(Some("Passes"));
+            passes_label.set_xalign(0.0);
+            passes_label.add_css_class("dim-label");
+            content_box.append(&passes_label);
+
+            let passes_row = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+            passes_row.set_hexpand(true);
+            let current_pass_limit = self.current_klondike_pass_limit();
+            let mut group_anchor: Option<gtk::CheckButton> = None;
+
+            for pass_limit in [
+                KlondikePassLimit::Unlimited,
+                KlondikePassLimit::Three,
+                KlondikePassLimit::Single,
+            ] {
+                let button = gtk::CheckButton::with_label(pass_limit.label());
+                if let Some(anchor) = group_anchor.as_ref() {
+                    button.set_group(Some(anchor));
+                } else {
+                    group_anchor = Some(button.clone());
+                }
+                if pass_limit == current_pass_limit {
+                    button.set_active(true);
+                }
+                button.connect_toggled(glib::clone!(
+                    #[weak(rename_to = window)]
+                    self,
+                    move |btn| {
+                        if btn.is_active() {
+                            window.set_klondike_pass_limit(pass_limit);
+                        }
+                    }
+                ));
+                passes_row.append(&button);
+            }
+            content_box.append(&passes_row);
+
+            let thoughtful_button = gtk::CheckButton::with_label("Thoughtful (all cards face up)");
+            thoughtful_button.set_active(self.current_klondike_thoughtful());
+            thoughtful_button.connect_toggled(glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                move |btn| {
+                    window.set_klondike_thoughtful(btn.is_active());
+                }
+            ));
+            content_box.append(&thoughtful_button);
         } else {
             let note = gtk::Label::new(Some(spec.settings_placeholder));
             note.set_xalign(0.0);
@@ -403,6 +618,41 @@ impl CardthropicWindow {
             note.add_css_class("dim-label");
             content_box.append(&note);
         }
+
+        if scoring::mode_is_scored(mode) {
+            let scoring_label = gtk::Label::new(Some("Scoring"));
+            scoring_label.set_xalign(0.0);
+            scoring_label.add_css_class("dim-label");
+            content_box.append(&scoring_label);
+
+            let scoring_row = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+            scoring_row.set_hexpand(true);
+            let current_system = self.scoring_system();
+            let mut group_anchor: Option<gtk::CheckButton> = None;
+
+            for system in ScoringSystem::ALL {
+                let button = gtk::CheckButton::with_label(system.label());
+                if let Some(anchor) = group_anchor.as_ref() {
+                    button.set_group(Some(anchor));
+                } else {
+                    group_anchor = Some(button.clone());
+                }
+                if system == current_system {
+                    button.set_active(true);
+                }
+                button.connect_toggled(glib::clone!(
+                    #[weak(rename_to = window)]
+                    self,
+                    move |btn| {
+                        if btn.is_active() {
+                            window.set_scoring_system(system, true);
+                        }
+                    }
+                ));
+                scoring_row.append(&button);
+            }
+            content_box.append(&scoring_row);
+        }
     }
 
     pub(super) fn update_game_settings_menu(&self) {
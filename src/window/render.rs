@@ -3,7 +3,11 @@ use crate::engine::boundary;
 use crate::engine::render_plan;
 use crate::engine::status_text;
 use crate::engine::variant_engine::engine_for_mode;
-use crate::game::{FreecellGame, SpiderGame};
+use crate::game::{
+    rank_label, CanfieldGame, FortyThievesGame, FreecellBuild, FreecellEmptyColumn, FreecellGame,
+    OpenTableauGame, OpenTableauLayout, PyramidGame, ScorpionGame, SpiderGame, WasteBuildGame,
+    WasteBuildLayout, YukonGame, YukonRuleSet,
+};
 use crate::startup_trace;
 use sourceview5::prelude::*;
 use std::time::Instant;
@@ -50,7 +54,11 @@ impl CardthropicWindow {
 
     fn current_variant_name(&self) -> String {
         if self.imp().chess_mode_active.get() {
-            return self.imp().chess_variant.get().label().to_string();
+            let label = self.imp().chess_variant.get().label();
+            return match self.chess960_start_number_label() {
+                Some(start_label) => format!("{label} {start_label}"),
+                None => label.to_string(),
+            };
         }
         match self.active_game_mode() {
             GameMode::Klondike => {
@@ -70,6 +78,35 @@ impl CardthropicWindow {
                 self.current_freecell_card_count_mode().card_count(),
                 self.current_freecell_cell_count()
             ),
+            GameMode::Yukon => match self.current_yukon_rule_set() {
+                YukonRuleSet::Yukon => "Yukon".to_string(),
+                YukonRuleSet::Russian => "Russian Solitaire".to_string(),
+            },
+            GameMode::Pyramid => "Pyramid".to_string(),
+            GameMode::FortyThieves => "Forty Thieves".to_string(),
+            GameMode::Scorpion => "Scorpion".to_string(),
+            GameMode::Canfield => "Canfield".to_string(),
+            GameMode::BakersDozen => "Baker's Dozen".to_string(),
+            GameMode::BeleagueredCastle => "Beleaguered Castle".to_string(),
+            GameMode::EightOff => "Eight Off".to_string(),
+            GameMode::SeahavenTowers => "Seahaven Towers".to_string(),
+            GameMode::BakersGame => "Baker's Game".to_string(),
+            GameMode::TriPeaks | GameMode::Golf => {
+                let mode = self.active_game_mode();
+                let wrap = WasteBuildLayout::for_mode(mode).is_some_and(|layout| {
+                    self.imp()
+                        .game
+                        .borrow()
+                        .waste_build(layout)
+                        .rules()
+                        .wrap_ranks
+                });
+                if wrap {
+                    format!("{} (K\u{2013}A wrap)", mode.label())
+                } else {
+                    mode.label().to_string()
+                }
+            }
         }
     }
 
@@ -550,6 +587,20 @@ impl CardthropicWindow {
         startup_trace::mark_once("render:first-enter");
         let render_started = Instant::now();
         self.refresh_window_title();
+        if let Some(canvas) = self.tableau_stacks().first() {
+            for mode in [GameMode::Pyramid, GameMode::TriPeaks, GameMode::Golf] {
+                let class = Self::slot_canvas_css_class(mode);
+                if canvas.has_css_class(class)
+                    && (self.imp().chess_mode_active.get() || self.active_game_mode() != mode)
+                {
+                    // Slot-canvas pictures are slot-addressed and partly
+                    // hidden; drop them so other layouts start from fresh
+                    // widgets.
+                    canvas.remove_css_class(class);
+                    self.clear_tableau_render_state_for_chess();
+                }
+            }
+        }
         if self.imp().chess_mode_active.get() {
             self.render_chess_board();
             self.record_render_timing(render_started.elapsed());
@@ -558,6 +609,7 @@ impl CardthropicWindow {
         }
         self.clear_chess_board_rotation_transform();
         self.imp().chess_drag_hover_row_from_top.set(None);
+        self.imp().chess_blast_preview_target.set(None);
         let was_chess_surface = self
             .imp()
             .tableau_frame
@@ -589,12 +641,57 @@ impl CardthropicWindow {
                 startup_trace::mark_once("render:first-exit");
                 return;
             }
-            GameMode::Freecell => {
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => {
                 self.render_freecell();
                 self.record_render_timing(render_started.elapsed());
                 startup_trace::mark_once("render:first-exit");
                 return;
             }
+            GameMode::Yukon => {
+                self.render_yukon();
+                self.record_render_timing(render_started.elapsed());
+                startup_trace::mark_once("render:first-exit");
+                return;
+            }
+            GameMode::Pyramid => {
+                self.render_pyramid();
+                self.record_render_timing(render_started.elapsed());
+                startup_trace::mark_once("render:first-exit");
+                return;
+            }
+            GameMode::TriPeaks | GameMode::Golf => {
+                self.render_waste_build();
+                self.record_render_timing(render_started.elapsed());
+                startup_trace::mark_once("render:first-exit");
+                return;
+            }
+            GameMode::FortyThieves => {
+                self.render_forty_thieves();
+                self.record_render_timing(render_started.elapsed());
+                startup_trace::mark_once("render:first-exit");
+                return;
+            }
+            GameMode::Scorpion => {
+                self.render_scorpion();
+                self.record_render_timing(render_started.elapsed());
+                startup_trace::mark_once("render:first-exit");
+                return;
+            }
+            GameMode::Canfield => {
+                self.render_canfield();
+                self.record_render_timing(render_started.elapsed());
+                startup_trace::mark_once("render:first-exit");
+                return;
+            }
+            GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                self.render_open_tableau();
+                self.record_render_timing(render_started.elapsed());
+                startup_trace::mark_once("render:first-exit");
+                return;
+            }
             GameMode::Klondike => {}
         }
 
@@ -637,8 +734,12 @@ impl CardthropicWindow {
             }
         }
 
-        imp.stock_label
-            .set_label(&render_plan::card_count_label(game.stock_len()));
+        if game.passes_exhausted() {
+            imp.stock_label.set_label("No more passes");
+        } else {
+            imp.stock_label
+                .set_label(&render_plan::card_count_label(game.stock_len()));
+        }
 
         imp.waste_label
             .set_label(&render_plan::card_count_label(game.waste_len()));
@@ -902,9 +1003,15 @@ impl CardthropicWindow {
         let status = if let Some(message) = imp.status_override.borrow().as_deref() {
             message.to_string()
         } else if freecell.is_won() {
-            format!("FreeCell won with {freecell_cells} free cells. All foundations complete.")
+            format!(
+                "{} won with {freecell_cells} free cells. All foundations complete.",
+                mode.label()
+            )
         } else if freecell.is_lost() {
-            format!("FreeCell blocked ({freecell_cells} free cells). No legal moves remain.")
+            format!(
+                "{} blocked ({freecell_cells} free cells). No legal moves remain.",
+                mode.label()
+            )
         } else if let Some(slot) = imp.selected_freecell.get() {
             format!(
                 "Selected free cell F{}/{}. Click a tableau column or foundation to move it.",
@@ -931,8 +1038,23 @@ impl CardthropicWindow {
                 )
             }
         } else if show_controls_hint {
+            let rules = freecell.rules();
+            let build_hint = match rules.build {
+                FreecellBuild::AlternateColour => "build descending alternating cascades",
+                FreecellBuild::Suit => "build down in suit",
+            };
+            let empty_column_hint = match rules.empty_column {
+                FreecellEmptyColumn::AnyCard => "",
+                FreecellEmptyColumn::KingsOnly => ", fill empty columns with kings only",
+            };
+            let supermove_hint = if rules.supermoves {
+                ""
+            } else {
+                ", move one card at a time"
+            };
             format!(
-                "FreeCell controls: build descending alternating cascades, clear to foundations, use {freecell_cells} free cells as buffers. Keyboard: arrows move focus, Enter activates."
+                "{} controls: {build_hint}{empty_column_hint}{supermove_hint}, clear to foundations, use {freecell_cells} free cells as buffers. Keyboard: arrows move focus, Enter activates.",
+                mode.label()
             )
         } else {
             String::new()
@@ -945,68 +1067,813 @@ impl CardthropicWindow {
         self.mark_session_dirty();
     }
 
-    pub(super) fn flash_smart_move_fail_tableau_run(&self, col: usize, start: usize) {
+    fn render_yukon(&self) {
         let imp = self.imp();
-        let previous_selected = *imp.selected_run.borrow();
-        let previous_waste_selected = imp.waste_selected.get();
+        imp.stock_picture.set_visible(false);
+        imp.stock_column_box.set_visible(false);
+        imp.stock_label.set_visible(false);
+        imp.stock_heading_box.set_visible(false);
+        imp.waste_overlay.set_visible(false);
+        imp.waste_column_box.set_visible(false);
+        imp.waste_label.set_visible(false);
+        imp.waste_heading_box.set_visible(false);
+        imp.top_row_spacer_box.set_visible(false);
+        imp.stock_waste_foundation_spacer_box.set_visible(false);
+        imp.selected_freecell.set(None);
+        imp.foundations_heading_box.set_visible(true);
+        imp.foundations_heading_box.set_halign(gtk::Align::Start);
+        imp.foundations_heading_box.set_margin_start(0);
+        imp.foundations_heading_label.set_label("Foundations");
+        imp.foundations_heading_label.set_xalign(0.0);
+        imp.foundations_heading_label.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_margin_start(0);
+        imp.foundations_area_box.set_visible(true);
+        let mode = self.active_game_mode();
+        let caps = engine_for_mode(mode).capabilities();
+        let yukon = imp.game.borrow().yukon().clone();
+        if yukon.is_won() && imp.timer_started.get() {
+            imp.timer_started.set(false);
+        }
+        self.note_current_seed_win_if_needed();
 
-        *imp.selected_run.borrow_mut() = Some(SelectedRun { col, start });
+        let selected_snapshot = imp.selected_run.try_borrow().ok().and_then(|run| *run);
+        let selected = selected_snapshot.and_then(|run| {
+            yukon
+                .tableau_card(run.col, run.start)
+                .filter(|card| card.face_up)
+                .map(|_| run)
+        });
+        if let Ok(mut selected_run) = imp.selected_run.try_borrow_mut() {
+            *selected_run = selected;
+        }
         imp.waste_selected.set(false);
-        self.render();
 
-        glib::timeout_add_local_once(
-            Duration::from_millis(100),
-            glib::clone!(
-                #[weak(rename_to = window)]
-                self,
-                move || {
-                    let imp = window.imp();
-                    let current = *imp.selected_run.borrow();
-                    if current == Some(SelectedRun { col, start }) {
-                        *imp.selected_run.borrow_mut() = previous_selected;
-                        imp.waste_selected.set(previous_waste_selected);
-                        window.render();
-                    }
-                }
-            ),
-        );
+        self.render_card_images_yukon(&yukon);
+
+        let controls =
+            render_plan::plan_controls(caps, imp.history.borrow().len(), imp.future.borrow().len());
+        imp.undo_button.set_sensitive(controls.undo_enabled);
+        imp.redo_button.set_sensitive(controls.redo_enabled);
+        imp.auto_hint_button
+            .set_sensitive(controls.auto_hint_enabled);
+        imp.cyclone_shuffle_button
+            .set_sensitive(controls.cyclone_enabled);
+        imp.peek_button.set_sensitive(controls.peek_enabled);
+        imp.robot_button.set_sensitive(controls.robot_enabled);
+        imp.seed_random_button
+            .set_sensitive(controls.seed_random_enabled);
+        imp.seed_rescue_button
+            .set_sensitive(controls.seed_rescue_enabled);
+        imp.seed_winnable_button
+            .set_sensitive(controls.seed_winnable_enabled);
+        imp.seed_repeat_button
+            .set_sensitive(controls.seed_repeat_enabled);
+        imp.seed_go_button.set_sensitive(controls.seed_go_enabled);
+        imp.seed_combo.set_sensitive(controls.seed_combo_enabled);
+
+        self.update_keyboard_focus_style();
+        let show_controls_hint = imp.pending_deal_instructions.replace(false);
+        let variant_name = self.current_variant_name();
+        let status = if let Some(message) = imp.status_override.borrow().as_deref() {
+            message.to_string()
+        } else if yukon.is_won() {
+            format!("{variant_name} won! All foundations complete.")
+        } else if yukon.is_lost() {
+            format!("{variant_name} blocked. No legal moves remain.")
+        } else if let Some(run) = selected {
+            let amount = yukon
+                .tableau()
+                .get(run.col)
+                .map(Vec::len)
+                .unwrap_or(0)
+                .saturating_sub(run.start);
+            if amount > 1 {
+                format!(
+                    "Selected {amount} cards from T{}. Click another tableau to move them together.",
+                    run.col + 1
+                )
+            } else {
+                format!(
+                    "Selected tableau T{}. Click a tableau or foundation to move it.",
+                    run.col + 1
+                )
+            }
+        } else if show_controls_hint {
+            let building = match yukon.rule_set() {
+                YukonRuleSet::Yukon => "alternating colors",
+                YukonRuleSet::Russian => "the same suit",
+            };
+            format!(
+                "{variant_name} controls: any face-up card moves with everything on top of it. Build down in {building}; only Kings fill empty columns. Keyboard: arrows move focus, Enter activates."
+            )
+        } else {
+            String::new()
+        };
+        self.append_status_line(&status);
+
+        self.apply_mobile_phone_mode_overrides();
+        self.update_tableau_overflow_hints();
+        self.update_stats_label();
+        self.mark_session_dirty();
     }
 
-    pub(super) fn flash_smart_move_fail_waste_top(&self) {
+    fn render_pyramid(&self) {
         let imp = self.imp();
-        let game = imp.game.borrow();
-        let show_count = render_plan::waste_visible_count(game.draw_mode(), game.waste_len());
-        if show_count == 0 {
-            return;
+        imp.stock_picture.set_visible(true);
+        imp.stock_column_box.set_visible(true);
+        imp.stock_label.set_visible(false);
+        imp.stock_heading_box.set_visible(true);
+        imp.stock_heading_label.set_label("Stock");
+        imp.waste_overlay.set_visible(true);
+        imp.waste_column_box.set_visible(true);
+        imp.waste_label.set_visible(false);
+        imp.waste_heading_box.set_visible(true);
+        imp.waste_heading_label.set_label("Waste");
+        imp.top_row_spacer_box.set_visible(false);
+        imp.stock_waste_foundation_spacer_box.set_visible(false);
+        imp.selected_freecell.set(None);
+        imp.foundations_heading_box.set_visible(true);
+        imp.foundations_heading_box.set_halign(gtk::Align::Start);
+        imp.foundations_heading_box.set_margin_start(0);
+        imp.foundations_heading_label.set_label("Removed");
+        imp.foundations_heading_label.set_xalign(0.0);
+        imp.foundations_heading_label.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_margin_start(0);
+        imp.foundations_area_box.set_visible(true);
+        let mode = self.active_game_mode();
+        let caps = engine_for_mode(mode).capabilities();
+        let pyramid = imp.game.borrow().pyramid().clone();
+        if pyramid.is_won() && imp.timer_started.get() {
+            imp.timer_started.set(false);
         }
-        drop(game);
+        self.note_current_seed_win_if_needed();
 
-        let previous_selected = *imp.selected_run.borrow();
-        let previous_waste_selected = imp.waste_selected.get();
+        // Pyramid selection reuses the tableau selection with `start` holding
+        // the slot; only available cards stay selected.
+        let selected_snapshot = imp.selected_run.try_borrow().ok().and_then(|run| *run);
+        let selected = selected_snapshot
+            .filter(|run| run.col == 0 && pyramid.is_available(PyramidCardRef::Pyramid(run.start)));
+        if let Ok(mut selected_run) = imp.selected_run.try_borrow_mut() {
+            *selected_run = selected;
+        }
+        if imp.waste_selected.get() && pyramid.waste_top().is_none() {
+            imp.waste_selected.set(false);
+        }
 
-        *imp.selected_run.borrow_mut() = None;
-        imp.waste_selected.set(true);
-        self.render();
+        self.render_card_images_pyramid(&pyramid);
 
-        glib::timeout_add_local_once(
-            Duration::from_millis(100),
-            glib::clone!(
-                #[weak(rename_to = window)]
-                self,
-                move || {
-                    let imp = window.imp();
-                    if imp.waste_selected.get() {
-                        *imp.selected_run.borrow_mut() = previous_selected;
-                        imp.waste_selected.set(previous_waste_selected);
-                        window.render();
-                    }
-                }
-            ),
-        );
+        let controls =
+            render_plan::plan_controls(caps, imp.history.borrow().len(), imp.future.borrow().len());
+        imp.undo_button.set_sensitive(controls.undo_enabled);
+        imp.redo_button.set_sensitive(controls.redo_enabled);
+        imp.auto_hint_button
+            .set_sensitive(controls.auto_hint_enabled);
+        imp.cyclone_shuffle_button
+            .set_sensitive(controls.cyclone_enabled);
+        imp.peek_button.set_sensitive(controls.peek_enabled);
+        imp.robot_button.set_sensitive(controls.robot_enabled);
+        imp.seed_random_button
+            .set_sensitive(controls.seed_random_enabled);
+        imp.seed_rescue_button
+            .set_sensitive(controls.seed_rescue_enabled);
+        imp.seed_winnable_button
+            .set_sensitive(controls.seed_winnable_enabled);
+        imp.seed_repeat_button
+            .set_sensitive(controls.seed_repeat_enabled);
+        imp.seed_go_button.set_sensitive(controls.seed_go_enabled);
+        imp.seed_combo.set_sensitive(controls.seed_combo_enabled);
+
+        self.update_keyboard_focus_style();
+        let show_controls_hint = imp.pending_deal_instructions.replace(false);
+        let selected_card = if imp.waste_selected.get() {
+            pyramid.waste_top()
+        } else {
+            selected.and_then(|run| pyramid.pyramid_card(run.start))
+        };
+        let status = if let Some(message) = imp.status_override.borrow().as_deref() {
+            message.to_string()
+        } else if pyramid.is_won() {
+            "Pyramid won! Every pyramid card is cleared.".to_string()
+        } else if pyramid.is_lost() {
+            "Pyramid blocked. No pairs remain and the stock is spent.".to_string()
+        } else if let Some(card) = selected_card {
+            format!(
+                "Selected {}. Click a card that adds up to 13 with it.",
+                card.label()
+            )
+        } else if show_controls_hint {
+            format!(
+                "Pyramid controls: remove uncovered pairs that add up to 13 (J=11, Q=12, K=13 alone). The waste pass can be recycled {} times. Keyboard: arrows move focus, Enter activates.",
+                crate::game::PYRAMID_MAX_RECYCLES
+            )
+        } else {
+            String::new()
+        };
+        self.append_status_line(&status);
+
+        self.apply_mobile_phone_mode_overrides();
+        self.update_tableau_overflow_hints();
+        self.update_stats_label();
+        self.mark_session_dirty();
     }
 
-    pub(super) fn render_card_images(&self, game: &KlondikeGame) {
-        startup_trace::mark_once("render:first-images-enter");
+    fn render_waste_build(&self) {
+        let imp = self.imp();
+        imp.stock_picture.set_visible(true);
+        imp.stock_column_box.set_visible(true);
+        imp.stock_label.set_visible(false);
+        imp.stock_heading_box.set_visible(true);
+        imp.stock_heading_label.set_label("Stock");
+        imp.waste_overlay.set_visible(true);
+        imp.waste_column_box.set_visible(true);
+        imp.waste_label.set_visible(false);
+        imp.waste_heading_box.set_visible(true);
+        imp.waste_heading_label.set_label("Waste");
+        imp.top_row_spacer_box.set_visible(false);
+        imp.stock_waste_foundation_spacer_box.set_visible(false);
+        imp.selected_freecell.set(None);
+        imp.waste_selected.set(false);
+        if let Ok(mut selected_run) = imp.selected_run.try_borrow_mut() {
+            *selected_run = None;
+        }
+        let mode = self.active_game_mode();
+        let Some(layout) = WasteBuildLayout::for_mode(mode) else {
+            return;
+        };
+        let caps = engine_for_mode(mode).capabilities();
+        let game = imp.game.borrow().waste_build(layout).clone();
+        if game.is_won() && imp.timer_started.get() {
+            imp.timer_started.set(false);
+        }
+        self.note_current_seed_win_if_needed();
+
+        // The score rides in the foundations heading; the slots stay hidden.
+        let rules = game.rules();
+        imp.foundations_heading_box
+            .set_visible(rules.streak_scoring);
+        imp.foundations_heading_box.set_halign(gtk::Align::Start);
+        imp.foundations_heading_box.set_margin_start(0);
+        imp.foundations_heading_label.set_label(&format!(
+            "Score {}  Streak {}  Best {}",
+            game.score(),
+            game.streak(),
+            game.best_streak()
+        ));
+        imp.foundations_heading_label.set_xalign(0.0);
+        imp.foundations_heading_label.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_visible(false);
+
+        self.render_card_images_waste_build(&game);
+
+        let controls =
+            render_plan::plan_controls(caps, imp.history.borrow().len(), imp.future.borrow().len());
+        imp.undo_button.set_sensitive(controls.undo_enabled);
+        imp.redo_button.set_sensitive(controls.redo_enabled);
+        imp.auto_hint_button
+            .set_sensitive(controls.auto_hint_enabled);
+        imp.cyclone_shuffle_button
+            .set_sensitive(controls.cyclone_enabled);
+        imp.peek_button.set_sensitive(controls.peek_enabled);
+        imp.robot_button.set_sensitive(controls.robot_enabled);
+        imp.seed_random_button
+            .set_sensitive(controls.seed_random_enabled);
+        imp.seed_rescue_button
+            .set_sensitive(controls.seed_rescue_enabled);
+        imp.seed_winnable_button
+            .set_sensitive(controls.seed_winnable_enabled);
+        imp.seed_repeat_button
+            .set_sensitive(controls.seed_repeat_enabled);
+        imp.seed_go_button.set_sensitive(controls.seed_go_enabled);
+        imp.seed_combo.set_sensitive(controls.seed_combo_enabled);
+
+        self.update_keyboard_focus_style();
+        let show_controls_hint = imp.pending_deal_instructions.replace(false);
+        let label = mode.label();
+        let status = if let Some(message) = imp.status_override.borrow().as_deref() {
+            message.to_string()
+        } else if game.is_won() && rules.streak_scoring {
+            format!(
+                "{label} won with {} points! Every tableau card is on the waste.",
+                game.score()
+            )
+        } else if game.is_won() {
+            format!("{label} won! Every tableau card is on the waste.")
+        } else if game.is_lost() {
+            format!("{label} blocked. Nothing connects to the waste and the stock is spent.")
+        } else if show_controls_hint {
+            let wrap = if rules.wrap_ranks {
+                ", King and Ace connect"
+            } else if layout == WasteBuildLayout::Golf {
+                ", nothing goes on a King"
+            } else {
+                ""
+            };
+            format!(
+                "{label} controls: play uncovered cards one rank above or below the waste card, any suit{wrap}. The stock deals once. Keyboard: arrows move focus, Enter activates."
+            )
+        } else {
+            String::new()
+        };
+        self.append_status_line(&status);
+
+        self.apply_mobile_phone_mode_overrides();
+        self.update_tableau_overflow_hints();
+        self.update_stats_label();
+        self.mark_session_dirty();
+    }
+
+    fn render_forty_thieves(&self) {
+        let imp = self.imp();
+        imp.stock_picture.set_visible(true);
+        imp.stock_column_box.set_visible(true);
+        imp.stock_label.set_visible(false);
+        imp.stock_heading_box.set_visible(true);
+        imp.stock_heading_label.set_label("Stock");
+        imp.waste_overlay.set_visible(true);
+        imp.waste_column_box.set_visible(true);
+        imp.waste_label.set_visible(false);
+        imp.waste_heading_box.set_visible(true);
+        imp.waste_heading_label.set_label("Waste");
+        imp.top_row_spacer_box.set_visible(false);
+        imp.stock_waste_foundation_spacer_box.set_visible(false);
+        imp.selected_freecell.set(None);
+        imp.foundations_heading_box.set_visible(true);
+        imp.foundations_heading_box.set_halign(gtk::Align::Start);
+        imp.foundations_heading_box.set_margin_start(0);
+        imp.foundations_heading_label.set_label("Foundations");
+        imp.foundations_heading_label.set_xalign(0.0);
+        imp.foundations_heading_label.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_margin_start(0);
+        imp.foundations_area_box.set_visible(true);
+        let mode = self.active_game_mode();
+        let caps = engine_for_mode(mode).capabilities();
+        let game = imp.game.borrow().forty_thieves().clone();
+        if game.is_won() && imp.timer_started.get() {
+            imp.timer_started.set(false);
+        }
+        self.note_current_seed_win_if_needed();
+
+        let selected_snapshot = imp.selected_run.try_borrow().ok().and_then(|run| *run);
+        let selected =
+            selected_snapshot.filter(|run| game.tableau_card(run.col, run.start).is_some());
+        if let Ok(mut selected_run) = imp.selected_run.try_borrow_mut() {
+            *selected_run = selected;
+        }
+        if imp.waste_selected.get() && game.waste_top().is_none() {
+            imp.waste_selected.set(false);
+        }
+
+        self.render_card_images_forty_thieves(&game);
+
+        let controls =
+            render_plan::plan_controls(caps, imp.history.borrow().len(), imp.future.borrow().len());
+        imp.undo_button.set_sensitive(controls.undo_enabled);
+        imp.redo_button.set_sensitive(controls.redo_enabled);
+        imp.auto_hint_button
+            .set_sensitive(controls.auto_hint_enabled);
+        imp.cyclone_shuffle_button
+            .set_sensitive(controls.cyclone_enabled);
+        imp.peek_button.set_sensitive(controls.peek_enabled);
+        imp.robot_button.set_sensitive(controls.robot_enabled);
+        imp.seed_random_button
+            .set_sensitive(controls.seed_random_enabled);
+        imp.seed_rescue_button
+            .set_sensitive(controls.seed_rescue_enabled);
+        imp.seed_winnable_button
+            .set_sensitive(controls.seed_winnable_enabled);
+        imp.seed_repeat_button
+            .set_sensitive(controls.seed_repeat_enabled);
+        imp.seed_go_button.set_sensitive(controls.seed_go_enabled);
+        imp.seed_combo.set_sensitive(controls.seed_combo_enabled);
+
+        self.update_keyboard_focus_style();
+        let show_controls_hint = imp.pending_deal_instructions.replace(false);
+        let status = if let Some(message) = imp.status_override.borrow().as_deref() {
+            message.to_string()
+        } else if game.is_won() {
+            "Forty Thieves won! All eight foundations are complete.".to_string()
+        } else if game.is_lost() {
+            "Forty Thieves blocked. No legal moves remain and the stock is spent.".to_string()
+        } else if imp.waste_selected.get() {
+            "Selected waste. Click a tableau column or foundation to move it.".to_string()
+        } else if let Some(run) = selected {
+            let amount = game
+                .tableau()
+                .get(run.col)
+                .map(Vec::len)
+                .unwrap_or(0)
+                .saturating_sub(run.start);
+            if amount > 1 {
+                format!(
+                    "Selected {amount} cards from T{}. Click another tableau to move this run.",
+                    run.col + 1
+                )
+            } else {
+                format!(
+                    "Selected tableau T{}. Click a tableau or foundation to move it.",
+                    run.col + 1
+                )
+            }
+        } else if show_controls_hint {
+            "Forty Thieves controls: build down in suit, one card at a time (runs move only as far as empty columns allow). The stock deals once. Keyboard: arrows move focus, Enter activates."
+                .to_string()
+        } else {
+            String::new()
+        };
+        self.append_status_line(&status);
+
+        self.apply_mobile_phone_mode_overrides();
+        self.update_tableau_overflow_hints();
+        self.update_stats_label();
+        self.mark_session_dirty();
+    }
+
+    fn render_scorpion(&self) {
+        let imp = self.imp();
+        imp.stock_picture.set_visible(true);
+        imp.stock_column_box.set_visible(true);
+        imp.stock_label.set_visible(false);
+        imp.stock_heading_box.set_visible(true);
+        imp.stock_heading_label.set_label("Reserve");
+        imp.waste_overlay.set_visible(false);
+        imp.waste_column_box.set_visible(false);
+        imp.waste_label.set_visible(false);
+        imp.waste_heading_box.set_visible(false);
+        imp.waste_heading_label.set_label("Waste");
+        imp.top_row_spacer_box.set_visible(false);
+        imp.stock_waste_foundation_spacer_box.set_visible(false);
+        imp.selected_freecell.set(None);
+        imp.foundations_heading_box.set_visible(true);
+        imp.foundations_heading_box.set_halign(gtk::Align::Start);
+        imp.foundations_heading_box.set_margin_start(0);
+        imp.foundations_heading_label.set_label("Completed Runs");
+        imp.foundations_heading_label.set_xalign(0.0);
+        imp.foundations_heading_label.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_margin_start(0);
+        imp.foundations_area_box.set_visible(true);
+        let mode = self.active_game_mode();
+        let caps = engine_for_mode(mode).capabilities();
+        let game = imp.game.borrow().scorpion().clone();
+        if game.is_won() && imp.timer_started.get() {
+            imp.timer_started.set(false);
+        }
+        self.note_current_seed_win_if_needed();
+
+        let selected_snapshot = imp.selected_run.try_borrow().ok().and_then(|run| *run);
+        let selected = selected_snapshot.and_then(|run| {
+            game.tableau_card(run.col, run.start)
+                .filter(|card| card.face_up)
+                .map(|_| run)
+        });
+        if let Ok(mut selected_run) = imp.selected_run.try_borrow_mut() {
+            *selected_run = selected;
+        }
+        imp.waste_selected.set(false);
+
+        self.render_card_images_scorpion(&game);
+
+        let controls =
+            render_plan::plan_controls(caps, imp.history.borrow().len(), imp.future.borrow().len());
+        imp.undo_button.set_sensitive(controls.undo_enabled);
+        imp.redo_button.set_sensitive(controls.redo_enabled);
+        imp.auto_hint_button
+            .set_sensitive(controls.auto_hint_enabled);
+        imp.cyclone_shuffle_button
+            .set_sensitive(controls.cyclone_enabled);
+        imp.peek_button.set_sensitive(controls.peek_enabled);
+        imp.robot_button.set_sensitive(controls.robot_enabled);
+        imp.seed_random_button
+            .set_sensitive(controls.seed_random_enabled);
+        imp.seed_rescue_button
+            .set_sensitive(controls.seed_rescue_enabled);
+        imp.seed_winnable_button
+            .set_sensitive(controls.seed_winnable_enabled);
+        imp.seed_repeat_button
+            .set_sensitive(controls.seed_repeat_enabled);
+        imp.seed_go_button.set_sensitive(controls.seed_go_enabled);
+        imp.seed_combo.set_sensitive(controls.seed_combo_enabled);
+
+        self.update_keyboard_focus_style();
+        let show_controls_hint = imp.pending_deal_instructions.replace(false);
+        let status = if let Some(message) = imp.status_override.borrow().as_deref() {
+            message.to_string()
+        } else if game.is_won() {
+            "Scorpion won! All four runs are complete.".to_string()
+        } else if game.is_lost() {
+            "Scorpion blocked. No legal moves remain and the reserve is spent.".to_string()
+        } else if let Some(run) = selected {
+            let amount = game
+                .tableau()
+                .get(run.col)
+                .map(Vec::len)
+                .unwrap_or(0)
+                .saturating_sub(run.start);
+            if amount > 1 {
+                format!(
+                    "Selected {amount} cards from T{}. Click another tableau to move them together.",
+                    run.col + 1
+                )
+            } else {
+                format!(
+                    "Selected tableau T{}. Click another tableau to move it.",
+                    run.col + 1
+                )
+            }
+        } else if show_controls_hint {
+            "Scorpion controls: any face-up card moves with everything on top of it. Build down in suit; only Kings fill empty columns. Completed King-to-Ace runs are removed, and the reserve deals once. Keyboard: arrows move focus, Enter activates."
+                .to_string()
+        } else {
+            String::new()
+        };
+        self.append_status_line(&status);
+
+        self.apply_mobile_phone_mode_overrides();
+        self.update_tableau_overflow_hints();
+        self.update_stats_label();
+        self.mark_session_dirty();
+    }
+
+    fn render_canfield(&self) {
+        let imp = self.imp();
+        imp.stock_picture.set_visible(true);
+        imp.stock_column_box.set_visible(true);
+        imp.stock_label.set_visible(false);
+        imp.stock_heading_box.set_visible(true);
+        imp.stock_heading_label.set_label("Stock");
+        imp.waste_overlay.set_visible(true);
+        imp.waste_column_box.set_visible(true);
+        imp.waste_label.set_visible(false);
+        imp.waste_heading_box.set_visible(true);
+        imp.waste_heading_label.set_label("Waste · Reserve");
+        imp.top_row_spacer_box.set_visible(false);
+        imp.stock_waste_foundation_spacer_box.set_visible(false);
+        imp.foundations_heading_box.set_visible(true);
+        imp.foundations_heading_box.set_halign(gtk::Align::Start);
+        imp.foundations_heading_box.set_margin_start(0);
+        imp.foundations_heading_label.set_label("Foundations");
+        imp.foundations_heading_label.set_xalign(0.0);
+        imp.foundations_heading_label.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_margin_start(0);
+        imp.foundations_area_box.set_visible(true);
+        let mode = self.active_game_mode();
+        let caps = engine_for_mode(mode).capabilities();
+        let game = imp.game.borrow().canfield().clone();
+        if game.is_won() && imp.timer_started.get() {
+            imp.timer_started.set(false);
+        }
+        self.note_current_seed_win_if_needed();
+
+        let selected_snapshot = imp.selected_run.try_borrow().ok().and_then(|run| *run);
+        let selected =
+            selected_snapshot.filter(|run| game.tableau_card(run.col, run.start).is_some());
+        if let Ok(mut selected_run) = imp.selected_run.try_borrow_mut() {
+            *selected_run = selected;
+        }
+        if imp.waste_selected.get() && game.waste_top().is_none() {
+            imp.waste_selected.set(false);
+        }
+        if imp.selected_freecell.get().is_some() && game.reserve_top().is_none() {
+            imp.selected_freecell.set(None);
+        }
+
+        self.render_card_images_canfield(&game);
+
+        let controls =
+            render_plan::plan_controls(caps, imp.history.borrow().len(), imp.future.borrow().len());
+        imp.undo_button.set_sensitive(controls.undo_enabled);
+        imp.redo_button.set_sensitive(controls.redo_enabled);
+        imp.auto_hint_button
+            .set_sensitive(controls.auto_hint_enabled);
+        imp.cyclone_shuffle_button
+            .set_sensitive(controls.cyclone_enabled);
+        imp.peek_button.set_sensitive(controls.peek_enabled);
+        imp.robot_button.set_sensitive(controls.robot_enabled);
+        imp.seed_random_button
+            .set_sensitive(controls.seed_random_enabled);
+        imp.seed_rescue_button
+            .set_sensitive(controls.seed_rescue_enabled);
+        imp.seed_winnable_button
+            .set_sensitive(controls.seed_winnable_enabled);
+        imp.seed_repeat_button
+            .set_sensitive(controls.seed_repeat_enabled);
+        imp.seed_go_button.set_sensitive(controls.seed_go_enabled);
+        imp.seed_combo.set_sensitive(controls.seed_combo_enabled);
+
+        self.update_keyboard_focus_style();
+        let show_controls_hint = imp.pending_deal_instructions.replace(false);
+        let base = rank_label(game.base().rank);
+        let status = if let Some(message) = imp.status_override.borrow().as_deref() {
+            message.to_string()
+        } else if game.is_won() {
+            "Canfield won! All four foundations are complete.".to_string()
+        } else if game.is_lost() {
+            "Canfield blocked. No legal moves remain.".to_string()
+        } else if imp.waste_selected.get() {
+            "Selected waste. Click a tableau column or foundation to move it.".to_string()
+        } else if imp.selected_freecell.get().is_some() {
+            "Selected reserve. Click a tableau column or foundation to move it.".to_string()
+        } else if let Some(run) = selected {
+            let amount = game
+                .tableau()
+                .get(run.col)
+                .map(Vec::len)
+                .unwrap_or(0)
+                .saturating_sub(run.start);
+            if amount > 1 {
+                format!(
+                    "Selected {amount} cards from T{}. Click another tableau to move this run.",
+                    run.col + 1
+                )
+            } else {
+                format!(
+                    "Selected tableau T{}. Click a tableau or foundation to move it.",
+                    run.col + 1
+                )
+            }
+        } else if show_controls_hint {
+            format!(
+                "Canfield controls: foundations build up in suit from {base}, wrapping King to Ace. Tableau builds down in alternating colours; the reserve fills empty columns. The stock turns three at a time with unlimited passes. Keyboard: arrows move focus, Enter activates."
+            )
+        } else {
+            String::new()
+        };
+        self.append_status_line(&status);
+
+        self.apply_mobile_phone_mode_overrides();
+        self.update_tableau_overflow_hints();
+        self.update_stats_label();
+        self.mark_session_dirty();
+    }
+
+    fn render_open_tableau(&self) {
+        let imp = self.imp();
+        imp.stock_picture.set_visible(false);
+        imp.stock_column_box.set_visible(false);
+        imp.stock_label.set_visible(false);
+        imp.stock_heading_box.set_visible(false);
+        imp.waste_overlay.set_visible(false);
+        imp.waste_column_box.set_visible(false);
+        imp.waste_label.set_visible(false);
+        imp.waste_heading_box.set_visible(false);
+        imp.top_row_spacer_box.set_visible(false);
+        imp.stock_waste_foundation_spacer_box.set_visible(false);
+        imp.selected_freecell.set(None);
+        imp.foundations_heading_box.set_visible(true);
+        imp.foundations_heading_box.set_halign(gtk::Align::Start);
+        imp.foundations_heading_box.set_margin_start(0);
+        imp.foundations_heading_label.set_label("Foundations");
+        imp.foundations_heading_label.set_xalign(0.0);
+        imp.foundations_heading_label.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_halign(gtk::Align::Start);
+        imp.foundations_area_box.set_margin_start(0);
+        imp.foundations_area_box.set_visible(true);
+        let mode = self.active_game_mode();
+        let caps = engine_for_mode(mode).capabilities();
+        let Some(layout) = OpenTableauLayout::for_mode(mode) else {
+            return;
+        };
+        let game = imp.game.borrow().open_tableau(layout).clone();
+        if game.is_won() && imp.timer_started.get() {
+            imp.timer_started.set(false);
+        }
+        self.note_current_seed_win_if_needed();
+
+        // Only top cards move, so a selection is always a column's last card.
+        let selected_snapshot = imp.selected_run.try_borrow().ok().and_then(|run| *run);
+        let selected = selected_snapshot.filter(|run| {
+            game.tableau()
+                .get(run.col)
+                .is_some_and(|pile| run.start + 1 == pile.len())
+        });
+        if let Ok(mut selected_run) = imp.selected_run.try_borrow_mut() {
+            *selected_run = selected;
+        }
+        imp.waste_selected.set(false);
+
+        self.render_card_images_open_tableau(&game);
+
+        let controls =
+            render_plan::plan_controls(caps, imp.history.borrow().len(), imp.future.borrow().len());
+        imp.undo_button.set_sensitive(controls.undo_enabled);
+        imp.redo_button.set_sensitive(controls.redo_enabled);
+        imp.auto_hint_button
+            .set_sensitive(controls.auto_hint_enabled);
+        imp.cyclone_shuffle_button
+            .set_sensitive(controls.cyclone_enabled);
+        imp.peek_button.set_sensitive(controls.peek_enabled);
+        imp.robot_button.set_sensitive(controls.robot_enabled);
+        imp.seed_random_button
+            .set_sensitive(controls.seed_random_enabled);
+        imp.seed_rescue_button
+            .set_sensitive(controls.seed_rescue_enabled);
+        imp.seed_winnable_button
+            .set_sensitive(controls.seed_winnable_enabled);
+        imp.seed_repeat_button
+            .set_sensitive(controls.seed_repeat_enabled);
+        imp.seed_go_button.set_sensitive(controls.seed_go_enabled);
+        imp.seed_combo.set_sensitive(controls.seed_combo_enabled);
+
+        self.update_keyboard_focus_style();
+        let show_controls_hint = imp.pending_deal_instructions.replace(false);
+        let variant_name = self.current_variant_name();
+        let status = if let Some(message) = imp.status_override.borrow().as_deref() {
+            message.to_string()
+        } else if game.is_won() {
+            format!("{variant_name} won! All foundations complete.")
+        } else if game.is_lost() {
+            format!("{variant_name} blocked. No legal moves remain.")
+        } else if let Some(run) = selected {
+            format!(
+                "Selected tableau T{}. Click a tableau or foundation to move it.",
+                run.col + 1
+            )
+        } else if show_controls_hint {
+            let empty_columns = if layout.empty_columns_fillable() {
+                "any card fills an empty column"
+            } else {
+                "empty columns stay empty"
+            };
+            format!(
+                "{variant_name} controls: every card is dealt face up. Move one card at a time, building down regardless of suit; {empty_columns}. Keyboard: arrows move focus, Enter activates."
+            )
+        } else {
+            String::new()
+        };
+        self.append_status_line(&status);
+
+        self.apply_mobile_phone_mode_overrides();
+        self.update_tableau_overflow_hints();
+        self.update_stats_label();
+        self.mark_session_dirty();
+    }
+
+    pub(super) fn flash_smart_move_fail_tableau_run(&self, col: usize, start: usize) {
+        let imp = self.imp();
+        let previous_selected = *imp.selected_run.borrow();
+        let previous_waste_selected = imp.waste_selected.get();
+
+        *imp.selected_run.borrow_mut() = Some(SelectedRun { col, start });
+        imp.waste_selected.set(false);
+        self.render();
+
+        glib::timeout_add_local_once(
+            Duration::from_millis(100),
+            glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                move || {
+                    let imp = window.imp();
+                    let current = *imp.selected_run.borrow();
+                    if current == Some(SelectedRun { col, start }) {
+                        *imp.selected_run.borrow_mut() = previous_selected;
+                        imp.waste_selected.set(previous_waste_selected);
+                        window.render();
+                    }
+                }
+            ),
+        );
+    }
+
+    pub(super) fn flash_smart_move_fail_waste_top(&self) {
+        let imp = self.imp();
+        let game = imp.game.borrow();
+        let show_count = render_plan::waste_visible_count(game.draw_mode(), game.waste_len());
+        if show_count == 0 {
+            return;
+        }
+        drop(game);
+
+        let previous_selected = *imp.selected_run.borrow();
+        let previous_waste_selected = imp.waste_selected.get();
+
+        *imp.selected_run.borrow_mut() = None;
+        imp.waste_selected.set(true);
+        self.render();
+
+        glib::timeout_add_local_once(
+            Duration::from_millis(100),
+            glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                move || {
+                    let imp = window.imp();
+                    if imp.waste_selected.get() {
+                        *imp.selected_run.borrow_mut() = previous_selected;
+                        imp.waste_selected.set(previous_waste_selected);
+                        window.render();
+                    }
+                }
+            ),
+        );
+    }
+
+    pub(super) fn render_card_images(&self, game: &KlondikeGame) {
+        startup_trace::mark_once("render:first-images-enter");
         let imp = self.imp();
         let deck_slot = imp.deck.borrow();
         let deck = deck_slot.as_ref();
@@ -1107,6 +1974,277 @@ impl CardthropicWindow {
         startup_trace::mark_once("render:first-images-exit");
     }
 
+    fn render_card_images_yukon(&self, game: &YukonGame) {
+        startup_trace::mark_once("render:first-images-enter");
+        let imp = self.imp();
+        let deck_slot = imp.deck.borrow();
+        let deck = deck_slot.as_ref();
+
+        startup_trace::mark_once("render:first-metrics-enter");
+        self.update_tableau_metrics();
+        let card_width = imp.card_width.get();
+        let card_height = imp.card_height.get();
+        let face_up_step = imp.face_up_step.get();
+        let face_down_step = imp.face_down_step.get();
+        let peek_active = imp.peek_active.get();
+        startup_trace::mark_once("render:first-metrics-exit");
+
+        startup_trace::mark_once("render:first-toprow-enter");
+        self.configure_stock_waste_foundation_widgets(card_width, card_height);
+        self.render_foundations_area_yukon(game, deck, card_width, card_height);
+        startup_trace::mark_once("render:first-toprow-exit");
+        startup_trace::mark_once("render:first-tableau-enter");
+        self.render_tableau_piles(
+            game.tableau(),
+            deck,
+            card_width,
+            card_height,
+            face_up_step,
+            face_down_step,
+            peek_active,
+        );
+        startup_trace::mark_once("render:first-tableau-exit");
+        startup_trace::mark_once("render:first-images-exit");
+    }
+
+    fn render_card_images_forty_thieves(&self, game: &FortyThievesGame) {
+        startup_trace::mark_once("render:first-images-enter");
+        let imp = self.imp();
+        let deck_slot = imp.deck.borrow();
+        let deck = deck_slot.as_ref();
+
+        startup_trace::mark_once("render:first-metrics-enter");
+        self.update_tableau_metrics();
+        let card_width = imp.card_width.get();
+        let card_height = imp.card_height.get();
+        let face_up_step = imp.face_up_step.get();
+        let face_down_step = imp.face_down_step.get();
+        let peek_active = imp.peek_active.get();
+        startup_trace::mark_once("render:first-metrics-exit");
+
+        startup_trace::mark_once("render:first-toprow-enter");
+        self.configure_stock_waste_foundation_widgets(card_width, card_height);
+        self.render_stock_picture_single(game.stock_len(), deck, card_width, card_height);
+        self.render_waste_top_only(
+            game.waste().len(),
+            game.waste_top(),
+            deck,
+            card_width,
+            card_height,
+        );
+        self.render_foundations_area_forty_thieves(game, deck, card_width, card_height);
+        startup_trace::mark_once("render:first-toprow-exit");
+        startup_trace::mark_once("render:first-tableau-enter");
+        self.render_tableau_piles(
+            game.tableau(),
+            deck,
+            card_width,
+            card_height,
+            face_up_step,
+            face_down_step,
+            peek_active,
+        );
+        startup_trace::mark_once("render:first-tableau-exit");
+        startup_trace::mark_once("render:first-images-exit");
+    }
+
+    fn render_card_images_scorpion(&self, game: &ScorpionGame) {
+        startup_trace::mark_once("render:first-images-enter");
+        let imp = self.imp();
+        let deck_slot = imp.deck.borrow();
+        let deck = deck_slot.as_ref();
+
+        startup_trace::mark_once("render:first-metrics-enter");
+        self.update_tableau_metrics();
+        let card_width = imp.card_width.get();
+        let card_height = imp.card_height.get();
+        let face_up_step = imp.face_up_step.get();
+        let face_down_step = imp.face_down_step.get();
+        let peek_active = imp.peek_active.get();
+        startup_trace::mark_once("render:first-metrics-exit");
+
+        startup_trace::mark_once("render:first-toprow-enter");
+        self.configure_stock_waste_foundation_widgets(card_width, card_height);
+        self.render_stock_picture_single(game.stock_len(), deck, card_width, card_height);
+        self.render_waste_fan_spider(card_width, card_height);
+        self.render_foundations_area_scorpion(game, deck, card_width, card_height);
+        startup_trace::mark_once("render:first-toprow-exit");
+        startup_trace::mark_once("render:first-tableau-enter");
+        self.render_tableau_piles(
+            game.tableau(),
+            deck,
+            card_width,
+            card_height,
+            face_up_step,
+            face_down_step,
+            peek_active,
+        );
+        startup_trace::mark_once("render:first-tableau-exit");
+        startup_trace::mark_once("render:first-images-exit");
+    }
+
+    fn render_card_images_canfield(&self, game: &CanfieldGame) {
+        startup_trace::mark_once("render:first-images-enter");
+        let imp = self.imp();
+        let deck_slot = imp.deck.borrow();
+        let deck = deck_slot.as_ref();
+
+        startup_trace::mark_once("render:first-metrics-enter");
+        self.update_tableau_metrics();
+        let card_width = imp.card_width.get();
+        let card_height = imp.card_height.get();
+        let face_up_step = imp.face_up_step.get();
+        let face_down_step = imp.face_down_step.get();
+        let peek_active = imp.peek_active.get();
+        startup_trace::mark_once("render:first-metrics-exit");
+
+        startup_trace::mark_once("render:first-toprow-enter");
+        self.configure_stock_waste_foundation_widgets(card_width, card_height);
+        self.render_stock_picture_single(game.stock_len(), deck, card_width, card_height);
+        self.render_waste_and_reserve_canfield(game, deck, card_width, card_height);
+        self.render_foundations_area_canfield(game, deck, card_width, card_height);
+        startup_trace::mark_once("render:first-toprow-exit");
+        startup_trace::mark_once("render:first-tableau-enter");
+        self.render_tableau_piles(
+            game.tableau(),
+            deck,
+            card_width,
+            card_height,
+            face_up_step,
+            face_down_step,
+            peek_active,
+        );
+        startup_trace::mark_once("render:first-tableau-exit");
+        startup_trace::mark_once("render:first-images-exit");
+    }
+
+    fn render_card_images_open_tableau(&self, game: &OpenTableauGame) {
+        startup_trace::mark_once("render:first-images-enter");
+        let imp = self.imp();
+        let deck_slot = imp.deck.borrow();
+        let deck = deck_slot.as_ref();
+
+        startup_trace::mark_once("render:first-metrics-enter");
+        self.update_tableau_metrics();
+        let card_width = imp.card_width.get();
+        let card_height = imp.card_height.get();
+        let face_up_step = imp.face_up_step.get();
+        let face_down_step = imp.face_down_step.get();
+        let peek_active = imp.peek_active.get();
+        startup_trace::mark_once("render:first-metrics-exit");
+
+        startup_trace::mark_once("render:first-toprow-enter");
+        self.configure_stock_waste_foundation_widgets(card_width, card_height);
+        self.render_foundations_area_open_tableau(game, deck, card_width, card_height);
+        startup_trace::mark_once("render:first-toprow-exit");
+        startup_trace::mark_once("render:first-tableau-enter");
+        self.render_tableau_piles(
+            game.tableau(),
+            deck,
+            card_width,
+            card_height,
+            face_up_step,
+            face_down_step,
+            peek_active,
+        );
+        startup_trace::mark_once("render:first-tableau-exit");
+        startup_trace::mark_once("render:first-images-exit");
+    }
+
+    fn render_card_images_pyramid(&self, game: &PyramidGame) {
+        startup_trace::mark_once("render:first-images-enter");
+        let imp = self.imp();
+        let deck_slot = imp.deck.borrow();
+        let deck = deck_slot.as_ref();
+
+        startup_trace::mark_once("render:first-metrics-enter");
+        self.update_tableau_metrics();
+        let card_width = imp.card_width.get();
+        let card_height = imp.card_height.get();
+        startup_trace::mark_once("render:first-metrics-exit");
+
+        startup_trace::mark_once("render:first-toprow-enter");
+        self.configure_stock_waste_foundation_widgets(card_width, card_height);
+        self.render_stock_picture_single(game.stock_len(), deck, card_width, card_height);
+        self.render_waste_top_only(
+            game.waste().len(),
+            game.waste_top(),
+            deck,
+            card_width,
+            card_height,
+        );
+        self.render_foundations_area_pyramid(game, deck, card_width, card_height);
+        startup_trace::mark_once("render:first-toprow-exit");
+        startup_trace::mark_once("render:first-tableau-enter");
+        if let Some(canvas) = self.tableau_stacks().first() {
+            canvas.add_css_class(Self::slot_canvas_css_class(GameMode::Pyramid));
+        }
+        self.render_tableau_slot_canvas(
+            game.pyramid(),
+            Self::pyramid_canvas_size,
+            Self::pyramid_slot_origin,
+            deck,
+            card_width,
+            card_height,
+        );
+        startup_trace::mark_once("render:first-tableau-exit");
+        startup_trace::mark_once("render:first-images-exit");
+    }
+
+    fn render_card_images_waste_build(&self, game: &WasteBuildGame) {
+        startup_trace::mark_once("render:first-images-enter");
+        let imp = self.imp();
+        let deck_slot = imp.deck.borrow();
+        let deck = deck_slot.as_ref();
+
+        startup_trace::mark_once("render:first-metrics-enter");
+        self.update_tableau_metrics();
+        let card_width = imp.card_width.get();
+        let card_height = imp.card_height.get();
+        startup_trace::mark_once("render:first-metrics-exit");
+
+        startup_trace::mark_once("render:first-toprow-enter");
+        self.configure_stock_waste_foundation_widgets(card_width, card_height);
+        self.render_stock_picture_single(game.stock_len(), deck, card_width, card_height);
+        self.render_waste_top_only(
+            game.waste().len(),
+            game.waste_top(),
+            deck,
+            card_width,
+            card_height,
+        );
+        self.hide_foundations_area();
+        startup_trace::mark_once("render:first-toprow-exit");
+        startup_trace::mark_once("render:first-tableau-enter");
+        let layout = game.layout();
+        if let Some(canvas) = self.tableau_stacks().first() {
+            canvas.add_css_class(Self::slot_canvas_css_class(layout.mode()));
+        }
+        self.render_tableau_slot_canvas(
+            game.tableau(),
+            |card_width, card_height, gap| {
+                Self::waste_build_canvas_size(layout, card_width, card_height, gap)
+            },
+            |slot, card_width, card_height, gap| {
+                Self::waste_build_slot_origin(layout, slot, card_width, card_height, gap)
+            },
+            deck,
+            card_width,
+            card_height,
+        );
+        startup_trace::mark_once("render:first-tableau-exit");
+        startup_trace::mark_once("render:first-images-exit");
+    }
+
+    /// Marks which slot layout currently owns the first tableau stack.
+    fn slot_canvas_css_class(mode: GameMode) -> &'static str {
+        match mode {
+            GameMode::TriPeaks => "tripeaks-canvas",
+            GameMode::Golf => "golf-canvas",
+            _ => "pyramid-canvas",
+        }
+    }
+
     pub(super) fn set_picture_from_card(
         &self,
         picture: &gtk::Picture,
@@ -1170,7 +2308,7 @@ impl CardthropicWindow {
         ]
     }
 
-    pub(super) fn waste_fan_slots(&self) -> [gtk::Picture; 6] {
+    pub(super) fn waste_fan_slots(&self) -> [gtk::Picture; 8] {
         let imp = self.imp();
         [
             imp.waste_picture_1.get(),
@@ -1179,10 +2317,12 @@ impl CardthropicWindow {
             imp.waste_picture_4.get(),
             imp.waste_picture_5.get(),
             imp.waste_picture_6.get(),
+            imp.waste_picture_7.get(),
+            imp.waste_picture_8.get(),
         ]
     }
 
-    pub(super) fn freecell_slot_pictures(&self) -> [gtk::Picture; 6] {
+    pub(super) fn freecell_slot_pictures(&self) -> [gtk::Picture; 8] {
         let imp = self.imp();
         [
             imp.waste_picture_1.get(),
@@ -1191,10 +2331,12 @@ impl CardthropicWindow {
             imp.waste_picture_4.get(),
             imp.waste_picture_5.get(),
             imp.waste_picture_6.get(),
+            imp.waste_picture_7.get(),
+            imp.waste_picture_8.get(),
         ]
     }
 
-    pub(super) fn tableau_stacks(&self) -> [gtk::Fixed; 10] {
+    pub(super) fn tableau_stacks(&self) -> [gtk::Fixed; 13] {
         let imp = self.imp();
         [
             imp.tableau_stack_1.get(),
@@ -1207,6 +2349,9 @@ impl CardthropicWindow {
             imp.tableau_stack_8.get(),
             imp.tableau_stack_9.get(),
             imp.tableau_stack_10.get(),
+            imp.tableau_stack_11.get(),
+            imp.tableau_stack_12.get(),
+            imp.tableau_stack_13.get(),
         ]
     }
 
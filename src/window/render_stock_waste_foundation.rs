@@ -1,7 +1,8 @@
 use super::*;
 use crate::engine::render_plan;
 use crate::game::{
-    Card, FreecellGame, SpiderGame, Suit, FREECELL_MAX_CELL_COUNT, FREECELL_MIN_CELL_COUNT,
+    rank_label, CanfieldGame, Card, FortyThievesGame, FreecellGame, OpenTableauGame, PyramidGame,
+    ScorpionGame, SpiderGame, Suit, YukonGame,
 };
 
 impl CardthropicWindow {
@@ -29,9 +30,8 @@ impl CardthropicWindow {
         imp.waste_overlay.set_valign(gtk::Align::Start);
         imp.waste_label.set_valign(gtk::Align::Start);
 
-        let freecell_cells = if self.active_game_mode() == GameMode::Freecell {
-            self.current_freecell_cell_count()
-                .clamp(FREECELL_MIN_CELL_COUNT, FREECELL_MAX_CELL_COUNT)
+        let freecell_cells = if self.active_game_mode().is_freecell_family() {
+            self.freecell_board_cell_count()
         } else {
             0
         };
@@ -50,7 +50,7 @@ impl CardthropicWindow {
         imp.last_stock_waste_foundation_size.set(size);
 
         let shrink_cards = self.active_game_mode() == GameMode::Spider;
-        let freecell_mode = self.active_game_mode() == GameMode::Freecell;
+        let freecell_mode = self.active_game_mode().is_freecell_family();
         if freecell_mode {
             // Keep FreeCell's top lane compact and deterministic so the tableau
             // never gets pushed down by expansion quirks in overlay children.
@@ -83,9 +83,14 @@ impl CardthropicWindow {
             picture.set_content_fit(gtk::ContentFit::Contain);
         }
         let spider_mode = self.active_game_mode() == GameMode::Spider;
+        let forty_thieves_mode = self.active_game_mode() == GameMode::FortyThieves;
         let mobile_mode = imp.mobile_phone_mode.get();
-        let foundation_slots = if spider_mode { 8 } else { 4 };
-        let foundation_gap = if spider_mode {
+        let foundation_slots = match self.active_game_mode() {
+            GameMode::Spider | GameMode::FortyThieves => 8,
+            GameMode::Pyramid => 1,
+            _ => 4,
+        };
+        let foundation_gap = if spider_mode || forty_thieves_mode {
             0
         } else if mobile_mode {
             2
@@ -94,13 +99,14 @@ impl CardthropicWindow {
         };
         let foundation_group_width =
             (card_width * foundation_slots) + (foundation_gap * (foundation_slots - 1));
-        let waste_strip_width = if self.active_game_mode() == GameMode::Spider {
+        let waste_strip_width = if spider_mode || forty_thieves_mode {
             card_width
-        } else if self.active_game_mode() == GameMode::Freecell {
-            let freecell_slots = i32::from(self.current_freecell_cell_count())
-                .clamp(1, i32::from(FREECELL_MAX_CELL_COUNT));
+        } else if self.active_game_mode().is_freecell_family() {
+            let freecell_slots = i32::from(freecell_cells).max(1);
             let step = Self::freecell_slot_step(card_width);
             card_width + step * freecell_slots.saturating_sub(1)
+        } else if self.active_game_mode() == GameMode::Canfield {
+            card_width + Self::freecell_slot_step(card_width)
         } else {
             render_plan::waste_overlay_width(card_width)
         };
@@ -133,10 +139,12 @@ impl CardthropicWindow {
     ) {
         let imp = self.imp();
         if game.stock_len() > 0 {
-            if let Some(back) =
-                self.paintable_for_card_display(None, false, deck, card_width, card_height)
+            // Thoughtful shows the next card the stock will deal.
+            let top = game.stock_top().filter(|_| game.is_thoughtful());
+            if let Some(paintable) =
+                self.paintable_for_card_display(top, top.is_some(), deck, card_width, card_height)
             {
-                imp.stock_picture.set_paintable(Some(&back));
+                imp.stock_picture.set_paintable(Some(&paintable));
             } else {
                 let empty = Self::blank_texture(card_width, card_height);
                 imp.stock_picture.set_paintable(Some(&empty));
@@ -287,8 +295,42 @@ impl CardthropicWindow {
         card_width: i32,
         card_height: i32,
     ) {
-        let completed = game.completed_runs().min(8);
-        let completed_suits = game.completed_run_suits();
+        self.render_completed_runs_area(
+            game.completed_run_suits(),
+            game.rule_set().goal_runs(),
+            deck,
+            card_width,
+            card_height,
+        );
+    }
+
+    pub(super) fn render_foundations_area_scorpion(
+        &self,
+        game: &ScorpionGame,
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        self.render_completed_runs_area(
+            game.completed_run_suits(),
+            4,
+            deck,
+            card_width,
+            card_height,
+        );
+    }
+
+    /// Shows one King marker per removed K→A run in the first `slot_count`
+    /// foundation slots and hides the rest.
+    fn render_completed_runs_area(
+        &self,
+        completed_suits: &[Suit],
+        slot_count: usize,
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        let completed = completed_suits.len().min(slot_count);
         let pictures = self.foundation_pictures();
         let slot_boxes: Vec<Option<gtk::Box>> = pictures
             .iter()
@@ -300,13 +342,14 @@ impl CardthropicWindow {
             })
             .collect();
         for (slot, picture) in pictures.into_iter().enumerate() {
+            let in_use = slot < slot_count;
             if let Some(slot_box) = slot_boxes[slot].as_ref() {
-                slot_box.set_visible(true);
+                slot_box.set_visible(in_use);
             }
-            picture.set_visible(true);
+            picture.set_visible(in_use);
             if slot < completed {
                 let marker = Card {
-                    suit: completed_suits.get(slot).copied().unwrap_or(Suit::Spades),
+                    suit: completed_suits[slot],
                     rank: 13,
                     face_up: true,
                 };
@@ -317,14 +360,20 @@ impl CardthropicWindow {
         }
         for (slot, placeholder) in self.foundation_placeholders().into_iter().enumerate() {
             placeholder.set_label("");
-            placeholder.set_visible(slot >= completed);
+            placeholder.set_visible(slot >= completed && slot < slot_count);
         }
     }
 
+    /// Canfield shows the waste first and the reserve one slot to its right.
+    pub(super) fn canfield_reserve_contains_waste_x(&self, x: f64) -> bool {
+        let step = Self::freecell_slot_step(self.imp().card_width.get());
+        x >= f64::from(step)
+    }
+
     pub(super) fn freecell_slot_index_from_waste_x(&self, x: f64) -> usize {
         let card_width = self.imp().card_width.get();
         let step = Self::freecell_slot_step(card_width);
-        let slot_count = i32::from(self.current_freecell_cell_count()).max(1);
+        let slot_count = i32::from(self.freecell_board_cell_count()).max(1);
         let idx = (x.max(0.0) as i32) / step.max(1);
         idx.clamp(0, slot_count - 1) as usize
     }
@@ -337,7 +386,7 @@ impl CardthropicWindow {
         card_height: i32,
     ) {
         let imp = self.imp();
-        // Keep the overlay's main child out of FreeCell measurement; the six
+        // Keep the overlay's main child out of FreeCell measurement; the eight
         // overlay children below render all free-cell slots.
         imp.waste_picture.set_visible(false);
         imp.waste_picture.set_paintable(None::<&gdk::Paintable>);
@@ -450,4 +499,312 @@ impl CardthropicWindow {
             placeholders[slot].set_visible(false);
         }
     }
+
+    /// Canfield lays the waste top and the reserve top side by side in the
+    /// waste strip. The reserve uses free-cell slot 0 so hints and drags can
+    /// address it like a free cell.
+    pub(super) fn render_waste_and_reserve_canfield(
+        &self,
+        game: &CanfieldGame,
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        let imp = self.imp();
+        imp.waste_picture.set_visible(true);
+        let step = Self::freecell_slot_step(card_width);
+        let slots = self.freecell_slot_pictures();
+        for picture in &slots {
+            picture.set_visible(false);
+            picture.set_margin_start(0);
+            picture.set_paintable(None::<&gdk::Paintable>);
+            picture.remove_css_class("waste-selected-card");
+        }
+        let reserve_slot = CANFIELD_RESERVE_CELL;
+        let waste_slot = reserve_slot + 1;
+        for (slot, card, margin, selected) in [
+            (waste_slot, game.waste_top(), 0, imp.waste_selected.get()),
+            (
+                reserve_slot,
+                game.reserve_top(),
+                step,
+                imp.selected_freecell.get() == Some(reserve_slot),
+            ),
+        ] {
+            let picture = &slots[slot];
+            picture.set_margin_start(margin);
+            picture.set_content_fit(gtk::ContentFit::Fill);
+            picture.set_halign(gtk::Align::Start);
+            picture.set_valign(gtk::Align::Start);
+            match card {
+                Some(card) => {
+                    if let Some(paintable) = self.paintable_for_card_display(
+                        Some(card),
+                        true,
+                        deck,
+                        card_width,
+                        card_height,
+                    ) {
+                        picture.set_paintable(Some(&paintable));
+                    }
+                }
+                None => {
+                    let empty = Self::blank_texture(card_width, card_height);
+                    picture.set_paintable(Some(&empty));
+                }
+            }
+            if selected && card.is_some() {
+                picture.add_css_class("waste-selected-card");
+            }
+            picture.set_visible(true);
+        }
+        imp.waste_placeholder_label
+            .set_visible(game.waste_top().is_none());
+        imp.waste_label.set_label(&format!(
+            "Waste: {} · Reserve: {}",
+            game.waste().len(),
+            game.reserve().len()
+        ));
+    }
+
+    /// Empty Canfield foundations show the dealt base rank they start from.
+    pub(super) fn render_foundations_area_canfield(
+        &self,
+        game: &CanfieldGame,
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        self.sync_foundation_slots_with_state();
+        let pictures = self.foundation_pictures();
+        let placeholders = self.foundation_placeholders();
+        let base = rank_label(game.base().rank);
+        for (slot, picture) in pictures.iter().enumerate() {
+            let in_use = slot < 4;
+            if let Some(slot_box) = picture
+                .parent()
+                .and_then(|widget| widget.parent())
+                .and_then(|widget| widget.downcast::<gtk::Box>().ok())
+            {
+                slot_box.set_visible(in_use);
+            }
+            picture.set_visible(in_use);
+            let top = self
+                .foundation_slot_suit(slot)
+                .filter(|_| in_use)
+                .and_then(|suit| game.foundations()[suit.foundation_index()].last().copied());
+            self.set_picture_from_card(picture, top, deck, card_width, card_height);
+            placeholders[slot].set_label(if in_use { base } else { "" });
+            placeholders[slot].set_visible(in_use && top.is_none());
+        }
+    }
+
+    pub(super) fn render_foundations_area_yukon(
+        &self,
+        game: &YukonGame,
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        self.render_suit_foundations_area(game.foundations(), deck, card_width, card_height);
+    }
+
+    pub(super) fn render_foundations_area_open_tableau(
+        &self,
+        game: &OpenTableauGame,
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        self.render_suit_foundations_area(game.foundations(), deck, card_width, card_height);
+    }
+
+    /// Four suit-indexed foundations with the remaining slots hidden.
+    fn render_suit_foundations_area(
+        &self,
+        foundations: &[Vec<Card>; 4],
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        self.sync_foundation_slots_with_state();
+        let pictures = self.foundation_pictures();
+        let placeholders = self.foundation_placeholders();
+        let slot_boxes: Vec<Option<gtk::Box>> = pictures
+            .iter()
+            .map(|picture| {
+                picture
+                    .parent()
+                    .and_then(|widget| widget.parent())
+                    .and_then(|widget| widget.downcast::<gtk::Box>().ok())
+            })
+            .collect();
+        for slot in 0..4 {
+            if let Some(slot_box) = slot_boxes[slot].as_ref() {
+                slot_box.set_visible(true);
+            }
+            pictures[slot].set_visible(true);
+            let top = self
+                .foundation_slot_suit(slot)
+                .and_then(|suit| foundations[suit.foundation_index()].last().copied());
+            self.set_picture_from_card(&pictures[slot], top, deck, card_width, card_height);
+            placeholders[slot].set_label("");
+            let empty = self
+                .foundation_slot_suit(slot)
+                .map(|suit| foundations[suit.foundation_index()].is_empty())
+                .unwrap_or(true);
+            placeholders[slot].set_visible(empty);
+        }
+        for slot in 4..8 {
+            if let Some(slot_box) = slot_boxes[slot].as_ref() {
+                slot_box.set_visible(false);
+            }
+            pictures[slot].set_visible(false);
+            self.set_picture_from_card(&pictures[slot], None, deck, card_width, card_height);
+            placeholders[slot].set_label("");
+            placeholders[slot].set_visible(false);
+        }
+    }
+
+    pub(super) fn render_stock_picture_single(
+        &self,
+        stock_len: usize,
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        let imp = self.imp();
+        imp.stock_label
+            .set_label(&render_plan::card_count_label(stock_len));
+        if stock_len > 0 {
+            if let Some(back) =
+                self.paintable_for_card_display(None, false, deck, card_width, card_height)
+            {
+                imp.stock_picture.set_paintable(Some(&back));
+                return;
+            }
+        }
+        let empty = Self::blank_texture(card_width, card_height);
+        imp.stock_picture.set_paintable(Some(&empty));
+    }
+
+    /// Pyramid, TriPeaks and Golf only ever play the waste top, so the fan
+    /// shows a single card.
+    pub(super) fn render_waste_top_only(
+        &self,
+        waste_len: usize,
+        top: Option<Card>,
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        let imp = self.imp();
+        imp.waste_picture.set_visible(true);
+        imp.waste_label
+            .set_label(&render_plan::card_count_label(waste_len));
+        let waste_widgets = self.waste_fan_slots();
+        for picture in &waste_widgets {
+            picture.set_visible(false);
+            picture.set_margin_start(0);
+            picture.set_paintable(None::<&gdk::Paintable>);
+            picture.remove_css_class("waste-selected-card");
+        }
+        if let (Some(card), Some(picture)) = (top, waste_widgets.first()) {
+            if imp.waste_selected.get() {
+                picture.add_css_class("waste-selected-card");
+            }
+            if let Some(paintable) =
+                self.paintable_for_card_display(Some(card), true, deck, card_width, card_height)
+            {
+                picture.set_paintable(Some(&paintable));
+            }
+            picture.set_visible(true);
+        }
+        imp.waste_placeholder_label.set_visible(top.is_none());
+    }
+
+    /// The removed pile takes the first foundation slot; the rest are hidden.
+    pub(super) fn render_foundations_area_pyramid(
+        &self,
+        game: &PyramidGame,
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        let pictures = self.foundation_pictures();
+        let placeholders = self.foundation_placeholders();
+        let slot_boxes: Vec<Option<gtk::Box>> = pictures
+            .iter()
+            .map(|picture| {
+                picture
+                    .parent()
+                    .and_then(|widget| widget.parent())
+                    .and_then(|widget| widget.downcast::<gtk::Box>().ok())
+            })
+            .collect();
+        for (slot, picture) in pictures.iter().enumerate() {
+            let visible = slot == 0;
+            if let Some(slot_box) = slot_boxes[slot].as_ref() {
+                slot_box.set_visible(visible);
+            }
+            picture.set_visible(visible);
+            let top = if visible {
+                game.removed().last().copied()
+            } else {
+                None
+            };
+            self.set_picture_from_card(picture, top, deck, card_width, card_height);
+            placeholders[slot].set_label("");
+            placeholders[slot].set_visible(visible && top.is_none());
+        }
+    }
+
+    /// Forty Thieves slots map one-to-one onto its eight foundation piles.
+    pub(super) fn render_foundations_area_forty_thieves(
+        &self,
+        game: &FortyThievesGame,
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        let pictures = self.foundation_pictures();
+        let placeholders = self.foundation_placeholders();
+        for (slot, picture) in pictures.iter().enumerate() {
+            if let Some(slot_box) = picture
+                .parent()
+                .and_then(|widget| widget.parent())
+                .and_then(|widget| widget.downcast::<gtk::Box>().ok())
+            {
+                slot_box.set_visible(true);
+            }
+            picture.set_visible(true);
+            let top = game
+                .foundations()
+                .get(slot)
+                .and_then(|pile| pile.last())
+                .copied();
+            self.set_picture_from_card(picture, top, deck, card_width, card_height);
+            placeholders[slot].set_label("");
+            placeholders[slot].set_visible(top.is_none());
+        }
+    }
+
+    /// TriPeaks and Golf build on the waste only, so every foundation slot
+    /// is hidden.
+    pub(super) fn hide_foundations_area(&self) {
+        let pictures = self.foundation_pictures();
+        let placeholders = self.foundation_placeholders();
+        for (slot, picture) in pictures.iter().enumerate() {
+            if let Some(slot_box) = picture
+                .parent()
+                .and_then(|widget| widget.parent())
+                .and_then(|widget| widget.downcast::<gtk::Box>().ok())
+            {
+                slot_box.set_visible(false);
+            }
+            picture.set_visible(false);
+            placeholders[slot].set_label("");
+            placeholders[slot].set_visible(false);
+        }
+    }
 }
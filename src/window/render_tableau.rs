@@ -74,7 +74,9 @@ impl CardthropicWindow {
                     picture.set_height_request(card_height);
                 }
 
-                let show_face_up = if peek_active {
+                let show_face_up = if game.is_thoughtful() {
+                    true
+                } else if peek_active {
                     !card.face_up
                 } else {
                     card.face_up
@@ -447,4 +449,293 @@ impl CardthropicWindow {
             }
         }
     }
+
+    /// Renders plain face-up/face-down cascades. Variants without extra
+    /// tableau decoration (Yukon-style layouts) render through this directly.
+    pub(super) fn render_tableau_piles(
+        &self,
+        tableau: &[Vec<Card>],
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+        face_up_step: i32,
+        face_down_step: i32,
+        peek_active: bool,
+    ) {
+        let imp = self.imp();
+        let mode_columns = tableau.len();
+        let stacks = self.tableau_stacks();
+        let mut tableau_card_pictures = imp.tableau_card_pictures.borrow_mut();
+        let mut tableau_picture_state_cache = imp.tableau_picture_state_cache.borrow_mut();
+        let card_render_mode = self.current_card_render_mode();
+        if tableau_card_pictures.len() < stacks.len() {
+            tableau_card_pictures.resize_with(stacks.len(), Vec::new);
+        }
+        if tableau_picture_state_cache.len() < stacks.len() {
+            tableau_picture_state_cache.resize_with(stacks.len(), Vec::new);
+        }
+
+        for (idx, stack) in stacks.iter().enumerate() {
+            let pictures = &mut tableau_card_pictures[idx];
+            let states = &mut tableau_picture_state_cache[idx];
+
+            if idx >= mode_columns {
+                if stack.is_visible() {
+                    stack.set_visible(false);
+                }
+                while let Some(picture) = pictures.pop() {
+                    stack.remove(&picture);
+                }
+                states.clear();
+                continue;
+            }
+
+            if !stack.is_visible() {
+                stack.set_visible(true);
+            }
+            if stack.width_request() != card_width {
+                stack.set_width_request(card_width);
+            }
+
+            let column = &tableau[idx];
+            let selected_run = *imp.selected_run.borrow();
+            while pictures.len() > column.len() {
+                if let Some(picture) = pictures.pop() {
+                    stack.remove(&picture);
+                }
+                let _ = states.pop();
+            }
+            while pictures.len() < column.len() {
+                let picture = gtk::Picture::new();
+                picture.set_can_shrink(true);
+                picture.set_content_fit(gtk::ContentFit::Contain);
+                stack.put(&picture, 0.0, 0.0);
+                pictures.push(picture);
+                states.push(None);
+            }
+            let mut y = 0;
+            for (card_idx, card) in column.iter().enumerate() {
+                let picture = &pictures[card_idx];
+                if picture.width_request() != card_width {
+                    picture.set_width_request(card_width);
+                }
+                if picture.height_request() != card_height {
+                    picture.set_height_request(card_height);
+                }
+
+                let show_face_up = if peek_active {
+                    !card.face_up
+                } else {
+                    card.face_up
+                };
+                let selected =
+                    selected_run.is_some_and(|run| run.col == idx && card_idx >= run.start);
+                let previous = states.get(card_idx).copied().flatten();
+                if previous.map(|state| state.selected) != Some(selected) {
+                    if selected {
+                        picture.add_css_class("tableau-selected-card");
+                    } else {
+                        picture.remove_css_class("tableau-selected-card");
+                    }
+                }
+
+                if previous.map(|state| {
+                    (
+                        state.card,
+                        state.display_face_up,
+                        state.card_render_mode,
+                        state.card_width,
+                        state.card_height,
+                    )
+                }) != Some((
+                    *card,
+                    show_face_up,
+                    card_render_mode,
+                    card_width,
+                    card_height,
+                )) {
+                    if let Some(paintable) = self.paintable_for_card_display(
+                        Some(*card),
+                        show_face_up,
+                        deck,
+                        card_width,
+                        card_height,
+                    ) {
+                        picture.set_paintable(Some(&paintable));
+                    } else {
+                        picture.set_paintable(None::<&gdk::Paintable>);
+                    }
+                }
+
+                if previous.map(|state| state.y) != Some(y) {
+                    stack.move_(picture, 0.0, f64::from(y));
+                }
+
+                if let Some(slot) = states.get_mut(card_idx) {
+                    *slot = Some(TableauPictureRenderState {
+                        card: *card,
+                        display_face_up: show_face_up,
+                        card_render_mode,
+                        selected,
+                        y,
+                        card_width,
+                        card_height,
+                    });
+                }
+
+                if card_idx + 1 < column.len() {
+                    y += if card.face_up {
+                        face_up_step
+                    } else {
+                        face_down_step
+                    };
+                }
+            }
+            while states.len() > pictures.len() {
+                let _ = states.pop();
+            }
+
+            let stack_height = render_plan::tableau_stack_height(
+                column,
+                card_height,
+                face_up_step,
+                face_down_step,
+            );
+            if stack.height_request() != stack_height {
+                stack.set_height_request(stack_height);
+            }
+        }
+    }
+
+    /// Renders a slot-addressed layout (Pyramid, TriPeaks, Golf) as one
+    /// canvas in the first tableau stack. Pictures are indexed by slot;
+    /// cleared slots keep a hidden picture so hint and focus lookups stay
+    /// slot-addressed.
+    pub(super) fn render_tableau_slot_canvas(
+        &self,
+        slots: &[Option<Card>],
+        canvas_size: impl Fn(i32, i32, i32) -> (i32, i32),
+        slot_origin: impl Fn(usize, i32, i32, i32) -> (i32, i32),
+        deck: Option<&AngloDeck>,
+        card_width: i32,
+        card_height: i32,
+    ) {
+        let imp = self.imp();
+        let stacks = self.tableau_stacks();
+        let gap = imp.tableau_row.spacing();
+        let mut tableau_card_pictures = imp.tableau_card_pictures.borrow_mut();
+        let mut tableau_picture_state_cache = imp.tableau_picture_state_cache.borrow_mut();
+        let card_render_mode = self.current_card_render_mode();
+        if tableau_card_pictures.len() < stacks.len() {
+            tableau_card_pictures.resize_with(stacks.len(), Vec::new);
+        }
+        if tableau_picture_state_cache.len() < stacks.len() {
+            tableau_picture_state_cache.resize_with(stacks.len(), Vec::new);
+        }
+        let selected_slot = imp.selected_run.borrow().map(|run| run.start);
+
+        for (idx, stack) in stacks.iter().enumerate() {
+            let pictures = &mut tableau_card_pictures[idx];
+            let states = &mut tableau_picture_state_cache[idx];
+
+            if idx > 0 {
+                if stack.is_visible() {
+                    stack.set_visible(false);
+                }
+                while let Some(picture) = pictures.pop() {
+                    stack.remove(&picture);
+                }
+                states.clear();
+                continue;
+            }
+
+            if !stack.is_visible() {
+                stack.set_visible(true);
+            }
+            let (canvas_width, canvas_height) = canvas_size(card_width, card_height, gap);
+            if stack.width_request() != canvas_width {
+                stack.set_width_request(canvas_width);
+            }
+            if stack.height_request() != canvas_height {
+                stack.set_height_request(canvas_height);
+            }
+
+            while pictures.len() > slots.len() {
+                if let Some(picture) = pictures.pop() {
+                    stack.remove(&picture);
+                }
+                let _ = states.pop();
+            }
+            while pictures.len() < slots.len() {
+                let picture = gtk::Picture::new();
+                picture.set_can_shrink(true);
+                picture.set_content_fit(gtk::ContentFit::Contain);
+                stack.put(&picture, 0.0, 0.0);
+                pictures.push(picture);
+                states.push(None);
+            }
+
+            for (slot, card) in slots.iter().enumerate() {
+                let picture = &pictures[slot];
+                let Some(card) = card else {
+                    picture.set_visible(false);
+                    states[slot] = None;
+                    continue;
+                };
+                picture.set_visible(true);
+                if picture.width_request() != card_width {
+                    picture.set_width_request(card_width);
+                }
+                if picture.height_request() != card_height {
+                    picture.set_height_request(card_height);
+                }
+
+                let selected = selected_slot == Some(slot);
+                let previous = states[slot];
+                if previous.map(|state| state.selected) != Some(selected) {
+                    if selected {
+                        picture.add_css_class("tableau-selected-card");
+                    } else {
+                        picture.remove_css_class("tableau-selected-card");
+                    }
+                }
+                if previous.map(|state| {
+                    (
+                        state.card,
+                        state.card_render_mode,
+                        state.card_width,
+                        state.card_height,
+                    )
+                }) != Some((*card, card_render_mode, card_width, card_height))
+                {
+                    if let Some(paintable) = self.paintable_for_card_display(
+                        Some(*card),
+                        card.face_up,
+                        deck,
+                        card_width,
+                        card_height,
+                    ) {
+                        picture.set_paintable(Some(&paintable));
+                    } else {
+                        picture.set_paintable(None::<&gdk::Paintable>);
+                    }
+                }
+
+                // The column gap can change without the card size changing, so
+                // slots are always repositioned.
+                let (x, y) = slot_origin(slot, card_width, card_height, gap);
+                stack.move_(picture, f64::from(x), f64::from(y));
+
+                states[slot] = Some(TableauPictureRenderState {
+                    card: *card,
+                    display_face_up: card.face_up,
+                    card_render_mode,
+                    selected,
+                    y,
+                    card_width,
+                    card_height,
+                });
+            }
+        }
+    }
 }
@@ -0,0 +1,894 @@
+use super::*;
+use crate::engine::boundary;
+use crate::engine::commands::EngineCommand;
+use crate::engine::replay::ReplayStep;
+use crate::engine::seed_ops;
+use crate::window::motion::MotionTarget;
+
+const REPLAY_PLAY_INTERVAL_MS: u64 = 500;
+
+/// Where a replayed move flies from and to, captured before it is applied.
+struct ReplayMotion {
+    from: Option<(f64, f64)>,
+    to: Option<(f64, f64)>,
+    /// The card that moves; `None` for a draw, which flies the new waste top.
+    card: Option<Card>,
+    run_texture: Option<gdk::Texture>,
+    foundation_slot: Option<usize>,
+}
+
+impl CardthropicWindow {
+    /// Starts a move log at the current board. The log it replaces is kept
+    /// for the viewer's Last Game button.
+    pub(super) fn begin_replay_recording(&self) {
+        self.archive_replay_recording();
+        let imp = self.imp();
+        if imp.chess_mode_active.get() {
+            self.end_replay_recording();
+            return;
+        }
+        let mode = self.active_game_mode();
+        let rules = self
+            .current_stats_key()
+            .map(|key| key.rules_label())
+            .unwrap_or_default();
+        let log = ReplayLog::new(
+            &imp.game.borrow(),
+            mode,
+            imp.current_seed.get(),
+            rules,
+            imp.klondike_draw_mode.get(),
+        );
+        let id = rand::random::<u64>().max(1);
+        *imp.replay_log.borrow_mut() = Some(log);
+        imp.replay_log_id.set(id);
+        imp.replay_board_id.set(id);
+        imp.replay_base_moves.set(imp.move_count.get());
+    }
+
+    pub(super) fn resume_replay_recording(&self, log: ReplayLog, id: u64, base_moves: u32) {
+        let imp = self.imp();
+        *imp.replay_log.borrow_mut() = Some(log);
+        imp.replay_log_id.set(id);
+        imp.replay_board_id.set(id);
+        imp.replay_base_moves.set(base_moves);
+    }
+
+    /// Stops recording; the board was changed some other way than a move.
+    pub(super) fn end_replay_recording(&self) {
+        let imp = self.imp();
+        *imp.replay_log.borrow_mut() = None;
+        imp.replay_log_id.set(0);
+        imp.replay_board_id.set(0);
+        imp.replay_base_moves.set(0);
+    }
+
+    pub(super) fn archive_replay_recording(&self) {
+        if let Some(log) = self.live_replay_log().filter(|log| !log.is_empty()) {
+            *self.imp().replay_last_game.borrow_mut() = Some(log);
+        }
+    }
+
+    /// The game on the board as a log, cut at the current undo position.
+    fn live_replay_log(&self) -> Option<ReplayLog> {
+        let imp = self.imp();
+        let id = imp.replay_log_id.get();
+        if id == 0 || imp.replay_board_id.get() != id {
+            return None;
+        }
+        let mut log = imp.replay_log.borrow().clone()?;
+        let moves = imp
+            .move_count
+            .get()
+            .saturating_sub(imp.replay_base_moves.get());
+        log.truncate(moves as usize);
+        Some(log)
+    }
+
+    /// Runs a move command and adds it to the log when it changed the board.
+    pub(super) fn execute_recorded_command(&self, mode: GameMode, command: EngineCommand) -> bool {
+        let imp = self.imp();
+        let changed = boundary::execute_command(&mut imp.game.borrow_mut(), mode, command).changed;
+        if !changed {
+            return changed;
+        }
+        let id = imp.replay_log_id.get();
+        if id == 0 || imp.replay_board_id.get() != id {
+            return changed;
+        }
+        let moves = imp
+            .move_count
+            .get()
+            .saturating_sub(imp.replay_base_moves.get()) as usize;
+        let mut replay_log = imp.replay_log.borrow_mut();
+        let Some(log) = replay_log.as_mut() else {
+            return changed;
+        };
+        if log.len() < moves {
+            // The log lost track of the board; stop rather than record a
+            // game that will not play back.
+            drop(replay_log);
+            self.end_replay_recording();
+            return changed;
+        }
+        log.truncate(moves);
+        log.record(command, &imp.game.borrow());
+        changed
+    }
+
+    pub(super) fn replay_viewer_active(&self) -> bool {
+        self.imp().replay_stash.borrow().is_some()
+    }
+
+    /// Sets the player's game aside so the viewer can use the board.
+    fn stash_game_for_replay(&self) {
+        if self.replay_viewer_active() {
+            return;
+        }
+        self.stop_rapid_wand();
+        self.stop_robot_mode();
+        self.cancel_seed_winnable_check(None);
+        self.flush_session_now();
+        let imp = self.imp();
+        let stash = ReplayStash {
+            game: imp.game.borrow().clone(),
+            session: self.build_saved_session(),
+            history: std::mem::take(&mut *imp.history.borrow_mut()),
+            future: std::mem::take(&mut *imp.future.borrow_mut()),
+            foundation_slot_suits: self.foundation_slot_suits_snapshot(),
+            stats_game_key: imp.stats_game_key.borrow().clone(),
+            stats_game_assisted: imp.stats_game_assisted.get(),
+            this_game: self.live_replay_log(),
+            replay_log: imp.replay_log.borrow().clone(),
+            replay_log_id: imp.replay_log_id.get(),
+            replay_board_id: imp.replay_board_id.get(),
+            replay_base_moves: imp.replay_base_moves.get(),
+            replay_last_game: imp.replay_last_game.borrow().clone(),
+        };
+        *imp.replay_stash.borrow_mut() = Some(stash);
+    }
+
+    fn restore_replay_stash(&self) {
+        let Some(stash) = self.imp().replay_stash.borrow_mut().take() else {
+            return;
+        };
+        let _ = self.restore_session_from_payload(&stash.session, "Back to your game.", false);
+        let imp = self.imp();
+        *imp.game.borrow_mut() = stash.game;
+        *imp.history.borrow_mut() = stash.history;
+        *imp.future.borrow_mut() = stash.future;
+        self.set_foundation_slot_suits(stash.foundation_slot_suits);
+        *imp.stats_game_key.borrow_mut() = stash.stats_game_key;
+        imp.stats_game_assisted.set(stash.stats_game_assisted);
+        *imp.replay_log.borrow_mut() = stash.replay_log;
+        imp.replay_log_id.set(stash.replay_log_id);
+        imp.replay_board_id.set(stash.replay_board_id);
+        imp.replay_base_moves.set(stash.replay_base_moves);
+        *imp.replay_last_game.borrow_mut() = stash.replay_last_game;
+        self.invalidate_card_render_cache();
+        self.render();
+    }
+
+    fn stop_replay_playback(&self) {
+        if let Some(source_id) = self.imp().replay_play_timer.borrow_mut().take() {
+            Self::remove_source_if_present(source_id);
+        }
+    }
+
+    /// Closes the viewer and puts the player's game back on the board.
+    pub(super) fn close_replay_viewer(&self) {
+        let dialog = self.imp().replay_dialog.borrow_mut().take();
+        self.leave_replay_viewer();
+        if let Some(dialog) = dialog {
+            dialog.close();
+        }
+    }
+
+    fn leave_replay_viewer(&self) {
+        let imp = self.imp();
+        self.stop_replay_playback();
+        *imp.replay_viewer_log.borrow_mut() = None;
+        imp.replay_viewer_position.set(0);
+        *imp.replay_scale.borrow_mut() = None;
+        *imp.replay_position_label.borrow_mut() = None;
+        *imp.replay_info_label.borrow_mut() = None;
+        *imp.replay_play_button.borrow_mut() = None;
+        *imp.replay_status_label.borrow_mut() = None;
+        self.restore_replay_stash();
+    }
+
+    fn set_replay_status(&self, text: &str, error: bool) {
+        let Some(label) = self.imp().replay_status_label.borrow().clone() else {
+            return;
+        };
+        label.set_text(text);
+        if error {
+            label.add_css_class("error");
+        } else {
+            label.remove_css_class("error");
+        }
+    }
+
+    fn refresh_replay_controls(&self) {
+        let imp = self.imp();
+        let log = imp.replay_viewer_log.borrow();
+        let len = log.as_ref().map_or(0, ReplayLog::len);
+        let position = imp.replay_viewer_position.get();
+        if let Some(scale) = imp.replay_scale.borrow().as_ref() {
+            scale.set_range(0.0, len.max(1) as f64);
+            scale.set_value(position as f64);
+            scale.set_sensitive(len > 0);
+        }
+        if let Some(label) = imp.replay_position_label.borrow().as_ref() {
+            label.set_text(&format!("Move {position} of {len}"));
+        }
+        if let Some(label) = imp.replay_info_label.borrow().as_ref() {
+            let info = match log.as_ref() {
+                Some(log) if log.rules.is_empty() => format!(
+                    "{}, seed {}",
+                    log.mode.label(),
+                    seed_ops::seed_label(log.seed)
+                ),
+                Some(log) => format!(
+                    "{} ({}), seed {}",
+                    log.mode.label(),
+                    log.rules,
+                    seed_ops::seed_label(log.seed)
+                ),
+                None => "No replay loaded.".to_string(),
+            };
+            label.set_text(&info);
+        }
+        if let Some(button) = imp.replay_play_button.borrow().as_ref() {
+            button.set_label(if imp.replay_play_timer.borrow().is_some() {
+                "Pause"
+            } else {
+                "Play"
+            });
+            button.set_sensitive(len > 0);
+        }
+    }
+
+    /// Puts the board the log reaches after `position` moves in place.
+    fn show_replay_position(&self, position: usize) -> Result<(), String> {
+        let imp = self.imp();
+        let (mode, draw_mode, seed, position, state) = {
+            let log = imp.replay_viewer_log.borrow();
+            let log = log
+                .as_ref()
+                .ok_or_else(|| "no replay is loaded".to_string())?;
+            let position = position.min(log.len());
+            let state = log.state_after(position)?;
+            (log.mode, log.draw_mode, log.seed, position, state)
+        };
+        let previous_mode = imp.current_game_mode.get();
+        {
+            let mut game = imp.game.borrow_mut();
+            game.set_runtime(state.runtime_for_mode(mode));
+            let _ = boundary::set_draw_mode(&mut game, mode, draw_mode);
+            if mode == GameMode::Freecell {
+                imp.freecell_card_count_mode
+                    .set(game.freecell().card_count_mode());
+            }
+            imp.freecell_cell_count
+                .set(game.freecell_for(FreecellLayout::FreeCell).freecell_count() as u8);
+            imp.spider_suit_mode.set(game.spider().suit_mode());
+        }
+        imp.current_game_mode.set(mode);
+        imp.klondike_draw_mode.set(draw_mode);
+        imp.current_seed.set(seed);
+        imp.move_count.set(position as u32);
+        imp.score.set(0);
+        imp.elapsed_seconds.set(0);
+        imp.timer_started.set(false);
+        imp.replay_board_id.set(0);
+        imp.replay_viewer_position.set(position);
+        *imp.selected_run.borrow_mut() = None;
+        imp.selected_freecell.set(None);
+        imp.waste_selected.set(false);
+        imp.history.borrow_mut().clear();
+        imp.future.borrow_mut().clear();
+        self.set_foundation_slot_suits([None, None, None, None]);
+        self.sync_foundation_slots_with_state();
+        self.set_seed_input_text(&seed_ops::seed_label(seed));
+        self.update_game_mode_menu_selection();
+        self.update_game_settings_menu();
+        self.invalidate_card_render_cache();
+        self.clear_hint_effects();
+        self.cancel_hint_loss_analysis();
+        self.reset_hint_cycle_memory();
+        self.reset_auto_play_memory();
+        if mode != previous_mode {
+            imp.last_metrics_key.set(0);
+            imp.last_stock_waste_foundation_size.set((0, 0, mode, 0));
+            self.handle_window_geometry_change();
+        }
+        *imp.status_override.borrow_mut() = Some(format!("Replay: move {position}."));
+        self.refresh_replay_controls();
+        self.render();
+        Ok(())
+    }
+
+    fn seek_replay(&self, position: usize) {
+        if let Err(err) = self.show_replay_position(position) {
+            self.set_replay_status(&format!("Cannot show that move: {err}."), true);
+        }
+    }
+
+    /// The flight of a move that is about to be replayed, as the move
+    /// actions animate it.
+    fn replay_motion(&self, mode: GameMode, command: EngineCommand) -> Option<ReplayMotion> {
+        if !self.should_play_non_drag_move_animation() {
+            return None;
+        }
+        match command {
+            EngineCommand::DrawOrRecycle { .. } => Some(ReplayMotion {
+                from: self.capture_motion_source(MotionTarget::Stock),
+                to: self.capture_motion_source(MotionTarget::WasteTop),
+                card: None,
+                run_texture: None,
+                foundation_slot: None,
+            }),
+            EngineCommand::MoveWasteToFoundation => {
+                let card = boundary::waste_top(&self.imp().game.borrow(), mode)?;
+                let slot = self.resolve_foundation_slot_for_card(card, None)?;
+                Some(ReplayMotion {
+                    from: self.capture_motion_source(MotionTarget::WasteTop),
+                    to: self.capture_motion_source(MotionTarget::Foundation(slot)),
+                    card: Some(card),
+                    run_texture: None,
+                    foundation_slot: Some(slot),
+                })
+            }
+            EngineCommand::MoveWasteToTableau { dst } => Some(ReplayMotion {
+                from: self.capture_motion_source(MotionTarget::WasteTop),
+                to: self.capture_tableau_landing_point(dst),
+                card: Some(boundary::waste_top(&self.imp().game.borrow(), mode)?),
+                run_texture: None,
+                foundation_slot: None,
+            }),
+            EngineCommand::MoveTableauRunToTableau { src, start, dst } => Some(ReplayMotion {
+                from: self.capture_motion_source(MotionTarget::TableauCard {
+                    col: src,
+                    index: start,
+                }),
+                to: self.capture_tableau_landing_point(dst),
+                card: None,
+                run_texture: Some(self.glitched_texture_for_tableau_run_motion(src, start)?),
+                foundation_slot: None,
+            }),
+            EngineCommand::MoveTableauTopToFoundation { src } => {
+                let card = boundary::tableau_top(&self.imp().game.borrow(), mode, src)?;
+                let slot = self.resolve_foundation_slot_for_card(card, None)?;
+                let index =
+                    boundary::tableau_len(&self.imp().game.borrow(), mode, src)?.checked_sub(1)?;
+                Some(ReplayMotion {
+                    from: self.capture_motion_source(MotionTarget::TableauCard { col: src, index }),
+                    to: self.capture_motion_source(MotionTarget::Foundation(slot)),
+                    card: Some(card),
+                    run_texture: None,
+                    foundation_slot: Some(slot),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Plays the next move on the board. Returns `false` at the end of the
+    /// log or when the move does not apply.
+    fn step_replay_forward(&self) -> bool {
+        let imp = self.imp();
+        let position = imp.replay_viewer_position.get();
+        let (mode, step) = {
+            let log = imp.replay_viewer_log.borrow();
+            let Some(log) = log.as_ref() else {
+                return false;
+            };
+            (log.mode, log.steps().get(position).cloned())
+        };
+        let Some(step) = step else {
+            return false;
+        };
+        if imp.current_game_mode.get() != mode || imp.move_count.get() as usize != position {
+            self.seek_replay(position + 1);
+            return true;
+        }
+        let motion = match step {
+            ReplayStep::Command(command) => self.replay_motion(mode, command),
+            ReplayStep::Reshuffle(_) => None,
+        };
+        let applied = imp
+            .replay_viewer_log
+            .borrow()
+            .as_ref()
+            .is_some_and(|log| log.apply_step(&mut imp.game.borrow_mut(), position));
+        if !applied {
+            self.set_replay_status(&format!("Move {} does not apply here.", position + 1), true);
+            return false;
+        }
+        if let Some((card, slot)) = motion
+            .as_ref()
+            .and_then(|motion| motion.card.zip(motion.foundation_slot))
+        {
+            self.establish_foundation_slot_for_card(card, slot);
+        }
+        self.sync_foundation_slots_with_state();
+        let position = position + 1;
+        imp.move_count.set(position as u32);
+        imp.replay_viewer_position.set(position);
+        *imp.selected_run.borrow_mut() = None;
+        imp.selected_freecell.set(None);
+        imp.waste_selected.set(false);
+        *imp.status_override.borrow_mut() = Some(format!("Replay: move {position}."));
+        self.refresh_replay_controls();
+        self.render();
+        if let Some(motion) = motion {
+            let texture = motion.run_texture.or_else(|| {
+                motion
+                    .card
+                    .or_else(|| boundary::waste_top(&imp.game.borrow(), mode))
+                    .and_then(|card| self.glitched_texture_for_card_motion(card))
+            });
+            if let (Some(texture), Some(from), Some(to)) = (texture, motion.from, motion.to) {
+                self.play_move_animation_to_point(texture, from, to);
+            }
+        }
+        true
+    }
+
+    fn step_replay_back(&self) {
+        let position = self.imp().replay_viewer_position.get();
+        if position > 0 {
+            self.seek_replay(position - 1);
+        }
+    }
+
+    fn replay_at_end(&self) -> bool {
+        let imp = self.imp();
+        imp.replay_viewer_log
+            .borrow()
+            .as_ref()
+            .is_none_or(|log| imp.replay_viewer_position.get() >= log.len())
+    }
+
+    fn toggle_replay_playback(&self) {
+        if self.imp().replay_play_timer.borrow().is_some() {
+            self.stop_replay_playback();
+            self.refresh_replay_controls();
+            return;
+        }
+        if self.imp().replay_viewer_log.borrow().is_none() {
+            return;
+        }
+        if self.replay_at_end() {
+            self.seek_replay(0);
+        }
+        let timer = glib::timeout_add_local(
+            Duration::from_millis(REPLAY_PLAY_INTERVAL_MS),
+            glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                #[upgrade_or]
+                glib::ControlFlow::Break,
+                move || {
+                    if window.step_replay_forward() && !window.replay_at_end() {
+                        return glib::ControlFlow::Continue;
+                    }
+                    window.imp().replay_play_timer.borrow_mut().take();
+                    window.refresh_replay_controls();
+                    glib::ControlFlow::Break
+                }
+            ),
+        );
+        *self.imp().replay_play_timer.borrow_mut() = Some(timer);
+        self.refresh_replay_controls();
+    }
+
+    /// Puts `log` in the viewer at its opening position.
+    fn load_replay(&self, log: ReplayLog, source: &str) {
+        self.stop_replay_playback();
+        self.stash_game_for_replay();
+        let len = log.len();
+        let won = log.is_won();
+        *self.imp().replay_viewer_log.borrow_mut() = Some(log);
+        match self.show_replay_position(0) {
+            Ok(()) => self.set_replay_status(
+                &format!(
+                    "Loaded {source}: {len} move{}{}.",
+                    if len == 1 { "" } else { "s" },
+                    if won { ", ending in a win" } else { "" }
+                ),
+                false,
+            ),
+            Err(err) => self.set_replay_status(&format!("Cannot load {source}: {err}."), true),
+        }
+    }
+
+    fn load_replay_text(&self, text: &str, source: &str) {
+        match ReplayLog::parse(text) {
+            Ok(log) => self.load_replay(log, source),
+            Err(err) => self.set_replay_status(&format!("Not a replay: {err}."), true),
+        }
+    }
+
+    fn load_this_game_replay(&self) {
+        let log = match self.imp().replay_stash.borrow().as_ref() {
+            Some(stash) => stash.this_game.clone(),
+            None => self.live_replay_log(),
+        };
+        match log.filter(|log| !log.is_empty()) {
+            Some(log) => self.load_replay(log, "this game"),
+            None => self.set_replay_status("This game has no recorded moves yet.", true),
+        }
+    }
+
+    fn load_last_game_replay(&self) {
+        let log = match self.imp().replay_stash.borrow().as_ref() {
+            Some(stash) => stash.replay_last_game.clone(),
+            None => self.imp().replay_last_game.borrow().clone(),
+        };
+        match log {
+            Some(log) => self.load_replay(log, "the last game"),
+            None => self.set_replay_status("No earlier game was recorded.", true),
+        }
+    }
+
+    fn replay_export_text(&self) -> Option<(String, String)> {
+        let log = self.imp().replay_viewer_log.borrow();
+        let log = log.as_ref()?;
+        let name = format!(
+            "cardthropic-replay-{}-{}.txt",
+            log.mode.id(),
+            seed_ops::seed_label(log.seed).replace([':', '/'], "-")
+        );
+        Some((log.to_text(), name))
+    }
+
+    fn paste_replay_from_clipboard(&self) {
+        self.clipboard().read_text_async(
+            None::<&gio::Cancellable>,
+            glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                move |result| match result {
+                    Ok(Some(text)) => window.load_replay_text(&text, "the pasted replay"),
+                    Ok(None) => window.set_replay_status("The clipboard is empty.", true),
+                    Err(err) => {
+                        window.set_replay_status(&format!("Paste failed: {err}."), true)
+                    }
+                }
+            ),
+        );
+    }
+
+    fn copy_replay_to_clipboard(&self) {
+        match self.replay_export_text() {
+            Some((text, _)) => {
+                self.clipboard().set_text(&text);
+                self.set_replay_status("Copied the replay to the clipboard.", false);
+            }
+            None => self.set_replay_status("Load a replay to copy it.", true),
+        }
+    }
+
+    fn replay_file_filters() -> (gio::ListStore, gtk::FileFilter) {
+        let filter = gtk::FileFilter::new();
+        filter.set_name(Some("Replay files"));
+        filter.add_pattern("*.txt");
+        filter.add_mime_type("text/plain");
+        let filters = gio::ListStore::new::<gtk::FileFilter>();
+        filters.append(&filter);
+        (filters, filter)
+    }
+
+    fn open_replay_file(&self, dialog: &gtk::Window) {
+        let file_dialog = gtk::FileDialog::builder()
+            .title("Open Replay")
+            .modal(true)
+            .build();
+        let (filters, filter) = Self::replay_file_filters();
+        file_dialog.set_filters(Some(&filters));
+        file_dialog.set_default_filter(Some(&filter));
+        file_dialog.open(
+            Some(dialog),
+            None::<&gio::Cancellable>,
+            glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                move |result: Result<gio::File, glib::Error>| match result {
+                    Ok(file) => match file.load_contents(None::<&gio::Cancellable>) {
+                        Ok((contents, _)) => {
+                            let text = String::from_utf8_lossy(contents.as_ref()).to_string();
+                            window.load_replay_text(&text, "the replay file");
+                        }
+                        Err(err) => {
+                            window.set_replay_status(&format!("Open failed: {err}."), true)
+                        }
+                    },
+                    Err(err) => {
+                        // Ignore user-cancel; report other failures.
+                        if !err.matches(gio::IOErrorEnum::Cancelled) {
+                            window.set_replay_status(&format!("Open failed: {err}."), true);
+                        }
+                    }
+                }
+            ),
+        );
+    }
+
+    fn export_replay_file(&self, dialog: &gtk::Window) {
+        let Some((text, name)) = self.replay_export_text() else {
+            self.set_replay_status("Load a replay to export it.", true);
+            return;
+        };
+        let file_dialog = gtk::FileDialog::builder()
+            .title("Export Replay")
+            .modal(true)
+            .initial_name(name.as_str())
+            .build();
+        let (filters, filter) = Self::replay_file_filters();
+        file_dialog.set_filters(Some(&filters));
+        file_dialog.set_default_filter(Some(&filter));
+        file_dialog.save(
+            Some(dialog),
+            None::<&gio::Cancellable>,
+            glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                move |result: Result<gio::File, glib::Error>| match result {
+                    Ok(file) => match file.replace_contents(
+                        text.as_bytes(),
+                        None,
+                        false,
+                        gio::FileCreateFlags::REPLACE_DESTINATION,
+                        None::<&gio::Cancellable>,
+                    ) {
+                        Ok(_) => window.set_replay_status("Exported the replay.", false),
+                        Err(err) => {
+                            window.set_replay_status(&format!("Export failed: {err}."), true)
+                        }
+                    },
+                    Err(err) => {
+                        if !err.matches(gio::IOErrorEnum::Cancelled) {
+                            window.set_replay_status(&format!("Export failed: {err}."), true);
+                        }
+                    }
+                }
+            ),
+        );
+    }
+
+    pub(super) fn show_replay_viewer_dialog(&self) {
+        if let Some(existing) = self.imp().replay_dialog.borrow().as_ref() {
+            existing.present();
+            return;
+        }
+        if self.imp().chess_mode_active.get() {
+            *self.imp().status_override.borrow_mut() =
+                Some("The Replay Viewer plays back solitaire games.".to_string());
+            self.render();
+            return;
+        }
+
+        let dialog = gtk::Window::builder()
+            .title("Replay Viewer")
+            .transient_for(self)
+            .modal(false)
+            .default_width(560)
+            .default_height(220)
+            .build();
+        dialog.set_destroy_with_parent(true);
+        dialog.set_hide_on_close(false);
+        dialog.connect_close_request(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[upgrade_or]
+            glib::Propagation::Proceed,
+            move |_| {
+                window.imp().replay_dialog.borrow_mut().take();
+                window.leave_replay_viewer();
+                glib::Propagation::Proceed
+            }
+        ));
+        let dialog_keys = gtk::EventControllerKey::new();
+        dialog_keys.set_propagation_phase(gtk::PropagationPhase::Capture);
+        dialog_keys.connect_key_pressed(glib::clone!(
+            #[weak]
+            dialog,
+            #[upgrade_or]
+            glib::Propagation::Proceed,
+            move |_, key, _, _| {
+                if key == gdk::Key::Escape {
+                    dialog.close();
+                    return glib::Propagation::Stop;
+                }
+                glib::Propagation::Proceed
+            }
+        ));
+        dialog.add_controller(dialog_keys);
+
+        let info_label = gtk::Label::new(None);
+        info_label.set_xalign(0.0);
+        info_label.set_wrap(true);
+        info_label.add_css_class("heading");
+
+        let scale = gtk::Scale::with_range(gtk::Orientation::Horizontal, 0.0, 1.0, 1.0);
+        scale.set_digits(0);
+        scale.set_draw_value(false);
+        scale.set_hexpand(true);
+        scale.connect_change_value(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[upgrade_or]
+            glib::Propagation::Proceed,
+            move |_, _, value| {
+                window.stop_replay_playback();
+                window.seek_replay(value.round().max(0.0) as usize);
+                glib::Propagation::Stop
+            }
+        ));
+        let position_label = gtk::Label::new(None);
+        position_label.add_css_class("dim-label");
+        let scrub_row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        scrub_row.append(&scale);
+        scrub_row.append(&position_label);
+
+        let transport_row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        transport_row.set_halign(gtk::Align::Center);
+        let start_button = gtk::Button::with_label("Start");
+        start_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.stop_replay_playback();
+                window.seek_replay(0);
+            }
+        ));
+        let back_button = gtk::Button::with_label("Back");
+        back_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.stop_replay_playback();
+                window.step_replay_back();
+            }
+        ));
+        let play_button = gtk::Button::with_label("Play");
+        play_button.add_css_class("suggested-action");
+        play_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.toggle_replay_playback();
+            }
+        ));
+        let forward_button = gtk::Button::with_label("Forward");
+        forward_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.stop_replay_playback();
+                window.step_replay_forward();
+                window.refresh_replay_controls();
+            }
+        ));
+        let end_button = gtk::Button::with_label("End");
+        end_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.stop_replay_playback();
+                let len = window
+                    .imp()
+                    .replay_viewer_log
+                    .borrow()
+                    .as_ref()
+                    .map_or(0, ReplayLog::len);
+                window.seek_replay(len);
+            }
+        ));
+        transport_row.append(&start_button);
+        transport_row.append(&back_button);
+        transport_row.append(&play_button);
+        transport_row.append(&forward_button);
+        transport_row.append(&end_button);
+
+        let status_label = gtk::Label::new(None);
+        status_label.set_xalign(0.0);
+        status_label.set_wrap(true);
+
+        let root = gtk::Box::new(gtk::Orientation::Vertical, 8);
+        root.set_margin_top(10);
+        root.set_margin_bottom(10);
+        root.set_margin_start(10);
+        root.set_margin_end(10);
+        root.append(&info_label);
+        root.append(&scrub_row);
+        root.append(&transport_row);
+        root.append(&status_label);
+
+        let actions_row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        actions_row.set_halign(gtk::Align::End);
+        let this_game_button = gtk::Button::with_label("This Game");
+        this_game_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.load_this_game_replay();
+            }
+        ));
+        let last_game_button = gtk::Button::with_label("Last Game");
+        last_game_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.load_last_game_replay();
+            }
+        ));
+        let open_button = gtk::Button::with_label("Open…");
+        open_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[weak]
+            dialog,
+            move |_| {
+                window.open_replay_file(&dialog);
+            }
+        ));
+        let paste_button = gtk::Button::with_label("Paste");
+        paste_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.paste_replay_from_clipboard();
+            }
+        ));
+        let copy_button = gtk::Button::with_label("Copy");
+        copy_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.copy_replay_to_clipboard();
+            }
+        ));
+        let export_button = gtk::Button::with_label("Export…");
+        export_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[weak]
+            dialog,
+            move |_| {
+                window.export_replay_file(&dialog);
+            }
+        ));
+        actions_row.append(&this_game_button);
+        actions_row.append(&last_game_button);
+        actions_row.append(&open_button);
+        actions_row.append(&paste_button);
+        actions_row.append(&copy_button);
+        actions_row.append(&export_button);
+        root.append(&actions_row);
+        dialog.set_child(Some(&root));
+
+        let imp = self.imp();
+        *imp.replay_scale.borrow_mut() = Some(scale);
+        *imp.replay_position_label.borrow_mut() = Some(position_label);
+        *imp.replay_info_label.borrow_mut() = Some(info_label);
+        *imp.replay_play_button.borrow_mut() = Some(play_button);
+        *imp.replay_status_label.borrow_mut() = Some(status_label);
+        *imp.replay_dialog.borrow_mut() = Some(dialog.clone());
+        self.refresh_replay_controls();
+        let has_this_game = self.live_replay_log().is_some_and(|log| !log.is_empty());
+        if has_this_game {
+            self.load_this_game_replay();
+        } else if imp.replay_last_game.borrow().is_some() {
+            self.load_last_game_replay();
+        } else {
+            self.set_replay_status(
+                "No moves recorded yet. Play a few moves, or open a replay file.",
+                false,
+            );
+        }
+        dialog.present();
+    }
+}
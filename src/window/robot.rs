@@ -3,6 +3,8 @@ use crate::engine::boundary;
 use crate::engine::freecell_planner::{
     self, FreecellPlannerAction, FreecellPlannerConfig, FreecellPlannerResult,
 };
+use crate::engine::moves::map_solver_move_to_hint_move;
+use crate::engine::open_tableau_planner;
 use crate::engine::seed_ops;
 use crate::game::{terminal_state, ChessColor, ChessPosition, ChessTerminalState};
 use crate::window::hint_core::FreecellHintAction;
@@ -105,13 +107,13 @@ impl CardthropicWindow {
             if game.can_move_freecell_to_foundation(cell) {
                 count = count.saturating_add(1);
             }
-            for dst in 0..8 {
+            for dst in 0..game.tableau().len() {
                 if game.can_move_freecell_to_tableau(cell, dst) {
                     count = count.saturating_add(1);
                 }
             }
         }
-        for src in 0..8 {
+        for src in 0..game.tableau().len() {
             if game.can_move_tableau_top_to_foundation(src) {
                 count = count.saturating_add(1);
             }
@@ -122,7 +124,7 @@ impl CardthropicWindow {
             }
             let len = game.tableau().get(src).map(Vec::len).unwrap_or(0);
             for start in 0..len {
-                for dst in 0..8 {
+                for dst in 0..game.tableau().len() {
                     if game.can_move_tableau_run_to_tableau(src, start, dst) {
                         count = count.saturating_add(1);
                     }
@@ -139,7 +141,7 @@ impl CardthropicWindow {
             .filter(|slot| slot.is_none())
             .count();
         let et = game.tableau().iter().filter(|col| col.is_empty()).count();
-        ((ef + 1) * (1usize << et)) as u32
+        game.rules().supermove_capacity(ef, et) as u32
     }
 
     fn robot_progress_snapshot(&self) -> (u32, u32) {
@@ -165,7 +167,10 @@ impl CardthropicWindow {
                     .count() as u32;
                 (foundation_like, empty_cols)
             }
-            GameMode::Freecell => {
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => {
                 let game = imp.game.borrow();
                 let freecell = game.freecell();
                 let foundation_like =
@@ -177,6 +182,76 @@ impl CardthropicWindow {
                     .count() as u32;
                 (foundation_like, empty_cols)
             }
+            GameMode::Yukon => {
+                let game = imp.game.borrow();
+                let yukon = game.yukon();
+                let foundation_like =
+                    yukon.foundations().iter().map(Vec::len).sum::<usize>() as u32;
+                let empty_cols = yukon.tableau().iter().filter(|col| col.is_empty()).count() as u32;
+                (foundation_like, empty_cols)
+            }
+            GameMode::Pyramid => {
+                let game = imp.game.borrow();
+                // Removed cards stand in for foundation progress; Pyramid has
+                // no columns to empty.
+                (game.pyramid().removed().len() as u32, 0)
+            }
+            GameMode::TriPeaks | GameMode::Golf => {
+                let Some(layout) = WasteBuildLayout::for_mode(self.active_game_mode()) else {
+                    return (0, 0);
+                };
+                let game = imp.game.borrow();
+                let waste_build = game.waste_build(layout);
+                // Cleared slots stand in for foundation progress.
+                let cleared = layout.slot_count() - waste_build.tableau_remaining();
+                (cleared as u32, 0)
+            }
+            GameMode::FortyThieves => {
+                let game = imp.game.borrow();
+                let forty_thieves = game.forty_thieves();
+                let foundation_like = forty_thieves
+                    .foundations()
+                    .iter()
+                    .map(Vec::len)
+                    .sum::<usize>() as u32;
+                (foundation_like, forty_thieves.empty_columns() as u32)
+            }
+            GameMode::Scorpion => {
+                let game = imp.game.borrow();
+                let scorpion = game.scorpion();
+                let foundation_like = (scorpion.completed_runs() as u32).saturating_mul(13);
+                let empty_cols = scorpion
+                    .tableau()
+                    .iter()
+                    .filter(|col| col.is_empty())
+                    .count() as u32;
+                (foundation_like, empty_cols)
+            }
+            GameMode::Canfield => {
+                let game = imp.game.borrow();
+                let canfield = game.canfield();
+                let foundation_like =
+                    canfield.foundations().iter().map(Vec::len).sum::<usize>() as u32;
+                let empty_cols = canfield
+                    .tableau()
+                    .iter()
+                    .filter(|col| col.is_empty())
+                    .count() as u32;
+                (foundation_like, empty_cols)
+            }
+            GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                let Some(layout) = OpenTableauLayout::for_mode(self.active_game_mode()) else {
+                    return (0, 0);
+                };
+                let game = imp.game.borrow();
+                let open_tableau = game.open_tableau(layout);
+                let empty_cols = open_tableau
+                    .tableau()
+                    .iter()
+                    .filter(|col| col.is_empty())
+                    .count() as u32;
+                (open_tableau.foundation_cards() as u32, empty_cols)
+            }
         }
     }
 
@@ -249,7 +324,10 @@ impl CardthropicWindow {
                 let _ = visible_total;
                 None
             }
-            GameMode::Freecell => {
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => {
                 let f = game.freecell();
                 let expected_total = usize::from(f.card_count_mode().card_count());
                 let mut seen = HashSet::new();
@@ -305,8 +383,9 @@ impl CardthropicWindow {
             }
             GameMode::Spider => {
                 let s = game.spider();
-                if s.completed_runs() > 8 {
-                    return Some("spider completed_runs exceeds 8".to_string());
+                let goal_runs = s.rule_set().goal_runs();
+                if s.completed_runs() > goal_runs {
+                    return Some(format!("spider completed_runs exceeds {goal_runs}"));
                 }
 
                 let mut total = s.stock_len() + s.completed_runs() * 13;
@@ -322,9 +401,242 @@ impl CardthropicWindow {
                     }
                 }
 
+                let expected = s.rule_set().decks() * 52;
+                if total != expected {
+                    return Some(format!(
+                        "spider card accounting mismatch (total={total}, expected={expected})"
+                    ));
+                }
+                None
+            }
+            GameMode::Yukon => {
+                let y = game.yukon();
+                let mut seen = HashSet::new();
+                for (fidx, pile) in y.foundations().iter().enumerate() {
+                    for (idx, card) in pile.iter().enumerate() {
+                        if card.suit.foundation_index() != fidx {
+                            return Some("yukon foundation suit mismatch".to_string());
+                        }
+                        if usize::from(card.rank) != idx + 1 {
+                            return Some("yukon foundation rank ordering invalid".to_string());
+                        }
+                        if !seen.insert((card.suit, card.rank)) {
+                            return Some("yukon duplicate card detected".to_string());
+                        }
+                    }
+                }
+                for pile in y.tableau() {
+                    if Self::tableau_has_face_down_after_face_up(pile) {
+                        return Some("yukon tableau has face-down card above face-up".to_string());
+                    }
+                    for card in pile {
+                        if !seen.insert((card.suit, card.rank)) {
+                            return Some("yukon duplicate card detected".to_string());
+                        }
+                    }
+                }
+                if seen.len() != 52 {
+                    return Some(format!(
+                        "yukon card accounting mismatch (total={}, expected=52)",
+                        seen.len()
+                    ));
+                }
+                None
+            }
+            GameMode::Pyramid => {
+                let p = game.pyramid();
+                let mut seen = HashSet::new();
+                for (slot, card) in p.pyramid().iter().enumerate() {
+                    if let Some(card) = card {
+                        if !card.face_up {
+                            return Some(format!("pyramid slot {slot} holds a face-down card"));
+                        }
+                        if !seen.insert((card.suit, card.rank)) {
+                            return Some("pyramid duplicate card detected".to_string());
+                        }
+                    }
+                }
+                for card in p.stock().iter().chain(p.waste()).chain(p.removed()) {
+                    if !seen.insert((card.suit, card.rank)) {
+                        return Some("pyramid duplicate card detected".to_string());
+                    }
+                }
+                if seen.len() != 52 {
+                    return Some(format!(
+                        "pyramid card accounting mismatch (total={}, expected=52)",
+                        seen.len()
+                    ));
+                }
+                None
+            }
+            GameMode::TriPeaks | GameMode::Golf => {
+                let layout = WasteBuildLayout::for_mode(mode)?;
+                let w = game.waste_build(layout);
+                let mut seen = HashSet::new();
+                for (slot, card) in w.tableau().iter().enumerate() {
+                    if let Some(card) = card {
+                        if w.is_exposed(slot) && !card.face_up {
+                            return Some(format!(
+                                "{} slot {slot} is exposed but face-down",
+                                mode.id()
+                            ));
+                        }
+                        if !seen.insert((card.suit, card.rank)) {
+                            return Some(format!("{} duplicate card detected", mode.id()));
+                        }
+                    }
+                }
+                for card in w.stock().iter().chain(w.waste()) {
+                    if !seen.insert((card.suit, card.rank)) {
+                        return Some(format!("{} duplicate card detected", mode.id()));
+                    }
+                }
+                if seen.len() != 52 {
+                    return Some(format!(
+                        "{} card accounting mismatch (total={}, expected=52)",
+                        mode.id(),
+                        seen.len()
+                    ));
+                }
+                None
+            }
+            GameMode::FortyThieves => {
+                use std::collections::HashMap;
+
+                let f = game.forty_thieves();
+                let mut copies: HashMap<(crate::game::Suit, u8), usize> = HashMap::new();
+                for (pile_idx, pile) in f.foundations().iter().enumerate() {
+                    for (idx, card) in pile.iter().enumerate() {
+                        if card.suit.foundation_index() != pile_idx / 2 {
+                            return Some("forty thieves foundation suit mismatch".to_string());
+                        }
+                        if usize::from(card.rank) != idx + 1 {
+                            return Some(
+                                "forty thieves foundation rank ordering invalid".to_string(),
+                            );
+                        }
+                    }
+                }
+                let all_cards = f
+                    .stock()
+                    .iter()
+                    .chain(f.waste())
+                    .chain(f.foundations().iter().flatten())
+                    .chain(f.tableau().iter().flatten());
+                for card in all_cards {
+                    let count = copies.entry((card.suit, card.rank)).or_default();
+                    *count += 1;
+                    if *count > 2 {
+                        return Some("forty thieves card appears more than twice".to_string());
+                    }
+                }
+                let total: usize = copies.values().sum();
                 if total != 104 {
                     return Some(format!(
-                        "spider card accounting mismatch (total={total}, expected=104)"
+                        "forty thieves card accounting mismatch (total={total}, expected=104)"
+                    ));
+                }
+                None
+            }
+            GameMode::Scorpion => {
+                let s = game.scorpion();
+                if s.completed_runs() > 4 {
+                    return Some("scorpion completed_runs exceeds 4".to_string());
+                }
+
+                let mut seen = HashSet::new();
+                let mut total = s.completed_runs() * 13;
+                for card in s.reserve().iter().chain(s.tableau().iter().flatten()) {
+                    if !seen.insert((card.suit, card.rank)) {
+                        return Some("scorpion duplicate card detected".to_string());
+                    }
+                    total += 1;
+                }
+                for pile in s.tableau() {
+                    if Self::tableau_has_face_down_after_face_up(pile) {
+                        return Some(
+                            "scorpion tableau has face-down card above face-up".to_string(),
+                        );
+                    }
+                }
+                if total != 52 {
+                    return Some(format!(
+                        "scorpion card accounting mismatch (total={total}, expected=52)"
+                    ));
+                }
+                None
+            }
+            GameMode::Canfield => {
+                let c = game.canfield();
+                let base = c.base();
+                for (pile_idx, pile) in c.foundations().iter().enumerate() {
+                    let mut expected = base.rank;
+                    for card in pile {
+                        if card.suit.foundation_index() != pile_idx {
+                            return Some("canfield foundation suit mismatch".to_string());
+                        }
+                        if card.rank != expected {
+                            return Some("canfield foundation rank ordering invalid".to_string());
+                        }
+                        expected = crate::game::FoundationBase::next_rank(expected);
+                    }
+                }
+
+                let mut seen = HashSet::new();
+                let all_cards = c
+                    .stock()
+                    .iter()
+                    .chain(c.waste())
+                    .chain(c.reserve())
+                    .chain(c.foundations().iter().flatten())
+                    .chain(c.tableau().iter().flatten());
+                for card in all_cards {
+                    if !seen.insert((card.suit, card.rank)) {
+                        return Some("canfield duplicate card detected".to_string());
+                    }
+                }
+                if seen.len() != 52 {
+                    return Some(format!(
+                        "canfield card accounting mismatch (total={}, expected=52)",
+                        seen.len()
+                    ));
+                }
+                None
+            }
+            GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                let layout = OpenTableauLayout::for_mode(mode)?;
+                let o = game.open_tableau(layout);
+                let mut seen = HashSet::new();
+                for (fidx, pile) in o.foundations().iter().enumerate() {
+                    for (idx, card) in pile.iter().enumerate() {
+                        if card.suit.foundation_index() != fidx {
+                            return Some("open tableau foundation suit mismatch".to_string());
+                        }
+                        if usize::from(card.rank) != idx + 1 {
+                            return Some(
+                                "open tableau foundation rank ordering invalid".to_string(),
+                            );
+                        }
+                        if !seen.insert((card.suit, card.rank)) {
+                            return Some("open tableau duplicate card detected".to_string());
+                        }
+                    }
+                }
+                if o.tableau().len() != layout.columns() {
+                    return Some("open tableau column count mismatch".to_string());
+                }
+                for card in o.tableau().iter().flatten() {
+                    if !card.face_up {
+                        return Some("open tableau contains face-down card".to_string());
+                    }
+                    if !seen.insert((card.suit, card.rank)) {
+                        return Some("open tableau duplicate card detected".to_string());
+                    }
+                }
+                if seen.len() != 52 {
+                    return Some(format!(
+                        "open tableau card accounting mismatch (total={}, expected=52)",
+                        seen.len()
                     ));
                 }
                 None
@@ -365,7 +677,7 @@ impl CardthropicWindow {
         imp.robot_freecell_planner_restart_debounce_ticks.set(0);
         imp.robot_freecell_planner_last_start_marker.set(0);
 
-        if self.active_game_mode() == GameMode::Freecell {
+        if self.active_game_mode().is_freecell_family() {
             let state_hash = self.current_game_hash();
             self.robot_mark_seen_state(state_hash);
             imp.robot_recent_hashes.borrow_mut().push_back(state_hash);
@@ -399,7 +711,7 @@ impl CardthropicWindow {
     }
 
     fn robot_track_hash_oscillation_and_mark_loss(&self, solver_source: &str) -> bool {
-        if self.active_game_mode() != GameMode::Freecell {
+        if !self.active_game_mode().is_freecell_family() {
             return false;
         }
         let hash = self.current_game_hash();
@@ -557,7 +869,7 @@ impl CardthropicWindow {
         action_signature: Option<String>,
         solver_source: &str,
     ) -> bool {
-        if self.active_game_mode() != GameMode::Freecell {
+        if !self.active_game_mode().is_freecell_family() {
             return false;
         }
         let Some(sig) = action_signature else {
@@ -905,7 +1217,7 @@ impl CardthropicWindow {
     pub(super) fn projected_freecell_planner_state(
         &self,
     ) -> Option<(crate::game::FreecellGame, u64)> {
-        if self.active_game_mode() != GameMode::Freecell {
+        if !self.active_game_mode().is_freecell_family() {
             return None;
         }
         let mut projected = self.imp().game.borrow().freecell().clone();
@@ -928,7 +1240,7 @@ impl CardthropicWindow {
     }
 
     pub(super) fn start_freecell_planner_if_needed(&self) {
-        if self.active_game_mode() != GameMode::Freecell {
+        if !self.active_game_mode().is_freecell_family() {
             return;
         }
         if self.imp().robot_freecell_planner_running.get() {
@@ -1048,7 +1360,7 @@ impl CardthropicWindow {
         let foundation_progressed = foundation_like > imp.robot_last_foundation_like.get();
         let mut progressed = foundation_progressed || empty_cols > imp.robot_last_empty_cols.get();
 
-        if self.active_game_mode() == GameMode::Freecell {
+        if self.active_game_mode().is_freecell_family() {
             let game = imp.game.borrow();
             let freecell = game.freecell();
             let mobility_now = Self::freecell_mobility_count(freecell);
@@ -1091,7 +1403,7 @@ impl CardthropicWindow {
                 self.render();
                 return true;
             }
-            if self.active_game_mode() == GameMode::Freecell
+            if self.active_game_mode().is_freecell_family()
                 && drought >= Self::ROBOT_FOUNDATION_DROUGHT_LIMIT
             {
                 self.cancel_freecell_planner();
@@ -1127,7 +1439,7 @@ impl CardthropicWindow {
 
         let streak = imp.robot_stall_streak.get().saturating_add(1);
         imp.robot_stall_streak.set(streak);
-        if self.active_game_mode() == GameMode::Freecell && streak >= Self::ROBOT_STALL_LIMIT {
+        if self.active_game_mode().is_freecell_family() && streak >= Self::ROBOT_STALL_LIMIT {
             self.emit_robot_status(
                 "running",
                 "search_reset",
@@ -1198,7 +1510,7 @@ impl CardthropicWindow {
     }
 
     fn robot_freecell_metrics_suffix(&self) -> String {
-        if self.active_game_mode() != GameMode::Freecell {
+        if !self.active_game_mode().is_freecell_family() {
             return String::new();
         }
         let freecell_moves = self
@@ -1517,7 +1829,7 @@ impl CardthropicWindow {
 
         let state_hash = self.current_game_hash();
         let mode = self.active_game_mode();
-        let (scripted_enabled, scripted_remaining, scripted_ready) = if mode == GameMode::Freecell {
+        let (scripted_enabled, scripted_remaining, scripted_ready) = if mode.is_freecell_family() {
             let playback = self.imp().robot_freecell_playback.borrow();
             (
                 playback.use_scripted_line(),
@@ -1541,7 +1853,7 @@ impl CardthropicWindow {
             let game = self.imp().game.borrow();
             let spider = game.spider();
             let stock_cards = spider.stock_len();
-            let stock_deals_left = stock_cards / 10;
+            let stock_deals_left = stock_cards.div_ceil(spider.tableau().len());
             let suited_edges = Self::spider_suited_edge_count(spider);
             let max_tail_run = Self::spider_max_face_up_tail_run(spider);
             let drought = self.imp().robot_moves_since_foundation_progress.get();
@@ -1591,7 +1903,10 @@ impl CardthropicWindow {
                     foundation_cards * 10
                 )
             }
-            GameMode::Freecell => {
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => {
                 let foundation_cards: usize = imp
                     .game
                     .borrow()
@@ -1606,6 +1921,56 @@ impl CardthropicWindow {
                     foundation_cards * 10
                 )
             }
+            GameMode::Yukon => {
+                let foundation_cards: usize = imp
+                    .game
+                    .borrow()
+                    .yukon()
+                    .foundations()
+                    .iter()
+                    .map(Vec::len)
+                    .sum();
+                format!(
+                    " progress_kind=foundation_cards progress_value={} progress_score={}",
+                    foundation_cards,
+                    foundation_cards * 10
+                )
+            }
+            GameMode::Pyramid => {
+                let removed_cards = imp.game.borrow().pyramid().removed().len();
+                format!(
+                    " progress_kind=removed_cards progress_value={} progress_score={}",
+                    removed_cards,
+                    removed_cards * 10
+                )
+            }
+            GameMode::TriPeaks | GameMode::Golf => {
+                let (cleared_slots, _) = self.robot_progress_snapshot();
+                format!(
+                    " progress_kind=cleared_slots progress_value={} progress_score={}",
+                    cleared_slots,
+                    cleared_slots * 10
+                )
+            }
+            GameMode::FortyThieves
+            | GameMode::Canfield
+            | GameMode::BakersDozen
+            | GameMode::BeleagueredCastle => {
+                let (foundation_cards, _) = self.robot_progress_snapshot();
+                format!(
+                    " progress_kind=foundation_cards progress_value={} progress_score={}",
+                    foundation_cards,
+                    foundation_cards * 10
+                )
+            }
+            GameMode::Scorpion => {
+                let runs = imp.game.borrow().scorpion().completed_runs();
+                format!(
+                    " progress_kind=completed_runs progress_value={} progress_score={}",
+                    runs,
+                    runs * 100
+                )
+            }
         }
     }
 
@@ -1679,7 +2044,10 @@ impl CardthropicWindow {
                     tableau_face_down_cards
                 )
             }
-            GameMode::Freecell => {
+            GameMode::Freecell
+            | GameMode::EightOff
+            | GameMode::SeahavenTowers
+            | GameMode::BakersGame => {
                 let game = imp.game.borrow();
                 let freecell = game.freecell();
                 let freecell_occupied = freecell
@@ -1717,61 +2085,264 @@ impl CardthropicWindow {
                     tableau_face_down_cards
                 )
             }
-        }
-    }
-
-    fn robot_move_description(hint_move: HintMove) -> &'static str {
-        match hint_move {
-            HintMove::WasteToFoundation => "waste -> foundation",
-            HintMove::TableauTopToFoundation { .. } => "tableau top -> foundation",
-            HintMove::WasteToTableau { .. } => "waste -> tableau",
-            HintMove::TableauRunToTableau { .. } => "tableau run -> tableau",
-            HintMove::Draw => "draw from stock",
-        }
-    }
-
-    fn robot_move_fields(&self, hint_move: Option<HintMove>) -> String {
-        let Some(hint_move) = hint_move else {
-            return " move_kind=na src_col=na src_start=na dst_col=na cards_moved_total=na draw_from_stock_cards=na recycle_cards=na".to_string();
-        };
-
-        match hint_move {
-            HintMove::WasteToFoundation => {
-                " move_kind=waste_to_foundation src_col=na src_start=na dst_col=foundation cards_moved_total=1 draw_from_stock_cards=na recycle_cards=na".to_string()
+            GameMode::Yukon => {
+                let game = imp.game.borrow();
+                let yukon = game.yukon();
+                let foundation_cards: usize = yukon.foundations().iter().map(Vec::len).sum();
+                let tableau_empty_cols =
+                    yukon.tableau().iter().filter(|col| col.is_empty()).count();
+                let tableau_nonempty_cols =
+                    yukon.tableau().len().saturating_sub(tableau_empty_cols);
+                let tableau_face_up_cards = yukon
+                    .tableau()
+                    .iter()
+                    .flat_map(|col| col.iter())
+                    .filter(|card| card.face_up)
+                    .count();
+                let tableau_face_down_cards = yukon
+                    .tableau()
+                    .iter()
+                    .flat_map(|col| col.iter())
+                    .filter(|card| !card.face_up)
+                    .count();
+                format!(
+                    " stock_cards=na waste_cards=na foundation_cards={} completed_runs=na tableau_empty_cols={} tableau_nonempty_cols={} tableau_face_up_cards={} tableau_face_down_cards={}",
+                    foundation_cards,
+                    tableau_empty_cols,
+                    tableau_nonempty_cols,
+                    tableau_face_up_cards,
+                    tableau_face_down_cards
+                )
             }
-            HintMove::TableauTopToFoundation { src } => {
+            GameMode::Pyramid => {
+                let game = imp.game.borrow();
+                let pyramid = game.pyramid();
                 format!(
-                    " move_kind=tableau_top_to_foundation src_col={} src_start=top dst_col=foundation cards_moved_total=1 draw_from_stock_cards=na recycle_cards=na",
-                    src
+                    " stock_cards={} waste_cards={} foundation_cards={} completed_runs=na pyramid_cards={} recycles_used={}",
+                    pyramid.stock_len(),
+                    pyramid.waste().len(),
+                    pyramid.removed().len(),
+                    pyramid.pyramid_remaining(),
+                    pyramid.recycles_used()
                 )
             }
-            HintMove::WasteToTableau { dst } => {
+            GameMode::TriPeaks | GameMode::Golf => {
+                let Some(layout) = WasteBuildLayout::for_mode(self.active_game_mode()) else {
+                    return String::new();
+                };
+                let game = imp.game.borrow();
+                let waste_build = game.waste_build(layout);
                 format!(
-                    " move_kind=waste_to_tableau src_col=waste src_start=top dst_col={} cards_moved_total=1 draw_from_stock_cards=na recycle_cards=na",
-                    dst
+                    " stock_cards={} waste_cards={} foundation_cards=na completed_runs=na tableau_cards={} streak={} score={}",
+                    waste_build.stock_len(),
+                    waste_build.waste().len(),
+                    waste_build.tableau_remaining(),
+                    waste_build.streak(),
+                    waste_build.score()
                 )
             }
-            HintMove::TableauRunToTableau { src, start, dst } => {
-                let cards = match self.active_game_mode() {
-                    GameMode::Spider => self
-                        .imp()
-                        .game
-                        .borrow()
-                        .spider()
-                        .tableau()
-                        .get(src)
-                        .map(Vec::len)
-                        .unwrap_or(0)
-                        .saturating_sub(start),
-                    GameMode::Klondike => self
-                        .imp()
-                        .game
-                        .borrow()
+            GameMode::FortyThieves => {
+                let game = imp.game.borrow();
+                let forty_thieves = game.forty_thieves();
+                let foundation_cards: usize =
+                    forty_thieves.foundations().iter().map(Vec::len).sum();
+                let tableau_empty_cols = forty_thieves.empty_columns();
+                let tableau_nonempty_cols = forty_thieves
+                    .tableau()
+                    .len()
+                    .saturating_sub(tableau_empty_cols);
+                let tableau_cards: usize = forty_thieves.tableau().iter().map(Vec::len).sum();
+                format!(
+                    " stock_cards={} waste_cards={} foundation_cards={} completed_runs=na tableau_empty_cols={} tableau_nonempty_cols={} tableau_face_up_cards={} tableau_face_down_cards=0",
+                    forty_thieves.stock_len(),
+                    forty_thieves.waste().len(),
+                    foundation_cards,
+                    tableau_empty_cols,
+                    tableau_nonempty_cols,
+                    tableau_cards
+                )
+            }
+            GameMode::Scorpion => {
+                let game = imp.game.borrow();
+                let scorpion = game.scorpion();
+                let tableau_empty_cols = scorpion
+                    .tableau()
+                    .iter()
+                    .filter(|col| col.is_empty())
+                    .count();
+                let tableau_nonempty_cols =
+                    scorpion.tableau().len().saturating_sub(tableau_empty_cols);
+                let tableau_face_up_cards = scorpion
+                    .tableau()
+                    .iter()
+                    .flat_map(|col| col.iter())
+                    .filter(|card| card.face_up)
+                    .count();
+                let tableau_face_down_cards = scorpion
+                    .tableau()
+                    .iter()
+                    .flat_map(|col| col.iter())
+                    .filter(|card| !card.face_up)
+                    .count();
+                format!(
+                    " stock_cards={} waste_cards=na foundation_cards=na completed_runs={} tableau_empty_cols={} tableau_nonempty_cols={} tableau_face_up_cards={} tableau_face_down_cards={}",
+                    scorpion.stock_len(),
+                    scorpion.completed_runs(),
+                    tableau_empty_cols,
+                    tableau_nonempty_cols,
+                    tableau_face_up_cards,
+                    tableau_face_down_cards
+                )
+            }
+            GameMode::Canfield => {
+                let game = imp.game.borrow();
+                let canfield = game.canfield();
+                let foundation_cards: usize = canfield.foundations().iter().map(Vec::len).sum();
+                let tableau_empty_cols = canfield
+                    .tableau()
+                    .iter()
+                    .filter(|col| col.is_empty())
+                    .count();
+                let tableau_nonempty_cols =
+                    canfield.tableau().len().saturating_sub(tableau_empty_cols);
+                let tableau_cards: usize = canfield.tableau().iter().map(Vec::len).sum();
+                format!(
+                    " stock_cards={} waste_cards={} reserve_cards={} foundation_cards={} completed_runs=na tableau_empty_cols={} tableau_nonempty_cols={} tableau_face_up_cards={} tableau_face_down_cards=0",
+                    canfield.stock_len(),
+                    canfield.waste().len(),
+                    canfield.reserve().len(),
+                    foundation_cards,
+                    tableau_empty_cols,
+                    tableau_nonempty_cols,
+                    tableau_cards
+                )
+            }
+            GameMode::BakersDozen | GameMode::BeleagueredCastle => {
+                let Some(layout) = OpenTableauLayout::for_mode(self.active_game_mode()) else {
+                    return String::new();
+                };
+                let game = imp.game.borrow();
+                let open_tableau = game.open_tableau(layout);
+                let tableau_empty_cols = open_tableau
+                    .tableau()
+                    .iter()
+                    .filter(|col| col.is_empty())
+                    .count();
+                let tableau_nonempty_cols = open_tableau
+                    .tableau()
+                    .len()
+                    .saturating_sub(tableau_empty_cols);
+                let tableau_cards: usize = open_tableau.tableau().iter().map(Vec::len).sum();
+                format!(
+                    " stock_cards=na waste_cards=na foundation_cards={} completed_runs=na tableau_empty_cols={} tableau_nonempty_cols={} tableau_face_up_cards={} tableau_face_down_cards=0",
+                    open_tableau.foundation_cards(),
+                    tableau_empty_cols,
+                    tableau_nonempty_cols,
+                    tableau_cards
+                )
+            }
+        }
+    }
+
+    fn robot_move_description(hint_move: HintMove) -> &'static str {
+        match hint_move {
+            HintMove::WasteToFoundation => "waste -> foundation",
+            HintMove::TableauTopToFoundation { .. } => "tableau top -> foundation",
+            HintMove::WasteToTableau { .. } => "waste -> tableau",
+            HintMove::TableauRunToTableau { .. } => "tableau run -> tableau",
+            HintMove::Draw => "draw from stock",
+        }
+    }
+
+    fn robot_move_fields(&self, hint_move: Option<HintMove>) -> String {
+        let Some(hint_move) = hint_move else {
+            return " move_kind=na src_col=na src_start=na dst_col=na cards_moved_total=na draw_from_stock_cards=na recycle_cards=na".to_string();
+        };
+
+        match hint_move {
+            HintMove::WasteToFoundation => {
+                " move_kind=waste_to_foundation src_col=na src_start=na dst_col=foundation cards_moved_total=1 draw_from_stock_cards=na recycle_cards=na".to_string()
+            }
+            HintMove::TableauTopToFoundation { src } => {
+                format!(
+                    " move_kind=tableau_top_to_foundation src_col={} src_start=top dst_col=foundation cards_moved_total=1 draw_from_stock_cards=na recycle_cards=na",
+                    src
+                )
+            }
+            HintMove::WasteToTableau { dst } => {
+                format!(
+                    " move_kind=waste_to_tableau src_col=waste src_start=top dst_col={} cards_moved_total=1 draw_from_stock_cards=na recycle_cards=na",
+                    dst
+                )
+            }
+            HintMove::TableauRunToTableau { src, start, dst } => {
+                let cards = match self.active_game_mode() {
+                    GameMode::Spider => self
+                        .imp()
+                        .game
+                        .borrow()
+                        .spider()
+                        .tableau()
+                        .get(src)
+                        .map(Vec::len)
+                        .unwrap_or(0)
+                        .saturating_sub(start),
+                    GameMode::Klondike => self
+                        .imp()
+                        .game
+                        .borrow()
                         .klondike()
                         .tableau_len(src)
                         .unwrap_or(0)
                         .saturating_sub(start),
-                    GameMode::Freecell => 0,
+                    GameMode::Yukon => self
+                        .imp()
+                        .game
+                        .borrow()
+                        .yukon()
+                        .tableau()
+                        .get(src)
+                        .map(Vec::len)
+                        .unwrap_or(0)
+                        .saturating_sub(start),
+                    GameMode::FortyThieves => self
+                        .imp()
+                        .game
+                        .borrow()
+                        .forty_thieves()
+                        .tableau()
+                        .get(src)
+                        .map(Vec::len)
+                        .unwrap_or(0)
+                        .saturating_sub(start),
+                    GameMode::Scorpion => self
+                        .imp()
+                        .game
+                        .borrow()
+                        .scorpion()
+                        .tableau()
+                        .get(src)
+                        .map(Vec::len)
+                        .unwrap_or(0)
+                        .saturating_sub(start),
+                    GameMode::Canfield => self
+                        .imp()
+                        .game
+                        .borrow()
+                        .canfield()
+                        .tableau()
+                        .get(src)
+                        .map(Vec::len)
+                        .unwrap_or(0)
+                        .saturating_sub(start),
+                    // Only top cards move in the open-tableau layouts.
+                    GameMode::BakersDozen | GameMode::BeleagueredCastle => 1,
+                    GameMode::Freecell
+                    | GameMode::EightOff
+                    | GameMode::SeahavenTowers | GameMode::BakersGame
+                    | GameMode::Pyramid
+                    | GameMode::TriPeaks
+                    | GameMode::Golf => 0,
                 };
                 format!(
                     " move_kind=tableau_run_to_tableau src_col={} src_start={} dst_col={} cards_moved_total={} draw_from_stock_cards=na recycle_cards=na",
@@ -1781,7 +2352,7 @@ impl CardthropicWindow {
             HintMove::Draw => {
                 match self.active_game_mode() {
                     GameMode::Spider => {
-                        let draw_from_stock_cards = self.imp().game.borrow().spider().stock_len().min(10);
+                        let draw_from_stock_cards = self.imp().game.borrow().spider().next_deal_len();
                         format!(
                             " move_kind=draw src_col=stock src_start=top dst_col=tableau cards_moved_total={} draw_from_stock_cards={} recycle_cards=0",
                             draw_from_stock_cards,
@@ -1808,7 +2379,49 @@ impl CardthropicWindow {
                             recycle_cards
                         )
                     }
-                    GameMode::Freecell => {
+                    GameMode::FortyThieves => {
+                        let draw_from_stock_cards =
+                            self.imp().game.borrow().forty_thieves().stock_len().min(1);
+                        format!(
+                            " move_kind=draw src_col=stock src_start=top dst_col=waste cards_moved_total={} draw_from_stock_cards={} recycle_cards=0",
+                            draw_from_stock_cards,
+                            draw_from_stock_cards
+                        )
+                    }
+                    GameMode::Scorpion => {
+                        let draw_from_stock_cards = self.imp().game.borrow().scorpion().stock_len();
+                        format!(
+                            " move_kind=draw src_col=stock src_start=top dst_col=tableau cards_moved_total={} draw_from_stock_cards={} recycle_cards=0",
+                            draw_from_stock_cards,
+                            draw_from_stock_cards
+                        )
+                    }
+                    GameMode::Canfield => {
+                        let game = self.imp().game.borrow();
+                        let canfield = game.canfield();
+                        let stock_before = canfield.stock_len();
+                        let draw_from_stock_cards = stock_before.min(3);
+                        let recycle_cards = if stock_before == 0 {
+                            canfield.waste().len()
+                        } else {
+                            0
+                        };
+                        format!(
+                            " move_kind=draw src_col=stock src_start=top dst_col=waste cards_moved_total={} draw_from_stock_cards={} recycle_cards={}",
+                            draw_from_stock_cards + recycle_cards,
+                            draw_from_stock_cards,
+                            recycle_cards
+                        )
+                    }
+                    GameMode::Freecell
+                    | GameMode::EightOff
+                    | GameMode::SeahavenTowers | GameMode::BakersGame
+                    | GameMode::Yukon
+                    | GameMode::Pyramid
+                    | GameMode::TriPeaks
+                    | GameMode::Golf
+                    | GameMode::BakersDozen
+                    | GameMode::BeleagueredCastle => {
                         " move_kind=draw src_col=na src_start=na dst_col=na cards_moved_total=0 draw_from_stock_cards=0 recycle_cards=0".to_string()
                     }
                 }
@@ -1921,6 +2534,9 @@ impl CardthropicWindow {
         if !self.guard_mode_engine("Rapid Wand") {
             return;
         }
+        if !self.guard_mode_feature("Rapid Wand", self.mode_capabilities().rapid_wand) {
+            return;
+        }
         if self.imp().rapid_wand_running.get() {
             return;
         }
@@ -2126,7 +2742,10 @@ impl CardthropicWindow {
             return;
         }
         let seed = seed_ops::random_seed();
-        let status = format!("Robot chess {reason}. Forever Mode started random seed {seed}.");
+        let status = format!(
+            "Robot chess {reason}. Forever Mode started random seed {seed}.",
+            seed = seed_ops::seed_label(seed)
+        );
         self.start_new_chess_game_with_seed_preserving_robot(seed, status);
     }
 
@@ -2152,9 +2771,15 @@ impl CardthropicWindow {
         }
         let seed = seed_ops::random_seed();
         let status = if self.imp().robot_forever_enabled.get() {
-            format!("Robot chess {reason}. Forever Mode started random seed {seed}.")
+            format!(
+                "Robot chess {reason}. Forever Mode started random seed {seed}.",
+                seed = seed_ops::seed_label(seed)
+            )
         } else {
-            format!("Robot chess {reason}. Started random seed {seed}.")
+            format!(
+                "Robot chess {reason}. Started random seed {seed}.",
+                seed = seed_ops::seed_label(seed)
+            )
         };
         self.start_new_chess_game_with_seed_preserving_robot(seed, status);
     }
@@ -2204,7 +2829,7 @@ impl CardthropicWindow {
                         return;
                     }
                 }
-                if mode == GameMode::Freecell {
+                if mode.is_freecell_family() {
                     let use_solver_line = self.robot_freecell_solver_anchor_matches_current_state()
                         && self
                             .imp()
@@ -2228,6 +2853,419 @@ impl CardthropicWindow {
         }
     }
 
+    /// Pyramid has no hidden cards, so the robot follows a solver line and
+    /// only re-solves when the board no longer matches the plan's anchor.
+    fn robot_step_pyramid(&self) -> bool {
+        let imp = self.imp();
+        let current_hash = self.current_game_hash();
+        if imp.robot_pyramid_plan_anchor_hash.get() != current_hash {
+            let game = imp.game.borrow().pyramid().clone();
+            let result = game.solve(self.automation_profile().hint_exhaustive_analysis_budget);
+            let plan = if result.winnable {
+                result.line
+            } else {
+                game.solver_moves().into_iter().take(1).collect()
+            };
+            *imp.robot_pyramid_plan.borrow_mut() = plan.into();
+        }
+        let next = imp.robot_pyramid_plan.borrow_mut().pop_front();
+        let Some(pyramid_move) = next else {
+            self.emit_robot_status(
+                "running",
+                "no_move",
+                "no legal pyramid moves remain",
+                Some("pyramid solver found no candidate move"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        };
+
+        let desc = {
+            let game = imp.game.borrow();
+            crate::engine::pyramid_hinting::describe_move(game.pyramid(), pyramid_move)
+        };
+        let (move_kind, cards_moved) = match pyramid_move {
+            PyramidMove::RemoveKing(_) => ("pyramid_king", 1),
+            PyramidMove::RemovePair(_, _) => ("pyramid_pair", 2),
+            PyramidMove::Draw => ("pyramid_draw", 0),
+        };
+        imp.auto_playing_move.set(true);
+        let changed = self.apply_pyramid_move(pyramid_move);
+        imp.auto_playing_move.set(false);
+        if !changed {
+            imp.robot_pyramid_plan.borrow_mut().clear();
+            imp.robot_pyramid_plan_anchor_hash.set(0);
+            self.emit_robot_status(
+                "running",
+                "move_invalid",
+                "pyramid move invalid; recalculating",
+                Some("apply_pyramid_move returned false"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        }
+        imp.robot_pyramid_plan_anchor_hash
+            .set(self.current_game_hash());
+        let move_fields = format!(" move_kind={move_kind} cards_moved_total={cards_moved}");
+        self.record_robot_move_and_maybe_pulse(&move_fields, &desc, "search");
+        if self.should_render_after_robot_move() {
+            self.render();
+        }
+        true
+    }
+
+    /// TriPeaks and Golf follow a solver line the same way as Pyramid; the
+    /// stock order is fixed, so a found line always plays out.
+    fn robot_step_waste_build(&self, layout: WasteBuildLayout) -> bool {
+        let imp = self.imp();
+        let current_hash = self.current_game_hash();
+        if imp.robot_waste_build_plan_anchor_hash.get() != current_hash {
+            let game = imp.game.borrow().waste_build(layout).clone();
+            let result = game.solve(self.automation_profile().hint_exhaustive_analysis_budget);
+            let plan = if result.winnable {
+                result.line
+            } else {
+                game.solver_moves().into_iter().take(1).collect()
+            };
+            *imp.robot_waste_build_plan.borrow_mut() = plan.into();
+        }
+        let next = imp.robot_waste_build_plan.borrow_mut().pop_front();
+        let Some(waste_build_move) = next else {
+            self.emit_robot_status(
+                "running",
+                "no_move",
+                "no card connects to the waste and the stock is spent",
+                Some("waste-build solver found no candidate move"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        };
+
+        let desc = {
+            let game = imp.game.borrow();
+            crate::engine::waste_build_hinting::describe_move(
+                game.waste_build(layout),
+                waste_build_move,
+            )
+        };
+        let (move_kind, cards_moved) = match waste_build_move {
+            WasteBuildMove::Play(_) => ("tableau_to_waste", 1),
+            WasteBuildMove::Draw => ("draw", 1),
+        };
+        imp.auto_playing_move.set(true);
+        let changed = self.apply_waste_build_move(waste_build_move);
+        imp.auto_playing_move.set(false);
+        if !changed {
+            imp.robot_waste_build_plan.borrow_mut().clear();
+            imp.robot_waste_build_plan_anchor_hash.set(0);
+            self.emit_robot_status(
+                "running",
+                "move_invalid",
+                "waste-build move invalid; recalculating",
+                Some("apply_waste_build_move returned false"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        }
+        imp.robot_waste_build_plan_anchor_hash
+            .set(self.current_game_hash());
+        let move_fields = format!(" move_kind={move_kind} cards_moved_total={cards_moved}");
+        self.record_robot_move_and_maybe_pulse(&move_fields, &desc, "search");
+        if self.should_render_after_robot_move() {
+            self.render();
+        }
+        true
+    }
+
+    /// Forty Thieves deals everything face up, so the robot follows a solver
+    /// line like Pyramid and falls back to the best-ranked move otherwise.
+    fn robot_step_forty_thieves(&self) -> bool {
+        let imp = self.imp();
+        let current_hash = self.current_game_hash();
+        if imp.robot_forty_thieves_plan_anchor_hash.get() != current_hash {
+            let game = imp.game.borrow().forty_thieves().clone();
+            let result = game.solve(self.automation_profile().hint_exhaustive_analysis_budget);
+            let plan = if result.winnable {
+                result.line
+            } else {
+                game.solver_moves().into_iter().take(1).collect()
+            };
+            *imp.robot_forty_thieves_plan.borrow_mut() = plan.into();
+        }
+        let next = imp.robot_forty_thieves_plan.borrow_mut().pop_front();
+        let Some(solver_move) = next else {
+            self.emit_robot_status(
+                "running",
+                "no_move",
+                "no legal forty thieves moves remain",
+                Some("forty thieves solver found no candidate move"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        };
+
+        let desc = {
+            let game = imp.game.borrow();
+            crate::engine::forty_thieves_hinting::describe_move(game.forty_thieves(), solver_move)
+        };
+        let hint_move = map_solver_move_to_hint_move(solver_move);
+        let move_fields = self.robot_move_fields(Some(hint_move));
+        imp.auto_playing_move.set(true);
+        let changed = self.apply_hint_move(hint_move);
+        imp.auto_playing_move.set(false);
+        if !changed {
+            imp.robot_forty_thieves_plan.borrow_mut().clear();
+            imp.robot_forty_thieves_plan_anchor_hash.set(0);
+            self.emit_robot_status(
+                "running",
+                "move_invalid",
+                "forty thieves move invalid; recalculating",
+                Some("apply_hint_move returned false"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        }
+        imp.robot_forty_thieves_plan_anchor_hash
+            .set(self.current_game_hash());
+        self.record_robot_move_and_maybe_pulse(&move_fields, &desc, "search");
+        if self.should_render_after_robot_move() {
+            self.render();
+        }
+        true
+    }
+
+    /// Baker's Dozen and Beleaguered Castle hide nothing, so the planner's
+    /// line is a proven win; without one the robot takes the best-ranked move.
+    fn robot_step_open_tableau(&self, layout: OpenTableauLayout) -> bool {
+        let imp = self.imp();
+        let current_hash = self.current_game_hash();
+        if imp.robot_open_tableau_plan_anchor_hash.get() != current_hash {
+            let game = imp.game.borrow().open_tableau(layout).clone();
+            let result = open_tableau_planner::solve(
+                &game,
+                self.automation_profile().hint_exhaustive_analysis_budget,
+            );
+            let plan = if result.winnable {
+                result.line
+            } else {
+                open_tableau_planner::solver_moves(&game)
+                    .into_iter()
+                    .take(1)
+                    .collect()
+            };
+            *imp.robot_open_tableau_plan.borrow_mut() = plan.into();
+        }
+        let next = imp.robot_open_tableau_plan.borrow_mut().pop_front();
+        let Some(solver_move) = next else {
+            self.emit_robot_status(
+                "running",
+                "no_move",
+                "no legal open tableau moves remain",
+                Some("open tableau planner found no candidate move"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        };
+
+        let desc = {
+            let game = imp.game.borrow();
+            crate::engine::open_tableau_hinting::describe_move(
+                game.open_tableau(layout),
+                solver_move,
+            )
+        };
+        let hint_move = map_solver_move_to_hint_move(solver_move);
+        let move_fields = self.robot_move_fields(Some(hint_move));
+        imp.auto_playing_move.set(true);
+        let changed = self.apply_hint_move(hint_move);
+        imp.auto_playing_move.set(false);
+        if !changed {
+            imp.robot_open_tableau_plan.borrow_mut().clear();
+            imp.robot_open_tableau_plan_anchor_hash.set(0);
+            self.emit_robot_status(
+                "running",
+                "move_invalid",
+                "open tableau move invalid; recalculating",
+                Some("apply_hint_move returned false"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        }
+        imp.robot_open_tableau_plan_anchor_hash
+            .set(self.current_game_hash());
+        self.record_robot_move_and_maybe_pulse(&move_fields, &desc, "search");
+        if self.should_render_after_robot_move() {
+            self.render();
+        }
+        true
+    }
+
+    /// Scorpion follows a solver line the same way Forty Thieves does; the
+    /// solver sees the face-down cards, so a found line is a guaranteed win.
+    fn robot_step_scorpion(&self) -> bool {
+        let imp = self.imp();
+        let current_hash = self.current_game_hash();
+        if imp.robot_scorpion_plan_anchor_hash.get() != current_hash {
+            let game = imp.game.borrow().scorpion().clone();
+            let result = game.solve(self.automation_profile().hint_exhaustive_analysis_budget);
+            let plan = if result.winnable {
+                result.line
+            } else {
+                game.solver_moves().into_iter().take(1).collect()
+            };
+            *imp.robot_scorpion_plan.borrow_mut() = plan.into();
+        }
+        let next = imp.robot_scorpion_plan.borrow_mut().pop_front();
+        let Some(solver_move) = next else {
+            self.emit_robot_status(
+                "running",
+                "no_move",
+                "no legal scorpion moves remain",
+                Some("scorpion solver found no candidate move"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        };
+
+        let desc = {
+            let game = imp.game.borrow();
+            crate::engine::scorpion_hinting::describe_move(game.scorpion(), solver_move)
+        };
+        let hint_move = map_solver_move_to_hint_move(solver_move);
+        let move_fields = self.robot_move_fields(Some(hint_move));
+        imp.auto_playing_move.set(true);
+        let changed = self.apply_hint_move(hint_move);
+        imp.auto_playing_move.set(false);
+        if !changed {
+            imp.robot_scorpion_plan.borrow_mut().clear();
+            imp.robot_scorpion_plan_anchor_hash.set(0);
+            self.emit_robot_status(
+                "running",
+                "move_invalid",
+                "scorpion move invalid; recalculating",
+                Some("apply_hint_move returned false"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        }
+        imp.robot_scorpion_plan_anchor_hash
+            .set(self.current_game_hash());
+        self.record_robot_move_and_maybe_pulse(&move_fields, &desc, "search");
+        if self.should_render_after_robot_move() {
+            self.render();
+        }
+        true
+    }
+
+    /// Canfield moves include the reserve, which has no `HintMove`, so the
+    /// plan is kept as solver moves like Pyramid's.
+    fn robot_step_canfield(&self) -> bool {
+        let imp = self.imp();
+        let current_hash = self.current_game_hash();
+        if imp.robot_canfield_plan_anchor_hash.get() != current_hash {
+            let game = imp.game.borrow().canfield().clone();
+            let result = game.solve(self.automation_profile().hint_exhaustive_analysis_budget);
+            let plan = if result.winnable {
+                result.line
+            } else {
+                game.solver_moves().into_iter().take(1).collect()
+            };
+            *imp.robot_canfield_plan.borrow_mut() = plan.into();
+        }
+        let next = imp.robot_canfield_plan.borrow_mut().pop_front();
+        let Some(canfield_move) = next else {
+            self.emit_robot_status(
+                "running",
+                "no_move",
+                "no legal canfield moves remain",
+                Some("canfield solver found no candidate move"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        };
+
+        let (desc, move_kind, cards_moved) = {
+            let game = imp.game.borrow();
+            let canfield = game.canfield();
+            let desc = crate::engine::canfield_hinting::describe_move(canfield, canfield_move);
+            let (move_kind, cards_moved) = match canfield_move {
+                CanfieldMove::Draw if canfield.stock_len() == 0 => {
+                    ("recycle", canfield.waste().len())
+                }
+                CanfieldMove::Draw => ("draw", canfield.stock_len().min(3)),
+                CanfieldMove::WasteToFoundation => ("waste_to_foundation", 1),
+                CanfieldMove::WasteToTableau { .. } => ("waste_to_tableau", 1),
+                CanfieldMove::ReserveToFoundation => ("reserve_to_foundation", 1),
+                CanfieldMove::ReserveToTableau { .. } => ("reserve_to_tableau", 1),
+                CanfieldMove::TableauTopToFoundation { .. } => ("tableau_to_foundation", 1),
+                CanfieldMove::TableauRunToTableau { src, start, .. } => (
+                    "tableau_run_to_tableau",
+                    canfield.tableau()[src].len().saturating_sub(start),
+                ),
+            };
+            (desc, move_kind, cards_moved)
+        };
+        imp.auto_playing_move.set(true);
+        let changed = self.apply_canfield_move(canfield_move);
+        imp.auto_playing_move.set(false);
+        if !changed {
+            imp.robot_canfield_plan.borrow_mut().clear();
+            imp.robot_canfield_plan_anchor_hash.set(0);
+            self.emit_robot_status(
+                "running",
+                "move_invalid",
+                "canfield move invalid; recalculating",
+                Some("apply_canfield_move returned false"),
+                None,
+                Some(false),
+                "search",
+            );
+            self.render();
+            return false;
+        }
+        imp.robot_canfield_plan_anchor_hash
+            .set(self.current_game_hash());
+        let move_fields = format!(" move_kind={move_kind} cards_moved_total={cards_moved}");
+        self.record_robot_move_and_maybe_pulse(&move_fields, &desc, "search");
+        if self.should_render_after_robot_move() {
+            self.render();
+        }
+        true
+    }
+
     pub(super) fn start_robot_mode(&self) {
         if self.imp().robot_mode_running.get() {
             return;
@@ -2235,6 +3273,9 @@ impl CardthropicWindow {
         if !self.guard_mode_engine("Robot Mode") {
             return;
         }
+        if !self.guard_mode_feature("Robot Mode", self.mode_capabilities().robot_mode) {
+            return;
+        }
 
         self.stop_rapid_wand();
         self.cancel_hint_loss_analysis();
@@ -2257,6 +3298,13 @@ impl CardthropicWindow {
         self.imp().robot_freecell_peak_used.set(0);
         self.imp().robot_freecell_plan.borrow_mut().clear();
         self.imp().robot_freecell_planner_wait_ticks.set(0);
+        self.imp().robot_pyramid_plan.borrow_mut().clear();
+        self.imp().robot_waste_build_plan.borrow_mut().clear();
+        self.imp().robot_waste_build_plan_anchor_hash.set(0);
+        self.imp().robot_canfield_plan.borrow_mut().clear();
+        self.imp().robot_canfield_plan_anchor_hash.set(0);
+        self.imp().robot_open_tableau_plan.borrow_mut().clear();
+        self.imp().robot_open_tableau_plan_anchor_hash.set(0);
         self.imp().robot_cpu_last_exec_ns.set(0);
         self.imp().robot_cpu_last_mono_us.set(0);
         self.imp().robot_cpu_last_pct.set(0.0);
@@ -2264,7 +3312,7 @@ impl CardthropicWindow {
         self.reset_robot_search_tracking_for_current_deal();
         let using_solver = if self.imp().chess_mode_active.get() {
             false
-        } else if self.active_game_mode() == GameMode::Freecell {
+        } else if self.active_game_mode().is_freecell_family() {
             self.imp()
                 .robot_freecell_playback
                 .borrow()
@@ -2297,7 +3345,7 @@ impl CardthropicWindow {
         }
 
         let mode = self.active_game_mode();
-        let scripted_active = if mode == GameMode::Freecell {
+        let scripted_active = if mode.is_freecell_family() {
             self.imp()
                 .robot_freecell_playback
                 .borrow()
@@ -2311,7 +3359,19 @@ impl CardthropicWindow {
             return;
         }
 
-        let mut moved = if mode != GameMode::Freecell && scripted_active {
+        let mut moved = if mode == GameMode::Pyramid {
+            self.robot_step_pyramid()
+        } else if let Some(layout) = WasteBuildLayout::for_mode(mode) {
+            self.robot_step_waste_build(layout)
+        } else if mode == GameMode::FortyThieves {
+            self.robot_step_forty_thieves()
+        } else if mode == GameMode::Scorpion {
+            self.robot_step_scorpion()
+        } else if mode == GameMode::Canfield {
+            self.robot_step_canfield()
+        } else if let Some(layout) = OpenTableauLayout::for_mode(mode) {
+            self.robot_step_open_tableau(layout)
+        } else if !mode.is_freecell_family() && scripted_active {
             let scripted_move = {
                 let mut playback = self.imp().robot_playback.borrow_mut();
                 playback.pop_scripted_move()
@@ -2346,7 +3406,7 @@ impl CardthropicWindow {
                 );
                 return;
             }
-        } else if mode == GameMode::Freecell {
+        } else if mode.is_freecell_family() {
             if scripted_active && self.imp().robot_freecell_plan.borrow().is_empty() {
                 let scripted_step = self
                     .imp()
@@ -2857,6 +3917,7 @@ impl CardthropicWindow {
             };
             if extracted > 0 {
                 moved = true;
+                self.end_replay_recording();
                 self.emit_robot_status(
                     "running",
                     "move_applied",
@@ -2894,12 +3955,12 @@ impl CardthropicWindow {
         }
         if moved && (!scripted_active || mode == GameMode::Spider) {
             let cooldown = self.imp().robot_freecell_planner_cooldown_ticks.get();
-            if mode == GameMode::Freecell && cooldown > 0 {
+            if mode.is_freecell_family() && cooldown > 0 {
                 self.imp()
                     .robot_freecell_planner_cooldown_ticks
                     .set(cooldown.saturating_sub(1));
             }
-            if mode == GameMode::Freecell {
+            if mode.is_freecell_family() {
                 self.note_current_state_for_hint_cycle();
                 self.robot_mark_seen_state(self.current_game_hash());
                 if self.robot_track_hash_oscillation_and_mark_loss("search") {
@@ -2918,7 +3979,7 @@ impl CardthropicWindow {
             if self.imp().robot_forever_enabled.get() && self.imp().seed_search_in_progress.get() {
                 return;
             }
-            if mode == GameMode::Freecell {
+            if mode.is_freecell_family() {
                 let force_loss_now = self.imp().robot_force_loss_now.replace(false);
                 let has_legal = self.imp().game.borrow().freecell().has_legal_moves();
                 if has_legal && !force_loss_now {
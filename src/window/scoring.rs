@@ -0,0 +1,105 @@
+use super::*;
+use crate::engine::boundary;
+use crate::engine::game_mode::VariantRuntime;
+use crate::engine::scoring::{self, ScoreFacts, ScoreTable};
+
+impl CardthropicWindow {
+    pub(super) fn scoring_system(&self) -> ScoringSystem {
+        self.imp().scoring_system.get()
+    }
+
+    pub(super) fn settings_has_scoring_system_key(settings: &gio::Settings) -> bool {
+        settings
+            .settings_schema()
+            .map(|schema| schema.has_key(SETTINGS_KEY_SCORING_SYSTEM))
+            .unwrap_or(false)
+    }
+
+    /// Switching systems rescores the current deal from its opening score;
+    /// the old score has no meaning under the new table.
+    pub(super) fn set_scoring_system(&self, system: ScoringSystem, persist: bool) {
+        let imp = self.imp();
+        if persist {
+            if let Some(settings) = imp.settings.borrow().clone() {
+                if Self::settings_has_scoring_system_key(&settings) {
+                    let _ = settings.set_string(SETTINGS_KEY_SCORING_SYSTEM, system.as_setting());
+                }
+            }
+        }
+        if imp.scoring_system.get() == system {
+            return;
+        }
+        imp.scoring_system.set(system);
+        imp.score.set(scoring::opening_score(
+            system,
+            self.current_score_table(),
+            0,
+        ));
+        if persist {
+            *imp.status_override.borrow_mut() =
+                Some(format!("{} scoring selected.", system.label()));
+            self.render();
+        }
+    }
+
+    fn current_score_table(&self) -> Option<ScoreTable> {
+        let imp = self.imp();
+        if imp.chess_mode_active.get() {
+            return None;
+        }
+        let runtime = imp.game.borrow().runtime_for_mode(self.active_game_mode());
+        scoring::table_for(
+            imp.scoring_system.get(),
+            &runtime,
+            imp.klondike_draw_mode.get(),
+        )
+    }
+
+    /// Sets the score a freshly dealt game starts from, paying the Vegas
+    /// buy-in out of the carried bank under Cumulative Vegas.
+    pub(super) fn open_score_for_new_deal(&self) {
+        let imp = self.imp();
+        imp.score.set(scoring::opening_score(
+            imp.scoring_system.get(),
+            self.current_score_table(),
+            imp.score.get(),
+        ));
+    }
+
+    pub(super) fn score_applied_move(&self, before: &VariantRuntime) {
+        let imp = self.imp();
+        let Some(table) = self.current_score_table() else {
+            return;
+        };
+        let after = imp.game.borrow().runtime_for_mode(self.active_game_mode());
+        if before.mode() != after.mode() {
+            return;
+        }
+        let (Some(before), Some(after)) = (
+            ScoreFacts::from_runtime(before),
+            ScoreFacts::from_runtime(&after),
+        ) else {
+            return;
+        };
+        imp.score.set(scoring::add_move_score(
+            imp.scoring_system.get(),
+            imp.score.get(),
+            scoring::move_delta(&table, &before, &after),
+        ));
+    }
+
+    /// Score for the HUD, or `None` when the current mode is unscored.
+    pub(super) fn current_displayed_score(&self) -> Option<i32> {
+        let imp = self.imp();
+        let table = self.current_score_table()?;
+        let mode = self.active_game_mode();
+        let won = boundary::is_won(&imp.game.borrow(), mode);
+        Some(scoring::displayed_score(
+            imp.scoring_system.get(),
+            &table,
+            imp.score.get(),
+            imp.elapsed_seconds.get(),
+            won,
+        ))
+    }
+}
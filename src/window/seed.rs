@@ -1,5 +1,6 @@
 use super::*;
 use crate::engine::boundary;
+use crate::engine::seed_ops;
 use crate::game::FreecellGame;
 use crate::game::SpiderGame;
 
@@ -24,6 +25,8 @@ impl CardthropicWindow {
         preserve_robot: bool,
     ) {
         let imp = self.imp();
+        self.close_replay_viewer();
+        self.archive_replay_recording();
         if !preserve_robot {
             self.stop_robot_mode();
         }
@@ -33,9 +36,12 @@ impl CardthropicWindow {
         let mode = self.active_game_mode();
         if mode == GameMode::Spider {
             let suit_mode = imp.spider_suit_mode.get();
+            let rule_set = imp.game.borrow().spider().rule_set();
             imp.game
                 .borrow_mut()
-                .set_spider(SpiderGame::new_with_seed_and_mode(seed, suit_mode));
+                .set_spider(SpiderGame::new_with_seed_and_rules(
+                    seed, suit_mode, rule_set,
+                ));
         } else if mode == GameMode::Freecell {
             let card_count_mode = imp.freecell_card_count_mode.get();
             let freecell_count = imp.freecell_cell_count.get();
@@ -56,7 +62,8 @@ impl CardthropicWindow {
         imp.robot_playback.borrow_mut().clear();
         imp.robot_freecell_playback.borrow_mut().clear();
         imp.current_seed.set(seed);
-        self.set_seed_input_text(&seed.to_string());
+        imp.seedless_board.set(false);
+        self.set_seed_input_text(&seed_ops::seed_label(seed));
         self.clear_seed_entry_feedback();
         *imp.selected_run.borrow_mut() = None;
         imp.selected_freecell.set(None);
@@ -68,7 +75,11 @@ impl CardthropicWindow {
         imp.move_count.set(0);
         imp.elapsed_seconds.set(0);
         imp.timer_started.set(false);
+        self.open_score_for_new_deal();
+        self.reset_stats_game_tracking();
+        self.clear_daily_game();
         self.note_seed_play_started(seed);
+        self.begin_replay_recording();
         self.reset_hint_cycle_memory();
         self.reset_auto_play_memory();
         if preserve_robot {
@@ -97,9 +97,15 @@ impl CardthropicWindow {
     }
 
     pub(super) fn note_current_seed_win_if_needed(&self) {
+        if self.replay_viewer_active() {
+            return;
+        }
+        self.note_stats_win_if_needed();
+        self.note_daily_win_if_needed();
         let mode = self.active_game_mode();
         if !boundary::is_won(&self.imp().game.borrow(), mode)
             || self.imp().current_seed_win_recorded.get()
+            || self.imp().seedless_board.get()
         {
             return;
         }
@@ -138,9 +144,10 @@ impl CardthropicWindow {
             .borrow()
             .dropdown_entries(MAX_SEED_DROPDOWN_ENTRIES);
         for (seed, stats) in seeds {
+            let label = seed_ops::seed_label(seed);
             imp.seed_combo.append(
-                Some(&seed.to_string()),
-                &format!("{seed}: Plays {}, Wins {}", stats.plays, stats.wins),
+                Some(&label),
+                &format!("{label}: Plays {}, Wins {}", stats.plays, stats.wins),
             );
         }
 
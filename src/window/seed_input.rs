@@ -61,7 +61,7 @@ impl CardthropicWindow {
         let parsed = seed_ops::parse_seed_input(&text)?;
         let seed = seed_ops::seed_from_text_or_random(&text)?;
         if parsed.is_none() {
-            self.set_seed_input_text(&seed.to_string());
+            self.set_seed_input_text(&seed_ops::seed_label(seed));
         }
         Ok(seed)
     }
@@ -96,7 +96,10 @@ impl CardthropicWindow {
                     .chars()
                     .all(|ch| ch.is_ascii_alphabetic())
             {
-                format!("Started a new game. Seed {seed}, [{original_seed_label}]")
+                format!(
+                    "Started a new game. Seed {seed}, [{original_seed_label}]",
+                    seed = seed_ops::seed_label(seed)
+                )
             } else {
                 seed_ops::msg_started_seed(seed)
             };
@@ -138,7 +141,10 @@ impl CardthropicWindow {
                 .chars()
                 .all(|ch| ch.is_ascii_alphabetic())
         {
-            format!("Started a new game. Seed {seed}, [{original_seed_label}]")
+            format!(
+                "Started a new game. Seed {seed}, [{original_seed_label}]",
+                seed = seed_ops::seed_label(seed)
+            )
         } else {
             seed_ops::msg_started_seed(seed)
         };
@@ -192,7 +198,7 @@ impl CardthropicWindow {
             self.cancel_seed_winnable_check(None);
             self.clear_seed_entry_feedback();
             let seed = self.imp().current_seed.get();
-            self.set_seed_input_text(&seed.to_string());
+            self.set_seed_input_text(&seed_ops::seed_label(seed));
             self.start_new_chess_game_with_seed(seed, seed_ops::msg_repeated_seed(seed));
             return;
         }
@@ -210,7 +216,7 @@ impl CardthropicWindow {
         self.cancel_seed_winnable_check(None);
         self.clear_seed_entry_feedback();
         let seed = self.imp().current_seed.get();
-        self.set_seed_input_text(&seed.to_string());
+        self.set_seed_input_text(&seed_ops::seed_label(seed));
         self.start_new_game_with_seed(seed, seed_ops::msg_repeated_seed(seed));
     }
 
@@ -232,7 +238,7 @@ impl CardthropicWindow {
         let seed_entry = gtk::Entry::new();
         let seed_text = self.seed_input_text();
         if seed_text.trim().is_empty() {
-            seed_entry.set_text(&self.imp().current_seed.get().to_string());
+            seed_entry.set_text(&seed_ops::seed_label(self.imp().current_seed.get()));
         } else {
             seed_entry.set_text(&seed_text);
         }
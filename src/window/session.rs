@@ -1,9 +1,14 @@
 use super::*;
 use crate::engine::boundary;
 use crate::engine::game_mode::VariantRuntime;
+use crate::engine::replay::ReplayLog;
+use crate::engine::seed_ops;
 use crate::engine::session::{decode_persisted_session, encode_persisted_session};
 use crate::engine::variant_state::VariantStateStore;
-use crate::game::{decode_fen, encode_fen, legal_moves, ChessPosition, ChessVariant};
+use crate::game::{
+    chess960_position_from_scharnagl, decode_fen, encode_fen, legal_moves, ChessPosition,
+    ChessVariant, FreecellGame, PysolGameNumber, SpiderGame, TextBoard, CHESS960_POSITION_COUNT,
+};
 use crate::startup_trace;
 
 impl CardthropicWindow {
@@ -108,6 +113,20 @@ impl CardthropicWindow {
             VariantRuntime::Klondike(game) => format!("k:{}", game.encode_for_session()),
             VariantRuntime::Spider(game) => format!("s:{}", game.encode_for_session()),
             VariantRuntime::Freecell(game) => format!("f:{}", game.encode_for_session()),
+            VariantRuntime::Yukon(game) => format!("y:{}", game.encode_for_session()),
+            VariantRuntime::Pyramid(game) => format!("p:{}", game.encode_for_session()),
+            VariantRuntime::TriPeaks(game) => format!("t:{}", game.encode_for_session()),
+            VariantRuntime::Golf(game) => format!("g:{}", game.encode_for_session()),
+            VariantRuntime::FortyThieves(game) => format!("ft:{}", game.encode_for_session()),
+            VariantRuntime::Scorpion(game) => format!("sc:{}", game.encode_for_session()),
+            VariantRuntime::Canfield(game) => format!("cf:{}", game.encode_for_session()),
+            VariantRuntime::BakersDozen(game) => format!("bd:{}", game.encode_for_session()),
+            VariantRuntime::BeleagueredCastle(game) => {
+                format!("bc:{}", game.encode_for_session())
+            }
+            VariantRuntime::EightOff(game) => format!("eo:{}", game.encode_for_session()),
+            VariantRuntime::SeahavenTowers(game) => format!("sh:{}", game.encode_for_session()),
+            VariantRuntime::BakersGame(game) => format!("bg:{}", game.encode_for_session()),
         }
     }
 
@@ -219,10 +238,12 @@ impl CardthropicWindow {
             })
             .collect::<String>();
         format!(
-            "mode={mode};draw={draw};selected={selected};waste={waste};moves={};elapsed={};timer={timer};apm_offset={};runtime_hex={runtime};apm={apm};fslots={foundation_slots};chess_mode={chess_mode};chess_variant={chess_variant};chess_fen={chess_position};chess_selected={chess_selected};chess_last_from={chess_last_from};chess_last_to={chess_last_to};chess_history={chess_history};chess_future={chess_future}",
+            "mode={mode};draw={draw};selected={selected};waste={waste};moves={};score={};elapsed={};timer={timer};apm_offset={};runtime_hex={runtime};apm={apm};fslots={foundation_slots};replay={};chess_mode={chess_mode};chess_variant={chess_variant};chess_fen={chess_position};chess_selected={chess_selected};chess_last_from={chess_last_from};chess_last_to={chess_last_to};chess_history={chess_history};chess_future={chess_future}",
             snapshot.move_count,
+            snapshot.score,
             snapshot.elapsed_seconds,
-            snapshot.apm_elapsed_offset_seconds
+            snapshot.apm_elapsed_offset_seconds,
+            snapshot.replay_id
         )
     }
 
@@ -242,6 +263,10 @@ impl CardthropicWindow {
             _ => return None,
         };
         let move_count = fields.get("moves")?.parse::<u32>().ok()?;
+        let score = fields
+            .get("score")
+            .and_then(|v| v.parse::<i32>().ok())
+            .unwrap_or(0);
         let elapsed_seconds = fields.get("elapsed")?.parse::<u32>().ok()?;
         let timer_started = match *fields.get("timer")? {
             "1" => true,
@@ -275,6 +300,10 @@ impl CardthropicWindow {
                 Some(out)
             })
             .unwrap_or([None, None, None, None]);
+        let replay_id = fields
+            .get("replay")
+            .and_then(|raw| raw.parse::<u64>().ok())
+            .unwrap_or(0);
         let chess_mode_active = fields
             .get("chess_mode")
             .map(|raw| *raw == "1")
@@ -297,6 +326,7 @@ impl CardthropicWindow {
                     Some(match chess_variant {
                         ChessVariant::Standard => crate::game::standard_position(),
                         ChessVariant::Chess960 => crate::game::chess960_position(0),
+                        ChessVariant::DoubleChess960 => crate::game::double_chess960_position(0),
                         ChessVariant::Atomic => crate::game::atomic_position(),
                     })
                 } else {
@@ -337,11 +367,13 @@ impl CardthropicWindow {
             selected_run,
             selected_waste,
             move_count,
+            score,
             elapsed_seconds,
             timer_started,
             apm_elapsed_offset_seconds,
             apm_samples,
             foundation_slot_suits,
+            replay_id,
             chess_mode_active,
             chess_variant,
             chess_position,
@@ -406,6 +438,7 @@ impl CardthropicWindow {
         match raw {
             "chess-standard" => Some(ChessVariant::Standard),
             "chess-960" => Some(ChessVariant::Chess960),
+            "chess-960-double" => Some(ChessVariant::DoubleChess960),
             "chess-atomic" => Some(ChessVariant::Atomic),
             _ => None,
         }
@@ -456,6 +489,9 @@ impl CardthropicWindow {
             "chess960" | "fischerandom" | "fischerrandom" | "chessfischerandom" => {
                 Some(ChessVariant::Chess960)
             }
+            "doublechess960" | "doublefischerandom" | "doublefischerrandom" => {
+                Some(ChessVariant::DoubleChess960)
+            }
             "atomic" | "atomicchess" | "chessatomic" => Some(ChessVariant::Atomic),
             _ => None,
         }
@@ -471,7 +507,7 @@ impl CardthropicWindow {
     fn parse_chess_notation_payload(
         raw: &str,
         fallback_variant: ChessVariant,
-    ) -> Option<(ChessVariant, ChessPosition)> {
+    ) -> Option<(ChessVariant, ChessPosition, Option<u16>)> {
         let trimmed = raw.trim();
         if trimmed.is_empty() {
             return None;
@@ -482,15 +518,17 @@ impl CardthropicWindow {
             fallback_variant,
             ChessVariant::Standard,
             ChessVariant::Chess960,
+            ChessVariant::DoubleChess960,
             ChessVariant::Atomic,
         ] {
             if let Some(position) = decode_fen(trimmed, variant) {
-                return Some((variant, position));
+                return Some((variant, position, None));
             }
         }
 
         let mut variant_hint = None;
         let mut fen_hint = None::<String>;
+        let mut start_number = None::<u16>;
         for line in trimmed.lines() {
             let line = line.trim();
             if line.is_empty() {
@@ -506,6 +544,15 @@ impl CardthropicWindow {
                     fen_hint = Some(value.to_string());
                     continue;
                 }
+                if tag.eq_ignore_ascii_case("StartPosition") {
+                    start_number = value
+                        .trim_start_matches(|ch: char| ch.is_ascii_alphabetic())
+                        .trim()
+                        .parse::<u16>()
+                        .ok()
+                        .filter(|number| *number < CHESS960_POSITION_COUNT);
+                    continue;
+                }
             }
 
             if let Some(value) = line
@@ -539,7 +586,12 @@ impl CardthropicWindow {
             }
         }
 
-        let fen = fen_hint?;
+        let Some(fen) = fen_hint else {
+            // A bare Chess960 start-position tag is enough to reproduce the layout.
+            let number = start_number?;
+            let position = chess960_position_from_scharnagl(number)?;
+            return Some((ChessVariant::Chess960, position, Some(number)));
+        };
         let mut candidate_variants = Vec::new();
         if let Some(variant) = variant_hint {
             candidate_variants.push(variant);
@@ -550,6 +602,7 @@ impl CardthropicWindow {
         for variant in [
             ChessVariant::Standard,
             ChessVariant::Chess960,
+            ChessVariant::DoubleChess960,
             ChessVariant::Atomic,
         ] {
             if !candidate_variants.contains(&variant) {
@@ -558,7 +611,8 @@ impl CardthropicWindow {
         }
         for variant in candidate_variants {
             if let Some(position) = decode_fen(&fen, variant) {
-                return Some((variant, position));
+                let start_number = start_number.filter(|_| variant == ChessVariant::Chess960);
+                return Some((variant, position, start_number));
             }
         }
         None
@@ -583,10 +637,17 @@ impl CardthropicWindow {
         let variant = match imp.chess_variant.get() {
             ChessVariant::Standard => "Standard",
             ChessVariant::Chess960 => "Chess960",
+            ChessVariant::DoubleChess960 => "Double Chess960",
             ChessVariant::Atomic => "Atomic",
         };
         let fen = encode_fen(&imp.chess_position.borrow());
-        format!("[Variant \"{variant}\"]\n[SetUp \"1\"]\n[FEN \"{fen}\"]")
+        let start_position = match imp.chess960_start_number.get() {
+            Some(number) if imp.chess_variant.get() == ChessVariant::Chess960 => {
+                format!("\n[StartPosition \"{number}\"]")
+            }
+            _ => String::new(),
+        };
+        format!("[Variant \"{variant}\"]{start_position}\n[SetUp \"1\"]\n[FEN \"{fen}\"]")
     }
 
     pub(super) fn restore_chess_from_notation_payload(
@@ -596,7 +657,8 @@ impl CardthropicWindow {
         persist_payload: bool,
     ) -> Result<(), String> {
         let fallback_variant = self.imp().chess_variant.get();
-        let Some((variant, position)) = Self::parse_chess_notation_payload(raw, fallback_variant)
+        let Some((variant, position, start_number)) =
+            Self::parse_chess_notation_payload(raw, fallback_variant)
         else {
             return Err(
                 "clipboard text is not supported chess notation (expected FEN or [FEN \"...\"])"
@@ -615,6 +677,8 @@ impl CardthropicWindow {
         imp.chess_variant.set(variant);
         *imp.chess_position.borrow_mut() = position;
         self.reset_chess_session_state();
+        imp.chess960_start_number.set(start_number);
+        self.sync_chess960_start_number();
         imp.history.borrow_mut().clear();
         imp.future.borrow_mut().clear();
         imp.move_count.set(0);
@@ -635,6 +699,9 @@ impl CardthropicWindow {
         }
 
         imp.session_dirty.set(false);
+        self.resume_stats_game_tracking();
+        self.clear_daily_game();
+        self.end_replay_recording();
         self.reset_hint_cycle_memory();
         self.reset_auto_play_memory();
         let state_hash = self.current_game_hash();
@@ -657,16 +724,108 @@ impl CardthropicWindow {
             Err(err) => err,
         };
 
+        let board_err = match self.restore_board_from_text_payload(raw, persist_payload) {
+            Ok(()) => return Ok(()),
+            Err(err) => err,
+        };
+
         self.restore_chess_from_notation_payload(
             raw,
             "Restored chess state from clipboard notation.",
             persist_payload,
         )
         .map_err(|chess_err| {
-            format!("Paste failed: {session_err}. Also not valid chess notation: {chess_err}.")
+            format!(
+                "Paste failed: {session_err}. Not a board either: {board_err}. Also not valid chess notation: {chess_err}."
+            )
         })
     }
 
+    /// Deals `pysol:<number>` in the active Klondike, Spider or FreeCell game,
+    /// or loads a plain-text board.
+    pub(super) fn restore_board_from_text_payload(
+        &self,
+        raw: &str,
+        persist_payload: bool,
+    ) -> Result<(), String> {
+        let Some(number) = raw.trim().strip_prefix("pysol:") else {
+            let mut board = TextBoard::parse(raw)?;
+            if !TextBoard::has_rules_line(raw) {
+                self.apply_current_klondike_rules(&mut board);
+            }
+            return self.start_text_board(
+                board,
+                "Loaded board from clipboard text.",
+                persist_payload,
+            );
+        };
+        let Some(number) = PysolGameNumber::parse(number) else {
+            return Err(format!("'{}' is not a PySol game number", number.trim()));
+        };
+        let mut board = match self.active_game_mode() {
+            GameMode::Klondike => TextBoard::Klondike(KlondikeGame::from_pysol_game(number)),
+            GameMode::Spider => TextBoard::Spider(SpiderGame::from_pysol_game(number)),
+            GameMode::Freecell => TextBoard::Freecell(FreecellGame::from_pysol_game(number)),
+            _ => {
+                return Err("PySol game numbers deal Klondike, Spider and FreeCell only".to_string())
+            }
+        };
+        self.apply_current_klondike_rules(&mut board);
+        self.start_text_board(
+            board,
+            &format!("Dealt PySol game {}.", number.label()),
+            persist_payload,
+        )
+    }
+
+    /// Gives a Klondike board that names no rules the current draw, pass and
+    /// Thoughtful settings, the way a fresh deal would get them.
+    pub(super) fn apply_current_klondike_rules(&self, board: &mut TextBoard) {
+        if let TextBoard::Klondike(game) = board {
+            game.set_draw_mode(self.current_klondike_draw_mode());
+            game.set_pass_limit(self.current_klondike_pass_limit());
+            game.set_thoughtful(self.current_klondike_thoughtful());
+        }
+    }
+
+    /// Starts play from a board that did not come from a seed, by routing it
+    /// through the session restore. Klondike plays under the board's own
+    /// rules. The board is marked seedless, so winning it is not credited to
+    /// the seed, the variant statistics or a daily challenge.
+    pub(super) fn start_text_board(
+        &self,
+        board: TextBoard,
+        status_message: &str,
+        persist_payload: bool,
+    ) -> Result<(), String> {
+        let imp = self.imp();
+        let mut store = imp.game.borrow().clone();
+        let mode = store.set_text_board(board);
+        let draw_mode = if mode == GameMode::Klondike {
+            store.klondike().draw_mode()
+        } else {
+            self.current_klondike_draw_mode()
+        };
+        let mut payload =
+            encode_persisted_session(&store, imp.current_seed.get(), mode, 0, 0, false, draw_mode);
+        payload.push_str("\nseedless=1");
+        self.restore_session_from_payload(
+            &payload,
+            &format!(
+                "{status_message} Not from a seed, so it stays out of seed history and statistics."
+            ),
+            persist_payload,
+        )
+    }
+
+    /// The active board in the plain-text format; chess has none.
+    pub(super) fn build_board_text(&self) -> Option<String> {
+        if self.imp().chess_mode_active.get() {
+            return None;
+        }
+        Some(self.imp().game.borrow().board_text(self.active_game_mode()))
+    }
+
     fn payload_field<'a>(raw: &'a str, key: &str) -> Option<&'a str> {
         for line in raw.lines() {
             let Some((k, v)) = line.split_once('=') else {
@@ -708,6 +867,8 @@ impl CardthropicWindow {
         } else {
             Self::encode_snapshot_stack(&imp.future.borrow())
         };
+        payload.push_str("\nscore=");
+        payload.push_str(&imp.score.get().to_string());
         payload.push_str("\nhistory=");
         payload.push_str(&history_encoded);
         payload.push_str("\nfuture=");
@@ -758,11 +919,34 @@ impl CardthropicWindow {
         } else {
             payload.push('-');
         }
+        payload.push_str("\nchess-sp=");
+        match imp.chess960_start_number.get() {
+            Some(number) if chess_mode_active => payload.push_str(&number.to_string()),
+            _ => payload.push('-'),
+        }
+        if imp.seedless_board.get() {
+            payload.push_str("\nseedless=1");
+        }
+        if let Some(date) = self.daily_game_session_value() {
+            payload.push_str("\ndaily=");
+            payload.push_str(&date);
+        }
+        // The move log only travels with the board it was recorded on.
+        if imp.replay_board_id.get() == imp.replay_log_id.get() {
+            if let Some(log) = imp.replay_log.borrow().as_ref() {
+                payload.push_str(&format!(
+                    "\nreplay-id={}\nreplay-base={}\nreplay={}",
+                    imp.replay_log_id.get(),
+                    imp.replay_base_moves.get(),
+                    Self::hex_encode(&log.to_text())
+                ));
+            }
+        }
         payload
     }
 
     fn persist_session_if_changed(&self) {
-        if !self.should_persist_shared_state() {
+        if !self.should_persist_shared_state() || self.replay_viewer_active() {
             return;
         }
         let settings = self.imp().settings.borrow().clone();
@@ -862,13 +1046,21 @@ impl CardthropicWindow {
         };
 
         let imp = self.imp();
+        self.close_replay_viewer();
         self.stop_rapid_wand();
         self.stop_robot_mode();
         self.cancel_seed_winnable_check(None);
         imp.game.borrow_mut().set_runtime(session.runtime.clone());
         imp.current_seed.set(session.seed);
+        imp.seedless_board
+            .set(Self::payload_field(raw, "seedless") == Some("1"));
         self.roll_apm_timeline_forward();
         imp.move_count.set(session.move_count);
+        imp.score.set(
+            Self::payload_field(raw, "score")
+                .and_then(|value| value.parse::<i32>().ok())
+                .unwrap_or(0),
+        );
         imp.elapsed_seconds.set(session.elapsed_seconds);
         imp.timer_started.set(session.timer_started);
         *imp.selected_run.borrow_mut() = None;
@@ -882,8 +1074,12 @@ impl CardthropicWindow {
         imp.klondike_draw_mode.set(session.klondike_draw_mode);
         imp.freecell_card_count_mode
             .set(session.freecell_card_count_mode);
-        imp.freecell_cell_count
-            .set(imp.game.borrow().freecell().freecell_count() as u8);
+        imp.freecell_cell_count.set(
+            imp.game
+                .borrow()
+                .freecell_for(FreecellLayout::FreeCell)
+                .freecell_count() as u8,
+        );
         let _ = boundary::set_draw_mode(
             &mut imp.game.borrow_mut(),
             session.mode,
@@ -893,7 +1089,11 @@ impl CardthropicWindow {
             .set(imp.timer_started.get() && !boundary::is_won(&imp.game.borrow(), session.mode));
         imp.spider_suit_mode
             .set(imp.game.borrow().spider().suit_mode());
-        self.set_seed_input_text(&session.seed.to_string());
+        if imp.seedless_board.get() {
+            self.set_seed_input_text("");
+        } else {
+            self.set_seed_input_text(&seed_ops::seed_label(session.seed));
+        }
         self.update_game_mode_menu_selection();
         self.invalidate_card_render_cache();
         imp.pending_deal_instructions.set(false);
@@ -948,6 +1148,9 @@ impl CardthropicWindow {
                     .unwrap_or_default();
                 *imp.chess_history.borrow_mut() = chess_history;
                 *imp.chess_future.borrow_mut() = chess_future;
+                imp.chess960_start_number
+                    .set(Self::payload_field(raw, "chess-sp").and_then(|value| value.parse().ok()));
+                self.sync_chess960_start_number();
                 let has_legal_moves = !legal_moves(&imp.chess_position.borrow()).is_empty();
                 imp.timer_started
                     .set(imp.timer_started.get() && has_legal_moves);
@@ -967,6 +1170,23 @@ impl CardthropicWindow {
             }
         }
         imp.session_dirty.set(false);
+        self.resume_stats_game_tracking();
+        self.resume_daily_game(Self::payload_field(raw, "daily"));
+        let replay = Self::payload_field(raw, "replay")
+            .and_then(Self::hex_decode)
+            .and_then(|text| ReplayLog::parse(&text).ok())
+            .filter(|log| log.mode == session.mode && !chess_mode_active);
+        let replay_id = Self::payload_field(raw, "replay-id").and_then(|value| value.parse().ok());
+        match (replay, replay_id) {
+            (Some(log), Some(id)) => {
+                let base = Self::payload_field(raw, "replay-base")
+                    .and_then(|value| value.parse().ok())
+                    .unwrap_or(0);
+                self.resume_replay_recording(log, id, base);
+            }
+            _ if session.move_count == 0 && !chess_mode_active => self.begin_replay_recording(),
+            _ => self.end_replay_recording(),
+        }
         self.reset_hint_cycle_memory();
         self.reset_auto_play_memory();
         let state_hash = self.current_game_hash();
@@ -979,11 +1199,16 @@ impl CardthropicWindow {
         let imp = self.imp();
         let elapsed = imp.elapsed_seconds.get();
         let apm = self.current_apm();
+        let score = self
+            .current_displayed_score()
+            .map(|score| format!("   Score: {score}"))
+            .unwrap_or_default();
         let mem = self.current_memory_mib_text();
         imp.stats_label.set_label(&format!(
-            "Moves: {}   APM: {:.1}   Time: {}   Mem: {}",
+            "Moves: {}   APM: {:.1}{}   Time: {}   Mem: {}",
             imp.move_count.get(),
             apm,
+            score,
             format_time(elapsed),
             mem
         ));
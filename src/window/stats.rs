@@ -0,0 +1,287 @@
+use super::*;
+use crate::engine::boundary;
+use crate::engine::stats::{StatsKey, VariantStatsStore};
+
+impl CardthropicWindow {
+    pub(super) fn load_variant_stats(&self) {
+        let raw = self
+            .imp()
+            .settings
+            .borrow()
+            .as_ref()
+            .filter(|settings| Self::settings_has_variant_stats_key(settings))
+            .map(|settings| settings.string(SETTINGS_KEY_VARIANT_STATS).to_string())
+            .unwrap_or_default();
+        *self.imp().variant_stats.borrow_mut() = VariantStatsStore::load_from_string(&raw);
+    }
+
+    fn settings_has_variant_stats_key(settings: &gio::Settings) -> bool {
+        settings
+            .settings_schema()
+            .map(|schema| schema.has_key(SETTINGS_KEY_VARIANT_STATS))
+            .unwrap_or(false)
+    }
+
+    fn persist_variant_stats(&self) {
+        if !self.should_persist_shared_state() {
+            return;
+        }
+        let imp = self.imp();
+        if let Some(settings) = imp.settings.borrow().as_ref() {
+            if Self::settings_has_variant_stats_key(settings) {
+                let payload = imp.variant_stats.borrow().serialize();
+                let _ = settings.set_string(SETTINGS_KEY_VARIANT_STATS, &payload);
+            }
+        }
+    }
+
+    /// Statistics table for the deal on the board, or `None` in chess.
+    pub(super) fn current_stats_key(&self) -> Option<StatsKey> {
+        let imp = self.imp();
+        if imp.chess_mode_active.get() {
+            return None;
+        }
+        Some(StatsKey::for_game(
+            &imp.game.borrow(),
+            self.active_game_mode(),
+            imp.klondike_draw_mode.get(),
+        ))
+    }
+
+    pub(super) fn reset_stats_game_tracking(&self) {
+        let imp = self.imp();
+        *imp.stats_game_key.borrow_mut() = None;
+        imp.stats_game_assisted.set(false);
+    }
+
+    /// A restored game that is already under way picks its statistics entry
+    /// back up, so winning it after a restart still counts.
+    pub(super) fn resume_stats_game_tracking(&self) {
+        let imp = self.imp();
+        self.reset_stats_game_tracking();
+        let mode = self.active_game_mode();
+        if imp.seedless_board.get()
+            || imp.move_count.get() == 0
+            || boundary::is_won(&imp.game.borrow(), mode)
+        {
+            return;
+        }
+        let Some(key) = self.current_stats_key() else {
+            return;
+        };
+        if imp.variant_stats.borrow().is_in_progress(&key) {
+            *imp.stats_game_key.borrow_mut() = Some(key);
+        }
+    }
+
+    /// Counts the game as played on its first move. Games the robot touches
+    /// are recorded as played but never as won; seedless boards not at all.
+    pub(super) fn note_stats_move_applied(&self) {
+        let imp = self.imp();
+        if imp.robot_mode_running.get() {
+            imp.stats_game_assisted.set(true);
+        }
+        if imp.seedless_board.get()
+            || imp.stats_game_key.borrow().is_some()
+            || imp.move_count.get() != 1
+        {
+            return;
+        }
+        if imp.stats_game_assisted.get() {
+            return;
+        }
+        let Some(key) = self.current_stats_key() else {
+            return;
+        };
+        imp.variant_stats
+            .borrow_mut()
+            .note_game_started(key.clone());
+        *imp.stats_game_key.borrow_mut() = Some(key);
+        self.persist_variant_stats();
+    }
+
+    pub(super) fn note_stats_win_if_needed(&self) {
+        let imp = self.imp();
+        let mode = self.active_game_mode();
+        if !boundary::is_won(&imp.game.borrow(), mode) {
+            return;
+        }
+        let Some(key) = imp.stats_game_key.borrow_mut().take() else {
+            return;
+        };
+        if imp.stats_game_assisted.get() {
+            return;
+        }
+        imp.variant_stats.borrow_mut().note_win(
+            key,
+            imp.elapsed_seconds.get(),
+            imp.move_count.get(),
+        );
+        self.persist_variant_stats();
+        self.refresh_stats_dialog();
+    }
+
+    fn reset_variant_stats(&self) {
+        let imp = self.imp();
+        *imp.variant_stats.borrow_mut() = VariantStatsStore::default();
+        self.reset_stats_game_tracking();
+        self.persist_variant_stats();
+        *imp.status_override.borrow_mut() = Some("Statistics reset.".to_string());
+        self.refresh_stats_dialog();
+        self.render();
+    }
+
+    fn copy_variant_stats_to_clipboard(&self) {
+        let csv = self.imp().variant_stats.borrow().export_csv();
+        self.clipboard().set_text(&csv);
+        *self.imp().status_override.borrow_mut() =
+            Some("Copied statistics to clipboard.".to_string());
+        self.render();
+    }
+
+    pub(super) fn refresh_stats_dialog(&self) {
+        let Some(grid) = self.imp().stats_grid.borrow().clone() else {
+            return;
+        };
+        while let Some(child) = grid.first_child() {
+            grid.remove(&child);
+        }
+
+        let headers = [
+            "Variant", "Rules", "Played", "Won", "Win %", "Streak", "Best", "Fastest", "Fewest",
+            "Avg APM",
+        ];
+        for (col, header) in headers.iter().enumerate() {
+            let label = gtk::Label::new(Some(header));
+            label.set_xalign(0.0);
+            label.add_css_class("heading");
+            grid.attach(&label, col as i32, 0, 1, 1);
+        }
+
+        let stats = self.imp().variant_stats.borrow();
+        if stats.is_empty() {
+            let note = gtk::Label::new(Some("No games recorded yet."));
+            note.set_xalign(0.0);
+            note.add_css_class("dim-label");
+            grid.attach(&note, 0, 1, headers.len() as i32, 1);
+            return;
+        }
+        for (row, (key, entry)) in stats.rows().into_iter().enumerate() {
+            let cells = [
+                key.mode.label().to_string(),
+                key.rules_label(),
+                entry.played.to_string(),
+                entry.won.to_string(),
+                format!("{:.1}", entry.win_rate_percent()),
+                entry.current_streak.to_string(),
+                entry.best_streak.to_string(),
+                entry
+                    .fastest_win_seconds
+                    .map(format_time)
+                    .unwrap_or_else(|| "-".to_string()),
+                entry
+                    .fewest_moves
+                    .map(|moves| moves.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                entry
+                    .average_apm()
+                    .map(|apm| format!("{apm:.1}"))
+                    .unwrap_or_else(|| "-".to_string()),
+            ];
+            for (col, text) in cells.iter().enumerate() {
+                let label = gtk::Label::new(Some(text));
+                label.set_xalign(if col < 2 { 0.0 } else { 1.0 });
+                grid.attach(&label, col as i32, row as i32 + 1, 1, 1);
+            }
+        }
+    }
+
+    pub(super) fn show_stats_dialog(&self) {
+        if let Some(existing) = self.imp().stats_dialog.borrow().as_ref() {
+            self.refresh_stats_dialog();
+            existing.present();
+            return;
+        }
+
+        let dialog = gtk::Window::builder()
+            .title("Statistics")
+            .transient_for(self)
+            .modal(false)
+            .default_width(760)
+            .default_height(420)
+            .build();
+        dialog.set_destroy_with_parent(true);
+        dialog.set_hide_on_close(false);
+        dialog.connect_close_request(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[upgrade_or]
+            glib::Propagation::Proceed,
+            move |_| {
+                *window.imp().stats_dialog.borrow_mut() = None;
+                *window.imp().stats_grid.borrow_mut() = None;
+                glib::Propagation::Proceed
+            }
+        ));
+        let dialog_keys = gtk::EventControllerKey::new();
+        dialog_keys.set_propagation_phase(gtk::PropagationPhase::Capture);
+        dialog_keys.connect_key_pressed(glib::clone!(
+            #[weak]
+            dialog,
+            #[upgrade_or]
+            glib::Propagation::Proceed,
+            move |_, key, _, _| {
+                if key == gdk::Key::Escape {
+                    dialog.close();
+                    return glib::Propagation::Stop;
+                }
+                glib::Propagation::Proceed
+            }
+        ));
+        dialog.add_controller(dialog_keys);
+
+        let grid = gtk::Grid::new();
+        grid.set_column_spacing(16);
+        grid.set_row_spacing(6);
+        let scroller = gtk::ScrolledWindow::new();
+        scroller.set_hexpand(true);
+        scroller.set_vexpand(true);
+        scroller.set_child(Some(&grid));
+
+        let root = gtk::Box::new(gtk::Orientation::Vertical, 8);
+        root.set_margin_top(10);
+        root.set_margin_bottom(10);
+        root.set_margin_start(10);
+        root.set_margin_end(10);
+        root.append(&scroller);
+
+        let actions_row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        actions_row.set_halign(gtk::Align::End);
+        let reset_button = gtk::Button::with_label("Reset");
+        reset_button.add_css_class("destructive-action");
+        reset_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.reset_variant_stats();
+            }
+        ));
+        let copy_button = gtk::Button::with_label("Copy CSV");
+        copy_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.copy_variant_stats_to_clipboard();
+            }
+        ));
+        actions_row.append(&reset_button);
+        actions_row.append(&copy_button);
+        root.append(&actions_row);
+        dialog.set_child(Some(&root));
+
+        *self.imp().stats_grid.borrow_mut() = Some(grid);
+        *self.imp().stats_dialog.borrow_mut() = Some(dialog.clone());
+        self.refresh_stats_dialog();
+        dialog.present();
+    }
+}
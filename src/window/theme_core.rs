@@ -398,6 +398,19 @@ impl CardthropicWindow {
         };
         imp.freecell_card_count_mode.set(freecell_card_count_mode);
         imp.freecell_cell_count.set(freecell_cell_count);
+        let scoring_system = {
+            let settings = imp.settings.borrow().clone();
+            settings
+                .as_ref()
+                .filter(|settings| Self::settings_has_scoring_system_key(settings))
+                .map(|settings| {
+                    ScoringSystem::from_setting(
+                        settings.string(SETTINGS_KEY_SCORING_SYSTEM).as_ref(),
+                    )
+                })
+                .unwrap_or(ScoringSystem::Standard)
+        };
+        imp.scoring_system.set(scoring_system);
         let seed = imp.current_seed.get();
         imp.game
             .borrow_mut()
@@ -1,4 +1,7 @@
 use crate::engine::game_mode::VariantRuntime;
+use crate::engine::replay::ReplayLog;
+use crate::engine::stats::StatsKey;
+use crate::engine::variant_state::VariantStateStore;
 use crate::game::{
     Card, ChessColor, ChessPosition, ChessVariant, DrawMode, GameMode, Square, Suit,
 };
@@ -11,11 +14,14 @@ pub struct Snapshot {
     pub(super) selected_run: Option<SelectedRun>,
     pub(super) selected_waste: bool,
     pub(super) move_count: u32,
+    pub(super) score: i32,
     pub(super) elapsed_seconds: u32,
     pub(super) timer_started: bool,
     pub(super) apm_elapsed_offset_seconds: u32,
     pub(super) apm_samples: Vec<ApmSample>,
     pub(super) foundation_slot_suits: [Option<Suit>; 4],
+    /// Which move recording the board belongs to; 0 when none.
+    pub(super) replay_id: u64,
     pub(super) chess_mode_active: bool,
     pub(super) chess_variant: ChessVariant,
     pub(super) chess_position: Option<ChessPosition>,
@@ -26,6 +32,25 @@ pub struct Snapshot {
     pub(super) chess_future: Vec<ChessPosition>,
 }
 
+/// The game set aside while the Replay Viewer has the board.
+#[derive(Debug)]
+pub(super) struct ReplayStash {
+    pub(super) game: VariantStateStore,
+    pub(super) session: String,
+    pub(super) history: Vec<Snapshot>,
+    pub(super) future: Vec<Snapshot>,
+    pub(super) foundation_slot_suits: [Option<Suit>; 4],
+    pub(super) stats_game_key: Option<StatsKey>,
+    pub(super) stats_game_assisted: bool,
+    /// The game on the board as a log, cut at its undo position.
+    pub(super) this_game: Option<ReplayLog>,
+    pub(super) replay_log: Option<ReplayLog>,
+    pub(super) replay_log_id: u64,
+    pub(super) replay_board_id: u64,
+    pub(super) replay_base_moves: u32,
+    pub(super) replay_last_game: Option<ReplayLog>,
+}
+
 #[derive(Debug, Clone, Copy)]
 #[allow(dead_code)]
 pub(super) struct ApmSample {
@@ -1,12 +1,33 @@
 use super::*;
+use crate::engine::seed_ops;
 use crate::engine::variant::spec_for_id;
 use crate::engine::variant_engine::engine_for_mode;
+use crate::game::{
+    KlondikePassLimit, SpiderGame, SpiderRuleSet, WasteBuildGame, WasteBuildLayout,
+    WasteBuildRules, YukonGame,
+};
 
 fn mode_switch_pun(mode: GameMode) -> &'static str {
     match mode {
         GameMode::Klondike => "🥇 Klondike mode: classic pressure, clean lines.",
         GameMode::Spider => "🕷️ Spider mode: one web, many traps.",
         GameMode::Freecell => "🗽 FreeCell mode: freedom through structure.",
+        GameMode::Yukon => "🐻 Yukon mode: every face-up card is a handle.",
+        GameMode::Pyramid => "🔺 Pyramid mode: everything adds up to thirteen.",
+        GameMode::TriPeaks => "⛰️ TriPeaks mode: three summits, one long streak.",
+        GameMode::Golf => "⛳ Golf mode: up and down, hoping for par.",
+        GameMode::FortyThieves => "🗡️ Forty Thieves mode: two decks, one pass, no mercy.",
+        GameMode::Scorpion => "🦂 Scorpion mode: every face-up card has a sting in its tail.",
+        GameMode::Canfield => "🎰 Canfield mode: the house always starts with a card up.",
+        GameMode::BakersDozen => "🥖 Baker's Dozen mode: thirteen loaves, kings at the bottom.",
+        GameMode::BeleagueredCastle => {
+            "🏰 Beleaguered Castle mode: the aces hold the keep; storm the rows."
+        }
+        GameMode::EightOff => "🎱 Eight Off mode: eight cells, one suit per column.",
+        GameMode::SeahavenTowers => {
+            "🌊 Seahaven Towers mode: ten columns, only kings find empty ground."
+        }
+        GameMode::BakersGame => "🧁 Baker's Game mode: FreeCell's older sibling keeps to suit.",
     }
 }
 
@@ -19,10 +40,22 @@ impl CardthropicWindow {
         self.imp().klondike_draw_mode.get()
     }
 
+    pub(super) fn current_klondike_pass_limit(&self) -> KlondikePassLimit {
+        self.imp().game.borrow().klondike().pass_limit()
+    }
+
+    pub(super) fn current_klondike_thoughtful(&self) -> bool {
+        self.imp().game.borrow().klondike().is_thoughtful()
+    }
+
     pub(super) fn current_spider_suit_mode(&self) -> SpiderSuitMode {
         self.imp().spider_suit_mode.get()
     }
 
+    pub(super) fn current_spider_rule_set(&self) -> SpiderRuleSet {
+        self.imp().game.borrow().spider().rule_set()
+    }
+
     pub(super) fn current_freecell_card_count_mode(&self) -> FreecellCardCountMode {
         self.imp().freecell_card_count_mode.get()
     }
@@ -31,6 +64,17 @@ impl CardthropicWindow {
         self.imp().freecell_cell_count.get()
     }
 
+    /// Cells on the active FreeCell-family board: the configured count for
+    /// classic FreeCell, the layout's own row for the others.
+    pub(super) fn freecell_board_cell_count(&self) -> u8 {
+        FreecellLayout::for_mode(self.active_game_mode())
+            .and_then(FreecellLayout::fixed_cell_count)
+            .unwrap_or_else(|| {
+                self.current_freecell_cell_count()
+                    .clamp(FREECELL_MIN_CELL_COUNT, FREECELL_MAX_CELL_COUNT)
+            })
+    }
+
     pub(super) fn set_klondike_draw_mode(&self, draw_mode: DrawMode) {
         let imp = self.imp();
         if imp.klondike_draw_mode.get() == draw_mode {
@@ -44,7 +88,7 @@ impl CardthropicWindow {
             format!(
                 "Deal {} selected. Redealt current seed {}.",
                 draw_mode.count(),
-                seed
+                seed_ops::seed_label(seed)
             ),
         );
         self.imp().history.borrow_mut().push(undo_anchor);
@@ -70,7 +114,7 @@ impl CardthropicWindow {
                     format!(
                         "Deal {} selected. Redealt current seed {}.",
                         draw_mode.count(),
-                        seed
+                        seed_ops::seed_label(seed)
                     ),
                 );
             }
@@ -83,6 +127,71 @@ impl CardthropicWindow {
         );
     }
 
+    pub(super) fn set_klondike_pass_limit(&self, pass_limit: KlondikePassLimit) {
+        if self.current_klondike_pass_limit() == pass_limit {
+            return;
+        }
+        let imp = self.imp();
+        if self.active_game_mode() != GameMode::Klondike {
+            // The limit rides on the stored deal and carries into the next one.
+            imp.game
+                .borrow_mut()
+                .klondike_mut()
+                .set_pass_limit(pass_limit);
+            self.update_game_settings_menu();
+            return;
+        }
+        let undo_anchor = self.snapshot();
+        imp.game
+            .borrow_mut()
+            .klondike_mut()
+            .set_pass_limit(pass_limit);
+        let seed = imp.current_seed.get();
+        self.start_new_game_with_seed(
+            seed,
+            format!(
+                "{} selected. Redealt current seed {}.",
+                pass_limit.label(),
+                seed_ops::seed_label(seed)
+            ),
+        );
+        self.imp().history.borrow_mut().push(undo_anchor);
+        self.imp().future.borrow_mut().clear();
+        self.render();
+    }
+
+    pub(super) fn set_klondike_thoughtful(&self, thoughtful: bool) {
+        if self.current_klondike_thoughtful() == thoughtful {
+            return;
+        }
+        let imp = self.imp();
+        if self.active_game_mode() != GameMode::Klondike {
+            imp.game
+                .borrow_mut()
+                .klondike_mut()
+                .set_thoughtful(thoughtful);
+            self.update_game_settings_menu();
+            return;
+        }
+        let undo_anchor = self.snapshot();
+        imp.game
+            .borrow_mut()
+            .klondike_mut()
+            .set_thoughtful(thoughtful);
+        let seed = imp.current_seed.get();
+        self.start_new_game_with_seed(
+            seed,
+            format!(
+                "Thoughtful {}. Redealt current seed {}.",
+                if thoughtful { "on" } else { "off" },
+                seed_ops::seed_label(seed)
+            ),
+        );
+        self.imp().history.borrow_mut().push(undo_anchor);
+        self.imp().future.borrow_mut().clear();
+        self.render();
+    }
+
     pub(super) fn set_spider_suit_mode(&self, suit_mode: SpiderSuitMode, persist: bool) {
         let imp = self.imp();
         if imp.spider_suit_mode.get() == suit_mode {
@@ -105,7 +214,7 @@ impl CardthropicWindow {
                 format!(
                     "Spider suits {} selected. Redealt current seed {}.",
                     suit_mode.suit_count(),
-                    seed
+                    seed_ops::seed_label(seed)
                 ),
             );
             self.imp().history.borrow_mut().push(undo_anchor);
@@ -134,7 +243,7 @@ impl CardthropicWindow {
                     format!(
                         "Spider suits {} selected. Redealt current seed {}.",
                         suit_mode.suit_count(),
-                        seed
+                        seed_ops::seed_label(seed)
                     ),
                 );
             }
@@ -147,6 +256,73 @@ impl CardthropicWindow {
         );
     }
 
+    pub(super) fn set_spider_rule_set(&self, rule_set: SpiderRuleSet) {
+        if self.current_spider_rule_set() == rule_set {
+            return;
+        }
+        let imp = self.imp();
+        let seed = imp.current_seed.get();
+        let suit_mode = imp.spider_suit_mode.get();
+        if self.active_game_mode() != GameMode::Spider {
+            // Like Yukon, the rule set rides on the stored deal.
+            imp.game
+                .borrow_mut()
+                .set_spider(SpiderGame::new_with_seed_and_rules(
+                    seed, suit_mode, rule_set,
+                ));
+            self.update_game_settings_menu();
+            return;
+        }
+        let undo_anchor = self.snapshot();
+        imp.game
+            .borrow_mut()
+            .set_spider(SpiderGame::new_with_seed_and_rules(
+                seed, suit_mode, rule_set,
+            ));
+        self.start_new_game_with_seed(
+            seed,
+            format!(
+                "{} rules selected. Redealt current seed {}.",
+                rule_set.label(),
+                seed_ops::seed_label(seed)
+            ),
+        );
+        self.imp().history.borrow_mut().push(undo_anchor);
+        self.imp().future.borrow_mut().clear();
+        self.render();
+    }
+
+    pub(super) fn select_spider_rule_set(&self, rule_set: SpiderRuleSet) {
+        let exited_chess = if self.imp().chess_mode_active.get() {
+            self.select_game_mode("spider");
+            true
+        } else {
+            false
+        };
+        if self.active_game_mode() != GameMode::Spider {
+            self.select_game_mode("spider");
+        }
+        if self.current_spider_rule_set() == rule_set {
+            if !exited_chess {
+                let seed = self.imp().current_seed.get();
+                self.start_new_game_with_seed(
+                    seed,
+                    format!(
+                        "{} rules selected. Redealt current seed {}.",
+                        rule_set.label(),
+                        seed_ops::seed_label(seed)
+                    ),
+                );
+            }
+        } else {
+            self.set_spider_rule_set(rule_set);
+        }
+        self.set_ephemeral_status(
+            mode_switch_pun(GameMode::Spider),
+            Duration::from_millis(2200),
+        );
+    }
+
     pub(super) fn set_freecell_card_count_mode(
         &self,
         card_count_mode: FreecellCardCountMode,
@@ -174,7 +350,7 @@ impl CardthropicWindow {
                     "Card Count {} and {} free cells selected. Redealt current seed {}.",
                     card_count_mode.card_count(),
                     self.current_freecell_cell_count(),
-                    seed
+                    seed_ops::seed_label(seed)
                 ),
             );
             self.imp().history.borrow_mut().push(undo_anchor);
@@ -204,7 +380,7 @@ impl CardthropicWindow {
                         "Card Count {} and {} free cells selected. Redealt current seed {}.",
                         card_count_mode.card_count(),
                         self.current_freecell_cell_count(),
-                        seed
+                        seed_ops::seed_label(seed)
                     ),
                 );
             }
@@ -217,6 +393,130 @@ impl CardthropicWindow {
         );
     }
 
+    pub(super) fn current_yukon_rule_set(&self) -> YukonRuleSet {
+        self.imp().game.borrow().yukon().rule_set()
+    }
+
+    pub(super) fn current_waste_build_rules(&self, layout: WasteBuildLayout) -> WasteBuildRules {
+        self.imp().game.borrow().waste_build(layout).rules()
+    }
+
+    /// Wrapping changes which deals are winnable, so it redeals the current
+    /// seed like the Yukon rule switch.
+    pub(super) fn set_waste_build_wrap_ranks(&self, layout: WasteBuildLayout, wrap_ranks: bool) {
+        let rules = self.current_waste_build_rules(layout);
+        if rules.wrap_ranks == wrap_ranks {
+            return;
+        }
+        let rules = WasteBuildRules {
+            wrap_ranks,
+            ..rules
+        };
+        let imp = self.imp();
+        let seed = imp.current_seed.get();
+        let game = WasteBuildGame::new_with_seed_and_rules(layout, seed, rules);
+        if self.active_game_mode() != layout.mode() {
+            *imp.game.borrow_mut().waste_build_mut(layout) = game;
+            self.update_game_settings_menu();
+            return;
+        }
+        let undo_anchor = self.snapshot();
+        *imp.game.borrow_mut().waste_build_mut(layout) = game;
+        self.start_new_game_with_seed(
+            seed,
+            format!(
+                "King\u{2013}Ace wrap {}. Redealt current seed {}.",
+                if wrap_ranks { "on" } else { "off" },
+                seed_ops::seed_label(seed)
+            ),
+        );
+        self.imp().history.borrow_mut().push(undo_anchor);
+        self.imp().future.borrow_mut().clear();
+        self.render();
+    }
+
+    /// Scoring never changes which moves are legal, so it toggles in place.
+    pub(super) fn set_waste_build_streak_scoring(&self, layout: WasteBuildLayout, enabled: bool) {
+        if self.current_waste_build_rules(layout).streak_scoring == enabled {
+            return;
+        }
+        self.imp()
+            .game
+            .borrow_mut()
+            .waste_build_mut(layout)
+            .set_streak_scoring(enabled);
+        if self.active_game_mode() == layout.mode() {
+            *self.imp().status_override.borrow_mut() = Some(format!(
+                "Streak scoring {}.",
+                if enabled { "on" } else { "off" }
+            ));
+            self.render();
+        }
+    }
+
+    pub(super) fn set_yukon_rule_set(&self, rule_set: YukonRuleSet) {
+        if self.current_yukon_rule_set() == rule_set {
+            return;
+        }
+        let imp = self.imp();
+        let seed = imp.current_seed.get();
+        if self.active_game_mode() != GameMode::Yukon {
+            // The rule set rides on the stored deal, so swap it in place and
+            // let the next Yukon session pick it up.
+            imp.game
+                .borrow_mut()
+                .set_yukon(YukonGame::new_with_seed_and_rules(seed, rule_set));
+            self.update_game_settings_menu();
+            return;
+        }
+        let undo_anchor = self.snapshot();
+        imp.game
+            .borrow_mut()
+            .set_yukon(YukonGame::new_with_seed_and_rules(seed, rule_set));
+        self.start_new_game_with_seed(
+            seed,
+            format!(
+                "{} rules selected. Redealt current seed {}.",
+                rule_set.label(),
+                seed_ops::seed_label(seed)
+            ),
+        );
+        self.imp().history.borrow_mut().push(undo_anchor);
+        self.imp().future.borrow_mut().clear();
+        self.render();
+    }
+
+    pub(super) fn select_yukon_rule_set(&self, rule_set: YukonRuleSet) {
+        let exited_chess = if self.imp().chess_mode_active.get() {
+            self.select_game_mode("yukon");
+            true
+        } else {
+            false
+        };
+        if self.active_game_mode() != GameMode::Yukon {
+            self.select_game_mode("yukon");
+        }
+        if self.current_yukon_rule_set() == rule_set {
+            if !exited_chess {
+                let seed = self.imp().current_seed.get();
+                self.start_new_game_with_seed(
+                    seed,
+                    format!(
+                        "{} rules selected. Redealt current seed {}.",
+                        rule_set.label(),
+                        seed_ops::seed_label(seed)
+                    ),
+                );
+            }
+        } else {
+            self.set_yukon_rule_set(rule_set);
+        }
+        self.set_ephemeral_status(
+            mode_switch_pun(GameMode::Yukon),
+            Duration::from_millis(2200),
+        );
+    }
+
     pub(super) fn set_freecell_cell_count(&self, freecell_count: u8, persist: bool) {
         let normalized =
             if (FREECELL_MIN_CELL_COUNT..=FREECELL_MAX_CELL_COUNT).contains(&freecell_count) {
@@ -244,6 +544,7 @@ impl CardthropicWindow {
                 let mut game = imp.game.borrow_mut();
                 let _ = game.freecell_mut().try_set_freecell_count(normalized);
             }
+            self.end_replay_recording();
             imp.freecell_cell_count.set(normalized);
             imp.last_stock_waste_foundation_size
                 .set((0, 0, imp.current_game_mode.get(), 0));
@@ -288,6 +589,13 @@ impl CardthropicWindow {
     }
 
     pub(super) fn guard_mode_engine(&self, action: &str) -> bool {
+        if self.replay_viewer_active() {
+            *self.imp().status_override.borrow_mut() = Some(format!(
+                "{action} is paused while the Replay Viewer shows a game. Close it to play on."
+            ));
+            self.render();
+            return false;
+        }
         let spec = self.mode_spec();
         if spec.engine_ready {
             return true;
@@ -301,7 +609,23 @@ impl CardthropicWindow {
         false
     }
 
+    /// Keyboard shortcuts reach features whose buttons are greyed out, so
+    /// capability-gated actions check again before running. Chess keeps its
+    /// own robot and wand, whatever card variant sits underneath.
+    pub(super) fn guard_mode_feature(&self, action: &str, supported: bool) -> bool {
+        if supported || self.imp().chess_mode_active.get() {
+            return true;
+        }
+        *self.imp().status_override.borrow_mut() = Some(format!(
+            "{action} is not available in {}.",
+            self.mode_spec().label
+        ));
+        self.render();
+        false
+    }
+
     pub(super) fn select_game_mode(&self, mode: &str) {
+        self.close_replay_viewer();
         let imp = self.imp();
         let previous_mode = imp.current_game_mode.get();
         let previous_chess_mode_active = imp.chess_mode_active.get();
@@ -314,6 +638,9 @@ impl CardthropicWindow {
                     imp.spider_suit_mode
                         .set(imp.game.borrow().spider().suit_mode());
                 }
+                if let Some(layout) = FreecellLayout::for_mode(spec.mode) {
+                    imp.game.borrow_mut().activate_freecell_layout(layout);
+                }
                 if spec.engine_ready {
                     format!("{} selected.", spec.label)
                 } else {
@@ -337,6 +664,11 @@ impl CardthropicWindow {
         self.update_game_settings_menu();
         *imp.status_override.borrow_mut() = Some(status);
         if imp.current_game_mode.get() != previous_mode || previous_chess_mode_active {
+            self.open_score_for_new_deal();
+            self.reset_stats_game_tracking();
+            self.clear_daily_game();
+            self.refresh_daily_dialog();
+            self.begin_replay_recording();
             imp.history.borrow_mut().push(undo_anchor);
             imp.future.borrow_mut().clear();
             // Hard reset geometry-sensitive caches on mode transitions so the
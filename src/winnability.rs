@@ -26,10 +26,13 @@ use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicU8, AtomicUsize,
 use std::sync::{mpsc, Arc};
 use std::thread;
 
-use crate::engine::freecell_planner::FreecellPlannerAction;
+use crate::engine::freecell_planner::{self, FreecellPlannerAction};
 use crate::engine::moves::HintMove;
+use crate::engine::open_tableau_planner;
 use crate::game::{
-    Card, DrawMode, FreecellGame, KlondikeGame, SolverMove, SpiderGame, SpiderSuitMode,
+    Card, DrawMode, FreecellGame, FreecellLayout, FreecellRules, KlondikeGame, KlondikePassLimit,
+    OpenTableauGame, OpenTableauLayout, SolverMove, SpiderGame, SpiderRuleSet, SpiderSuitMode,
+    ThoughtfulMove, ThoughtfulVerdict, WasteBuildGame, WasteBuildLayout, WasteBuildRules,
 };
 
 #[derive(Debug, Clone)]
@@ -347,14 +350,26 @@ fn capped_seed_check_budgets(
 pub fn is_seed_winnable(
     seed: u64,
     draw_mode: DrawMode,
+    pass_limit: KlondikePassLimit,
     guided_budget: usize,
     exhaustive_budget: usize,
     cancel: &AtomicBool,
 ) -> Option<SeedWinnabilityCheckResult> {
-    let (guided_budget, exhaustive_budget) =
-        capped_seed_check_budgets(draw_mode, guided_budget, exhaustive_budget);
     let mut game = KlondikeGame::new_with_seed(seed);
     game.set_draw_mode(draw_mode);
+    game.set_pass_limit(pass_limit);
+    is_klondike_position_winnable(&game, guided_budget, exhaustive_budget, cancel)
+}
+
+/// The seed check run from any Klondike position, such as an edited board.
+pub fn is_klondike_position_winnable(
+    game: &KlondikeGame,
+    guided_budget: usize,
+    exhaustive_budget: usize,
+    cancel: &AtomicBool,
+) -> Option<SeedWinnabilityCheckResult> {
+    let (guided_budget, exhaustive_budget) =
+        capped_seed_check_budgets(game.draw_mode(), guided_budget, exhaustive_budget);
     let guided_progress = AtomicUsize::new(0);
     let Some(guided) =
         game.guided_winnability_cancelable_with_progress(guided_budget, cancel, &guided_progress)
@@ -428,6 +443,66 @@ pub fn is_seed_winnable(
     })
 }
 
+/// Thoughtful Klondike check: one exact search, so a line that is not found
+/// inside the budget is proven missing unless `hit_state_limit` is set.
+pub fn is_thoughtful_seed_winnable(
+    seed: u64,
+    draw_mode: DrawMode,
+    pass_limit: KlondikePassLimit,
+    max_states: usize,
+    cancel: &AtomicBool,
+) -> Option<SeedWinnabilityCheckResult> {
+    let mut game = KlondikeGame::new_with_seed(seed);
+    game.set_draw_mode(draw_mode);
+    game.set_pass_limit(pass_limit);
+    game.set_thoughtful(true);
+    is_thoughtful_position_winnable(&game, max_states, cancel)
+}
+
+pub fn is_thoughtful_position_winnable(
+    game: &KlondikeGame,
+    max_states: usize,
+    cancel: &AtomicBool,
+) -> Option<SeedWinnabilityCheckResult> {
+    let Some(result) = game.solve_thoughtful_cancelable(max_states, cancel) else {
+        return Some(SeedWinnabilityCheckResult {
+            winnable: false,
+            iterations: 0,
+            moves_to_win: None,
+            hit_state_limit: true,
+            solver_line: None,
+            hint_line: None,
+            freecell_line: None,
+            canceled: true,
+        });
+    };
+    let winnable = result.verdict == ThoughtfulVerdict::Winnable;
+    // The robot replays plain solver moves; a line that lifts a card off a
+    // foundation is reported but not handed over.
+    let solver_line = winnable
+        .then(|| {
+            result
+                .line
+                .iter()
+                .map(|candidate| match candidate {
+                    ThoughtfulMove::Play(solver_move) => Some(*solver_move),
+                    ThoughtfulMove::FoundationToTableau { .. } => None,
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .flatten();
+    Some(SeedWinnabilityCheckResult {
+        winnable,
+        iterations: result.explored_states,
+        moves_to_win: winnable.then(|| u32::try_from(result.line.len()).unwrap_or(u32::MAX)),
+        hit_state_limit: result.verdict == ThoughtfulVerdict::Unknown,
+        solver_line,
+        hint_line: None,
+        freecell_line: None,
+        canceled: false,
+    })
+}
+
 #[derive(Clone)]
 struct SpiderGuidedNode {
     priority: i64,
@@ -505,6 +580,11 @@ fn spider_face_up_count(game: &SpiderGame) -> usize {
         .sum()
 }
 
+/// Whether the next stock deal is the last one the rule set hands out.
+fn spider_last_deal_pending(game: &SpiderGame) -> bool {
+    game.stock_len() > 0 && game.stock_len() <= game.tableau().len()
+}
+
 fn spider_empty_col_count(game: &SpiderGame) -> usize {
     game.tableau().iter().filter(|pile| pile.is_empty()).count()
 }
@@ -629,13 +709,13 @@ fn spider_successors_guided(
 ) -> Vec<(SpiderGame, HintMove, i64)> {
     let mut successors = Vec::new();
     let mut has_tableau_moves = false;
-    for src in 0..10 {
+    for src in 0..game.tableau().len() {
         let source = &game.tableau()[src];
         for start in 0..source.len() {
             if !source[start].face_up || !is_descending_face_up_run(&source[start..]) {
                 continue;
             }
-            for dst in 0..10 {
+            for dst in 0..game.tableau().len() {
                 if !game.can_move_run(src, start, dst) {
                     continue;
                 }
@@ -901,7 +981,7 @@ where
             if start == 0 {
                 // Clearing a source column creates strategic capacity, especially before last deal.
                 adjusted_score += policy.empty_create_bonus;
-                if game.stock_len() == 10 {
+                if spider_last_deal_pending(game) {
                     adjusted_score += policy.pre_last_draw_clear_bonus;
                 }
             }
@@ -909,7 +989,7 @@ where
                 let moved_len = game.tableau()[src].len().saturating_sub(start) as i64;
                 adjusted_score += policy.empty_bridge_bonus;
                 adjusted_score += moved_len * policy.empty_bridge_len_bonus;
-                if game.stock_len() == 10 {
+                if spider_last_deal_pending(game) {
                     adjusted_score += policy.pre_last_draw_empty_bonus;
                 }
             }
@@ -1049,13 +1129,13 @@ fn spider_find_policy(suit_mode: SpiderSuitMode) -> SpiderSolverPolicy {
 
 fn spider_has_reveal_ready_move(game: &SpiderGame) -> bool {
     let hidden_before = spider_hidden_count(game);
-    for src in 0..10 {
+    for src in 0..game.tableau().len() {
         let source = &game.tableau()[src];
         for start in 0..source.len() {
             if !source[start].face_up || !is_descending_face_up_run(&source[start..]) {
                 continue;
             }
-            for dst in 0..10 {
+            for dst in 0..game.tableau().len() {
                 if !game.can_move_run(src, start, dst) {
                     continue;
                 }
@@ -1113,6 +1193,7 @@ struct SpiderSinglePlaythroughResult {
 fn spider_single_playthrough_line(
     seed: u64,
     suit_mode: SpiderSuitMode,
+    rule_set: SpiderRuleSet,
     guided_budget: usize,
     exhaustive_budget: usize,
     cancel: &AtomicBool,
@@ -1129,6 +1210,7 @@ fn spider_single_playthrough_line(
     fn attempt_with_salt(
         seed: u64,
         suit_mode: SpiderSuitMode,
+        rule_set: SpiderRuleSet,
         max_steps: usize,
         stagnation_limit: usize,
         repeat_limit: usize,
@@ -1179,7 +1261,7 @@ fn spider_single_playthrough_line(
             }
         };
 
-        let mut game = SpiderGame::new_with_seed_and_mode(seed, suit_mode);
+        let mut game = SpiderGame::new_with_seed_and_rules(seed, suit_mode, rule_set);
         if game.is_won() {
             return finish(
                 Some(Vec::new()),
@@ -1258,7 +1340,7 @@ fn spider_single_playthrough_line(
             let branch_count = spider_solver_ranked_candidates(&game, suit_mode, parent_hash).len();
             generated_branches = generated_branches.saturating_add(branch_count);
             let selection_salt = next_u64(&mut rng_state);
-            let last_deal_candidate = game.stock_len() == 10;
+            let last_deal_candidate = spider_last_deal_pending(&game);
             let last_deal_ready = spider_empty_col_count(&game) > 0
                 || spider_has_reveal_ready_move(&game)
                 || spider_suited_desc_edges(&game) >= 12
@@ -1646,6 +1728,7 @@ fn spider_single_playthrough_line(
     let primary = attempt_with_salt(
         seed,
         suit_mode,
+        rule_set,
         max_steps,
         stagnation_limit,
         repeat_limit,
@@ -1792,6 +1875,7 @@ fn spider_single_playthrough_line(
                     let trial = attempt_with_salt(
                         seed,
                         suit_mode,
+                        rule_set,
                         rescue_steps,
                         rescue_stagnation,
                         rescue_repeat,
@@ -1847,6 +1931,7 @@ fn spider_single_playthrough_line(
                     let trial = attempt_with_salt(
                         seed,
                         suit_mode,
+                        rule_set,
                         rescue_steps,
                         rescue_stagnation,
                         rescue_repeat,
@@ -1973,10 +2058,22 @@ fn spider_verifier_budgets(
 pub fn is_spider_seed_winnable(
     seed: u64,
     suit_mode: SpiderSuitMode,
+    rule_set: SpiderRuleSet,
+    guided_budget: usize,
+    exhaustive_budget: usize,
+    cancel: &AtomicBool,
+) -> Option<SeedWinnabilityCheckResult> {
+    let start = SpiderGame::new_with_seed_and_rules(seed, suit_mode, rule_set);
+    is_spider_position_winnable(start, guided_budget, exhaustive_budget, cancel)
+}
+
+pub fn is_spider_position_winnable(
+    start: SpiderGame,
     guided_budget: usize,
     exhaustive_budget: usize,
     cancel: &AtomicBool,
 ) -> Option<SeedWinnabilityCheckResult> {
+    let suit_mode = start.suit_mode();
     if cancel.load(Ordering::Relaxed) {
         return Some(SeedWinnabilityCheckResult {
             winnable: false,
@@ -1990,7 +2087,6 @@ pub fn is_spider_seed_winnable(
         });
     }
 
-    let start = SpiderGame::new_with_seed_and_mode(seed, suit_mode);
     if start.is_won() {
         return Some(SeedWinnabilityCheckResult {
             winnable: true,
@@ -2161,6 +2257,14 @@ pub(crate) fn freecell_wand_state_hash(game: &FreecellGame) -> u64 {
     crate::engine::freecell_planner::zobrist_hash(game)
 }
 
+/// Unlike the Wand hash this tells cell slots apart, so a parent chain keyed
+/// on it replays with the cell indices it recorded.
+fn freecell_exact_state_hash(game: &FreecellGame) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    game.hash(&mut hasher);
+    hasher.finish()
+}
+
 #[derive(Clone)]
 struct FreecellGuidedNode {
     priority: i64,
@@ -2203,17 +2307,18 @@ fn freecell_foundation_cards(game: &FreecellGame) -> usize {
 
 fn freecell_legal_move_count(game: &FreecellGame) -> usize {
     let mut count = 0_usize;
+    let columns = game.tableau().len();
     for cell in 0..game.freecell_count() {
         if game.can_move_freecell_to_foundation(cell) {
             count += 1;
         }
-        for dst in 0..8 {
+        for dst in 0..columns {
             if game.can_move_freecell_to_tableau(cell, dst) {
                 count += 1;
             }
         }
     }
-    for src in 0..8 {
+    for src in 0..columns {
         if game.can_move_tableau_top_to_foundation(src) {
             count += 1;
         }
@@ -2224,7 +2329,7 @@ fn freecell_legal_move_count(game: &FreecellGame) -> usize {
         }
         let len = game.tableau().get(src).map(Vec::len).unwrap_or(0);
         for start in 0..len {
-            for dst in 0..8 {
+            for dst in 0..columns {
                 if game.can_move_tableau_run_to_tableau(src, start, dst) {
                     count += 1;
                 }
@@ -2318,23 +2423,11 @@ fn freecell_slot_preference_bias(card: Option<crate::game::Card>, cell: usize) -
 
 fn freecell_has_foundation_push(game: &FreecellGame) -> bool {
     (0..game.freecell_count()).any(|cell| game.can_move_freecell_to_foundation(cell))
-        || (0..8).any(|src| game.can_move_tableau_top_to_foundation(src))
+        || (0..game.tableau().len()).any(|src| game.can_move_tableau_top_to_foundation(src))
 }
 
 fn freecell_safe_foundation_bias(game: &FreecellGame, card: Option<Card>) -> i64 {
-    let Some(card) = card else {
-        return 0;
-    };
-    if card.rank <= 1 {
-        return 90_000;
-    }
-    let needed = usize::from(card.rank.saturating_sub(1));
-    let opposite_ok = if card.color_red() {
-        game.foundations()[0].len() >= needed && game.foundations()[3].len() >= needed
-    } else {
-        game.foundations()[1].len() >= needed && game.foundations()[2].len() >= needed
-    };
-    if opposite_ok {
+    if card.is_some_and(|card| game.is_safe_foundation_play(card)) {
         90_000
     } else {
         0
@@ -2348,7 +2441,7 @@ fn freecell_supermove_capacity(game: &FreecellGame) -> usize {
         .filter(|slot| slot.is_none())
         .count();
     let et = game.tableau().iter().filter(|col| col.is_empty()).count();
-    (ef + 1) * (1usize << et)
+    game.rules().supermove_capacity(ef, et)
 }
 
 fn freecell_king_to_empty_column_bias(
@@ -2498,7 +2591,7 @@ fn freecell_tableau_to_freecell_unlock_bias(
     if next.can_move_tableau_top_to_foundation(src) {
         bias += 360;
     }
-    let can_new_top_move_to_tableau = (0..8)
+    let can_new_top_move_to_tableau = (0..next.tableau().len())
         .filter(|&dst| dst != src)
         .any(|dst| next.can_move_tableau_run_to_tableau(src, next_len.saturating_sub(1), dst));
     if can_new_top_move_to_tableau {
@@ -2535,6 +2628,7 @@ fn freecell_wand_candidates(
     game: &FreecellGame,
 ) -> Vec<(FreecellGame, FreecellPlannerAction, i64)> {
     let mut out = Vec::new();
+    let columns = game.tableau().len();
 
     for cell in 0..game.freecell_count() {
         if !game.can_move_freecell_to_foundation(cell) {
@@ -2556,7 +2650,7 @@ fn freecell_wand_candidates(
         ));
     }
 
-    for src in 0..8 {
+    for src in 0..columns {
         if !game.can_move_tableau_top_to_foundation(src) {
             continue;
         }
@@ -2577,7 +2671,7 @@ fn freecell_wand_candidates(
     }
 
     for cell in 0..game.freecell_count() {
-        for dst in 0..8 {
+        for dst in 0..columns {
             if !game.can_move_freecell_to_tableau(cell, dst) {
                 continue;
             }
@@ -2599,10 +2693,10 @@ fn freecell_wand_candidates(
         }
     }
 
-    for src in 0..8 {
+    for src in 0..columns {
         let len = game.tableau().get(src).map(Vec::len).unwrap_or(0);
         for start in 0..len {
-            for dst in 0..8 {
+            for dst in 0..columns {
                 if !game.can_move_tableau_run_to_tableau(src, start, dst) {
                     continue;
                 }
@@ -2627,7 +2721,7 @@ fn freecell_wand_candidates(
         }
     }
 
-    for src in 0..8 {
+    for src in 0..columns {
         let card = game.tableau_top(src);
         for cell in 0..game.freecell_count() {
             if !game.can_move_tableau_top_to_freecell(src, cell) {
@@ -2830,8 +2924,25 @@ struct FreecellSinglePlaythroughResult {
     stop_reason: FreecellFindStopReason,
 }
 
+/// Opening deal for a layout; only classic FreeCell plays partial decks.
+fn freecell_deal(
+    seed: u64,
+    layout: FreecellLayout,
+    card_count_mode: crate::game::FreecellCardCountMode,
+) -> FreecellGame {
+    match layout {
+        FreecellLayout::FreeCell => {
+            FreecellGame::new_with_seed_and_card_count(seed, card_count_mode)
+        }
+        FreecellLayout::EightOff | FreecellLayout::SeahavenTowers | FreecellLayout::BakersGame => {
+            FreecellGame::new_with_seed_for_layout(layout, seed)
+        }
+    }
+}
+
 fn freecell_single_playthrough_line(
     seed: u64,
+    layout: FreecellLayout,
     card_count_mode: crate::game::FreecellCardCountMode,
     cancel: &AtomicBool,
 ) -> FreecellSinglePlaythroughResult {
@@ -2849,7 +2960,7 @@ fn freecell_single_playthrough_line(
             stop_reason,
         };
 
-    let mut game = FreecellGame::new_with_seed_and_card_count(seed, card_count_mode);
+    let mut game = freecell_deal(seed, layout, card_count_mode);
     if game.is_won() {
         return finish(Some(Vec::new()), 0, 0, FreecellFindStopReason::Won);
     }
@@ -2930,10 +3041,22 @@ fn freecell_single_playthrough_line(
 
 pub fn is_freecell_seed_winnable(
     seed: u64,
+    layout: FreecellLayout,
+    rules: FreecellRules,
     card_count_mode: crate::game::FreecellCardCountMode,
     guided_budget: usize,
     exhaustive_budget: usize,
     cancel: &AtomicBool,
+) -> Option<SeedWinnabilityCheckResult> {
+    let start = freecell_deal(seed, layout, card_count_mode).with_rules(rules);
+    is_freecell_position_winnable(start, guided_budget, exhaustive_budget, cancel)
+}
+
+pub fn is_freecell_position_winnable(
+    start: FreecellGame,
+    guided_budget: usize,
+    exhaustive_budget: usize,
+    cancel: &AtomicBool,
 ) -> Option<SeedWinnabilityCheckResult> {
     if cancel.load(Ordering::Relaxed) {
         return Some(SeedWinnabilityCheckResult {
@@ -2948,9 +3071,52 @@ pub fn is_freecell_seed_winnable(
         });
     }
 
-    let start = FreecellGame::new_with_seed_and_card_count(seed, card_count_mode);
+    let max_states = capped_freecell_seed_check_budget(guided_budget, exhaustive_budget);
+    if let Some(result) = freecell_proof_check(&start, max_states, cancel) {
+        return Some(result);
+    }
+    Some(freecell_guided_check(start, max_states, cancel))
+}
+
+/// Runs the planner's exhaustive search, which is far cheaper per position
+/// than the guided check. `None` when it ran out of budget without a verdict.
+fn freecell_proof_check(
+    start: &FreecellGame,
+    max_states: usize,
+    cancel: &AtomicBool,
+) -> Option<SeedWinnabilityCheckResult> {
+    const FREECELL_PROOF_STATES_PER_GUIDED_STATE: usize = 4;
+
+    let proof = freecell_planner::prove_line(
+        start,
+        max_states.saturating_mul(FREECELL_PROOF_STATES_PER_GUIDED_STATE),
+        Some(cancel),
+    );
+    let winnable = !proof.stalled;
+    if !winnable && !proof.exhausted {
+        return None;
+    }
+    Some(SeedWinnabilityCheckResult {
+        winnable,
+        iterations: proof.explored_states,
+        moves_to_win: winnable.then_some(proof.actions.len() as u32),
+        hit_state_limit: false,
+        solver_line: None,
+        hint_line: None,
+        freecell_line: winnable.then(|| proof.actions.into_iter().collect()),
+        canceled: false,
+    })
+}
+
+/// Best-first search from `start`; every candidate comes from the game's own
+/// move checks, so the line follows whatever rules the game carries.
+fn freecell_guided_check(
+    start: FreecellGame,
+    max_states: usize,
+    cancel: &AtomicBool,
+) -> SeedWinnabilityCheckResult {
     if start.is_won() {
-        return Some(SeedWinnabilityCheckResult {
+        return SeedWinnabilityCheckResult {
             winnable: true,
             iterations: 1,
             moves_to_win: Some(0),
@@ -2959,16 +3125,15 @@ pub fn is_freecell_seed_winnable(
             hint_line: None,
             freecell_line: Some(Vec::new()),
             canceled: false,
-        });
+        };
     }
 
-    let max_states = capped_freecell_seed_check_budget(guided_budget, exhaustive_budget);
     let mut visited: HashSet<FreecellGame> = HashSet::new();
     let mut frontier: BinaryHeap<FreecellGuidedNode> = BinaryHeap::new();
     let mut serial = 0_u64;
     let mut parent_by_hash: HashMap<u64, (Option<u64>, Option<FreecellPlannerAction>)> =
         HashMap::new();
-    let start_hash = freecell_wand_state_hash(&start);
+    let start_hash = freecell_exact_state_hash(&start);
     parent_by_hash.insert(start_hash, (None, None));
     frontier.push(FreecellGuidedNode {
         priority: 0,
@@ -2979,7 +3144,7 @@ pub fn is_freecell_seed_winnable(
 
     while let Some(node) = frontier.pop() {
         if cancel.load(Ordering::Relaxed) {
-            return Some(SeedWinnabilityCheckResult {
+            return SeedWinnabilityCheckResult {
                 winnable: false,
                 iterations: visited.len(),
                 moves_to_win: None,
@@ -2988,7 +3153,7 @@ pub fn is_freecell_seed_winnable(
                 hint_line: None,
                 freecell_line: None,
                 canceled: true,
-            });
+            };
         }
 
         if !visited.insert(node.state.clone()) {
@@ -2996,7 +3161,7 @@ pub fn is_freecell_seed_winnable(
         }
         if node.state.is_won() {
             let mut line_rev: Vec<FreecellPlannerAction> = Vec::new();
-            let mut cursor = Some(freecell_wand_state_hash(&node.state));
+            let mut cursor = Some(freecell_exact_state_hash(&node.state));
             while let Some(hash) = cursor {
                 let Some((parent_hash, action)) = parent_by_hash.get(&hash).copied() else {
                     break;
@@ -3007,7 +3172,7 @@ pub fn is_freecell_seed_winnable(
                 cursor = parent_hash;
             }
             line_rev.reverse();
-            return Some(SeedWinnabilityCheckResult {
+            return SeedWinnabilityCheckResult {
                 winnable: true,
                 iterations: visited.len(),
                 moves_to_win: Some(node.depth),
@@ -3016,10 +3181,10 @@ pub fn is_freecell_seed_winnable(
                 hint_line: None,
                 freecell_line: Some(line_rev),
                 canceled: false,
-            });
+            };
         }
         if visited.len() >= max_states {
-            return Some(SeedWinnabilityCheckResult {
+            return SeedWinnabilityCheckResult {
                 winnable: false,
                 iterations: visited.len(),
                 moves_to_win: None,
@@ -3028,16 +3193,16 @@ pub fn is_freecell_seed_winnable(
                 hint_line: None,
                 freecell_line: None,
                 canceled: false,
-            });
+            };
         }
 
-        let current_hash = freecell_wand_state_hash(&node.state);
+        let current_hash = freecell_exact_state_hash(&node.state);
         for (next, action, score) in freecell_wand_candidates(&node.state) {
             if visited.contains(&next) {
                 continue;
             }
             serial = serial.wrapping_add(1);
-            let next_hash = freecell_wand_state_hash(&next);
+            let next_hash = freecell_exact_state_hash(&next);
             parent_by_hash
                 .entry(next_hash)
                 .or_insert((Some(current_hash), Some(action)));
@@ -3050,7 +3215,7 @@ pub fn is_freecell_seed_winnable(
         }
     }
 
-    Some(SeedWinnabilityCheckResult {
+    SeedWinnabilityCheckResult {
         winnable: false,
         iterations: visited.len(),
         moves_to_win: None,
@@ -3059,7 +3224,7 @@ pub fn is_freecell_seed_winnable(
         hint_line: None,
         freecell_line: None,
         canceled: false,
-    })
+    }
 }
 
 pub fn find_winnable_freecell_seed_parallel(
@@ -3067,6 +3232,7 @@ pub fn find_winnable_freecell_seed_parallel(
     attempts: u32,
     guided_budget: usize,
     exhaustive_budget: usize,
+    layout: FreecellLayout,
     card_count_mode: crate::game::FreecellCardCountMode,
     cancel: Arc<AtomicBool>,
     progress_checked: Option<Arc<AtomicU32>>,
@@ -3109,7 +3275,8 @@ pub fn find_winnable_freecell_seed_parallel(
             let seed = start_seed.wrapping_add(u64::from(index));
             let _ = guided_budget;
             let _ = exhaustive_budget;
-            let attempt = freecell_single_playthrough_line(seed, card_count_mode, cancel.as_ref());
+            let attempt =
+                freecell_single_playthrough_line(seed, layout, card_count_mode, cancel.as_ref());
             if let Some(progress) = &progress_stats {
                 progress.store_attempt(
                     seed,
@@ -3146,6 +3313,7 @@ pub fn find_winnable_spider_seed_parallel(
     guided_budget: usize,
     exhaustive_budget: usize,
     suit_mode: SpiderSuitMode,
+    rule_set: SpiderRuleSet,
     cancel: Arc<AtomicBool>,
     progress_checked: Option<Arc<AtomicU32>>,
     progress_stats: Option<Arc<SpiderFindProgress>>,
@@ -3205,6 +3373,7 @@ pub fn find_winnable_spider_seed_parallel(
                     let won_line = is_spider_seed_winnable(
                         cseed,
                         suit_mode,
+                        rule_set,
                         verify_guided,
                         verify_exhaustive,
                         cancel.as_ref(),
@@ -3233,6 +3402,7 @@ pub fn find_winnable_spider_seed_parallel(
                 let attempt = spider_single_playthrough_line(
                     seed,
                     suit_mode,
+                    rule_set,
                     guided_budget,
                     exhaustive_budget,
                     cancel.as_ref(),
@@ -3324,11 +3494,215 @@ pub fn find_winnable_spider_seed_parallel(
     result
 }
 
+/// TriPeaks and Golf deal with no hidden choices beyond the stock order, so
+/// one exhaustive search settles a seed. The robot re-solves from the live
+/// position, so no line is carried back.
+pub fn is_waste_build_seed_winnable(
+    seed: u64,
+    layout: WasteBuildLayout,
+    rules: WasteBuildRules,
+    max_states: usize,
+    cancel: &AtomicBool,
+) -> Option<SeedWinnabilityCheckResult> {
+    let game = WasteBuildGame::new_with_seed_and_rules(layout, seed, rules);
+    let Some(result) = game.solve_cancelable(max_states, cancel) else {
+        return Some(SeedWinnabilityCheckResult {
+            winnable: false,
+            iterations: 0,
+            moves_to_win: None,
+            hit_state_limit: true,
+            solver_line: None,
+            hint_line: None,
+            freecell_line: None,
+            canceled: true,
+        });
+    };
+    Some(SeedWinnabilityCheckResult {
+        winnable: result.winnable,
+        iterations: result.explored_states,
+        moves_to_win: result
+            .winnable
+            .then(|| u32::try_from(result.line.len()).unwrap_or(u32::MAX)),
+        hit_state_limit: result.hit_state_limit,
+        solver_line: None,
+        hint_line: None,
+        freecell_line: None,
+        canceled: false,
+    })
+}
+
+pub fn find_winnable_waste_build_seed_parallel(
+    start_seed: u64,
+    attempts: u32,
+    max_states: usize,
+    layout: WasteBuildLayout,
+    rules: WasteBuildRules,
+    cancel: Arc<AtomicBool>,
+) -> Option<(u64, u32)> {
+    if attempts == 0 {
+        return None;
+    }
+
+    let worker_count = thread::available_parallelism()
+        .map(|n| n.get())
+        .map(|n| n.min(4))
+        .unwrap_or(1)
+        .min(attempts as usize)
+        .max(1);
+
+    let next_index = Arc::new(AtomicU32::new(0));
+    let (sender, receiver) = mpsc::channel::<(u64, u32)>();
+    let mut handles = Vec::with_capacity(worker_count);
+
+    for _ in 0..worker_count {
+        let next_index = Arc::clone(&next_index);
+        let cancel = Arc::clone(&cancel);
+        let sender = sender.clone();
+        let handle = thread::spawn(move || loop {
+            if cancel.load(Ordering::Relaxed) {
+                break;
+            }
+            let index = next_index.fetch_add(1, Ordering::Relaxed);
+            if index >= attempts {
+                break;
+            }
+
+            let seed = start_seed.wrapping_add(u64::from(index));
+            let game = WasteBuildGame::new_with_seed_and_rules(layout, seed, rules);
+            let Some(result) = game.solve_cancelable(max_states, cancel.as_ref()) else {
+                break;
+            };
+            if !result.winnable {
+                continue;
+            }
+            if !cancel.swap(true, Ordering::Relaxed) {
+                let _ = sender.send((seed, index + 1));
+            }
+            break;
+        });
+        handles.push(handle);
+    }
+
+    drop(sender);
+    let result = receiver.recv().ok();
+    cancel.store(true, Ordering::Relaxed);
+    for handle in handles {
+        let _ = handle.join();
+    }
+    result
+}
+
+/// Baker's Dozen and Beleaguered Castle deal every card face up, so an
+/// exhausted search is a proof of loss rather than a guess.
+pub fn is_open_tableau_seed_winnable(
+    seed: u64,
+    layout: OpenTableauLayout,
+    max_states: usize,
+    cancel: &AtomicBool,
+) -> Option<SeedWinnabilityCheckResult> {
+    let game = OpenTableauGame::new_with_seed(layout, seed);
+    is_open_tableau_position_winnable(&game, max_states, cancel)
+}
+
+pub fn is_open_tableau_position_winnable(
+    game: &OpenTableauGame,
+    max_states: usize,
+    cancel: &AtomicBool,
+) -> Option<SeedWinnabilityCheckResult> {
+    let Some(result) = open_tableau_planner::solve_cancelable(game, max_states, cancel) else {
+        return Some(SeedWinnabilityCheckResult {
+            winnable: false,
+            iterations: 0,
+            moves_to_win: None,
+            hit_state_limit: true,
+            solver_line: None,
+            hint_line: None,
+            freecell_line: None,
+            canceled: true,
+        });
+    };
+    Some(SeedWinnabilityCheckResult {
+        winnable: result.winnable,
+        iterations: result.explored_states,
+        moves_to_win: result
+            .winnable
+            .then(|| u32::try_from(result.line.len()).unwrap_or(u32::MAX)),
+        hit_state_limit: result.hit_state_limit,
+        solver_line: None,
+        hint_line: None,
+        freecell_line: None,
+        canceled: false,
+    })
+}
+
+pub fn find_winnable_open_tableau_seed_parallel(
+    start_seed: u64,
+    attempts: u32,
+    max_states: usize,
+    layout: OpenTableauLayout,
+    cancel: Arc<AtomicBool>,
+) -> Option<(u64, u32)> {
+    if attempts == 0 {
+        return None;
+    }
+
+    let worker_count = thread::available_parallelism()
+        .map(|n| n.get())
+        .map(|n| n.min(4))
+        .unwrap_or(1)
+        .min(attempts as usize)
+        .max(1);
+
+    let next_index = Arc::new(AtomicU32::new(0));
+    let (sender, receiver) = mpsc::channel::<(u64, u32)>();
+    let mut handles = Vec::with_capacity(worker_count);
+
+    for _ in 0..worker_count {
+        let next_index = Arc::clone(&next_index);
+        let cancel = Arc::clone(&cancel);
+        let sender = sender.clone();
+        let handle = thread::spawn(move || loop {
+            if cancel.load(Ordering::Relaxed) {
+                break;
+            }
+            let index = next_index.fetch_add(1, Ordering::Relaxed);
+            if index >= attempts {
+                break;
+            }
+
+            let seed = start_seed.wrapping_add(u64::from(index));
+            let game = OpenTableauGame::new_with_seed(layout, seed);
+            let Some(result) =
+                open_tableau_planner::solve_cancelable(&game, max_states, cancel.as_ref())
+            else {
+                break;
+            };
+            if !result.winnable {
+                continue;
+            }
+            if !cancel.swap(true, Ordering::Relaxed) {
+                let _ = sender.send((seed, index + 1));
+            }
+            break;
+        });
+        handles.push(handle);
+    }
+
+    drop(sender);
+    let result = receiver.recv().ok();
+    cancel.store(true, Ordering::Relaxed);
+    for handle in handles {
+        let _ = handle.join();
+    }
+    result
+}
+
 pub fn find_winnable_seed_parallel(
     start_seed: u64,
     attempts: u32,
     max_states: usize,
     draw_mode: DrawMode,
+    pass_limit: KlondikePassLimit,
     cancel: Arc<AtomicBool>,
 ) -> Option<(u64, u32, Vec<SolverMove>)> {
     if attempts == 0 {
@@ -3362,6 +3736,7 @@ pub fn find_winnable_seed_parallel(
             let seed = start_seed.wrapping_add(u64::from(index));
             let mut game = KlondikeGame::new_with_seed(seed);
             game.set_draw_mode(draw_mode);
+            game.set_pass_limit(pass_limit);
             let Some(winnable) = game.is_winnable_guided_cancelable(max_states, cancel.as_ref())
             else {
                 break;
@@ -3400,11 +3775,127 @@ mod tests {
     use super::*;
     use crate::game::Suit;
 
+    #[test]
+    fn freecell_guided_check_follows_the_game_rules() {
+        fn c(suit: Suit, rank: u8) -> Card {
+            Card {
+                suit,
+                rank,
+                face_up: true,
+            }
+        }
+        let foundations: [Vec<Card>; 4] =
+            std::array::from_fn(|idx| (1..=10).map(|rank| c(Suit::ALL[idx], rank)).collect());
+        // Each jack sits under a two-card run of another suit.
+        let buried = |jack: Suit, run: Suit| vec![c(jack, 11), c(run, 13), c(run, 12)];
+        let tableau = vec![
+            buried(Suit::Spades, Suit::Hearts),
+            buried(Suit::Hearts, Suit::Spades),
+            buried(Suit::Clubs, Suit::Diamonds),
+            buried(Suit::Diamonds, Suit::Clubs),
+        ];
+        let single_card = FreecellRules {
+            supermoves: false,
+            ..FreecellRules::CLASSIC
+        };
+        for (layout, rules) in [
+            (
+                FreecellLayout::BakersGame,
+                FreecellLayout::BakersGame.rules(),
+            ),
+            (
+                FreecellLayout::SeahavenTowers,
+                FreecellLayout::SeahavenTowers.rules(),
+            ),
+            (FreecellLayout::FreeCell, single_card),
+        ] {
+            let start = FreecellGame::debug_new_with_layout(
+                layout,
+                foundations.clone(),
+                &[],
+                tableau.clone(),
+            )
+            .with_rules(rules);
+            let result = freecell_guided_check(start.clone(), 50_000, &AtomicBool::new(false));
+            assert!(result.winnable, "{layout:?} should be solved");
+            let mut game = start;
+            for action in result.freecell_line.expect("winning line") {
+                assert!(
+                    apply_freecell_action(&mut game, action),
+                    "{layout:?} replayed an illegal {action:?}"
+                );
+            }
+            assert!(game.is_won());
+        }
+    }
+
+    #[test]
+    fn freecell_seed_check_settles_classic_microsoft_deals() {
+        let check = |number| {
+            is_freecell_seed_winnable(
+                crate::game::microsoft_deal_seed(number).expect("valid deal number"),
+                FreecellLayout::FreeCell,
+                FreecellRules::CLASSIC,
+                crate::game::FreecellCardCountMode::FiftyTwo,
+                180_000,
+                300_000,
+                &AtomicBool::new(false),
+            )
+            .expect("result should always be returned")
+        };
+
+        let impossible = check(11_982);
+        assert!(!impossible.winnable);
+        assert!(!impossible.hit_state_limit, "ms:11982 should be proven");
+
+        let first = check(1);
+        assert!(first.winnable);
+        let mut game = freecell_deal(
+            crate::game::microsoft_deal_seed(1).expect("valid deal number"),
+            FreecellLayout::FreeCell,
+            crate::game::FreecellCardCountMode::FiftyTwo,
+        );
+        for action in first.freecell_line.expect("winning line") {
+            assert!(
+                apply_freecell_action(&mut game, action),
+                "illegal {action:?}"
+            );
+        }
+        assert!(game.is_won());
+    }
+
+    #[test]
+    fn position_checks_solve_edited_boards() {
+        let cancel = AtomicBool::new(false);
+        let freecell = "Game: FreeCell\nFoundations: C-K D-K H-K S-J\nQS\nKS\n-\n-\n-\n-\n-\n-";
+        let Ok(crate::game::TextBoard::Freecell(game)) = crate::game::TextBoard::parse(freecell)
+        else {
+            panic!("near-won FreeCell board");
+        };
+        let result = is_freecell_position_winnable(game, 1_000, 1_000, &cancel).expect("result");
+        assert!(result.winnable);
+
+        let klondike = "Foundations: C-K D-K H-K S-Q\nKS\n-\n-\n-\n-\n-\n-";
+        let Ok(crate::game::TextBoard::Klondike(game)) = crate::game::TextBoard::parse(klondike)
+        else {
+            panic!("near-won Klondike board");
+        };
+        let result = is_klondike_position_winnable(&game, 1_000, 1_000, &cancel).expect("result");
+        assert!(result.winnable);
+    }
+
     #[test]
     fn spider_seed_winnability_honors_cancel_flag() {
         let cancel = AtomicBool::new(true);
-        let result = is_spider_seed_winnable(123, SpiderSuitMode::One, 100, 100, &cancel)
-            .expect("result should always be returned");
+        let result = is_spider_seed_winnable(
+            123,
+            SpiderSuitMode::One,
+            SpiderRuleSet::Spider,
+            100,
+            100,
+            &cancel,
+        )
+        .expect("result should always be returned");
         assert!(result.canceled);
         assert!(!result.winnable);
         assert!(result.hint_line.is_none());
@@ -3414,8 +3905,15 @@ mod tests {
     #[test]
     fn spider_seed_winnability_returns_spider_hint_line_shape() {
         let cancel = AtomicBool::new(false);
-        let result = is_spider_seed_winnable(123, SpiderSuitMode::Four, 4, 4, &cancel)
-            .expect("result should always be returned");
+        let result = is_spider_seed_winnable(
+            123,
+            SpiderSuitMode::Four,
+            SpiderRuleSet::Spider,
+            4,
+            4,
+            &cancel,
+        )
+        .expect("result should always be returned");
         assert!(result.solver_line.is_none());
         if let Some(line) = result.hint_line {
             assert_eq!(
@@ -3434,6 +3932,7 @@ mod tests {
             15_000,
             0,
             SpiderSuitMode::Four,
+            SpiderRuleSet::Spider,
             cancel,
             None,
             None,
@@ -3559,6 +4058,7 @@ mod tests {
             15_000,
             0,
             SpiderSuitMode::Four,
+            SpiderRuleSet::Spider,
             Arc::clone(&cancel),
             Some(Arc::clone(&progress_checked)),
             Some(Arc::clone(&progress_stats)),
@@ -3620,6 +4120,7 @@ mod tests {
                 15_000,
                 0,
                 SpiderSuitMode::Four,
+                SpiderRuleSet::Spider,
                 Arc::clone(&cancel),
                 Some(Arc::clone(&progress_checked)),
                 Some(Arc::clone(&progress_stats)),
@@ -3698,6 +4199,7 @@ mod tests {
                 15_000,
                 0,
                 SpiderSuitMode::One,
+                SpiderRuleSet::Spider,
                 Arc::clone(&cancel),
                 Some(Arc::clone(&progress_checked)),
                 Some(Arc::clone(&progress_stats)),